@@ -0,0 +1,194 @@
+//! Convex hulls of point sets.
+//!
+//! The points within `TOLERANCE` from the boundary of the hull are regarded as inside, so the
+//! collinear points on the edges and the coplanar points on the faces are not the vertices of
+//! the hull.
+
+use crate::{cgmath64::*, tolerance::*};
+use std::collections::HashSet;
+
+/// Returns the indices of the vertices of the convex hull of `points`, counterclockwise.
+///
+/// The collinear points on the edges of the hull and the duplicated points are omitted.
+/// If all points are within `TOLERANCE`, the only vertex is returned, and if all points are
+/// collinear, the two ends are returned. The hull of the empty set is empty.
+/// # Examples
+/// ```
+/// use truck_base::{cgmath64::*, convex_hull::*};
+/// let points = [
+///     Point2::new(0.0, 0.0),
+///     Point2::new(1.0, 0.0),
+///     Point2::new(0.5, 0.5),
+///     Point2::new(1.0, 1.0),
+///     Point2::new(0.5, 1.0),
+///     Point2::new(0.0, 1.0),
+/// ];
+/// assert_eq!(convex_hull_2d(&points), vec![0, 1, 3, 5]);
+/// ```
+pub fn convex_hull_2d(points: &[Point2]) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..points.len()).collect();
+    indices.sort_by(|i, j| {
+        let (p, q) = (points[*i], points[*j]);
+        p.x.total_cmp(&q.x).then(p.y.total_cmp(&q.y))
+    });
+    indices.dedup_by(|i, j| points[*i].near(&points[*j]));
+    if indices.len() < 3 {
+        return indices;
+    }
+    // Andrew's monotone chain: the lower hull and the upper hull
+    let mut hull: Vec<usize> = Vec::with_capacity(indices.len() + 1);
+    let chains = [indices.clone(), indices.into_iter().rev().collect()];
+    for chain in chains {
+        let start = hull.len();
+        for i in chain {
+            while hull.len() >= start + 2 {
+                let (a, b) = (points[hull[hull.len() - 2]], points[hull[hull.len() - 1]]);
+                if left_distance(a, b, points[i]) > TOLERANCE {
+                    break;
+                }
+                hull.pop();
+            }
+            hull.push(i);
+        }
+        hull.pop();
+    }
+    // if all points are collinear, only the ends remain
+    hull
+}
+
+/// Returns the signed distance from the line `ab` to `p`, positive on the left.
+fn left_distance(a: Point2, b: Point2, p: Point2) -> f64 {
+    (b - a).perp_dot(p - a) / (b - a).magnitude()
+}
+
+/// Returns the triangles of the boundary of the convex hull of `points`, by the incremental
+/// algorithm.
+///
+/// Each triangle is the triple of the indices of `points`, counterclockwise from the outside.
+/// The coplanar points on the faces of the hull are omitted, so the flat faces may be
+/// triangulated arbitrarily. If `points` are coplanar, the hull has no volume and the empty
+/// vector is returned.
+/// # Examples
+/// ```
+/// use truck_base::{cgmath64::*, convex_hull::*};
+/// let points = [
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(0.0, 0.0, 1.0),
+///     Point3::new(0.1, 0.1, 0.1),
+/// ];
+/// let triangles = convex_hull_3d(&points);
+/// assert_eq!(triangles.len(), 4);
+/// // the inner point is not a vertex
+/// assert!(triangles.iter().flatten().all(|i| *i != 4));
+/// // the triangles are oriented outward
+/// let volume: f64 = triangles
+///     .iter()
+///     .map(|[a, b, c]| points[*a].to_vec().dot(points[*b].to_vec().cross(points[*c].to_vec())))
+///     .sum();
+/// assert!(f64::abs(volume / 6.0 - 1.0 / 6.0) < 1.0e-10);
+/// ```
+pub fn convex_hull_3d(points: &[Point3]) -> Vec<[usize; 3]> {
+    let Some(simplex) = initial_simplex(points) else {
+        return Vec::new();
+    };
+    let mut faces: Vec<HullFace> = [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]]
+        .into_iter()
+        .map(|[a, b, c]| {
+            let face = HullFace::new(points, [simplex[a], simplex[b], simplex[c]]);
+            let opposite = simplex[6 - a - b - c];
+            match face.distance(points[opposite]) > 0.0 {
+                true => HullFace::new(points, [simplex[a], simplex[c], simplex[b]]),
+                false => face,
+            }
+        })
+        .collect();
+    for (i, p) in points.iter().enumerate() {
+        if simplex.contains(&i) {
+            continue;
+        }
+        let (visible, hidden): (Vec<_>, Vec<_>) =
+            faces.into_iter().partition(|face| face.distance(*p) > TOLERANCE);
+        faces = hidden;
+        if visible.is_empty() {
+            continue;
+        }
+        let edges: HashSet<(usize, usize)> = visible.iter().flat_map(HullFace::edges).collect();
+        let horizon = edges.iter().filter(|(a, b)| !edges.contains(&(*b, *a)));
+        faces.extend(horizon.map(|(a, b)| HullFace::new(points, [*a, *b, i])));
+    }
+    // The coplanar points added before the farther ones may remain on the flat faces or the
+    // edges. They are removed by running again only with the extreme points.
+    let vertices: HashSet<usize> = faces.iter().flat_map(|face| face.vertices).collect();
+    let vertex_count = vertices.len();
+    let mut extremes: Vec<usize> = vertices
+        .into_iter()
+        .filter(|i| {
+            let mut normals: Vec<Vector3> = Vec::new();
+            faces.iter().filter(|face| face.vertices.contains(i)).for_each(|face| {
+                if !normals.iter().any(|n| n.near(&face.normal)) {
+                    normals.push(face.normal);
+                }
+            });
+            normals.len() > 2
+        })
+        .collect();
+    let mut triangles: Vec<[usize; 3]> = match extremes.len() < vertex_count {
+        true => {
+            extremes.sort();
+            let extreme_points: Vec<Point3> = extremes.iter().map(|i| points[*i]).collect();
+            let triangles = convex_hull_3d(&extreme_points);
+            triangles.into_iter().map(|t| t.map(|i| extremes[i])).collect()
+        }
+        false => faces.into_iter().map(|face| face.vertices).collect(),
+    };
+    triangles.sort();
+    triangles
+}
+
+/// A triangle of the hull with its unit outward normal.
+struct HullFace {
+    vertices: [usize; 3],
+    origin: Point3,
+    normal: Vector3,
+}
+
+impl HullFace {
+    fn new(points: &[Point3], vertices: [usize; 3]) -> Self {
+        let [a, b, c] = vertices.map(|i| points[i]);
+        Self {
+            vertices,
+            origin: a,
+            normal: (b - a).cross(c - a).normalize(),
+        }
+    }
+    fn distance(&self, p: Point3) -> f64 { self.normal.dot(p - self.origin) }
+    fn edges(&self) -> [(usize, usize); 3] {
+        let [a, b, c] = self.vertices;
+        [(a, b), (b, c), (c, a)]
+    }
+}
+
+/// Returns four points spanning a tetrahedron, `None` if the points are coplanar.
+fn initial_simplex(points: &[Point3]) -> Option<[usize; 4]> {
+    let farthest = |distance: &dyn Fn(Point3) -> f64| {
+        let (i, d) = points
+            .iter()
+            .map(|p| distance(*p))
+            .enumerate()
+            .max_by(|(_, d0), (_, d1)| d0.total_cmp(d1))?;
+        match d > TOLERANCE {
+            true => Some(i),
+            false => None,
+        }
+    };
+    let i0 = (0..points.len()).min_by(|i, j| points[*i].x.total_cmp(&points[*j].x))?;
+    let p0 = points[i0];
+    let i1 = farthest(&|p| p.distance(p0))?;
+    let dir = (points[i1] - p0).normalize();
+    let i2 = farthest(&|p| (p - p0).cross(dir).magnitude())?;
+    let normal = dir.cross(points[i2] - p0).normalize();
+    let i3 = farthest(&|p| f64::abs(normal.dot(p - p0)))?;
+    Some([i0, i1, i2, i3])
+}
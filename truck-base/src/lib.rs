@@ -19,6 +19,7 @@ pub mod bounding_box;
 pub mod cgmath64;
 /// Additional traits for cgmath
 pub mod cgmath_extend_traits;
+pub mod convex_hull;
 /// Utilities for performing calculations related to differentiation
 pub mod ders;
 /// Utility
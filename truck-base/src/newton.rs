@@ -56,9 +56,44 @@ impl_jacobian!(Matrix4, Vector4);
 /// ```
 pub fn solve<V, M>(
     function: impl Fn(V) -> CalcOutput<V, M>,
-    mut hint: V,
+    hint: V,
     trials: usize,
 ) -> Result<V, NewtonLog<V>>
+where
+    V: Sub<Output = V> + Copy + Tolerance,
+    M: Jacobian<V>,
+{
+    solve_with_log(function, hint, trials).map(|(res, _)| res)
+}
+
+/// Same as [`solve`], but returns the log also on the convergence.
+/// # Examples
+/// ```
+/// use truck_base::{newton::*, assert_near2};
+///
+/// let function = |x: f64| CalcOutput {
+///     value: x * x - 2.0,
+///     derivation: 2.0 * x,
+/// };
+/// let (sqrt2, log) = solve_with_log(function, 1.0, 10).unwrap();
+/// assert_near2!(sqrt2, f64::sqrt(2.0));
+/// assert!(log.iterations() < 10);
+/// assert_eq!(log.last(), Some(&sqrt2));
+///
+/// // no root
+/// let function = |x: f64| CalcOutput {
+///     value: x * x + 1.0,
+///     derivation: 2.0 * x,
+/// };
+/// let log = solve_with_log(function, 0.0, 10).unwrap_err();
+/// assert!(log.degenerate());
+/// assert_eq!(log.iterations(), 1);
+/// ```
+pub fn solve_with_log<V, M>(
+    function: impl Fn(V) -> CalcOutput<V, M>,
+    mut hint: V,
+    trials: usize,
+) -> Result<(V, NewtonLog<V>), NewtonLog<V>>
 where
     V: Sub<Output = V> + Copy + Tolerance,
     M: Jacobian<V>,
@@ -73,7 +108,7 @@ where
         };
         let next = hint - inv * value;
         if next.near2(&hint) {
-            return Ok(hint);
+            return Ok((hint, log));
         }
         hint = next;
     }
@@ -86,6 +121,8 @@ mod newtonlog {
     #[derive(Clone, Debug)]
     pub struct NewtonLog<T> {
         log: Option<Vec<T>>,
+        last: Option<T>,
+        iterations: usize,
         degenerate: bool,
     }
 
@@ -96,10 +133,14 @@ mod newtonlog {
             match activate {
                 true => NewtonLog {
                     log: Some(Vec::with_capacity(trials)),
+                    last: None,
+                    iterations: 0,
                     degenerate: false,
                 },
                 false => NewtonLog {
                     log: None,
+                    last: None,
+                    iterations: 0,
                     degenerate: false,
                 },
             }
@@ -107,14 +148,25 @@ mod newtonlog {
         /// Returns `true` iff the Newton method terminates due to Jacobian degeneracy.
         #[inline(always)]
         pub fn degenerate(&self) -> bool { self.degenerate }
+        /// Returns the number of the iterations, i.e. the evaluations of the function.
+        #[inline(always)]
+        pub fn iterations(&self) -> usize { self.iterations }
+        /// Returns the last value at which the function is evaluated.
+        #[inline(always)]
+        pub fn last(&self) -> Option<&T> { self.last.as_ref() }
+        #[inline(always)]
+        pub(super) fn set_degenerate(&mut self, degenerate: bool) { self.degenerate = degenerate }
+    }
+
+    impl<T: Clone> NewtonLog<T> {
         #[inline(always)]
         pub(super) fn push(&mut self, log: T) {
             if let Some(vec) = &mut self.log {
-                vec.push(log)
+                vec.push(log.clone())
             }
+            self.last = Some(log);
+            self.iterations += 1;
         }
-        #[inline(always)]
-        pub(super) fn set_degenerate(&mut self, degenerate: bool) { self.degenerate = degenerate }
     }
 
     impl<T: Debug> Display for NewtonLog<T> {
@@ -9,16 +9,135 @@ pub const TOLERANCE: f64 = 1.0e-6;
 pub const TOLERANCE2: f64 = TOLERANCE * TOLERANCE;
 
 /// Defines a tolerance in the whole package
+///
+/// # Absolute or relative
+///
+/// The absolute comparisons [`Tolerance::near`] and [`Origin::so_small`] are for the values
+/// whose magnitudes are about `1`, e.g. the parameters of the normalized curves, the unit
+/// vectors, and the coordinates of the models in moderate units. They are the defaults of the
+/// whole package.
+///
+/// The relative comparison [`Tolerance::near_rel`] is for the values whose magnitudes are not
+/// known in advance, e.g. the coordinates of the imported models: `TOLERANCE` merges distinct
+/// micro features of the small models, and is below the rounding errors of the huge ones.
+/// The geometric helpers take [`ToleranceSpec`] so that the callers choose the policy, and the
+/// default spec is the absolute `TOLERANCE`.
 pub trait Tolerance: AbsDiffEq<Epsilon = f64> + Debug {
     /// The "distance" is less than `TOLERANCE`.
     fn near(&self, other: &Self) -> bool { self.abs_diff_eq(other, TOLERANCE) }
 
     /// The "distance" is less than `TOLERANCR2`.
     fn near2(&self, other: &Self) -> bool { self.abs_diff_eq(other, TOLERANCE2) }
+
+    /// The "distance" is less than `rel` times the larger scale of `self` and `other`, or than
+    /// `TOLERANCE` if it is larger. cf. [`ToleranceScale`]
+    /// # Examples
+    /// ```
+    /// use truck_base::{cgmath64::*, tolerance::*};
+    /// let p = Point3::new(1.0e6, 2.0e6, 0.0);
+    /// let q = p + Vector3::new(0.1, 0.0, 0.0);
+    /// assert!(!p.near(&q));
+    /// assert!(p.near_rel(&q, 1.0e-6));
+    ///
+    /// // the absolute floor
+    /// assert!(1.0e-8.near_rel(&2.0e-8, 1.0e-6));
+    /// ```
+    fn near_rel(&self, other: &Self, rel: f64) -> bool
+    where Self: ToleranceScale {
+        ToleranceSpec::new(TOLERANCE, rel).near(self, other)
+    }
 }
 
 impl<T: AbsDiffEq<Epsilon = f64> + Debug> Tolerance for T {}
 
+/// The scale of values for the relative tolerance: the maximum of the absolute values of the
+/// components, which are compared by [`Tolerance::near`].
+pub trait ToleranceScale {
+    /// Returns the maximum of the absolute values of the components.
+    fn tolerance_scale(&self) -> f64;
+}
+
+impl ToleranceScale for f64 {
+    #[inline(always)]
+    fn tolerance_scale(&self) -> f64 { self.abs() }
+}
+
+macro_rules! impl_tolerance_scale {
+    ($($vector: ident: $dim: literal),*; $($matrix: ident: $mdim: literal),*) => {
+        $(impl ToleranceScale for $vector {
+            #[inline(always)]
+            fn tolerance_scale(&self) -> f64 {
+                let array: &[f64; $dim] = self.as_ref();
+                array.iter().fold(0.0, |max, x| f64::max(max, x.abs()))
+            }
+        })*
+        $(impl ToleranceScale for $matrix {
+            #[inline(always)]
+            fn tolerance_scale(&self) -> f64 {
+                let array: &[[f64; $mdim]; $mdim] = self.as_ref();
+                array.iter().flatten().fold(0.0, |max, x| f64::max(max, x.abs()))
+            }
+        })*
+    };
+}
+impl_tolerance_scale!(
+    Vector1: 1, Vector2: 2, Vector3: 3, Vector4: 4, Point1: 1, Point2: 2, Point3: 3;
+    Matrix2: 2, Matrix3: 3, Matrix4: 4
+);
+
+/// The policy of the tolerance of the geometric comparisons: the values are near if their
+/// "distance" is less than `abs` or `rel` times their scale.
+///
+/// The default spec is the absolute `TOLERANCE`, which is the same as [`Tolerance::near`].
+/// # Examples
+/// ```
+/// use truck_base::{cgmath64::*, tolerance::*};
+/// let spec = ToleranceSpec::default();
+/// assert_eq!(spec.threshold(1.0e6), TOLERANCE);
+///
+/// let spec = ToleranceSpec::new(TOLERANCE, 1.0e-9);
+/// assert_eq!(spec.threshold(1.0), TOLERANCE);
+/// assert_eq!(spec.threshold(1.0e6), 1.0e-3);
+/// let p = Vector2::new(1.0e6, 0.0);
+/// assert!(spec.near(&p, &(p + Vector2::new(0.0, 1.0e-4))));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ToleranceSpec {
+    /// the absolute tolerance, which is the lower bound of the threshold
+    pub abs: f64,
+    /// the ratio of the threshold to the scale of the values
+    pub rel: f64,
+}
+
+impl Default for ToleranceSpec {
+    #[inline(always)]
+    fn default() -> Self { Self::new(TOLERANCE, 0.0) }
+}
+
+impl From<f64> for ToleranceSpec {
+    /// The absolute tolerance `abs`.
+    #[inline(always)]
+    fn from(abs: f64) -> Self { Self::new(abs, 0.0) }
+}
+
+impl ToleranceSpec {
+    /// Creates the spec from the absolute tolerance and the relative one.
+    #[inline(always)]
+    pub const fn new(abs: f64, rel: f64) -> Self { Self { abs, rel } }
+
+    /// Returns the threshold of the "distance" of the values whose scale is `scale`.
+    #[inline(always)]
+    pub fn threshold(&self, scale: f64) -> f64 { f64::max(self.abs, self.rel * scale) }
+
+    /// Returns whether the "distance" of `a` and `b` is less than the threshold of the larger
+    /// scale of them.
+    #[inline(always)]
+    pub fn near<T: Tolerance + ToleranceScale + ?Sized>(&self, a: &T, b: &T) -> bool {
+        let scale = f64::max(a.tolerance_scale(), b.tolerance_scale());
+        a.abs_diff_eq(b, self.threshold(scale))
+    }
+}
+
 /// Asserts that `left.near(&right)` (using `Tolerance`).
 #[macro_export]
 macro_rules! assert_near {
@@ -115,6 +234,54 @@ fn assert_near2_without_msg() { assert_near2!(1.0, 2.0) }
 #[should_panic]
 fn assert_near2_with_msg() { assert_near2!(1.0, 2.0, "{}", "test OK") }
 
+/// Asserts that `left.near_rel(&right, rel)` (using `Tolerance`).
+#[macro_export]
+macro_rules! assert_near_rel {
+    ($left: expr, $right: expr, $rel: expr $(,)?) => {{
+        let (left, right) = ($left, $right);
+        assert!(
+            $crate::tolerance::Tolerance::near_rel(&left, &right, $rel),
+            "assertion failed: `left` is near `right`\nleft: {left:?},\nright: {right:?}",
+        )
+    }};
+    ($left: expr, $right: expr, $rel: expr, $($arg: tt)+) => {{
+        let (left, right) = ($left, $right);
+        assert!(
+            $crate::tolerance::Tolerance::near_rel(&left, &right, $rel),
+            "assertion failed: `left` is near `right`\nleft: {left:?},\nright: {right:?}: {}",
+            format_args!($($arg)+),
+        )
+    }};
+}
+
+/// Similar to `assert_near_rel!`, but returns a test failure instead of panicking if the condition fails.
+#[macro_export]
+macro_rules! prop_assert_near_rel {
+    ($left: expr, $right: expr, $rel: expr $(,)?) => {{
+        let (left, right) = ($left, $right);
+        prop_assert!(
+            $crate::tolerance::Tolerance::near_rel(&left, &right, $rel),
+            "assertion failed: `left` is near `right`\nleft: {left:?},\nright: {right:?}",
+        )
+    }};
+    ($left: expr, $right: expr, $rel: expr, $($arg: tt)+) => {{
+        let (left, right) = ($left, $right);
+        prop_assert!(
+            $crate::tolerance::Tolerance::near_rel(&left, &right, $rel),
+            "assertion failed: `left` is near `right`\nleft: {left:?},\nright: {right:?}: {}",
+            format_args!($($arg)+),
+        )
+    }};
+}
+
+#[test]
+#[should_panic]
+fn assert_near_rel_without_msg() { assert_near_rel!(1.0e6, 1.1e6, 1.0e-6) }
+
+#[test]
+#[should_panic]
+fn assert_near_rel_with_msg() { assert_near_rel!(1.0e6, 1.1e6, 1.0e-6, "{}", "test OK") }
+
 /// The structs defined the origin. `f64`, `Vector`, and so on.
 pub trait Origin: Tolerance + Zero {
     /// near origin
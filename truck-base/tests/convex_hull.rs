@@ -0,0 +1,82 @@
+use proptest::prelude::*;
+use std::collections::HashSet;
+use truck_base::{assert_near, cgmath64::*, convex_hull::*, tolerance::*};
+
+fn volume(points: &[Point3], triangles: &[[usize; 3]]) -> f64 {
+    let det = |[a, b, c]: [usize; 3]| {
+        let [a, b, c] = [a, b, c].map(|i| points[i].to_vec());
+        a.dot(b.cross(c))
+    };
+    triangles.iter().map(|t| det(*t)).sum::<f64>() / 6.0
+}
+
+#[test]
+fn degenerate_2d() {
+    assert!(convex_hull_2d(&[]).is_empty());
+    let same = [Point2::new(1.0, 1.0); 3];
+    assert_eq!(convex_hull_2d(&same).len(), 1);
+    let collinear: Vec<Point2> = (0..5).map(|i| Point2::new(i as f64, 2.0 * i as f64)).collect();
+    let mut hull = convex_hull_2d(&collinear);
+    hull.sort();
+    assert_eq!(hull, vec![0, 4]);
+}
+
+#[test]
+fn coplanar_grid_3d() {
+    let points: Vec<Point3> = (0..27)
+        .map(|i| Point3::new((i % 3) as f64, (i / 3 % 3) as f64, (i / 9) as f64))
+        .collect();
+    let triangles = convex_hull_3d(&points);
+    assert_eq!(triangles.len(), 12);
+    let vertices: HashSet<usize> = triangles.iter().flatten().copied().collect();
+    assert_eq!(vertices, HashSet::from([0, 2, 6, 8, 18, 20, 24, 26]));
+    assert_near!(volume(&points, &triangles), 8.0);
+
+    let square: Vec<Point3> = points.iter().filter(|p| p.z == 0.0).copied().collect();
+    assert!(convex_hull_3d(&square).is_empty());
+}
+
+proptest! {
+    #[test]
+    fn hull_2d_contains_points(
+        coords in prop::collection::vec(prop::array::uniform2(-10.0f64..10.0), 1..50),
+    ) {
+        let points: Vec<Point2> = coords.iter().map(|[x, y]| Point2::new(*x, *y)).collect();
+        let hull = convex_hull_2d(&points);
+        prop_assert!(!hull.is_empty());
+        if hull.len() >= 3 {
+            let closed = hull.iter().zip(hull.iter().cycle().skip(1));
+            for (a, b) in closed {
+                let (a, b) = (points[*a], points[*b]);
+                for p in &points {
+                    let distance = (b - a).perp_dot(p - a) / (b - a).magnitude();
+                    prop_assert!(distance > -TOLERANCE, "{distance}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hull_3d_contains_points(
+        coords in prop::collection::vec(prop::array::uniform3(-10.0f64..10.0), 4..60),
+    ) {
+        let points: Vec<Point3> = coords.iter().map(|[x, y, z]| Point3::new(*x, *y, *z)).collect();
+        let triangles = convex_hull_3d(&points);
+        prop_assert!(triangles.len() >= 4);
+        // closed and consistently oriented
+        let edges: HashSet<(usize, usize)> = triangles
+            .iter()
+            .flat_map(|[a, b, c]| [(*a, *b), (*b, *c), (*c, *a)])
+            .collect();
+        prop_assert_eq!(edges.len(), triangles.len() * 3);
+        prop_assert!(edges.iter().all(|(a, b)| edges.contains(&(*b, *a))));
+        for [a, b, c] in &triangles {
+            let [a, b, c] = [a, b, c].map(|i| points[*i]);
+            let normal = (b - a).cross(c - a).normalize();
+            for p in &points {
+                prop_assert!(normal.dot(p - a) < 1.0e-6 * 20.0, "{}", normal.dot(p - a));
+            }
+        }
+        prop_assert!(volume(&points, &triangles) > 0.0);
+    }
+}
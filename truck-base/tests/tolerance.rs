@@ -0,0 +1,69 @@
+use truck_base::{assert_near_rel, cgmath64::*, tolerance::*};
+
+/// the points of the model of the size `scale`, and the points moved by `scale * 1.0e-10`
+fn perturbed_points(scale: f64) -> Vec<(Point3, Point3)> {
+    let delta = Vector3::new(1.0, -1.0, 0.5) * scale * 1.0e-10;
+    (0..10)
+        .map(|i| {
+            let t = i as f64;
+            let p = Point3::new(f64::cos(t), f64::sin(t), t / 10.0) * scale;
+            (p, p + delta)
+        })
+        .collect()
+}
+
+/// the points of the model of the size `scale`, and the points moved by `scale * 0.1`
+fn distinct_points(scale: f64) -> Vec<(Point3, Point3)> {
+    let delta = Vector3::new(1.0, 0.0, 0.0) * scale * 0.1;
+    perturbed_points(scale).into_iter().map(|(p, _)| (p, p + delta)).collect()
+}
+
+#[test]
+fn micro_model() {
+    let spec = ToleranceSpec::new(0.0, 1.0e-6);
+    // the absolute tolerance merges the distinct features
+    assert!(distinct_points(1.0e-6).iter().all(|(p, q)| p.near(q)));
+    assert!(distinct_points(1.0e-6).iter().all(|(p, q)| !spec.near(p, q)));
+    assert!(perturbed_points(1.0e-6).iter().all(|(p, q)| spec.near(p, q)));
+    // `near_rel` is floored by `TOLERANCE`
+    assert!(distinct_points(1.0e-6).iter().all(|(p, q)| p.near_rel(q, 1.0e-6)));
+}
+
+#[test]
+fn unit_model() {
+    let spec = ToleranceSpec::default();
+    assert_eq!(spec, ToleranceSpec::new(TOLERANCE, 0.0));
+    assert_eq!(spec, ToleranceSpec::from(TOLERANCE));
+    // the default spec is the same as the absolute tolerance
+    let values = (-20..=20).map(|i| 1.0 + i as f64 * TOLERANCE / 10.0);
+    values.for_each(|x| {
+        assert_eq!(spec.near(&1.0, &x), 1.0_f64.near(&x), "{x}");
+        assert_eq!(1.0_f64.near_rel(&x, 1.0e-12), 1.0_f64.near(&x), "{x}");
+    });
+    let pairs = perturbed_points(1.0).into_iter().chain(distinct_points(1.0));
+    pairs.for_each(|(p, q)| {
+        assert_eq!(spec.near(&p, &q), p.near(&q));
+        assert_eq!(p.near_rel(&q, 1.0e-9), p.near(&q));
+    });
+}
+
+#[test]
+fn huge_model() {
+    // the absolute tolerance is below the rounding errors of the coordinates
+    assert!(perturbed_points(1.0e6).iter().all(|(p, q)| !p.near(q)));
+    assert!(perturbed_points(1.0e6).iter().all(|(p, q)| p.near_rel(q, 1.0e-9)));
+    assert!(distinct_points(1.0e6).iter().all(|(p, q)| !p.near_rel(q, 1.0e-9)));
+    perturbed_points(1.0e6).into_iter().for_each(|(p, q)| assert_near_rel!(p, q, 1.0e-9));
+}
+
+#[test]
+fn tolerance_scale() {
+    assert_eq!((-3.0_f64).tolerance_scale(), 3.0);
+    assert_eq!(Vector3::new(1.0, -4.0, 2.0).tolerance_scale(), 4.0);
+    assert_eq!(Point2::new(-5.0, 2.0).tolerance_scale(), 5.0);
+    assert_eq!(Matrix3::from_diagonal(Vector3::new(1.0, 2.0, -6.0)).tolerance_scale(), 6.0);
+    // the relative tolerance of matrices
+    let mat = Matrix4::from_scale(1.0e6);
+    assert!(mat.near_rel(&(mat * (1.0 + 1.0e-10)), 1.0e-9));
+    assert!(!mat.near(&(mat * (1.0 + 1.0e-10))));
+}
@@ -418,6 +418,15 @@ pub fn derive_parameter_division_1d(input: TokenStream) -> TokenStream {
                 variants, trait_name,
                 fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>),
             };
+            // the iterators of the variants are boxed, since their types are different.
+            let sample_iter_arms = variants.iter().map(|variant| {
+                let variant_name = &variant.ident;
+                let boxed = quote! { Box::new(#trait_name::sample_iter(got, range, tol)) };
+                match variant.fields.iter().next().and_then(|field| field.ident.as_ref()) {
+                    Some(ident) => quote! { Self::#variant_name { #ident: got } => #boxed },
+                    None => quote! { Self::#variant_name(got) => #boxed },
+                }
+            });
             quote! {
                 #[automatically_derived]
                 impl #gen #trait_name for #ty #gen
@@ -427,6 +436,12 @@ pub fn derive_parameter_division_1d(input: TokenStream) -> TokenStream {
                     #(#tys: #trait_name<Point = <#top_ty as #trait_name>::Point>,)* {
                     type Point = <#top_ty as #trait_name>::Point;
                     #(#methods)*
+                    fn sample_iter(&self, range: (f64, f64), tol: f64) -> impl Iterator<Item = (f64, Self::Point)> {
+                        let iter: Box<dyn Iterator<Item = (f64, Self::Point)> + '_> = match self {
+                            #(#sample_iter_arms,)*
+                        };
+                        iter
+                    }
                 }
             }
         }
@@ -446,6 +461,9 @@ pub fn derive_parameter_division_1d(input: TokenStream) -> TokenStream {
                     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>) {
                         self.0.parameter_division(range, tol)
                     }
+                    fn sample_iter(&self, range: (f64, f64), tol: f64) -> impl Iterator<Item = (f64, Self::Point)> {
+                        self.0.sample_iter(range, tol)
+                    }
                 }
             }
         }
@@ -696,6 +714,11 @@ pub fn derive_parametric_surface3d(input: TokenStream) -> TokenStream {
                 variants,
                 trait_name1,
                 fn normal(&self, u: f64, v: f64) -> Vector3,
+                fn degenerate_boundaries(
+                    &self,
+                    ranges: ((f64, f64), (f64, f64)),
+                    tol: f64,
+                ) -> Vec<truck_geotrait::DegenerateEdge>,
             );
             quote! {
                 #[automatically_derived]
@@ -753,6 +776,13 @@ pub fn derive_parametric_surface3d(input: TokenStream) -> TokenStream {
                     #(#where_predicates,)*
                     #field_type: #trait_name0, {
                     fn normal(&self, u: f64, v: f64) -> Vector3 { self.0.normal(u, v) }
+                    fn degenerate_boundaries(
+                        &self,
+                        ranges: ((f64, f64), (f64, f64)),
+                        tol: f64,
+                    ) -> Vec<truck_geotrait::DegenerateEdge> {
+                        self.0.degenerate_boundaries(ranges, tol)
+                    }
                 }
             }
         }
@@ -784,6 +814,13 @@ pub fn derive_snp_d1(input: TokenStream) -> TokenStream {
                     hint: H,
                     trials: usize,
                 ) -> Option<f64>,
+                fn search_nearest_parameter_with_presearch<H: Into<SPHint1D>>(
+                    &self,
+                    pt: Self::Point,
+                    hint: H,
+                    trials: usize,
+                    division: usize,
+                ) -> Option<f64>,
             );
             quote! {
                 #[automatically_derived]
@@ -817,6 +854,15 @@ pub fn derive_snp_d1(input: TokenStream) -> TokenStream {
                     ) -> Option<f64> {
                         self.0.search_nearest_parameter(pt, hint, trials)
                     }
+                    fn search_nearest_parameter_with_presearch<H: Into<SPHint1D>>(
+                        &self,
+                        pt: Self::Point,
+                        hint: H,
+                        trials: usize,
+                        division: usize,
+                    ) -> Option<f64> {
+                        self.0.search_nearest_parameter_with_presearch(pt, hint, trials, division)
+                    }
                 }
             }
         }
@@ -848,6 +894,13 @@ pub fn derive_snp_d2(input: TokenStream) -> TokenStream {
                     hint: H,
                     trials: usize,
                 ) -> Option<(f64, f64)>,
+                fn search_nearest_parameter_with_presearch<H: Into<SPHint2D>>(
+                    &self,
+                    pt: Self::Point,
+                    hint: H,
+                    trials: usize,
+                    division: usize,
+                ) -> Option<(f64, f64)>,
             );
             quote! {
                 #[automatically_derived]
@@ -881,6 +934,15 @@ pub fn derive_snp_d2(input: TokenStream) -> TokenStream {
                     ) -> Option<(f64, f64)> {
                         self.0.search_nearest_parameter(pt, hint, trials)
                     }
+                    fn search_nearest_parameter_with_presearch<H: Into<SPHint2D>>(
+                        &self,
+                        pt: Self::Point,
+                        hint: H,
+                        trials: usize,
+                        division: usize,
+                    ) -> Option<(f64, f64)> {
+                        self.0.search_nearest_parameter_with_presearch(pt, hint, trials, division)
+                    }
                 }
             }
         }
@@ -913,6 +975,13 @@ pub fn derive_sp_d1(input: TokenStream) -> TokenStream {
                     hint: H,
                     trials: usize,
                 ) -> Option<f64>,
+                fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+                    &self,
+                    pt: Self::Point,
+                    hint: H,
+                    trials: usize,
+                    division: usize,
+                ) -> Option<f64>,
             );
             quote! {
                 #[automatically_derived]
@@ -947,6 +1016,15 @@ pub fn derive_sp_d1(input: TokenStream) -> TokenStream {
                     ) -> Option<f64> {
                         self.0.search_parameter(pt, hint, trials)
                     }
+                    fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+                        &self,
+                        pt: Self::Point,
+                        hint: H,
+                        trials: usize,
+                        division: usize,
+                    ) -> Option<f64> {
+                        self.0.search_parameter_with_presearch(pt, hint, trials, division)
+                    }
                 }
             }
         }
@@ -978,6 +1056,13 @@ pub fn derive_sp_d2(input: TokenStream) -> TokenStream {
                     hint: H,
                     trials: usize,
                 ) -> Option<(f64, f64)>,
+                fn search_parameter_with_presearch<H: Into<SPHint2D>>(
+                    &self,
+                    pt: Self::Point,
+                    hint: H,
+                    trials: usize,
+                    division: usize,
+                ) -> Option<(f64, f64)>,
             );
             quote! {
                 #[automatically_derived]
@@ -1011,6 +1096,15 @@ pub fn derive_sp_d2(input: TokenStream) -> TokenStream {
                     ) -> Option<(f64, f64)> {
                         self.0.search_parameter(pt, hint, trials)
                     }
+                    fn search_parameter_with_presearch<H: Into<SPHint2D>>(
+                        &self,
+                        pt: Self::Point,
+                        hint: H,
+                        trials: usize,
+                        division: usize,
+                    ) -> Option<(f64, f64)> {
+                        self.0.search_parameter_with_presearch(pt, hint, trials, division)
+                    }
                 }
             }
         }
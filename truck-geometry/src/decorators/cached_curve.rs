@@ -0,0 +1,290 @@
+use super::*;
+use serde::{Deserializer, Serializer};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Mutex, PoisonError};
+
+/// the granularity of the quantized parameters, well below `TOLERANCE`
+const QUANTUM: f64 = TOLERANCE * 1.0e-6;
+/// the maximum number of the cached points and derivatives, respectively
+const EVALUATION_CAPACITY: usize = 1 << 12;
+/// the maximum number of the cached divisions
+const DIVISION_CAPACITY: usize = 16;
+
+/// capped map evicting the least recently used entries
+#[derive(Debug)]
+struct Cache<K, V> {
+    map: HashMap<K, (V, u64)>,
+    capacity: usize,
+}
+
+impl<K, V> Cache<K, V> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            map: HashMap::new(),
+            capacity,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Cache<K, V> {
+    fn get(&mut self, key: &K, tick: u64) -> Option<V> {
+        let (value, last_use) = self.map.get_mut(key)?;
+        *last_use = tick;
+        Some(value.clone())
+    }
+
+    /// Inserts `value`. If the cache is full, evicts the entries not used in the last
+    /// `capacity / 2` ticks, which are at least the half of the entries.
+    fn insert(&mut self, key: K, value: V, tick: u64) {
+        if self.map.len() >= self.capacity {
+            let threshold = tick.saturating_sub(self.capacity as u64 / 2);
+            self.map.retain(|_, (_, last_use)| *last_use > threshold);
+        }
+        self.map.insert(key, (value, tick));
+    }
+}
+
+/// the memo of [`CachedCurve`]
+#[derive(Debug)]
+pub(super) struct CurveMemo<P, V> {
+    points: Cache<i64, P>,
+    ders: Cache<(usize, i64), V>,
+    divisions: Cache<[u64; 3], (Vec<f64>, Vec<P>)>,
+    tick: u64,
+}
+
+impl<P, V> Default for CurveMemo<P, V> {
+    fn default() -> Self {
+        Self {
+            points: Cache::new(EVALUATION_CAPACITY),
+            ders: Cache::new(EVALUATION_CAPACITY),
+            divisions: Cache::new(DIVISION_CAPACITY),
+            tick: 0,
+        }
+    }
+}
+
+/// the accessor of a cache in [`CurveMemo`]
+type CacheAccessor<P, V, K, T> = fn(&mut CurveMemo<P, V>) -> &mut Cache<K, T>;
+
+/// Returns the quantized parameter, or `None` if `t` is not finite or too large to quantize.
+fn quantize(t: f64) -> Option<i64> {
+    let q = (t / QUANTUM).round();
+    match q.abs() < (i64::MAX / 2) as f64 {
+        true => Some(q as i64),
+        false => None,
+    }
+}
+
+impl<C: ParametricCurve> CachedCurve<C> {
+    /// constructor
+    #[inline(always)]
+    pub fn new(curve: C) -> Self {
+        Self {
+            curve,
+            memo: Mutex::new(CurveMemo::default()),
+        }
+    }
+    /// Returns the reference of the cached curve
+    #[inline(always)]
+    pub const fn curve(&self) -> &C { &self.curve }
+    /// Returns the mutable reference of the cached curve. The cache is cleared.
+    #[inline(always)]
+    pub fn curve_mut(&mut self) -> &mut C {
+        self.clear_cache();
+        &mut self.curve
+    }
+    /// Returns the cached curve
+    #[inline(always)]
+    pub fn into_curve(self) -> C { self.curve }
+    /// Clears the cache
+    #[inline(always)]
+    pub fn clear_cache(&mut self) {
+        *self.memo.get_mut().unwrap_or_else(PoisonError::into_inner) = CurveMemo::default();
+    }
+    /// Returns the number of the cached points and derivatives
+    pub fn cache_len(&self) -> usize {
+        let memo = self.memo.lock().unwrap_or_else(PoisonError::into_inner);
+        memo.points.map.len() + memo.ders.map.len()
+    }
+
+    /// Returns the cached value, or caches the value evaluated by `eval`.
+    /// The lock is released while evaluating, so that the other threads are not blocked.
+    fn cached<K: Hash + Eq, T: Clone>(
+        &self,
+        cache: CacheAccessor<C::Point, C::Vector, K, T>,
+        key: K,
+        eval: impl FnOnce() -> T,
+    ) -> T {
+        let tick = {
+            let mut memo = self.memo.lock().unwrap_or_else(PoisonError::into_inner);
+            memo.tick += 1;
+            let tick = memo.tick;
+            if let Some(value) = cache(&mut *memo).get(&key, tick) {
+                return value;
+            }
+            tick
+        };
+        let value = eval();
+        let mut memo = self.memo.lock().unwrap_or_else(PoisonError::into_inner);
+        cache(&mut *memo).insert(key, value.clone(), tick);
+        value
+    }
+}
+
+impl<C: ParametricCurve + Clone> Clone for CachedCurve<C> {
+    /// The clone has an empty cache.
+    #[inline(always)]
+    fn clone(&self) -> Self { Self::new(self.curve.clone()) }
+}
+
+impl<C: ParametricCurve + Debug> Debug for CachedCurve<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachedCurve")
+            .field("curve", &self.curve)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: ParametricCurve + PartialEq> PartialEq for CachedCurve<C> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool { self.curve == other.curve }
+}
+
+impl<C: ParametricCurve + Serialize> Serialize for CachedCurve<C> {
+    #[inline(always)]
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.curve.serialize(serializer)
+    }
+}
+
+impl<'de, C: ParametricCurve + Deserialize<'de>> Deserialize<'de> for CachedCurve<C> {
+    #[inline(always)]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        C::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl<C: ParametricCurve> From<C> for CachedCurve<C> {
+    #[inline(always)]
+    fn from(curve: C) -> Self { Self::new(curve) }
+}
+
+impl<C: ParametricCurve> ParametricCurve for CachedCurve<C>
+where C::Point: Clone
+{
+    type Point = C::Point;
+    type Vector = C::Vector;
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector {
+        match quantize(t) {
+            Some(q) => self.cached(|memo| &mut memo.ders, (n, q), || self.curve.der_n(n, t)),
+            None => self.curve.der_n(n, t),
+        }
+    }
+    fn subs(&self, t: f64) -> Self::Point {
+        match quantize(t) {
+            Some(q) => self.cached(|memo| &mut memo.points, q, || self.curve.subs(t)),
+            None => self.curve.subs(t),
+        }
+    }
+    fn der(&self, t: f64) -> Self::Vector {
+        match quantize(t) {
+            Some(q) => self.cached(|memo| &mut memo.ders, (1, q), || self.curve.der(t)),
+            None => self.curve.der(t),
+        }
+    }
+    fn der2(&self, t: f64) -> Self::Vector {
+        match quantize(t) {
+            Some(q) => self.cached(|memo| &mut memo.ders, (2, q), || self.curve.der2(t)),
+            None => self.curve.der2(t),
+        }
+    }
+    #[inline(always)]
+    fn period(&self) -> Option<f64> { self.curve.period() }
+    #[inline(always)]
+    fn parameter_range(&self) -> ParameterRange { self.curve.parameter_range() }
+}
+
+impl<C: BoundedCurve> BoundedCurve for CachedCurve<C> where C::Point: Clone {}
+
+impl<C> ParameterDivision1D for CachedCurve<C>
+where
+    C: ParametricCurve + ParameterDivision1D<Point = <C as ParametricCurve>::Point>,
+    <C as ParametricCurve>::Point: Clone,
+{
+    type Point = <C as ParametricCurve>::Point;
+    fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>) {
+        let key = [range.0.to_bits(), range.1.to_bits(), tol.to_bits()];
+        self.cached(|memo| &mut memo.divisions, key, || {
+            self.curve.parameter_division(range, tol)
+        })
+    }
+}
+
+impl<C: ParametricCurve + SearchNearestParameter<D1>> SearchNearestParameter<D1>
+    for CachedCurve<C>
+{
+    type Point = <C as SearchNearestParameter<D1>>::Point;
+    #[inline(always)]
+    fn search_nearest_parameter<H: Into<SPHint1D>>(
+        &self,
+        pt: Self::Point,
+        hint: H,
+        trials: usize,
+    ) -> Option<f64> {
+        self.curve.search_nearest_parameter(pt, hint, trials)
+    }
+    #[inline(always)]
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        pt: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<f64> {
+        self.curve.search_nearest_parameter_with_presearch(pt, hint, trials, division)
+    }
+}
+
+impl<C: ParametricCurve + SearchParameter<D1>> SearchParameter<D1> for CachedCurve<C> {
+    type Point = <C as SearchParameter<D1>>::Point;
+    #[inline(always)]
+    fn search_parameter<H: Into<SPHint1D>>(
+        &self,
+        pt: Self::Point,
+        hint: H,
+        trials: usize,
+    ) -> Option<f64> {
+        self.curve.search_parameter(pt, hint, trials)
+    }
+    #[inline(always)]
+    fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        pt: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<f64> {
+        self.curve.search_parameter_with_presearch(pt, hint, trials, division)
+    }
+}
+
+impl<C: Cut> Cut for CachedCurve<C>
+where C::Point: Clone
+{
+    fn cut(&mut self, t: f64) -> Self {
+        let curve = self.curve_mut().cut(t);
+        Self::new(curve)
+    }
+}
+
+impl<C: ParametricCurve + Invertible> Invertible for CachedCurve<C> {
+    #[inline(always)]
+    fn invert(&mut self) { self.curve_mut().invert() }
+}
+
+impl<C: ParametricCurve + Transformed<T>, T> Transformed<T> for CachedCurve<C> {
+    #[inline(always)]
+    fn transform_by(&mut self, trans: T) { self.curve_mut().transform_by(trans) }
+}
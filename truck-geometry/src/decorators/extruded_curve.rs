@@ -1,10 +1,20 @@
 use super::*;
 use algo::surface::SspVector;
 
+/// The maximum number of the division of `v` of the extrusions created by
+/// [`ExtrudedCurve::by_extrusion_scaled`].
+const MAX_V_DIVISION: usize = 1024;
+
 impl<C, V: Copy> ExtrudedCurve<C, V> {
-    /// Creates a linear extruded curve by extrusion.
+    /// Creates a linear extruded curve by extrusion. The range of `v` is `[0, 1]`.
     #[inline(always)]
-    pub const fn by_extrusion(curve: C, vector: V) -> Self { Self { curve, vector } }
+    pub const fn by_extrusion(curve: C, vector: V) -> Self {
+        Self {
+            curve,
+            vector,
+            length: None,
+        }
+    }
 
     /// Returns the curve before extruded.
     #[inline(always)]
@@ -13,9 +23,41 @@ impl<C, V: Copy> ExtrudedCurve<C, V> {
     #[inline(always)]
     pub fn into_entity_curve(self) -> C { self.curve }
 
+    /// Returns the length of the extrusion if `v` is the arc length along the extrusion, i.e.
+    /// `self` is created by [`ExtrudedCurve::by_extrusion_scaled`].
+    #[inline(always)]
+    pub const fn length(&self) -> Option<f64> { self.length }
+}
+
+impl<C, V: InnerSpace<Scalar = f64>> ExtrudedCurve<C, V> {
+    /// Creates a linear extruded curve by extrusion, whose `v` is the arc length along the
+    /// extrusion. The range of `v` is `[0, vector.magnitude()]`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let line = Line(Point3::origin(), Point3::new(1.0, 0.0, 0.0));
+    /// let extruded = ExtrudedCurve::by_extrusion_scaled(line, Vector3::new(0.0, 0.0, 1000.0));
+    /// assert_eq!(extruded.range_tuple(), ((0.0, 1.0), (0.0, 1000.0)));
+    /// assert_near!(extruded.subs(0.5, 250.0), Point3::new(0.5, 0.0, 250.0));
+    /// assert_near!(extruded.extruding_vector(), Vector3::new(0.0, 0.0, 1000.0));
+    /// ```
+    /// # Remarks
+    /// `vector` must not be zero.
+    #[inline(always)]
+    pub fn by_extrusion_scaled(curve: C, vector: V) -> Self {
+        let length = vector.magnitude();
+        Self {
+            curve,
+            vector: vector / length,
+            length: Some(length),
+        }
+    }
+}
+
+impl<C, V: VectorSpace<Scalar = f64>> ExtrudedCurve<C, V> {
     /// Returns the vector of extruded curve.
     #[inline(always)]
-    pub const fn extruding_vector(&self) -> V { self.vector }
+    pub fn extruding_vector(&self) -> V { self.vector * self.length.unwrap_or(1.0) }
 }
 
 impl<C> ParametricSurface for ExtrudedCurve<C, C::Vector>
@@ -51,7 +93,7 @@ where
     fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
         (
             self.curve.parameter_range(),
-            (Bound::Included(0.0), Bound::Included(1.0)),
+            (Bound::Included(0.0), Bound::Included(self.length.unwrap_or(1.0))),
         )
     }
     #[inline(always)]
@@ -72,17 +114,32 @@ where
 {
 }
 
-impl<C: ParameterDivision1D, V> ParameterDivision2D for ExtrudedCurve<C, V> {
-    #[inline(always)]
+impl<C, V> ParameterDivision2D for ExtrudedCurve<C, V>
+where
+    C: ParameterDivision1D,
+    C::Point: MetricSpace<Metric = f64> + Copy,
+{
     fn parameter_division(
         &self,
         (urange, vrange): ((f64, f64), (f64, f64)),
         tol: f64,
     ) -> (Vec<f64>, Vec<f64>) {
-        (
-            self.curve.parameter_division(urange, tol).0,
-            vec![vrange.0, vrange.1],
-        )
+        let (udiv, pts) = self.curve.parameter_division(urange, tol);
+        let vdiv = match self.length {
+            // `v` is the arc length: the cells are as long as the longest chord of the curve.
+            Some(_) => {
+                let chord = pts
+                    .windows(2)
+                    .fold(tol, |chord, pair| f64::max(chord, pair[0].distance(pair[1])));
+                let n = f64::ceil((vrange.1 - vrange.0) / chord) as usize;
+                let n = n.clamp(1, MAX_V_DIVISION);
+                (0..=n)
+                    .map(|i| vrange.0 + (vrange.1 - vrange.0) * i as f64 / n as f64)
+                    .collect()
+            }
+            None => vec![vrange.0, vrange.1],
+        };
+        (udiv, vdiv)
     }
 }
 
@@ -100,15 +157,16 @@ where
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: P,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = algo::surface::presearch_hint(self, point, hint.into(), division);
         algo::surface::search_parameter(self, point, hint, trials)
     }
 }
@@ -122,19 +180,21 @@ impl<C: ParametricCurve3D + BoundedCurve> SearchNearestParameter<D2> for Extrude
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_nearest_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = algo::surface::presearch_hint(self, point, hint.into(), division);
         algo::surface::search_nearest_parameter(self, point, hint, trials)
     }
 }
 
+/// The inverse inverts the curve, i.e. reverses the parameter `u` on the range of the curve.
 impl<C: Invertible> Invertible for ExtrudedCurve<C, Vector3> {
     #[inline(always)]
     fn invert(&mut self) { self.curve.invert() }
@@ -142,21 +202,44 @@ impl<C: Invertible> Invertible for ExtrudedCurve<C, Vector3> {
     fn inverse(&self) -> Self {
         Self {
             curve: self.curve.inverse(),
-            vector: self.vector,
+            ..*self
         }
     }
 }
 
+impl<C: Invertible + BoundedCurve> InvertibleSurface for ExtrudedCurve<C, Vector3> {
+    #[inline(always)]
+    fn inverse_map(&self) -> ParamMap2D { ParamMap2D::reverse_u(self.curve.range_tuple()) }
+}
+
 impl<C: Transformed<Matrix4>> Transformed<Matrix4> for ExtrudedCurve<C, Vector3> {
     fn transform_by(&mut self, trans: Matrix4) {
         self.curve.transform_by(trans);
-        self.vector = trans.transform_vector(self.vector);
+        (self.vector, self.length) = transform_extrusion(self.vector, self.length, trans);
     }
     fn transformed(&self, trans: Matrix4) -> Self {
+        let (vector, length) = transform_extrusion(self.vector, self.length, trans);
         Self {
             curve: self.curve.transformed(trans),
-            vector: trans.transform_vector(self.vector),
+            vector,
+            length,
+        }
+    }
+}
+
+/// Transforms the extrusion, keeping `v` the arc length in the scaled form.
+fn transform_extrusion(
+    vector: Vector3,
+    length: Option<f64>,
+    trans: Matrix4,
+) -> (Vector3, Option<f64>) {
+    let vector = trans.transform_vector(vector);
+    match length {
+        Some(length) => {
+            let scale = vector.magnitude();
+            (vector / scale, Some(length * scale))
         }
+        None => (vector, None),
     }
 }
 
@@ -165,8 +248,10 @@ impl From<ExtrudedCurve<Line<Point3>, Vector3>> for Plane {
         ExtrudedCurve {
             curve: Line(o, p),
             vector,
+            ..
         }: ExtrudedCurve<Line<Point3>, Vector3>,
     ) -> Self {
+        // `vector` is the derivation by `v` in both forms
         Self::new(o, p, o + vector)
     }
 }
@@ -127,15 +127,16 @@ where
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_nearest_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = algo::surface::presearch_hint(self, point, hint.into(), division);
         algo::surface::search_nearest_parameter(self, point, hint, trials)
     }
 }
@@ -155,15 +156,16 @@ where
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = algo::surface::presearch_hint(self, point, hint.into(), division);
         algo::surface::search_parameter(self, point, hint, trials)
     }
 }
@@ -289,6 +289,13 @@ where
     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Point3>) {
         algo::curve::parameter_division(self, range, tol)
     }
+    fn sample_iter(
+        &self,
+        range: (f64, f64),
+        tol: f64,
+    ) -> impl Iterator<Item = (f64, Self::Point)> {
+        algo::curve::sample_iter(self, range, tol)
+    }
 }
 
 impl<C, S0, S1> Cut for IntersectionCurve<C, S0, S1>
@@ -323,10 +330,19 @@ where
         point: Point3,
         hint: H,
         trials: usize,
+    ) -> Option<f64> {
+        self.search_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+        division: usize,
     ) -> Option<f64> {
         let t = self
             .leader()
-            .search_nearest_parameter(point, hint, trials)?;
+            .search_nearest_parameter_with_presearch(point, hint, trials, division)?;
         let pt = self.subs(t);
         match pt.near(&point) {
             true => Some(t),
@@ -347,10 +363,19 @@ where
         point: Point3,
         hint: H,
         trials: usize,
+    ) -> Option<f64> {
+        self.search_nearest_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+        division: usize,
     ) -> Option<f64> {
         let (near_point, _, _) = self.search_nearest_point(point, None, None, trials)?;
         self.leader()
-            .search_nearest_parameter(near_point, hint, trials)
+            .search_nearest_parameter_with_presearch(near_point, hint, trials, division)
     }
 }
 
@@ -92,6 +92,9 @@ pub struct RevolutedCurve<C> {
 pub struct ExtrudedCurve<C, V> {
     curve: C,
     vector: V,
+    /// the end of the range of `v` if `vector` is the unit direction, cf. `by_extrusion_scaled`.
+    #[serde(default)]
+    length: Option<f64>,
 }
 
 /// invertible and transformable geometric element
@@ -278,6 +281,87 @@ pub struct TrimmedCurve<C> {
     range: (f64, f64),
 }
 
+/// Strictly increasing piecewise cubic map of parameters, i.e. the Hermite interpolation of the
+/// values and the derivatives at the breakpoints.
+///
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// use truck_geometry::errors::Error;
+/// let map = ParameterMap::try_new(vec![0.0, 0.5, 1.0], vec![0.0, 0.2, 1.0]).unwrap();
+/// assert_eq!(map.range_tuple(), (0.0, 1.0));
+/// assert_near!(map.subs(0.5), 0.2);
+/// assert_near!(map.inverse(0.2), 0.5);
+/// assert!((1..100).all(|i| map.der(i as f64 / 100.0) > 0.0));
+///
+/// // the derivatives at the breakpoints are validated
+/// let res = ParameterMap::try_from_hermite(
+///     vec![0.0, 1.0, 2.0],
+///     vec![0.0, 1.0, 2.0],
+///     vec![1.0, 1.0, 10.0],
+/// );
+/// assert_eq!(res, Err(Error::NonMonotoneParameterMap(1.0, 2.0)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ParameterMap {
+    params: Vec<f64>,
+    values: Vec<f64>,
+    ders: Vec<f64>,
+}
+
+/// curve reparametrized by a strictly increasing map of parameters, i.e. `curve(map(s))`
+///
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// let line = Line(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0));
+/// let map = ParameterMap::try_new(vec![0.0, 1.0, 2.0], vec![0.0, 0.5, 1.0]).unwrap();
+/// let curve = ReparametrizedCurve::new(line, map);
+///
+/// assert_eq!(curve.range_tuple(), (0.0, 2.0));
+/// assert_near!(curve.subs(1.0), Point2::new(0.5, 0.0));
+/// assert_near!(curve.back(), Point2::new(1.0, 0.0));
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SelfSameGeometry)]
+pub struct ReparametrizedCurve<C> {
+    curve: C,
+    map: ParameterMap,
+}
+
+/// curve memoizing the evaluations and the parameter divisions of an expensive curve
+///
+/// The points and the derivatives are cached by the parameters quantized by
+/// `TOLERANCE * 1.0e-6`, so the cached results are the same as the fresh ones within the accuracy
+/// of the parameters. The results of [`ParameterDivision1D::parameter_division`] are cached by the
+/// range and the tolerance. The caches are capped, and the least recently used entries are
+/// evicted. The cache is behind a mutex, and an evaluation while another thread uses the cache
+/// is not cached rather than waiting for the lock.
+///
+/// The cloned curve and the deserialized curve have empty caches. The mutation of the curve,
+/// e.g. [`Invertible::invert`], clears the cache.
+///
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// let bsp = BSplineCurve::new(
+///     KnotVec::bezier_knot(2),
+///     vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0), Point2::new(2.0, 0.0)],
+/// );
+/// let cached = CachedCurve::new(bsp.clone());
+/// for _ in 0..2 {
+///     for i in 0..=10 {
+///         let t = i as f64 / 10.0;
+///         assert_eq!(cached.subs(t), bsp.subs(t));
+///         assert_eq!(cached.der(t), bsp.der(t));
+///     }
+/// }
+/// assert_eq!(cached.cache_len(), 22);
+/// ```
+pub struct CachedCurve<C: ParametricCurve> {
+    curve: C,
+    memo: std::sync::Mutex<cached_curve::CurveMemo<C::Point, C::Vector>>,
+}
+
 /// homotopy surface connecting two curves.
 ///
 /// # Examples
@@ -346,6 +430,7 @@ pub struct ApproxFilletSurface<S0, S1> {
 }
 
 mod af_surface;
+mod cached_curve;
 mod extruded_curve;
 mod homotopy;
 mod intersection_curve;
@@ -353,5 +438,6 @@ mod pcurve;
 mod processor;
 /// structure and trait, associated with rolling ball fillet surface
 pub mod rbf_surface;
+mod reparametrized_curve;
 mod revolved_curve;
 mod trimmied_curve;
@@ -135,6 +135,15 @@ where
         point: Self::Point,
         hint: H,
         trials: usize,
+    ) -> Option<f64> {
+        self.search_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
     ) -> Option<f64> {
         let hint = hint.into();
         let shint = match hint {
@@ -144,10 +153,10 @@ where
             }
             SPHint1D::Range(x, y) => {
                 let p = self.curve.subs(y);
-                let ranges = (0..PRESEARCH_DIVISION).fold(
+                let ranges = (0..division).fold(
                     ((p.x, p.x), (p.y, p.y)),
                     |((x0, x1), (y0, y1)), i| {
-                        let t = x + (y - x) * i as f64 / PRESEARCH_DIVISION as f64;
+                        let t = x + (y - x) * i as f64 / division as f64;
                         let p = self.curve.subs(t);
                         (
                             (f64::min(x0, p.x), f64::max(x1, p.x)),
@@ -159,8 +168,8 @@ where
             }
             SPHint1D::None => SPHint2D::None,
         };
-        let (x, y) = self.surface.search_parameter(point, shint, trials)?;
-        self.curve.search_parameter(Point2::new(x, y), hint, trials)
+        let (x, y) = self.surface.search_parameter_with_presearch(point, shint, trials, division)?;
+        self.curve.search_parameter_with_presearch(Point2::new(x, y), hint, trials, division)
     }
 }
 
@@ -178,15 +187,16 @@ where
         hint: H,
         trials: usize,
     ) -> Option<f64> {
-        let hint = match hint.into() {
-            SPHint1D::Parameter(hint) => hint,
-            SPHint1D::Range(x, y) => {
-                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
-            }
-            SPHint1D::None => {
-                algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_nearest_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<f64> {
+        let hint = algo::curve::presearch_hint(self, point, hint.into(), division);
         algo::curve::search_nearest_parameter(self, point, hint, trials)
     }
 }
@@ -205,6 +215,13 @@ where
     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<S::Point>) {
         algo::curve::parameter_division(self, range, tol)
     }
+    fn sample_iter(
+        &self,
+        range: (f64, f64),
+        tol: f64,
+    ) -> impl Iterator<Item = (f64, Self::Point)> {
+        algo::curve::sample_iter(self, range, tol)
+    }
 }
 
 impl<C, S> Invertible for PCurve<C, S>
@@ -75,6 +75,8 @@ impl<E, T: One> Processor<E, T> {
     }
 }
 
+/// The inverse flips the orientation. The inverted curves are parametrized reversely on the same
+/// range, and the parameters `u` and `v` of the inverted surfaces are swapped.
 impl<E: Clone, T: Clone> Invertible for Processor<E, T> {
     #[inline(always)]
     fn invert(&mut self) { self.orientation = !self.orientation; }
@@ -88,6 +90,13 @@ impl<E: Clone, T: Clone> Invertible for Processor<E, T> {
     }
 }
 
+/// The analytic surfaces without orientations, e.g. [`Sphere`] and [`Torus`], are inverted through
+/// the processor.
+impl<E: Clone, T: Clone> InvertibleSurface for Processor<E, T> {
+    #[inline(always)]
+    fn inverse_map(&self) -> ParamMap2D { ParamMap2D::SWAP }
+}
+
 impl<C: BoundedCurve, T> Processor<C, T> {
     #[inline(always)]
     fn get_curve_parameter(&self, t: f64) -> f64 {
@@ -458,11 +467,29 @@ where
         point: <E as SearchParameter<D1>>::Point,
         hint: H,
         trials: usize,
+    ) -> Option<f64> {
+        SearchParameter::<D1>::search_parameter_with_presearch(
+            self,
+            point,
+            hint,
+            trials,
+            PRESEARCH_DIVISION,
+        )
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: <E as SearchParameter<D1>>::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
     ) -> Option<f64> {
         let inv = self.transform.inverse_transform().unwrap();
-        let t = self
-            .entity
-            .search_parameter(inv.transform_point(point), hint, trials)?;
+        let t = self.entity.search_parameter_with_presearch(
+            inv.transform_point(point),
+            hint,
+            trials,
+            division,
+        )?;
         Some(self.get_curve_parameter(t))
     }
 }
@@ -479,11 +506,29 @@ where
         point: E::Point,
         hint: H,
         trials: usize,
+    ) -> Option<(f64, f64)> {
+        SearchParameter::<D2>::search_parameter_with_presearch(
+            self,
+            point,
+            hint,
+            trials,
+            PRESEARCH_DIVISION,
+        )
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: E::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
     ) -> Option<(f64, f64)> {
         let inv = self.transform.inverse_transform().unwrap();
-        let (u, v) = self
-            .entity
-            .search_parameter(inv.transform_point(point), hint, trials)?;
+        let (u, v) = self.entity.search_parameter_with_presearch(
+            inv.transform_point(point),
+            hint,
+            trials,
+            division,
+        )?;
         match self.orientation {
             true => Some((u, v)),
             false => Some((v, u)),
@@ -504,11 +549,29 @@ where
         point: Self::Point,
         hint: H,
         trials: usize,
+    ) -> Option<f64> {
+        SearchNearestParameter::<D1>::search_nearest_parameter_with_presearch(
+            self,
+            point,
+            hint,
+            trials,
+            PRESEARCH_DIVISION,
+        )
+    }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
     ) -> Option<f64> {
         let inv = self.transform.inverse_transform().unwrap();
-        let hint =
-            self.entity
-                .search_nearest_parameter(inv.transform_point(point), hint, trials)?;
+        let hint = self.entity.search_nearest_parameter_with_presearch(
+            inv.transform_point(point),
+            hint,
+            trials,
+            division,
+        )?;
         let hint = self.get_curve_parameter(hint);
         algo::curve::search_nearest_parameter(self, point, hint, trials)
     }
@@ -527,11 +590,29 @@ where
         point: Self::Point,
         hint: H,
         trials: usize,
+    ) -> Option<(f64, f64)> {
+        SearchNearestParameter::<D2>::search_nearest_parameter_with_presearch(
+            self,
+            point,
+            hint,
+            trials,
+            PRESEARCH_DIVISION,
+        )
+    }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
     ) -> Option<(f64, f64)> {
         let inv = self.transform.inverse_transform().unwrap();
-        let hint =
-            self.entity
-                .search_nearest_parameter(inv.transform_point(point), hint, trials)?;
+        let hint = self.entity.search_nearest_parameter_with_presearch(
+            inv.transform_point(point),
+            hint,
+            trials,
+            division,
+        )?;
         let hint = match self.orientation {
             true => hint,
             false => (hint.1, hint.0),
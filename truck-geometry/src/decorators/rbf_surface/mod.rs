@@ -233,6 +233,15 @@ where
         point: Self::Point,
         hint: H,
         trials: usize,
+    ) -> Option<(f64, f64)> {
+        self.search_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
     ) -> Option<(f64, f64)> {
         let curve_hint = match hint.into() {
             SPHint2D::Parameter(_, v) => SPHint1D::Parameter(v),
@@ -240,7 +249,8 @@ where
             SPHint2D::None => SPHint1D::None,
         };
         let edge_curve = &self.edge_curve;
-        let v = edge_curve.search_nearest_parameter(point, curve_hint, trials)?;
+        let v = edge_curve
+            .search_nearest_parameter_with_presearch(point, curve_hint, trials, division)?;
         let cc = self.contact_circle(v)?;
 
         let cp0 = cc.contact_point0.point - cc.center;
@@ -319,6 +329,13 @@ where
     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>) {
         truck_geotrait::algo::curve::parameter_division(self, range, tol)
     }
+    fn sample_iter(
+        &self,
+        range: (f64, f64),
+        tol: f64,
+    ) -> impl Iterator<Item = (f64, Self::Point)> {
+        truck_geotrait::algo::curve::sample_iter(self, range, tol)
+    }
 }
 
 impl<C, S0, S1, R> SearchParameter<D1> for RbfContactCurve<C, S0, S1, R>
@@ -334,9 +351,18 @@ where
         point: Self::Point,
         hint: H,
         trials: usize,
+    ) -> Option<f64> {
+        self.search_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
     ) -> Option<f64> {
         let edge_curve = &self.surface.edge_curve;
-        let t = edge_curve.search_nearest_parameter(point, hint, trials)?;
+        let t = edge_curve.search_nearest_parameter_with_presearch(point, hint, trials, division)?;
         let cc = self.surface.contact_circle(t)?;
         let q = match self.index {
             0 => cc.contact_point0.point,
@@ -0,0 +1,494 @@
+use super::*;
+use errors::Error;
+
+/// the number of the pieces of each span in the composition of maps
+const COMPOSITION_DIVISION: usize = 4;
+
+impl ParameterMap {
+    /// Creates the monotone piecewise cubic interpolation of `values` at `params`.
+    ///
+    /// The derivatives at the breakpoints are the weighted harmonic means of the slopes of the
+    /// adjacent spans (Fritsch-Butland), which keep the interpolation strictly increasing.
+    /// # Errors
+    /// - [`Error::DifferentLength`] if `params` and `values` have different lengths,
+    /// - [`Error::ZeroRange`] if there are less than two breakpoints, and
+    /// - [`Error::NonMonotoneParameterMap`] if `params` or `values` is not strictly increasing.
+    pub fn try_new(params: Vec<f64>, values: Vec<f64>) -> Result<Self> {
+        check_breakpoints(&params, &values)?;
+        let slopes: Vec<f64> = (1..params.len())
+            .map(|i| (values[i] - values[i - 1]) / (params[i] - params[i - 1]))
+            .collect();
+        let n = slopes.len();
+        let ders = (0..=n)
+            .map(|i| match i {
+                0 => slopes[0],
+                _ if i == n => slopes[n - 1],
+                _ => {
+                    let (h0, h1) = (params[i] - params[i - 1], params[i + 1] - params[i]);
+                    let (d0, d1) = (slopes[i - 1], slopes[i]);
+                    3.0 * (h0 + h1) / ((2.0 * h1 + h0) / d0 + (h1 + 2.0 * h0) / d1)
+                }
+            })
+            .collect();
+        Ok(Self {
+            params,
+            values,
+            ders,
+        })
+    }
+
+    /// Creates the Hermite interpolation of `values` and the derivatives `ders` at `params`.
+    /// # Errors
+    /// - [`Error::DifferentLength`] if the lengths of the vectors are different,
+    /// - [`Error::ZeroRange`] if there are less than two breakpoints, and
+    /// - [`Error::NonMonotoneParameterMap`] with the first span on which the map is not strictly
+    ///   increasing.
+    pub fn try_from_hermite(params: Vec<f64>, values: Vec<f64>, ders: Vec<f64>) -> Result<Self> {
+        if ders.len() != params.len() {
+            return Err(Error::DifferentLength);
+        }
+        check_breakpoints(&params, &values)?;
+        let map = Self {
+            params,
+            values,
+            ders,
+        };
+        match (0..map.params.len() - 1).find(|&i| !map.monotone_span(i)) {
+            Some(i) => Err(Error::NonMonotoneParameterMap(map.params[i], map.params[i + 1])),
+            None => Ok(map),
+        }
+    }
+
+    /// Returns the breakpoints of the map.
+    #[inline(always)]
+    pub fn params(&self) -> &[f64] { &self.params }
+    /// Returns the values at the breakpoints.
+    #[inline(always)]
+    pub fn values(&self) -> &[f64] { &self.values }
+    /// Returns the derivatives at the breakpoints.
+    #[inline(always)]
+    pub fn ders(&self) -> &[f64] { &self.ders }
+    /// Returns the domain of the map.
+    #[inline(always)]
+    pub fn range_tuple(&self) -> (f64, f64) { (self.params[0], *self.params.last().unwrap()) }
+    /// Returns the image of the map.
+    #[inline(always)]
+    pub fn image(&self) -> (f64, f64) { (self.values[0], *self.values.last().unwrap()) }
+
+    /// Returns the value at `s`. The parameters out of the domain are extrapolated by the
+    /// polynomials of the end spans.
+    #[inline(always)]
+    pub fn subs(&self, s: f64) -> f64 { self.der_n(0, s) }
+    /// Returns the derivative at `s`.
+    #[inline(always)]
+    pub fn der(&self, s: f64) -> f64 { self.der_n(1, s) }
+    /// Returns the 2nd-order derivative at `s`.
+    #[inline(always)]
+    pub fn der2(&self, s: f64) -> f64 { self.der_n(2, s) }
+    /// Returns the `n`th-order derivative at `s`.
+    pub fn der_n(&self, n: usize, s: f64) -> f64 {
+        let i = self.span(s);
+        let (s0, s1) = (self.params[i], self.params[i + 1]);
+        let (t0, t1) = (self.values[i], self.values[i + 1]);
+        let h = s1 - s0;
+        let (m0, m1) = (h * self.ders[i], h * self.ders[i + 1]);
+        let x = (s - s0) / h;
+        // the coefficients of the Hermite basis functions and their derivatives by x
+        let [h00, h10, h01, h11] = match n {
+            0 => [
+                2.0 * x * x * x - 3.0 * x * x + 1.0,
+                x * x * x - 2.0 * x * x + x,
+                -2.0 * x * x * x + 3.0 * x * x,
+                x * x * x - x * x,
+            ],
+            1 => [
+                6.0 * x * x - 6.0 * x,
+                3.0 * x * x - 4.0 * x + 1.0,
+                -6.0 * x * x + 6.0 * x,
+                3.0 * x * x - 2.0 * x,
+            ],
+            2 => [12.0 * x - 6.0, 6.0 * x - 4.0, -12.0 * x + 6.0, 6.0 * x - 2.0],
+            3 => [12.0, 6.0, -12.0, 6.0],
+            _ => return 0.0,
+        };
+        (h00 * t0 + h10 * m0 + h01 * t1 + h11 * m1) / h.powi(n as i32)
+    }
+
+    /// Returns the parameter `s` with `self.subs(s) == t`. The values out of the image are
+    /// extrapolated by the polynomials of the end spans.
+    pub fn inverse(&self, t: f64) -> f64 {
+        let n = self.values.len() - 1;
+        let i = usize::clamp(self.values.partition_point(|v| *v <= t), 1, n) - 1;
+        let (mut a, mut b) = (self.params[i], self.params[i + 1]);
+        let mut s = match (t - self.values[i]) / (self.values[i + 1] - self.values[i]) {
+            x if (0.0..=1.0).contains(&x) => a + x * (b - a),
+            x if x < 0.0 => {
+                a = f64::NEG_INFINITY;
+                self.params[i] + (t - self.values[i]) / self.ders[i]
+            }
+            _ => {
+                b = f64::INFINITY;
+                self.params[i + 1] + (t - self.values[i + 1]) / self.ders[i + 1]
+            }
+        };
+        // Newton's method safeguarded by bisection
+        for _ in 0..100 {
+            let f = self.subs(s) - t;
+            if f.so_small() {
+                break;
+            }
+            match f < 0.0 {
+                true => a = s,
+                false => b = s,
+            }
+            let next = s - f / self.der(s);
+            s = match a < next && next < b {
+                true => next,
+                false if a.is_finite() && b.is_finite() => (a + b) / 2.0,
+                false => next,
+            };
+        }
+        s
+    }
+
+    /// Returns the composite `self(inner(s))`.
+    ///
+    /// The composite is interpolated by the Hermite polynomials at the breakpoints of `inner`,
+    /// the preimages of the breakpoints of `self`, and the inner points dividing each span into
+    /// four, so the composite is exact at the breakpoints. The derivatives at the breakpoints are
+    /// limited so that the composite is strictly increasing.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let map0 = ParameterMap::try_new(vec![0.0, 0.5, 1.0], vec![0.0, 0.3, 1.0]).unwrap();
+    /// let map1 = ParameterMap::try_new(vec![0.0, 2.0, 4.0], vec![0.0, 0.6, 1.0]).unwrap();
+    /// let map = map0.compose(&map1);
+    /// assert_eq!(map.range_tuple(), (0.0, 4.0));
+    /// // exact at the breakpoints
+    /// for s in [0.0, 2.0, 4.0] {
+    ///     assert_near!(map.subs(s), map0.subs(map1.subs(s)));
+    /// }
+    /// for i in 0..=100 {
+    ///     let s = 4.0 * i as f64 / 100.0;
+    ///     assert!(f64::abs(map.subs(s) - map0.subs(map1.subs(s))) < 1.0e-3);
+    /// }
+    /// ```
+    /// # Panics
+    /// Panics if the image of `inner` is out of the domain of `self` more than `TOLERANCE`.
+    pub fn compose(&self, inner: &ParameterMap) -> ParameterMap {
+        let (s0, s1) = self.range_tuple();
+        let (t0, t1) = inner.image();
+        assert!(
+            s0 - TOLERANCE <= t0 && t1 <= s1 + TOLERANCE,
+            "The image {:?} is out of the domain {:?}.",
+            (t0, t1),
+            (s0, s1),
+        );
+        let mut breakpoints: Vec<f64> = inner.params.clone();
+        self.params
+            .iter()
+            .filter(|s| t0 < **s && **s < t1)
+            .for_each(|s| breakpoints.push(inner.inverse(*s)));
+        breakpoints.sort_by(f64::total_cmp);
+        breakpoints.dedup_by(|s, t| (*s).near(&*t));
+        let params: Vec<f64> = breakpoints
+            .windows(2)
+            .flat_map(|w| {
+                (0..COMPOSITION_DIVISION).map(move |j| {
+                    let x = j as f64 / COMPOSITION_DIVISION as f64;
+                    w[0] + (w[1] - w[0]) * x
+                })
+            })
+            .chain([*breakpoints.last().unwrap()])
+            .collect();
+        let values: Vec<f64> = params.iter().map(|s| self.subs(inner.subs(*s))).collect();
+        let ders: Vec<f64> = params
+            .iter()
+            .map(|s| self.der(inner.subs(*s)) * inner.der(*s))
+            .collect();
+        let mut map = Self {
+            params,
+            values,
+            ders,
+        };
+        map.limit_ders();
+        map
+    }
+
+    /// Divides the map at `s`, and returns the latter part.
+    fn cut(&mut self, s: f64) -> Self {
+        let (t, der) = (self.subs(s), self.der(s));
+        let i = self.params.partition_point(|p| *p <= s);
+        let mut params = vec![s];
+        let mut values = vec![t];
+        let mut ders = vec![der];
+        params.extend(self.params.drain(i..));
+        values.extend(self.values.drain(i..));
+        ders.extend(self.ders.drain(i..));
+        if !self.params.last().unwrap().near(&s) {
+            self.params.push(s);
+            self.values.push(t);
+            self.ders.push(der);
+        }
+        if params.len() > 1 && params[0].near(&params[1]) {
+            params.remove(1);
+            values.remove(1);
+            ders.remove(1);
+        }
+        Self {
+            params,
+            values,
+            ders,
+        }
+    }
+
+    /// Returns the map `t0 + t1 - self(s0 + s1 - s)`, where `(s0, s1)` is the domain.
+    fn mirrored(&self, (t0, t1): (f64, f64)) -> Self {
+        let (s0, s1) = self.range_tuple();
+        Self {
+            params: self.params.iter().rev().map(|s| s0 + s1 - s).collect(),
+            values: self.values.iter().rev().map(|t| t0 + t1 - t).collect(),
+            ders: self.ders.iter().rev().copied().collect(),
+        }
+    }
+
+    /// the index of the span including `s`, where the end spans include the outsides
+    fn span(&self, s: f64) -> usize {
+        let n = self.params.len() - 1;
+        usize::clamp(self.params.partition_point(|p| *p <= s), 1, n) - 1
+    }
+
+    /// Returns whether the derivative of the `i`th span is non-negative.
+    fn monotone_span(&self, i: usize) -> bool {
+        let h = self.params[i + 1] - self.params[i];
+        let (t0, t1) = (self.values[i], self.values[i + 1]);
+        let (m0, m1) = (h * self.ders[i], h * self.ders[i + 1]);
+        // the derivative by `x` is `a x^2 + b x + c` on `0 <= x <= 1`.
+        let a = 6.0 * (t0 - t1) + 3.0 * (m0 + m1);
+        let b = 6.0 * (t1 - t0) - 4.0 * m0 - 2.0 * m1;
+        let c = m0;
+        let vertex = match a > 0.0 && 0.0 < -b && -b < 2.0 * a {
+            true => c - b * b / (4.0 * a),
+            false => f64::INFINITY,
+        };
+        m0 >= 0.0 && m1 >= 0.0 && vertex >= 0.0
+    }
+
+    /// Limits the derivatives into the region of Fritsch-Carlson, in which the spans are
+    /// monotone.
+    fn limit_ders(&mut self) {
+        (0..self.params.len() - 1).for_each(|i| {
+            let h = self.params[i + 1] - self.params[i];
+            let delta = (self.values[i + 1] - self.values[i]) / h;
+            let (alpha, beta) = (self.ders[i] / delta, self.ders[i + 1] / delta);
+            let norm2 = alpha * alpha + beta * beta;
+            if norm2 > 9.0 {
+                let tau = 3.0 / f64::sqrt(norm2);
+                self.ders[i] = tau * alpha * delta;
+                self.ders[i + 1] = tau * beta * delta;
+            }
+        });
+    }
+}
+
+fn check_breakpoints(params: &[f64], values: &[f64]) -> Result<()> {
+    if params.len() != values.len() {
+        return Err(Error::DifferentLength);
+    }
+    if params.len() < 2 {
+        return Err(Error::ZeroRange);
+    }
+    let increasing = |i: &usize| params[*i] < params[i + 1] && values[*i] < values[i + 1];
+    match (0..params.len() - 1).find(|i| !increasing(i)) {
+        Some(i) => Err(Error::NonMonotoneParameterMap(params[i], params[i + 1])),
+        None => Ok(()),
+    }
+}
+
+impl<C> ReparametrizedCurve<C> {
+    /// constructor. The image of `map` is assumed to be in the parameter range of `curve`.
+    #[inline(always)]
+    pub const fn new(curve: C, map: ParameterMap) -> Self { Self { curve, map } }
+    /// Returns the reference of the curve before the reparametrization
+    #[inline(always)]
+    pub const fn curve(&self) -> &C { &self.curve }
+    /// Returns the mutable reference of the curve before the reparametrization
+    #[inline(always)]
+    pub fn curve_mut(&mut self) -> &mut C { &mut self.curve }
+    /// Returns the map of the parameters
+    #[inline(always)]
+    pub const fn map(&self) -> &ParameterMap { &self.map }
+
+    /// Returns the curve reparametrized by `map` again, i.e. `curve(self.map(map(s)))`, composing
+    /// the maps instead of nesting the curves. cf. [`ParameterMap::compose`]
+    /// # Panics
+    /// Panics if the image of `map` is out of the parameter range more than `TOLERANCE`.
+    pub fn compose(&self, map: &ParameterMap) -> Self
+    where C: Clone {
+        Self::new(self.curve.clone(), self.map.compose(map))
+    }
+
+    #[inline(always)]
+    fn mapped_hint<H: Into<SPHint1D>>(&self, hint: H) -> SPHint1D {
+        match hint.into() {
+            SPHint1D::Parameter(s) => SPHint1D::Parameter(self.map.subs(s)),
+            SPHint1D::Range(s0, s1) => SPHint1D::Range(self.map.subs(s0), self.map.subs(s1)),
+            SPHint1D::None => {
+                let (t0, t1) = self.map.image();
+                SPHint1D::Range(t0, t1)
+            }
+        }
+    }
+}
+
+/// The derivatives are computed by the chain rule, i.e. Faà di Bruno's formula.
+impl<C> ParametricCurve for ReparametrizedCurve<C>
+where
+    C: ParametricCurve,
+    C::Vector: VectorSpace<Scalar = f64>,
+{
+    type Point = C::Point;
+    type Vector = C::Vector;
+    #[inline(always)]
+    fn subs(&self, s: f64) -> C::Point { self.curve.subs(self.map.subs(s)) }
+    #[inline(always)]
+    fn der(&self, s: f64) -> C::Vector {
+        self.curve.der(self.map.subs(s)) * self.map.der(s)
+    }
+    #[inline(always)]
+    fn der2(&self, s: f64) -> C::Vector {
+        let t = self.map.subs(s);
+        let (der, der2) = (self.map.der(s), self.map.der2(s));
+        self.curve.der2(t) * (der * der) + self.curve.der(t) * der2
+    }
+    fn der_n(&self, n: usize, s: f64) -> C::Vector {
+        let t = self.map.subs(s);
+        if n == 0 {
+            return self.curve.der_n(0, t);
+        }
+        // bell[j][k] is the partial Bell polynomial B_{j, k} of the derivatives of the map.
+        let map_ders: Vec<f64> = (0..=n).map(|i| self.map.der_n(i, s)).collect();
+        let mut bell = vec![vec![0.0; n + 1]; n + 1];
+        bell[0][0] = 1.0;
+        (1..=n).for_each(|j| {
+            (1..=j).for_each(|k| {
+                let mut binom = 1.0;
+                bell[j][k] = (1..=j - k + 1).fold(0.0, |sum, i| {
+                    let term = binom * map_ders[i] * bell[j - i][k - 1];
+                    binom *= (j - i) as f64 / i as f64;
+                    sum + term
+                });
+            })
+        });
+        (1..=n).fold(C::Vector::zero(), |sum, k| {
+            sum + self.curve.der_n(k, t) * bell[n][k]
+        })
+    }
+    #[inline(always)]
+    fn parameter_range(&self) -> ParameterRange {
+        let (s0, s1) = self.map.range_tuple();
+        (Bound::Included(s0), Bound::Included(s1))
+    }
+}
+
+impl<C> BoundedCurve for ReparametrizedCurve<C>
+where
+    C: ParametricCurve,
+    C::Vector: VectorSpace<Scalar = f64>,
+{
+}
+
+impl<C> Cut for ReparametrizedCurve<C>
+where
+    C: ParametricCurve,
+    C::Vector: VectorSpace<Scalar = f64>,
+{
+    fn cut(&mut self, s: f64) -> Self {
+        let map = self.map.cut(s);
+        Self::new(self.curve.clone(), map)
+    }
+}
+
+/// The parameter of the inverse of the curve is assumed to be `t0 + t1 - t`, where `(t0, t1)` is
+/// the parameter range of the curve, as [`TrimmedCurve`] does.
+impl<C: Invertible + BoundedCurve> Invertible for ReparametrizedCurve<C> {
+    fn invert(&mut self) {
+        self.map = self.map.mirrored(self.curve.range_tuple());
+        self.curve.invert();
+    }
+}
+
+impl<C: Transformed<T>, T> Transformed<T> for ReparametrizedCurve<C> {
+    #[inline(always)]
+    fn transform_by(&mut self, trans: T) { self.curve.transform_by(trans) }
+}
+
+impl<C: SearchNearestParameter<D1>> SearchNearestParameter<D1> for ReparametrizedCurve<C> {
+    type Point = C::Point;
+    #[inline(always)]
+    fn search_nearest_parameter<H: Into<SPHint1D>>(
+        &self,
+        pt: C::Point,
+        hint: H,
+        trials: usize,
+    ) -> Option<f64> {
+        let hint = self.mapped_hint(hint);
+        let t = self.curve.search_nearest_parameter(pt, hint, trials)?;
+        Some(self.map.inverse(t))
+    }
+    #[inline(always)]
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        pt: C::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<f64> {
+        let hint = self.mapped_hint(hint);
+        let t = self.curve.search_nearest_parameter_with_presearch(pt, hint, trials, division)?;
+        Some(self.map.inverse(t))
+    }
+}
+
+impl<C: SearchParameter<D1>> SearchParameter<D1> for ReparametrizedCurve<C> {
+    type Point = C::Point;
+    #[inline(always)]
+    fn search_parameter<H: Into<SPHint1D>>(
+        &self,
+        pt: C::Point,
+        hint: H,
+        trials: usize,
+    ) -> Option<f64> {
+        let hint = self.mapped_hint(hint);
+        let t = self.curve.search_parameter(pt, hint, trials)?;
+        Some(self.map.inverse(t))
+    }
+    #[inline(always)]
+    fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        pt: C::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<f64> {
+        let hint = self.mapped_hint(hint);
+        let t = self.curve.search_parameter_with_presearch(pt, hint, trials, division)?;
+        Some(self.map.inverse(t))
+    }
+}
+
+impl<C: ParameterDivision1D> ParameterDivision1D for ReparametrizedCurve<C> {
+    type Point = C::Point;
+    fn parameter_division(&self, (s0, s1): (f64, f64), tol: f64) -> (Vec<f64>, Vec<C::Point>) {
+        let range = (self.map.subs(s0), self.map.subs(s1));
+        let (mut params, points) = self.curve.parameter_division(range, tol);
+        params.iter_mut().for_each(|t| *t = self.map.inverse(*t));
+        if let Some(t) = params.first_mut() {
+            *t = s0;
+        }
+        if let Some(t) = params.last_mut() {
+            *t = s1;
+        }
+        (params, points)
+    }
+}
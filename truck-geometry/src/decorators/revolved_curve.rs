@@ -20,6 +20,12 @@ impl Revolution {
     #[inline(always)]
     fn contains(self, p: Point3) -> bool { (p - self.origin).cross(self.axis).so_small() }
     #[inline(always)]
+    fn distance(self, p: Point3) -> f64 { (p - self.origin).cross(self.axis).magnitude() }
+    #[inline(always)]
+    fn foot(self, p: Point3) -> Point3 {
+        self.origin + self.axis * (p - self.origin).dot(self.axis)
+    }
+    #[inline(always)]
     fn proj_point(&self, p: Point3) -> Point2 {
         let r = p - self.origin;
         let z = r.dot(self.axis);
@@ -120,36 +126,70 @@ impl<C: ParametricCurve3D> ParametricSurface for RevolutedCurve<C> {
 }
 
 impl<C: ParametricCurve3D + BoundedCurve> ParametricSurface3D for RevolutedCurve<C> {
+    /// Returns the normal vector at `(u, v)`.
+    ///
+    /// At the poles, i.e. the points on the axis, `uder × vder` vanishes. Then, returns the limit
+    /// of the normals approaching along the meridian of `v` from the inside of the parameter
+    /// range: from above at the interior points and at the front, and from below at the back.
+    /// The limit is the axis for the generating curves perpendicular to the axis, e.g. spheres,
+    /// and is continuous for any path into the pole in that case.
     #[inline(always)]
     fn normal(&self, u: f64, v: f64) -> Vector3 {
-        let (u0, u1) = self.curve.range_tuple();
-        let (uder, vder) = if u.near(&u0) {
-            let pt = self.curve.subs(u);
-            let radius = self.axis().cross(pt - self.origin());
-            if radius.so_small() {
-                let uder = self.curve.der(u);
-                (uder, self.axis().cross(uder))
-            } else {
-                (self.uder(u, v), self.vder(u, v))
-            }
-        } else if u.near(&u1) {
-            let pt = self.curve.subs(u);
-            let radius = self.axis().cross(pt - self.origin());
-            if radius.so_small() {
-                let uder = self.curve.der(u);
-                (uder, uder.cross(self.axis()))
-            } else {
-                (self.uder(u, v), self.vder(u, v))
-            }
-        } else {
-            (self.uder(u, v), self.vder(u, v))
+        let regular = || self.uder(u, v).cross(self.vder(u, v)).normalize();
+        let pt = self.curve.subs(u);
+        if !self.axis().cross(pt - self.origin()).so_small() {
+            return regular();
+        }
+        // Near the pole, `c(s) - o` is approximated by `c^(k)(u) (s - u)^k / k!`. So, the normal
+        // tends to `sign(s - u) w × (a × w)`, where `w` is the rotated `c^(k)(u)`.
+        let der = match self.curve.der(u) {
+            der if der.so_small() => self.curve.der2(u),
+            der => der,
         };
-        uder.cross(vder).normalize()
+        let w = self.revolution.rotation_matrix(v) * der;
+        let normal = w.cross(self.axis().cross(w));
+        if normal.so_small() {
+            return regular();
+        }
+        let (_, u1) = self.curve.range_tuple();
+        match u.near(&u1) {
+            true => -normal.normalize(),
+            false => normal.normalize(),
+        }
+    }
+    /// Returns the boundaries of the parameter rectangle `ranges` collapsed into points.
+    ///
+    /// The boundaries `u = const` are the circles, which collapse if and only if the points of
+    /// the generating curve are within `tol` from the axis. The boundaries `v = const` are the
+    /// rotated generating curve, which collapse only if the generating curve does.
+    fn degenerate_boundaries(
+        &self,
+        ((u0, u1), _): ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> Vec<DegenerateEdge> {
+        const DIVISION: usize = 8;
+        let on_axis = |u: f64| self.revolution.distance(self.curve.subs(u)) <= tol;
+        let p = self.curve.subs(u0);
+        let collapsed = (1..=DIVISION).all(|i| {
+            let u = u0 + (u1 - u0) * i as f64 / DIVISION as f64;
+            self.curve.subs(u).distance2(p) <= tol * tol
+        });
+        [
+            (DegenerateEdge::UFront, on_axis(u0)),
+            (DegenerateEdge::UBack, on_axis(u1)),
+            (DegenerateEdge::VFront, collapsed),
+            (DegenerateEdge::VBack, collapsed),
+        ]
+        .into_iter()
+        .filter(|(_, degenerate)| *degenerate)
+        .map(|(edge, _)| edge)
+        .collect()
     }
 }
 
 impl<C: ParametricCurve3D + BoundedCurve> BoundedSurface for RevolutedCurve<C> {}
 
+/// The inverse reverses the axis, i.e. maps the angle `v` to `2π - v`.
 impl<C: Clone> Invertible for RevolutedCurve<C> {
     #[inline(always)]
     fn invert(&mut self) { self.revolution.invert() }
@@ -162,6 +202,11 @@ impl<C: Clone> Invertible for RevolutedCurve<C> {
     }
 }
 
+impl<C: Clone> InvertibleSurface for RevolutedCurve<C> {
+    #[inline(always)]
+    fn inverse_map(&self) -> ParamMap2D { ParamMap2D::reverse_v((0.0, 2.0 * PI)) }
+}
+
 #[derive(Clone, Debug)]
 struct ProjectedCurve<C> {
     curve: C,
@@ -208,15 +253,16 @@ impl<C: ParametricCurve3D + BoundedCurve> SearchParameter<D1> for ProjectedCurve
         hint: H,
         trials: usize,
     ) -> Option<f64> {
-        let hint = match hint.into() {
-            SPHint1D::Parameter(t) => t,
-            SPHint1D::Range(x, y) => {
-                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
-            }
-            SPHint1D::None => {
-                algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<f64> {
+        let hint = algo::curve::presearch_hint(self, point, hint.into(), division);
         algo::curve::search_parameter(self, point, hint, trials)
     }
 }
@@ -229,15 +275,16 @@ impl<C: ParametricCurve3D + BoundedCurve> SearchNearestParameter<D1> for Project
         hint: H,
         trials: usize,
     ) -> Option<f64> {
-        let hint = match hint.into() {
-            SPHint1D::Parameter(t) => t,
-            SPHint1D::Range(x, y) => {
-                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
-            }
-            SPHint1D::None => {
-                algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_nearest_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<f64> {
+        let hint = algo::curve::presearch_hint(self, point, hint.into(), division);
         algo::curve::search_nearest_parameter(self, point, hint, trials)
     }
 }
@@ -296,6 +343,43 @@ impl<C: ParametricCurve3D + BoundedCurve> RevolutedCurve<C> {
     /// ```
     #[inline(always)]
     pub fn is_back_fixed(&self) -> bool { self.revolution.contains(self.curve.back()) }
+    /// Returns the boundaries of the parameter rectangle which degenerate, with their images.
+    ///
+    /// The ends of the generating curve within `tol` from the axis are the poles, and collapse
+    /// into the feet on the axis.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let line = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 1.0)],
+    /// );
+    /// let surface = RevolutedCurve::by_revolution(line, Point3::origin(), Vector3::unit_y());
+    /// assert_eq!(
+    ///     surface.degenerate_edges(TOLERANCE),
+    ///     vec![DegenerateBoundary {
+    ///         edge: DegenerateEdge::UFront,
+    ///         collapse: Collapse::Point(Point3::origin()),
+    ///     }],
+    /// );
+    /// ```
+    pub fn degenerate_edges(&self, tol: f64) -> Vec<DegenerateBoundary<Point3>> {
+        let urange = self.curve.range_tuple();
+        self.degenerate_boundaries((urange, (0.0, 2.0 * PI)), tol)
+            .into_iter()
+            .map(|edge| {
+                let point = match edge {
+                    DegenerateEdge::UFront => self.revolution.foot(self.curve.front()),
+                    DegenerateEdge::UBack => self.revolution.foot(self.curve.back()),
+                    DegenerateEdge::VFront | DegenerateEdge::VBack => self.curve.front(),
+                };
+                DegenerateBoundary {
+                    edge,
+                    collapse: Collapse::Point(point),
+                }
+            })
+            .collect()
+    }
 }
 
 impl<C: ParametricCurve3D + BoundedCurve> SearchParameter<D2> for RevolutedCurve<C> {
@@ -305,6 +389,15 @@ impl<C: ParametricCurve3D + BoundedCurve> SearchParameter<D2> for RevolutedCurve
         point: Point3,
         hint: H,
         trials: usize,
+    ) -> Option<(f64, f64)> {
+        self.search_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+        division: usize,
     ) -> Option<(f64, f64)> {
         let (t0, t1) = self.curve.range_tuple();
         if self.is_front_fixed() && self.curve.front().near(&point) {
@@ -330,7 +423,7 @@ impl<C: ParametricCurve3D + BoundedCurve> SearchParameter<D2> for RevolutedCurve
                 SPHint2D::Range((x0, _), (x1, _)) => SPHint1D::Range(x0, x1),
                 SPHint2D::None => SPHint1D::None,
             };
-            let t = proj_curve.search_parameter(p, hint0, trials)?;
+            let t = proj_curve.search_parameter_with_presearch(p, hint0, trials, division)?;
             let p = self.curve.subs(t);
             let ang = self.revolution.proj_angle(p, point);
             match self.subs(t, ang).near(&point) {
@@ -348,6 +441,15 @@ impl<C: ParametricCurve3D + BoundedCurve> SearchNearestParameter<D2> for Revolut
         point: Point3,
         hint: H,
         trials: usize,
+    ) -> Option<(f64, f64)> {
+        self.search_nearest_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+        division: usize,
     ) -> Option<(f64, f64)> {
         let (t0, t1) = self.curve.range_tuple();
         let on_axis = move |o: Point3, normal: Vector3| {
@@ -377,7 +479,7 @@ impl<C: ParametricCurve3D + BoundedCurve> SearchNearestParameter<D2> for Revolut
                 SPHint2D::Range((x0, _), (x1, _)) => SPHint1D::Range(x0, x1),
                 SPHint2D::None => SPHint1D::None,
             };
-            let t = proj_curve.search_nearest_parameter(p, hint0, trials)?;
+            let t = proj_curve.search_nearest_parameter_with_presearch(p, hint0, trials, division)?;
             let p = self.curve.subs(t);
             Some((t, self.revolution.proj_angle(p, point)))
         }
@@ -481,6 +583,11 @@ impl IncludeCurve<NurbsCurve<Vector4>> for RevolutedCurve<NurbsCurve<Vector4>> {
     }
 }
 
+/// Divides the generating curve by its own division and the angular direction uniformly.
+///
+/// Each cell of the division is within `tol` from the surface: the generating curve is divided
+/// with `tol / 2`, and the circles are divided into the arcs whose sagittas are within `tol / 2`
+/// on the farthest division point from the axis. The number of the arcs is given in closed form.
 impl<C> ParameterDivision2D for RevolutedCurve<C>
 where C: ParametricCurve3D + ParameterDivision1D<Point = Point3>
 {
@@ -489,21 +596,18 @@ where C: ParametricCurve3D + ParameterDivision1D<Point = Point3>
         (urange, vrange): ((f64, f64), (f64, f64)),
         tol: f64,
     ) -> (Vec<f64>, Vec<f64>) {
-        let curve_division = self.curve.parameter_division(urange, tol);
-        let max = curve_division
-            .1
+        nonpositive_tolerance!(tol);
+        let (curve_division, points) = self.curve.parameter_division(urange, tol / 2.0);
+        let radius = points
             .into_iter()
-            .fold(0.0, |max2, pt| {
-                let h = self.revolution.proj_point(pt).y;
-                f64::max(max2, h)
-            })
-            .sqrt();
-        let acos = f64::acos(1.0 - tol / max);
-        let div: usize = 1 + ((vrange.1 - vrange.0) / acos).floor() as usize;
+            .fold(0.0, |max, pt| f64::max(max, self.revolution.distance(pt)));
+        // the central angle of the arc whose sagitta is `tol / 2`, at most `PI`
+        let delta = 2.0 * f64::acos(f64::max(1.0 - tol / (2.0 * radius), 0.0));
+        let div = usize::max(f64::ceil((vrange.1 - vrange.0) / delta) as usize, 1);
         let circle_division = (0..=div)
             .map(|j| vrange.0 + (vrange.1 - vrange.0) * j as f64 / div as f64)
             .collect();
-        (curve_division.0, circle_division)
+        (curve_division, circle_division)
     }
 }
 
@@ -10,6 +10,9 @@ impl<C> TrimmedCurve<C> {
     /// Returns the mutable reference of non-trimmed curve
     #[inline(always)]
     pub fn curve_mut(&mut self) -> &mut C { &mut self.curve }
+    /// Returns the trimming range of the parameter
+    #[inline(always)]
+    pub const fn range(&self) -> (f64, f64) { self.range }
 }
 
 impl<C: ParametricCurve> ParametricCurve for TrimmedCurve<C> {
@@ -41,6 +44,65 @@ impl<C: ParametricCurve> Cut for TrimmedCurve<C> {
     }
 }
 
+/// The inverse of the trimmed curve is the trimmed inverse.
+/// The parameter of the inverse of the non-trimmed curve is assumed to be `t0 + t1 - t`,
+/// where `(t0, t1)` is the parameter range of the non-trimmed curve.
+impl<C: Invertible + BoundedCurve> Invertible for TrimmedCurve<C> {
+    fn invert(&mut self) {
+        let (t0, t1) = self.curve.range_tuple();
+        self.curve.invert();
+        self.range = (t0 + t1 - self.range.1, t0 + t1 - self.range.0);
+    }
+}
+
+impl<C: Invertible + BoundedCurve> TrimmedCurve<C> {
+    /// Returns the restriction of the curve to `range`, sharing the non-trimmed curve.
+    ///
+    /// If `range` is decreasing, returns the restriction of the inverse, whose parameter is
+    /// mirrored as [`Invertible::invert`] of `TrimmedCurve` does.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let line = Line(Point2::new(0.0, 0.0), Point2::new(4.0, 0.0));
+    /// let trimmed = TrimmedCurve::new(line, (0.25, 1.0));
+    ///
+    /// let sub = trimmed.subcurve((0.5, 0.75));
+    /// assert_eq!(sub.range(), (0.5, 0.75));
+    /// assert_eq!(sub.subs(0.6), trimmed.subs(0.6));
+    ///
+    /// let sub = trimmed.subcurve((0.75, 0.5));
+    /// assert_eq!(sub.range(), (0.25, 0.5));
+    /// assert_near!(sub.front(), Point2::new(3.0, 0.0));
+    /// assert_near!(sub.back(), Point2::new(2.0, 0.0));
+    /// ```
+    /// # Panics
+    /// Panics if an end of `range` is out of the trimming range, or if `range` is degenerate.
+    pub fn subcurve(&self, range: (f64, f64)) -> Self {
+        let (t0, t1) = check_curve_subrange(self, range).unwrap_or_else(|e| panic!("{e}"));
+        assert!(!t0.near(&t1), "The range {range:?} is degenerate.");
+        let mut curve = Self::new(self.curve.clone(), (f64::min(t0, t1), f64::max(t0, t1)));
+        if t0 > t1 {
+            curve.invert();
+        }
+        curve
+    }
+}
+
+impl<C: Transformed<T>, T> Transformed<T> for TrimmedCurve<C> {
+    #[inline(always)]
+    fn transform_by(&mut self, trans: T) { self.curve.transform_by(trans) }
+}
+
+impl<C> TrimmedCurve<C> {
+    #[inline(always)]
+    fn bounded_hint<H: Into<SPHint1D>>(&self, hint: H) -> SPHint1D {
+        match hint.into() {
+            SPHint1D::None => SPHint1D::Range(self.range.0, self.range.1),
+            hint => hint,
+        }
+    }
+}
+
 impl<C: SearchNearestParameter<D1>> SearchNearestParameter<D1> for TrimmedCurve<C> {
     type Point = C::Point;
     #[inline(always)]
@@ -50,8 +112,20 @@ impl<C: SearchNearestParameter<D1>> SearchNearestParameter<D1> for TrimmedCurve<
         hint: H,
         trials: usize,
     ) -> Option<f64> {
+        let hint = self.bounded_hint(hint);
         self.curve.search_nearest_parameter(pt, hint, trials)
     }
+    #[inline(always)]
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        pt: C::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<f64> {
+        let hint = self.bounded_hint(hint);
+        self.curve.search_nearest_parameter_with_presearch(pt, hint, trials, division)
+    }
 }
 
 impl<C: SearchParameter<D1>> SearchParameter<D1> for TrimmedCurve<C> {
@@ -63,8 +137,20 @@ impl<C: SearchParameter<D1>> SearchParameter<D1> for TrimmedCurve<C> {
         hint: H,
         trials: usize,
     ) -> Option<f64> {
+        let hint = self.bounded_hint(hint);
         self.curve.search_parameter(pt, hint, trials)
     }
+    #[inline(always)]
+    fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        pt: C::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<f64> {
+        let hint = self.bounded_hint(hint);
+        self.curve.search_parameter_with_presearch(pt, hint, trials, division)
+    }
 }
 
 impl<C: ParameterDivision1D> ParameterDivision1D for TrimmedCurve<C> {
@@ -72,4 +158,11 @@ impl<C: ParameterDivision1D> ParameterDivision1D for TrimmedCurve<C> {
     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>) {
         self.curve.parameter_division(range, tol)
     }
+    fn sample_iter(
+        &self,
+        range: (f64, f64),
+        tol: f64,
+    ) -> impl Iterator<Item = (f64, Self::Point)> {
+        self.curve.sample_iter(range, tol)
+    }
 }
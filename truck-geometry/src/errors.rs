@@ -191,6 +191,26 @@ the number of control points: {1}"
     /// ```
     #[error("Gaussian elimination is failed.")]
     GaussianEliminationFailure,
+    /// The patch in the grid is not a Bezier patch compatible with the other patches.
+    /// cf. [`BSplineSurface::from_bezier_grid`](crate::nurbs::BSplineSurface::from_bezier_grid)
+    #[error("The ({0}, {1})th patch is not a Bezier patch compatible with the grid.")]
+    NotBezierPatch(usize, usize),
+    /// The patch in the grid does not share the boundary control points with the former patches.
+    /// cf. [`BSplineSurface::from_bezier_grid`](crate::nurbs::BSplineSurface::from_bezier_grid)
+    #[error("The ({0}, {1})th patch is not connected to the former patches.")]
+    DisconnectedBezierPatches(usize, usize),
+    /// The map of parameters is not strictly increasing on the span.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// assert_eq!(
+    ///     ParameterMap::try_new(vec![0.0, 1.0, 2.0], vec![0.0, 0.5, 0.5]),
+    ///     Err(Error::NonMonotoneParameterMap(1.0, 2.0)),
+    /// );
+    /// ```
+    #[error("The map of parameters is not strictly increasing on the span [{0}, {1}].")]
+    NonMonotoneParameterMap(f64, f64),
 }
 
 #[test]
@@ -208,5 +228,8 @@ fn print_messages() {
     writeln!(stderr, "{}\n", Error::EmptyControlPoints).unwrap();
     writeln!(stderr, "{}\n", Error::TooShortKnotVector(1, 2)).unwrap();
     writeln!(stderr, "{}\n", Error::IrregularControlPoints).unwrap();
+    writeln!(stderr, "{}\n", Error::NotBezierPatch(1, 2)).unwrap();
+    writeln!(stderr, "{}\n", Error::DisconnectedBezierPatches(1, 2)).unwrap();
+    writeln!(stderr, "{}\n", Error::NonMonotoneParameterMap(0.0, 1.0)).unwrap();
     writeln!(stderr, "*******************************************************").unwrap();
 }
@@ -23,8 +23,8 @@ const PRESEARCH_DIVISION: usize = 50;
 /// re-export `truck_base`
 pub mod base {
     pub use truck_base::{
-        assert_near, assert_near2, bounding_box::BoundingBox, cgmath64::*, hash, hash::HashGen,
-        prop_assert_near, prop_assert_near2, tolerance::*,
+        assert_near, assert_near2, assert_near_rel, bounding_box::BoundingBox, cgmath64::*, hash,
+        hash::HashGen, prop_assert_near, prop_assert_near2, prop_assert_near_rel, tolerance::*,
     };
     pub use truck_geotrait::*;
 }
@@ -40,6 +40,10 @@ pub mod specifieds;
 /// Declares some decorators
 pub mod decorators;
 
+/// Deterministic generators of random geometries for property-based tests.
+#[cfg(feature = "test-util")]
+pub mod test_util;
+
 /// re-export all modules.
 pub mod prelude {
     use crate::*;
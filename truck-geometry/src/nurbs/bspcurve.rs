@@ -138,6 +138,41 @@ impl<P> BSplineCurve<P> {
         self.knot_vec.translate(x);
         self
     }
+
+    /// Returns the reversed curve, with the same parameter range.
+    ///
+    /// The control points are reversed and the knot vector is mirrored about the midpoint of the
+    /// range, i.e. the knot `k` is mapped to `t0 + t1 - k` where `(t0, t1)` is the range. Hence,
+    /// `reversed.subs(t)` coincides with `self.subs(t0 + t1 - t)` up to the rounding errors of
+    /// the mirrored knots, even if the knot vector is not symmetric. This is the same curve as
+    /// [`Invertible::inverse`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![1.0, 1.0, 1.0, 1.5, 4.0, 4.0, 4.0]);
+    /// let ctrl_pts = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 2.0),
+    ///     Vector2::new(3.0, 1.0),
+    ///     Vector2::new(4.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let reversed = bspcurve.reversed();
+    /// assert_eq!(reversed.knot_vec().as_slice(), &[1.0, 1.0, 1.0, 3.5, 4.0, 4.0, 4.0]);
+    /// assert_eq!(reversed.control_point(0), &Vector2::new(4.0, 0.0));
+    /// assert_near2!(reversed.subs(1.2), bspcurve.subs(3.8));
+    /// ```
+    #[inline(always)]
+    pub fn reversed(&self) -> Self
+    where P: Clone {
+        let mut knot_vec = self.knot_vec.clone();
+        knot_vec.invert();
+        let control_points = self.control_points.iter().rev().cloned().collect();
+        BSplineCurve {
+            knot_vec,
+            control_points,
+        }
+    }
 }
 
 impl<P: ControlPoint<f64>> BSplineCurve<P> {
@@ -418,6 +453,54 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
             .all(move |vec| vec.near(&self.control_points[0]))
     }
 
+    /// Returns the restriction of the curve to `range`, keeping the parameter.
+    ///
+    /// The knots at the ends of `range` are inserted, so the subcurve is exactly the same curve.
+    /// If `range` is decreasing, returns the restriction of the inverse, i.e. the parameter `t`
+    /// of the result corresponds to `t0 + t1 - t` of `self`, where `(t0, t1)` is the parameter
+    /// range of `self`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 3.0, 3.0, 3.0]);
+    /// let ctrl_pts = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 2.0),
+    ///     Vector2::new(3.0, 1.0),
+    ///     Vector2::new(4.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// let sub = bspcurve.subcurve((0.5, 2.0));
+    /// assert_eq!(sub.range_tuple(), (0.5, 2.0));
+    /// assert_near2!(sub.subs(1.2), bspcurve.subs(1.2));
+    ///
+    /// // the decreasing range gives the reversed piece
+    /// let sub = bspcurve.subcurve((2.0, 0.5));
+    /// assert_eq!(sub.range_tuple(), (1.0, 2.5));
+    /// assert_near2!(sub.front(), bspcurve.subs(2.0));
+    /// assert_near2!(sub.subs(1.2), bspcurve.subs(1.8));
+    /// ```
+    /// # Panics
+    /// Panics if an end of `range` is out of the parameter range, cf. [`check_curve_subrange`],
+    /// or if `range` is degenerate.
+    pub fn subcurve(&self, range: (f64, f64)) -> Self {
+        let (t0, t1) = check_curve_subrange(self, range).unwrap_or_else(|e| panic!("{e}"));
+        assert!(!t0.near(&t1), "The range {range:?} is degenerate.");
+        let (s0, s1) = self.range_tuple();
+        if t0 > t1 {
+            return self.inverse().subcurve((s0 + s1 - t0, s0 + s1 - t1));
+        }
+        let mut curve = self.clone();
+        if t1 < s1 - TOLERANCE {
+            curve.cut(t1);
+        }
+        match t0 > s0 + TOLERANCE {
+            true => curve.cut(t0),
+            false => curve,
+        }
+    }
+
     /// Adds a knot `x`, and do not change `self` as a curve.  
     /// # Examples
     /// ```
@@ -480,6 +563,61 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         self
     }
 
+    /// Adds the knot `t` until the multiplicity of `t` reaches `multiplicity`, and do not change
+    /// `self` as a curve. The multiplicity is capped at `degree + 1`, and if `t` already has the
+    /// multiplicity, nothing is added. If `t` is near an existing knot, the knot is added.
+    ///
+    /// At the multiplicity `degree`, the curve passes through a control point at `t` and is only
+    /// C0 there in general, so moving the control point makes a corner. This is useful before
+    /// cutting the curve or introducing discontinuities.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 2.0, 2.0, 2.0]);
+    /// let ctrl_pts = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 2.0),
+    ///     Vector2::new(2.0, -1.0),
+    ///     Vector2::new(3.0, 1.0),
+    ///     Vector2::new(4.0, 0.0),
+    /// ];
+    /// let org_curve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// let mut bspcurve = org_curve.clone();
+    /// bspcurve.insert_knot_to(1.0, 3);
+    /// assert_eq!(bspcurve.knot_vec().multiplicity(4), 3);
+    /// assert!(bspcurve.near2_as_curve(&org_curve));
+    /// // the curve passes through the control point
+    /// assert_near2!(bspcurve.subs(1.0), *bspcurve.control_point(3));
+    ///
+    /// // the multiplicity is capped at `degree + 1`
+    /// bspcurve.insert_knot_to(1.0, 10);
+    /// assert_eq!(bspcurve.knot_vec().multiplicity(4), 4);
+    /// assert!(bspcurve.near2_as_curve(&org_curve));
+    ///
+    /// // the end knots of the clamped curve already have the multiplicity `degree + 1`
+    /// bspcurve.insert_knot_to(0.0, 4).insert_knot_to(2.0, 4);
+    /// assert_eq!(bspcurve.knot_vec().len(), org_curve.knot_vec().len() + 3);
+    /// ```
+    /// # Panics
+    /// Panics if `t` is out of the range of the knot vector.
+    pub fn insert_knot_to(&mut self, t: f64, multiplicity: usize) -> &mut Self {
+        let (t0, t1) = (self.knot_vec[0], self.knot_vec[self.knot_vec.len() - 1]);
+        assert!(
+            t0 - TOLERANCE <= t && t <= t1 + TOLERANCE,
+            "the knot {t} is out of the range of the knot vector ({t0}, {t1})."
+        );
+        let (t, current) = match self.knot_vec.iter().position(|u| u.near(&t)) {
+            Some(idx) => (self.knot_vec[idx], self.knot_vec.multiplicity(idx)),
+            None => (t, 0),
+        };
+        let multiplicity = usize::min(multiplicity, self.degree() + 1);
+        (current..multiplicity).for_each(|_| {
+            self.add_knot(t);
+        });
+        self
+    }
+
     /// Removes a knot corresponding to the indices `idx`, and do not change `self` as a curve.
     /// If cannot remove the knot, do not change `self` and return `self`.
     /// # Examples
@@ -552,6 +690,132 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         Ok(self)
     }
 
+    /// Removes a knot corresponding to the index `idx` unconditionally,
+    /// by the knot removal algorithm of Tiller.
+    ///
+    /// Unlike [`BSplineCurve::try_remove_knot`], the knot is removed even if it is not removable.
+    /// In that case, the geometry of the curve changes, and the control points are recomputed
+    /// from both ends of the affected span so that the error is split between them.
+    /// The change can be measured by [`BSplineCurve::knot_removal_deviation`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(3);
+    /// let ctrl_pts = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 2.0),
+    ///     Vector2::new(2.0, -1.0),
+    ///     Vector2::new(3.0, 0.0),
+    /// ];
+    /// let org_curve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// // removes the removable knot
+    /// let mut bspcurve = org_curve.clone();
+    /// bspcurve.add_knot(0.5);
+    /// let removed = bspcurve.remove_knot_once(4);
+    /// assert!(removed.near2_as_curve(&org_curve));
+    ///
+    /// // removes the knot that is not removable
+    /// let mut ctrl_pts = bspcurve.control_points().clone();
+    /// ctrl_pts[2] += Vector2::new(0.0, 1.0);
+    /// let bspcurve = BSplineCurve::new(bspcurve.knot_vec().clone(), ctrl_pts);
+    /// let removed = bspcurve.remove_knot_once(4);
+    /// assert_eq!(removed.knot_vec(), &KnotVec::bezier_knot(3));
+    /// assert!(!removed.near2_as_curve(&bspcurve));
+    /// ```
+    /// # Panics
+    /// Panics if the knot is an end knot, i.e. `idx <= degree` or `idx >= control_points.len()`,
+    /// or the multiplicity of the knot is more than the degree.
+    pub fn remove_knot_once(&self, idx: usize) -> BSplineCurve<P> {
+        let k = self.degree();
+        let n = self.control_points.len();
+        if idx < k + 1 || idx >= n {
+            panic!("the knot {idx} is an end knot and cannot be removed.");
+        }
+        let knot_vec = &self.knot_vec;
+        let ctrl = &self.control_points;
+        let u = knot_vec[idx];
+        // `r`: the index of the last knot equal to `u`, `s`: the multiplicity of `u`.
+        let r = (idx..knot_vec.len())
+            .take_while(|i| knot_vec[*i].near(&u))
+            .last()
+            .unwrap();
+        let s = knot_vec.multiplicity(idx);
+        if s > k || r - s + 1 >= n {
+            panic!("the knot {idx} cannot be removed since its multiplicity is too large.");
+        }
+        let (first, last) = (r - k, r - s);
+
+        // the new control points computed from the front and the back
+        let off = first - 1;
+        let mut temp = vec![P::origin(); last + 2 - off];
+        temp[0] = ctrl[off];
+        temp[last + 1 - off] = ctrl[last + 1];
+        let (mut i, mut j) = (first, last);
+        while i < j {
+            let alfi = (u - knot_vec[i]) * inv_or_zero(knot_vec[i + k + 1] - knot_vec[i]);
+            let alfj = (u - knot_vec[j]) * inv_or_zero(knot_vec[j + k + 1] - knot_vec[j]);
+            let (ii, jj) = (i - off, j - off);
+            let prev = temp[ii - 1].to_vec() * (1.0 - alfi);
+            temp[ii] = P::from_vec((ctrl[i].to_vec() - prev) * inv_or_zero(alfi));
+            let next = temp[jj + 1].to_vec() * alfj;
+            temp[jj] = P::from_vec((ctrl[j].to_vec() - next) * inv_or_zero(1.0 - alfj));
+            i += 1;
+            j -= 1;
+        }
+
+        // When two sequences meet, the point computed twice is averaged.
+        // Otherwise, the middle control point is removed.
+        let fout = (first + last) / 2;
+        let mut control_points = ctrl[..first].to_vec();
+        control_points.extend((first..last).map(|l| match l.cmp(&fout) {
+            std::cmp::Ordering::Less => temp[l - off],
+            std::cmp::Ordering::Equal if i > j => {
+                let (p, q) = (temp[l - off], temp[l + 1 - off]);
+                p + (q - p) / 2.0
+            }
+            _ => temp[l + 1 - off],
+        }));
+        control_points.extend_from_slice(&ctrl[last + 1..]);
+        let mut knot_vec = self.knot_vec.clone();
+        knot_vec.remove(idx);
+        BSplineCurve::new_unchecked(knot_vec, control_points)
+    }
+
+    /// Returns an upper bound of the distance between `self` and the curve given by
+    /// [`BSplineCurve::remove_knot_once`] with the same index.
+    ///
+    /// The bound is the maximum distance between the control points of `self` and the ones of the
+    /// removed curve refined by the removed knot. So, the knot is removable without changing the
+    /// curve if this returns zero up to the tolerance.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Vector2::new(-1.0, 1.0), Vector2::new(0.0, -1.0), Vector2::new(1.0, 1.0)];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// bspcurve.add_knot(0.5);
+    /// assert!(bspcurve.knot_removal_deviation(3).so_small());
+    ///
+    /// *bspcurve.control_point_mut(1) += Vector2::new(0.0, 0.1);
+    /// assert!(bspcurve.knot_removal_deviation(3) > 0.01);
+    /// ```
+    /// # Panics
+    /// Panics in the same cases as [`BSplineCurve::remove_knot_once`].
+    pub fn knot_removal_deviation(&self, idx: usize) -> f64 {
+        let mut removed = self.remove_knot_once(idx);
+        removed.add_knot(self.knot_vec[idx]);
+        self.control_points
+            .iter()
+            .zip(&removed.control_points)
+            .map(|(p, q)| {
+                let diff = *p - *q;
+                (0..P::DIM).map(|i| diff[i] * diff[i]).sum::<f64>()
+            })
+            .fold(0.0, f64::max)
+            .sqrt()
+    }
+
     /// elevate 1 degree for bezier curve.
     fn elevate_degree_bezier(&mut self) -> &mut Self {
         let k = self.degree();
@@ -772,6 +1036,56 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         result
     }
 
+    /// Separates `self` into Bezier curves by inserting each knot up to the degree at once.
+    ///
+    /// Each segment keeps its own parameter range, and the adjacent segments share the end
+    /// control points exactly.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.4, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(2.0, -1.0),
+    ///     Point2::new(3.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let segments = bspcurve.bezier_segments();
+    /// assert_eq!(segments.len(), 2);
+    /// assert_eq!(segments[0].knot_vec().as_slice(), &[0.0, 0.0, 0.0, 0.4, 0.4, 0.4]);
+    /// assert_eq!(segments[0].control_points()[2], segments[1].control_points()[0]);
+    ///
+    /// const N: usize = 100;
+    /// for i in 0..=N {
+    ///     let t = 0.4 * (i as f64) / (N as f64);
+    ///     assert_near!(bspcurve.subs(t), segments[0].subs(t));
+    ///     let t = 0.4 + 0.6 * (i as f64) / (N as f64);
+    ///     assert_near!(bspcurve.subs(t), segments[1].subs(t));
+    /// }
+    /// ```
+    pub fn bezier_segments(&self) -> Vec<BSplineCurve<P>> {
+        let degree = self.degree();
+        let mut bspline = self.clone();
+        for (knot, n) in self.knot_vec.bezier_insertions(degree) {
+            (0..n).for_each(|_| {
+                bspline.add_knot(knot);
+            });
+        }
+        bspline
+            .knot_vec
+            .span_indices()
+            .into_iter()
+            .map(|k| {
+                let (front, back) = (bspline.knot(k), bspline.knot(k + 1));
+                let knot_vec = KnotVec::bezier_knot_between(front, back, degree);
+                let control_points = bspline.control_points[k - degree..=k].to_vec();
+                BSplineCurve::new_unchecked(knot_vec, control_points)
+            })
+            .collect()
+    }
+
     /// Makes the curve locally injective.
     /// # Example
     /// ```
@@ -894,6 +1208,34 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     pub fn near2_as_curve(&self, other: &BSplineCurve<P>) -> bool {
         self.sub_near_as_curve(other, 1, |x, y| x.near2(y))
     }
+
+    /// Determines whether `self` and `other` is near as the B-spline curves by `spec`.
+    ///
+    /// The points are compared at the same parameters as [`BSplineCurve::near_as_curve`], by the
+    /// threshold of the scale of the curves, which is the maximum of the scales of the control
+    /// points. The default spec is the same as [`BSplineCurve::near_as_curve`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0e6, 2.0e6),
+    ///     Point2::new(3.0e6, 0.0),
+    /// ];
+    /// let bspcurve0 = BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts);
+    /// let mut bspcurve1 = bspcurve0.clone();
+    /// *bspcurve1.control_point_mut(1) += Vector2::new(1.0e-4, 0.0);
+    /// // below the rounding errors of the coordinates
+    /// assert!(!bspcurve0.near_as_curve(&bspcurve1));
+    /// assert!(bspcurve0.near_as_curve_with(&bspcurve1, ToleranceSpec::new(TOLERANCE, 1.0e-9)));
+    /// ```
+    pub fn near_as_curve_with(&self, other: &BSplineCurve<P>, spec: ToleranceSpec) -> bool
+    where P: ToleranceScale {
+        let ctrl_pts = self.control_points.iter().chain(&other.control_points);
+        let scale = ctrl_pts.fold(0.0, |max, p| f64::max(max, p.tolerance_scale()));
+        let tol = spec.threshold(scale);
+        self.sub_near_as_curve(other, 1, |x, y| x.abs_diff_eq(y, tol))
+    }
 }
 
 impl<P: ControlPoint<f64>> ParameterTransform for BSplineCurve<P> {
@@ -996,6 +1338,13 @@ where P: ControlPoint<f64>
     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<P>) {
         algo::curve::parameter_division(self, range, tol)
     }
+    fn sample_iter(
+        &self,
+        range: (f64, f64),
+        tol: f64,
+    ) -> impl Iterator<Item = (f64, Self::Point)> {
+        algo::curve::sample_iter(self, range, tol)
+    }
 }
 
 impl<P> BSplineCurve<P>
@@ -1062,6 +1411,259 @@ where
         Some(hint)
     }
 }
+impl BSplineCurve<Point3> {
+    /// Returns the parameters and the points where the curve crosses or touches `plane`, in
+    /// ascending order of the parameters.
+    ///
+    /// The roots of the signed distance to the plane are searched on each knot span, and refined by
+    /// Newton's method. A point where the curve touches the plane within `tol` without crossing it
+    /// is reported once. If the whole curve lies in the plane within `tol`, there are infinitely
+    /// many intersections, and the empty vector is returned by convention. Use
+    /// [`BSplineCurve::lies_in_plane`] to distinguish it from the curve apart from the plane.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // parabola y = x^2 - 1 / 4 on -1 <= x <= 1
+    /// let curve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![
+    ///         Point3::new(-1.0, 0.75, 0.0),
+    ///         Point3::new(0.0, -1.25, 0.0),
+    ///         Point3::new(1.0, 0.75, 0.0),
+    ///     ],
+    /// );
+    /// let plane = Plane::zx();
+    /// let res = curve.intersect_plane(&plane, 1.0e-6);
+    /// assert_eq!(res.len(), 2);
+    /// assert_near!(res[0].0, 0.25);
+    /// assert_near!(res[0].1, Point3::new(-0.5, 0.0, 0.0));
+    /// assert_near!(res[1].1, Point3::new(0.5, 0.0, 0.0));
+    ///
+    /// // touches at the vertex
+    /// let plane = Plane::new(
+    ///     Point3::new(0.0, -0.25, 0.0),
+    ///     Point3::new(0.0, -0.25, 1.0),
+    ///     Point3::new(1.0, -0.25, 0.0),
+    /// );
+    /// let res = curve.intersect_plane(&plane, 1.0e-6);
+    /// assert_eq!(res.len(), 1);
+    /// assert_near!(res[0].1, Point3::new(0.0, -0.25, 0.0));
+    ///
+    /// // the curve lies in the plane
+    /// assert!(curve.intersect_plane(&Plane::xy(), 1.0e-6).is_empty());
+    /// assert!(curve.lies_in_plane(&Plane::xy(), 1.0e-6));
+    /// ```
+    pub fn intersect_plane(&self, plane: &Plane, tol: f64) -> Vec<(f64, Point3)> {
+        if self.lies_in_plane(plane, tol) {
+            return Vec::new();
+        }
+        let (origin, normal) = (plane.origin(), plane.normal());
+        let dist = |t: f64| normal.dot(self.subs(t) - origin);
+        let ders = |t: f64| (normal.dot(self.der(t)), normal.dot(self.der2(t)));
+        let division = 4 * (self.degree() + 1);
+        let (knots, _) = self.knot_vec.to_single_multi();
+        let mut params: Vec<f64> = knots
+            .windows(2)
+            .flat_map(|span| {
+                let width = span[1] - span[0];
+                (0..division).map(move |i| span[0] + width * i as f64 / division as f64)
+            })
+            .collect();
+        params.push(knots[knots.len() - 1]);
+        let values: Vec<f64> = params.iter().map(|t| dist(*t)).collect();
+
+        let mut res: Vec<f64> = Vec::new();
+        for i in 0..params.len() {
+            let (t, d) = (params[i], values[i]);
+            let (prev, next) = (values.get(i.wrapping_sub(1)), values.get(i + 1));
+            // crossing between the samples
+            if next.is_some_and(|next| d * next < 0.0) {
+                let root = bracketed_root(t, params[i + 1], d, &dist, |t| ders(t).0);
+                res.push(root);
+            }
+            if d == 0.0 {
+                res.push(t);
+                continue;
+            }
+            // a local minimum of the distance, which may touch the plane
+            let same_side = |x: &f64| x * d > 0.0 && x.abs() >= d.abs();
+            if prev.is_none_or(same_side) && next.is_none_or(same_side) {
+                let t0 = params[i.saturating_sub(1)];
+                let t1 = params[usize::min(i + 1, params.len() - 1)];
+                let extremum = bracketed_root(t0, t1, f64::NAN, &|t| ders(t).0, |t| ders(t).1);
+                // the curve crosses the plane twice between the samples
+                let e = dist(extremum);
+                if e * d < 0.0 {
+                    let der = |t: f64| ders(t).0;
+                    res.push(bracketed_root(t0, extremum, dist(t0), &dist, der));
+                    res.push(bracketed_root(extremum, t1, e, &dist, der));
+                    continue;
+                }
+                let t = match dist(extremum).abs() < d.abs() {
+                    true => extremum,
+                    false => t,
+                };
+                if dist(t).abs() < tol {
+                    res.push(t);
+                }
+            }
+        }
+        res.sort_by(f64::total_cmp);
+        // the roots are merged if the curve between them does not leave the plane
+        res.dedup_by(|t1, t0| {
+            let merged = (*t0 + *t1) / 2.0;
+            let touching = dist(merged).abs() < tol;
+            if touching {
+                *t0 = merged;
+            }
+            touching || self.subs(*t0).near(&self.subs(*t1))
+        });
+        res.into_iter().map(|t| (t, self.subs(t))).collect()
+    }
+
+    /// Returns whether the curve lies in `plane` within `tol`, i.e. all control points are within
+    /// `tol` from the plane.
+    #[inline(always)]
+    pub fn lies_in_plane(&self, plane: &Plane, tol: f64) -> bool {
+        let (origin, normal) = (plane.origin(), plane.normal());
+        let dist = |p: &Point3| normal.dot(p - origin).abs();
+        self.control_points.iter().all(|p| dist(p) < tol)
+    }
+
+    /// Returns the division of the parameter range such that the curve deviates from the chords
+    /// on the screen by less than `pixel_tol` pixels.
+    ///
+    /// The points are projected by `mvp` into the clip coordinates, and the normalized device
+    /// coordinates `[-1, 1]^2` are mapped to the viewport whose size is `viewport` in pixels. The
+    /// deviation is measured from the segment between the projected ends of each span, so the far
+    /// curves are divided coarsely and the near ones finely.
+    ///
+    /// A span with a point behind the near plane, i.e. whose `w` coordinate is not positive, cannot
+    /// be projected, and is divided by the deviation in the world space as
+    /// [`ParameterDivision1D::parameter_division`]. The tolerance is the length of `pixel_tol`
+    /// pixels on the near plane at the center of the view, or `pixel_tol` if `mvp` is singular.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let curve = |z: f64| {
+    ///     let ctrl_pts = vec![
+    ///         Point3::new(-1.0, 0.0, z),
+    ///         Point3::new(0.0, 2.0, z),
+    ///         Point3::new(1.0, 0.0, z),
+    ///     ];
+    ///     BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts)
+    /// };
+    /// let eye = Point3::new(0.0, 0.0, 5.0);
+    /// let view = Matrix4::look_at_rh(eye, Point3::origin(), Vector3::unit_y());
+    /// let mvp = perspective(Deg(60.0), 1.0, 0.1, 1000.0) * view;
+    ///
+    /// let near = curve(0.0).parameter_division_screen_space(mvp, (800.0, 800.0), 0.5);
+    /// let far = curve(-500.0).parameter_division_screen_space(mvp, (800.0, 800.0), 0.5);
+    /// assert!(far.len() < near.len());
+    /// assert_eq!((near[0], near[near.len() - 1]), (0.0, 1.0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `pixel_tol` must be greater than or equal to `TOLERANCE`.
+    pub fn parameter_division_screen_space(
+        &self,
+        mvp: Matrix4,
+        viewport: (f64, f64),
+        pixel_tol: f64,
+    ) -> Vec<f64> {
+        nonpositive_tolerance!(pixel_tol);
+        let (width, height) = viewport;
+        let project = |p: Point3| {
+            let c = mvp * p.to_homogeneous();
+            let x = (c.x / c.w + 1.0) * width / 2.0;
+            let y = (c.y / c.w + 1.0) * height / 2.0;
+            (c.w > 0.0).then(|| Point2::new(x, y))
+        };
+        let world_tol = mvp.invert().map_or(pixel_tol, |inv| {
+            let unproject = |x: f64| {
+                Point3::from_homogeneous(inv * Vector4::new(x, 0.0, -1.0, 1.0))
+            };
+            let tol = unproject(0.0).distance(unproject(2.0 / width)) * pixel_tol;
+            match tol.is_finite() {
+                true => f64::max(tol, TOLERANCE),
+                false => pixel_tol,
+            }
+        });
+
+        let range = self.range_tuple();
+        let mut params = vec![range.0];
+        let mut pending = vec![(range, (self.subs(range.0), self.subs(range.1)), 100)];
+        while let Some(((t0, t1), (p0, p1), trials)) = pending.pop() {
+            let gen = p0.midpoint(p1);
+            let ratio = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
+            let t = t0 * (1.0 - ratio) + t1 * ratio;
+            let [Some(s0), Some(s), Some(s1)] = [p0, self.subs(t), p1].map(project) else {
+                let division = algo::curve::sample_iter(self, (t0, t1), world_tol).skip(1);
+                params.extend(division.map(|(t, _)| t));
+                continue;
+            };
+            if segment_distance2(s, s0, s1) < pixel_tol * pixel_tol || trials == 0 {
+                params.push(t1);
+                continue;
+            }
+            let mid_param = (t0 + t1) / 2.0;
+            let mid_value = self.subs(mid_param);
+            pending.push(((mid_param, t1), (mid_value, p1), trials - 1));
+            pending.push(((t0, mid_param), (p0, mid_value), trials - 1));
+        }
+        params
+    }
+}
+
+/// the squared distance from `p` to the segment between `a` and `b`
+fn segment_distance2(p: Point2, a: Point2, b: Point2) -> f64 {
+    let (ab, ap) = (b - a, p - a);
+    let len2 = ab.magnitude2();
+    let t = match len2 > 0.0 {
+        true => f64::clamp(ap.dot(ab) / len2, 0.0, 1.0),
+        false => 0.0,
+    };
+    (ap - ab * t).magnitude2()
+}
+
+/// Returns the root of `function` in `[t0, t1]` by Newton's method safeguarded by bisection.
+///
+/// If the value at `t0` is given as `f0`, the function is assumed to change the sign in the
+/// interval. Otherwise, i.e. `f0` is `NaN`, Newton's method starts from the midpoint and the result
+/// is clamped to the interval.
+fn bracketed_root(
+    mut t0: f64,
+    mut t1: f64,
+    mut f0: f64,
+    function: &impl Fn(f64) -> f64,
+    derivation: impl Fn(f64) -> f64,
+) -> f64 {
+    let mut t = (t0 + t1) / 2.0;
+    for _ in 0..100 {
+        let (f, der) = (function(t), derivation(t));
+        let newton = t - f / der;
+        let next = match f0.is_nan() {
+            true => newton.clamp(t0, t1),
+            false => {
+                match f * f0 > 0.0 {
+                    true => (t0, f0) = (t, f),
+                    false => t1 = t,
+                }
+                match t0 < newton && newton < t1 {
+                    true => newton,
+                    false => (t0 + t1) / 2.0,
+                }
+            }
+        };
+        if f64::abs(next - t) < TOLERANCE * TOLERANCE || next.is_nan() {
+            return t;
+        }
+        t = next;
+    }
+    t
+}
+
 impl<P> SearchNearestParameter<D1> for BSplineCurve<P>
 where
     P: ControlPoint<f64>
@@ -1121,15 +1723,16 @@ where
         hint: H,
         trial: usize,
     ) -> Option<f64> {
-        let hint = match hint.into() {
-            SPHint1D::Parameter(hint) => hint,
-            SPHint1D::Range(x, y) => {
-                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
-            }
-            SPHint1D::None => {
-                algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_nearest_parameter_with_presearch(point, hint, trial, PRESEARCH_DIVISION)
+    }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: P,
+        hint: H,
+        trial: usize,
+        division: usize,
+    ) -> Option<f64> {
+        let hint = algo::curve::presearch_hint(self, point, hint.into(), division);
         algo::curve::search_nearest_parameter(self, point, hint, trial)
     }
 }
@@ -1143,15 +1746,16 @@ where
     type Point = P;
     #[inline(always)]
     fn search_parameter<H: Into<SPHint1D>>(&self, point: P, hint: H, trial: usize) -> Option<f64> {
-        let hint = match hint.into() {
-            SPHint1D::Parameter(hint) => hint,
-            SPHint1D::Range(x, y) => {
-                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
-            }
-            SPHint1D::None => {
-                algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_parameter_with_presearch(point, hint, trial, PRESEARCH_DIVISION)
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: P,
+        hint: H,
+        trial: usize,
+        division: usize,
+    ) -> Option<f64> {
+        let hint = algo::curve::presearch_hint(self, point, hint.into(), division);
         algo::curve::search_parameter(self, point, hint, trial)
     }
 }
@@ -277,6 +277,100 @@ impl<P> BSplineSurface<P> {
         self
     }
 
+    /// Inverts the first parameter `u`, and do not change `self` as a set of points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::from(vec![0.0, 0.0, 1.0, 3.0, 3.0]), KnotVec::bezier_knot(1));
+    /// let ctrl_pts = vec![
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(0.0, 1.0)],
+    ///     vec![Point2::new(1.0, 0.5), Point2::new(1.0, 1.5)],
+    ///     vec![Point2::new(2.0, 0.0), Point2::new(2.0, 1.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let mut inverted = bspsurface.clone();
+    /// inverted.uinvert();
+    /// assert_eq!(inverted.uknot_vec().as_slice(), &[0.0, 0.0, 2.0, 3.0, 3.0]);
+    /// assert_near!(inverted.subs(0.5, 0.3), bspsurface.subs(2.5, 0.3));
+    /// ```
+    pub fn uinvert(&mut self) -> &mut Self {
+        self.knot_vecs.0.invert();
+        self.control_points.reverse();
+        self
+    }
+
+    /// Inverts the second parameter `v`, and do not change `self` as a set of points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::from(vec![0.0, 0.0, 1.0, 3.0, 3.0]));
+    /// let ctrl_pts = vec![
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(0.5, 1.0), Point2::new(0.0, 2.0)],
+    ///     vec![Point2::new(1.0, 0.0), Point2::new(1.5, 1.0), Point2::new(1.0, 2.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let mut inverted = bspsurface.clone();
+    /// inverted.vinvert();
+    /// assert_eq!(inverted.vknot_vec().as_slice(), &[0.0, 0.0, 2.0, 3.0, 3.0]);
+    /// assert_near!(inverted.subs(0.3, 0.5), bspsurface.subs(0.3, 2.5));
+    /// ```
+    pub fn vinvert(&mut self) -> &mut Self {
+        self.knot_vecs.1.invert();
+        self.control_points
+            .iter_mut()
+            .for_each(|vec| vec.reverse());
+        self
+    }
+
+    /// Applies `orientation = (swap, uinvert, vinvert)` in this order, and fits the knot vectors
+    /// to the neighbor across `shared_edge`. cf. [`BSplineSurface::reorient_like`]
+    pub(super) fn apply_orientation(
+        &mut self,
+        (swap, uinvert, vinvert): (bool, bool, bool),
+        neighbor_knot_vecs: &(KnotVec, KnotVec),
+        shared_edge: SurfaceSide,
+    ) where
+        P: Clone,
+    {
+        if swap {
+            self.swap_axes();
+        }
+        if uinvert {
+            self.uinvert();
+        }
+        if vinvert {
+            self.vinvert();
+        }
+        let ((u0, u1), (v0, v1)) = (
+            knot_range(&neighbor_knot_vecs.0),
+            knot_range(&neighbor_knot_vecs.1),
+        );
+        let fit_range = |knot_vec: &mut KnotVec, front: f64, back: f64| {
+            let (t0, t1) = knot_range(knot_vec);
+            let scalar = (back - front) / (t1 - t0);
+            knot_vec.transform(scalar, front - t0 * scalar);
+        };
+        let (uknot_vec, vknot_vec) = &mut self.knot_vecs;
+        match shared_edge {
+            SurfaceSide::UFront => {
+                fit_range(vknot_vec, v0, v1);
+                uknot_vec.translate(u0 - knot_range(uknot_vec).1);
+            }
+            SurfaceSide::UBack => {
+                fit_range(vknot_vec, v0, v1);
+                uknot_vec.translate(u1 - knot_range(uknot_vec).0);
+            }
+            SurfaceSide::VFront => {
+                fit_range(uknot_vec, u0, u1);
+                vknot_vec.translate(v0 - knot_range(vknot_vec).1);
+            }
+            SurfaceSide::VBack => {
+                fit_range(uknot_vec, u0, u1);
+                vknot_vec.translate(v1 - knot_range(vknot_vec).0);
+            }
+        }
+    }
+
     /// The range of the parameter of the surface.
     #[inline(always)]
     pub fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
@@ -350,6 +444,64 @@ impl<P: ControlPoint<f64>> BSplineSurface<P> {
     #[inline(always)]
     pub fn get_closure(&self) -> impl Fn(f64, f64) -> P + '_ { move |u, v| self.subs(u, v) }
 
+    /// Returns the closure of substitution by the normalized parameters.
+    fn normalized_closure(&self) -> impl Fn(f64, f64) -> P + '_ {
+        let ((u0, u1), (v0, v1)) = (knot_range(&self.knot_vecs.0), knot_range(&self.knot_vecs.1));
+        move |s, t| self.subs(u0 + (u1 - u0) * s, v0 + (v1 - v0) * t)
+    }
+
+    /// Reorients the parameters of `self` by swapping the axes and inverting the parameters so
+    /// that `self` continues `neighbor` across the side `shared_edge` of `neighbor`.
+    ///
+    /// Of the eight reorientations, the one whose opposite side fits `shared_edge` best is chosen.
+    /// Then, the knot vectors are transformed so that `self` starts at `shared_edge` and has the
+    /// same range along `shared_edge` as `neighbor`, which makes the parameterizations of the two
+    /// surfaces continuous.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1));
+    /// let neighbor = BSplineSurface::new(
+    ///     knot_vecs.clone(),
+    ///     vec![
+    ///         vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///         vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+    ///     ],
+    /// );
+    /// // attached at `u = 1` of `neighbor`, with the swapped and inverted parameters.
+    /// let surface = BSplineSurface::new(
+    ///     knot_vecs,
+    ///     vec![
+    ///         vec![Point3::new(1.0, 1.0, 0.0), Point3::new(2.0, 1.0, 1.0)],
+    ///         vec![Point3::new(1.0, 0.0, 0.0), Point3::new(2.0, 0.0, 1.0)],
+    ///     ],
+    /// );
+    /// let reoriented = surface.reorient_like(&neighbor, SurfaceSide::UBack);
+    /// assert!(reoriented.near_as_surface(&{
+    ///     let mut surface = surface.clone();
+    ///     surface.swap_axes().vinvert().knot_translate(1.0, 0.0);
+    ///     surface
+    /// }));
+    /// for i in 0..=10 {
+    ///     let v = i as f64 / 10.0;
+    ///     assert_near!(reoriented.subs(1.0, v), neighbor.subs(1.0, v));
+    /// }
+    /// ```
+    pub fn reorient_like(
+        &self,
+        neighbor: &BSplineSurface<P>,
+        shared_edge: SurfaceSide,
+    ) -> BSplineSurface<P> {
+        let orientation = best_orientation(
+            self.normalized_closure(),
+            neighbor.normalized_closure(),
+            shared_edge,
+        );
+        let mut res = self.clone();
+        res.apply_orientation(orientation, &neighbor.knot_vecs, shared_edge);
+        res
+    }
+
     #[inline(always)]
     fn udelta_control_points(&self, i: usize, j: usize) -> P::Diff {
         if i == 0 {
@@ -513,6 +665,50 @@ impl<P: ControlPoint<f64>> BSplineSurface<P> {
     }
 }
 
+/// Returns the front and the back of `knot_vec`.
+#[inline(always)]
+pub(super) fn knot_range(knot_vec: &KnotVec) -> (f64, f64) {
+    (knot_vec[0], knot_vec[knot_vec.len() - 1])
+}
+
+/// Returns `(swap, uinvert, vinvert)` such that the side of the reoriented surface opposite to
+/// `shared_edge` fits `shared_edge` of the neighbor best. The surfaces are given by the closures
+/// of the normalized parameters.
+pub(super) fn best_orientation<Q: ControlPoint<f64>>(
+    subs: impl Fn(f64, f64) -> Q,
+    neighbor_subs: impl Fn(f64, f64) -> Q,
+    shared_edge: SurfaceSide,
+) -> (bool, bool, bool) {
+    const N: usize = 8;
+    type Side = fn(f64) -> (f64, f64);
+    let (neighbor_side, side): (Side, Side) = match shared_edge {
+        SurfaceSide::UFront => (|t| (0.0, t), |t| (1.0, t)),
+        SurfaceSide::UBack => (|t| (1.0, t), |t| (0.0, t)),
+        SurfaceSide::VFront => (|t| (t, 0.0), |t| (t, 1.0)),
+        SurfaceSide::VBack => (|t| (t, 1.0), |t| (t, 0.0)),
+    };
+    let deviation = |(swap, uinvert, vinvert): (bool, bool, bool)| {
+        (0..=N)
+            .map(|k| {
+                let x = k as f64 / N as f64;
+                let (s, t) = neighbor_side(x);
+                let p = neighbor_subs(s, t);
+                let (s, t) = side(x);
+                let s = if uinvert { 1.0 - s } else { s };
+                let t = if vinvert { 1.0 - t } else { t };
+                let q = if swap { subs(t, s) } else { subs(s, t) };
+                (0..Q::DIM).fold(0.0, |dist, i| f64::max(dist, f64::abs(p[i] - q[i])))
+            })
+            .fold(0.0, f64::max)
+    };
+    (0..8)
+        .map(|i| (i & 4 != 0, i & 2 != 0, i & 1 != 0))
+        .map(|orientation| (deviation(orientation), orientation))
+        .min_by(|(dev0, _), (dev1, _)| dev0.total_cmp(dev1))
+        .map(|(_, orientation)| orientation)
+        .unwrap()
+}
+
 impl<V: Homogeneous> BSplineSurface<V> {
     /// lift up control points to homogeneous coordinate.
     pub fn lift_up(surface: BSplineSurface<V::Point>) -> Self {
@@ -1187,6 +1383,170 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         res
     }
 
+    /// Decomposes the surface into the grid of Bezier patches by inserting each knot up to the
+    /// degree at once in both directions.
+    ///
+    /// The `(i, j)`th patch is the `i`th span of `u` and the `j`th span of `v`. Each patch keeps
+    /// its own parameter range, and the adjacent patches share the boundary control points exactly.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// let knot_vecs = (
+    ///     KnotVec::from(vec![0.0, 0.0, 0.0, 0.3, 1.0, 1.0, 1.0]),
+    ///     KnotVec::uniform_knot(1, 3),
+    /// );
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 1.0), Point3::new(0.0, 2.0, 0.0), Point3::new(0.0, 3.0, 1.0)],
+    ///     vec![Point3::new(1.0, 0.0, 1.0), Point3::new(1.0, 1.0, -1.0), Point3::new(1.0, 2.0, 2.0), Point3::new(1.0, 3.0, 0.0)],
+    ///     vec![Point3::new(2.0, 0.0, 0.0), Point3::new(2.0, 1.0, 2.0), Point3::new(2.0, 2.0, 0.0), Point3::new(2.0, 3.0, 1.0)],
+    ///     vec![Point3::new(3.0, 0.0, 1.0), Point3::new(3.0, 1.0, 0.0), Point3::new(3.0, 2.0, 1.0), Point3::new(3.0, 3.0, 0.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let patches = bspsurface.bezier_patches();
+    /// assert_eq!(patches.len(), 2);
+    /// assert_eq!(patches[0].len(), 3);
+    ///
+    /// let patch = &patches[1][2];
+    /// assert_eq!(patch.degrees(), (2, 1));
+    /// assert_eq!(patch.uknot_vec().as_slice(), &[0.3, 0.3, 0.3, 1.0, 1.0, 1.0]);
+    /// assert_near!(patch.subs(0.5, 0.8), bspsurface.subs(0.5, 0.8));
+    /// ```
+    pub fn bezier_patches(&self) -> Vec<Vec<BSplineSurface<P>>> {
+        let (udegree, vdegree) = self.degrees();
+        let mut bspsurface = self.clone();
+        for (knot, n) in self.knot_vecs.0.bezier_insertions(udegree) {
+            (0..n).for_each(|_| {
+                bspsurface.add_uknot(knot);
+            });
+        }
+        for (knot, n) in self.knot_vecs.1.bezier_insertions(vdegree) {
+            (0..n).for_each(|_| {
+                bspsurface.add_vknot(knot);
+            });
+        }
+        let (uknot_vec, vknot_vec) = &bspsurface.knot_vecs;
+        let vspans = vknot_vec.span_indices();
+        uknot_vec
+            .span_indices()
+            .into_iter()
+            .map(|i| {
+                let (front, back) = (uknot_vec[i], uknot_vec[i + 1]);
+                let uknot_vec = KnotVec::bezier_knot_between(front, back, udegree);
+                let rows = &bspsurface.control_points[i - udegree..=i];
+                vspans
+                    .iter()
+                    .map(|&j| {
+                        let (front, back) = (vknot_vec[j], vknot_vec[j + 1]);
+                        let vknot_vec = KnotVec::bezier_knot_between(front, back, vdegree);
+                        let control_points = rows
+                            .iter()
+                            .map(|row| row[j - vdegree..=j].to_vec())
+                            .collect();
+                        let knot_vecs = (uknot_vec.clone(), vknot_vec);
+                        BSplineSurface::new_unchecked(knot_vecs, control_points)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Reassembles the grid of Bezier patches, e.g. the output of [`BSplineSurface::bezier_patches`],
+    /// into one surface whose inner knots have the multiplicities of the degrees.
+    ///
+    /// The control points shared by the adjacent patches are taken from the former patch, and
+    /// checked to be the same as the latter within `continuity_check_tol` in each component.
+    /// # Failures
+    /// * If the grid of patches is empty, returns [`Error::EmptyControlPoints`].
+    /// * If the rows of the grid have different lengths, returns [`Error::IrregularControlPoints`].
+    /// * If the `(i, j)`th patch is not a Bezier patch with the same positive degrees as the
+    ///   others, or its parameter range does not fit the grid, returns [`Error::NotBezierPatch`].
+    /// * If the `(i, j)`th patch and the former patches have different shared control points,
+    ///   returns [`Error::DisconnectedBezierPatches`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    ///
+    /// let knot_vecs = (KnotVec::uniform_knot(2, 2), KnotVec::bezier_knot(1));
+    /// let ctrl_pts = vec![
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(0.0, 1.0)],
+    ///     vec![Point2::new(1.0, 0.5), Point2::new(1.0, 1.5)],
+    ///     vec![Point2::new(2.0, 0.5), Point2::new(2.0, 1.5)],
+    ///     vec![Point2::new(3.0, 0.0), Point2::new(3.0, 1.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let patches = bspsurface.bezier_patches();
+    /// let reassembled = BSplineSurface::from_bezier_grid(patches, 1.0e-10).unwrap();
+    /// assert_eq!(reassembled.uknot_vec().as_slice(), &[0.0, 0.0, 0.0, 0.5, 0.5, 1.0, 1.0, 1.0]);
+    /// assert!(reassembled.near2_as_surface(&bspsurface));
+    /// ```
+    pub fn from_bezier_grid(
+        patches: Vec<Vec<BSplineSurface<P>>>,
+        continuity_check_tol: f64,
+    ) -> Result<BSplineSurface<P>> {
+        let Some(first) = patches.first().and_then(|row| row.first()) else {
+            return Err(Error::EmptyControlPoints);
+        };
+        let (udegree, vdegree) = first.degrees();
+        let (ulen, vlen) = (patches.len(), patches[0].len());
+        if patches.iter().any(|row| row.len() != vlen) {
+            return Err(Error::IrregularControlPoints);
+        }
+        let uranges: Vec<(f64, f64)> = patches
+            .iter()
+            .map(|row| knot_range(&row[0].knot_vecs.0))
+            .collect();
+        let vranges: Vec<(f64, f64)> = patches[0]
+            .iter()
+            .map(|patch| knot_range(&patch.knot_vecs.1))
+            .collect();
+        let fits_grid = |knot_vec: &KnotVec, degree: usize, ranges: &[(f64, f64)], idx: usize| {
+            let (front, back) = ranges[idx];
+            let contiguous = idx == 0 || ranges[idx - 1].1.near(&front);
+            let is_bezier = knot_vec.len() == 2 * degree + 2
+                && knot_vec[..=degree].iter().all(|t| t.near(&front))
+                && knot_vec[degree + 1..].iter().all(|t| t.near(&back));
+            degree > 0 && contiguous && is_bezier && !front.near(&back)
+        };
+
+        let mut control_points = vec![vec![P::origin(); vlen * vdegree + 1]; ulen * udegree + 1];
+        for (i, row) in patches.iter().enumerate() {
+            for (j, patch) in row.iter().enumerate() {
+                let (uknot_vec, vknot_vec) = &patch.knot_vecs;
+                if patch.degrees() != (udegree, vdegree)
+                    || !fits_grid(uknot_vec, udegree, &uranges, i)
+                    || !fits_grid(vknot_vec, vdegree, &vranges, j)
+                {
+                    return Err(Error::NotBezierPatch(i, j));
+                }
+                for (a, pts) in patch.control_points.iter().enumerate() {
+                    for (b, pt) in pts.iter().enumerate() {
+                        let target = &mut control_points[i * udegree + a][j * vdegree + b];
+                        if (i > 0 && a == 0) || (j > 0 && b == 0) {
+                            let diff = |k: usize| f64::abs(target[k] - pt[k]);
+                            if (0..P::DIM).any(|k| diff(k) > continuity_check_tol) {
+                                return Err(Error::DisconnectedBezierPatches(i, j));
+                            }
+                        } else {
+                            *target = *pt;
+                        }
+                    }
+                }
+            }
+        }
+
+        let merged_knot_vec = |ranges: &[(f64, f64)], degree: usize| {
+            let mut vec = vec![ranges[0].0; degree + 1];
+            for (front, _) in &ranges[1..] {
+                vec.extend(std::iter::repeat_n(*front, degree));
+            }
+            vec.extend(std::iter::repeat_n(ranges[ranges.len() - 1].1, degree + 1));
+            KnotVec::from(vec)
+        };
+        let knot_vecs = (merged_knot_vec(&uranges, udegree), merged_knot_vec(&vranges, vdegree));
+        Ok(BSplineSurface::new_unchecked(knot_vecs, control_points))
+    }
+
     /// Creates a sectional curve with normalized knot vector from the parameter `p` to the parameter `q`.
     /// # Examples
     /// ```
@@ -1423,6 +1783,36 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         self
     }
 
+    /// Rescales the knot vectors so that both parameter ranges become `[0, 1]`, without changing
+    /// the geometry. Returns the previous ranges `((u0, u1), (v0, v1))`: the point at `(u, v)`
+    /// before is the one at `((u - u0) / (u1 - u0), (v - v0) / (v1 - v0))` after.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (
+    ///     KnotVec::from(vec![-1.0, -1.0, 0.5, 3.0, 3.0]),
+    ///     KnotVec::from(vec![2.0, 2.0, 2.0, 4.0, 4.0, 4.0]),
+    /// );
+    /// let control_points = (0..3)
+    ///     .map(|i| (0..3).map(|j| Point3::new(i as f64, j as f64, (i * j) as f64)).collect())
+    ///     .collect();
+    /// let surface = BSplineSurface::new(knot_vecs, control_points);
+    ///
+    /// let mut normalized = surface.clone();
+    /// let ranges = normalized.normalize_parameter_ranges();
+    /// assert_eq!(ranges, ((-1.0, 3.0), (2.0, 4.0)));
+    /// assert_eq!(normalized.range_tuple(), ((0.0, 1.0), (0.0, 1.0)));
+    /// assert_near!(normalized.subs(0.25, 0.5), surface.subs(0.0, 3.0));
+    /// ```
+    /// # Panics
+    /// Panics if the range of either knot vector is so small.
+    pub fn normalize_parameter_ranges(&mut self) -> ((f64, f64), (f64, f64)) {
+        let range = |knot_vec: &KnotVec| (knot_vec[0], knot_vec[knot_vec.len() - 1]);
+        let ranges = (range(&self.knot_vecs.0), range(&self.knot_vecs.1));
+        self.knot_normalize();
+        ranges
+    }
+
     /// Translates the knot vectors.
     #[inline(always)]
     pub fn knot_translate(&mut self, x: f64, y: f64) -> &mut Self {
@@ -1562,6 +1952,35 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
     pub fn near2_as_surface(&self, other: &BSplineSurface<P>) -> bool {
         self.sub_near_as_surface(other, 1, |x, y| x.near2(y))
     }
+
+    /// Determines whether `self` and `other` is near as the B-spline surfaces by `spec`.
+    ///
+    /// The points are compared at the same parameters as [`BSplineSurface::near_as_surface`], by
+    /// the threshold of the scale of the surfaces, which is the maximum of the scales of the
+    /// control points. The default spec is the same as [`BSplineSurface::near_as_surface`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // the square of the side `1.0e-6`
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = (0..3)
+    ///     .map(|i| (0..3).map(|j| Point3::new(i as f64, j as f64, 0.0) * 0.5e-6).collect())
+    ///     .collect();
+    /// let bspsurface0 = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let mut bspsurface1 = bspsurface0.clone();
+    /// *bspsurface1.control_point_mut(1, 1) += Vector3::new(0.0, 0.0, 5.0e-7);
+    /// // the micro surfaces are merged by the absolute tolerance
+    /// assert!(bspsurface0.near_as_surface(&bspsurface1));
+    /// let spec = ToleranceSpec::new(0.0, 1.0e-3);
+    /// assert!(!bspsurface0.near_as_surface_with(&bspsurface1, spec));
+    /// ```
+    pub fn near_as_surface_with(&self, other: &BSplineSurface<P>, spec: ToleranceSpec) -> bool
+    where P: ToleranceScale {
+        let ctrl_pts = self.control_points.iter().chain(&other.control_points).flatten();
+        let scale = ctrl_pts.fold(0.0, |max, p| f64::max(max, p.tolerance_scale()));
+        let tol = spec.threshold(scale);
+        self.sub_near_as_surface(other, 1, |x, y| x.abs_diff_eq(y, tol))
+    }
 }
 
 impl<V: Bounded> BSplineSurface<V> {
@@ -1589,13 +2008,279 @@ where P: EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
 
 impl ParametricSurface3D for BSplineSurface<Point3> {}
 
+impl BSplineSurface<Point3> {
+    /// Returns the normal vectors at all the grid points `(us[i], vs[j])`.
+    ///
+    /// The basis functions are evaluated only once for each parameter,
+    /// and the results are the same as `self.normal(us[i], vs[j])`.
+    /// At the degenerate points where `uder` and `vder` are parallel,
+    /// the normal vectors are the zero vector instead of `NaN`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // a parabolic surface with the degenerate point at the origin
+    /// let surface = BSplineSurface::new(
+    ///     (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2)),
+    ///     vec![
+    ///         vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 0.0)],
+    ///         vec![Point3::new(0.5, 0.0, 0.0), Point3::new(0.5, 0.5, 0.0), Point3::new(0.0, 0.5, 0.0)],
+    ///         vec![Point3::new(1.0, 0.0, 1.0), Point3::new(1.0, 1.0, 1.0), Point3::new(0.0, 1.0, 1.0)],
+    ///     ],
+    /// );
+    /// let us = [0.0, 0.25, 0.5, 1.0];
+    /// let vs = [0.0, 0.5, 1.0];
+    /// let normals = surface.normals_grid(&us, &vs);
+    /// for (i, u) in us.iter().enumerate() {
+    ///     for (j, v) in vs.iter().enumerate() {
+    ///         match i {
+    ///             0 => assert_eq!(normals[i][j], Vector3::zero()),
+    ///             _ => assert_eq!(normals[i][j], surface.normal(*u, *v)),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn normals_grid(&self, us: &[f64], vs: &[f64]) -> Vec<Vec<Vector3>> {
+        let (degree0, degree1) = self.degrees();
+        let (uknot_vec, vknot_vec) = &self.knot_vecs;
+        let vbases: Vec<_> = vs
+            .iter()
+            .map(|v| {
+                let basis = vknot_vec.bspline_basis_functions(degree1, 0, *v);
+                let der_basis = vknot_vec.bspline_basis_functions(degree1, 1, *v);
+                (basis, der_basis)
+            })
+            .collect();
+        us.iter()
+            .map(|u| {
+                let ubasis = uknot_vec.bspline_basis_functions(degree0, 0, *u);
+                let uder_basis = uknot_vec.bspline_basis_functions(degree0, 1, *u);
+                vbases
+                    .iter()
+                    .map(|(vbasis, vder_basis)| {
+                        let (mut uder, mut vder) = (Vector3::zero(), Vector3::zero());
+                        let iter = self.control_points.iter().zip(&ubasis).zip(&uder_basis);
+                        for ((vec, b0), db0) in iter {
+                            for ((p, b1), db1) in vec.iter().zip(vbasis).zip(vder_basis) {
+                                let p = ControlPoint::to_vec(*p);
+                                uder += p * (db0 * b1);
+                                vder += p * (b0 * db1);
+                            }
+                        }
+                        let normal = uder.cross(vder);
+                        match normal.so_small2() {
+                            true => Vector3::zero(),
+                            false => normal.normalize(),
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Moves the interior control points by one explicit step of the mean curvature flow, and
+    /// returns the maximum displacement of the control points.
+    ///
+    /// The mean curvature vector `H n` is sampled at the parameters divided by `tol` and at the
+    /// Greville abscissae, where `H` is the average of the principal curvatures and `n` is the
+    /// unit normal. Each interior control point moves by `dt` times the average of the sampled
+    /// vectors weighted by its basis function. The boundary control points are fixed, and the
+    /// degenerate samples, where the normal vanishes, are ignored.
+    ///
+    /// The step is explicit and only conditionally stable: `dt` should be less than about half
+    /// the square of the distance between adjacent control points. Larger steps overshoot and
+    /// make the control net oscillate. Iterate small steps until the returned displacement is
+    /// small enough.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // a flat square with a bump at the center
+    /// let mut ctrl_pts: Vec<Vec<Point3>> = (0..5)
+    ///     .map(|i| (0..5).map(|j| Point3::new(i as f64, j as f64, 0.0)).collect())
+    ///     .collect();
+    /// ctrl_pts[2][2].z = 1.0;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0]);
+    /// let mut surface = BSplineSurface::new((knot_vec.clone(), knot_vec), ctrl_pts);
+    ///
+    /// let mut last = f64::INFINITY;
+    /// for _ in 0..20 {
+    ///     let displacement = surface.smooth_step(0.4, 0.01);
+    ///     assert!(displacement < last);
+    ///     last = displacement;
+    /// }
+    /// // the bump is flattened, but the boundary is fixed
+    /// assert!(surface.control_point(2, 2).z < 0.6);
+    /// assert_eq!(*surface.control_point(0, 2), Point3::new(0.0, 2.0, 0.0));
+    /// ```
+    pub fn smooth_step(&mut self, dt: f64, tol: f64) -> f64 {
+        let (degree0, degree1) = self.degrees();
+        let (len0, len1) = (self.control_points.len(), self.control_points[0].len());
+        if len0 < 3 || len1 < 3 {
+            return 0.0;
+        }
+        let (us, vs) = self.parameter_division(self.range_tuple(), tol);
+        let us = with_greville_abscissae(us, &self.knot_vecs.0, degree0, len0);
+        let vs = with_greville_abscissae(vs, &self.knot_vecs.1, degree1, len1);
+        let vbases: Vec<_> = vs
+            .iter()
+            .map(|v| self.knot_vecs.1.bspline_basis_functions(degree1, 0, *v))
+            .collect();
+        let mut sums = vec![vec![(Vector3::zero(), 0.0); len1]; len0];
+        for u in &us {
+            let ubasis = self.knot_vecs.0.bspline_basis_functions(degree0, 0, *u);
+            for (v, vbasis) in vs.iter().zip(&vbases) {
+                let Some(vector) = self.mean_curvature_vector(*u, *v) else {
+                    continue;
+                };
+                for (i, b0) in ubasis.iter().enumerate().take(len0 - 1).skip(1) {
+                    for (j, b1) in vbasis.iter().enumerate().take(len1 - 1).skip(1) {
+                        let weight = b0 * b1;
+                        sums[i][j].0 += vector * weight;
+                        sums[i][j].1 += weight;
+                    }
+                }
+            }
+        }
+        let mut max = 0.0;
+        for (vec, sums) in self.control_points.iter_mut().zip(sums) {
+            for (p, (sum, weight)) in vec.iter_mut().zip(sums) {
+                if weight > 0.0 {
+                    let delta = sum * (dt / weight);
+                    *p += delta;
+                    max = f64::max(max, delta.magnitude());
+                }
+            }
+        }
+        max
+    }
+
+    /// Returns the mean curvature vector, `None` at the degenerate points.
+    fn mean_curvature_vector(&self, u: f64, v: f64) -> Option<Vector3> {
+        let (uder, vder) = (self.uder(u, v), self.vder(u, v));
+        let normal = uder.cross(vder);
+        if normal.so_small2() {
+            return None;
+        }
+        let normal = normal.normalize();
+        let (e, f, g) = (uder.dot(uder), uder.dot(vder), vder.dot(vder));
+        let l = self.uuder(u, v).dot(normal);
+        let m = self.uvder(u, v).dot(normal);
+        let n = self.vvder(u, v).dot(normal);
+        let h = (e * n - 2.0 * f * m + g * l) / (2.0 * (e * g - f * f));
+        Some(normal * h)
+    }
+
+    /// Returns the boundaries of the parameter rectangle which degenerate, with their images.
+    ///
+    /// By the convex hull property, the boundaries are classified by the control net:
+    /// - [`Collapse::Point`]: the control points on the boundary are within `tol` from the first.
+    /// - [`Collapse::Line`]: the control points on the boundary and on the next row are within
+    ///   `tol` from a line. Then, the normal vanishes along the boundary. The segment is the hull
+    ///   of the control points on the boundary.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // a cone with the apex at the origin
+    /// let surface = BSplineSurface::new(
+    ///     (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1)),
+    ///     vec![
+    ///         vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 0.0)],
+    ///         vec![Point3::new(1.0, 0.0, 1.0), Point3::new(0.0, 1.0, 1.0)],
+    ///     ],
+    /// );
+    /// let degenerate = surface.degenerate_edges(TOLERANCE);
+    /// assert_eq!(
+    ///     degenerate,
+    ///     vec![DegenerateBoundary {
+    ///         edge: DegenerateEdge::UFront,
+    ///         collapse: Collapse::Point(Point3::origin()),
+    ///     }],
+    /// );
+    /// ```
+    pub fn degenerate_edges(&self, tol: f64) -> Vec<DegenerateBoundary<Point3>> {
+        control_net_degeneration(&self.control_points, tol)
+    }
+}
+
+/// Returns the degenerate boundaries of the surface with the control net `control_points`.
+///
+/// cf. [`BSplineSurface::degenerate_edges`]
+pub(super) fn control_net_degeneration(
+    control_points: &[Vec<Point3>],
+    tol: f64,
+) -> Vec<DegenerateBoundary<Point3>> {
+    let (m, n) = (control_points.len(), control_points[0].len());
+    if m < 2 || n < 2 {
+        return Vec::new();
+    }
+    let row = |i: usize| control_points[i].clone();
+    let column = |j: usize| control_points.iter().map(|row| row[j]).collect::<Vec<_>>();
+    [
+        (DegenerateEdge::UFront, row(0), row(1)),
+        (DegenerateEdge::UBack, row(m - 1), row(m - 2)),
+        (DegenerateEdge::VFront, column(0), column(1)),
+        (DegenerateEdge::VBack, column(n - 1), column(n - 2)),
+    ]
+    .into_iter()
+    .filter_map(|(edge, boundary, next)| {
+        let collapse = boundary_collapse(&boundary, &next, tol)?;
+        Some(DegenerateBoundary { edge, collapse })
+    })
+    .collect()
+}
+
+/// Returns the image of the boundary with the control points `boundary`, if it degenerates.
+fn boundary_collapse(boundary: &[Point3], next: &[Point3], tol: f64) -> Option<Collapse<Point3>> {
+    let p = boundary[0];
+    let distance2 = |q: &&Point3| q.distance2(p);
+    if boundary.iter().all(|q| q.distance2(p) <= tol * tol) {
+        return Some(Collapse::Point(p));
+    }
+    let far = boundary.iter().max_by(|q, r| distance2(q).total_cmp(&distance2(r)))?;
+    let dir = (*far - p).normalize();
+    let on_line = |q: &Point3| {
+        let r = *q - p;
+        (r - dir * r.dot(dir)).magnitude2() <= tol * tol
+    };
+    if !boundary.iter().chain(next).all(on_line) {
+        return None;
+    }
+    let (t0, t1) = boundary.iter().fold((0.0, 0.0), |(t0, t1), q| {
+        let t = (*q - p).dot(dir);
+        (f64::min(t0, t), f64::max(t1, t))
+    });
+    Some(Collapse::Line(p + dir * t0, p + dir * t1))
+}
+
+/// Adds the Greville abscissae to the sorted parameters `params`, so that the support of each
+/// basis function contains at least one parameter.
+fn with_greville_abscissae(
+    mut params: Vec<f64>,
+    knot_vec: &KnotVec,
+    degree: usize,
+    len: usize,
+) -> Vec<f64> {
+    if degree > 0 {
+        let greville = |i: usize| knot_vec[i + 1..=i + degree].iter().sum::<f64>() / degree as f64;
+        params.extend((0..len).map(greville));
+    }
+    params.sort_by(f64::total_cmp);
+    params.dedup_by(|a, b| f64::near(a, b));
+    params
+}
+
 impl<V> BoundedSurface for BSplineSurface<V> where BSplineSurface<V>: ParametricSurface {}
 
+/// The inverse swaps the parameters `u` and `v`.
 impl<V: Clone> Invertible for BSplineSurface<V> {
     #[inline(always)]
     fn invert(&mut self) { self.swap_axes(); }
 }
 
+impl<V: Clone> InvertibleSurface for BSplineSurface<V> {
+    #[inline(always)]
+    fn inverse_map(&self) -> ParamMap2D { ParamMap2D::SWAP }
+}
+
 impl<P, V> SearchParameter<D2> for BSplineSurface<P>
 where
     P: ControlPoint<f64, Diff = V>
@@ -1611,15 +2296,16 @@ where
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: P,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = algo::surface::presearch_hint(self, point, hint.into(), division);
         algo::surface::search_parameter(self, point, hint, trials)
     }
 }
@@ -1638,15 +2324,16 @@ where
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_nearest_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: P,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = algo::surface::presearch_hint(self, point, hint.into(), division);
         algo::surface::search_nearest_parameter(self, point, hint, trials)
     }
 }
@@ -574,6 +574,36 @@ impl KnotVec {
         vec.extend(std::iter::repeat_n(1.0, degree + 1));
         KnotVec(vec)
     }
+
+    /// Returns the Bezier knot vector of `degree` whose range is from `front` to `back`.
+    pub(super) fn bezier_knot_between(front: f64, back: f64, degree: usize) -> KnotVec {
+        let mut vec = vec![front; degree + 1];
+        vec.extend(std::iter::repeat_n(back, degree + 1));
+        KnotVec(vec)
+    }
+
+    /// Returns the knots and the numbers of times they have to be inserted so that
+    /// the end knots have multiplicity `degree + 1` and the inner knots `degree`.
+    pub(super) fn bezier_insertions(&self, degree: usize) -> Vec<(f64, usize)> {
+        let (knots, mults) = self.to_single_multi();
+        let last = knots.len() - 1;
+        knots
+            .into_iter()
+            .zip(mults)
+            .enumerate()
+            .filter_map(|(i, (knot, mult))| {
+                let target = if i == 0 || i == last { degree + 1 } else { degree };
+                (mult < target).then_some((knot, target - mult))
+            })
+            .collect()
+    }
+
+    /// Returns the indices `k` such that `self[k]` and `self[k + 1]` are different.
+    pub(super) fn span_indices(&self) -> Vec<usize> {
+        (0..self.len() - 1)
+            .filter(|&k| !self[k].near(&self[k + 1]))
+            .collect()
+    }
 }
 
 impl From<Vec<f64>> for KnotVec {
@@ -118,6 +118,20 @@ pub struct NurbsCurve<V>(BSplineCurve<V>);
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize, SelfSameGeometry)]
 pub struct NurbsSurface<V>(BSplineSurface<V>);
 
+/// The sides of the boundary of B-spline surfaces,
+/// in the same order as [`BSplineSurface::splitted_boundary`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SurfaceSide {
+    /// the side where `v` is the front of the knot vector
+    VFront,
+    /// the side where `u` is the back of the knot vector
+    UBack,
+    /// the side where `v` is the back of the knot vector
+    VBack,
+    /// the side where `u` is the front of the knot vector
+    UFront,
+}
+
 mod bspcurve;
 mod bspsurface;
 mod knot_vec;
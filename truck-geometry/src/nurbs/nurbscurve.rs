@@ -214,6 +214,13 @@ impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V> + Tolerance> Nur
         self
     }
 
+    /// Adds the knot `t` until the multiplicity of `t` reaches `multiplicity`, and do not change
+    /// `self` as a curve. cf.[`BSplineCurve::insert_knot_to`]
+    pub fn insert_knot_to(&mut self, t: f64, multiplicity: usize) -> &mut Self {
+        self.0.insert_knot_to(t, multiplicity);
+        self
+    }
+
     /// Removes a knot corresponding to the indices `idx`, and do not change `self` as a curve.
     /// If cannot remove the knot, do not change `self` and return `self`.
     /// cf.[`BSplineCurve::remove_knot`]
@@ -263,6 +270,39 @@ impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V> + Tolerance> Nur
 
     /// Makes two splines having the same normalized knot vectors. cf.[`BSplineCurve::syncro_knots`]
     pub fn syncro_knots(&mut self, other: &mut Self) { self.0.syncro_knots(&mut other.0) }
+
+    /// Separates `self` into Bezier curves. cf.[`BSplineCurve::bezier_segments`]
+    pub fn bezier_segments(&self) -> Vec<Self> {
+        let segments = self.0.bezier_segments().into_iter();
+        segments.map(NurbsCurve::new).collect()
+    }
+
+    /// Returns the restriction of the curve to `range`, keeping the parameter.
+    /// The weights are kept, so the subcurves of arcs are exactly arcs.
+    /// cf.[`BSplineCurve::subcurve`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // the quarter of the unit circle
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    ///     Vector3::new(1.0, 1.0, 1.0) / f64::sqrt(2.0),
+    ///     Vector3::new(0.0, 1.0, 1.0),
+    /// ];
+    /// let arc = NurbsCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+    /// let sub = arc.subcurve((0.75, 0.25));
+    /// assert_eq!(sub.range_tuple(), (0.25, 0.75));
+    /// for i in 0..=10 {
+    ///     let t = 0.25 + 0.05 * i as f64;
+    ///     assert_near!(sub.subs(t).to_vec().magnitude(), 1.0);
+    ///     assert_near!(sub.subs(t), arc.subs(1.0 - t));
+    /// }
+    /// ```
+    /// # Panics
+    /// Panics if an end of `range` is out of the parameter range, or if `range` is degenerate.
+    #[inline(always)]
+    pub fn subcurve(&self, range: (f64, f64)) -> Self { NurbsCurve(self.0.subcurve(range)) }
 }
 
 impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V> + Tolerance> ParameterTransform
@@ -395,6 +435,13 @@ where V::Point: MetricSpace<Metric = f64> + HashGen<f64>
     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<V::Point>) {
         algo::curve::parameter_division(self, range, tol)
     }
+    fn sample_iter(
+        &self,
+        range: (f64, f64),
+        tol: f64,
+    ) -> impl Iterator<Item = (f64, Self::Point)> {
+        algo::curve::sample_iter(self, range, tol)
+    }
 }
 
 impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V>> SearchNearestParameter<D1>
@@ -449,15 +496,16 @@ where
         hint: H,
         trial: usize,
     ) -> Option<f64> {
-        let hint = match hint.into() {
-            SPHint1D::Parameter(hint) => hint,
-            SPHint1D::Range(x, y) => {
-                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
-            }
-            SPHint1D::None => {
-                algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_nearest_parameter_with_presearch(point, hint, trial, PRESEARCH_DIVISION)
+    }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: V::Point,
+        hint: H,
+        trial: usize,
+        division: usize,
+    ) -> Option<f64> {
+        let hint = algo::curve::presearch_hint(self, point, hint.into(), division);
         algo::curve::search_nearest_parameter(self, point, hint, trial)
     }
 }
@@ -476,15 +524,16 @@ where
         hint: H,
         trial: usize,
     ) -> Option<f64> {
-        let hint = match hint.into() {
-            SPHint1D::Parameter(hint) => hint,
-            SPHint1D::Range(x, y) => {
-                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
-            }
-            SPHint1D::None => {
-                algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_parameter_with_presearch(point, hint, trial, PRESEARCH_DIVISION)
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: V::Point,
+        hint: H,
+        trial: usize,
+        division: usize,
+    ) -> Option<f64> {
+        let hint = algo::curve::presearch_hint(self, point, hint.into(), division);
         algo::curve::search_parameter(self, point, hint, trial)
     }
 }
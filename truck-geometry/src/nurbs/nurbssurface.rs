@@ -1,4 +1,7 @@
-use super::*;
+use super::{
+    bspsurface::{best_orientation, control_net_degeneration, knot_range},
+    *,
+};
 use algo::surface::{SsnpVector, SspVector};
 
 impl<V> NurbsSurface<V> {
@@ -169,6 +172,18 @@ impl<V> NurbsSurface<V> {
         self.0.swap_axes();
         self
     }
+    /// Inverts the first parameter `u`. cf.[`BSplineSurface::uinvert`]
+    #[inline(always)]
+    pub fn uinvert(&mut self) -> &mut Self {
+        self.0.uinvert();
+        self
+    }
+    /// Inverts the second parameter `v`. cf.[`BSplineSurface::vinvert`]
+    #[inline(always)]
+    pub fn vinvert(&mut self) -> &mut Self {
+        self.0.vinvert();
+        self
+    }
     /// The range of the parameter of the surface.
     #[inline(always)]
     pub fn parameter_range(&self) -> (ParameterRange, ParameterRange) { self.0.parameter_range() }
@@ -227,6 +242,26 @@ impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V>> NurbsSurface<V>
     /// Returns the closure of substitution.
     #[inline(always)]
     pub fn get_closure(&self) -> impl Fn(f64, f64) -> V::Point + '_ { move |u, v| self.subs(u, v) }
+
+    /// Returns the closure of substitution by the normalized parameters.
+    fn normalized_closure(&self) -> impl Fn(f64, f64) -> V::Point + '_ {
+        let ((u0, u1), (v0, v1)) = (knot_range(self.uknot_vec()), knot_range(self.vknot_vec()));
+        move |s, t| self.subs(u0 + (u1 - u0) * s, v0 + (v1 - v0) * t)
+    }
+
+    /// Reorients the parameters of `self` so that `self` continues `neighbor` across the side
+    /// `shared_edge` of `neighbor`. cf.[`BSplineSurface::reorient_like`]
+    pub fn reorient_like(&self, neighbor: &Self, shared_edge: SurfaceSide) -> Self
+    where V::Point: ControlPoint<f64> {
+        let orientation = best_orientation(
+            self.normalized_closure(),
+            neighbor.normalized_closure(),
+            shared_edge,
+        );
+        let mut res = self.clone();
+        res.0.apply_orientation(orientation, neighbor.knot_vecs(), shared_edge);
+        res
+    }
 }
 
 impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V>> NurbsSurface<V>
@@ -395,12 +430,39 @@ impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V> + Tolerance> Nur
     #[inline(always)]
     pub fn vcut(&mut self, v: f64) -> Self { Self::new(self.0.vcut(v)) }
 
+    /// Decomposes the surface into the grid of Bezier patches.
+    /// cf.[`BSplineSurface::bezier_patches`]
+    pub fn bezier_patches(&self) -> Vec<Vec<Self>> {
+        let patches = self.0.bezier_patches().into_iter();
+        patches
+            .map(|row| row.into_iter().map(Self::new).collect())
+            .collect()
+    }
+
+    /// Reassembles the grid of Bezier patches into one surface.
+    /// The shared control points are compared in the homogeneous coordinates.
+    /// cf.[`BSplineSurface::from_bezier_grid`]
+    pub fn from_bezier_grid(patches: Vec<Vec<Self>>, continuity_check_tol: f64) -> Result<Self> {
+        let patches = patches
+            .into_iter()
+            .map(|row| row.into_iter().map(Self::into_non_rationalized).collect())
+            .collect();
+        BSplineSurface::from_bezier_grid(patches, continuity_check_tol).map(Self::new)
+    }
+
     /// Normalizes the knot vectors
     #[inline(always)]
     pub fn knot_normalize(&mut self) -> &mut Self {
         self.0.knot_normalize();
         self
     }
+    /// Rescales the knot vectors so that both parameter ranges become `[0, 1]`, without changing
+    /// the geometry, and returns the previous ranges.
+    /// cf. [`BSplineSurface::normalize_parameter_ranges`]
+    #[inline(always)]
+    pub fn normalize_parameter_ranges(&mut self) -> ((f64, f64), (f64, f64)) {
+        self.0.normalize_parameter_ranges()
+    }
     /// Translates the knot vectors.
     #[inline(always)]
     pub fn knot_translate(&mut self, x: f64, y: f64) -> &mut Self {
@@ -515,15 +577,16 @@ where
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_nearest_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: V::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = algo::surface::presearch_hint(self, point, hint.into(), division);
         algo::surface::search_nearest_parameter(self, point, hint, trials)
     }
 }
@@ -543,6 +606,7 @@ where V::Point: Bounded<Scalar = f64>
     }
 }
 
+/// The inverse swaps the parameters `u` and `v`.
 impl<V: Clone> Invertible for NurbsSurface<V> {
     #[inline(always)]
     fn invert(&mut self) { self.swap_axes(); }
@@ -554,6 +618,11 @@ impl<V: Clone> Invertible for NurbsSurface<V> {
     }
 }
 
+impl<V: Clone> InvertibleSurface for NurbsSurface<V> {
+    #[inline(always)]
+    fn inverse_map(&self) -> ParamMap2D { ParamMap2D::SWAP }
+}
+
 impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V>> ParametricSurface
     for NurbsSurface<V>
 {
@@ -612,6 +681,21 @@ impl ParametricSurface3D for NurbsSurface<Vector4> {
     }
 }
 
+impl NurbsSurface<Vector4> {
+    /// Returns the boundaries of the parameter rectangle which degenerate, with their images.
+    ///
+    /// The control points are dehomogenized and classified in the same way as
+    /// [`BSplineSurface::degenerate_edges`], which is valid for the positive weights.
+    pub fn degenerate_edges(&self, tol: f64) -> Vec<DegenerateBoundary<Point3>> {
+        let control_points: Vec<Vec<Point3>> = self
+            .control_points()
+            .iter()
+            .map(|row| row.iter().map(|v| v.to_point()).collect())
+            .collect();
+        control_net_degeneration(&control_points, tol)
+    }
+}
+
 impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V>> ParameterDivision2D
     for NurbsSurface<V>
 where V::Point: MetricSpace<Metric = f64> + HashGen<f64>
@@ -780,15 +864,16 @@ where
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: V::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = algo::surface::presearch_hint(self, point, hint.into(), division);
         algo::surface::search_parameter(self, point, hint, trials)
     }
 }
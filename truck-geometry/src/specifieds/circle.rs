@@ -26,6 +26,8 @@ impl ParametricCurve for UnitCircle<Point2> {
     #[inline]
     fn der2(&self, t: f64) -> Vector2 { self.der_n(2, t) }
     #[inline]
+    fn period(&self) -> Option<f64> { Some(2.0 * PI) }
+    #[inline]
     fn parameter_range(&self) -> ParameterRange {
         (Bound::Included(0.0), Bound::Excluded(2.0 * PI))
     }
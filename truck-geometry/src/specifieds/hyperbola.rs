@@ -51,6 +51,13 @@ where
     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<P>) {
         algo::curve::parameter_division(self, range, tol)
     }
+    fn sample_iter(
+        &self,
+        range: (f64, f64),
+        tol: f64,
+    ) -> impl Iterator<Item = (f64, Self::Point)> {
+        algo::curve::sample_iter(self, range, tol)
+    }
 }
 
 impl SearchNearestParameter<D1> for UnitHyperbola<Point2> {
@@ -132,6 +132,7 @@ impl ParametricSurface3D for Plane {
 
 impl BoundedSurface for Plane {}
 
+/// The inverse swaps the u-axis and the v-axis, i.e. the parameters `u` and `v`.
 impl Invertible for Plane {
     #[inline(always)]
     fn inverse(&self) -> Self {
@@ -145,6 +146,11 @@ impl Invertible for Plane {
     fn invert(&mut self) { *self = self.inverse(); }
 }
 
+impl InvertibleSurface for Plane {
+    #[inline(always)]
+    fn inverse_map(&self) -> ParamMap2D { ParamMap2D::SWAP }
+}
+
 impl IncludeCurve<Line<Point3>> for Plane {
     #[inline(always)]
     fn include(&self, line: &Line<Point3>) -> bool {
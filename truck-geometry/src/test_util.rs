@@ -0,0 +1,118 @@
+use crate::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// the range of the coordinates of the generated points and vectors
+const COORDINATE_RANGE: f64 = 10.0;
+/// the maximum displacement of the inner knots from the uniform knots, relative to the span
+const KNOT_JITTER: f64 = 0.4;
+
+/// Deterministic generator of random geometries for property-based tests.
+///
+/// The same seed always generates the same sequence of geometries, so that failing cases can be
+/// reproduced. The coordinates of the points and the vectors are in `[-10, 10]`, and the knot
+/// vectors are clamped on `[0, 1]` with random distinct inner knots.
+///
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// use truck_geometry::test_util::TestRng;
+///
+/// // subcurve then concat round-trips
+/// let mut rng = TestRng::new(42);
+/// for _ in 0..10 {
+///     let curve = rng.bspline_curve(3, 8);
+///     let t = rng.parameter();
+///     let mut front = curve.clone();
+///     let back = front.cut(t);
+///     let concat = front.try_concat(&back).unwrap();
+///     for i in 0..=10 {
+///         let t = i as f64 / 10.0;
+///         assert_near!(concat.subs(t), curve.subs(t));
+///     }
+/// }
+///
+/// // the same seed generates the same geometries
+/// assert_eq!(TestRng::new(7).point3(), TestRng::new(7).point3());
+/// ```
+#[derive(Clone, Debug)]
+pub struct TestRng(StdRng);
+
+impl TestRng {
+    /// Creates the generator from the seed.
+    #[inline(always)]
+    pub fn new(seed: u64) -> Self { Self(StdRng::seed_from_u64(seed)) }
+
+    /// Returns a random number in `[0, 1)`.
+    #[inline(always)]
+    pub fn parameter(&mut self) -> f64 { self.0.random() }
+
+    /// Returns a random number in `[-10, 10)`.
+    #[inline(always)]
+    pub fn coordinate(&mut self) -> f64 { self.0.random_range(-COORDINATE_RANGE..COORDINATE_RANGE) }
+
+    /// Returns a random point whose coordinates are in `[-10, 10)`.
+    #[inline(always)]
+    pub fn point3(&mut self) -> Point3 {
+        Point3::new(self.coordinate(), self.coordinate(), self.coordinate())
+    }
+
+    /// Returns a random vector whose components are in `[-10, 10)`.
+    #[inline(always)]
+    pub fn vector3(&mut self) -> Vector3 {
+        Vector3::new(self.coordinate(), self.coordinate(), self.coordinate())
+    }
+
+    /// Returns a random unit vector.
+    pub fn unit_vector3(&mut self) -> Vector3 {
+        loop {
+            let vec = self.vector3();
+            if !vec.so_small() {
+                return vec.normalize();
+            }
+        }
+    }
+
+    /// Returns the clamped knot vector on `[0, 1]` for `len` control points of `degree`.
+    /// The inner knots are random and distinct.
+    ///
+    /// # Panics
+    /// Panics if `len <= degree`.
+    pub fn knot_vec(&mut self, degree: usize, len: usize) -> KnotVec {
+        assert!(len > degree, "the control points are too few for the degree.");
+        let division = len - degree;
+        let mut vec = vec![0.0; degree + 1];
+        vec.extend((1..division).map(|i| {
+            let jitter = self.0.random_range(-KNOT_JITTER..KNOT_JITTER);
+            (i as f64 + jitter) / division as f64
+        }));
+        vec.extend(std::iter::repeat_n(1.0, degree + 1));
+        KnotVec::from(vec)
+    }
+
+    /// Returns a random B-spline curve of `degree` with `len` control points.
+    ///
+    /// # Panics
+    /// Panics if `len <= degree`.
+    pub fn bspline_curve(&mut self, degree: usize, len: usize) -> BSplineCurve<Point3> {
+        let knot_vec = self.knot_vec(degree, len);
+        let control_points = (0..len).map(|_| self.point3()).collect();
+        BSplineCurve::new(knot_vec, control_points)
+    }
+
+    /// Returns a random B-spline surface of the degrees `(udegree, vdegree)` with
+    /// `ulen x vlen` control points.
+    ///
+    /// # Panics
+    /// Panics if `ulen <= udegree` or `vlen <= vdegree`.
+    pub fn bspline_surface(
+        &mut self,
+        (udegree, vdegree): (usize, usize),
+        (ulen, vlen): (usize, usize),
+    ) -> BSplineSurface<Point3> {
+        let knot_vecs = (self.knot_vec(udegree, ulen), self.knot_vec(vdegree, vlen));
+        let control_points = (0..ulen)
+            .map(|_| (0..vlen).map(|_| self.point3()).collect())
+            .collect();
+        BSplineSurface::new(knot_vecs, control_points)
+    }
+}
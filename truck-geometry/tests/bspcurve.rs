@@ -96,6 +96,108 @@ proptest! {
     }
 }
 
+proptest! {
+    #[test]
+    fn remove_knot_once(
+        degree in 2usize..=4,
+        div in 1usize..=4,
+        knot in 0.05f64..0.95,
+        times in 1usize..=2,
+        perturbation in prop::array::uniform3(-1f64..=1.0),
+        pts in prop::array::uniform8(prop::array::uniform3(-10f64..=10.0))
+    ) {
+        let knot_vec = KnotVec::uniform_knot(degree, div);
+        let control_points = pts[0..degree + div].iter().map(|&p| Point3::from(p)).collect();
+        let org_curve = BSplineCurve::new(knot_vec, control_points);
+        let mut bsp = org_curve.clone();
+        (0..times).for_each(|_| {
+            bsp.add_knot(knot);
+        });
+        let idx = bsp.knot_vec().floor(knot).unwrap();
+
+        // removable knot
+        prop_assert!(bsp.knot_removal_deviation(idx).so_small());
+        let removed = bsp.remove_knot_once(idx);
+        prop_assert_eq!(removed.knot_vec().len(), bsp.knot_vec().len() - 1);
+        prop_assert!(removed.near2_as_curve(&bsp));
+
+        // non-removable knot
+        let i = idx - degree / 2 - 1;
+        *bsp.control_point_mut(i) += Vector3::from(perturbation);
+        let removed = bsp.remove_knot_once(idx);
+        let deviation = bsp.knot_removal_deviation(idx);
+        let (t0, t1) = bsp.range_tuple();
+        for j in 0..=100 {
+            let t = t0 + (t1 - t0) * j as f64 / 100.0;
+            prop_assert!(removed.subs(t).distance(bsp.subs(t)) <= deviation + TOLERANCE);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn insert_knot_to(
+        degree in 2usize..=4,
+        div in 2usize..=4,
+        knot in 0.05f64..0.95,
+        on_knot in prop::bool::ANY,
+        multiplicity in 0usize..=6,
+        pts in prop::array::uniform8(prop::array::uniform3(-10f64..=10.0))
+    ) {
+        let knot_vec = KnotVec::uniform_knot(degree, div);
+        let control_points = pts[0..degree + div].iter().map(|&p| Point3::from(p)).collect();
+        let org_curve = BSplineCurve::new(knot_vec, control_points);
+        // an existing knot of the multiplicity one, or a new knot
+        let (t, current) = match on_knot {
+            true => (org_curve.knot(degree + 1), 1),
+            false => (knot, 0),
+        };
+        prop_assume!(on_knot || org_curve.knot_vec().iter().all(|u| !u.near(&t)));
+
+        let mut bsp = org_curve.clone();
+        bsp.insert_knot_to(t, multiplicity);
+        let expected = usize::clamp(multiplicity, current, degree + 1);
+        let idx = bsp.knot_vec().floor(t).unwrap();
+        match expected {
+            // no knot is inserted, and `idx` is the one of the previous knot
+            0 => prop_assert_eq!(bsp.knot_vec(), org_curve.knot_vec()),
+            _ => prop_assert_eq!(bsp.knot_vec().multiplicity(idx), expected),
+        }
+        prop_assert_eq!(bsp.knot_vec().len(), org_curve.knot_vec().len() + expected - current);
+        prop_assert!(bsp.near2_as_curve(&org_curve));
+        if expected >= degree {
+            prop_assert!(bsp.subs(t).near2(bsp.control_point(idx - degree)));
+        }
+    }
+}
+
+#[test]
+fn insert_knot_to_end() {
+    let knot_vec = KnotVec::from(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let ctrl_pts = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 2.0),
+        Vector2::new(2.0, -1.0),
+        Vector2::new(3.0, 1.0),
+    ];
+    let org_curve = BSplineCurve::new(knot_vec, ctrl_pts);
+    assert!(!org_curve.is_clamped());
+
+    let mut bspcurve = org_curve.clone();
+    bspcurve.insert_knot_to(0.0, 3).insert_knot_to(6.0, 3);
+    assert_eq!(bspcurve.knot_vec().len(), 11);
+    assert_eq!(bspcurve.knot_vec().multiplicity(0), 3);
+    assert_eq!(bspcurve.knot_vec().multiplicity(10), 3);
+    assert!(bspcurve.near2_as_curve(&org_curve));
+
+    // the clamped curve is not changed
+    let mut clamped = org_curve.clone();
+    clamped.clamp();
+    let knot_vec = clamped.knot_vec().clone();
+    clamped.insert_knot_to(0.0, 4).insert_knot_to(6.0, 2);
+    assert_eq!(clamped.knot_vec(), &knot_vec);
+}
+
 #[test]
 fn concat_negative_test() {
     let curve0 = BSplineCurve::new(
@@ -169,6 +271,42 @@ fn test_near_as_curve() {
     assert!(!bspline0.near_as_curve(&bspline2));
 }
 
+#[test]
+fn near_as_curve_with_spec() {
+    let curve = |scale: f64, bump: f64| {
+        let ctrl_pts = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 2.0, bump),
+            Point3::new(3.0, -1.0, 1.0),
+            Point3::new(4.0, 0.0, 0.0),
+        ];
+        let ctrl_pts = ctrl_pts.into_iter().map(|p| p * scale).collect();
+        BSplineCurve::new(KnotVec::uniform_knot(2, 2), ctrl_pts)
+    };
+    let relative = ToleranceSpec::new(0.0, 1.0e-9);
+    for scale in [1.0e-6, 1.0, 1.0e6] {
+        let curve0 = curve(scale, 0.0);
+        let (perturbed, distinct) = (curve(scale, 1.0e-11), curve(scale, 0.1));
+        assert!(curve0.near_as_curve_with(&perturbed, relative), "{scale}");
+        assert!(!curve0.near_as_curve_with(&distinct, relative), "{scale}");
+        // the default spec is the same as the absolute tolerance
+        let default = ToleranceSpec::default();
+        assert_eq!(
+            curve0.near_as_curve_with(&distinct, default),
+            curve0.near_as_curve(&distinct),
+        );
+        assert_eq!(
+            curve0.near_as_curve_with(&perturbed, default),
+            curve0.near_as_curve(&perturbed),
+        );
+    }
+    // the absolute tolerance fails at both extremes
+    assert!(curve(1.0e-6, 0.0).near_as_curve(&curve(1.0e-6, 0.1)));
+    assert!(!curve(1.0e6, 0.0).near_as_curve(&curve(1.0e6, 1.0e-11)));
+    assert!(curve(1.0, 0.0).near_as_curve(&curve(1.0, 1.0e-11)));
+    assert!(!curve(1.0, 0.0).near_as_curve(&curve(1.0, 0.1)));
+}
+
 #[test]
 fn test_parameter_division() {
     let knot_vec = KnotVec::uniform_knot(2, 3);
@@ -216,6 +354,217 @@ fn test_invert() {
     }
 }
 
+#[test]
+fn reversed_exactness() {
+    // non-uniform and non-symmetric knot vector on the range (-0.5, 2.0)
+    let knot_vec = KnotVec::from(vec![-0.5, -0.5, -0.5, -0.5, 0.1, 0.3, 1.4, 2.0, 2.0, 2.0, 2.0]);
+    let ctrl_pts = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 3.0, -2.0),
+        Point3::new(2.5, -1.0, 0.5),
+        Point3::new(4.0, 2.0, 1.0),
+        Point3::new(3.0, 5.0, -1.5),
+        Point3::new(6.0, 1.0, 2.0),
+        Point3::new(7.0, 0.0, 0.0),
+    ];
+    let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    let reversed = bspcurve.reversed();
+    assert_eq!(reversed.range_tuple(), (-0.5, 2.0));
+    assert_eq!(reversed.front(), bspcurve.back());
+    assert_eq!(reversed.back(), bspcurve.front());
+
+    const N: usize = 1000;
+    for i in 0..=N {
+        let t = -0.5 + 2.5 * (i as f64) / (N as f64);
+        let (pt0, pt1) = (reversed.subs(t), bspcurve.subs(1.5 - t));
+        assert!(pt0.distance(pt1) < 64.0 * f64::EPSILON, "{t}: {pt0:?} {pt1:?}");
+    }
+
+    let round_trip = reversed.reversed();
+    assert_eq!(round_trip.control_points(), bspcurve.control_points());
+    let knots = round_trip.knot_vec().iter().zip(bspcurve.knot_vec().iter());
+    knots.for_each(|(k0, k1)| assert!(f64::abs(k0 - k1) <= 4.0 * f64::EPSILON));
+    assert_eq!(reversed, bspcurve.inverse());
+}
+
+#[test]
+fn intersect_plane_touching() {
+    // z = (2t - 1)^2 - eps with control points, i.e. the parabola touches or slightly crosses the
+    // plane z = 0 at t = 0.5.
+    let tol = 1.0e-6;
+    for eps in [0.0, 0.1 * tol, -0.1 * tol] {
+        let curve = BSplineCurve::new(
+            KnotVec::bezier_knot(2),
+            vec![
+                Point3::new(0.0, 0.0, 1.0 - eps),
+                Point3::new(1.0, 0.0, -1.0 - eps),
+                Point3::new(2.0, 0.0, 1.0 - eps),
+            ],
+        );
+        let res = curve.intersect_plane(&Plane::xy(), tol);
+        assert_eq!(res.len(), 1, "{eps}: {res:?}");
+        assert!((res[0].0 - 0.5).abs() < 1.0e-2, "{eps}: {res:?}");
+        assert!(res[0].1.z.abs() < tol);
+    }
+}
+
+#[test]
+fn intersect_plane_between_samples() {
+    // the vertex of the parabola is at t = 2 / 3.7, between the samples of the parameter, and the
+    // plane is slightly above the vertex.
+    let curve = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            Point3::new(0.0, 0.0, 1.0),
+            Point3::new(1.0, 0.0, -1.0),
+            Point3::new(2.0, 0.0, 0.7),
+        ],
+    );
+    let (vertex, height) = (2.0 / 3.7, -0.3 / 3.7 + 1.0e-4);
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, height),
+        Point3::new(1.0, 0.0, height),
+        Point3::new(0.0, 1.0, height),
+    );
+    let res = curve.intersect_plane(&plane, 1.0e-6);
+    assert_eq!(res.len(), 2, "{res:?}");
+    assert!(res[0].0 < vertex && vertex < res[1].0, "{res:?}");
+    res.iter().for_each(|(_, p)| assert_near!(p.z, height));
+}
+
+proptest! {
+    #[test]
+    fn intersect_plane_random(
+        c in prop::array::uniform8(prop::array::uniform3(-10f64..10f64)),
+        normal in prop::array::uniform3(-1f64..1f64),
+    ) {
+        prop_assume!(Vector3::from(normal).magnitude() > 0.1);
+        let curve = BSplineCurve::new(
+            KnotVec::uniform_knot(3, 5),
+            c.into_iter().map(Point3::from).collect(),
+        );
+        let normal = Vector3::from(normal).normalize();
+        let a = match normal.x.abs() < 0.5 {
+            true => Vector3::unit_x().cross(normal),
+            false => Vector3::unit_y().cross(normal),
+        };
+        let (p, q) = (Point3::from_vec(a), Point3::from_vec(normal.cross(a)));
+        let plane = Plane::new(Point3::origin(), p, q);
+        let res = curve.intersect_plane(&plane, 1.0e-6);
+        for (t, p) in &res {
+            prop_assert_near!(curve.subs(*t), *p);
+            prop_assert!(p.to_vec().dot(plane.normal()).abs() < 1.0e-6);
+        }
+        // the number of sign changes on the dense samples
+        const N: usize = 1000;
+        let dist = |i: usize| curve.subs(i as f64 / N as f64).to_vec().dot(plane.normal());
+        let changes = (0..N).filter(|i| dist(*i) * dist(*i + 1) < 0.0).count();
+        prop_assert!(res.len() >= changes, "{} {changes}", res.len());
+    }
+}
+
+#[test]
+fn search_with_presearch_division() {
+    // the zigzag with 20 peaks
+    let ctrl_pts: Vec<Point2> = (0..40)
+        .map(|i| Point2::new(i as f64, if i % 2 == 0 { 0.0 } else { 4.0 }))
+        .collect();
+    let curve = BSplineCurve::new(KnotVec::uniform_knot(3, 37), ctrl_pts);
+    let t = 0.737;
+    let pt = curve.subs(t);
+
+    let res = curve.search_nearest_parameter_with_presearch(pt, None, 100, 500);
+    assert_near!(res.unwrap(), t);
+    let res = curve.search_parameter_with_presearch(pt, None, 100, 500);
+    assert_near!(res.unwrap(), t);
+    let res = curve.search_nearest_parameter_with_presearch(pt, (0.7, 0.8), 100, 10);
+    assert_near!(res.unwrap(), t);
+    // a parameter hint is not presearched
+    let res = curve.search_nearest_parameter_with_presearch(pt, 0.74, 100, 1);
+    assert_near!(res.unwrap(), t);
+
+    // the decorators pass the division through
+    let trimmed = TrimmedCurve::new(curve.clone(), (0.7, 0.8));
+    let res = trimmed.search_nearest_parameter_with_presearch(pt, None, 100, 10);
+    assert_near!(res.unwrap(), t);
+    let processor: Processor<_, Matrix3> = Processor::new(curve);
+    let res = processor.search_parameter_with_presearch(pt, None, 100, 500);
+    assert_near!(res.unwrap(), t);
+}
+
+/// the camera at `(0, 0, 5)` looking at the origin, and the viewport of 800 x 600 pixels
+fn camera() -> (Matrix4, (f64, f64)) {
+    let eye = Point3::new(0.0, 0.0, 5.0);
+    let view = Matrix4::look_at_rh(eye, Point3::origin(), Vector3::unit_y());
+    (perspective(Deg(60.0), 4.0 / 3.0, 0.1, 1000.0) * view, (800.0, 600.0))
+}
+
+#[test]
+fn parameter_division_screen_space() {
+    let (mvp, viewport) = camera();
+    let project = |p: Point3| {
+        let c = mvp * p.to_homogeneous();
+        Point2::new(
+            (c.x / c.w + 1.0) * viewport.0 / 2.0,
+            (c.y / c.w + 1.0) * viewport.1 / 2.0,
+        )
+    };
+    let ctrl_pts = vec![
+        Point3::new(-2.0, -1.0, 0.0),
+        Point3::new(-1.0, 3.0, -1.0),
+        Point3::new(0.0, -3.0, 1.0),
+        Point3::new(1.0, 3.0, 0.0),
+        Point3::new(2.0, -1.0, -2.0),
+    ];
+    let curve = BSplineCurve::new(KnotVec::uniform_knot(3, 2), ctrl_pts);
+    let pixel_tol = 0.5;
+    let div = curve.parameter_division_screen_space(mvp, viewport, pixel_tol);
+    assert_eq!((div[0], div[div.len() - 1]), curve.range_tuple());
+    div.windows(2).for_each(|w| {
+        assert!(w[0] < w[1]);
+        let (s0, s1) = (project(curve.subs(w[0])), project(curve.subs(w[1])));
+        let mid = project(curve.subs((w[0] + w[1]) / 2.0));
+        // The deviation from the chord is tested at a point near the middle of each span. The
+        // projection is not affine, so the point may slide along the chord.
+        let (chord, vec) = ((s1 - s0).normalize(), mid - s0);
+        assert!((vec - chord * vec.dot(chord)).magnitude() < 2.0 * pixel_tol);
+    });
+
+    // the further curve is divided coarsely
+    let moved = curve.transformed(Matrix4::from_translation(Vector3::new(0.0, 0.0, -50.0)));
+    let far = moved.parameter_division_screen_space(mvp, viewport, pixel_tol);
+    assert!(far.len() < div.len());
+    // the world space division does not depend on the camera
+    let (world, _) = moved.parameter_division(moved.range_tuple(), 0.01);
+    assert!(far.len() < world.len());
+}
+
+#[test]
+fn parameter_division_behind_camera() {
+    let (mvp, viewport) = camera();
+    // the curve passing through the camera, whose latter half is behind the near plane
+    let ctrl_pts = vec![
+        Point3::new(0.0, -1.0, -5.0),
+        Point3::new(1.0, 2.0, 0.0),
+        Point3::new(-1.0, 1.0, 10.0),
+        Point3::new(0.0, -1.0, 15.0),
+    ];
+    let curve = BSplineCurve::new(KnotVec::bezier_knot(3), ctrl_pts);
+    let div = curve.parameter_division_screen_space(mvp, viewport, 0.5);
+    assert_eq!((div[0], div[div.len() - 1]), curve.range_tuple());
+    assert!(div.windows(2).all(|w| w[0] < w[1]));
+    div.windows(2).for_each(|w| {
+        let (p0, p1) = (curve.subs(w[0]), curve.subs(w[1]));
+        let depth = |p: Point3| (mvp * p.to_homogeneous()).w;
+        if depth(p0) <= 0.0 || depth(p1) <= 0.0 {
+            let mid = curve.subs((w[0] + w[1]) / 2.0);
+            assert!(mid.distance(p0.midpoint(p1)) < 1.0e-3);
+        }
+    });
+    // the spans behind the camera are divided in the world space
+    assert!(div.iter().any(|t| (mvp * curve.subs(*t).to_homogeneous()).w <= 0.0));
+}
+
 #[test]
 #[ignore]
 fn bsp_bench() {
@@ -1,5 +1,5 @@
 use proptest::prelude::*;
-use truck_geometry::prelude::*;
+use truck_geometry::{errors::Error, prelude::*};
 
 #[test]
 fn test_substitution() {
@@ -243,3 +243,376 @@ proptest! {
         prop_assert!((der0 - der1).magnitude() < 0.01 * der0.magnitude());
     }
 }
+
+proptest! {
+    #[test]
+    fn test_normals_grid(
+        us in prop::collection::vec(0f64..=1.0, 1..8),
+        vs in prop::collection::vec(0f64..=1.0, 1..8),
+        (udegree, vdegree) in (1usize..=4, 1usize..=4),
+        (udiv, vdiv) in (1usize..=4, 1usize..=4),
+        pts in prop::array::uniform8(prop::array::uniform8(prop::array::uniform3(-10f64..=10.0))),
+    ) {
+        let uknot_vec = KnotVec::uniform_knot(udegree, udiv);
+        let vknot_vec = KnotVec::uniform_knot(vdegree, vdiv);
+        let control_points = pts[..udegree + udiv]
+            .iter()
+            .map(|vec| {
+                vec[..vdegree + vdiv]
+                    .iter()
+                    .map(|&p| Point3::from(p))
+                    .collect()
+            })
+            .collect::<Vec<Vec<_>>>();
+        let bsp = BSplineSurface::new((uknot_vec, vknot_vec), control_points);
+        let normals = bsp.normals_grid(&us, &vs);
+        prop_assert_eq!(normals.len(), us.len());
+        for (u, vec) in us.iter().zip(normals) {
+            prop_assert_eq!(vec.len(), vs.len());
+            for (v, normal) in vs.iter().zip(vec) {
+                let cross = bsp.uder(*u, *v).cross(bsp.vder(*u, *v));
+                match cross.so_small2() {
+                    true => prop_assert_eq!(normal, Vector3::zero()),
+                    false => prop_assert_eq!(normal, bsp.normal(*u, *v)),
+                }
+            }
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_bezier_patches(
+        (udegree, vdegree) in (1usize..=4, 1usize..=4),
+        (udiv, vdiv) in (1usize..=4, 1usize..=4),
+        pts in prop::array::uniform8(prop::array::uniform8(prop::array::uniform3(-10f64..=10.0))),
+        (s, t) in (0f64..=1.0, 0f64..=1.0),
+    ) {
+        let uknot_vec = KnotVec::uniform_knot(udegree, udiv);
+        let vknot_vec = KnotVec::uniform_knot(vdegree, vdiv);
+        let control_points = pts[..udegree + udiv]
+            .iter()
+            .map(|vec| {
+                vec[..vdegree + vdiv]
+                    .iter()
+                    .map(|&p| Point3::from(p))
+                    .collect()
+            })
+            .collect::<Vec<Vec<_>>>();
+        let bsp = BSplineSurface::new((uknot_vec, vknot_vec), control_points);
+        let patches = bsp.bezier_patches();
+        prop_assert_eq!(patches.len(), udiv);
+        for (i, row) in patches.iter().enumerate() {
+            prop_assert_eq!(row.len(), vdiv);
+            for (j, patch) in row.iter().enumerate() {
+                prop_assert_eq!(patch.degrees(), (udegree, vdegree));
+                let (u0, u1) = (i as f64 / udiv as f64, (i + 1) as f64 / udiv as f64);
+                let (v0, v1) = (j as f64 / vdiv as f64, (j + 1) as f64 / vdiv as f64);
+                prop_assert!(patch.uknot_vec().same_range(&KnotVec::from(vec![u0, u1])));
+                prop_assert!(patch.vknot_vec().same_range(&KnotVec::from(vec![v0, v1])));
+                // interior and boundary parameters of the patch
+                let (u, v) = (u0 + (u1 - u0) * s, v0 + (v1 - v0) * t);
+                for (u, v) in [(u, v), (u0, v), (u, v0), (u0, v0), (u1, v1)] {
+                    prop_assert!(patch.subs(u, v).near(&bsp.subs(u, v)));
+                }
+            }
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn normalize_parameter_ranges(
+        (udegree, vdegree) in (1usize..=4, 1usize..=4),
+        (udiv, vdiv) in (1usize..=4, 1usize..=4),
+        pts in prop::array::uniform8(prop::array::uniform8(prop::array::uniform3(-10f64..=10.0))),
+        (uscale, vscale) in (0.1f64..=10.0, 0.1f64..=10.0),
+        (umove, vmove) in (-10f64..=10.0, -10f64..=10.0),
+        (s, t) in (0f64..=1.0, 0f64..=1.0),
+    ) {
+        let mut uknot_vec = KnotVec::uniform_knot(udegree, udiv);
+        uknot_vec.transform(uscale, umove);
+        let mut vknot_vec = KnotVec::uniform_knot(vdegree, vdiv);
+        vknot_vec.transform(vscale, vmove);
+        let control_points = pts[..udegree + udiv]
+            .iter()
+            .map(|vec| {
+                vec[..vdegree + vdiv]
+                    .iter()
+                    .map(|&p| Point3::from(p))
+                    .collect()
+            })
+            .collect::<Vec<Vec<_>>>();
+        let bsp = BSplineSurface::new((uknot_vec, vknot_vec), control_points);
+        let mut normalized = bsp.clone();
+        let ((u0, u1), (v0, v1)) = normalized.normalize_parameter_ranges();
+        prop_assert_eq!(((u0, u1), (v0, v1)), bsp.range_tuple());
+        let ((a0, a1), (b0, b1)) = normalized.range_tuple();
+        prop_assert_near!(Vector4::new(a0, a1, b0, b1), Vector4::new(0.0, 1.0, 0.0, 1.0));
+        prop_assert_eq!(normalized.control_points(), bsp.control_points());
+        for (s, t) in [(s, t), (0.0, 0.0), (1.0, 1.0), (s, 0.0), (0.0, t)] {
+            let (u, v) = (u0 + (u1 - u0) * s, v0 + (v1 - v0) * t);
+            prop_assert_near!(normalized.subs(s, t), bsp.subs(u, v));
+        }
+    }
+}
+
+#[test]
+fn bezier_grid_round_trip() {
+    // already compatible: the inner knots have the multiplicities of the degrees
+    let uknot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.3, 0.3, 1.0, 1.0, 1.0]);
+    let vknot_vec = KnotVec::from(vec![0.0, 0.0, 0.5, 2.0, 2.0]);
+    let control_points: Vec<Vec<Point3>> = (0..5)
+        .map(|i| {
+            (0..3)
+                .map(|j| Point3::new(i as f64, j as f64, f64::sin((i * 3 + j) as f64)))
+                .collect()
+        })
+        .collect();
+    let bsp = BSplineSurface::new((uknot_vec, vknot_vec), control_points);
+    let patches = bsp.bezier_patches();
+    assert_eq!(patches.len(), 2);
+    assert_eq!(patches[0].len(), 2);
+    let reassembled = BSplineSurface::from_bezier_grid(patches, 1.0e-12).unwrap();
+    assert_eq!(reassembled, bsp);
+
+    let nurbs = NurbsSurface::new(BSplineSurface::new(
+        bsp.knot_vecs().clone(),
+        bsp.control_points()
+            .iter()
+            .map(|vec| vec.iter().map(|p| Vector4::new(p.x, p.y, p.z, 1.5)).collect())
+            .collect(),
+    ));
+    let patches = nurbs.bezier_patches();
+    assert_near!(patches[1][0].subs(0.6, 0.2), nurbs.subs(0.6, 0.2));
+    let reassembled = NurbsSurface::from_bezier_grid(patches, 1.0e-12).unwrap();
+    assert_eq!(reassembled, nurbs);
+}
+
+#[test]
+fn invalid_bezier_grid() {
+    let bsp = BSplineSurface::new(
+        (KnotVec::uniform_knot(2, 3), KnotVec::uniform_knot(1, 2)),
+        (0..5)
+            .map(|i| (0..3).map(|j| Point2::new(i as f64, j as f64)).collect())
+            .collect(),
+    );
+    let patches = bsp.bezier_patches();
+    assert_eq!(
+        BSplineSurface::<Point2>::from_bezier_grid(Vec::new(), 1.0e-6),
+        Err(Error::EmptyControlPoints),
+    );
+
+    let mut irregular = patches.clone();
+    irregular[1].pop();
+    assert_eq!(
+        BSplineSurface::from_bezier_grid(irregular, 1.0e-6),
+        Err(Error::IrregularControlPoints),
+    );
+
+    let mut swapped = patches.clone();
+    swapped.swap(0, 1);
+    assert_eq!(
+        BSplineSurface::from_bezier_grid(swapped, 1.0e-6),
+        Err(Error::NotBezierPatch(1, 0)),
+    );
+
+    let mut elevated = patches.clone();
+    elevated[2][1].elevate_udegree();
+    assert_eq!(
+        BSplineSurface::from_bezier_grid(elevated, 1.0e-6),
+        Err(Error::NotBezierPatch(2, 1)),
+    );
+
+    let mut moved = patches;
+    *moved[1][1].control_point_mut(0, 1) += Vector2::new(0.0, 1.0e-3);
+    assert_eq!(
+        BSplineSurface::from_bezier_grid(moved, 1.0e-6),
+        Err(Error::DisconnectedBezierPatches(1, 1)),
+    );
+}
+
+#[test]
+fn reorient_like_neighbor() {
+    let knot_vecs = (
+        KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 2.0, 2.0, 2.0]),
+        KnotVec::from(vec![1.0, 1.0, 1.0, 2.0, 3.0, 3.0, 3.0]),
+    );
+    let control_points: Vec<Vec<Point3>> = (0..4)
+        .map(|i| {
+            (0..4)
+                .map(|j| Point3::new(i as f64, j as f64, f64::sin((i * 4 + j) as f64)))
+                .collect()
+        })
+        .collect();
+    let bsp = BSplineSurface::new(knot_vecs, control_points);
+    let mut front = bsp.clone();
+    let uback = front.ucut(1.0);
+    let mut vfront = bsp.clone();
+    let vback = vfront.vcut(2.0);
+    let cases = [
+        (&front, &uback, SurfaceSide::UBack),
+        (&uback, &front, SurfaceSide::UFront),
+        (&vfront, &vback, SurfaceSide::VBack),
+        (&vback, &vfront, SurfaceSide::VFront),
+    ];
+    for (neighbor, answer, shared_edge) in cases {
+        for i in 0..8 {
+            let mut surface = answer.clone();
+            if i & 4 != 0 {
+                surface.swap_axes();
+            }
+            if i & 2 != 0 {
+                surface.uinvert();
+            }
+            if i & 1 != 0 {
+                surface.vinvert();
+            }
+            surface.knot_translate(5.0, -3.0);
+            let reoriented = surface.reorient_like(neighbor, shared_edge);
+            assert!(reoriented.near_as_surface(answer), "{shared_edge:?}, {i}");
+
+            let nurbs = NurbsSurface::<Vector4>::from(surface);
+            let neighbor = NurbsSurface::<Vector4>::from(neighbor.clone());
+            let reoriented = nurbs.reorient_like(&neighbor, shared_edge);
+            let answer = NurbsSurface::<Vector4>::from(answer.clone());
+            assert!(reoriented.near_as_surface(&answer), "{shared_edge:?}, {i}");
+        }
+    }
+}
+
+#[test]
+fn smooth_step_fixes_boundary() {
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.3, 0.6, 1.0, 1.0, 1.0]);
+    let grid = |z: fn(usize, usize) -> f64| -> Vec<Vec<Point3>> {
+        (0..5)
+            .map(|i| (0..5).map(|j| Point3::new(i as f64, j as f64, z(i, j))).collect())
+            .collect()
+    };
+
+    // a flat surface does not move
+    let flat = BSplineSurface::new((knot_vec.clone(), knot_vec.clone()), grid(|_, _| 0.0));
+    let mut surface = flat.clone();
+    assert!(surface.smooth_step(0.4, 0.01).so_small());
+    assert!(surface.near_as_surface(&flat));
+
+    let bumped = grid(|i, j| ((i * 7 + j * 3) % 4) as f64 * 0.25);
+    let original = BSplineSurface::new((knot_vec.clone(), knot_vec), bumped);
+    let mut surface = original.clone();
+    let first = surface.smooth_step(0.4, 0.01);
+    for _ in 0..30 {
+        surface.smooth_step(0.4, 0.01);
+    }
+    assert!(surface.smooth_step(0.4, 0.01) < first * 0.5);
+    for i in 0..5 {
+        for j in [0, 4] {
+            assert_eq!(surface.control_point(i, j), original.control_point(i, j));
+            assert_eq!(surface.control_point(j, i), original.control_point(j, i));
+        }
+    }
+}
+
+#[test]
+fn degenerate_edges() {
+    let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1));
+    // regular
+    let surface = BSplineSurface::new(
+        knot_vecs.clone(),
+        vec![
+            vec![Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0)],
+            vec![Point3::new(0.0, 0.5, 1.0), Point3::new(2.0, 0.5, 1.0)],
+            vec![Point3::new(0.0, 1.0, 0.0), Point3::new(2.0, 1.0, 0.0)],
+        ],
+    );
+    assert!(surface.degenerate_edges(TOLERANCE).is_empty());
+
+    // the boundary `u = 1` collapses into a point
+    let surface = BSplineSurface::new(
+        knot_vecs.clone(),
+        vec![
+            vec![Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0)],
+            vec![Point3::new(0.0, 0.5, 1.0), Point3::new(2.0, 0.5, 1.0)],
+            vec![Point3::new(1.0, 1.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+        ],
+    );
+    assert_eq!(
+        surface.degenerate_edges(TOLERANCE),
+        vec![DegenerateBoundary {
+            edge: DegenerateEdge::UBack,
+            collapse: Collapse::Point(Point3::new(1.0, 1.0, 0.0)),
+        }],
+    );
+    let ranges = surface.range_tuple();
+    assert_eq!(
+        surface.degenerate_boundaries(ranges, TOLERANCE),
+        vec![DegenerateEdge::UBack],
+    );
+
+    // the surface around the boundary `u = 0` collapses into a segment
+    let surface = BSplineSurface::new(
+        knot_vecs,
+        vec![
+            vec![Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0)],
+            vec![Point3::new(0.5, 0.0, 0.0), Point3::new(1.5, 0.0, 0.0)],
+            vec![Point3::new(0.0, 1.0, 0.0), Point3::new(2.0, 1.0, 0.0)],
+        ],
+    );
+    let degenerate = surface.degenerate_edges(TOLERANCE);
+    assert_eq!(degenerate.len(), 1);
+    assert_eq!(degenerate[0].edge, DegenerateEdge::UFront);
+    match degenerate[0].collapse {
+        Collapse::Line(p, q) => {
+            assert_near!(p, Point3::new(0.0, 0.0, 0.0));
+            assert_near!(q, Point3::new(2.0, 0.0, 0.0));
+        }
+        collapse => panic!("{collapse:?}"),
+    }
+    assert!(surface.uder(0.0, 0.5).cross(surface.vder(0.0, 0.5)).so_small());
+
+    // the nurbs surface with the same control points
+    let nurbs: NurbsSurface<Vector4> = NurbsSurface::from(surface);
+    assert_eq!(nurbs.degenerate_edges(TOLERANCE), degenerate);
+}
+
+#[test]
+#[ignore]
+fn presearch_batch_bench() {
+    const N: usize = 10_000;
+    // the per-point presearch is too slow to run for all points
+    const SINGLE: usize = 100;
+    const DIVISION: usize = 50;
+    let ctrl_pts: Vec<Vec<Point3>> = (0..20)
+        .map(|i| {
+            (0..20)
+                .map(|j| Point3::new(i as f64, j as f64, f64::sin(i as f64 * 1.3 + j as f64)))
+                .collect()
+        })
+        .collect();
+    let knot_vecs = (KnotVec::uniform_knot(3, 17), KnotVec::uniform_knot(3, 17));
+    let surface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    // the points scattered around the surface by the fractional parts of irrational multiples
+    let points: Vec<Point3> = (0..N)
+        .map(|k| {
+            let [u, v, w] = [0.618034, 0.414214, 0.732051].map(|x| (k as f64 * x).fract());
+            surface.subs(u, v) + Vector3::new(0.0, 0.0, w - 0.5)
+        })
+        .collect();
+    let ranges = surface.range_tuple();
+
+    let instant = std::time::Instant::now();
+    let single: Vec<(f64, f64)> = points[..SINGLE]
+        .iter()
+        .map(|point| algo::surface::presearch(&surface, *point, ranges, DIVISION))
+        .collect();
+    let single_cost = instant.elapsed().as_secs_f64() / SINGLE as f64;
+
+    let instant = std::time::Instant::now();
+    let batch = algo::surface::presearch_batch(&surface, &points, ranges, DIVISION);
+    let batch_cost = instant.elapsed().as_secs_f64() / N as f64;
+    assert_eq!(&batch[..SINGLE], &single);
+
+    // Each point evaluates the surface at the 51 x 51 grid without the batch, and only scans the
+    // grid with the batch.
+    let ratio = single_cost / batch_cost;
+    assert!(ratio >= 100.0, "the batch only accelerates {ratio} times per point");
+}
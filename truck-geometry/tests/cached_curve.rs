@@ -0,0 +1,163 @@
+use truck_geometry::prelude::*;
+
+type Intersection = IntersectionCurve<BSplineCurve<Point3>, Sphere, Sphere>;
+
+/// the unit circle in the xy-plane, traced by the intersection of two spheres
+fn intersection() -> Intersection {
+    let sphere0 = Sphere::new(Point3::new(0.0, 0.0, 1.0), f64::sqrt(2.0));
+    let sphere1 = Sphere::new(Point3::new(0.0, 0.0, -1.0), f64::sqrt(2.0));
+    let bsp = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 2.0, 0.0),
+            Point3::new(-1.0, 0.0, 0.0),
+        ],
+    );
+    IntersectionCurve::new(sphere0, sphere1, bsp)
+}
+
+#[test]
+fn cached_curve_is_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<CachedCurve<Intersection>>();
+    assert_send_sync::<CachedCurve<BSplineCurve<Point3>>>();
+}
+
+#[test]
+fn cached_evaluation_on_grid() {
+    const N: usize = 1000;
+    let curve = intersection();
+    let cached = CachedCurve::new(curve.clone());
+    // the first pass fills the cache, the second pass is served from the cache
+    for _ in 0..2 {
+        for i in 0..=N {
+            let t = i as f64 / N as f64;
+            assert_near!(cached.subs(t), curve.subs(t));
+            assert_near!(cached.der(t), curve.der(t));
+            assert_near!(cached.der2(t), curve.der2(t));
+            assert_near!(cached.der_n(3, t), curve.der_n(3, t));
+        }
+    }
+    assert_eq!(cached.cache_len(), 4 * (N + 1));
+
+    // the parameters within the quantization share the cached results
+    let t = 0.123456;
+    assert_eq!(cached.subs(t + TOLERANCE * 1.0e-8), cached.subs(t));
+    assert_near!(cached.subs(t + TOLERANCE * 1.0e-8), curve.subs(t + TOLERANCE * 1.0e-8));
+
+    let (params, points) = cached.parameter_division((0.0, 1.0), 0.01);
+    assert_eq!((params, points), curve.parameter_division((0.0, 1.0), 0.01));
+    assert_eq!(
+        cached.parameter_division((0.0, 1.0), 0.01),
+        curve.parameter_division((0.0, 1.0), 0.01),
+    );
+    assert_eq!(
+        cached.parameter_division((0.0, 0.5), 0.01),
+        curve.parameter_division((0.0, 0.5), 0.01),
+    );
+}
+
+#[test]
+fn cached_curve_eviction() {
+    let line = Line(Point2::new(0.0, 0.0), Point2::new(1.0, 2.0));
+    let cached = CachedCurve::new(line);
+    const N: usize = 100_000;
+    for i in 0..=N {
+        let t = i as f64 / N as f64;
+        assert_eq!(cached.subs(t), line.subs(t));
+    }
+    assert!(cached.cache_len() <= 1 << 12);
+}
+
+#[test]
+fn cached_curve_mutation() {
+    let bsp = BSplineCurve::new(
+        KnotVec::uniform_knot(2, 2),
+        vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(2.0, -1.0),
+            Point2::new(3.0, 0.0),
+        ],
+    );
+    let mut cached = CachedCurve::new(bsp.clone());
+    let _ = cached.subs(0.2);
+    assert_eq!(cached.clone().cache_len(), 0);
+
+    cached.invert();
+    assert_eq!(cached.cache_len(), 0);
+    assert_near!(cached.subs(0.2), bsp.inverse().subs(0.2));
+
+    let cached1 = cached.cut(0.5);
+    assert_near!(cached.back(), bsp.inverse().subs(0.5));
+    assert_near!(cached1.front(), bsp.inverse().subs(0.5));
+
+    let mut cached = CachedCurve::new(bsp.clone());
+    let _ = cached.subs(0.2);
+    let mat = Matrix3::from_translation(Vector2::new(1.0, 0.0));
+    cached.transform_by(mat);
+    assert_near!(cached.subs(0.2), bsp.transformed(mat).subs(0.2));
+
+    // the cache is shared among the threads
+    let cached = CachedCurve::new(bsp.clone());
+    std::thread::scope(|scope| {
+        (0..4).for_each(|_| {
+            scope.spawn(|| {
+                (0..=100).for_each(|i| {
+                    let t = i as f64 / 100.0;
+                    assert_eq!(cached.subs(t), bsp.subs(t));
+                })
+            });
+        });
+    });
+    assert_eq!(cached.cache_len(), 101);
+}
+
+/// emulation of the tessellation of the faces sharing an edge: the edge is divided and the
+/// points and the tangents at the division are evaluated for each face
+fn tessellate<C>(curve: &C, faces: usize) -> f64
+where C: ParametricCurve<Point = Point3, Vector = Vector3> + ParameterDivision1D<Point = Point3> {
+    (0..faces)
+        .map(|_| {
+            let (params, division) = curve.parameter_division((0.0, 1.0), 0.001);
+            let tangents: f64 = params.iter().map(|t| curve.der(*t).magnitude()).sum();
+            let points: f64 = params.iter().map(|t| curve.subs(*t).to_vec().magnitude()).sum();
+            tangents + points + division.len() as f64
+        })
+        .sum()
+}
+
+#[test]
+#[ignore]
+fn cached_curve_bench() {
+    const FACES: usize = 8;
+    let curve = intersection();
+
+    let instant = std::time::Instant::now();
+    let x = tessellate(&curve, FACES);
+    let uncached = instant.elapsed();
+
+    let cached = CachedCurve::new(curve);
+    let instant = std::time::Instant::now();
+    let y = tessellate(&cached, FACES);
+    let with_cache = instant.elapsed();
+    assert_near!(x, y);
+
+    let ratio = uncached.as_secs_f64() / with_cache.as_secs_f64();
+    println!("tessellate intersection curve: {}us", uncached.as_micros());
+    println!("tessellate cached intersection curve: {}us", with_cache.as_micros());
+    assert!(ratio >= 3.0, "the cache only accelerates {ratio} times");
+
+    // the overhead of the lock on a cheap curve
+    const N: usize = 100_000;
+    let line = Line(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 2.0, 3.0));
+    let cached = CachedCurve::new(line);
+    let instant = std::time::Instant::now();
+    let x: f64 = (0..N).map(|i| line.subs((i % 100) as f64 / 100.0).x).sum();
+    println!("evaluate line: {}us", instant.elapsed().as_micros());
+    let instant = std::time::Instant::now();
+    let y: f64 = (0..N).map(|i| cached.subs((i % 100) as f64 / 100.0).x).sum();
+    println!("evaluate cached line: {}us", instant.elapsed().as_micros());
+    assert_near!(x, y);
+}
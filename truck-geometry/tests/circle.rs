@@ -45,3 +45,38 @@ fn parameter_division() {
         assert!(p.to_vec().magnitude() > 0.95);
     }
 }
+
+#[test]
+fn resample_uniform() {
+    let arc = TrimmedCurve::new(UnitCircle::<Point2>::new(), (0.3, 2.5));
+    let nurbs: NurbsCurve<Vector3> = arc.to_same_geometry();
+    let pts0 = arc.resample_uniform(12, 1.0e-6);
+    let pts1 = nurbs.resample_uniform(12, 1.0e-6);
+    assert_eq!(pts0.len(), 12);
+    for (i, (p, q)) in pts0.into_iter().zip(pts1).enumerate() {
+        let t = 0.3 + 2.2 * i as f64 / 11.0;
+        assert_near!(p, Point2::new(f64::cos(t), f64::sin(t)));
+        // the NURBS curve is not parameterized by arc length, but the points coincide.
+        assert!(p.distance(q) < 1.0e-5, "{i}: {p:?} {q:?}");
+    }
+    assert!(arc.resample_uniform(0, 1.0e-6).is_empty());
+    assert_eq!(arc.resample_uniform(1, 1.0e-6), vec![arc.front()]);
+}
+
+#[test]
+fn subs_wrapped() {
+    let circle = UnitCircle::<Point2>::new();
+    for k in -2..=2 {
+        let t = 0.3 + 2.0 * PI * k as f64;
+        assert_near!(circle.wrap_parameter(t), 0.3);
+        assert_near!(circle.subs_wrapped(t), circle.subs(0.3));
+        assert_near!(circle.der_wrapped(t), circle.der(0.3));
+    }
+
+    // the non-periodic curves are clamped to the ends
+    let points = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)];
+    let line = BSplineCurve::new(KnotVec::bezier_knot(1), points);
+    assert_eq!(line.wrap_parameter(0.5), 0.5);
+    assert_near!(line.subs_wrapped(-1.0), line.front());
+    assert_near!(line.subs_wrapped(2.0), line.back());
+}
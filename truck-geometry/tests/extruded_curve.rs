@@ -0,0 +1,152 @@
+use std::f64::consts::PI;
+use std::ops::Bound;
+use truck_geometry::prelude::*;
+
+type ExtrudedArc = ExtrudedCurve<TrimmedCurve<UnitCircle<Point3>>, Vector3>;
+
+const VECTOR: Vector3 = Vector3::new(0.0, 600.0, 800.0);
+
+fn arc() -> TrimmedCurve<UnitCircle<Point3>> {
+    TrimmedCurve::new(UnitCircle::new(), (0.0, PI / 2.0))
+}
+
+/// the maximum ratio of the sides of the cells in the space
+fn max_aspect_ratio(surface: &ExtrudedArc, (udiv, vdiv): &(Vec<f64>, Vec<f64>)) -> f64 {
+    let cells = udiv.windows(2).flat_map(|u| vdiv.windows(2).map(move |v| (u, v)));
+    cells.fold(0.0, |max, (u, v)| {
+        let p = surface.subs(u[0], v[0]);
+        let a = p.distance(surface.subs(u[1], v[0]));
+        let b = p.distance(surface.subs(u[0], v[1]));
+        f64::max(max, f64::max(a / b, b / a))
+    })
+}
+
+/// the minimum number of the trials for the convergence, or `MAX_TRIALS + 1` if not converged
+fn trials_to_converge(surface: &ExtrudedArc, point: Point3, hint: (f64, f64)) -> usize {
+    const MAX_TRIALS: usize = 32;
+    (0..=MAX_TRIALS)
+        .find(|trials| {
+            let res = surface.search_parameter(point, hint, *trials);
+            res.is_some_and(|(u, v)| surface.subs(u, v).near(&point))
+        })
+        .unwrap_or(MAX_TRIALS + 1)
+}
+
+#[test]
+fn scaled_parameter() {
+    let unscaled = ExtrudedCurve::by_extrusion(arc(), VECTOR);
+    let scaled = ExtrudedCurve::by_extrusion_scaled(arc(), VECTOR);
+    assert_eq!(unscaled.range_tuple().1, (0.0, 1.0));
+    assert_eq!(scaled.range_tuple().1, (0.0, 1000.0));
+    assert_near!(scaled.extruding_vector(), VECTOR);
+    assert_near!(scaled.vder(0.3, 0.0), VECTOR / 1000.0);
+    for i in 0..=8 {
+        let u = PI / 2.0 * i as f64 / 8.0;
+        for j in 0..=8 {
+            let v = j as f64 / 8.0;
+            assert_near!(scaled.subs(u, 1000.0 * v), unscaled.subs(u, v));
+        }
+    }
+
+    // the transformation keeps `v` the arc length
+    let curve: NurbsCurve<Vector4> = arc().to_same_geometry();
+    let scaled = ExtrudedCurve::by_extrusion_scaled(curve, VECTOR);
+    let mut transformed = scaled.clone();
+    transformed.transform_by(Matrix4::from_scale(2.0));
+    assert_eq!(transformed.range_tuple().1, (0.0, 2000.0));
+    assert_near!(transformed.subs(0.3, 2000.0), scaled.subs(0.3, 1000.0) * 2.0);
+}
+
+#[test]
+fn scaled_division() {
+    let unscaled = ExtrudedCurve::by_extrusion(arc(), VECTOR);
+    let scaled = ExtrudedCurve::by_extrusion_scaled(arc(), VECTOR);
+    let division0 = unscaled.parameter_division(unscaled.range_tuple(), 0.01);
+    let division1 = scaled.parameter_division(scaled.range_tuple(), 0.01);
+    assert_eq!(division0.0, division1.0);
+    assert_eq!(division0.1.len(), 2);
+    assert_eq!(division1.1[0], 0.0);
+    assert_eq!(division1.1[division1.1.len() - 1], 1000.0);
+
+    let ratio0 = max_aspect_ratio(&unscaled, &division0);
+    let ratio1 = max_aspect_ratio(&scaled, &division1);
+    assert!(ratio0 > 1000.0, "{ratio0}");
+    assert!(ratio1 < 20.0, "{ratio1}");
+}
+
+#[test]
+fn scaled_search_parameter() {
+    let unscaled = ExtrudedCurve::by_extrusion(arc(), VECTOR);
+    let scaled = ExtrudedCurve::by_extrusion_scaled(arc(), VECTOR);
+    for i in 0..=8 {
+        let u = PI / 2.0 * i as f64 / 8.0;
+        for j in 0..=8 {
+            let v = j as f64 / 8.0;
+            let point = unscaled.subs(u, v);
+            let trials0 = trials_to_converge(&unscaled, point, (PI / 4.0, 0.5));
+            let trials1 = trials_to_converge(&scaled, point, (PI / 4.0, 500.0));
+            // the scaling of `v` does not slow down the convergence
+            assert!(trials1 <= usize::min(trials0 + 1, 10), "{u} {v} {trials0} {trials1}");
+            let (u1, v1) = scaled.search_parameter(point, (PI / 4.0, 500.0), 10).unwrap();
+            assert_near!(Vector2::new(u1, v1), Vector2::new(u, 1000.0 * v));
+        }
+    }
+}
+
+/// a wave along the x-axis whose amplitude grows with `x`
+#[derive(Clone, Copy, Debug)]
+struct Wave;
+
+impl Wave {
+    const K: f64 = 10.0 * PI;
+    fn amplitude(t: f64) -> f64 { 0.05 + 0.3 * t }
+}
+
+impl ParametricCurve for Wave {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, t: f64) -> Point3 {
+        Point3::new(t, Self::amplitude(t) * f64::cos(Self::K * t), 0.0)
+    }
+    fn der(&self, t: f64) -> Vector3 {
+        let (s, c) = f64::sin_cos(Self::K * t);
+        Vector3::new(1.0, 0.3 * c - Self::amplitude(t) * Self::K * s, 0.0)
+    }
+    fn der2(&self, t: f64) -> Vector3 {
+        let (s, c) = f64::sin_cos(Self::K * t);
+        let y = -0.6 * Self::K * s - Self::amplitude(t) * Self::K * Self::K * c;
+        Vector3::new(0.0, y, 0.0)
+    }
+    fn der_n(&self, n: usize, t: f64) -> Vector3 {
+        match n {
+            0 => self.subs(t).to_vec(),
+            1 => self.der(t),
+            2 => self.der2(t),
+            _ => unimplemented!(),
+        }
+    }
+    fn parameter_range(&self) -> ParameterRange { (Bound::Included(0.0), Bound::Included(1.0)) }
+}
+
+impl BoundedCurve for Wave {}
+
+#[test]
+fn search_nearest_parameter_with_fine_presearch() {
+    let surface = ExtrudedCurve::by_extrusion(Wave, Vector3::unit_z());
+    let point = Point3::new(0.55, 0.4, 0.5);
+    // the global nearest point is on the crest around `x = 0.6`
+    let nearest = (0..=10000)
+        .map(|i| surface.subs(i as f64 / 10000.0, 0.5).distance(point))
+        .fold(f64::INFINITY, f64::min);
+
+    // The grid of 4 parts starts from the crest around `x = 0.8`, and stays there.
+    let (u, v) = surface.search_nearest_parameter_with_presearch(point, None, 100, 4).unwrap();
+    assert!(u > 0.75);
+    assert!(surface.subs(u, v).distance(point) > nearest + 0.05);
+
+    let (u, v) = surface.search_nearest_parameter_with_presearch(point, None, 100, 100).unwrap();
+    assert!(f64::abs(u - 0.6) < 0.01);
+    assert!(surface.subs(u, v).distance(point) < nearest + 1.0e-6);
+    let res = surface.search_nearest_parameter(point, None, 100).unwrap();
+    assert_near!(surface.subs(res.0, res.1), surface.subs(u, v));
+}
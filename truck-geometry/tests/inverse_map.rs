@@ -0,0 +1,114 @@
+use proptest::{prelude::*, test_runner::TestCaseResult};
+use std::f64::consts::PI;
+use truck_geometry::prelude::*;
+
+const N: usize = 8;
+
+/// Checks the relations of [`InvertibleSurface`] on the grid in the interior of the range.
+fn check_inverse_map<S>(old: &S, range: ((f64, f64), (f64, f64))) -> TestCaseResult
+where S: InvertibleSurface + ParametricSurface3D {
+    let ((u0, u1), (v0, v1)) = range;
+    let (new, map) = old.inverse_with_map();
+    for i in 0..N {
+        for j in 0..N {
+            let u = u0 + (u1 - u0) * (i as f64 + 0.5) / N as f64;
+            let v = v0 + (v1 - v0) * (j as f64 + 0.5) / N as f64;
+            let (s, t) = map.apply(u, v);
+            prop_assert_near!(new.subs(s, t), old.subs(u, v));
+            // the normals are unstable at the singular points
+            if !old.uder(u, v).cross(old.vder(u, v)).so_small() {
+                prop_assert_near!(new.normal(s, t), -old.normal(u, v));
+            }
+        }
+    }
+    let round_trip = map.then(new.inverse_map());
+    prop_assert!(!round_trip.swap);
+    prop_assert_near!(Vector2::from(round_trip.u), Vector2::new(1.0, 0.0));
+    prop_assert_near!(Vector2::from(round_trip.v), Vector2::new(1.0, 0.0));
+    let (s, t) = map.apply(0.3, 0.7);
+    let (u, v) = map.inverse().apply(s, t);
+    prop_assert_near!(Vector2::new(u, v), Vector2::new(0.3, 0.7));
+    Ok(())
+}
+
+fn bspline_surface(pts: [[[f64; 3]; 4]; 3]) -> BSplineSurface<Point3> {
+    let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::uniform_knot(2, 2));
+    let control_points = pts
+        .iter()
+        .map(|row| row.iter().map(|p| Point3::from(*p)).collect())
+        .collect();
+    BSplineSurface::new(knot_vecs, control_points)
+}
+
+fn bspline_curve(pts: [[f64; 3]; 4]) -> BSplineCurve<Point3> {
+    let knot_vec = KnotVec::from(vec![-1.0, -1.0, -1.0, 0.5, 2.0, 2.0, 2.0]);
+    BSplineCurve::new(knot_vec, pts.iter().map(|p| Point3::from(*p)).collect())
+}
+
+proptest! {
+    #[test]
+    fn splines(
+        pts in prop::array::uniform3(prop::array::uniform4(prop::array::uniform3(-10f64..=10.0))),
+        weights in prop::array::uniform12(0.5f64..=2.0),
+    ) {
+        let bsp = bspline_surface(pts);
+        check_inverse_map(&bsp, bsp.range_tuple())?;
+
+        let control_points = (0..3)
+            .map(|i| {
+                (0..4)
+                    .map(|j| {
+                        let (p, w) = (bsp.control_point(i, j), weights[4 * i + j]);
+                        (p.to_vec() * w).extend(w)
+                    })
+                    .collect()
+            })
+            .collect();
+        let nurbs = NurbsSurface::new(BSplineSurface::new(bsp.knot_vecs().clone(), control_points));
+        check_inverse_map(&nurbs, nurbs.range_tuple())?;
+    }
+
+    #[test]
+    fn plane(pts in prop::array::uniform3(prop::array::uniform3(-10f64..=10.0))) {
+        let [p, q, r] = pts.map(Point3::from);
+        check_inverse_map(&Plane::new(p, q, r), ((-1.0, 2.0), (-1.0, 2.0)))?;
+    }
+
+    #[test]
+    fn extruded_curve(
+        pts in prop::array::uniform4(prop::array::uniform3(-10f64..=10.0)),
+        vector in prop::array::uniform3(-10f64..=10.0),
+    ) {
+        let surface = ExtrudedCurve::by_extrusion(bspline_curve(pts), Vector3::from(vector));
+        check_inverse_map(&surface, surface.range_tuple())?;
+    }
+
+    #[test]
+    fn revoluted_curve(
+        pts in prop::array::uniform4(prop::array::uniform3(-10f64..=10.0)),
+        origin in prop::array::uniform3(-10f64..=10.0),
+        axis in prop::array::uniform3(-1f64..=1.0),
+    ) {
+        let axis = Vector3::from(axis);
+        prop_assume!(!axis.so_small());
+        let curve = bspline_curve(pts);
+        let surface = RevolutedCurve::by_revolution(curve, Point3::from(origin), axis);
+        check_inverse_map(&surface, ((-1.0, 2.0), (0.0, 2.0 * PI)))?;
+
+        let processor = Processor::<_, Matrix4>::new(surface);
+        check_inverse_map(&processor, ((-1.0, 2.0), (0.0, 2.0 * PI)))?;
+    }
+
+    #[test]
+    fn analytic(
+        center in prop::array::uniform3(-10f64..=10.0),
+        radii in prop::array::uniform2(0.5f64..=2.0),
+    ) {
+        let center = Point3::from(center);
+        let sphere = Processor::<_, Matrix4>::new(Sphere::new(center, radii[0]));
+        check_inverse_map(&sphere, ((0.0, PI), (0.0, 2.0 * PI)))?;
+        let torus = Torus::new(center, radii[0] + radii[1], radii[1]);
+        let torus = Processor::<_, Matrix4>::new(torus);
+        check_inverse_map(&torus, ((0.0, 2.0 * PI), (0.0, 2.0 * PI)))?;
+    }
+}
@@ -0,0 +1,131 @@
+use std::f64::consts::PI;
+use truck_geometry::errors::Error;
+use truck_geometry::prelude::*;
+
+/// The point at the angle `PI / 3` is marked, and moved to the parameter `0.5`.
+fn reparametrized_circle() -> ReparametrizedCurve<UnitCircle<Point2>> {
+    let map = ParameterMap::try_new(
+        vec![0.0, 0.25, 0.5, 1.0],
+        vec![0.0, 0.1, PI / 3.0, 2.0 * PI],
+    )
+    .unwrap();
+    ReparametrizedCurve::new(UnitCircle::new(), map)
+}
+
+fn curve() -> BSplineCurve<Point3> {
+    BSplineCurve::new(
+        KnotVec::uniform_knot(2, 3),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 2.0, 0.0),
+            Point3::new(2.0, -1.0, 1.0),
+            Point3::new(3.0, 1.0, 0.0),
+            Point3::new(4.0, 0.0, 2.0),
+        ],
+    )
+}
+
+#[test]
+fn reparametrized_circle_geometry() {
+    let circle = UnitCircle::<Point2>::new();
+    let curve = reparametrized_circle();
+    assert_eq!(curve.range_tuple(), (0.0, 1.0));
+    assert_near!(curve.subs(0.5), circle.subs(PI / 3.0));
+    assert_near!(curve.front(), circle.front());
+    assert_near!(curve.back(), circle.back());
+
+    for i in 0..=100 {
+        let s = i as f64 / 100.0;
+        let pt = curve.subs(s);
+        assert_near!(pt.to_vec().magnitude(), 1.0);
+        assert_near!(pt, circle.subs(curve.map().subs(s)));
+    }
+    for i in 1..100 {
+        let t = 2.0 * PI * i as f64 / 100.0;
+        let s = curve.search_parameter(circle.subs(t), None, 100).unwrap();
+        assert_near!(curve.map().subs(s), t);
+    }
+}
+
+#[test]
+fn reparametrized_derivatives() {
+    const EPS: f64 = 1.0e-5;
+    let curve = reparametrized_circle();
+    for i in 1..100 {
+        let s = i as f64 / 100.0;
+        let der = (curve.subs(s + EPS) - curve.subs(s - EPS)) / (2.0 * EPS);
+        assert!((curve.der(s) - der).magnitude() < 1.0e-4 * (1.0 + der.magnitude()));
+        assert_near!(curve.der_n(1, s), curve.der(s));
+        // the second derivative of the map jumps at the breakpoints
+        if [25, 50].contains(&i) {
+            continue;
+        }
+        let der2 = (curve.der(s + EPS) - curve.der(s - EPS)) / (2.0 * EPS);
+        assert!((curve.der2(s) - der2).magnitude() < 1.0e-4 * (1.0 + der2.magnitude()));
+        assert_near!(curve.der_n(2, s), curve.der2(s));
+        let der3 = (curve.der2(s + EPS) - curve.der2(s - EPS)) / (2.0 * EPS);
+        let diff = curve.der_n(3, s) - der3;
+        assert!(diff.magnitude() < 1.0e-3 * (1.0 + der3.magnitude()));
+    }
+}
+
+#[test]
+fn reparametrized_cut_and_inverse() {
+    let map = ParameterMap::try_new(vec![0.0, 2.0, 3.0], vec![0.0, 0.3, 1.0]).unwrap();
+    let curve = ReparametrizedCurve::new(curve(), map);
+
+    let inverse = curve.inverse();
+    assert_eq!(inverse.range_tuple(), (0.0, 3.0));
+    for i in 0..=30 {
+        let s = i as f64 / 10.0;
+        assert_near!(inverse.subs(s), curve.subs(3.0 - s));
+    }
+
+    let mut former = curve.clone();
+    let latter = former.cut(1.5);
+    assert_eq!(former.range_tuple(), (0.0, 1.5));
+    assert_eq!(latter.range_tuple(), (1.5, 3.0));
+    for i in 0..=30 {
+        let s = i as f64 / 10.0;
+        match s < 1.5 {
+            true => assert_near!(former.subs(s), curve.subs(s)),
+            false => assert_near!(latter.subs(s), curve.subs(s)),
+        }
+    }
+}
+
+#[test]
+fn composed_reparametrization() {
+    let curve = reparametrized_circle();
+    let map = ParameterMap::try_new(vec![0.0, 1.0, 2.0], vec![0.0, 0.8, 1.0]).unwrap();
+    let composed = curve.compose(&map);
+    assert_eq!(composed.range_tuple(), (0.0, 2.0));
+    assert_near!(composed.subs(1.0), curve.subs(0.8));
+    for i in 0..=100 {
+        let s = 2.0 * i as f64 / 100.0;
+        assert_near!(composed.subs(s).to_vec().magnitude(), 1.0);
+        let t = composed.map().subs(s);
+        assert!(f64::abs(t - curve.map().subs(map.subs(s))) < 1.0e-3);
+        assert!(composed.map().der(s) > 0.0);
+    }
+}
+
+#[test]
+fn non_monotone_maps() {
+    assert_eq!(
+        ParameterMap::try_new(vec![0.0, 0.5, 1.0], vec![0.0, 2.0, 1.0]),
+        Err(Error::NonMonotoneParameterMap(0.5, 1.0)),
+    );
+    assert_eq!(
+        ParameterMap::try_new(vec![0.0, 0.5, 0.5], vec![0.0, 1.0, 2.0]),
+        Err(Error::NonMonotoneParameterMap(0.5, 0.5)),
+    );
+    let (params, values) = (vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 2.0]);
+    assert_eq!(
+        ParameterMap::try_from_hermite(params, values, vec![1.0, -0.5, 1.0]),
+        Err(Error::NonMonotoneParameterMap(0.0, 1.0)),
+    );
+    assert_eq!(ParameterMap::try_new(vec![0.0], vec![0.0]), Err(Error::ZeroRange));
+    let res = ParameterMap::try_new(vec![0.0, 1.0], vec![0.0]);
+    assert_eq!(res, Err(Error::DifferentLength));
+}
@@ -176,3 +176,141 @@ fn include_curve_abnormal1() {
     let line = BSplineCurve::new(KnotVec::bezier_knot(1), vec![pt0, pt1]);
     assert!(!surface.include(&line));
 }
+
+fn assert_pole_limit<S: ParametricSurface3D>(surface: &S, (u0, u1): (f64, f64)) {
+    for (pole, sign) in [(u0, 1.0), (u1, -1.0)] {
+        for j in 0..8 {
+            let v = 2.0 * PI * j as f64 / 8.0 + 0.1;
+            let limit = surface.normal(pole, v);
+            assert!(limit.x.is_finite() && limit.y.is_finite() && limit.z.is_finite());
+            assert_near!(limit.magnitude(), 1.0);
+            let mut errors = (1..=6).map(|k| {
+                let u = pole + sign * (u1 - u0) * f64::powi(0.1, k);
+                let normal = surface.normal(u, v);
+                assert!(normal.x.is_finite() && normal.y.is_finite() && normal.z.is_finite());
+                normal.distance(limit)
+            });
+            let first = errors.next().unwrap();
+            let last = errors.last().unwrap();
+            assert!(last < 1.0e-5 && last <= first + 1.0e-10, "{pole} {v}: {first} {last}");
+        }
+    }
+}
+
+#[test]
+fn sphere_pole_normal() {
+    let arc = TrimmedCurve::new(UnitCircle::<Point3>::new(), (PI / 2.0, 1.5 * PI));
+    let arc: NurbsCurve<Vector4> = arc.to_same_geometry();
+    // the meridian from the north pole to the south pole
+    let arc = arc.transformed(Matrix4::from_angle_x(Rad(PI / 2.0)));
+    let sphere = RevolutedCurve::by_revolution(arc, Point3::origin(), Vector3::unit_z());
+    let range = sphere.range_tuple().0;
+    assert_pole_limit(&sphere, range);
+
+    // the limits are the axis, independent of `v`
+    for j in 0..8 {
+        let v = 2.0 * PI * j as f64 / 8.0;
+        let (north, south) = (sphere.normal(range.0, v), sphere.normal(range.1, v));
+        assert_near!(north, Vector3::unit_z());
+        assert_near!(south, -Vector3::unit_z());
+        assert_near!(north, sphere.subs(range.0, v).to_vec());
+        assert_near!(south, sphere.subs(range.1, v).to_vec());
+    }
+}
+
+#[test]
+fn cone_apex_normal() {
+    let line = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Point3::new(0.0, 0.0, 1.0), Point3::new(1.0, 0.0, 0.0)],
+    );
+    let cone = RevolutedCurve::by_revolution(line, Point3::origin(), Vector3::unit_z());
+    assert_pole_limit(&cone, (0.0, 1.0));
+
+    // the normal at the apex is the limit along the generating line
+    for j in 0..8 {
+        let v = 2.0 * PI * j as f64 / 8.0;
+        assert_near!(cone.normal(0.0, v), cone.normal(0.5, v));
+    }
+}
+
+#[test]
+fn sphere_degenerate_edges() {
+    let arc = TrimmedCurve::new(UnitCircle::<Point3>::new(), (PI / 2.0, 1.5 * PI));
+    let arc: NurbsCurve<Vector4> = arc.to_same_geometry();
+    let arc = arc.transformed(Matrix4::from_angle_x(Rad(PI / 2.0)));
+    let sphere = RevolutedCurve::by_revolution(arc, Point3::origin(), Vector3::unit_z());
+    let degenerate = sphere.degenerate_edges(TOLERANCE);
+    assert_eq!(degenerate.len(), 2);
+    assert_eq!(degenerate[0].edge, DegenerateEdge::UFront);
+    assert_eq!(degenerate[1].edge, DegenerateEdge::UBack);
+    match (degenerate[0].collapse, degenerate[1].collapse) {
+        (Collapse::Point(north), Collapse::Point(south)) => {
+            assert_near!(north, Point3::new(0.0, 0.0, 1.0));
+            assert_near!(south, Point3::new(0.0, 0.0, -1.0));
+        }
+        res => panic!("{res:?}"),
+    }
+    // the same as the collapsed boundaries of the whole parameter rectangle
+    let ranges = sphere.range_tuple();
+    let sampled = sphere.degenerate_boundaries(ranges, TOLERANCE);
+    let edges: Vec<DegenerateEdge> = degenerate.iter().map(|d| d.edge).collect();
+    assert_eq!(edges, sampled);
+
+    // a band off the poles has no degenerate edges
+    let (u0, u1) = ranges.0;
+    let band = ((u0 + 0.1, u1 - 0.1), ranges.1);
+    assert!(sphere.degenerate_boundaries(band, TOLERANCE).is_empty());
+}
+
+#[test]
+fn cylinder_degenerate_edges() {
+    let line = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 1.0)],
+    );
+    let cylinder = RevolutedCurve::by_revolution(line, Point3::origin(), Vector3::unit_z());
+    assert!(cylinder.degenerate_edges(TOLERANCE).is_empty());
+}
+
+#[test]
+fn parameter_division() {
+    let pt0 = Point3::new(1.0, 0.0, 0.0);
+    let pt1 = Point3::new(3.0, 2.0, 0.0);
+    let curve = BSplineCurve::new(KnotVec::bezier_knot(1), vec![pt0, pt1]);
+    let surface = RevolutedCurve::by_revolution(curve, Point3::origin(), Vector3::unit_y());
+    let tol = 0.01;
+    let (udiv, vdiv) = surface.parameter_division(((0.0, 1.0), (0.0, 2.0 * PI)), tol);
+    // the number of the arcs is the minimum one for the outer circle of radius 3
+    let delta = 2.0 * f64::acos(1.0 - tol / 6.0);
+    assert_eq!(vdiv.len() - 1, f64::ceil(2.0 * PI / delta) as usize);
+
+    // the bilinear interpolations of the cells are within the tolerance
+    udiv.windows(2).for_each(|u| {
+        vdiv.windows(2).for_each(|v| {
+            let corners = [
+                surface.subs(u[0], v[0]),
+                surface.subs(u[1], v[0]),
+                surface.subs(u[0], v[1]),
+                surface.subs(u[1], v[1]),
+            ];
+            (0..=4).for_each(|i| {
+                (0..=4).for_each(|j| {
+                    let (s, t) = (i as f64 / 4.0, j as f64 / 4.0);
+                    let bilinear = corners[0].to_vec() * (1.0 - s) * (1.0 - t)
+                        + corners[1].to_vec() * s * (1.0 - t)
+                        + corners[2].to_vec() * (1.0 - s) * t
+                        + corners[3].to_vec() * s * t;
+                    let pt = surface.subs(u[0] + (u[1] - u[0]) * s, v[0] + (v[1] - v[0]) * t);
+                    assert!(pt.distance(Point3::from_vec(bilinear)) <= tol);
+                });
+            });
+        });
+    });
+
+    // the generating curve on the axis
+    let curve = BSplineCurve::new(KnotVec::bezier_knot(1), vec![Point3::origin(), pt0]);
+    let surface = RevolutedCurve::by_revolution(curve, Point3::origin(), Vector3::unit_x());
+    let (_, vdiv) = surface.parameter_division(((0.0, 1.0), (0.0, 2.0 * PI)), tol);
+    assert_eq!(vdiv.len(), 3);
+}
@@ -0,0 +1,83 @@
+use std::f64::consts::PI;
+use truck_geometry::prelude::*;
+use algo::surface::{proximity, SurfaceProximity};
+
+const SPHERE_RANGE: ((f64, f64), (f64, f64)) = ((0.0, PI), (0.0, 2.0 * PI));
+
+#[test]
+fn disjoint_spheres() {
+    let (center0, center1) = (Point3::new(0.0, 0.0, 0.0), Point3::new(3.0, 0.5, -0.2));
+    let sphere0 = Sphere::new(center0, 1.0);
+    let sphere1 = Sphere::new(center1, 1.5);
+    let res = proximity(&sphere0, &sphere1, SPHERE_RANGE, SPHERE_RANGE, 1.0e-6);
+    let SurfaceProximity::Disjoint {
+        distance,
+        witness_pair: (p, q),
+    } = res
+    else {
+        panic!("{res:?}");
+    };
+    let dir = (center1 - center0).normalize();
+    assert_near!(distance, center0.distance(center1) - 2.5);
+    assert_near!(p, center0 + dir);
+    assert_near!(q, center1 - dir * 1.5);
+}
+
+#[test]
+fn tangent_spheres() {
+    let dir = Vector3::new(1.0, 1.0, 1.0).normalize();
+    let sphere0 = Sphere::new(Point3::origin(), 1.0);
+    let sphere1 = Sphere::new(Point3::from_vec(dir * 3.0), 2.0);
+    let res = proximity(&sphere0, &sphere1, SPHERE_RANGE, SPHERE_RANGE, 1.0e-3);
+    let SurfaceProximity::Tangential { contact_samples } = res else {
+        panic!("{res:?}");
+    };
+    assert_eq!(contact_samples.len(), 1);
+    assert!(contact_samples[0].distance(Point3::from_vec(dir)) < 1.0e-4);
+}
+
+#[test]
+fn overlapping_spheres() {
+    let (center0, center1) = (Point3::new(0.0, 0.0, 0.0), Point3::new(1.2, 0.3, 0.1));
+    let sphere0 = Sphere::new(center0, 1.0);
+    let sphere1 = Sphere::new(center1, 0.8);
+    let res = proximity(&sphere0, &sphere1, SPHERE_RANGE, SPHERE_RANGE, 1.0e-6);
+    let SurfaceProximity::Intersecting { seed_points } = res else {
+        panic!("{res:?}");
+    };
+    assert!(!seed_points.is_empty());
+    for point in seed_points {
+        assert!(f64::abs(point.distance(center0) - 1.0) < 1.0e-5);
+        assert!(f64::abs(point.distance(center1) - 0.8) < 1.0e-5);
+    }
+}
+
+#[test]
+fn plane_tangent_to_cylinder() {
+    let line = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 2.0)],
+    );
+    let cylinder = RevolutedCurve::by_revolution(line, Point3::origin(), Vector3::unit_z());
+    let cylinder_range = ((0.0, 1.0), (0.0, 2.0 * PI));
+    let plane = Plane::new(
+        Point3::new(-2.0, 1.0, -1.0),
+        Point3::new(2.0, 1.0, -1.0),
+        Point3::new(-2.0, 1.0, 3.0),
+    );
+    let plane_range = ((0.0, 1.0), (0.0, 1.0));
+    let res = proximity(&plane, &cylinder, plane_range, cylinder_range, 1.0e-3);
+    let SurfaceProximity::Tangential { contact_samples } = res else {
+        panic!("{res:?}");
+    };
+    assert!(!contact_samples.is_empty());
+    for point in contact_samples {
+        assert!(point.x.abs() < 1.0e-4 && (point.y - 1.0).abs() < 1.0e-4, "{point:?}");
+        assert!(-TOLERANCE < point.z && point.z < 2.0 + TOLERANCE);
+    }
+
+    // moving the plane slightly into the cylinder
+    let plane = plane.transformed(Matrix4::from_translation(Vector3::new(0.0, -0.01, 0.0)));
+    let res = proximity(&plane, &cylinder, plane_range, cylinder_range, 1.0e-3);
+    assert!(matches!(res, SurfaceProximity::Intersecting { .. }), "{res:?}");
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "test-util")]
+
+use truck_geometry::{prelude::*, test_util::TestRng};
+
+#[test]
+fn deterministic() {
+    let (mut rng0, mut rng1) = (TestRng::new(0), TestRng::new(0));
+    assert_eq!(rng0.vector3(), rng1.vector3());
+    assert_eq!(rng0.bspline_curve(2, 5), rng1.bspline_curve(2, 5));
+    assert_eq!(
+        rng0.bspline_surface((2, 3), (4, 5)),
+        rng1.bspline_surface((2, 3), (4, 5)),
+    );
+    assert_ne!(TestRng::new(1).point3(), TestRng::new(2).point3());
+}
+
+#[test]
+fn sizes() {
+    let mut rng = TestRng::new(1);
+    for degree in 1..=4 {
+        for len in degree + 1..degree + 6 {
+            let curve = rng.bspline_curve(degree, len);
+            assert_eq!(curve.degree(), degree);
+            assert_eq!(curve.control_points().len(), len);
+            assert_eq!(curve.range_tuple(), (0.0, 1.0));
+            let (knots, mults) = curve.knot_vec().to_single_multi();
+            assert_eq!(knots.len(), len - degree + 1);
+            assert!(mults[1..mults.len() - 1].iter().all(|mult| *mult == 1));
+        }
+    }
+    let surface = rng.bspline_surface((2, 3), (4, 6));
+    assert_eq!((surface.udegree(), surface.vdegree()), (2, 3));
+    assert_eq!(surface.control_points().len(), 4);
+    assert_eq!(surface.control_points()[0].len(), 6);
+    (0..10).for_each(|_| assert!(rng.unit_vector3().magnitude().near(&1.0)));
+}
+
+#[test]
+fn subsurface_round_trip() {
+    let mut rng = TestRng::new(2);
+    for _ in 0..10 {
+        let surface = rng.bspline_surface((3, 2), (6, 5));
+        let (u, v) = (rng.parameter(), rng.parameter());
+        let mut front = surface.clone();
+        let back = front.ucut(u);
+        for _ in 0..10 {
+            let v = rng.parameter();
+            assert_near!(front.subs(u / 2.0, v), surface.subs(u / 2.0, v));
+            assert_near!(back.subs((u + 1.0) / 2.0, v), surface.subs((u + 1.0) / 2.0, v));
+        }
+        let mut front = surface.clone();
+        let back = front.vcut(v);
+        for _ in 0..10 {
+            let u = rng.parameter();
+            assert_near!(front.subs(u, v / 2.0), surface.subs(u, v / 2.0));
+            assert_near!(back.subs(u, (v + 1.0) / 2.0), surface.subs(u, (v + 1.0) / 2.0));
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use truck_geometry::prelude::*;
+
+fn curve() -> BSplineCurve<Point3> {
+    BSplineCurve::new(
+        KnotVec::uniform_knot(2, 3),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 2.0, 0.0),
+            Point3::new(2.0, -1.0, 1.0),
+            Point3::new(3.0, 1.0, 0.0),
+            Point3::new(4.0, 0.0, 2.0),
+        ],
+    )
+}
+
+#[test]
+fn trimmed_evaluation() {
+    let curve = curve();
+    let trimmed = TrimmedCurve::new(curve.clone(), (0.2, 0.7));
+    assert_eq!(trimmed.range_tuple(), (0.2, 0.7));
+    assert_eq!(trimmed.front(), curve.subs(0.2));
+    assert_eq!(trimmed.back(), curve.subs(0.7));
+    assert_eq!(trimmed.der(0.5), curve.der(0.5));
+
+    let mut trimmed0 = trimmed.clone();
+    let trimmed1 = trimmed0.cut(0.4);
+    assert_eq!(trimmed0.range(), (0.2, 0.4));
+    assert_eq!(trimmed1.range(), (0.4, 0.7));
+}
+
+#[test]
+fn trimmed_inverse() {
+    let trimmed = TrimmedCurve::new(curve(), (0.2, 0.7));
+    let inverse = trimmed.inverse();
+    assert_near!(inverse.range_tuple().0, 0.3);
+    assert_near!(inverse.range_tuple().1, 0.8);
+    assert_near!(inverse.front(), trimmed.back());
+    assert_near!(inverse.back(), trimmed.front());
+    for i in 0..=10 {
+        let t = 0.2 + 0.05 * i as f64;
+        assert_near!(inverse.subs(1.0 - t), trimmed.subs(t));
+    }
+}
+
+#[test]
+fn trimmed_search_parameter() {
+    let curve = curve();
+    let trimmed = TrimmedCurve::new(curve.clone(), (0.2, 0.7));
+    let mat = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    let transformed = trimmed.transformed(mat);
+    assert_eq!(transformed.range(), (0.2, 0.7));
+    assert_near!(transformed.subs(0.5), mat.transform_point(curve.subs(0.5)));
+
+    for i in 0..=10 {
+        let t = 0.2 + 0.05 * i as f64;
+        let s = trimmed.search_parameter(curve.subs(t), None, 100).unwrap();
+        assert!((0.2 - TOLERANCE..=0.7 + TOLERANCE).contains(&s));
+        assert_near!(trimmed.subs(s), curve.subs(t));
+    }
+}
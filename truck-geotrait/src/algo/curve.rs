@@ -22,6 +22,22 @@ where
     res
 }
 
+/// Returns the initial guess of [`search_nearest_parameter`] and [`search_parameter`] for `hint`.
+///
+/// A parameter hint is returned as it is. Otherwise, the parameter is presearched by
+/// [`presearch`] with `division` in the range of the hint, or in the parameter range of the
+/// curve if there is no hint.
+pub fn presearch_hint<C>(curve: &C, point: C::Point, hint: SPHint1D, division: usize) -> f64
+where
+    C: BoundedCurve,
+    C::Point: MetricSpace<Metric = f64> + Copy, {
+    match hint {
+        SPHint1D::Parameter(t) => t,
+        SPHint1D::Range(x, y) => presearch(curve, point, (x, y), division),
+        SPHint1D::None => presearch(curve, point, curve.range_tuple(), division),
+    }
+}
+
 /// Searches the nearest parameter by Newton's method.
 pub fn search_nearest_parameter<C>(
     curve: &C,
@@ -43,7 +59,7 @@ where
             derivation: der2.dot(diff) + der.magnitude2(),
         }
     };
-    newton::solve(function, hint, trials).ok()
+    with_extrapolation(|| newton::solve(function, hint, trials).ok())
 }
 
 /// Searches the parameter by Newton's method.
@@ -60,73 +76,330 @@ where
             derivation: der.magnitude2(),
         }
     };
-    newton::solve(function, hint, trials).ok().and_then(|t| {
-        match curve.subs(t).to_vec().near(&point.to_vec()) {
-            true => Some(t),
-            false => None,
-        }
+    with_extrapolation(|| {
+        newton::solve(function, hint, trials).ok().and_then(|t| {
+            match curve.subs(t).to_vec().near(&point.to_vec()) {
+                true => Some(t),
+                false => None,
+            }
+        })
     })
 }
 
 /// Creates the curve division
 ///
+/// This is the collection of [`sample_iter`].
+///
 /// # Panics
 ///
 /// `tol` must be greater than or equal to `TOLERANCE`.
 pub fn parameter_division<C>(curve: &C, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<C::Point>)
+where
+    C: ParametricCurve,
+    C::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>, {
+    sample_iter(curve, range, tol).unzip()
+}
+
+/// Returns the iterator on the parameters and the points of the curve division, in the order of
+/// the parameters. The division is the same as [`parameter_division`], but the intervals are
+/// divided lazily, so the whole division is not allocated.
+///
+/// # Examples
+/// ```
+/// use std::ops::Bound;
+/// use std::f64::consts::PI;
+/// use truck_geotrait::*;
+/// use truck_base::cgmath64::*;
+///
+/// #[derive(Clone, Copy, Debug)]
+/// struct UnitCircle;
+/// impl ParametricCurve for UnitCircle {
+///     type Point = Point2;
+///     type Vector = Vector2;
+///     fn der_n(&self, n: usize, t: f64) -> Vector2 {
+///         let t = t + n as f64 * PI / 2.0;
+///         Vector2::new(f64::cos(t), f64::sin(t))
+///     }
+///     fn subs(&self, t: f64) -> Point2 { Point2::from_vec(self.der_n(0, t)) }
+///     fn der(&self, t: f64) -> Vector2 { self.der_n(1, t) }
+///     fn der2(&self, t: f64) -> Vector2 { self.der_n(2, t) }
+///     fn parameter_range(&self) -> ParameterRange {
+///         (Bound::Included(0.0), Bound::Excluded(2.0 * PI))
+///     }
+/// }
+///
+/// let mut iter = algo::curve::sample_iter(&UnitCircle, (0.0, PI), 0.01);
+/// assert_eq!(iter.next(), Some((0.0, Point2::new(1.0, 0.0))));
+///
+/// let (params, pts): (Vec<f64>, Vec<Point2>) = iter.unzip();
+/// let (params0, pts0) = algo::curve::parameter_division(&UnitCircle, (0.0, PI), 0.01);
+/// assert_eq!(params, params0[1..]);
+/// assert_eq!(pts, pts0[1..]);
+/// ```
+///
+/// # Panics
+///
+/// `tol` must be greater than or equal to `TOLERANCE`.
+pub fn sample_iter<C>(curve: &C, range: (f64, f64), tol: f64) -> SampleIter<'_, C, C::Point>
 where
     C: ParametricCurve,
     C::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>, {
     nonpositive_tolerance!(tol);
-    sub_parameter_division(
+    let ends = (curve.subs(range.0), curve.subs(range.1));
+    SampleIter {
         curve,
-        range,
-        (curve.subs(range.0), curve.subs(range.1)),
         tol,
-        100,
-    )
+        first: Some((range.0, ends.0)),
+        pending: vec![(range, ends, 100)],
+    }
 }
 
-fn sub_parameter_division<C>(
-    curve: &C,
-    range: (f64, f64),
-    ends: (C::Point, C::Point),
+/// The iterator on the curve division. cf. [`sample_iter`]
+#[derive(Clone, Debug)]
+pub struct SampleIter<'a, C, P> {
+    curve: &'a C,
     tol: f64,
-    trials: usize,
-) -> (Vec<f64>, Vec<C::Point>)
+    first: Option<(f64, P)>,
+    // the intervals not yet emitted, whose leftmost one is on the top
+    pending: Vec<PendingInterval<P>>,
+}
+
+/// the parameter range, the points at the ends, and the remaining depth of the division
+type PendingInterval<P> = ((f64, f64), (P, P), usize);
+
+impl<C> Iterator for SampleIter<'_, C, C::Point>
+where
+    C: ParametricCurve,
+    C::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
+{
+    type Item = (f64, C::Point);
+    fn next(&mut self) -> Option<(f64, C::Point)> {
+        if let Some(first) = self.first.take() {
+            return Some(first);
+        }
+        while let Some((range, ends, trials)) = self.pending.pop() {
+            let gen = ends.0.midpoint(ends.1);
+            let p = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
+            let t = range.0 * (1.0 - p) + range.1 * p;
+            let mid = ends.0 + (ends.1 - ends.0) * p;
+            let dist2 = self.curve.subs(t).distance2(mid);
+            if dist2 < self.tol * self.tol || trials == 0 {
+                return Some((range.1, ends.1));
+            }
+            let mid_param = (range.0 + range.1) / 2.0;
+            let mid_value = self.curve.subs(mid_param);
+            self.pending.push(((mid_param, range.1), (mid_value, ends.1), trials - 1));
+            self.pending.push(((range.0, mid_param), (ends.0, mid_value), trials - 1));
+        }
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // each pending interval emits at least its end
+        (self.pending.len() + usize::from(self.first.is_some()), None)
+    }
+}
+
+impl<C> std::iter::FusedIterator for SampleIter<'_, C, C::Point>
 where
     C: ParametricCurve,
     C::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
 {
-    let gen = ends.0.midpoint(ends.1);
-    let p = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
-    let t = range.0 * (1.0 - p) + range.1 * p;
-    let mid = ends.0 + (ends.1 - ends.0) * p;
-    let dist2 = curve.subs(t).distance2(mid);
-    if dist2 < tol * tol || trials == 0 {
-        (vec![range.0, range.1], vec![ends.0, ends.1])
+}
+
+/// Returns the cumulative arc lengths of `curve` at the increasing parameters `division`,
+/// by [`arc_length`]. The estimated error of each span is less than `tol`.
+pub fn arc_length_table<C>(curve: &C, division: &[f64], tol: f64) -> Vec<(f64, f64)>
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    let mut len = 0.0;
+    let mut table = Vec::with_capacity(division.len());
+    if let Some(t) = division.first() {
+        table.push((*t, 0.0));
+    }
+    division.windows(2).for_each(|w| {
+        len += arc_length(curve, (w[0], w[1]), tol);
+        table.push((w[1], len));
+    });
+    table
+}
+
+/// Returns the arc length of `curve` on `range`, by the adaptive Gauss-Legendre quadrature.
+/// The estimated error is less than `tol`.
+///
+/// The range is bisected until the quadratures of the halves agree with the whole one. Around
+/// the points where the derivative vanishes, e.g. cusps, the speed is not smooth and all the
+/// quadratures may miss the point. So the spans where the tangent is reversed are bisected until
+/// they are shorter than `tol` or reach the depth limit.
+pub fn arc_length<C>(curve: &C, range: (f64, f64), tol: f64) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    adaptive_gauss_legendre(curve, range, gauss_legendre(curve, range), tol, 20)
+}
+
+/// the nodes and the weights of the 5-point Gauss-Legendre quadrature on `[-1, 1]`
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+    (-0.906179845938664, 0.2369268850561891),
+    (-0.5384693101056831, 0.4786286704993665),
+    (0.0, 0.5688888888888889),
+    (0.5384693101056831, 0.4786286704993665),
+    (0.906179845938664, 0.2369268850561891),
+];
+
+fn gauss_legendre<C>(curve: &C, (s0, s1): (f64, f64)) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    let (mid, half) = ((s0 + s1) / 2.0, (s1 - s0) / 2.0);
+    let speed = |t: f64| curve.der(t).magnitude();
+    half * GAUSS_LEGENDRE.iter().map(|(x, w)| w * speed(mid + half * x)).sum::<f64>()
+}
+
+fn adaptive_gauss_legendre<C>(
+    curve: &C,
+    (s0, s1): (f64, f64),
+    whole: f64,
+    tol: f64,
+    depth: usize,
+) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    let mid = (s0 + s1) / 2.0;
+    let (left, right) = (gauss_legendre(curve, (s0, mid)), gauss_legendre(curve, (mid, s1)));
+    let smooth = curve.der(s0).dot(curve.der(s1)) > 0.0 || whole <= tol;
+    if depth == 0 || (smooth && (left + right - whole).abs() <= tol) {
+        left + right
     } else {
-        let mid_param = (range.0 + range.1) / 2.0;
-        let mid_value = curve.subs(mid_param);
-        let (mut params, mut pts) = sub_parameter_division(
-            curve,
-            (range.0, mid_param),
-            (ends.0, mid_value),
-            tol,
-            trials - 1,
-        );
-        let _ = (params.pop(), pts.pop());
-        let (new_params, new_pts) = sub_parameter_division(
-            curve,
-            (mid_param, range.1),
-            (mid_value, ends.1),
-            tol,
-            trials - 1,
-        );
-        params.extend(new_params);
-        pts.extend(new_pts);
-        (params, pts)
+        adaptive_gauss_legendre(curve, (s0, mid), left, tol / 2.0, depth - 1)
+            + adaptive_gauss_legendre(curve, (mid, s1), right, tol / 2.0, depth - 1)
+    }
+}
+
+/// Returns the parameter whose arc length is `len`, where `table` is created by
+/// [`arc_length_table`]. The span of `table` including `len` is searched by Newton's method,
+/// and the arc lengths in the span are calculated under the tolerance `tol`.
+///
+/// The Newton steps are safeguarded by the bisection of the span, so the search also converges
+/// around the points where the derivative vanishes. The lengths out of the table are clamped
+/// to the ends of the table.
+pub fn parameter_by_arc_length<C>(curve: &C, table: &[(f64, f64)], len: f64, tol: f64) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    const TRIALS: usize = 64;
+    let idx = table.partition_point(|(_, l)| *l < len).clamp(1, table.len() - 1);
+    let ((t0, l0), (t1, l1)) = (table[idx - 1], table[idx]);
+    if (l1 - l0).so_small() || len <= l0 {
+        return t0;
+    } else if len >= l1 {
+        return t1;
+    }
+    // the root is in `[a, b]`
+    let (mut a, mut b) = (t0, t1);
+    let mut t = t0 + (t1 - t0) * (len - l0) / (l1 - l0);
+    for _ in 0..TRIALS {
+        let diff = l0 + arc_length(curve, (t0, t), tol) - len;
+        if diff.abs() <= tol {
+            break;
+        }
+        match diff < 0.0 {
+            true => a = t,
+            false => b = t,
+        }
+        let speed = curve.der(t).magnitude();
+        let next = t - diff / speed;
+        t = match speed.so_small() || next <= a || next >= b {
+            true => (a + b) / 2.0,
+            false => next,
+        };
+    }
+    t
+}
+
+/// Returns the parameter of `curve` on `range` whose arc length from the front of `range`
+/// is `len`, by [`parameter_by_arc_length`].
+///
+/// The arc lengths are calculated under the tolerance `tol`, and `len` is clamped to the arc
+/// length of `range`.
+/// # Examples
+/// ```
+/// use truck_geotrait::*;
+/// use truck_base::cgmath64::*;
+///
+/// #[derive(Clone, Debug)]
+/// struct Parabola;
+/// impl ParametricCurve for Parabola {
+///     type Point = Point2;
+///     type Vector = Vector2;
+///     fn subs(&self, t: f64) -> Point2 { Point2::new(t, t * t) }
+///     fn der(&self, t: f64) -> Vector2 { Vector2::new(1.0, 2.0 * t) }
+///     fn der2(&self, _: f64) -> Vector2 { Vector2::new(0.0, 2.0) }
+///     fn der_n(&self, n: usize, t: f64) -> Vector2 {
+///         match n {
+///             0 => self.subs(t).to_vec(),
+///             1 => self.der(t),
+///             2 => self.der2(t),
+///             _ => Vector2::zero(),
+///         }
+///     }
+///     fn parameter_range(&self) -> ParameterRange {
+///         (std::ops::Bound::Unbounded, std::ops::Bound::Unbounded)
+///     }
+/// }
+///
+/// // the exact arc length from the origin to `(t, t^2)`
+/// let length = |t: f64| t * f64::sqrt(1.0 + 4.0 * t * t) / 2.0 + f64::asinh(2.0 * t) / 4.0;
+/// let total = algo::curve::arc_length(&Parabola, (0.0, 2.0), 1.0e-8);
+/// assert!(f64::abs(total - length(2.0)) < 1.0e-8);
+///
+/// let t = algo::curve::parameter_at_arc_length(&Parabola, (0.0, 2.0), 3.0, 1.0e-8);
+/// assert!(f64::abs(length(t) - 3.0) < 1.0e-7);
+/// ```
+pub fn parameter_at_arc_length<C>(curve: &C, range: (f64, f64), len: f64, tol: f64) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    let table = arc_length_table(curve, &[range.0, range.1], tol);
+    parameter_by_arc_length(curve, &table, len, tol)
+}
+
+/// Returns `n` points on `curve` spaced equally by arc length, including both ends.
+///
+/// The arc lengths are calculated on the division by [`parameter_division`] with `tol`,
+/// and the errors of the arc lengths of the points are estimated to be less than `tol`.
+///
+/// # Panics
+///
+/// `tol` must be greater than or equal to `TOLERANCE`.
+pub fn resample_uniform<C>(curve: &C, n: usize, tol: f64) -> Vec<C::Point>
+where
+    C: BoundedCurve,
+    C::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    nonpositive_tolerance!(tol);
+    let (t0, t1) = curve.range_tuple();
+    match n {
+        0 => return Vec::new(),
+        1 => return vec![curve.subs(t0)],
+        _ => {}
     }
+    let (division, _) = parameter_division(curve, (t0, t1), tol);
+    let span_tol = tol / division.len() as f64;
+    let table = arc_length_table(curve, &division, span_tol);
+    let total = table.last().unwrap().1;
+    (0..n)
+        .map(|i| match i {
+            0 => curve.subs(t0),
+            _ if i + 1 == n => curve.subs(t1),
+            _ => {
+                let len = total * i as f64 / (n - 1) as f64;
+                curve.subs(parameter_by_arc_length(curve, &table, len, span_tol))
+            }
+        })
+        .collect()
 }
 
 #[derive(Clone, Debug)]
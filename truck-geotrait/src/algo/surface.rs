@@ -2,35 +2,164 @@ use newton::Jacobian;
 
 use super::*;
 
+mod geodesic;
+mod proximity;
+pub use geodesic::geodesic;
+pub use proximity::{proximity, SurfaceProximity, TANGENCY_ANGLE_TOLERANCE};
+
 /// Divides the domain into equal parts, examines all the values, and returns `(u, v)` such that `surface.subs(u, v)` is closest to `point`.
 /// This method is useful to get an efficient hint of `search_nearest_parameter`.
 pub fn presearch<S>(
     surface: &S,
     point: S::Point,
-    (urange, vrange): ((f64, f64), (f64, f64)),
+    ranges: ((f64, f64), (f64, f64)),
     division: usize,
 ) -> (f64, f64)
 where
     S: ParametricSurface,
     S::Point: MetricSpace<Metric = f64> + Copy,
 {
-    let mut res = (0.0, 0.0);
-    let mut min = f64::INFINITY;
-    let ((u0, u1), (v0, v1)) = (urange, vrange);
-    for i in 0..=division {
-        for j in 0..=division {
-            let p = i as f64 / division as f64;
-            let q = j as f64 / division as f64;
-            let u = u0 * (1.0 - p) + u1 * p;
-            let v = v0 * (1.0 - q) + v1 * q;
-            let dist = surface.subs(u, v).distance2(point);
-            if dist < min {
-                min = dist;
-                res = (u, v);
+    let grid = PresearchGrid::new(surface, ranges, division);
+    grid.nearest(point)
+}
+
+/// Returns the initial guess of [`search_nearest_parameter`] and [`search_parameter`] for `hint`.
+///
+/// A parameter hint is returned as it is. Otherwise, the parameter is presearched by
+/// [`presearch`] with `division` in the ranges of the hint, or in the parameter ranges of the
+/// surface if there is no hint.
+pub fn presearch_hint<S>(
+    surface: &S,
+    point: S::Point,
+    hint: SPHint2D,
+    division: usize,
+) -> (f64, f64)
+where
+    S: BoundedSurface,
+    S::Point: MetricSpace<Metric = f64> + Copy,
+{
+    match hint {
+        SPHint2D::Parameter(u, v) => (u, v),
+        SPHint2D::Range(range0, range1) => presearch(surface, point, (range0, range1), division),
+        SPHint2D::None => presearch(surface, point, surface.range_tuple(), division),
+    }
+}
+
+/// Returns the same hints as [`presearch`] for each point of `points`, evaluating the surface
+/// on the grid only once.
+///
+/// The points of the grid are stored in one contiguous vector, and each point is compared with
+/// all of them by the squared distances. With the feature `rayon`, the points are processed in
+/// parallel.
+/// # Examples
+/// ```
+/// use truck_geotrait::*;
+/// use truck_base::cgmath64::*;
+/// #[derive(Clone)]
+/// struct Paraboloid;
+/// impl ParametricSurface for Paraboloid {
+///     type Point = Point3;
+///     type Vector = Vector3;
+///     fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(u, v, u * u + v * v) }
+///     fn uder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(1.0, 0.0, 2.0 * u) }
+///     fn vder(&self, _: f64, v: f64) -> Vector3 { Vector3::new(0.0, 1.0, 2.0 * v) }
+///     fn uuder(&self, _: f64, _: f64) -> Vector3 { Vector3::new(0.0, 0.0, 2.0) }
+///     fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+///     fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::new(0.0, 0.0, 2.0) }
+///     fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> Vector3 { unimplemented!() }
+/// }
+///
+/// let ranges = ((-1.0, 1.0), (-1.0, 1.0));
+/// let points = [Point3::new(0.5, -0.5, 1.0), Point3::new(-1.0, 0.0, 0.0)];
+/// let hints = algo::surface::presearch_batch(&Paraboloid, &points, ranges, 10);
+/// for (point, hint) in points.iter().zip(hints) {
+///     assert_eq!(hint, algo::surface::presearch(&Paraboloid, *point, ranges, 10));
+/// }
+/// ```
+pub fn presearch_batch<S>(
+    surface: &S,
+    points: &[S::Point],
+    ranges: ((f64, f64), (f64, f64)),
+    division: usize,
+) -> Vec<(f64, f64)>
+where
+    S: ParametricSurface,
+    S::Point: MetricSpace<Metric = f64> + Copy + BatchPoint,
+{
+    let grid = PresearchGrid::new(surface, ranges, division);
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        points.par_iter().map(|point| grid.nearest(*point)).collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    points.iter().map(|point| grid.nearest(*point)).collect()
+}
+
+/// The points processed by [`presearch_batch`], which are shared among the threads with the
+/// feature `rayon`.
+#[cfg(feature = "rayon")]
+pub trait BatchPoint: Send + Sync {}
+#[cfg(feature = "rayon")]
+impl<P: Send + Sync> BatchPoint for P {}
+/// The points processed by [`presearch_batch`], which are shared among the threads with the
+/// feature `rayon`.
+#[cfg(not(feature = "rayon"))]
+pub trait BatchPoint {}
+#[cfg(not(feature = "rayon"))]
+impl<P> BatchPoint for P {}
+
+/// The values of a surface on the grid dividing the domain into equal parts.
+struct PresearchGrid<P> {
+    ranges: ((f64, f64), (f64, f64)),
+    division: usize,
+    /// the value at `(i, j)`-th parameter is the `i * (division + 1) + j`-th element
+    points: Vec<P>,
+}
+
+impl<P: MetricSpace<Metric = f64> + Copy> PresearchGrid<P> {
+    fn new<S>(surface: &S, ranges: ((f64, f64), (f64, f64)), division: usize) -> Self
+    where S: ParametricSurface<Point = P> {
+        let points = (0..=division)
+            .flat_map(|i| (0..=division).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                let (u, v) = Self::parameter(ranges, division, i, j);
+                surface.subs(u, v)
+            })
+            .collect();
+        Self {
+            ranges,
+            division,
+            points,
+        }
+    }
+
+    fn parameter(
+        ((u0, u1), (v0, v1)): ((f64, f64), (f64, f64)),
+        division: usize,
+        i: usize,
+        j: usize,
+    ) -> (f64, f64) {
+        let p = i as f64 / division as f64;
+        let q = j as f64 / division as f64;
+        (u0 * (1.0 - p) + u1 * p, v0 * (1.0 - q) + v1 * q)
+    }
+
+    /// Returns the parameter of the grid point closest to `point`, the first one if tied.
+    fn nearest(&self, point: P) -> (f64, f64) {
+        let mut res = (0, f64::INFINITY);
+        for (k, p) in self.points.iter().enumerate() {
+            let dist = p.distance2(point);
+            if dist < res.1 {
+                res = (k, dist);
             }
         }
+        let n = self.division + 1;
+        match res.1 < f64::INFINITY {
+            true => Self::parameter(self.ranges, self.division, res.0 / n, res.0 % n),
+            false => (0.0, 0.0),
+        }
     }
-    res
 }
 
 /// Vectors whose points returned by the surface that can be the target of [`search_nearest_parameter`].
@@ -99,7 +228,13 @@ impl SsnpVector for Vector3 {
     fn from_param((u, v): (f64, f64)) -> Self { Self::new(u, v, 0.0) }
 }
 
+/// The initial damping factor used by [`search_nearest_parameter`].
+pub const INITIAL_DAMPING: f64 = 1.0e-3;
+
 /// Searches the parameter by Newton's method.
+///
+/// If Newton's method does not converge, e.g. it oscillates around a saddle point of
+/// the distance function, the parameter is searched again by [`search_nearest_parameter_damped`].
 #[inline(always)]
 pub fn search_nearest_parameter<P, S>(
     surface: &S,
@@ -113,8 +248,74 @@ where
     S: ParametricSurface<Point = P, Vector = P::Diff>,
 {
     let function = move |param: P::Diff| SsnpVector::subs(surface, point, param);
-    let res = newton::solve(function, P::Diff::from_param(hint), trials);
-    res.ok().map(P::Diff::into_param)
+    match with_extrapolation(|| newton::solve(function, P::Diff::from_param(hint), trials)) {
+        Ok(res) => Some(res.into_param()),
+        Err(_) => search_nearest_parameter_damped(surface, point, hint, trials, INITIAL_DAMPING),
+    }
+}
+
+/// Searches the parameter by the Levenberg-Marquardt method.
+///
+/// The damping factor `lambda * I` is added to the Hessian of the squared distance function.
+/// `lambda` is shrunk on the steps which decrease the distance and grown on the other steps,
+/// so the distance decreases monotonically even around the saddle points of the distance function.
+pub fn search_nearest_parameter_damped<P, S>(
+    surface: &S,
+    point: P,
+    (mut u, mut v): (f64, f64),
+    trials: usize,
+    mut lambda: f64,
+) -> Option<(f64, f64)>
+where
+    P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64>,
+    P::Diff: InnerSpace<Scalar = f64>,
+    S: ParametricSurface<Point = P, Vector = P::Diff>,
+{
+    const MAX_DAMPING: f64 = 1.0e16;
+    // the iterations may go beyond the domain
+    let _extrapolation = ExtrapolationGuard::new();
+    let mut dist2 = surface.subs(u, v).distance2(point);
+    for _ in 0..=trials {
+        let diff = surface.subs(u, v) - point;
+        let (uder, vder) = (surface.uder(u, v), surface.vder(u, v));
+        let gradient = Vector2::new(uder.dot(diff), vder.dot(diff));
+        if gradient.so_small() {
+            return Some((u, v));
+        }
+        let uv = uder.dot(vder) + surface.uvder(u, v).dot(diff);
+        let hessian = Matrix2::new(
+            uder.dot(uder) + surface.uuder(u, v).dot(diff),
+            uv,
+            uv,
+            vder.dot(vder) + surface.vvder(u, v).dot(diff),
+        );
+        loop {
+            let current = Vector2::new(u, v);
+            let damped = hessian + Matrix2::from_value(lambda);
+            // Only the positive definite matrices give the descent directions.
+            let next = match damped[0][0] > 0.0 && damped.determinant() > 0.0 {
+                true => damped.invert().map(|inv| current - inv * gradient),
+                false => None,
+            };
+            if let Some(Vector2 { x, y }) = next {
+                let next_dist2 = surface.subs(x, y).distance2(point);
+                if next_dist2 <= dist2 {
+                    (u, v, dist2) = (x, y, next_dist2);
+                    lambda = f64::max(lambda * 0.1, f64::EPSILON);
+                    break;
+                }
+            }
+            // Around the local minimum, the distance cannot be decreased due to the rounding error.
+            if lambda < 1.0 && next.is_some_and(|next| next.near2(&current)) {
+                return Some((u, v));
+            }
+            lambda *= 10.0;
+            if lambda > MAX_DAMPING {
+                return None;
+            }
+        }
+    }
+    None
 }
 
 /// Vectors whose points returned by the surface that can be the target of [`search_parameter`].
@@ -181,6 +382,7 @@ where
     P::Diff: SspVector<Point = P>,
     S: ParametricSurface<Point = P, Vector = P::Diff>,
 {
+    let _extrapolation = ExtrapolationGuard::new();
     let function = move |param: Vector2| SspVector::subs(surface, point, param);
     let res = newton::solve(function, hint.into(), trials);
     res.ok().and_then(
@@ -191,6 +393,86 @@ where
     )
 }
 
+/// The diagnostics of the failed search, returned by [`search_parameter_verbose`] and
+/// [`search_nearest_parameter_verbose`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SearchFailure {
+    /// the last parameter at which Newton's method is evaluated
+    pub parameter: (f64, f64),
+    /// the magnitude of the residual of the equation at `parameter`
+    pub residual: f64,
+    /// the number of the iterations of Newton's method
+    pub iterations: usize,
+    /// whether Newton's method terminates due to the Jacobian degeneracy
+    pub degenerate: bool,
+}
+
+/// Same as [`search_parameter`], but returns the diagnostics on the failure.
+///
+/// The residual is the distance between `point` and the point of `surface` at the last parameter,
+/// so the caller can accept the near-miss.
+pub fn search_parameter_verbose<P, S>(
+    surface: &S,
+    point: P,
+    hint: (f64, f64),
+    trials: usize,
+) -> Result<(f64, f64), SearchFailure>
+where
+    P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + Tolerance,
+    P::Diff: SspVector<Point = P>,
+    S: ParametricSurface<Point = P, Vector = P::Diff>,
+{
+    let _extrapolation = ExtrapolationGuard::new();
+    let function = move |param: Vector2| SspVector::subs(surface, point, param);
+    let (param, log) = match newton::solve_with_log(function, hint.into(), trials) {
+        Ok((Vector2 { x: u, y: v }, _)) if surface.subs(u, v).near(&point) => return Ok((u, v)),
+        Ok((param, log)) => (param, log),
+        Err(log) => (log.last().copied().unwrap_or_else(|| hint.into()), log),
+    };
+    Err(SearchFailure {
+        parameter: param.into(),
+        residual: surface.subs(param.x, param.y).distance(point),
+        iterations: log.iterations(),
+        degenerate: log.degenerate(),
+    })
+}
+
+/// Same as [`search_nearest_parameter`], but returns the diagnostics on the failure.
+///
+/// The diagnostics are the ones of Newton's method before the fallback to
+/// [`search_nearest_parameter_damped`]. The residual is the magnitude of the equation of
+/// the foot of the perpendicular at the last parameter.
+pub fn search_nearest_parameter_verbose<P, S>(
+    surface: &S,
+    point: P,
+    hint: (f64, f64),
+    trials: usize,
+) -> Result<(f64, f64), SearchFailure>
+where
+    P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64>,
+    P::Diff: SsnpVector<Point = P>,
+    S: ParametricSurface<Point = P, Vector = P::Diff>,
+{
+    let function = move |param: P::Diff| SsnpVector::subs(surface, point, param);
+    let init = P::Diff::from_param(hint);
+    let log = match with_extrapolation(|| newton::solve_with_log(function, init, trials)) {
+        Ok((res, _)) => return Ok(res.into_param()),
+        Err(log) => log,
+    };
+    let damped = search_nearest_parameter_damped(surface, point, hint, trials, INITIAL_DAMPING);
+    if let Some(res) = damped {
+        return Ok(res);
+    }
+    let last = log.last().copied().unwrap_or(init);
+    let residual = with_extrapolation(|| SsnpVector::subs(surface, point, last).value.magnitude());
+    Err(SearchFailure {
+        parameter: last.into_param(),
+        residual,
+        iterations: log.iterations(),
+        degenerate: log.degenerate(),
+    })
+}
+
 /// Searches the parameters of the intersection point of `surface` and `curve`.
 pub fn search_intersection_parameter<C, S>(
     surface: &S,
@@ -207,6 +489,7 @@ where
         value: surface.subs(x, y) - curve.subs(z),
         derivation: Matrix3::from_cols(surface.uder(x, y), surface.vder(x, y), -curve.der(z)),
     };
+    let _extrapolation = ExtrapolationGuard::new();
     let hint = Vector3::new(hint0.0, hint0.1, hint1);
     let Vector3 { x, y, z } = newton::solve(function, hint, trials).ok()?;
     match surface.subs(x, y).near(&curve.subs(z)) {
@@ -215,6 +498,17 @@ where
     }
 }
 
+/// The source of the jitter of the probe points in the surface division.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum JitterMode {
+    /// Hashes the indices of the cell and the seed derived from the parameter range and
+    /// the depth of the refinement. The division does not depend on the position of the surface.
+    #[default]
+    Indexed,
+    /// Hashes the point evaluated at the center of the cell. This is the legacy behavior.
+    Hashed,
+}
+
 /// Creates the surface division
 ///
 /// # Panics
@@ -222,9 +516,27 @@ where
 /// `tol` must be more than `TOLERANCE`.
 #[inline(always)]
 pub fn parameter_division<S>(
+    surface: &S,
+    range: ((f64, f64), (f64, f64)),
+    tol: f64,
+) -> (Vec<f64>, Vec<f64>)
+where
+    S: ParametricSurface,
+    S::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
+{
+    parameter_division_with_jitter(surface, range, tol, JitterMode::default())
+}
+
+/// Creates the surface division with the specified source of jitter.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn parameter_division_with_jitter<S>(
     surface: &S,
     (urange, vrange): ((f64, f64), (f64, f64)),
     tol: f64,
+    jitter: JitterMode,
 ) -> (Vec<f64>, Vec<f64>)
 where
     S: ParametricSurface,
@@ -232,26 +544,306 @@ where
 {
     nonpositive_tolerance!(tol);
     let (mut udiv, mut vdiv) = (vec![urange.0, urange.1], vec![vrange.0, vrange.1]);
-    sub_parameter_division(surface, (&mut udiv, &mut vdiv), tol);
+    let jitter = Jitter::new(jitter, (urange, vrange));
+    sub_parameter_division(surface, (&mut udiv, &mut vdiv), tol, jitter, 0);
     (udiv, vdiv)
 }
 
-fn sub_parameter_division<S>(surface: &S, (udiv, vdiv): (&mut Vec<f64>, &mut Vec<f64>), tol: f64)
+/// Creates the surface division starting from the previous division `prev`, e.g. the division
+/// of the surface before a slight edit.
+///
+/// First, each parameter of `prev` is removed if the merged cells around it are within
+/// `tol / 2`, without removing two adjacent parameters at once. The margin keeps the division
+/// from oscillating through repeated edits. Then, the cells are divided in the same way as
+/// [`parameter_division`], so the result satisfies `tol` on all cells, not only on the edited
+/// region. The parameters of `prev` are reused where the surface is still within the tolerance.
+/// The range of the division is the one of `prev`.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`, and each of `prev` must be a strictly increasing
+/// sequence of at least two parameters.
+/// # Examples
+/// ```
+/// use truck_geotrait::*;
+/// use truck_base::cgmath64::*;
+/// /// the bump of the height `0.1` at `(c, 0.5)`
+/// #[derive(Clone)]
+/// struct Bump(f64);
+/// impl ParametricSurface for Bump {
+///     type Point = Point3;
+///     type Vector = Vector3;
+///     fn subs(&self, u: f64, v: f64) -> Point3 {
+///         let r2 = (u - self.0) * (u - self.0) + (v - 0.5) * (v - 0.5);
+///         Point3::new(u, v, 0.1 * f64::exp(-r2 * 50.0))
+///     }
+///     fn uder(&self, _: f64, _: f64) -> Vector3 { unimplemented!() }
+///     fn vder(&self, _: f64, _: f64) -> Vector3 { unimplemented!() }
+///     fn uuder(&self, _: f64, _: f64) -> Vector3 { unimplemented!() }
+///     fn uvder(&self, _: f64, _: f64) -> Vector3 { unimplemented!() }
+///     fn vvder(&self, _: f64, _: f64) -> Vector3 { unimplemented!() }
+///     fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> Vector3 { unimplemented!() }
+/// }
+///
+/// let (range, tol) = (((0.0, 1.0), (0.0, 1.0)), 1.0e-3);
+/// let (udiv, vdiv) = algo::surface::parameter_division(&Bump(0.3), range, tol);
+/// // move the bump slightly
+/// let prev = (udiv.as_slice(), vdiv.as_slice());
+/// let (udiv, vdiv) = algo::surface::parameter_division_refine(&Bump(0.32), prev, tol);
+/// assert_eq!((udiv[0], vdiv[vdiv.len() - 1]), (0.0, 1.0));
+///
+/// // starting from the coarsest division is the same as `parameter_division`.
+/// let coarsest: (&[f64], &[f64]) = (&[0.0, 1.0], &[0.0, 1.0]);
+/// assert_eq!(
+///     algo::surface::parameter_division_refine(&Bump(0.32), coarsest, tol),
+///     algo::surface::parameter_division(&Bump(0.32), range, tol),
+/// );
+/// ```
+pub fn parameter_division_refine<S>(
+    surface: &S,
+    (udiv, vdiv): (&[f64], &[f64]),
+    tol: f64,
+) -> (Vec<f64>, Vec<f64>)
+where
+    S: ParametricSurface,
+    S::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
+{
+    nonpositive_tolerance!(tol);
+    let is_division = |div: &[f64]| div.len() >= 2 && div.windows(2).all(|x| x[0] < x[1]);
+    assert!(is_division(udiv), "the previous division of u is not increasing: {udiv:?}");
+    assert!(is_division(vdiv), "the previous division of v is not increasing: {vdiv:?}");
+    let fits = |urange, vrange| cell_fits(surface, (urange, vrange), tol / 2.0);
+    let mut udiv = coarsened(udiv, |urange| {
+        vdiv.windows(2).all(|v| fits(urange, (v[0], v[1])))
+    });
+    let mut vdiv = coarsened(vdiv, |vrange| {
+        udiv.windows(2).all(|u| fits((u[0], u[1]), vrange))
+    });
+    let range = |div: &[f64]| (div[0], div[div.len() - 1]);
+    let jitter = Jitter::new(JitterMode::default(), (range(&udiv), range(&vdiv)));
+    sub_parameter_division(surface, (&mut udiv, &mut vdiv), tol, jitter, 0);
+    (udiv, vdiv)
+}
+
+/// Removes each parameter of `div` such that `mergeable` returns `true` for the range between
+/// the adjacent parameters. The parameter next to a removed one is kept.
+fn coarsened(div: &[f64], mergeable: impl Fn((f64, f64)) -> bool) -> Vec<f64> {
+    let mut res = vec![div[0]];
+    let mut k = 1;
+    while k + 1 < div.len() {
+        if mergeable((div[k - 1], div[k + 1])) {
+            res.push(div[k + 1]);
+            k += 2;
+        } else {
+            res.push(div[k]);
+            k += 1;
+        }
+    }
+    if k + 1 == div.len() {
+        res.push(div[k]);
+    }
+    res
+}
+
+/// Returns whether the interpolation of the cell is within `tol` at the points dividing the cell
+/// into quarters.
+fn cell_fits<S>(surface: &S, (urange, vrange): ((f64, f64), (f64, f64)), tol: f64) -> bool
+where
+    S: ParametricSurface,
+    S::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64>,
+{
+    let pt00 = surface.subs(urange.0, vrange.0);
+    let pt01 = surface.subs(urange.0, vrange.1);
+    let pt10 = surface.subs(urange.1, vrange.0);
+    let pt11 = surface.subs(urange.1, vrange.1);
+    let corners = [[pt00, pt01], [pt10, pt11]];
+    let collapsed = collapsed_side(corners);
+    let ratios = [0.25, 0.5, 0.75];
+    ratios.into_iter().flat_map(|p| ratios.map(|q| (p, q))).all(|(p, q)| {
+        let u = urange.0 * (1.0 - p) + urange.1 * p;
+        let v = vrange.0 * (1.0 - q) + vrange.1 * q;
+        let pt = cell_interpolation(corners, collapsed, (p, q));
+        surface.subs(u, v).distance2(pt) <= tol * tol
+    })
+}
+
+/// Returns the iterator on the rows of the points of the division by
+/// [`ParameterDivision2D::parameter_division`]: each row is the parameter `u` and the points at
+/// `(u, v)` for all `v` in the division of `v`. The rows are evaluated lazily, so only one row is
+/// allocated at once.
+///
+/// # Panics
+///
+/// `tol` must be greater than or equal to `TOLERANCE`.
+pub fn sample_rows<S>(surface: &S, range: ((f64, f64), (f64, f64)), tol: f64) -> SampleRows<'_, S>
+where S: ParametricSurface + ParameterDivision2D {
+    let (udiv, vdiv) = surface.parameter_division(range, tol);
+    SampleRows {
+        surface,
+        udiv: udiv.into_iter(),
+        vdiv,
+    }
+}
+
+/// The iterator on the rows of the surface division. cf. [`sample_rows`]
+#[derive(Clone, Debug)]
+pub struct SampleRows<'a, S> {
+    surface: &'a S,
+    udiv: std::vec::IntoIter<f64>,
+    vdiv: Vec<f64>,
+}
+
+impl<S> SampleRows<'_, S> {
+    /// Returns the division of `v`, common to all rows.
+    #[inline(always)]
+    pub fn vdivision(&self) -> &[f64] { &self.vdiv }
+}
+
+impl<S: ParametricSurface> Iterator for SampleRows<'_, S> {
+    type Item = (f64, Vec<S::Point>);
+    fn next(&mut self) -> Option<Self::Item> {
+        let u = self.udiv.next()?;
+        let row = self.vdiv.iter().map(|v| self.surface.subs(u, *v)).collect();
+        Some((u, row))
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.udiv.size_hint() }
+}
+
+impl<S: ParametricSurface> ExactSizeIterator for SampleRows<'_, S> {}
+impl<S: ParametricSurface> std::iter::FusedIterator for SampleRows<'_, S> {}
+
+/// The initial state of the seed of [`JitterMode::Indexed`]
+const JITTER_SEED: u64 = 0x5EED;
+
+#[derive(Clone, Copy, Debug)]
+struct Jitter {
+    mode: JitterMode,
+    seed: u64,
+}
+
+impl Jitter {
+    /// Returns the jitter whose seed is derived from the parameter range.
+    fn new(mode: JitterMode, (urange, vrange): ((f64, f64), (f64, f64))) -> Self {
+        let seed = [urange.0, urange.1, vrange.0, vrange.1]
+            .into_iter()
+            .fold(JITTER_SEED, |seed, x| splitmix64(seed ^ x.to_bits()));
+        Self { mode, seed }
+    }
+
+    /// Returns the ratios of the probe point in the cell `(i, j)`.
+    fn ratios<P: HashGen<f64> + Copy>(
+        self,
+        (i, j): (usize, usize),
+        depth: u64,
+        gen: impl Fn() -> P,
+    ) -> (f64, f64) {
+        match self.mode {
+            JitterMode::Indexed => {
+                let hash = splitmix64(self.seed ^ splitmix64(depth));
+                let hash = splitmix64(hash ^ splitmix64(i as u64));
+                let hash0 = splitmix64(hash ^ splitmix64(!(j as u64)));
+                let hash1 = splitmix64(hash0);
+                (unit_interval(hash0), unit_interval(hash1))
+            }
+            JitterMode::Hashed => {
+                let gen = gen();
+                (HashGen::hash1(gen), HashGen::hash1(gen))
+            }
+        }
+    }
+}
+
+/// SplitMix64 finalizer
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Maps the hash to `[0, 1)`.
+fn unit_interval(hash: u64) -> f64 { (hash >> 11) as f64 / (1u64 << 53) as f64 }
+
+/// Returns the side of the cell `[[pt00, pt01], [pt10, pt11]]` collapsed into a point, if the
+/// opposite side is not collapsed.
+fn collapsed_side<P>([[pt00, pt01], [pt10, pt11]]: [[P; 2]; 2]) -> Option<DegenerateEdge>
+where P: MetricSpace<Metric = f64> + Copy {
+    let collapsed = |p: P, q: P| p.distance2(q) <= TOLERANCE2;
+    let (u0, u1) = (collapsed(pt00, pt01), collapsed(pt10, pt11));
+    let (v0, v1) = (collapsed(pt00, pt10), collapsed(pt01, pt11));
+    match (u0, u1, v0, v1) {
+        (true, false, _, _) => Some(DegenerateEdge::UFront),
+        (false, true, _, _) => Some(DegenerateEdge::UBack),
+        (_, _, true, false) => Some(DegenerateEdge::VFront),
+        (_, _, false, true) => Some(DegenerateEdge::VBack),
+        _ => None,
+    }
+}
+
+/// Interpolates the cell by the cone from the collapsed side `edge` to the opposite side.
+fn cone_interpolation<P>(
+    [[pt00, pt01], [pt10, pt11]]: [[P; 2]; 2],
+    edge: DegenerateEdge,
+    (p, q): (f64, f64),
+) -> P
+where
+    P: EuclideanSpace<Scalar = f64>,
+{
+    let (apex, (base0, base1), s, t) = match edge {
+        DegenerateEdge::UFront => (pt00, (pt10, pt11), p, q),
+        DegenerateEdge::UBack => (pt10, (pt00, pt01), 1.0 - p, q),
+        DegenerateEdge::VFront => (pt00, (pt01, pt11), q, p),
+        DegenerateEdge::VBack => (pt01, (pt00, pt10), 1.0 - q, p),
+    };
+    let base = base0 + (base1 - base0) * t;
+    apex + (base - apex) * s
+}
+
+/// Interpolates the cell at the ratios `(p, q)`, by the cone if a side is `collapsed`, otherwise
+/// bilinearly.
+fn cell_interpolation<P>(
+    corners: [[P; 2]; 2],
+    collapsed: Option<DegenerateEdge>,
+    (p, q): (f64, f64),
+) -> P
 where
+    P: EuclideanSpace<Scalar = f64>,
+{
+    let [[pt00, pt01], [pt10, pt11]] = corners;
+    match collapsed {
+        Some(edge) => cone_interpolation(corners, edge, (p, q)),
+        None => P::from_vec(
+            pt00.to_vec() * (1.0 - p) * (1.0 - q)
+                + pt01.to_vec() * (1.0 - p) * q
+                + pt10.to_vec() * p * (1.0 - q)
+                + pt11.to_vec() * p * q,
+        ),
+    }
+}
+
+fn sub_parameter_division<S>(
+    surface: &S,
+    (udiv, vdiv): (&mut Vec<f64>, &mut Vec<f64>),
+    tol: f64,
+    jitter: Jitter,
+    depth: u64,
+) where
     S: ParametricSurface,
-    S::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>, {
+    S::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
+{
     let mut divide_flag0 = vec![false; udiv.len() - 1];
     let mut divide_flag1 = vec![false; vdiv.len() - 1];
 
-    for (u, ub) in udiv.windows(2).zip(&mut divide_flag0) {
-        for (v, vb) in vdiv.windows(2).zip(&mut divide_flag1) {
+    for (i, (u, ub)) in udiv.windows(2).zip(&mut divide_flag0).enumerate() {
+        for (j, (v, vb)) in vdiv.windows(2).zip(&mut divide_flag1).enumerate() {
             if *ub && *vb {
                 continue;
             }
             let (u_gen, v_gen) = ((u[0] + u[1]) / 2.0, (v[0] + v[1]) / 2.0);
-            let gen = surface.subs(u_gen, v_gen);
-            let p = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
-            let q = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
+            let (p, q) = jitter.ratios((i, j), depth, || surface.subs(u_gen, v_gen));
+            let p = 0.5 + (0.2 * p - 0.1);
+            let q = 0.5 + (0.2 * q - 0.1);
             let u0 = u[0] * (1.0 - p) + u[1] * p;
             let v0 = v[0] * (1.0 - q) + v[1] * q;
             let p0 = surface.subs(u0, v0);
@@ -259,13 +851,32 @@ where
             let pt01 = surface.subs(u[0], v[1]);
             let pt10 = surface.subs(u[1], v[0]);
             let pt11 = surface.subs(u[1], v[1]);
-            let pt = S::Point::from_vec(
-                pt00.to_vec() * (1.0 - p) * (1.0 - q)
-                    + pt01.to_vec() * (1.0 - p) * q
-                    + pt10.to_vec() * p * (1.0 - q)
-                    + pt11.to_vec() * p * q,
-            );
-            if p0.distance2(pt) > tol * tol {
+            let corners = [[pt00, pt01], [pt10, pt11]];
+            let collapsed = collapsed_side(corners);
+            let pt = cell_interpolation(corners, collapsed, (p, q));
+            // the cell is not divided where the surface is not defined, i.e. `NaN`
+            let dist2 = p0.distance2(pt);
+            if dist2 <= tol * tol || dist2.is_nan() {
+                continue;
+            }
+            if let Some(edge) = collapsed {
+                // The cell is divided along the collapsed side only if the opposite side is
+                // too coarse, since the error of the cone across the cell decreases toward
+                // the apex.
+                let ((s, t), (pt0, pt1)) = match edge {
+                    DegenerateEdge::UFront => ((u[1], v_gen), (pt10, pt11)),
+                    DegenerateEdge::UBack => ((u[0], v_gen), (pt00, pt01)),
+                    DegenerateEdge::VFront => ((u_gen, v[1]), (pt01, pt11)),
+                    DegenerateEdge::VBack => ((u_gen, v[0]), (pt00, pt10)),
+                };
+                let coarse = surface.subs(s, t).distance2(pt0.midpoint(pt1)) > tol * tol;
+                let along_u = matches!(edge, DegenerateEdge::VFront | DegenerateEdge::VBack);
+                match (coarse, along_u) {
+                    (true, _) => (*ub, *vb) = (true, true),
+                    (false, true) => *vb = true,
+                    (false, false) => *ub = true,
+                }
+            } else {
                 let delu = pt00.midpoint(pt01).distance(p0) + pt10.midpoint(pt11).distance(p0);
                 let delv = pt00.midpoint(pt10).distance(p0) + pt01.midpoint(pt11).distance(p0);
                 if delu > delv * 2.0 {
@@ -298,6 +909,6 @@ where
     if udiv.len() != new_udiv.len() || vdiv.len() != new_vdiv.len() {
         *udiv = new_udiv;
         *vdiv = new_vdiv;
-        sub_parameter_division(surface, (udiv, vdiv), tol);
+        sub_parameter_division(surface, (udiv, vdiv), tol, jitter, depth + 1);
     }
 }
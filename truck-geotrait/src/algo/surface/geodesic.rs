@@ -0,0 +1,220 @@
+use super::*;
+
+/// the maximum number of halving the step to satisfy the tolerance
+const MAX_HALVING: usize = 30;
+/// the number of bisections of the last step crossing the boundary of the domain
+const BOUNDARY_BISECTION: usize = 30;
+
+/// Traces the geodesic on `surface` from `start_uv` in the direction `start_dir` in the parameter
+/// space, and returns the path in the parameter space.
+///
+/// The geodesic equation `x''^k + Γ^k_ij x'^i x'^j = 0` parametrized by the arc length is
+/// integrated by the classical Runge-Kutta method, where the Christoffel symbols `Γ^k_ij` are
+/// derived from the first fundamental form and the second derivatives of the surface. Each step
+/// is halved until the difference from two half steps is less than `tol` in the space, and the
+/// chord of each step deviates from the geodesic by at most `tol`. The velocity is normalized
+/// to the unit speed after each step.
+///
+/// The path starts at `start_uv`, and ends when the arc length reaches `length`, or at the
+/// boundary of the bounded and non-periodic parameter ranges. The path also ends at the
+/// singular points of the surface, where the derivatives are linearly dependent. The parameters
+/// in the periodic ranges are not wrapped by the periods.
+/// # Examples
+/// ```
+/// use std::f64::consts::PI;
+/// use truck_geotrait::*;
+/// use truck_base::{assert_near, cgmath64::*, tolerance::*};
+///
+/// // the unit sphere parametrized by the longitude and the latitude
+/// #[derive(Clone, Debug)]
+/// struct Sphere;
+/// impl ParametricSurface for Sphere {
+///     type Point = Point3;
+///     type Vector = Vector3;
+///     fn subs(&self, u: f64, v: f64) -> Point3 {
+///         Point3::new(v.cos() * u.cos(), v.cos() * u.sin(), v.sin())
+///     }
+///     fn uder(&self, u: f64, v: f64) -> Vector3 {
+///         Vector3::new(-v.cos() * u.sin(), v.cos() * u.cos(), 0.0)
+///     }
+///     fn vder(&self, u: f64, v: f64) -> Vector3 {
+///         Vector3::new(-v.sin() * u.cos(), -v.sin() * u.sin(), v.cos())
+///     }
+///     fn uuder(&self, u: f64, v: f64) -> Vector3 {
+///         Vector3::new(-v.cos() * u.cos(), -v.cos() * u.sin(), 0.0)
+///     }
+///     fn uvder(&self, u: f64, v: f64) -> Vector3 {
+///         Vector3::new(v.sin() * u.sin(), -v.sin() * u.cos(), 0.0)
+///     }
+///     fn vvder(&self, u: f64, v: f64) -> Vector3 { -self.subs(u, v).to_vec() }
+///     fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> Vector3 { unimplemented!() }
+///     fn u_period(&self) -> Option<f64> { Some(2.0 * PI) }
+/// }
+/// impl ParametricSurface3D for Sphere {}
+///
+/// // The geodesic starting at the equator toward the northeast is a great circle.
+/// let path = algo::surface::geodesic(&Sphere, (0.0, 0.0), (1.0, 1.0), PI, 1.0e-6);
+/// let normal = Vector3::new(0.0, -1.0, 1.0).normalize();
+/// for (u, v) in &path {
+///     assert!(Sphere.subs(*u, *v).to_vec().dot(normal).abs() < 1.0e-5);
+/// }
+/// // the antipode of the start point
+/// let (u, v) = *path.last().unwrap();
+/// assert_near!(Sphere.subs(u, v), Point3::new(-1.0, 0.0, 0.0));
+/// ```
+/// # Panics
+/// `tol` must be greater than or equal to `TOLERANCE`.
+pub fn geodesic<S: ParametricSurface3D>(
+    surface: &S,
+    start_uv: (f64, f64),
+    start_dir: (f64, f64),
+    length: f64,
+    tol: f64,
+) -> Vec<(f64, f64)> {
+    nonpositive_tolerance!(tol);
+    // the stages of the Runge-Kutta method may go beyond the domain
+    let _extrapolation = ExtrapolationGuard::new();
+    let domain = Domain::new(surface);
+    let mut path = vec![start_uv];
+    let (u, v) = start_uv;
+    let Some(mut state) = unit_speed(surface, [u, v, start_dir.0, start_dir.1]) else {
+        return path;
+    };
+    let (mut travelled, mut step) = (0.0, length);
+    while travelled < length - TOLERANCE {
+        step = f64::min(step, length - travelled);
+        let Some((next, step_len)) = adaptive_step(surface, state, step, tol) else {
+            break;
+        };
+        if !domain.includes(next) {
+            if let Some(last) = boundary_step(surface, &domain, state, step_len) {
+                path.push((last[0], last[1]));
+            }
+            break;
+        }
+        path.push((next[0], next[1]));
+        travelled += step_len;
+        state = next;
+        step = step_len * 2.0;
+    }
+    path
+}
+
+/// the parameter and its derivative by the arc length
+type State = [f64; 4];
+
+/// the bounded and non-periodic parameter ranges of a surface
+struct Domain {
+    urange: Option<(f64, f64)>,
+    vrange: Option<(f64, f64)>,
+}
+
+impl Domain {
+    fn new<S: ParametricSurface>(surface: &S) -> Self {
+        let (urange, vrange) = surface.try_range_tuple();
+        Self {
+            urange: urange.filter(|_| surface.u_period().is_none()),
+            vrange: vrange.filter(|_| surface.v_period().is_none()),
+        }
+    }
+    fn includes(&self, [u, v, ..]: State) -> bool {
+        let inside = |t: f64, range: Option<(f64, f64)>| {
+            range.is_none_or(|(t0, t1)| t0 - TOLERANCE <= t && t <= t1 + TOLERANCE)
+        };
+        inside(u, self.urange) && inside(v, self.vrange)
+    }
+    fn clamp(&self, [u, v, du, dv]: State) -> State {
+        let clamp = |t: f64, range: Option<(f64, f64)>| {
+            range.map_or(t, |(t0, t1)| f64::clamp(t, t0, t1))
+        };
+        [clamp(u, self.urange), clamp(v, self.vrange), du, dv]
+    }
+}
+
+/// Returns the derivative of the state, and the acceleration in the space.
+fn derivative<S: ParametricSurface3D>(
+    surface: &S,
+    [u, v, du, dv]: State,
+) -> Option<(State, f64)> {
+    let (su, sv) = (surface.uder(u, v), surface.vder(u, v));
+    let (e, f, g) = (su.dot(su), su.dot(sv), sv.dot(sv));
+    let det = e * g - f * f;
+    if det < TOLERANCE2 * e.max(g).max(1.0) {
+        return None;
+    }
+    let w = surface.uuder(u, v) * (du * du)
+        + surface.uvder(u, v) * (2.0 * du * dv)
+        + surface.vvder(u, v) * (dv * dv);
+    // the first fundamental form times the accelerations in the parameter space is `-(w.su, w.sv)`
+    let (a, b) = (-w.dot(su), -w.dot(sv));
+    let ddu = (g * a - f * b) / det;
+    let ddv = (e * b - f * a) / det;
+    let curvature = (w + su * ddu + sv * ddv).magnitude();
+    Some(([du, dv, ddu, ddv], curvature))
+}
+
+/// Scales the derivative of the state to the unit speed in the space.
+fn unit_speed<S: ParametricSurface3D>(surface: &S, [u, v, du, dv]: State) -> Option<State> {
+    let speed = (surface.uder(u, v) * du + surface.vder(u, v) * dv).magnitude();
+    match speed.so_small() {
+        true => None,
+        false => Some([u, v, du / speed, dv / speed]),
+    }
+}
+
+fn rk4_step<S: ParametricSurface3D>(surface: &S, state: State, h: f64) -> Option<State> {
+    let add = |x: State, k: State, c: f64| [0, 1, 2, 3].map(|i| x[i] + k[i] * c);
+    let (k1, _) = derivative(surface, state)?;
+    let (k2, _) = derivative(surface, add(state, k1, h / 2.0))?;
+    let (k3, _) = derivative(surface, add(state, k2, h / 2.0))?;
+    let (k4, _) = derivative(surface, add(state, k3, h))?;
+    Some([0, 1, 2, 3].map(|i| state[i] + (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]) * h / 6.0))
+}
+
+/// Returns the next state by the step at most `h` satisfying the tolerance, and the step.
+fn adaptive_step<S: ParametricSurface3D>(
+    surface: &S,
+    state: State,
+    mut h: f64,
+    tol: f64,
+) -> Option<(State, f64)> {
+    let (_, curvature) = derivative(surface, state)?;
+    // the sagitta of the chord is `curvature * h^2 / 8`
+    if curvature * h * h > 8.0 * tol {
+        h = f64::sqrt(8.0 * tol / curvature);
+    }
+    for _ in 0..MAX_HALVING {
+        let full = rk4_step(surface, state, h)?;
+        let half = rk4_step(surface, rk4_step(surface, state, h / 2.0)?, h / 2.0)?;
+        let error = surface.subs(full[0], full[1]).distance(surface.subs(half[0], half[1]));
+        if error < tol {
+            return Some((unit_speed(surface, half)?, h));
+        }
+        h /= 2.0;
+    }
+    None
+}
+
+/// Returns the state on the boundary of the domain, by the bisection of the step from `state`
+/// with the step `h` going out of the domain.
+fn boundary_step<S: ParametricSurface3D>(
+    surface: &S,
+    domain: &Domain,
+    state: State,
+    h: f64,
+) -> Option<State> {
+    let (mut inside, mut outside) = (0.0, h);
+    let mut last = None;
+    for _ in 0..BOUNDARY_BISECTION {
+        let mid = (inside + outside) / 2.0;
+        let next = rk4_step(surface, state, mid)?;
+        match domain.includes(next) {
+            true => {
+                inside = mid;
+                last = Some(next);
+            }
+            false => outside = mid,
+        }
+    }
+    last.map(|last| domain.clamp(last))
+}
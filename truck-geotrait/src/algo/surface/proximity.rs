@@ -0,0 +1,543 @@
+use super::*;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use truck_base::bounding_box::BoundingBox;
+
+/// The relation between two surfaces, returned by [`proximity`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum SurfaceProximity {
+    /// The surfaces are apart.
+    Disjoint {
+        /// the minimum distance between the surfaces
+        distance: f64,
+        /// the closest points on the first and the second surfaces
+        witness_pair: (Point3, Point3),
+    },
+    /// The surfaces cross each other transversally.
+    Intersecting {
+        /// the points on the intersection curves, the seeds of tracing the curves
+        seed_points: Vec<Point3>,
+    },
+    /// The surfaces touch each other, with the parallel or anti-parallel normals.
+    ///
+    /// Tracing the intersection curves does not work in this case.
+    Tangential {
+        /// the sampled points of contact
+        contact_samples: Vec<Point3>,
+    },
+}
+
+/// The angular tolerance of the normals at the points of contact, used by [`proximity`].
+pub const TANGENCY_ANGLE_TOLERANCE: f64 = 1.0e-3;
+
+const MESH_RELATIVE_TOLERANCE: f64 = 1.0e-2;
+const PRESEARCH_DIVISION: usize = 8;
+const TRIALS: usize = 100;
+
+/// Classifies the relation between two surfaces restricted to the parameter ranges.
+///
+/// The surfaces are divided into cells by [`parameter_division`], and the pairs of the cells
+/// whose bounding boxes are within `tol` are traversed on the hierarchies of the cells.
+/// - If the oriented distances to the second surface from the points of a cell of the first
+///   surface change their signs, or the two cells have a common point with transversal normals,
+///   the surfaces are [`SurfaceProximity::Intersecting`], and the common points are refined
+///   into the seed points.
+/// - If the cells have a pair of points within `tol` whose normals are parallel or
+///   anti-parallel within [`TANGENCY_ANGLE_TOLERANCE`], the surfaces are
+///   [`SurfaceProximity::Tangential`].
+/// - Otherwise, the surfaces are [`SurfaceProximity::Disjoint`], and the closest points are
+///   searched by the best-first search on the hierarchies of the cells.
+///
+/// # Examples
+/// ```
+/// use truck_geotrait::*;
+/// use truck_geotrait::algo::surface::{proximity, SurfaceProximity};
+/// use truck_base::{assert_near, cgmath64::*};
+///
+/// #[derive(Clone, Debug)]
+/// struct Plane(f64);
+/// impl ParametricSurface for Plane {
+///     type Point = Point3;
+///     type Vector = Vector3;
+///     fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(u, v, self.0) }
+///     fn uder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_x() }
+///     fn vder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_y() }
+///     fn uuder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+///     fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+///     fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+///     fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+/// }
+/// impl ParametricSurface3D for Plane {}
+///
+/// let range = ((0.0, 1.0), (0.0, 1.0));
+/// let res = proximity(&Plane(0.0), &Plane(0.5), range, range, 1.0e-6);
+/// match res {
+///     SurfaceProximity::Disjoint { distance, witness_pair: (p, q) } => {
+///         assert_near!(distance, 0.5);
+///         assert_near!(q - p, Vector3::new(0.0, 0.0, 0.5));
+///     }
+///     _ => panic!("{res:?}"),
+/// }
+/// ```
+pub fn proximity<S0, S1>(
+    surface0: &S0,
+    surface1: &S1,
+    range0: ((f64, f64), (f64, f64)),
+    range1: ((f64, f64), (f64, f64)),
+    tol: f64,
+) -> SurfaceProximity
+where
+    S0: ParametricSurface3D,
+    S1: ParametricSurface3D,
+{
+    let pair = SurfacePair {
+        surface0,
+        surface1,
+        range0,
+        range1,
+    };
+    let tree0 = CellTree::new(surface0, range0);
+    let tree1 = CellTree::new(surface1, range1);
+
+    let (mut seed_points, mut contact_samples) = (Vec::new(), Vec::new());
+    for (cell0, cell1) in close_cell_pairs(&tree0, &tree1, tol) {
+        match pair.classify(cell0, cell1, tol) {
+            Some(Contact::Transversal(point)) => push_unique(&mut seed_points, point, tol),
+            Some(Contact::Tangential(point)) => push_unique(&mut contact_samples, point, tol),
+            None => {}
+        }
+    }
+    if !seed_points.is_empty() {
+        SurfaceProximity::Intersecting { seed_points }
+    } else if !contact_samples.is_empty() {
+        SurfaceProximity::Tangential { contact_samples }
+    } else {
+        let (param0, param1) = pair.minimum_distance(&tree0, &tree1);
+        let pt0 = surface0.subs(param0.0, param0.1);
+        let pt1 = surface1.subs(param1.0, param1.1);
+        let distance = pt0.distance(pt1);
+        // the contact missed by the traversal of the cells
+        if distance <= tol {
+            let normal0 = surface0.normal(param0.0, param0.1);
+            let normal1 = surface1.normal(param1.0, param1.1);
+            let points = vec![pt0.midpoint(pt1)];
+            return match normal0.cross(normal1).magnitude() <= f64::sin(TANGENCY_ANGLE_TOLERANCE) {
+                true => SurfaceProximity::Tangential {
+                    contact_samples: points,
+                },
+                false => SurfaceProximity::Intersecting {
+                    seed_points: points,
+                },
+            };
+        }
+        SurfaceProximity::Disjoint {
+            distance,
+            witness_pair: (pt0, pt1),
+        }
+    }
+}
+
+fn push_unique(points: &mut Vec<Point3>, point: Point3, tol: f64) {
+    if points.iter().all(|p| p.distance(point) > tol) {
+        points.push(point);
+    }
+}
+
+/// A cell of the surface division.
+#[derive(Clone, Copy, Debug)]
+struct Cell {
+    urange: (f64, f64),
+    vrange: (f64, f64),
+}
+
+impl Cell {
+    fn center(self) -> (f64, f64) {
+        (
+            (self.urange.0 + self.urange.1) / 2.0,
+            (self.vrange.0 + self.vrange.1) / 2.0,
+        )
+    }
+
+    /// the corners and the center
+    fn samples(self) -> [(f64, f64); 5] {
+        let ((u0, u1), (v0, v1)) = (self.urange, self.vrange);
+        [(u0, v0), (u1, v0), (u0, v1), (u1, v1), self.center()]
+    }
+
+    /// the cell with the neighborhood of its size
+    fn expanded(self) -> Self {
+        let ((u0, u1), (v0, v1)) = (self.urange, self.vrange);
+        let (du, dv) = (u1 - u0, v1 - v0);
+        Cell {
+            urange: (u0 - du, u1 + du),
+            vrange: (v0 - dv, v1 + dv),
+        }
+    }
+
+    fn includes(self, param: (f64, f64)) -> bool { includes(param, (self.urange, self.vrange)) }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum CellNode {
+    Leaf(Cell),
+    Branch(usize, usize),
+}
+
+/// The bounding volume hierarchy of the cells of the surface division.
+#[derive(Clone, Debug)]
+struct CellTree {
+    nodes: Vec<(BoundingBox<Point3>, CellNode)>,
+}
+
+impl CellTree {
+    fn new<S: ParametricSurface3D>(
+        surface: &S,
+        (urange, vrange): ((f64, f64), (f64, f64)),
+    ) -> Self {
+        let samples: BoundingBox<Point3> = (0..=PRESEARCH_DIVISION)
+            .flat_map(|i| (0..=PRESEARCH_DIVISION).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                let p = i as f64 / PRESEARCH_DIVISION as f64;
+                let q = j as f64 / PRESEARCH_DIVISION as f64;
+                let u = urange.0 * (1.0 - p) + urange.1 * p;
+                let v = vrange.0 * (1.0 - q) + vrange.1 * q;
+                surface.subs(u, v)
+            })
+            .collect();
+        let tol = f64::max(samples.diameter() * MESH_RELATIVE_TOLERANCE, TOLERANCE);
+        let (udiv, vdiv) = parameter_division(surface, (urange, vrange), tol);
+        let mut tree = CellTree { nodes: Vec::new() };
+        let indices = ((0, udiv.len() - 1), (0, vdiv.len() - 1));
+        // the margin of the bounding boxes, which covers the deviation from the division
+        tree.build(surface, (&udiv, &vdiv), indices, tol * 2.0);
+        tree
+    }
+
+    fn root(&self) -> usize { self.nodes.len() - 1 }
+
+    /// Builds the nodes of the cells in the index ranges, and returns the index of the node.
+    fn build<S: ParametricSurface3D>(
+        &mut self,
+        surface: &S,
+        (udiv, vdiv): (&[f64], &[f64]),
+        ((i0, i1), (j0, j1)): ((usize, usize), (usize, usize)),
+        margin: f64,
+    ) -> usize {
+        let node = if i1 - i0 == 1 && j1 - j0 == 1 {
+            let cell = Cell {
+                urange: (udiv[i0], udiv[i1]),
+                vrange: (vdiv[j0], vdiv[j1]),
+            };
+            let bdb: BoundingBox<Point3> = cell
+                .samples()
+                .into_iter()
+                .map(|(u, v)| surface.subs(u, v))
+                .collect();
+            let margin = Vector3::new(margin, margin, margin);
+            let bdb = BoundingBox::from_iter([bdb.min() - margin, bdb.max() + margin]);
+            (bdb, CellNode::Leaf(cell))
+        } else {
+            let (range0, range1) = if i1 - i0 >= j1 - j0 {
+                let i = (i0 + i1) / 2;
+                (((i0, i), (j0, j1)), ((i, i1), (j0, j1)))
+            } else {
+                let j = (j0 + j1) / 2;
+                (((i0, i1), (j0, j)), ((i0, i1), (j, j1)))
+            };
+            let child0 = self.build(surface, (udiv, vdiv), range0, margin);
+            let child1 = self.build(surface, (udiv, vdiv), range1, margin);
+            let bdb = self.nodes[child0].0 + self.nodes[child1].0;
+            (bdb, CellNode::Branch(child0, child1))
+        };
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+}
+
+/// Returns the distance between two boxes.
+fn box_distance(bdb0: &BoundingBox<Point3>, bdb1: &BoundingBox<Point3>) -> f64 {
+    let (min0, max0, min1, max1) = (bdb0.min(), bdb0.max(), bdb1.min(), bdb1.max());
+    let gap = |i: usize| f64::max(f64::max(min0[i] - max1[i], min1[i] - max0[i]), 0.0);
+    Vector3::new(gap(0), gap(1), gap(2)).magnitude()
+}
+
+/// Returns the children pairs of the nodes, splitting the larger branch.
+fn split_pair(tree0: &CellTree, tree1: &CellTree, (n0, n1): (usize, usize)) -> Vec<(usize, usize)> {
+    let ((bdb0, node0), (bdb1, node1)) = (&tree0.nodes[n0], &tree1.nodes[n1]);
+    match (*node0, *node1) {
+        (CellNode::Leaf(_), CellNode::Leaf(_)) => Vec::new(),
+        (CellNode::Branch(c0, c1), CellNode::Leaf(_)) => vec![(c0, n1), (c1, n1)],
+        (CellNode::Leaf(_), CellNode::Branch(c0, c1)) => vec![(n0, c0), (n0, c1)],
+        (CellNode::Branch(c0, c1), CellNode::Branch(d0, d1)) => {
+            match bdb0.diameter() >= bdb1.diameter() {
+                true => vec![(c0, n1), (c1, n1)],
+                false => vec![(n0, d0), (n0, d1)],
+            }
+        }
+    }
+}
+
+/// Returns the pairs of the cells whose bounding boxes are within `tol`.
+fn close_cell_pairs(tree0: &CellTree, tree1: &CellTree, tol: f64) -> Vec<(Cell, Cell)> {
+    let mut res = Vec::new();
+    let mut stack = vec![(tree0.root(), tree1.root())];
+    while let Some((n0, n1)) = stack.pop() {
+        let ((bdb0, node0), (bdb1, node1)) = (&tree0.nodes[n0], &tree1.nodes[n1]);
+        if box_distance(bdb0, bdb1) > tol {
+            continue;
+        }
+        match (*node0, *node1) {
+            (CellNode::Leaf(cell0), CellNode::Leaf(cell1)) => res.push((cell0, cell1)),
+            _ => stack.extend(split_pair(tree0, tree1, (n0, n1))),
+        }
+    }
+    res
+}
+
+/// Returns whether the oriented distances to `surface1` from the samples of `cell0` change
+/// their signs.
+fn changes_sign<S0, S1>(surface0: &S0, cell0: Cell, surface1: &S1, cell1: Cell, tol: f64) -> bool
+where
+    S0: ParametricSurface3D,
+    S1: ParametricSurface3D, {
+    let (mut positive, mut negative) = (false, false);
+    for (u, v) in cell0.samples() {
+        let point = surface0.subs(u, v);
+        let hint = cell1.center();
+        // The projection is ignored if it is not around the cell, e.g. on the opposite side.
+        let Some((s, t)) = search_nearest_parameter(surface1, point, hint, TRIALS)
+            .filter(|param| cell1.expanded().includes(*param))
+        else {
+            continue;
+        };
+        let distance = surface1.normal(s, t).dot(point - surface1.subs(s, t));
+        positive |= distance > tol;
+        negative |= distance < -tol;
+    }
+    positive && negative
+}
+
+/// A candidate of the best-first search, ordered by the lower bound reversely.
+#[derive(Clone, Copy, Debug)]
+struct Candidate {
+    lower_bound: f64,
+    nodes: (usize, usize),
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering { other.lower_bound.total_cmp(&self.lower_bound) }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Contact {
+    Transversal(Point3),
+    Tangential(Point3),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct SurfacePair<'a, S0, S1> {
+    surface0: &'a S0,
+    surface1: &'a S1,
+    range0: ((f64, f64), (f64, f64)),
+    range1: ((f64, f64), (f64, f64)),
+}
+
+fn clamp((u, v): (f64, f64), ((u0, u1), (v0, v1)): ((f64, f64), (f64, f64))) -> (f64, f64) {
+    (u.clamp(u0, u1), v.clamp(v0, v1))
+}
+
+fn includes((u, v): (f64, f64), ((u0, u1), (v0, v1)): ((f64, f64), (f64, f64))) -> bool {
+    u0 - TOLERANCE <= u && u <= u1 + TOLERANCE && v0 - TOLERANCE <= v && v <= v1 + TOLERANCE
+}
+
+impl<S0: ParametricSurface3D, S1: ParametricSurface3D> SurfacePair<'_, S0, S1> {
+    fn classify(&self, cell0: Cell, cell1: Cell, tol: f64) -> Option<Contact> {
+        if let Some(point) = self.crossing_seed(cell0, cell1, tol) {
+            return Some(Contact::Transversal(point));
+        }
+        let (param0, param1) = self.closest_pair(cell0.center(), cell1.center());
+        let pt0 = self.surface0.subs(param0.0, param0.1);
+        let pt1 = self.surface1.subs(param1.0, param1.1);
+        if pt0.distance(pt1) > tol {
+            return None;
+        }
+        let normal0 = self.surface0.normal(param0.0, param0.1);
+        let normal1 = self.surface1.normal(param1.0, param1.1);
+        match normal0.cross(normal1).magnitude() <= f64::sin(TANGENCY_ANGLE_TOLERANCE) {
+            true => Some(Contact::Tangential(pt0.midpoint(pt1))),
+            false => None,
+        }
+    }
+
+    /// Returns a point of the intersection if the oriented distances to one surface from
+    /// the samples of the cell of the other surface change their signs.
+    fn crossing_seed(&self, cell0: Cell, cell1: Cell, tol: f64) -> Option<Point3> {
+        let crossing = changes_sign(self.surface0, cell0, self.surface1, cell1, tol)
+            || changes_sign(self.surface1, cell1, self.surface0, cell0, tol);
+        if !crossing {
+            return None;
+        }
+        let (hint0, hint1) = (cell0.center(), cell1.center());
+        let pt0 = self.surface0.subs(hint0.0, hint0.1);
+        let pt1 = self.surface1.subs(hint1.0, hint1.1);
+        let normal0 = self.surface0.normal(hint0.0, hint0.1);
+        let normal1 = self.surface1.normal(hint1.0, hint1.1);
+        let plane_normal = normal0.cross(normal1);
+        if plane_normal.so_small() {
+            return None;
+        }
+        let (param0, param1) =
+            self.double_projection((hint0, hint1), pt0.midpoint(pt1), plane_normal.normalize())?;
+        let normal0 = self.surface0.normal(param0.0, param0.1);
+        let normal1 = self.surface1.normal(param1.0, param1.1);
+        let transversal = normal0.cross(normal1).magnitude() > f64::sin(TANGENCY_ANGLE_TOLERANCE);
+        match transversal && includes(param0, self.range0) && includes(param1, self.range1) {
+            true => {
+                let point = self.surface0.subs(param0.0, param0.1);
+                Some(point.midpoint(self.surface1.subs(param1.0, param1.1)))
+            }
+            false => None,
+        }
+    }
+
+    /// Searches the common point of the surfaces on the plane by Newton's method.
+    fn double_projection(
+        &self,
+        (hint0, hint1): ((f64, f64), (f64, f64)),
+        plane_point: Point3,
+        plane_normal: Vector3,
+    ) -> Option<((f64, f64), (f64, f64))> {
+        let (surface0, surface1) = (self.surface0, self.surface1);
+        let function = move |Vector4 { x, y, z, w }| {
+            let (pt0, pt1) = (surface0.subs(x, y), surface1.subs(z, w));
+            let (uder0, vder0) = (surface0.uder(x, y), surface0.vder(x, y));
+            let (uder1, vder1) = (surface1.uder(z, w), surface1.vder(z, w));
+            CalcOutput {
+                value: (pt0 - pt1).extend(plane_normal.dot(pt0.midpoint(pt1) - plane_point)),
+                derivation: Matrix4::from_cols(
+                    uder0.extend(plane_normal.dot(uder0) / 2.0),
+                    vder0.extend(plane_normal.dot(vder0) / 2.0),
+                    (-uder1).extend(plane_normal.dot(uder1) / 2.0),
+                    (-vder1).extend(plane_normal.dot(vder1) / 2.0),
+                ),
+            }
+        };
+        let hint = Vector4::new(hint0.0, hint0.1, hint1.0, hint1.1);
+        let Vector4 { x, y, z, w } = newton::solve(function, hint, TRIALS).ok()?;
+        match surface0.subs(x, y).near(&surface1.subs(z, w)) {
+            true => Some(((x, y), (z, w))),
+            false => None,
+        }
+    }
+
+    fn squared_distance(&self, (u0, v0): (f64, f64), (u1, v1): (f64, f64)) -> f64 {
+        self.surface0.subs(u0, v0).distance2(self.surface1.subs(u1, v1))
+    }
+
+    /// Searches the closest pair of the points by the Levenberg-Marquardt method,
+    /// in the same way as [`search_nearest_parameter_damped`].
+    fn closest_pair(&self, hint0: (f64, f64), hint1: (f64, f64)) -> ((f64, f64), (f64, f64)) {
+        const MAX_DAMPING: f64 = 1.0e16;
+        let (surface0, surface1) = (self.surface0, self.surface1);
+        let (mut param0, mut param1) = (clamp(hint0, self.range0), clamp(hint1, self.range1));
+        let mut dist2 = self.squared_distance(param0, param1);
+        let mut lambda = INITIAL_DAMPING;
+        for _ in 0..TRIALS {
+            let ((u0, v0), (u1, v1)) = (param0, param1);
+            let diff = surface0.subs(u0, v0) - surface1.subs(u1, v1);
+            let (uder0, vder0) = (surface0.uder(u0, v0), surface0.vder(u0, v0));
+            let (uder1, vder1) = (surface1.uder(u1, v1), surface1.vder(u1, v1));
+            let gradient = Vector4::new(
+                uder0.dot(diff),
+                vder0.dot(diff),
+                -uder1.dot(diff),
+                -vder1.dot(diff),
+            );
+            // The gradient is not a criterion of the convergence, since the distance function
+            // is degenerate of the fourth order around the tangential contact.
+            let uv0 = uder0.dot(vder0) + surface0.uvder(u0, v0).dot(diff);
+            let uv1 = uder1.dot(vder1) - surface1.uvder(u1, v1).dot(diff);
+            let hessian = Matrix4::new(
+                uder0.dot(uder0) + surface0.uuder(u0, v0).dot(diff),
+                uv0,
+                -uder0.dot(uder1),
+                -uder0.dot(vder1),
+                uv0,
+                vder0.dot(vder0) + surface0.vvder(u0, v0).dot(diff),
+                -vder0.dot(uder1),
+                -vder0.dot(vder1),
+                -uder0.dot(uder1),
+                -vder0.dot(uder1),
+                uder1.dot(uder1) - surface1.uuder(u1, v1).dot(diff),
+                uv1,
+                -uder0.dot(vder1),
+                -vder0.dot(vder1),
+                uv1,
+                vder1.dot(vder1) - surface1.vvder(u1, v1).dot(diff),
+            );
+            let current = Vector4::new(u0, v0, u1, v1);
+            let accepted = loop {
+                let damped = hessian + Matrix4::from_value(lambda);
+                let next = damped.invert().map(|inv| current - inv * gradient);
+                if let Some(Vector4 { x, y, z, w }) = next {
+                    let next0 = clamp((x, y), self.range0);
+                    let next1 = clamp((z, w), self.range1);
+                    let next_dist2 = self.squared_distance(next0, next1);
+                    if next_dist2 <= dist2 {
+                        (param0, param1, dist2) = (next0, next1, next_dist2);
+                        lambda = f64::max(lambda * 0.1, f64::EPSILON);
+                        break next.is_some_and(|next| !next.near2(&current));
+                    }
+                }
+                lambda *= 10.0;
+                if lambda > MAX_DAMPING {
+                    break false;
+                }
+            };
+            if !accepted {
+                break;
+            }
+        }
+        (param0, param1)
+    }
+
+    /// Returns the parameters of the closest points by the best-first search.
+    fn minimum_distance(&self, tree0: &CellTree, tree1: &CellTree) -> ((f64, f64), (f64, f64)) {
+        let mut best = (f64::INFINITY, None);
+        let mut heap = BinaryHeap::from([Candidate {
+            lower_bound: 0.0,
+            nodes: (tree0.root(), tree1.root()),
+        }]);
+        while let Some(Candidate { lower_bound, nodes }) = heap.pop() {
+            if lower_bound >= best.0 {
+                break;
+            }
+            if let (CellNode::Leaf(cell0), CellNode::Leaf(cell1)) =
+                (tree0.nodes[nodes.0].1, tree1.nodes[nodes.1].1)
+            {
+                let params = self.closest_pair(cell0.center(), cell1.center());
+                let distance = self.squared_distance(params.0, params.1).sqrt();
+                if distance < best.0 {
+                    best = (distance, Some(params));
+                }
+                continue;
+            }
+            heap.extend(split_pair(tree0, tree1, nodes).into_iter().map(|nodes| Candidate {
+                lower_bound: box_distance(&tree0.nodes[nodes.0].0, &tree1.nodes[nodes.1].0),
+                nodes,
+            }));
+        }
+        // The trees have at least one leaf.
+        best.1.unwrap()
+    }
+}
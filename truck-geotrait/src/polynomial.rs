@@ -40,6 +40,13 @@ where P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>
     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>) {
         algo::curve::parameter_division(self, range, tol)
     }
+    fn sample_iter(
+        &self,
+        range: (f64, f64),
+        tol: f64,
+    ) -> impl Iterator<Item = (f64, Self::Point)> {
+        algo::curve::sample_iter(self, range, tol)
+    }
 }
 
 impl<P> SearchNearestParameter<D1> for PolynomialCurve<P>
@@ -54,15 +61,16 @@ where
         hint: H,
         trials: usize,
     ) -> Option<f64> {
-        let hint = match hint.into() {
-            SPHint1D::Parameter(hint) => hint,
-            SPHint1D::Range(x, y) => {
-                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
-            }
-            SPHint1D::None => {
-                algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_nearest_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: P,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<f64> {
+        let hint = algo::curve::presearch_hint(self, point, hint.into(), division);
         algo::curve::search_nearest_parameter(self, point, hint, trials)
     }
 }
@@ -74,15 +82,16 @@ where
 {
     type Point = P;
     fn search_parameter<H: Into<SPHint1D>>(&self, point: P, hint: H, trials: usize) -> Option<f64> {
-        let hint = match hint.into() {
-            SPHint1D::Parameter(hint) => hint,
-            SPHint1D::Range(x, y) => {
-                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
-            }
-            SPHint1D::None => {
-                algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
+        self.search_parameter_with_presearch(point, hint, trials, PRESEARCH_DIVISION)
+    }
+    fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: P,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<f64> {
+        let hint = algo::curve::presearch_hint(self, point, hint.into(), division);
         algo::curve::search_parameter(self, point, hint, trials)
     }
 }
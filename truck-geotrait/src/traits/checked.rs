@@ -0,0 +1,294 @@
+use super::*;
+use std::cell::Cell;
+use std::sync::atomic::{AtomicU8, Ordering};
+use thiserror::Error;
+use truck_base::tolerance::TOLERANCE;
+
+/// Error for evaluating curves or surfaces out of their domains.
+#[derive(Clone, Copy, PartialEq, Debug, Error)]
+pub enum DomainError {
+    /// The parameter of a curve is out of the parameter range.
+    #[error("The parameter {t} is out of the range {range:?} of the curve.")]
+    Curve {
+        /// the parameter
+        t: f64,
+        /// the parameter range of the curve
+        range: ParameterRange,
+    },
+    /// The parameter of a surface is out of the parameter range.
+    #[error("The parameter ({u}, {v}) is out of the range {range:?} of the surface.")]
+    Surface {
+        /// the parameter `u`
+        u: f64,
+        /// the parameter `v`
+        v: f64,
+        /// the parameter ranges of the surface, `(u-range, v-range)`
+        range: (ParameterRange, ParameterRange),
+    },
+}
+
+/// Moves `t` by the multiple of `period` into the period starting at the lower end of `range`.
+fn wrap(t: f64, range: ParameterRange, period: Option<f64>) -> f64 {
+    match (period, bound2opt(range.0)) {
+        (Some(period), Some(t0)) => t0 + (t - t0).rem_euclid(period),
+        _ => t,
+    }
+}
+
+/// Returns whether `t` is in `range` within `TOLERANCE`. `NaN` is in no range.
+fn includes(range: ParameterRange, t: f64) -> bool {
+    let lower = bound2opt(range.0).is_none_or(|t0| t >= t0 - TOLERANCE);
+    let upper = bound2opt(range.1).is_none_or(|t1| t <= t1 + TOLERANCE);
+    lower && upper && !t.is_nan()
+}
+
+/// Returns the parameter wrapped by the period, if `t` is in the domain of `curve`.
+///
+/// The parameters within `TOLERANCE` from the ends of the range are regarded as in the domain.
+/// If the curve is periodic, the parameter is moved into the period starting at the front end.
+/// # Examples
+/// ```
+/// use std::ops::Bound;
+/// use truck_geotrait::*;
+/// // the unit circle parametrized by the angle
+/// #[derive(Clone)]
+/// struct Angle;
+/// impl ParametricCurve for Angle {
+///     type Point = (f64, f64);
+///     type Vector = f64;
+///     fn subs(&self, t: f64) -> (f64, f64) { (f64::cos(t), f64::sin(t)) }
+///     fn der(&self, _: f64) -> f64 { unimplemented!() }
+///     fn der2(&self, _: f64) -> f64 { unimplemented!() }
+///     fn der_n(&self, _: usize, _: f64) -> f64 { unimplemented!() }
+///     fn parameter_range(&self) -> ParameterRange {
+///         (Bound::Included(0.0), Bound::Excluded(2.0 * std::f64::consts::PI))
+///     }
+///     fn period(&self) -> Option<f64> { Some(2.0 * std::f64::consts::PI) }
+/// }
+///
+/// let t = check_curve_domain(&Angle, 7.0).unwrap();
+/// assert!(f64::abs(t - (7.0 - 2.0 * std::f64::consts::PI)) < 1.0e-10);
+/// assert!(check_curve_domain(&Angle, f64::NAN).is_err());
+/// ```
+pub fn check_curve_domain<C: ParametricCurve>(curve: &C, t: f64) -> Result<f64, DomainError> {
+    let range = curve.parameter_range();
+    let t = wrap(t, range, curve.period());
+    match includes(range, t) {
+        true => Ok(t),
+        false => Err(DomainError::Curve { t, range }),
+    }
+}
+
+/// Returns the sub-range `range` clamped into the domain of `curve`, if both ends are in it.
+///
+/// The ends within `TOLERANCE` from the domain are regarded as in the domain and clamped into it.
+/// Unlike [`check_curve_domain`], the ends are not wrapped by the period, and the order of the
+/// ends is kept, so that decreasing sub-ranges can be checked.
+pub fn check_curve_subrange<C: ParametricCurve>(
+    curve: &C,
+    (t0, t1): (f64, f64),
+) -> Result<(f64, f64), DomainError> {
+    let range = curve.parameter_range();
+    let clamp = |t: f64| match includes(range, t) {
+        true => {
+            let t = bound2opt(range.0).map_or(t, |t0| f64::max(t, t0));
+            Ok(bound2opt(range.1).map_or(t, |t1| f64::min(t, t1)))
+        }
+        false => Err(DomainError::Curve { t, range }),
+    };
+    Ok((clamp(t0)?, clamp(t1)?))
+}
+
+/// Returns the parameters wrapped by the periods, if `(u, v)` is in the domain of `surface`.
+///
+/// The parameters within `TOLERANCE` from the ends of the ranges are regarded as in the domain.
+/// If the surface is periodic, the parameters are moved into the periods starting at the front
+/// ends.
+pub fn check_surface_domain<S: ParametricSurface>(
+    surface: &S,
+    u: f64,
+    v: f64,
+) -> Result<(f64, f64), DomainError> {
+    let range = surface.parameter_range();
+    let u = wrap(u, range.0, surface.u_period());
+    let v = wrap(v, range.1, surface.v_period());
+    match includes(range.0, u) && includes(range.1, v) {
+        true => Ok((u, v)),
+        false => Err(DomainError::Surface { u, v, range }),
+    }
+}
+
+/// the state of the strict domain checks: `0` for uninitialized, `1` for off, and `2` for on.
+static STRICT_DOMAIN_CHECKS: AtomicU8 = AtomicU8::new(0);
+
+/// Turns on or off the strict domain checks, which are available only with debug assertions.
+///
+/// In the strict mode, the implementations of the geometric enums in the downstream crates,
+/// e.g. `Curve` and `Surface` of `truck-modeling`, assert that the parameters of `subs` and
+/// the derivations are in the domains. The initial state is on if the environment variable
+/// `TRUCK_STRICT_DOMAIN_CHECKS` is set, so that whole test suites can be run in the strict mode.
+/// The state is shared by all threads.
+#[inline(always)]
+pub fn strict_domain_checks(on: bool) {
+    STRICT_DOMAIN_CHECKS.store(if on { 2 } else { 1 }, Ordering::Relaxed);
+}
+
+thread_local! {
+    /// the depth of the nested [`with_extrapolation`] on the current thread
+    static EXTRAPOLATION_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Suspends the strict domain checks on the current thread while alive.
+#[derive(Debug)]
+pub(crate) struct ExtrapolationGuard(());
+
+impl ExtrapolationGuard {
+    #[inline(always)]
+    pub(crate) fn new() -> Self {
+        EXTRAPOLATION_DEPTH.with(|depth| depth.set(depth.get() + 1));
+        ExtrapolationGuard(())
+    }
+}
+
+impl Drop for ExtrapolationGuard {
+    #[inline(always)]
+    fn drop(&mut self) { EXTRAPOLATION_DEPTH.with(|depth| depth.set(depth.get() - 1)) }
+}
+
+/// Runs `f` with the strict domain checks suspended on the current thread.
+///
+/// This is for the algorithms evaluating the natural extensions of curves and surfaces beyond
+/// their domains intentionally, e.g. the intermediate iterations of Newton's method. The
+/// results of such algorithms are still checked when they are evaluated outside.
+#[inline(always)]
+pub fn with_extrapolation<T>(f: impl FnOnce() -> T) -> T {
+    let _guard = ExtrapolationGuard::new();
+    f()
+}
+
+/// Returns whether the strict domain checks are on. Always `false` without debug assertions,
+/// and in [`with_extrapolation`].
+#[inline(always)]
+pub fn strict_domain_checks_enabled() -> bool {
+    if !cfg!(debug_assertions) || EXTRAPOLATION_DEPTH.with(Cell::get) > 0 {
+        return false;
+    }
+    match STRICT_DOMAIN_CHECKS.load(Ordering::Relaxed) {
+        0 => {
+            let on = std::env::var_os("TRUCK_STRICT_DOMAIN_CHECKS").is_some();
+            strict_domain_checks(on);
+            on
+        }
+        state => state == 2,
+    }
+}
+
+/// Asserts that `t` is in the domain of `curve` if the strict domain checks are on.
+///
+/// The message of the panic contains the parameter and the range.
+#[inline(always)]
+pub fn debug_assert_curve_domain<C: ParametricCurve>(curve: &C, t: f64) {
+    if strict_domain_checks_enabled() {
+        if let Err(error) = check_curve_domain(curve, t) {
+            panic!("strict domain check: {error}");
+        }
+    }
+}
+
+/// Asserts that `(u, v)` is in the domain of `surface` if the strict domain checks are on.
+///
+/// The message of the panic contains the parameters and the ranges.
+#[inline(always)]
+pub fn debug_assert_surface_domain<S: ParametricSurface>(surface: &S, u: f64, v: f64) {
+    if strict_domain_checks_enabled() {
+        if let Err(error) = check_surface_domain(surface, u, v) {
+            panic!("strict domain check: {error}");
+        }
+    }
+}
+
+/// The wrapper of a curve checking the domain before evaluation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CheckedParametricCurve<C>(pub C);
+
+impl<C: ParametricCurve> CheckedParametricCurve<C> {
+    /// Substitutes the parameter `t`, if `t` is in the domain.
+    #[inline(always)]
+    pub fn try_subs(&self, t: f64) -> Result<C::Point, DomainError> {
+        check_curve_domain(&self.0, t).map(|t| self.0.subs(t))
+    }
+    /// Returns the derivation, if `t` is in the domain.
+    #[inline(always)]
+    pub fn try_der(&self, t: f64) -> Result<C::Vector, DomainError> {
+        check_curve_domain(&self.0, t).map(|t| self.0.der(t))
+    }
+    /// Returns the 2nd-order derivation, if `t` is in the domain.
+    #[inline(always)]
+    pub fn try_der2(&self, t: f64) -> Result<C::Vector, DomainError> {
+        check_curve_domain(&self.0, t).map(|t| self.0.der2(t))
+    }
+    /// Returns the `n`th-order derivation, if `t` is in the domain.
+    #[inline(always)]
+    pub fn try_der_n(&self, n: usize, t: f64) -> Result<C::Vector, DomainError> {
+        check_curve_domain(&self.0, t).map(|t| self.0.der_n(n, t))
+    }
+}
+
+/// The wrapper of a surface checking the domain before evaluation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CheckedParametricSurface<S>(pub S);
+
+impl<S: ParametricSurface> CheckedParametricSurface<S> {
+    /// Substitutes the parameter `(u, v)`, if `(u, v)` is in the domain.
+    /// # Examples
+    /// ```
+    /// use std::ops::Bound;
+    /// use truck_geotrait::*;
+    /// // the graph of `u * v` over the unit square
+    /// #[derive(Clone)]
+    /// struct Graph;
+    /// impl ParametricSurface for Graph {
+    ///     type Point = f64;
+    ///     type Vector = f64;
+    ///     fn subs(&self, u: f64, v: f64) -> f64 { u * v }
+    ///     fn uder(&self, _: f64, v: f64) -> f64 { v }
+    ///     fn vder(&self, u: f64, _: f64) -> f64 { u }
+    ///     fn uuder(&self, _: f64, _: f64) -> f64 { 0.0 }
+    ///     fn uvder(&self, _: f64, _: f64) -> f64 { 1.0 }
+    ///     fn vvder(&self, _: f64, _: f64) -> f64 { 0.0 }
+    ///     fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> f64 { unimplemented!() }
+    ///     fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
+    ///         let range = (Bound::Included(0.0), Bound::Included(1.0));
+    ///         (range, range)
+    ///     }
+    /// }
+    ///
+    /// let checked = CheckedParametricSurface(Graph);
+    /// assert_eq!(checked.try_subs(0.5, 0.5), Ok(0.25));
+    /// let error = checked.try_subs(0.5, 1.5).unwrap_err();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "The parameter (0.5, 1.5) is out of the range ((Included(0.0), Included(1.0)), \
+    ///      (Included(0.0), Included(1.0))) of the surface.",
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn try_subs(&self, u: f64, v: f64) -> Result<S::Point, DomainError> {
+        check_surface_domain(&self.0, u, v).map(|(u, v)| self.0.subs(u, v))
+    }
+    /// Returns the derivation by `u`, if `(u, v)` is in the domain.
+    #[inline(always)]
+    pub fn try_uder(&self, u: f64, v: f64) -> Result<S::Vector, DomainError> {
+        check_surface_domain(&self.0, u, v).map(|(u, v)| self.0.uder(u, v))
+    }
+    /// Returns the derivation by `v`, if `(u, v)` is in the domain.
+    #[inline(always)]
+    pub fn try_vder(&self, u: f64, v: f64) -> Result<S::Vector, DomainError> {
+        check_surface_domain(&self.0, u, v).map(|(u, v)| self.0.vder(u, v))
+    }
+    /// Returns $\partial^{m + n} S / \partial u^m \partial v^n$, if `(u, v)` is in the domain.
+    #[inline(always)]
+    pub fn try_der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Result<S::Vector, DomainError> {
+        check_surface_domain(&self.0, u, v).map(|(u, v)| self.0.der_mn(m, n, u, v))
+    }
+}
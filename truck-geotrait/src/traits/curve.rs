@@ -4,6 +4,7 @@ use thiserror::Error;
 use truck_base::{
     assert_near,
     cgmath64::{Point2, Point3, Vector2, Vector3},
+    hash::HashGen,
     tolerance::Tolerance,
 };
 
@@ -38,6 +39,71 @@ pub trait ParametricCurve: Clone {
     /// `None` in default implementation; `Some(period)` if periodic.
     #[inline(always)]
     fn period(&self) -> Option<f64> { None }
+    /// Reduces `t` into `parameter_range`. If the curve is periodic, `t` is moved by the multiple
+    /// of the period into the period from the front end of the range, or from `0` if the range is
+    /// unbounded. Otherwise, `t` is clamped to the bounded ends of the range.
+    #[inline(always)]
+    fn wrap_parameter(&self, t: f64) -> f64 {
+        let (x, y) = self.parameter_range();
+        let (t0, t1) = (bound2opt(x), bound2opt(y));
+        match self.period() {
+            Some(period) => {
+                let t0 = t0.unwrap_or(0.0);
+                t0 + (t - t0).rem_euclid(period)
+            }
+            None => {
+                let t = t0.map_or(t, |t0| f64::max(t, t0));
+                t1.map_or(t, |t1| f64::min(t, t1))
+            }
+        }
+    }
+    /// Substitutes the parameter `t` reduced into `parameter_range`.
+    /// cf. [`ParametricCurve::wrap_parameter`]
+    ///
+    /// The periodic curves can be evaluated at the parameters out of the range, e.g. on the loops
+    /// crossing the seams. The non-periodic curves are evaluated at the nearest end of the range.
+    /// # Examples
+    /// ```
+    /// use std::ops::Bound;
+    /// use std::f64::consts::PI;
+    /// use truck_geotrait::*;
+    /// use truck_base::{assert_near, cgmath64::*};
+    ///
+    /// #[derive(Clone, Debug)]
+    /// struct Circle;
+    /// impl ParametricCurve for Circle {
+    ///     type Point = Point2;
+    ///     type Vector = Vector2;
+    ///     fn subs(&self, t: f64) -> Point2 {
+    ///         // the parameter out of the range is a bug of the caller
+    ///         assert!((0.0..=2.0 * PI).contains(&t));
+    ///         Point2::new(f64::cos(t), f64::sin(t))
+    ///     }
+    ///     fn der(&self, t: f64) -> Vector2 { Vector2::new(-f64::sin(t), f64::cos(t)) }
+    ///     fn der2(&self, t: f64) -> Vector2 { -self.subs(t).to_vec() }
+    ///     fn der_n(&self, n: usize, t: f64) -> Vector2 {
+    ///         match n {
+    ///             0 => self.subs(t).to_vec(),
+    ///             1 => self.der(t),
+    ///             _ => unimplemented!(),
+    ///         }
+    ///     }
+    ///     fn parameter_range(&self) -> ParameterRange {
+    ///         (Bound::Included(0.0), Bound::Excluded(2.0 * PI))
+    ///     }
+    ///     fn period(&self) -> Option<f64> { Some(2.0 * PI) }
+    /// }
+    ///
+    /// assert_near!(Circle.subs_wrapped(-PI / 2.0), Point2::new(0.0, -1.0));
+    /// assert_near!(Circle.subs_wrapped(5.0 * PI), Point2::new(-1.0, 0.0));
+    /// assert_near!(Circle.der_wrapped(-PI / 2.0), Vector2::new(1.0, 0.0));
+    /// ```
+    #[inline(always)]
+    fn subs_wrapped(&self, t: f64) -> Self::Point { self.subs(self.wrap_parameter(t)) }
+    /// Returns the derivation at the parameter `t` reduced into `parameter_range`.
+    /// cf. [`ParametricCurve::subs_wrapped`]
+    #[inline(always)]
+    fn der_wrapped(&self, t: f64) -> Self::Vector { self.der(self.wrap_parameter(t)) }
 }
 
 /// bounded parametric curves i.e. it is guaranteed that the return value of `parameter_range` is not `Bound::Unbounded`.
@@ -57,6 +123,55 @@ pub trait BoundedCurve: ParametricCurve {
         let (_, y) = self.parameter_range();
         self.subs(bound2opt(y).expect(UNBOUNDED_ERROR))
     }
+    /// Returns `n` points spaced equally by arc length, including both ends.
+    /// cf. [`algo::curve::resample_uniform`](crate::algo::curve::resample_uniform)
+    ///
+    /// The arc lengths are calculated under `tol`, i.e. the arc length of each point from the
+    /// front is estimated to differ from the exact one by less than `tol`.
+    /// # Examples
+    /// ```
+    /// use std::ops::Bound;
+    /// use truck_geotrait::*;
+    /// use truck_base::{assert_near, cgmath64::*};
+    ///
+    /// #[derive(Clone, Debug)]
+    /// struct Parabola;
+    /// impl ParametricCurve for Parabola {
+    ///     type Point = Point2;
+    ///     type Vector = Vector2;
+    ///     fn subs(&self, t: f64) -> Point2 { Point2::new(t, t * t) }
+    ///     fn der(&self, t: f64) -> Vector2 { Vector2::new(1.0, 2.0 * t) }
+    ///     fn der2(&self, _: f64) -> Vector2 { Vector2::new(0.0, 2.0) }
+    ///     fn der_n(&self, n: usize, t: f64) -> Vector2 {
+    ///         match n {
+    ///             0 => self.subs(t).to_vec(),
+    ///             1 => self.der(t),
+    ///             2 => self.der2(t),
+    ///             _ => Vector2::zero(),
+    ///         }
+    ///     }
+    ///     fn parameter_range(&self) -> ParameterRange {
+    ///         (Bound::Included(0.0), Bound::Included(2.0))
+    ///     }
+    /// }
+    /// impl BoundedCurve for Parabola {}
+    ///
+    /// let pts = Parabola.resample_uniform(5, 1.0e-6);
+    /// assert_eq!(pts.len(), 5);
+    /// assert_near!(pts[0], Point2::new(0.0, 0.0));
+    /// assert_near!(pts[4], Point2::new(2.0, 4.0));
+    /// // the exact arc length from the origin to `(t, t^2)`
+    /// let length = |t: f64| t * f64::sqrt(1.0 + 4.0 * t * t) / 2.0 + f64::asinh(2.0 * t) / 4.0;
+    /// for (i, p) in pts.iter().enumerate() {
+    ///     assert!(f64::abs(length(p.x) - length(2.0) * i as f64 / 4.0) < 1.0e-6);
+    /// }
+    /// ```
+    fn resample_uniform(&self, n: usize, tol: f64) -> Vec<Self::Point>
+    where
+        Self::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
+        Self::Vector: InnerSpace<Scalar = f64>, {
+        crate::algo::curve::resample_uniform(self, n, tol)
+    }
 }
 
 /// Implementation for the test of topological methods.
@@ -150,6 +265,26 @@ pub trait ParameterDivision1D {
     ///
     /// `tol` must be greater than or equal to `TOLERANCE`.
     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>);
+    /// Returns the iterator on the pairs of the parameters and the points of the division, in the
+    /// order of the parameters. The pairs are the same as
+    /// [`ParameterDivision1D::parameter_division`].
+    ///
+    /// The default implementation collects the division. The curves divided by
+    /// [`algo::curve::parameter_division`](crate::algo::curve::parameter_division) override it
+    /// by [`algo::curve::sample_iter`](crate::algo::curve::sample_iter), which divides the
+    /// curve lazily.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be greater than or equal to `TOLERANCE`.
+    fn sample_iter(
+        &self,
+        range: (f64, f64),
+        tol: f64,
+    ) -> impl Iterator<Item = (f64, Self::Point)> {
+        let (params, pts) = self.parameter_division(range, tol);
+        params.into_iter().zip(pts)
+    }
 }
 
 impl<C: ParameterDivision1D> ParameterDivision1D for &C {
@@ -157,6 +292,13 @@ impl<C: ParameterDivision1D> ParameterDivision1D for &C {
     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>) {
         (*self).parameter_division(range, tol)
     }
+    fn sample_iter(
+        &self,
+        range: (f64, f64),
+        tol: f64,
+    ) -> impl Iterator<Item = (f64, Self::Point)> {
+        (*self).sample_iter(range, tol)
+    }
 }
 
 impl<C: ParameterDivision1D> ParameterDivision1D for Box<C> {
@@ -164,6 +306,13 @@ impl<C: ParameterDivision1D> ParameterDivision1D for Box<C> {
     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>) {
         (**self).parameter_division(range, tol)
     }
+    fn sample_iter(
+        &self,
+        range: (f64, f64),
+        tol: f64,
+    ) -> impl Iterator<Item = (f64, Self::Point)> {
+        (**self).sample_iter(range, tol)
+    }
 }
 
 /// parameter range move by affine transformation
@@ -1,6 +1,8 @@
 use std::ops::Bound;
 use truck_base::cgmath64::*;
 
+mod checked;
+pub use checked::*;
 mod curve;
 pub use curve::*;
 mod surface;
@@ -103,6 +103,24 @@ pub trait SearchParameter<Dim: SPDimension> {
         hint: H,
         trials: usize,
     ) -> Option<Dim::Parameter>;
+    /// Same as [`Self::search_parameter`], but presearches the initial guess on the grid of
+    /// `division` parts in each direction if `hint` is not a parameter.
+    ///
+    /// The geometries presearching the hint implement [`Self::search_parameter`] by this method
+    /// with the default division of the crate. A finer division is more reliable for the geometries
+    /// with many features, and a coarser one is cheaper for the simple ones. The default
+    /// implementation is for the geometries not presearching, and ignores `division`.
+    #[inline(always)]
+    fn search_parameter_with_presearch<H: Into<Dim::Hint>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<Dim::Parameter> {
+        let _ = division;
+        self.search_parameter(point, hint, trials)
+    }
 }
 
 impl<Dim: SPDimension, T: SearchParameter<Dim>> SearchParameter<Dim> for &T {
@@ -116,6 +134,16 @@ impl<Dim: SPDimension, T: SearchParameter<Dim>> SearchParameter<Dim> for &T {
     ) -> Option<Dim::Parameter> {
         T::search_parameter(*self, point, hint, trials)
     }
+    #[inline(always)]
+    fn search_parameter_with_presearch<H: Into<Dim::Hint>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<Dim::Parameter> {
+        T::search_parameter_with_presearch(*self, point, hint, trials, division)
+    }
 }
 
 impl<Dim: SPDimension, T: SearchParameter<Dim>> SearchParameter<Dim> for Box<T> {
@@ -129,6 +157,16 @@ impl<Dim: SPDimension, T: SearchParameter<Dim>> SearchParameter<Dim> for Box<T>
     ) -> Option<Dim::Parameter> {
         T::search_parameter(&**self, point, hint, trials)
     }
+    #[inline(always)]
+    fn search_parameter_with_presearch<H: Into<Dim::Hint>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<Dim::Parameter> {
+        T::search_parameter_with_presearch(&**self, point, hint, trials, division)
+    }
 }
 
 /// Search parameter `t` such that `self.subs(t)` is nearest point.
@@ -143,6 +181,24 @@ pub trait SearchNearestParameter<Dim: SPDimension> {
         hint: H,
         trials: usize,
     ) -> Option<Dim::Parameter>;
+    /// Same as [`Self::search_nearest_parameter`], but presearches the initial guess on the grid of
+    /// `division` parts in each direction if `hint` is not a parameter.
+    ///
+    /// The geometries presearching the hint implement [`Self::search_nearest_parameter`] by this
+    /// method with the default division of the crate. A finer division is more reliable for the
+    /// geometries with many features, and a coarser one is cheaper for the simple ones. The default
+    /// implementation is for the geometries not presearching, and ignores `division`.
+    #[inline(always)]
+    fn search_nearest_parameter_with_presearch<H: Into<Dim::Hint>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<Dim::Parameter> {
+        let _ = division;
+        self.search_nearest_parameter(point, hint, trials)
+    }
 }
 
 impl<Dim: SPDimension, T: SearchNearestParameter<Dim>> SearchNearestParameter<Dim> for &T {
@@ -156,6 +212,16 @@ impl<Dim: SPDimension, T: SearchNearestParameter<Dim>> SearchNearestParameter<Di
     ) -> Option<Dim::Parameter> {
         T::search_nearest_parameter(*self, point, hint, trials)
     }
+    #[inline(always)]
+    fn search_nearest_parameter_with_presearch<H: Into<Dim::Hint>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<Dim::Parameter> {
+        T::search_nearest_parameter_with_presearch(*self, point, hint, trials, division)
+    }
 }
 
 impl<Dim: SPDimension, T: SearchNearestParameter<Dim>> SearchNearestParameter<Dim> for Box<T> {
@@ -169,4 +235,14 @@ impl<Dim: SPDimension, T: SearchNearestParameter<Dim>> SearchNearestParameter<Di
     ) -> Option<Dim::Parameter> {
         T::search_nearest_parameter(&**self, point, hint, trials)
     }
+    #[inline(always)]
+    fn search_nearest_parameter_with_presearch<H: Into<Dim::Hint>>(
+        &self,
+        point: Self::Point,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<Dim::Parameter> {
+        T::search_nearest_parameter_with_presearch(&**self, point, hint, trials, division)
+    }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use truck_base::tolerance::Origin;
 
 type Tuple = (f64, f64);
 /// Parametric surface
@@ -149,16 +150,207 @@ pub trait ParametricSurface3D: ParametricSurface<Point = Point3, Vector = Vector
         let abs_vder = cross.dot(cross_vder) / abs;
         (cross_vder * abs - cross * abs_vder) / (abs * abs)
     }
+    /// Returns the asymptotic directions at `(u, v)`, i.e. the tangent directions whose normal
+    /// curvatures are zero.
+    ///
+    /// The directions are the unit vectors in the 3D space, expressed as the combinations
+    /// `du * uder + dv * vder` of the derivations, where `(du, dv)` are the zeros of the second
+    /// fundamental form `L du^2 + 2M du dv + N dv^2`. Each direction is determined up to sign.
+    /// The number of the directions depends on the principal curvatures:
+    /// - two directions at the hyperbolic points, where the Gaussian curvature is negative,
+    /// - one direction at the parabolic points, where one principal curvature is zero,
+    /// - no directions at the elliptic points, where the Gaussian curvature is positive.
+    ///
+    /// The principal curvatures within `TOLERANCE` are regarded as zero. At the flat points, where
+    /// both principal curvatures are zero, every direction is asymptotic, so no directions are
+    /// returned. No directions are returned at the singular points either.
+    /// # Examples
+    /// ```
+    /// use truck_geotrait::*;
+    /// use truck_base::{cgmath64::*, tolerance::*};
+    /// // the hyperbolic paraboloid `z = xy`
+    /// #[derive(Clone)]
+    /// struct Saddle;
+    /// impl ParametricSurface for Saddle {
+    ///     type Point = Point3;
+    ///     type Vector = Vector3;
+    ///     fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(u, v, u * v) }
+    ///     fn uder(&self, _: f64, v: f64) -> Vector3 { Vector3::new(1.0, 0.0, v) }
+    ///     fn vder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(0.0, 1.0, u) }
+    ///     fn uuder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    ///     fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_z() }
+    ///     fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    ///     fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> Vector3 { unimplemented!() }
+    /// }
+    /// impl ParametricSurface3D for Saddle {}
+    ///
+    /// // the asymptotic directions of the saddle are the straight lines on it
+    /// let dirs = Saddle.asymptotic_directions(0.5, 0.0);
+    /// assert_eq!(dirs.len(), 2);
+    /// let uder = Saddle.uder(0.5, 0.0).normalize();
+    /// let vder = Saddle.vder(0.5, 0.0).normalize();
+    /// assert!(dirs.iter().any(|dir| dir.cross(uder).so_small()));
+    /// assert!(dirs.iter().any(|dir| dir.cross(vder).so_small()));
+    /// ```
+    fn asymptotic_directions(&self, u: f64, v: f64) -> Vec<Vector3> {
+        let (uder, vder) = (self.uder(u, v), self.vder(u, v));
+        let cross = uder.cross(vder);
+        if cross.so_small() {
+            return Vec::new();
+        }
+        let normal = cross.normalize();
+        // the first and the second fundamental forms
+        let (e, f, g) = (uder.dot(uder), uder.dot(vder), vder.dot(vder));
+        let l = self.uuder(u, v).dot(normal);
+        let m = self.uvder(u, v).dot(normal);
+        let n = self.vvder(u, v).dot(normal);
+        let det = e * g - f * f;
+        let gaussian = (l * n - m * m) / det;
+        let mean = (e * n - 2.0 * f * m + g * l) / (2.0 * det);
+        let disc = f64::sqrt(f64::max(mean * mean - gaussian, 0.0));
+        let (k0, k1) = (mean - disc, mean + disc);
+
+        // the eigenvectors of the second fundamental form in the parameter plane
+        let angle = f64::atan2(2.0 * m, l - n) / 2.0;
+        let (cos, sin) = (angle.cos(), angle.sin());
+        let (e0, e1) = (Vector2::new(cos, sin), Vector2::new(-sin, cos));
+        let lambda0 = l * cos * cos + 2.0 * m * cos * sin + n * sin * sin;
+        let lambda1 = l * sin * sin - 2.0 * m * cos * sin + n * cos * cos;
+        let tangent = |x: Vector2| (uder * x.x + vder * x.y).normalize();
+        match (k0.so_small(), k1.so_small()) {
+            (true, true) => Vec::new(),
+            (true, false) | (false, true) => match lambda0.abs() < lambda1.abs() {
+                true => vec![tangent(e0)],
+                false => vec![tangent(e1)],
+            },
+            _ if k0 * k1 < 0.0 => {
+                let (x0, x1) = (e0 * lambda1.abs().sqrt(), e1 * lambda0.abs().sqrt());
+                vec![tangent(x0 + x1), tangent(x0 - x1)]
+            }
+            _ => Vec::new(),
+        }
+    }
+    /// Returns the boundaries of the parameter rectangle `ranges` collapsed into points, e.g.
+    /// the poles of spheres and the apexes of cones.
+    ///
+    /// A boundary is regarded as collapsed if the sampled points on it are within `tol` from
+    /// its front end.
+    /// # Examples
+    /// ```
+    /// use truck_geotrait::*;
+    /// use truck_base::cgmath64::*;
+    /// // the cone with the apex at the origin
+    /// #[derive(Clone)]
+    /// struct Cone;
+    /// impl ParametricSurface for Cone {
+    ///     type Point = Point3;
+    ///     type Vector = Vector3;
+    ///     fn subs(&self, u: f64, v: f64) -> Point3 {
+    ///         Point3::new(u * f64::cos(v), u * f64::sin(v), u)
+    ///     }
+    ///     fn uder(&self, _: f64, v: f64) -> Vector3 {
+    ///         Vector3::new(f64::cos(v), f64::sin(v), 1.0)
+    ///     }
+    ///     fn vder(&self, u: f64, v: f64) -> Vector3 {
+    ///         Vector3::new(-u * f64::sin(v), u * f64::cos(v), 0.0)
+    ///     }
+    ///     fn uuder(&self, _: f64, _: f64) -> Vector3 { unimplemented!() }
+    ///     fn uvder(&self, _: f64, _: f64) -> Vector3 { unimplemented!() }
+    ///     fn vvder(&self, _: f64, _: f64) -> Vector3 { unimplemented!() }
+    ///     fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> Vector3 { unimplemented!() }
+    /// }
+    /// impl ParametricSurface3D for Cone {}
+    ///
+    /// let ranges = ((0.0, 1.0), (0.0, 2.0 * std::f64::consts::PI));
+    /// let degenerate = Cone.degenerate_boundaries(ranges, 1.0e-6);
+    /// assert_eq!(degenerate, vec![DegenerateEdge::UFront]);
+    /// ```
+    fn degenerate_boundaries(
+        &self,
+        (urange, vrange): ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> Vec<DegenerateEdge> {
+        const DIVISION: usize = 8;
+        let collapsed = |curve: &dyn Fn(f64) -> Point3, (t0, t1): (f64, f64)| {
+            let p = curve(t0);
+            (1..=DIVISION).all(|i| {
+                let t = t0 + (t1 - t0) * i as f64 / DIVISION as f64;
+                curve(t).distance2(p) <= tol * tol
+            })
+        };
+        [
+            (DegenerateEdge::UFront, urange.0, vrange, false),
+            (DegenerateEdge::UBack, urange.1, vrange, false),
+            (DegenerateEdge::VFront, vrange.0, urange, true),
+            (DegenerateEdge::VBack, vrange.1, urange, true),
+        ]
+        .into_iter()
+        .filter(|(_, s, range, v_fixed)| match v_fixed {
+            true => collapsed(&|t| self.subs(t, *s), *range),
+            false => collapsed(&|t| self.subs(*s, t), *range),
+        })
+        .map(|(edge, ..)| edge)
+        .collect()
+    }
+}
+
+/// The boundary of the parameter rectangle of a surface, collapsed into a point.
+///
+/// cf. [`ParametricSurface3D::degenerate_boundaries`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DegenerateEdge {
+    /// the boundary `u = urange.0`
+    UFront,
+    /// the boundary `u = urange.1`
+    UBack,
+    /// the boundary `v = vrange.0`
+    VFront,
+    /// the boundary `v = vrange.1`
+    VBack,
+}
+
+/// The image of a degenerate boundary of a surface.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Collapse<P> {
+    /// the boundary collapses into the point
+    Point(P),
+    /// the boundary and the surface around it collapse into the segment between the points
+    Line(P, P),
+}
+
+/// A degenerate boundary of a surface, with its image.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DegenerateBoundary<P> {
+    /// the boundary of the parameter rectangle
+    pub edge: DegenerateEdge,
+    /// the image of the boundary
+    pub collapse: Collapse<P>,
 }
 
 impl<S: ParametricSurface3D> ParametricSurface3D for &S {
     #[inline(always)]
     fn normal(&self, u: f64, v: f64) -> Vector3 { (*self).normal(u, v) }
+    #[inline(always)]
+    fn degenerate_boundaries(
+        &self,
+        ranges: ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> Vec<DegenerateEdge> {
+        (*self).degenerate_boundaries(ranges, tol)
+    }
 }
 
 impl<S: ParametricSurface3D> ParametricSurface3D for Box<S> {
     #[inline(always)]
     fn normal(&self, u: f64, v: f64) -> Vector3 { (**self).normal(u, v) }
+    #[inline(always)]
+    fn degenerate_boundaries(
+        &self,
+        ranges: ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> Vec<DegenerateEdge> {
+        (**self).degenerate_boundaries(ranges, tol)
+    }
 }
 
 /// Bounded surface with parametric range i.e. it is guaranteed that the return value of `parameter_range` is not `Bound::Unbounded`.
@@ -178,6 +370,123 @@ impl<S: BoundedSurface> BoundedSurface for &S {}
 
 impl<S: BoundedSurface> BoundedSurface for Box<S> {}
 
+/// The map from the parameters of a surface to those of another surface with the same image,
+/// e.g. the inverse. The parameters are swapped first if `swap` is `true`, and then mapped by
+/// the affine maps `t ↦ a t + b` of `u` and `v` with `(a, b)`.
+/// # Examples
+/// ```
+/// use truck_geotrait::*;
+/// let map = ParamMap2D {
+///     swap: true,
+///     u: (-1.0, 2.0),
+///     v: (1.0, 0.5),
+/// };
+/// assert_eq!(map.apply(1.0, 3.0), (-1.0, 1.5));
+/// assert_eq!(map.inverse().apply(-1.0, 1.5), (1.0, 3.0));
+/// assert_eq!(map.then(map.inverse()), ParamMap2D::IDENTITY);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParamMap2D {
+    /// whether the parameters are swapped before the affine maps
+    pub swap: bool,
+    /// the affine map `(a, b)` of the first parameter after swapping
+    pub u: (f64, f64),
+    /// the affine map `(a, b)` of the second parameter after swapping
+    pub v: (f64, f64),
+}
+
+impl ParamMap2D {
+    /// the identity map
+    pub const IDENTITY: ParamMap2D = ParamMap2D {
+        swap: false,
+        u: (1.0, 0.0),
+        v: (1.0, 0.0),
+    };
+    /// the map swapping the parameters
+    pub const SWAP: ParamMap2D = ParamMap2D {
+        swap: true,
+        u: (1.0, 0.0),
+        v: (1.0, 0.0),
+    };
+
+    /// Returns the map reversing `u` on `urange`, i.e. `u ↦ u0 + u1 - u`.
+    #[inline(always)]
+    pub fn reverse_u((u0, u1): (f64, f64)) -> ParamMap2D {
+        ParamMap2D {
+            u: (-1.0, u0 + u1),
+            ..ParamMap2D::IDENTITY
+        }
+    }
+
+    /// Returns the map reversing `v` on `vrange`, i.e. `v ↦ v0 + v1 - v`.
+    #[inline(always)]
+    pub fn reverse_v((v0, v1): (f64, f64)) -> ParamMap2D {
+        ParamMap2D {
+            v: (-1.0, v0 + v1),
+            ..ParamMap2D::IDENTITY
+        }
+    }
+
+    /// Maps the parameter `(u, v)`.
+    #[inline(always)]
+    pub fn apply(&self, u: f64, v: f64) -> (f64, f64) {
+        let (u, v) = if self.swap { (v, u) } else { (u, v) };
+        (self.u.0 * u + self.u.1, self.v.0 * v + self.v.1)
+    }
+
+    /// Returns the inverse map.
+    /// # Panics
+    /// Panics if the affine maps are not invertible, i.e. their scales are zero.
+    pub fn inverse(&self) -> ParamMap2D {
+        assert!(self.u.0 != 0.0 && self.v.0 != 0.0, "the map is not invertible.");
+        let inv = |(a, b): (f64, f64)| (1.0 / a, -b / a);
+        let (u, v) = (inv(self.u), inv(self.v));
+        let (u, v) = if self.swap { (v, u) } else { (u, v) };
+        ParamMap2D {
+            swap: self.swap,
+            u,
+            v,
+        }
+    }
+
+    /// Returns the composition applying `self` first and `other` next.
+    pub fn then(&self, other: ParamMap2D) -> ParamMap2D {
+        let (u, v) = if other.swap {
+            (self.v, self.u)
+        } else {
+            (self.u, self.v)
+        };
+        let compose = |(a0, b0): (f64, f64), (a1, b1): (f64, f64)| (a1 * a0, a1 * b0 + b1);
+        ParamMap2D {
+            swap: self.swap != other.swap,
+            u: compose(u, other.u),
+            v: compose(v, other.v),
+        }
+    }
+}
+
+/// Surfaces whose inverses report how the parameters are mapped.
+///
+/// The inverse `new` of the surface `old` satisfies the following relations for all `(u, v)`
+/// in the domain of `old`, where `map = old.inverse_map()`:
+/// - `new.subs(map.apply(u, v)) == old.subs(u, v)`,
+/// - `new.normal(map.apply(u, v)) == -old.normal(u, v)`.
+///
+/// The inverse of a bounded curve is parametrized reversely on the same range, so the surfaces
+/// inverted by inverting the curves report [`ParamMap2D::reverse_u`] or [`ParamMap2D::reverse_v`].
+pub trait InvertibleSurface: Invertible {
+    /// Returns the map from the parameters of `self` to those of `self.inverse()`.
+    fn inverse_map(&self) -> ParamMap2D;
+    /// Returns the inverse and the map from the parameters of `self` to those of the inverse.
+    #[inline(always)]
+    fn inverse_with_map(&self) -> (Self, ParamMap2D) { (self.inverse(), self.inverse_map()) }
+}
+
+impl<S: InvertibleSurface> InvertibleSurface for Box<S> {
+    #[inline(always)]
+    fn inverse_map(&self) -> ParamMap2D { (**self).inverse_map() }
+}
+
 /// Whether the surface includes the boundary curve.
 pub trait IncludeCurve<C: ParametricCurve> {
     /// Returns whether the curve `curve` is included in the surface `self`.
@@ -0,0 +1,71 @@
+use std::ops::Bound;
+use truck_base::cgmath64::*;
+use truck_geotrait::*;
+
+/// the semicubical parabola `(t^3, t^2)`, whose derivative vanishes at the cusp `t = 0`
+#[derive(Clone, Copy, Debug)]
+struct Cusp;
+
+impl ParametricCurve for Cusp {
+    type Point = Point2;
+    type Vector = Vector2;
+    fn subs(&self, t: f64) -> Point2 { Point2::new(t * t * t, t * t) }
+    fn der(&self, t: f64) -> Vector2 { Vector2::new(3.0 * t * t, 2.0 * t) }
+    fn der2(&self, t: f64) -> Vector2 { Vector2::new(6.0 * t, 2.0) }
+    fn der_n(&self, n: usize, t: f64) -> Vector2 {
+        match n {
+            0 => self.subs(t).to_vec(),
+            1 => self.der(t),
+            2 => self.der2(t),
+            3 => Vector2::new(6.0, 0.0),
+            _ => Vector2::zero(),
+        }
+    }
+    fn parameter_range(&self) -> ParameterRange { (Bound::Unbounded, Bound::Unbounded) }
+}
+
+/// the exact arc length of [`Cusp`] from `t = -1`
+fn cusp_length(t: f64) -> f64 {
+    let length_from_cusp = |t: f64| (f64::powf(9.0 * t * t + 4.0, 1.5) - 8.0) / 27.0;
+    match t < 0.0 {
+        true => length_from_cusp(1.0) - length_from_cusp(t),
+        false => length_from_cusp(1.0) + length_from_cusp(t),
+    }
+}
+
+#[test]
+fn arc_length_across_cusp() {
+    let tol = 1.0e-8;
+    // the cusp is not at the midpoints of the bisected spans
+    let range = (-1.0, 1.3);
+    let total = algo::curve::arc_length(&Cusp, range, tol);
+    assert!(f64::abs(total - cusp_length(1.3)) < tol);
+
+    for i in 0..=20 {
+        let len = total * i as f64 / 20.0;
+        let t = algo::curve::parameter_at_arc_length(&Cusp, range, len, tol);
+        assert!(f64::abs(cusp_length(t) - len) < 10.0 * tol, "{len} {t}");
+    }
+    // Newton's method cannot step at the cusp, where the speed vanishes.
+    let len = cusp_length(0.0);
+    let t = algo::curve::parameter_at_arc_length(&Cusp, range, len, tol);
+    assert!(f64::abs(cusp_length(t) - len) < 10.0 * tol, "{t}");
+
+    // the lengths out of the range are clamped
+    assert_eq!(algo::curve::parameter_at_arc_length(&Cusp, range, -1.0, tol), -1.0);
+    assert_eq!(algo::curve::parameter_at_arc_length(&Cusp, range, total + 1.0, tol), 1.3);
+}
+
+#[test]
+fn arc_length_from_cusp() {
+    let tol = 1.0e-8;
+    let table = algo::curve::arc_length_table(&Cusp, &[0.0, 0.5, 1.0], tol);
+    assert_eq!(table[0], (0.0, 0.0));
+    let exact = |t: f64| cusp_length(t) - cusp_length(0.0);
+    assert!(f64::abs(table[1].1 - exact(0.5)) < tol);
+    assert!(f64::abs(table[2].1 - exact(1.0)) < 2.0 * tol);
+    for len in [1.0e-6, 0.1, 0.5, 1.2] {
+        let t = algo::curve::parameter_by_arc_length(&Cusp, &table, len, tol);
+        assert!(f64::abs(exact(t) - len) < 10.0 * tol, "{len} {t}");
+    }
+}
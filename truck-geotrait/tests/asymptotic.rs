@@ -0,0 +1,133 @@
+use truck_base::{cgmath64::*, tolerance::*, *};
+use truck_geotrait::*;
+
+/// the unit sphere, `u` is the longitude and `v` is the latitude
+#[derive(Clone, Debug)]
+struct Sphere;
+
+impl ParametricSurface for Sphere {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        Point3::new(u.cos() * v.cos(), u.sin() * v.cos(), v.sin())
+    }
+    fn uder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(-u.sin() * v.cos(), u.cos() * v.cos(), 0.0)
+    }
+    fn vder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(-u.cos() * v.sin(), -u.sin() * v.sin(), v.cos())
+    }
+    fn uuder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(-u.cos() * v.cos(), -u.sin() * v.cos(), 0.0)
+    }
+    fn uvder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(u.sin() * v.sin(), -u.cos() * v.sin(), 0.0)
+    }
+    fn vvder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(-u.cos() * v.cos(), -u.sin() * v.cos(), -v.sin())
+    }
+    fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> Vector3 { unimplemented!() }
+}
+impl ParametricSurface3D for Sphere {}
+
+/// the cylinder with the radius 2 around the z-axis
+#[derive(Clone, Debug)]
+struct Cylinder;
+
+impl ParametricSurface for Cylinder {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(2.0 * u.cos(), 2.0 * u.sin(), v) }
+    fn uder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(-2.0 * u.sin(), 2.0 * u.cos(), 0.0) }
+    fn vder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_z() }
+    fn uuder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(-2.0 * u.cos(), -2.0 * u.sin(), 0.0) }
+    fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> Vector3 { unimplemented!() }
+}
+impl ParametricSurface3D for Cylinder {}
+
+/// the graph of `z = x^2 - 3y^2 + xy`, with the skewed parameters `x = u + v / 2, y = v`
+#[derive(Clone, Debug)]
+struct Saddle;
+
+impl Saddle {
+    /// the derivations `[z_x, z_y, z_xx, z_xy, z_yy]` of the height
+    fn height_ders(x: f64, y: f64) -> [f64; 5] { [2.0 * x + y, -6.0 * y + x, 2.0, 1.0, -6.0] }
+}
+
+impl ParametricSurface for Saddle {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        let (x, y) = (u + v / 2.0, v);
+        Point3::new(x, y, x * x - 3.0 * y * y + x * y)
+    }
+    fn uder(&self, u: f64, v: f64) -> Vector3 {
+        let [zx, _, _, _, _] = Self::height_ders(u + v / 2.0, v);
+        Vector3::new(1.0, 0.0, zx)
+    }
+    fn vder(&self, u: f64, v: f64) -> Vector3 {
+        let [zx, zy, _, _, _] = Self::height_ders(u + v / 2.0, v);
+        Vector3::new(0.5, 1.0, zx / 2.0 + zy)
+    }
+    fn uuder(&self, u: f64, v: f64) -> Vector3 {
+        let [_, _, zxx, _, _] = Self::height_ders(u + v / 2.0, v);
+        Vector3::new(0.0, 0.0, zxx)
+    }
+    fn uvder(&self, u: f64, v: f64) -> Vector3 {
+        let [_, _, zxx, zxy, _] = Self::height_ders(u + v / 2.0, v);
+        Vector3::new(0.0, 0.0, zxx / 2.0 + zxy)
+    }
+    fn vvder(&self, u: f64, v: f64) -> Vector3 {
+        let [_, _, zxx, zxy, zyy] = Self::height_ders(u + v / 2.0, v);
+        Vector3::new(0.0, 0.0, zxx / 4.0 + zxy + zyy)
+    }
+    fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> Vector3 { unimplemented!() }
+}
+impl ParametricSurface3D for Saddle {}
+
+/// Returns the normal curvature of the surface in the tangent direction `dir`.
+fn normal_curvature<S: ParametricSurface3D>(surface: &S, (u, v): (f64, f64), dir: Vector3) -> f64 {
+    let (uder, vder) = (surface.uder(u, v), surface.vder(u, v));
+    let first = Matrix2::new(uder.dot(uder), uder.dot(vder), uder.dot(vder), vder.dot(vder));
+    let x = first.invert().unwrap() * Vector2::new(dir.dot(uder), dir.dot(vder));
+    let normal = surface.normal(u, v);
+    let second = surface.uuder(u, v).dot(normal) * x.x * x.x
+        + 2.0 * surface.uvder(u, v).dot(normal) * x.x * x.y
+        + surface.vvder(u, v).dot(normal) * x.y * x.y;
+    second / (uder * x.x + vder * x.y).magnitude2()
+}
+
+fn parameters() -> impl Iterator<Item = (f64, f64)> {
+    (0..5).flat_map(|i| (0..5).map(move |j| (0.3 * i as f64 - 0.6, 0.2 * j as f64 - 0.4)))
+}
+
+#[test]
+fn elliptic() {
+    parameters().for_each(|(u, v)| assert!(Sphere.asymptotic_directions(u, v).is_empty()));
+}
+
+#[test]
+fn parabolic() {
+    parameters().for_each(|(u, v)| {
+        let dirs = Cylinder.asymptotic_directions(u, v);
+        assert_eq!(dirs.len(), 1);
+        // the rulings of the cylinder
+        assert!(dirs[0].cross(Vector3::unit_z()).so_small());
+    });
+}
+
+#[test]
+fn hyperbolic() {
+    parameters().for_each(|(u, v)| {
+        let dirs = Saddle.asymptotic_directions(u, v);
+        assert_eq!(dirs.len(), 2);
+        assert!(!dirs[0].cross(dirs[1]).so_small());
+        dirs.iter().for_each(|dir| {
+            assert_near!(dir.magnitude(), 1.0);
+            assert!(dir.dot(Saddle.normal(u, v)).so_small());
+            assert!(normal_curvature(&Saddle, (u, v), *dir).so_small());
+        });
+    });
+}
@@ -0,0 +1,107 @@
+use algo::surface;
+use std::f64::consts::PI;
+use std::ops::Bound;
+use truck_base::{cgmath64::*, *};
+use truck_geotrait::*;
+
+/// the unit square in the xy-plane, scaled by 2 in the direction of the x-axis
+#[derive(Clone, Debug)]
+struct Rectangle;
+
+impl ParametricSurface for Rectangle {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(2.0 * u, v, 0.0) }
+    fn uder(&self, _: f64, _: f64) -> Vector3 { Vector3::new(2.0, 0.0, 0.0) }
+    fn vder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_y() }
+    fn uuder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> Vector3 { unimplemented!() }
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
+        let range = (Bound::Included(0.0), Bound::Included(1.0));
+        (range, range)
+    }
+}
+impl ParametricSurface3D for Rectangle {}
+
+/// the cylinder with the radius 2 around the z-axis
+#[derive(Clone, Debug)]
+struct Cylinder;
+
+impl ParametricSurface for Cylinder {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(2.0 * u.cos(), 2.0 * u.sin(), v) }
+    fn uder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(-2.0 * u.sin(), 2.0 * u.cos(), 0.0) }
+    fn vder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_z() }
+    fn uuder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(-2.0 * u.cos(), -2.0 * u.sin(), 0.0) }
+    fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> Vector3 { unimplemented!() }
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
+        (
+            (Bound::Included(0.0), Bound::Excluded(2.0 * PI)),
+            (Bound::Included(0.0), Bound::Included(10.0)),
+        )
+    }
+    fn u_period(&self) -> Option<f64> { Some(2.0 * PI) }
+}
+impl ParametricSurface3D for Cylinder {}
+
+fn length<S: ParametricSurface3D>(surface: &S, path: &[(f64, f64)]) -> f64 {
+    path.windows(2)
+        .map(|p| surface.subs(p[0].0, p[0].1).distance(surface.subs(p[1].0, p[1].1)))
+        .sum()
+}
+
+#[test]
+fn geodesic_on_plane() {
+    let path = surface::geodesic(&Rectangle, (0.1, 0.2), (1.0, 2.0), 0.5, 1.0e-6);
+    assert_near!(Point2::from(path[0]), Point2::new(0.1, 0.2));
+    assert_near!(length(&Rectangle, &path), 0.5);
+    // the direction in the space is `(2, 2, 0)`
+    let end = Rectangle.subs(0.1, 0.2) + Vector3::new(1.0, 1.0, 0.0).normalize() * 0.5;
+    let (u, v) = *path.last().unwrap();
+    assert_near!(Rectangle.subs(u, v), end);
+    path.iter().for_each(|(u, v)| {
+        let pt = Rectangle.subs(*u, *v);
+        assert_near!(pt.x - pt.y, 0.0);
+    });
+}
+
+#[test]
+fn geodesic_stops_at_boundary() {
+    let path = surface::geodesic(&Rectangle, (0.1, 0.5), (1.0, 0.0), 10.0, 1.0e-6);
+    assert_near!(Point2::from(*path.last().unwrap()), Point2::new(1.0, 0.5));
+    assert_near!(length(&Rectangle, &path), 1.8);
+
+    // the degenerate direction
+    let path = surface::geodesic(&Rectangle, (0.1, 0.5), (0.0, 0.0), 10.0, 1.0e-6);
+    assert_eq!(path, vec![(0.1, 0.5)]);
+}
+
+#[test]
+fn geodesic_on_cylinder() {
+    // the helix going around the cylinder twice, the parameters are not wrapped
+    let pitch = 1.0;
+    let turn = f64::sqrt((4.0 * PI).powi(2) + pitch * pitch);
+    let path = surface::geodesic(&Cylinder, (0.0, 0.0), (2.0 * PI, pitch), 2.0 * turn, 1.0e-6);
+    assert!(path.len() > 20);
+    let (u, v) = *path.last().unwrap();
+    assert!(f64::abs(u - 4.0 * PI) < 1.0e-4);
+    assert!(f64::abs(v - 2.0 * pitch) < 1.0e-4);
+    // the helix is a straight line in the unrolled parameter space
+    path.iter().for_each(|(u, v)| {
+        assert!(f64::abs(u / (2.0 * PI) - v / pitch) < 1.0e-4);
+    });
+    // the chords are within the tolerance from the helix
+    path.windows(2).for_each(|p| {
+        let mid = Cylinder.subs(p[0].0, p[0].1).midpoint(Cylinder.subs(p[1].0, p[1].1));
+        assert!(2.0 - mid.to_vec().truncate().magnitude() < 1.0e-5);
+    });
+
+    // the geodesic along the generator stops at the top
+    let path = surface::geodesic(&Cylinder, (1.0, 3.0), (0.0, 1.0), 20.0, 1.0e-6);
+    assert_near!(Point2::from(*path.last().unwrap()), Point2::new(1.0, 10.0));
+}
@@ -0,0 +1,167 @@
+use algo::surface::{self, SsnpVector};
+use truck_base::{assert_near, cgmath64::*, newton, tolerance::*};
+use truck_geotrait::*;
+
+#[derive(Clone, Copy, Debug)]
+struct EggCrate;
+
+impl ParametricSurface for EggCrate {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(u, v, f64::sin(u) * f64::sin(v)) }
+    fn uder(&self, u: f64, v: f64) -> Vector3 { Vector3::new(1.0, 0.0, f64::cos(u) * f64::sin(v)) }
+    fn vder(&self, u: f64, v: f64) -> Vector3 { Vector3::new(0.0, 1.0, f64::sin(u) * f64::cos(v)) }
+    fn uuder(&self, u: f64, v: f64) -> Vector3 { Vector3::new(0.0, 0.0, -f64::sin(u) * f64::sin(v)) }
+    fn uvder(&self, u: f64, v: f64) -> Vector3 { Vector3::new(0.0, 0.0, f64::cos(u) * f64::cos(v)) }
+    fn vvder(&self, u: f64, v: f64) -> Vector3 { Vector3::new(0.0, 0.0, -f64::sin(u) * f64::sin(v)) }
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Vector3 {
+        let d = |k: usize, x: f64| f64::sin(x + k as f64 * std::f64::consts::FRAC_PI_2);
+        match (m, n) {
+            (0, 0) => self.subs(u, v).to_vec(),
+            (1, 0) => self.uder(u, v),
+            (0, 1) => self.vder(u, v),
+            _ => Vector3::new(0.0, 0.0, d(m, u) * d(n, v)),
+        }
+    }
+}
+
+/// the unit sphere, `u` is the longitude and `v` is the latitude
+#[derive(Clone, Copy, Debug)]
+struct UnitSphere;
+
+impl ParametricSurface for UnitSphere {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        Point3::new(f64::cos(u) * f64::cos(v), f64::sin(u) * f64::cos(v), f64::sin(v))
+    }
+    fn uder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(-f64::sin(u) * f64::cos(v), f64::cos(u) * f64::cos(v), 0.0)
+    }
+    fn vder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(-f64::cos(u) * f64::sin(v), -f64::sin(u) * f64::sin(v), f64::cos(v))
+    }
+    fn uuder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(-f64::cos(u) * f64::cos(v), -f64::sin(u) * f64::cos(v), 0.0)
+    }
+    fn uvder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(f64::sin(u) * f64::sin(v), -f64::cos(u) * f64::sin(v), 0.0)
+    }
+    fn vvder(&self, u: f64, v: f64) -> Vector3 { -self.subs(u, v).to_vec() }
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Vector3 {
+        match (m, n) {
+            (0, 0) => self.subs(u, v).to_vec(),
+            (1, 0) => self.uder(u, v),
+            (0, 1) => self.vder(u, v),
+            (2, 0) => self.uuder(u, v),
+            (1, 1) => self.uvder(u, v),
+            (0, 2) => self.vvder(u, v),
+            _ => unimplemented!(),
+        }
+    }
+}
+
+fn is_foot(surface: &EggCrate, point: Point3, (u, v): (f64, f64)) -> bool {
+    let diff = surface.subs(u, v) - point;
+    diff.dot(surface.uder(u, v)).so_small() && diff.dot(surface.vder(u, v)).so_small()
+}
+
+#[test]
+fn newton_fails_around_saddle() {
+    let surface = EggCrate;
+    let point = Point3::new(0.6, 1.8, -0.9);
+    let hint = (1.6, 1.1);
+
+    let function = move |param: Vector3| SsnpVector::subs(&surface, point, param);
+    assert!(newton::solve(function, Vector3::new(hint.0, hint.1, 0.0), 100).is_err());
+
+    let res = surface::search_nearest_parameter(&surface, point, hint, 100).unwrap();
+    assert!(is_foot(&surface, point, res));
+    let dist = surface.subs(res.0, res.1).distance(point);
+    assert!(dist < surface.subs(hint.0, hint.1).distance(point));
+}
+
+#[test]
+fn damped_search_decreases_distance() {
+    let surface = EggCrate;
+    for i in 0..20 {
+        for j in 0..20 {
+            for k in 0..10 {
+                let point = Point3::new(i as f64 * 0.3, j as f64 * 0.3, k as f64 * 0.3 - 1.5);
+                let hint = (i as f64 * 0.3 + 1.0, j as f64 * 0.3 - 0.7);
+                let res = surface::search_nearest_parameter_damped(
+                    &surface,
+                    point,
+                    hint,
+                    100,
+                    surface::INITIAL_DAMPING,
+                )
+                .unwrap_or_else(|| panic!("{point:?} {hint:?}"));
+                assert!(is_foot(&surface, point, res));
+                let dist = surface.subs(res.0, res.1).distance(point);
+                assert!(dist <= surface.subs(hint.0, hint.1).distance(point));
+            }
+        }
+    }
+}
+
+#[test]
+fn damped_search_on_sphere() {
+    let surface = UnitSphere;
+    let point = Point3::new(0.3, 0.2, 0.1);
+    let nearest = 1.0 - point.to_vec().magnitude();
+
+    // Newton's method converges to the farthest point from the hint on the opposite side.
+    let hint = (3.0, 0.0);
+    let function = move |param: Vector3| SsnpVector::subs(&surface, point, param);
+    let res = newton::solve(function, Vector3::new(hint.0, hint.1, 0.0), 100).unwrap();
+    assert_near!(surface.subs(res.x, res.y).distance(point), 2.0 - nearest);
+
+    for i in 0..=12 {
+        for j in 0..=8 {
+            let hint = (i as f64 * 0.5 - 3.0, j as f64 * 0.35 - 1.4);
+            let res = surface::search_nearest_parameter_damped(
+                &surface,
+                point,
+                hint,
+                100,
+                surface::INITIAL_DAMPING,
+            )
+            .unwrap_or_else(|| panic!("{hint:?}"));
+            assert_near!(surface.subs(res.0, res.1).distance(point), nearest);
+        }
+    }
+}
+
+#[test]
+fn verbose_search_reports_failure() {
+    let surface = UnitSphere;
+    // the point on the sphere
+    let point = surface.subs(0.4, 0.3);
+    let res = surface::search_parameter_verbose(&surface, point, (0.3, 0.2), 100).unwrap();
+    assert_near!(surface.subs(res.0, res.1), point);
+    let res = surface::search_parameter(&surface, point, (0.3, 0.2), 100);
+    assert!(res.is_some());
+
+    // Newton's method converges to the foot of the perpendicular, which is apart from the point.
+    let point = Point3::new(1.5, 0.0, 0.0);
+    let failure = surface::search_parameter_verbose(&surface, point, (0.3, 0.2), 100).unwrap_err();
+    assert!(Vector2::from(failure.parameter).magnitude() < 1.0e-3);
+    assert_near!(failure.residual, 0.5);
+    assert!(0 < failure.iterations && failure.iterations <= 101);
+    assert!(!failure.degenerate);
+    assert!(surface::search_parameter(&surface, point, (0.3, 0.2), 100).is_none());
+
+    // The fallback can find the foot around the saddle, so the search succeeds.
+    let surface = EggCrate;
+    let (point, hint) = (Point3::new(0.6, 1.8, -0.9), (1.6, 1.1));
+    let res = surface::search_nearest_parameter_verbose(&surface, point, hint, 100).unwrap();
+    assert!(is_foot(&surface, point, res));
+
+    // Without the iterations, the last parameter is the hint.
+    let failure = surface::search_nearest_parameter_verbose(&surface, point, hint, 0).unwrap_err();
+    assert_eq!(failure.parameter, hint);
+    assert_eq!(failure.iterations, 1);
+    assert_near!(failure.residual, surface.subs(hint.0, hint.1).distance(point));
+    assert!(surface::search_nearest_parameter(&surface, point, hint, 0).is_none());
+}
@@ -0,0 +1,321 @@
+use algo::surface::{self, JitterMode};
+use std::f64::consts::PI;
+use truck_base::{cgmath64::*, hash::HashGen, tolerance::TOLERANCE};
+use truck_geotrait::*;
+
+/// the surface `z = 0.001 sin(40πu)` translated by `offset`
+#[derive(Clone, Copy, Debug)]
+struct Corrugated {
+    offset: Vector3,
+}
+
+impl ParametricSurface for Corrugated {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        Point3::new(u, v, 0.001 * f64::sin(40.0 * PI * u)) + self.offset
+    }
+    fn uder(&self, u: f64, _: f64) -> Vector3 {
+        Vector3::new(1.0, 0.0, 0.04 * PI * f64::cos(40.0 * PI * u))
+    }
+    fn vder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_y() }
+    fn uuder(&self, u: f64, _: f64) -> Vector3 {
+        Vector3::new(0.0, 0.0, -1.6 * PI * PI * f64::sin(40.0 * PI * u))
+    }
+    fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Vector3 {
+        match (m, n) {
+            (0, 0) => self.subs(u, v).to_vec(),
+            (1, 0) => self.uder(u, v),
+            (0, 1) => self.vder(u, v),
+            (2, 0) => self.uuder(u, v),
+            (_, 0) => {
+                let coef = 0.001 * f64::powi(40.0 * PI, m as i32);
+                let t = 40.0 * PI * u + m as f64 * PI / 2.0;
+                Vector3::new(0.0, 0.0, coef * f64::sin(t))
+            }
+            _ => Vector3::zero(),
+        }
+    }
+}
+
+/// whether the division captures all the crests and troughs of the oscillation.
+fn resolves(udiv: &[f64]) -> bool {
+    (0..40).all(|i| {
+        let (t0, t1) = (i as f64 / 40.0, (i + 1) as f64 / 40.0);
+        udiv.iter().any(|u| t0 < *u && *u < t1)
+    })
+}
+
+#[test]
+fn corrugated_surface_division() {
+    let range = ((0.0, 1.0), (0.0, 1.0));
+    let mut legacy_failed = false;
+    let mut first_division = None;
+    for i in 0..50 {
+        let offset = Vector3::new(i as f64 * 0.37, i as f64 * 1.3 - 20.0, i as f64 * 0.011);
+        let surface = Corrugated { offset };
+
+        let (udiv, vdiv) = surface::parameter_division(&surface, range, 5.0e-4);
+        match &first_division {
+            None => first_division = Some((udiv, vdiv)),
+            Some(division) => assert_eq!(division, &(udiv, vdiv)),
+        }
+
+        let (udiv, _) =
+            surface::parameter_division_with_jitter(&surface, range, 5.0e-4, JitterMode::Hashed);
+        legacy_failed |= !resolves(&udiv);
+    }
+    let (udiv, _) = first_division.unwrap();
+    assert!(resolves(&udiv));
+    assert!(legacy_failed);
+}
+
+/// the unit sphere, `u` for the longitude and `v` for the colatitude
+#[derive(Clone, Copy, Debug)]
+struct UvSphere;
+
+impl ParametricSurface for UvSphere {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        Point3::new(f64::sin(v) * f64::cos(u), f64::sin(v) * f64::sin(u), f64::cos(v))
+    }
+    fn uder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(-f64::sin(v) * f64::sin(u), f64::sin(v) * f64::cos(u), 0.0)
+    }
+    fn vder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(f64::cos(v) * f64::cos(u), f64::cos(v) * f64::sin(u), -f64::sin(v))
+    }
+    fn uuder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(-f64::sin(v) * f64::cos(u), -f64::sin(v) * f64::sin(u), 0.0)
+    }
+    fn uvder(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(-f64::cos(v) * f64::sin(u), f64::cos(v) * f64::cos(u), 0.0)
+    }
+    fn vvder(&self, u: f64, v: f64) -> Vector3 { -self.subs(u, v).to_vec() }
+    fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> Vector3 { unimplemented!() }
+}
+
+impl ParametricSurface3D for UvSphere {}
+
+#[test]
+fn sphere_division_at_poles() {
+    let (range, tol) = (((0.0, 2.0 * PI), (0.0, PI)), 1.0e-3);
+    let degenerate = UvSphere.degenerate_boundaries(range, TOLERANCE);
+    assert_eq!(degenerate, vec![DegenerateEdge::VFront, DegenerateEdge::VBack]);
+
+    let (udiv, vdiv) = surface::parameter_division(&UvSphere, range, tol);
+    let count = |v0: f64, v1: f64| vdiv.iter().filter(|v| v0 <= **v && **v < v1).count();
+    let poles = usize::max(count(0.0, PI / 6.0), count(5.0 * PI / 6.0, PI + 1.0));
+    let equator = count(5.0 * PI / 12.0, 7.0 * PI / 12.0);
+    assert!(poles <= 2 * equator, "poles: {poles}, equator: {equator}");
+
+    let max_deviation = udiv
+        .windows(2)
+        .flat_map(|u| vdiv.windows(2).map(move |v| (u, v)))
+        .flat_map(|(u, v)| {
+            let corners = [[(u[0], v[0]), (u[0], v[1])], [(u[1], v[0]), (u[1], v[1])]];
+            let [[pt00, pt01], [pt10, pt11]] = corners.map(|c| c.map(|(u, v)| UvSphere.subs(u, v)));
+            (1..4).flat_map(move |i| (1..4).map(move |j| (i as f64 / 4.0, j as f64 / 4.0))).map(
+                move |(p, q)| {
+                    let pt = Point3::from_vec(
+                        pt00.to_vec() * (1.0 - p) * (1.0 - q)
+                            + pt01.to_vec() * (1.0 - p) * q
+                            + pt10.to_vec() * p * (1.0 - q)
+                            + pt11.to_vec() * p * q,
+                    );
+                    let (u, v) = (u[0] * (1.0 - p) + u[1] * p, v[0] * (1.0 - q) + v[1] * q);
+                    UvSphere.subs(u, v).distance(pt)
+                },
+            )
+        })
+        .fold(0.0, f64::max);
+    assert!(max_deviation < tol, "max deviation: {max_deviation}");
+}
+
+/// the helix of the radius `1` and the pitch `2π * pitch`
+#[derive(Clone, Copy, Debug)]
+struct Helix {
+    pitch: f64,
+}
+
+impl ParametricCurve for Helix {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, t: f64) -> Point3 { Point3::new(f64::cos(t), f64::sin(t), self.pitch * t) }
+    fn der(&self, t: f64) -> Vector3 { Vector3::new(-f64::sin(t), f64::cos(t), self.pitch) }
+    fn der2(&self, t: f64) -> Vector3 { Vector3::new(-f64::cos(t), -f64::sin(t), 0.0) }
+    fn der_n(&self, _: usize, _: f64) -> Vector3 { unimplemented!() }
+}
+
+impl ParameterDivision1D for Helix {
+    type Point = Point3;
+    fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Point3>) {
+        algo::curve::parameter_division(self, range, tol)
+    }
+    fn sample_iter(
+        &self,
+        range: (f64, f64),
+        tol: f64,
+    ) -> impl Iterator<Item = (f64, Self::Point)> {
+        algo::curve::sample_iter(self, range, tol)
+    }
+}
+
+/// the recursive division, which was the implementation of `algo::curve::parameter_division`
+fn recursive_division(
+    curve: &Helix,
+    range: (f64, f64),
+    ends: (Point3, Point3),
+    tol: f64,
+    trials: usize,
+) -> Vec<f64> {
+    let p = 0.5 + (0.2 * HashGen::hash1(ends.0.midpoint(ends.1)) - 0.1);
+    let t = range.0 * (1.0 - p) + range.1 * p;
+    let mid = ends.0 + (ends.1 - ends.0) * p;
+    if curve.subs(t).distance2(mid) < tol * tol || trials == 0 {
+        return vec![range.0, range.1];
+    }
+    let mid_param = (range.0 + range.1) / 2.0;
+    let mid_value = curve.subs(mid_param);
+    let (left, right) = ((range.0, mid_param), (mid_param, range.1));
+    let mut params = recursive_division(curve, left, (ends.0, mid_value), tol, trials - 1);
+    params.pop();
+    params.extend(recursive_division(curve, right, (mid_value, ends.1), tol, trials - 1));
+    params
+}
+
+#[test]
+fn streamed_curve_division() {
+    for (pitch, range, tol) in [
+        (0.1, (0.0, 2.0 * PI), 1.0e-2),
+        (0.0, (-1.0, 7.0), 1.0e-4),
+        (3.0, (0.3, 0.4), 1.0e-3),
+        (0.5, (0.0, 30.0), TOLERANCE),
+    ] {
+        let helix = Helix { pitch };
+        let ends = (helix.subs(range.0), helix.subs(range.1));
+        let expected = recursive_division(&helix, range, ends, tol, 100);
+
+        let mut iter = algo::curve::sample_iter(&helix, range, tol);
+        assert_eq!(iter.size_hint(), (2, None));
+        let mut params = Vec::new();
+        while let Some((t, pt)) = iter.next() {
+            assert!(iter.size_hint().0 < expected.len() - params.len());
+            assert_eq!(pt, helix.subs(t));
+            params.push(t);
+        }
+        assert_eq!(params, expected);
+
+        let (params, pts) = helix.parameter_division(range, tol);
+        assert_eq!(params, expected);
+        let streamed: Vec<(f64, Point3)> = helix.sample_iter(range, tol).collect();
+        assert_eq!(streamed, params.into_iter().zip(pts).collect::<Vec<_>>());
+    }
+}
+
+impl ParameterDivision2D for Corrugated {
+    fn parameter_division(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        surface::parameter_division(self, range, tol)
+    }
+}
+
+#[test]
+fn streamed_surface_rows() {
+    let surface = Corrugated {
+        offset: Vector3::new(1.0, 2.0, 3.0),
+    };
+    let range = ((0.0, 0.5), (0.0, 1.0));
+    let (udiv, vdiv) = surface.parameter_division(range, 5.0e-4);
+    let mut rows = surface::sample_rows(&surface, range, 5.0e-4);
+    assert_eq!(rows.vdivision(), vdiv);
+    assert_eq!(rows.len(), udiv.len());
+    rows.next();
+    assert_eq!(rows.len(), udiv.len() - 1);
+    let rows = surface::sample_rows(&surface, range, 5.0e-4);
+    for ((u, row), u0) in rows.zip(udiv) {
+        assert_eq!(u, u0);
+        let expected: Vec<Point3> = vdiv.iter().map(|v| surface.subs(u, *v)).collect();
+        assert_eq!(row, expected);
+    }
+}
+
+/// the bump of the height `0.1` at `center` on the plane
+#[derive(Clone, Copy, Debug)]
+struct Bump {
+    center: (f64, f64),
+}
+
+impl ParametricSurface for Bump {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        let (du, dv) = (u - self.center.0, v - self.center.1);
+        Point3::new(u, v, 0.1 * f64::exp(-(du * du + dv * dv) * 50.0))
+    }
+    fn uder(&self, _: f64, _: f64) -> Vector3 { unimplemented!() }
+    fn vder(&self, _: f64, _: f64) -> Vector3 { unimplemented!() }
+    fn uuder(&self, _: f64, _: f64) -> Vector3 { unimplemented!() }
+    fn uvder(&self, _: f64, _: f64) -> Vector3 { unimplemented!() }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { unimplemented!() }
+    fn der_mn(&self, _: usize, _: usize, _: f64, _: f64) -> Vector3 { unimplemented!() }
+}
+
+/// the maximum deviation of the bilinear interpolation at the centers of the cells
+fn max_center_deviation(surface: &Bump, udiv: &[f64], vdiv: &[f64]) -> f64 {
+    udiv.windows(2)
+        .flat_map(|u| vdiv.windows(2).map(move |v| (u, v)))
+        .map(|(u, v)| {
+            let corners = [(u[0], v[0]), (u[0], v[1]), (u[1], v[0]), (u[1], v[1])];
+            let sum = corners.iter().fold(Vector3::zero(), |sum, (u, v)| {
+                sum + surface.subs(*u, *v).to_vec()
+            });
+            let center = surface.subs((u[0] + u[1]) / 2.0, (v[0] + v[1]) / 2.0);
+            center.distance(Point3::from_vec(sum / 4.0))
+        })
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn refined_division() {
+    let (range, tol) = (((0.0, 1.0), (0.0, 1.0)), 1.0e-3);
+    let before = Bump { center: (0.3, 0.5) };
+    let (udiv, vdiv) = surface::parameter_division(&before, range, tol);
+
+    // the probe points of the division are jittered around the centers of the cells
+    let bound = 1.1 * tol;
+
+    // the same surface
+    let (udiv0, vdiv0) = surface::parameter_division_refine(&before, (&udiv, &vdiv), tol);
+    assert!(max_center_deviation(&before, &udiv0, &vdiv0) < bound);
+
+    // the moved bump
+    let after = Bump { center: (0.35, 0.5) };
+    let (udiv1, vdiv1) = surface::parameter_division_refine(&after, (&udiv, &vdiv), tol);
+    assert!(max_center_deviation(&after, &udiv1, &vdiv1) < bound);
+    assert_eq!((udiv1[0], udiv1[udiv1.len() - 1]), range.0);
+    assert_eq!((vdiv1[0], vdiv1[vdiv1.len() - 1]), range.1);
+    // the parameters far from the bumps are reused
+    let far = |u: &&f64| **u > 0.7;
+    let reused = udiv.iter().filter(far).filter(|u| udiv1.contains(u)).count();
+    assert_eq!(reused, udiv.iter().filter(far).count());
+
+    // the too fine division is coarsened
+    let fine: Vec<f64> = (0..=256).map(|i| i as f64 / 256.0).collect();
+    let (udiv2, vdiv2) = surface::parameter_division_refine(&after, (&fine, &fine), tol);
+    assert!(udiv2.len() < fine.len() && vdiv2.len() < fine.len());
+    assert!(max_center_deviation(&after, &udiv2, &vdiv2) < bound);
+
+    // the coarsest division is the same as the division from scratch
+    let coarsest: (&[f64], &[f64]) = (&[0.0, 1.0], &[0.0, 1.0]);
+    assert_eq!(
+        surface::parameter_division_refine(&after, coarsest, tol),
+        surface::parameter_division(&after, range, tol),
+    );
+}
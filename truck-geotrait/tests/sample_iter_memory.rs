@@ -0,0 +1,88 @@
+//! Compares the peak memory of hashing the divisions of many curves, collected or streamed.
+//! The allocator is global, so this file has only one test.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use truck_base::cgmath64::*;
+use truck_geotrait::*;
+
+struct PeakAllocator;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let current = CURRENT.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+        PEAK.fetch_max(current, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        CURRENT.fetch_sub(layout.size(), Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: PeakAllocator = PeakAllocator;
+
+/// Returns the peak of the memory allocated while running `f`, from the memory before it.
+fn peak_memory(f: impl FnOnce()) -> usize {
+    let base = CURRENT.load(Ordering::SeqCst);
+    PEAK.store(base, Ordering::SeqCst);
+    f();
+    PEAK.load(Ordering::SeqCst) - base
+}
+
+/// the arc of the unit circle on the plane `z = height`
+#[derive(Clone, Copy, Debug)]
+struct Arc {
+    height: f64,
+}
+
+impl ParametricCurve for Arc {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, t: f64) -> Point3 { Point3::new(f64::cos(t), f64::sin(t), self.height) }
+    fn der(&self, t: f64) -> Vector3 { Vector3::new(-f64::sin(t), f64::cos(t), 0.0) }
+    fn der2(&self, t: f64) -> Vector3 { Vector3::new(-f64::cos(t), -f64::sin(t), 0.0) }
+    fn der_n(&self, _: usize, _: f64) -> Vector3 { unimplemented!() }
+}
+
+fn hash_point(pt: Point3, hasher: &mut DefaultHasher) {
+    [pt.x, pt.y, pt.z].map(f64::to_bits).hash(hasher)
+}
+
+#[test]
+fn streamed_hashing_memory() {
+    // the edges of a shell with 50k edges
+    let curves: Vec<Arc> = (0..50_000).map(|i| Arc { height: i as f64 * 0.01 }).collect();
+    let (range, tol) = ((0.0, 1.5), 1.0e-5);
+
+    let mut collected = 0;
+    let collected_peak = peak_memory(|| {
+        let mut hasher = DefaultHasher::new();
+        curves.iter().for_each(|curve| {
+            let (_, pts) = algo::curve::parameter_division(curve, range, tol);
+            pts.into_iter().for_each(|pt| hash_point(pt, &mut hasher));
+        });
+        collected = hasher.finish();
+    });
+
+    let mut streamed = 0;
+    let streamed_peak = peak_memory(|| {
+        let mut hasher = DefaultHasher::new();
+        curves.iter().for_each(|curve| {
+            let pts = algo::curve::sample_iter(curve, range, tol);
+            pts.for_each(|(_, pt)| hash_point(pt, &mut hasher));
+        });
+        streamed = hasher.finish();
+    });
+
+    assert_eq!(collected, streamed);
+    assert!(
+        2 * streamed_peak < collected_peak,
+        "streamed: {streamed_peak}, collected: {collected_peak}"
+    );
+}
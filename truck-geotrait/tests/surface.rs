@@ -67,6 +67,24 @@ fn polysurface_presearch() {
     assert_eq!(v, 0.3);
 }
 
+#[test]
+fn polysurface_presearch_batch() {
+    let coef0 = vec![Vector3::new(0.0, 1.0, 0.0), Vector3::new(1.0, 0.0, 1.0)];
+    let coef1 = vec![Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, -1.0)];
+    let poly = PolynomialSurface::by_tensor(
+        PolynomialCurve::<Point3>(coef0),
+        PolynomialCurve::<Point3>(coef1),
+    );
+    let points: Vec<Point3> = (0..100)
+        .map(|_| Point3::new(rand::random(), rand::random(), 4.0 * rand::random::<f64>() - 2.0))
+        .collect();
+    let ranges = ((-0.5, 1.5), (0.0, 1.0));
+    let hints = surface::presearch_batch(&poly, &points, ranges, 30);
+    for (point, hint) in points.iter().zip(hints) {
+        assert_eq!(hint, surface::presearch(&poly, *point, ranges, 30));
+    }
+}
+
 fn exec_polysurface_snp_on_surface() -> bool {
     let coef0 = vec![
         Vector3::new(0.0, 1.0, 3.0 * rand::random::<f64>() - 1.5),
@@ -0,0 +1,204 @@
+use super::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
+use std::hash::{Hash, Hasher};
+
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::prelude::*;
+
+/// The triangle passed by [`StreamingTessellation::tessellate_with`]: the positions, the normals
+/// and the uv-coordinates of three vertices.
+pub type StreamedTriangle = ([Point3; 3], [Vector3; 3], [(f64, f64); 3]);
+
+/// The state of a face when it was tessellated. The face is tessellated again if it changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct FaceStamp {
+    /// the newest revision among the surface and the curves of the boundaries
+    revision: usize,
+    orientation: bool,
+    /// the hash of the ids and the orientations of the boundary edges
+    boundaries: u64,
+}
+
+impl FaceStamp {
+    fn new<C, S>(face: &Face<Point3, C, S>) -> Self {
+        let mut hasher = FxHasher::default();
+        face.absolute_boundaries().iter().for_each(|wire| {
+            wire.len().hash(&mut hasher);
+            wire.iter().for_each(|edge| (edge.id(), edge.orientation()).hash(&mut hasher));
+        });
+        let edge_revision = |revision: usize, edge: Edge<Point3, C>| revision.max(edge.revision());
+        Self {
+            revision: face.edge_iter().fold(face.revision(), edge_revision),
+            orientation: face.orientation(),
+            boundaries: hasher.finish(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct FaceBuffer {
+    stamp: FaceStamp,
+    triangles: Vec<StreamedTriangle>,
+}
+
+impl FaceBuffer {
+    /// The stamp is taken before tessellation, so the face is tessellated again by the next update
+    /// if the geometry is set while it is tessellated.
+    fn new<C: PolylineableCurve, S: MeshableSurface>(face: &Face<Point3, C, S>, tol: f64) -> Self {
+        let stamp = FaceStamp::new(face);
+        let mut triangles = Vec::new();
+        face.tessellate_with(tol, &mut |p, n, uv| triangles.push((p, n, uv)));
+        Self { stamp, triangles }
+    }
+}
+
+/// The faces changed by [`ShellTessellation::update`].
+#[derive(Clone, Debug)]
+pub struct UpdateReport<S> {
+    /// The faces tessellated again or newly, in the order of the shell.
+    pub refreshed: Vec<FaceID<S>>,
+    /// The faces removed from the shell, whose buffers are dropped.
+    pub removed: Vec<FaceID<S>>,
+}
+
+impl<S> UpdateReport<S> {
+    /// Returns `true` if no buffers have been changed.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.refreshed.is_empty() && self.removed.is_empty() }
+}
+
+/// The tessellation of a shell maintained face by face.
+///
+/// Each face is tessellated by [`StreamingTessellation::tessellate_with`], and the triangles are
+/// retained in the buffer of the face. [`ShellTessellation::update`] tessellates only the faces
+/// added to the shell or changed after the last tessellation, so a renderer can update only the
+/// buffers of the changed faces.
+///
+/// A face is changed if the surface, the curve of a boundary edge, the orientation or the list
+/// of the boundary edges has been changed. Moving a vertex without setting the curves of the
+/// edges is not detected.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let mut shell = cube.into_boundaries().pop().unwrap();
+///
+/// let mut tessellation = ShellTessellation::new(&shell, 0.01);
+/// assert_eq!(tessellation.triangles().count(), 12);
+///
+/// // nothing has been changed
+/// assert!(tessellation.update(&shell).is_empty());
+///
+/// // set the same plane with another parametrization
+/// let plane = match shell[0].surface() {
+///     Surface::Plane(plane) => plane,
+///     _ => unreachable!(),
+/// };
+/// let (o, p, q) = (plane.origin(), plane.u_axis(), plane.v_axis());
+/// shell[0].set_surface(Surface::Plane(Plane::new(o + p, o + p + q, o)));
+/// let report = tessellation.update(&shell);
+/// assert_eq!(report.refreshed, vec![shell[0].id()]);
+///
+/// // remove a face
+/// let face = shell.pop().unwrap();
+/// let report = tessellation.update(&shell);
+/// assert!(report.refreshed.is_empty());
+/// assert_eq!(report.removed, vec![face.id()]);
+/// assert_eq!(tessellation.triangles().count(), 10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShellTessellation<S> {
+    tol: f64,
+    order: Vec<FaceID<S>>,
+    buffers: HashMap<FaceID<S>, FaceBuffer>,
+}
+
+impl<S> ShellTessellation<S> {
+    /// Tessellates all faces of `shell`.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be greater than or equal to `TOLERANCE`.
+    pub fn new<C: PolylineableCurve>(shell: &Shell<Point3, C, S>, tol: f64) -> Self
+    where S: MeshableSurface {
+        nonpositive_tolerance!(tol);
+        let mut tessellation = Self {
+            tol,
+            order: Vec::new(),
+            buffers: HashMap::default(),
+        };
+        tessellation.update(shell);
+        tessellation
+    }
+
+    /// Returns the tolerance of the tessellation.
+    #[inline(always)]
+    pub fn tolerance(&self) -> f64 { self.tol }
+
+    /// Tessellates the faces added to `shell` or changed after the last tessellation, and drops
+    /// the buffers of the faces removed from `shell`. The buffers of the other faces are reused.
+    ///
+    /// The changed faces are tessellated in parallel except on wasm. The geometry of each face is
+    /// locked only while it is cloned, so the shell may be read by other threads in the meantime.
+    pub fn update<C: PolylineableCurve>(&mut self, shell: &Shell<Point3, C, S>) -> UpdateReport<S>
+    where S: MeshableSurface {
+        let is_changed = |face: &&Face<Point3, C, S>| {
+            let buffer = self.buffers.get(&face.id());
+            buffer.is_none_or(|buffer| buffer.stamp != FaceStamp::new(*face))
+        };
+        let changed: Vec<_> = shell.face_iter().filter(is_changed).collect();
+        #[cfg(not(target_arch = "wasm32"))]
+        let changed = changed.into_par_iter();
+        #[cfg(target_arch = "wasm32")]
+        let changed = changed.into_iter();
+        let tol = self.tol;
+        let refreshed: Vec<_> = changed
+            .map(|face| (face.id(), FaceBuffer::new(face, tol)))
+            .collect();
+
+        let order: Vec<_> = shell.face_iter().map(Face::id).collect();
+        let current: HashSet<_> = order.iter().copied().collect();
+        let removed: Vec<_> = self
+            .order
+            .iter()
+            .filter(|id| !current.contains(id))
+            .copied()
+            .collect();
+        removed.iter().for_each(|id| {
+            self.buffers.remove(id);
+        });
+        self.order = order;
+        let refreshed = refreshed
+            .into_iter()
+            .map(|(id, buffer)| {
+                self.buffers.insert(id, buffer);
+                id
+            })
+            .collect();
+        UpdateReport { refreshed, removed }
+    }
+
+    /// Returns the ids of the faces in the order of the shell at the last update.
+    #[inline(always)]
+    pub fn face_ids(&self) -> &[FaceID<S>] { &self.order }
+
+    /// Returns the triangles of the face `id`, or `None` if the face was not in the shell at the
+    /// last update.
+    #[inline(always)]
+    pub fn face_triangles(&self, id: FaceID<S>) -> Option<&[StreamedTriangle]> {
+        self.buffers.get(&id).map(|buffer| buffer.triangles.as_slice())
+    }
+
+    /// Returns the iterator over the triangles of all faces in the order of the shell.
+    pub fn triangles(&self) -> impl Iterator<Item = &StreamedTriangle> {
+        self.order
+            .iter()
+            .flat_map(|id| self.buffers.get(id).into_iter().flat_map(|b| &b.triangles))
+    }
+}
@@ -357,4 +357,428 @@ impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape
     }
 }
 
+/// Trait for tessellating `Face` and `Shell` without building polygon meshes.
+pub trait StreamingTessellation {
+    /// Tessellates shapes and passes each triangle to `sink` as the tuple of positions,
+    /// normals and uv-coordinates of three vertices.
+    /// The division of curves and surfaces and the triangulation are the same as [`MeshableShape::triangulation`],
+    /// however, no polygon mesh is retained.
+    ///
+    /// The winding of triangles and the normals are compatible to the orientation of faces,
+    /// and holes of faces are not filled.
+    /// Faces whose boundaries cannot be projected to their surfaces are skipped.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be greater than or equal to `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let face: Face = builder::tsweep(&e, Vector3::unit_y());
+    ///
+    /// let mut area = 0.0;
+    /// face.tessellate_with(0.01, &mut |p, n, _| {
+    ///     let normal = (p[1] - p[0]).cross(p[2] - p[0]);
+    ///     assert!(normal.dot(n[0]) > 0.0);
+    ///     area += normal.magnitude() / 2.0;
+    /// });
+    /// assert_near!(area, 1.0);
+    /// ```
+    fn tessellate_with<F>(&self, tol: f64, sink: &mut F)
+    where F: FnMut([Point3; 3], [Vector3; 3], [(f64, f64); 3]);
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> StreamingTessellation for Face<Point3, C, S> {
+    fn tessellate_with<F>(&self, tol: f64, sink: &mut F)
+    where F: FnMut([Point3; 3], [Vector3; 3], [(f64, f64); 3]) {
+        nonpositive_tolerance!(tol);
+        triangulation::face_streaming_tessellation(self, tol, triangulation::by_search_parameter, sink)
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> StreamingTessellation for Shell<Point3, C, S> {
+    fn tessellate_with<F>(&self, tol: f64, sink: &mut F)
+    where F: FnMut([Point3; 3], [Vector3; 3], [(f64, f64); 3]) {
+        self.face_iter().for_each(|face| face.tessellate_with(tol, sink))
+    }
+}
+
+/// Parallel version of [`StreamingTessellation`] for `Shell`.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait ParallelStreamingTessellation {
+    /// Tessellates faces in parallel and passes each triangle to the sink.
+    /// Each thread creates its own sink by `sink_factory`.
+    /// The order of faces passed to sinks is not deterministic.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be greater than or equal to `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    /// use std::sync::Mutex;
+    ///
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let area = Mutex::new(0.0);
+    /// cube.boundaries()[0].par_tessellate_with(0.01, || {
+    ///     |p: [Point3; 3], _, _| {
+    ///         *area.lock().unwrap() += (p[1] - p[0]).cross(p[2] - p[0]).magnitude() / 2.0;
+    ///     }
+    /// });
+    /// assert_near!(area.into_inner().unwrap(), 6.0);
+    /// ```
+    fn par_tessellate_with<G, F>(&self, tol: f64, sink_factory: G)
+    where
+        G: Fn() -> F + Send + Sync,
+        F: FnMut([Point3; 3], [Vector3; 3], [(f64, f64); 3]);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<C: PolylineableCurve, S: MeshableSurface> ParallelStreamingTessellation
+    for Shell<Point3, C, S>
+{
+    fn par_tessellate_with<G, F>(&self, tol: f64, sink_factory: G)
+    where
+        G: Fn() -> F + Send + Sync,
+        F: FnMut([Point3; 3], [Vector3; 3], [(f64, f64); 3]), {
+        use rayon::prelude::*;
+        nonpositive_tolerance!(tol);
+        self.face_par_iter()
+            .for_each_init(&sink_factory, |sink, face| face.tessellate_with(tol, sink))
+    }
+}
+
+/// Trait for tessellating `Shell` and `Solid` into one indexed mesh welded along edges.
+pub trait WeldedTessellation {
+    /// Tessellates shapes by [`MeshableShape::triangulation`] and returns the positions,
+    /// the normals and the triangles of one mesh.
+    /// The normals correspond to the positions, and the triangles are the indices of them.
+    ///
+    /// The meshes of faces are welded by the points of the polylines of the shared edges,
+    /// so the mesh of a closed shell is closed without optimization filters.
+    /// The normals of the welded vertices are the average of the normals of the faces,
+    /// and the triangles degenerated by welding, e.g. at the apexes of cones, are removed.
+    /// Faces whose boundaries cannot be projected to their surfaces are skipped.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be greater than or equal to `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    /// use truck_topology::shell::ShellCondition;
+    ///
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let (positions, normals, triangles) = cube.to_mesh(0.01);
+    /// assert_eq!(positions.len(), 8);
+    /// assert_eq!(normals.len(), 8);
+    /// assert_eq!(triangles.len(), 12);
+    ///
+    /// let attrs = StandardAttributes { positions, normals, ..Default::default() };
+    /// let mesh = PolygonMesh::new(attrs, Faces::from_iter(triangles));
+    /// assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    /// ```
+    fn to_mesh(&self, tol: f64) -> (Vec<Point3>, Vec<Vector3>, Vec<[usize; 3]>);
+
+    /// Tessellates shapes into the welded mesh same as [`WeldedTessellation::to_mesh`], and
+    /// returns the index of the originating face of each triangle.
+    ///
+    /// The index of a face is its position in `face_iter`, which counts the skipped faces,
+    /// so the faces of the shape can be looked up by the indices, e.g. for per-face materials.
+    /// The vertices of the mesh are shared by the triangles of adjacent faces, and each vertex
+    /// has the normal of the same index.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be greater than or equal to `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let (mesh, face_ids) = cube.triangulation_with_face_ids(0.01);
+    /// assert_eq!(mesh.positions().len(), 8);
+    /// assert_eq!(face_ids.len(), mesh.faces().len());
+    /// // two triangles for each face of the cube
+    /// (0..6).for_each(|i| assert_eq!(face_ids.iter().filter(|id| **id == i).count(), 2));
+    /// ```
+    fn triangulation_with_face_ids(&self, tol: f64) -> (PolygonMesh, Vec<usize>);
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> WeldedTessellation for Shell<Point3, C, S> {
+    fn to_mesh(&self, tol: f64) -> (Vec<Point3>, Vec<Vector3>, Vec<[usize; 3]>) {
+        weld_meshed_shells([&self.triangulation(tol)]).into_arrays()
+    }
+    fn triangulation_with_face_ids(&self, tol: f64) -> (PolygonMesh, Vec<usize>) {
+        weld_meshed_shells([&self.triangulation(tol)]).into_mesh()
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> WeldedTessellation for Solid<Point3, C, S> {
+    fn to_mesh(&self, tol: f64) -> (Vec<Point3>, Vec<Vector3>, Vec<[usize; 3]>) {
+        weld_meshed_shells(self.triangulation(tol).boundaries()).into_arrays()
+    }
+    fn triangulation_with_face_ids(&self, tol: f64) -> (PolygonMesh, Vec<usize>) {
+        weld_meshed_shells(self.triangulation(tol).boundaries()).into_mesh()
+    }
+}
+
+/// The quality of the triangles of a tessellation. cf. [`MeshQualityAnalysis`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeshQuality {
+    /// the minimum of the angles of the non-degenerate triangles, in radians
+    pub min_triangle_angle: f64,
+    /// the maximum of the aspect ratios of the non-degenerate triangles: the longest edge over
+    /// the shortest altitude, normalized so that the ratio of the equilateral triangle is `1`
+    pub max_aspect_ratio: f64,
+    /// the number of the triangles whose shortest altitudes are less than `TOLERANCE`
+    pub degenerate_count: usize,
+    /// the number of all triangles, including the degenerate ones
+    pub triangle_count: usize,
+}
+
+impl Default for MeshQuality {
+    /// The quality of no triangles: the angle and the aspect ratio are the ones of the
+    /// equilateral triangle, which are the bounds for all triangles.
+    fn default() -> Self {
+        Self {
+            min_triangle_angle: std::f64::consts::PI / 3.0,
+            max_aspect_ratio: 1.0,
+            degenerate_count: 0,
+            triangle_count: 0,
+        }
+    }
+}
+
+impl MeshQuality {
+    /// Adds the triangle `p` to the statistics.
+    pub fn add_triangle(&mut self, p: [Point3; 3]) {
+        self.triangle_count += 1;
+        let edges = [p[1] - p[0], p[2] - p[1], p[0] - p[2]];
+        let double_area = edges[0].cross(edges[1]).magnitude();
+        let longest = edges.iter().map(|e| e.magnitude()).fold(0.0, f64::max);
+        // the shortest altitude is on the longest edge
+        if longest.so_small() || double_area / longest < TOLERANCE {
+            self.degenerate_count += 1;
+            return;
+        }
+        let angle = (0..3)
+            .map(|i| edges[i].angle(-edges[(i + 2) % 3]).0)
+            .fold(std::f64::consts::PI, f64::min);
+        let aspect_ratio = longest * longest * f64::sqrt(3.0) / (2.0 * double_area);
+        self.min_triangle_angle = f64::min(self.min_triangle_angle, angle);
+        self.max_aspect_ratio = f64::max(self.max_aspect_ratio, aspect_ratio);
+    }
+
+    /// Merges the statistics of the other triangles.
+    pub fn merge(&mut self, other: MeshQuality) {
+        self.min_triangle_angle = f64::min(self.min_triangle_angle, other.min_triangle_angle);
+        self.max_aspect_ratio = f64::max(self.max_aspect_ratio, other.max_aspect_ratio);
+        self.degenerate_count += other.degenerate_count;
+        self.triangle_count += other.triangle_count;
+    }
+}
+
+/// Trait for measuring the quality of the triangles of tessellations, for diagnostics.
+pub trait MeshQualityAnalysis {
+    /// Tessellates each face by [`StreamingTessellation::tessellate_with`], which is the same
+    /// triangulation as [`MeshableShape::triangulation`], and returns the quality of the
+    /// triangles. The slivers, e.g. near the poles of spheres or along tight trims, are detected
+    /// by the small minimum angle and the large aspect ratio.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be greater than or equal to `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let quality = cube.boundaries()[0].mesh_quality(0.01);
+    /// assert_eq!(quality.triangle_count, 12);
+    /// assert_eq!(quality.degenerate_count, 0);
+    /// assert_near!(quality.min_triangle_angle, std::f64::consts::PI / 4.0);
+    /// assert_near!(quality.max_aspect_ratio, f64::sqrt(3.0));
+    /// ```
+    fn mesh_quality(&self, tol: f64) -> MeshQuality;
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> MeshQualityAnalysis for Face<Point3, C, S> {
+    fn mesh_quality(&self, tol: f64) -> MeshQuality {
+        let mut quality = MeshQuality::default();
+        self.tessellate_with(tol, &mut |p, _, _| quality.add_triangle(p));
+        quality
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> MeshQualityAnalysis for Shell<Point3, C, S> {
+    fn mesh_quality(&self, tol: f64) -> MeshQuality {
+        let mut quality = MeshQuality::default();
+        self.face_iter().for_each(|face| quality.merge(face.mesh_quality(tol)));
+        quality
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> MeshQualityAnalysis for Solid<Point3, C, S> {
+    fn mesh_quality(&self, tol: f64) -> MeshQuality {
+        let mut quality = MeshQuality::default();
+        self.boundaries().iter().for_each(|shell| quality.merge(shell.mesh_quality(tol)));
+        quality
+    }
+}
+
+/// The point shared by the meshes of faces: the end vertex of edges, or the `usize`-th point of
+/// the polyline of an edge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum SharedPoint {
+    Vertex(VertexID<Point3>),
+    Edge(EdgeID<PolylineCurve>, usize),
+}
+
+fn point_bits(p: Point3) -> [u64; 3] { [p.x.to_bits(), p.y.to_bits(), p.z.to_bits()] }
+
+/// The welded mesh: the normals correspond to the positions, and `face_ids[i]` is the index of
+/// the face of `triangles[i]`.
+#[derive(Clone, Debug, Default)]
+struct WeldedMesh {
+    positions: Vec<Point3>,
+    normals: Vec<Vector3>,
+    triangles: Vec<[usize; 3]>,
+    face_ids: Vec<usize>,
+}
+
+impl WeldedMesh {
+    fn into_arrays(self) -> (Vec<Point3>, Vec<Vector3>, Vec<[usize; 3]>) {
+        (self.positions, self.normals, self.triangles)
+    }
+
+    fn into_mesh(self) -> (PolygonMesh, Vec<usize>) {
+        let attrs = StandardAttributes {
+            positions: self.positions,
+            normals: self.normals,
+            ..Default::default()
+        };
+        let tris = self.triangles.into_iter().map(|tri| tri.map(|i| (i, None, Some(i))));
+        (PolygonMesh::debug_new(attrs, Faces::from_iter(tris)), self.face_ids)
+    }
+}
+
+/// Merges the meshes of faces into one indexed mesh, identifying the vertices on the same
+/// point of the polylines of edges.
+fn weld_meshed_shells<'a>(
+    shells: impl IntoIterator<Item = &'a Shell<Point3, PolylineCurve, Option<PolygonMesh>>>,
+) -> WeldedMesh {
+    use rustc_hash::FxHashMap as HashMap;
+    let WeldedMesh {
+        mut positions,
+        mut normals,
+        mut triangles,
+        mut face_ids,
+    } = WeldedMesh::default();
+    let mut shared_map = HashMap::<SharedPoint, usize>::default();
+    let faces = shells.into_iter().flat_map(Shell::face_iter).enumerate();
+    faces.for_each(|(face_id, face)| {
+        let Some(mut poly) = face.surface() else {
+            return;
+        };
+        if !face.orientation() {
+            poly.invert();
+        }
+        // The boundary points of the mesh are the copies of the points of the polylines.
+        let boundary_map: HashMap<[u64; 3], SharedPoint> = face
+            .edge_iter()
+            .flat_map(|edge| {
+                let (front, back) = (edge.absolute_front().id(), edge.absolute_back().id());
+                let (id, curve) = (edge.id(), edge.curve());
+                let last = curve.len() - 1;
+                curve.into_iter().enumerate().map(move |(i, p)| {
+                    let shared = match i {
+                        0 => SharedPoint::Vertex(front),
+                        _ if i == last => SharedPoint::Vertex(back),
+                        _ => SharedPoint::Edge(id, i),
+                    };
+                    (point_bits(p), shared)
+                })
+            })
+            .collect();
+        let mut index_map = vec![None; poly.positions().len()];
+        let mut index = |pos: usize| -> usize {
+            if let Some(idx) = index_map[pos] {
+                return idx;
+            }
+            let p = poly.positions()[pos];
+            let mut push = || {
+                positions.push(p);
+                normals.push(Vector3::zero());
+                positions.len() - 1
+            };
+            let idx = match boundary_map.get(&point_bits(p)) {
+                Some(shared) => *shared_map.entry(*shared).or_insert_with(push),
+                None => push(),
+            };
+            index_map[pos] = Some(idx);
+            idx
+        };
+        let tris: Vec<_> = poly
+            .faces()
+            .triangle_iter()
+            .map(|tri| (tri, tri.map(|v| index(v.pos))))
+            .collect();
+        tris.into_iter().for_each(|(tri, idcs)| {
+            if idcs[0] == idcs[1] || idcs[1] == idcs[2] || idcs[2] == idcs[0] {
+                return;
+            }
+            tri.iter().zip(idcs).for_each(|(v, idx)| {
+                if let Some(nor) = v.nor {
+                    normals[idx] += poly.normals()[nor];
+                }
+            });
+            triangles.push(idcs);
+            face_ids.push(face_id);
+        });
+    });
+    normals.iter_mut().for_each(|n| {
+        if !n.so_small() {
+            *n = n.normalize();
+        }
+    });
+    WeldedMesh {
+        positions,
+        normals,
+        triangles,
+        face_ids,
+    }
+}
+
+mod cache;
+pub use cache::{ShellTessellation, StreamedTriangle, UpdateReport};
 mod triangulation;
@@ -548,13 +548,26 @@ fn spade_round(x: f64) -> f64 {
     }
 }
 
-/// Tessellates one surface trimmed by polyline.
-fn trimming_tessellation<S>(surface: &S, polyboundary: &PolyBoundary, tol: f64) -> PolygonMesh
-where S: PreMeshableSurface {
+/// Creates the constrained triangulation of the parameter domain trimmed by polyline.
+fn trimming_triangulation<S>(
+    surface: &S,
+    polyboundary: &PolyBoundary,
+    tol: f64,
+) -> (Cdt, HashMap<FixedVertexHandle, Point3>)
+where
+    S: PreMeshableSurface,
+{
     let mut triangulation = Cdt::new();
     let mut boundary_map = HashMap::<FixedVertexHandle, Point3>::default();
     polyboundary.insert_to(&mut triangulation, &mut boundary_map);
     insert_surface(&mut triangulation, surface, polyboundary, tol);
+    (triangulation, boundary_map)
+}
+
+/// Tessellates one surface trimmed by polyline.
+fn trimming_tessellation<S>(surface: &S, polyboundary: &PolyBoundary, tol: f64) -> PolygonMesh
+where S: PreMeshableSurface {
+    let (triangulation, boundary_map) = trimming_triangulation(surface, polyboundary, tol);
     let mut mesh = triangulation_into_polymesh(
         triangulation.vertices(),
         triangulation.inner_faces(),
@@ -644,14 +657,7 @@ fn triangulation_into_polymesh<'a>(
         .collect();
     let tri_faces: Vec<[StandardVertex; 3]> = triangles
         .map(|tri| tri.vertices())
-        .filter(|tri| {
-            fn sp2cg(p: SPoint2) -> Point2 { Point2::new(p.x, p.y) }
-            let tri = array![i => sp2cg(*tri[i].as_ref()); 3];
-            let (a, b) = (tri[1] - tri[0], tri[2] - tri[0]);
-            let c = tri[0] + (a + b) / 3.0;
-            let area = a.x * b.y - a.y * b.x;
-            polyline.include(c) && !area.so_small2()
-        })
+        .filter(|tri| is_inner_triangle(array![i => sp2cg(*tri[i].as_ref()); 3], polyline))
         .map(|tri| {
             let idcs = array![i => vmap[&tri[i].fix()]; 3];
             array![i => [idcs[i], idcs[i], idcs[i]].into(); 3]
@@ -667,6 +673,71 @@ fn triangulation_into_polymesh<'a>(
     )
 }
 
+fn sp2cg(p: SPoint2) -> Point2 { Point2::new(p.x, p.y) }
+
+/// whether the triangle `tri` on the parameter domain is a non-degenerate triangle in the trimmed domain.
+fn is_inner_triangle(tri: [Point2; 3], polyline: &PolyBoundary) -> bool {
+    let (a, b) = (tri[1] - tri[0], tri[2] - tri[0]);
+    let c = tri[0] + (a + b) / 3.0;
+    let area = a.x * b.y - a.y * b.x;
+    polyline.include(c) && !area.so_small2()
+}
+
+/// Tessellates a face and passes each triangle to `sink` without creating polygon mesh.
+pub(super) fn face_streaming_tessellation<C, S>(
+    face: &Face<Point3, C, S>,
+    tol: f64,
+    sp: impl SP<S>,
+    sink: &mut impl FnMut([Point3; 3], [Vector3; 3], [(f64, f64); 3]),
+) where
+    C: PolylineableCurve,
+    S: PreMeshableSurface,
+{
+    let surface = face.surface();
+    let create_polyline = |edge: &Edge<Point3, C>| {
+        let curve = edge.curve();
+        let poly = PolylineCurve::from_curve(&curve, curve.range_tuple(), tol);
+        match edge.orientation() {
+            true => poly,
+            false => poly.inverse(),
+        }
+    };
+    let preboundary = face
+        .absolute_boundaries()
+        .iter()
+        .map(|wire| PolyBoundaryPiece::try_new(&surface, wire.iter().map(create_polyline), &sp))
+        .collect::<Option<Vec<_>>>();
+    let Some(preboundary) = preboundary else {
+        return;
+    };
+    let boundary = PolyBoundary::new(preboundary, &surface, tol);
+    let (triangulation, boundary_map) = trimming_triangulation(&surface, &boundary, tol);
+    triangulation
+        .inner_faces()
+        .map(|tri| tri.vertices())
+        .filter(|tri| is_inner_triangle(array![i => sp2cg(*tri[i].as_ref()); 3], &boundary))
+        .for_each(|tri| {
+            let uv = array![i => sp2cg(*tri[i].as_ref()); 3];
+            let mut positions = array![i => match boundary_map.get(&tri[i].fix()) {
+                Some(point) => *point,
+                None => surface.subs(uv[i].x, uv[i].y),
+            }; 3];
+            let mut normals = array![i => surface.normal(uv[i].x, uv[i].y); 3];
+            let mut uv = array![i => (uv[i].x, uv[i].y); 3];
+            let normal = normals[0] + normals[1] + normals[2];
+            let face_normal = (positions[1] - positions[0]).cross(positions[2] - positions[0]);
+            if (normal.dot(face_normal) < 0.0) == face.orientation() {
+                positions.swap(1, 2);
+                normals.swap(1, 2);
+                uv.swap(1, 2);
+            }
+            if !face.orientation() {
+                normals.iter_mut().for_each(|n| *n = -*n);
+            }
+            sink(positions, normals, uv)
+        });
+}
+
 fn polyline_on_surface(
     surface: impl PreMeshableSurface,
     p: SurfacePoint,
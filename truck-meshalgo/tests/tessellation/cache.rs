@@ -0,0 +1,116 @@
+use super::*;
+
+/// the shell of `n x m` unit squares on the plane `z = 0` sharing the vertices and the edges
+fn grid(n: usize, m: usize) -> Shell {
+    let v: Vec<Vec<Vertex>> = (0..=n)
+        .map(|i| (0..=m).map(|j| builder::vertex(Point3::new(i as f64, j as f64, 0.0))).collect())
+        .collect();
+    let h: Vec<Vec<Edge>> = (0..n)
+        .map(|i| (0..=m).map(|j| builder::line(&v[i][j], &v[i + 1][j])).collect())
+        .collect();
+    let w: Vec<Vec<Edge>> = (0..=n)
+        .map(|i| (0..m).map(|j| builder::line(&v[i][j], &v[i][j + 1])).collect())
+        .collect();
+    (0..n)
+        .flat_map(|i| (0..m).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let wire: Wire = vec![
+                h[i][j].clone(),
+                w[i + 1][j].clone(),
+                h[i][j + 1].inverse(),
+                w[i][j].inverse(),
+            ]
+            .into();
+            builder::try_attach_plane(vec![wire]).unwrap()
+        })
+        .collect()
+}
+
+/// sets the same plane with another parametrization
+fn reparametrize(face: &Face) {
+    let Surface::Plane(plane) = face.surface() else {
+        panic!("the face is not planar");
+    };
+    let (o, p, q) = (plane.origin(), plane.u_axis(), plane.v_axis());
+    face.set_surface(Surface::Plane(Plane::new(o + p, o + p + q, o)));
+}
+
+fn assert_same_as_scratch(tessellation: &ShellTessellation<Surface>, shell: &Shell) {
+    let mut scratch = Vec::new();
+    shell.tessellate_with(tessellation.tolerance(), &mut |p, n, _| scratch.push((p, n)));
+    assert_eq!(tessellation.triangles().count(), scratch.len());
+    tessellation
+        .triangles()
+        .zip(scratch)
+        .for_each(|((p0, n0, _), (p1, n1))| {
+            (0..3).for_each(|i| {
+                assert_near!(p0[i], p1[i]);
+                assert_near!(n0[i], n1[i]);
+            })
+        });
+}
+
+#[test]
+fn refresh_one_face() {
+    let shell = grid(40, 25);
+    assert_eq!(shell.len(), 1000);
+    let mut tessellation = ShellTessellation::new(&shell, 0.01);
+    assert_eq!(tessellation.face_ids().len(), 1000);
+    assert_same_as_scratch(&tessellation, &shell);
+    assert!(tessellation.update(&shell).is_empty());
+
+    reparametrize(&shell[500]);
+    let report = tessellation.update(&shell);
+    assert_eq!(report.refreshed, vec![shell[500].id()]);
+    assert!(report.removed.is_empty());
+    assert_same_as_scratch(&tessellation, &shell);
+
+    // the faces next to the edge are refreshed
+    let edge = shell[500].boundaries()[0][1].clone();
+    edge.set_curve(edge.curve());
+    let report = tessellation.update(&shell);
+    assert_eq!(report.refreshed, vec![shell[500].id(), shell[525].id()]);
+    assert!(tessellation.update(&shell).is_empty());
+}
+
+#[test]
+fn topology_change() {
+    let mut shell = grid(4, 4);
+    let mut tessellation = ShellTessellation::new(&shell, 0.01);
+
+    let face = shell.remove(5);
+    let report = tessellation.update(&shell);
+    assert!(report.refreshed.is_empty());
+    assert_eq!(report.removed, vec![face.id()]);
+    assert!(tessellation.face_triangles(face.id()).is_none());
+    assert_eq!(tessellation.face_ids().len(), 15);
+    assert_same_as_scratch(&tessellation, &shell);
+
+    shell.push(face.inverse());
+    shell[0].invert();
+    let report = tessellation.update(&shell);
+    assert_eq!(report.refreshed, vec![shell[0].id(), face.id()]);
+    assert!(report.removed.is_empty());
+    assert!(tessellation.face_triangles(face.id()).is_some());
+    assert_same_as_scratch(&tessellation, &shell);
+}
+
+#[test]
+fn concurrent_read() {
+    let shell = grid(10, 10);
+    let mut tessellation = ShellTessellation::new(&shell, 0.01);
+    shell.face_iter().step_by(7).for_each(reparametrize);
+    std::thread::scope(|scope| {
+        let reader = scope.spawn(|| {
+            (0..100).for_each(|_| {
+                shell.face_iter().for_each(|face| {
+                    assert!(matches!(face.surface(), Surface::Plane(_)));
+                })
+            })
+        });
+        let report = tessellation.update(&shell);
+        assert_eq!(report.refreshed.len(), 15);
+        reader.join().unwrap();
+    });
+    assert_same_as_scratch(&tessellation, &shell);
+}
@@ -2,4 +2,8 @@ use truck_meshalgo::prelude::*;
 use truck_modeling::*;
 use truck_topology::shell::ShellCondition;
 
+mod cache;
+mod quality;
+mod streaming;
 mod triangulation;
+mod welding;
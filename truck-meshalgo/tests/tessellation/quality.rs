@@ -0,0 +1,46 @@
+use super::*;
+use std::f64::consts::PI;
+
+#[test]
+fn triangle_quality() {
+    let mut quality = MeshQuality::default();
+    let s = f64::sqrt(3.0) / 2.0;
+    quality.add_triangle([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.5, s, 0.0)].map(Point3::from));
+    assert_near!(quality.min_triangle_angle, PI / 3.0);
+    assert_near!(quality.max_aspect_ratio, 1.0);
+
+    quality.add_triangle([(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (0.0, 0.0, 1.0)].map(Point3::from));
+    assert_near!(quality.min_triangle_angle, f64::atan(0.25));
+    assert_near!(quality.max_aspect_ratio, 17.0 * f64::sqrt(3.0) / 8.0);
+
+    // collinear points
+    quality.add_triangle([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (2.0, 0.0, 0.0)].map(Point3::from));
+    assert_eq!(quality.triangle_count, 3);
+    assert_eq!(quality.degenerate_count, 1);
+    assert_near!(quality.min_triangle_angle, f64::atan(0.25));
+}
+
+#[test]
+fn sliver_face() {
+    let v = builder::vertices([(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 0.05, 0.0)]);
+    let wire: Wire = (0..3).map(|i| builder::line(&v[i], &v[(i + 1) % 3])).collect();
+    let sliver: Face = builder::try_attach_plane(vec![wire]).unwrap();
+    let sliver_quality = sliver.mesh_quality(0.01);
+    assert!(sliver_quality.triangle_count > 0);
+    assert!(sliver_quality.min_triangle_angle < 0.01, "{sliver_quality:?}");
+    assert!(sliver_quality.max_aspect_ratio > 100.0, "{sliver_quality:?}");
+
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let mut faces = cube.into_boundaries().pop().unwrap();
+    let cube_quality = faces.mesh_quality(0.01);
+    assert!(cube_quality.min_triangle_angle > PI / 8.0);
+
+    faces.push(sliver);
+    let quality = faces.mesh_quality(0.01);
+    let count = cube_quality.triangle_count + sliver_quality.triangle_count;
+    assert_eq!(quality.triangle_count, count);
+    assert_eq!(quality.min_triangle_angle, sliver_quality.min_triangle_angle);
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+fn streaming_area(face: &Face, tol: f64) -> (usize, f64) {
+    let (mut count, mut area) = (0, 0.0);
+    face.tessellate_with(tol, &mut |p, n, _| {
+        let normal = (p[1] - p[0]).cross(p[2] - p[0]);
+        assert!(n.iter().all(|n| normal.dot(*n) > 0.0));
+        count += 1;
+        area += normal.magnitude() / 2.0;
+    });
+    (count, area)
+}
+
+#[test]
+fn plane_with_hole() {
+    let v = builder::vertices([
+        (0.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (2.0, 2.0, 0.0),
+        (0.0, 2.0, 0.0),
+        (0.5, 0.5, 0.0),
+        (1.5, 0.5, 0.0),
+        (1.5, 1.5, 0.0),
+        (0.5, 1.5, 0.0),
+    ]);
+    let wire0: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    let wire1: Wire = (0..4)
+        .map(|i| builder::line(&v[4 + i], &v[4 + (i + 1) % 4]))
+        .collect();
+    let face: Face = builder::try_attach_plane(vec![wire0, wire1.inverse()]).unwrap();
+
+    let (count, area) = streaming_area(&face, 0.01);
+    assert!(count >= 8);
+    assert_near!(area, 3.0);
+    face.tessellate_with(0.01, &mut |p, _, _| {
+        let c = (p[0].to_vec() + p[1].to_vec() + p[2].to_vec()) / 3.0;
+        assert!(!(0.5 < c.x && c.x < 1.5 && 0.5 < c.y && c.y < 1.5));
+    });
+
+    let (inv_count, inv_area) = streaming_area(&face.inverse(), 0.01);
+    assert_eq!(count, inv_count);
+    assert_near!(area, inv_area);
+}
+
+#[test]
+fn revolved_face() {
+    use std::f64::consts::PI;
+    let v0 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let v1 = builder::vertex(Point3::new(1.0, 0.0, 1.0));
+    let edge = builder::line(&v0, &v1);
+    let shell: Shell = builder::rsweep(&edge, Point3::origin(), Vector3::unit_z(), Rad(PI));
+
+    let (count, area) = shell.face_iter().fold((0, 0.0), |(count, area), face| {
+        let (c, a) = streaming_area(face, 0.01);
+        (count + c, area + a)
+    });
+    assert!(count > 0);
+    assert!(f64::abs(area - PI) < PI * 0.01, "area: {area}");
+
+    let mesh = shell.triangulation(0.01).to_polygon();
+    let mesh_area = mesh.faces().triangle_iter().fold(0.0, |sum, tri| {
+        let p = tri.map(|v| mesh.positions()[v.pos]);
+        sum + (p[1] - p[0]).cross(p[2] - p[0]).magnitude() / 2.0
+    });
+    assert_near!(area, mesh_area);
+
+    let par_area = std::sync::Mutex::new(0.0);
+    shell.par_tessellate_with(0.01, || {
+        |p: [Point3; 3], _, _| {
+            *par_area.lock().unwrap() += (p[1] - p[0]).cross(p[2] - p[0]).magnitude() / 2.0;
+        }
+    });
+    assert_near!(par_area.into_inner().unwrap(), area);
+}
+
+#[test]
+fn seam_crossing_face() {
+    use std::f64::consts::PI;
+    // the wall of the cylinder in the angles `[-PI / 4, PI / 4]`, crossing the seam twice
+    let p = |angle: f64, z: f64| Point3::new(f64::cos(angle), f64::sin(angle), z);
+    let (a0, a1) = (-PI / 4.0, PI / 4.0);
+    let v = builder::vertices([p(a0, 0.0), p(a0, 1.0), p(a1, 1.0), p(a1, 0.0)]);
+    let wire: Wire = vec![
+        builder::line(&v[0], &v[1]),
+        builder::circle_arc(&v[1], &v[2], p(0.0, 1.0)),
+        builder::line(&v[2], &v[3]),
+        builder::circle_arc(&v[3], &v[0], p(0.0, 0.0)),
+    ]
+    .into();
+    let w = builder::vertices([p(0.0, 0.0), p(0.0, 1.0)]);
+    let line: Edge = builder::line(&w[0], &w[1]);
+    let surface = RevolutedCurve::by_revolution(line.curve(), Point3::origin(), Vector3::unit_z());
+    let face: Face = Face::new(vec![wire], Processor::new(surface).into());
+
+    let (count, area) = streaming_area(&face, 0.01);
+    assert!(count > 0);
+    assert!(f64::abs(area - PI / 2.0) < PI * 0.01, "area: {area}");
+    // no triangles go around the cylinder through the outside of the face
+    face.tessellate_with(0.01, &mut |p, _, _| {
+        assert!(p.iter().all(|p| p.x > 0.5), "{p:?}");
+    });
+}
@@ -0,0 +1,122 @@
+use super::*;
+
+/// a cube, a cylinder, a sphere and a torus
+fn solids() -> Vec<Solid> {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube = builder::tsweep(&f, Vector3::unit_z());
+
+    let v = builder::vertex(Point3::new(0.0, -0.5, 0.5));
+    let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_y(), Rad(7.0));
+    let disk = builder::try_attach_plane(&[circle]).unwrap();
+    let cylinder = builder::tsweep(&disk, Vector3::unit_y());
+
+    let v = builder::vertex(Point3::new(0.0, 0.5, 0.0));
+    let wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_x(), Rad(std::f64::consts::PI));
+    let sphere = Solid::new(vec![builder::cone(&wire, Vector3::unit_y(), Rad(7.0))]);
+
+    let v = builder::vertex(Point3::new(0.75, 0.0, 0.25));
+    let w = builder::rsweep(&v, Point3::new(0.75, 0.0, 0.0), Vector3::unit_y(), Rad(7.0));
+    let torus = builder::rsweep(&w, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let torus = Solid::new(vec![torus]);
+    vec![cube, cylinder, sphere, torus]
+}
+
+#[test]
+fn welded_mesh_is_closed() {
+    for (i, solid) in solids().into_iter().enumerate() {
+        let (positions, normals, triangles) = solid.to_mesh(0.01);
+        assert_eq!(positions.len(), normals.len());
+        assert!(normals.iter().all(|n| n.magnitude().near(&1.0)), "solid no. {i}");
+
+        let unwelded = solid.triangulation(0.01).to_polygon();
+        assert!(positions.len() < unwelded.positions().len(), "solid no. {i}");
+        let area = |p: [Point3; 3]| (p[1] - p[0]).cross(p[2] - p[0]).magnitude() / 2.0;
+        let unwelded_area = unwelded.faces().triangle_iter().fold(0.0, |sum, tri| {
+            sum + area(tri.map(|v| unwelded.positions()[v.pos]))
+        });
+        let welded_area = triangles.iter().fold(0.0, |sum, tri| {
+            sum + area(tri.map(|idx| positions[idx]))
+        });
+        assert_near!(welded_area, unwelded_area);
+
+        let attrs = StandardAttributes {
+            positions,
+            normals,
+            ..Default::default()
+        };
+        let mesh = PolygonMesh::new(attrs, Faces::from_iter(triangles));
+        assert_eq!(
+            mesh.shell_condition(),
+            ShellCondition::Closed,
+            "not closed: solid no. {i}"
+        );
+    }
+}
+
+#[test]
+fn welded_open_shell() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let mut shell = cube.into_boundaries().pop().unwrap();
+    shell.pop();
+
+    let (positions, _, triangles) = shell.to_mesh(0.01);
+    assert_eq!(positions.len(), 8);
+    assert_eq!(triangles.len(), 10);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(triangles),
+    );
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+}
+
+#[test]
+fn face_ids_after_welding() {
+    let area = |p: [Point3; 3]| (p[1] - p[0]).cross(p[2] - p[0]).magnitude() / 2.0;
+    for (i, solid) in solids().into_iter().enumerate() {
+        let (mesh, face_ids) = solid.triangulation_with_face_ids(0.01);
+        assert_eq!(face_ids.len(), mesh.faces().len(), "solid no. {i}");
+        assert_eq!(mesh.shell_condition(), ShellCondition::Closed, "solid no. {i}");
+
+        // the triangles of each face cover the mesh of the face
+        let meshed = solid.triangulation(0.01);
+        let mut areas = vec![0.0; meshed.face_iter().count()];
+        mesh.faces().triangle_iter().zip(&face_ids).for_each(|(tri, id)| {
+            areas[*id] += area(tri.map(|v| mesh.positions()[v.pos]));
+        });
+        meshed.face_iter().zip(areas).for_each(|(face, welded_area)| {
+            let poly = face.surface().unwrap();
+            let face_area = poly.faces().triangle_iter().fold(0.0, |sum, tri| {
+                sum + area(tri.map(|v| poly.positions()[v.pos]))
+            });
+            assert_near!(welded_area, face_area);
+        });
+    }
+}
+
+#[test]
+fn face_ids_of_cube() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let (mesh, face_ids) = cube.triangulation_with_face_ids(0.01);
+    // the vertices are shared by the triangles of three faces
+    assert_eq!(mesh.positions().len(), 8);
+    let faces: Vec<_> = cube.face_iter().collect();
+    mesh.faces().triangle_iter().zip(face_ids).for_each(|(tri, id)| {
+        let p = tri.map(|v| mesh.positions()[v.pos]);
+        let normal = (p[1] - p[0]).cross(p[2] - p[0]).normalize();
+        let center = Point3::centroid(&p);
+        let face = faces[id];
+        let (u, v) = face.surface().search_parameter(center, None, 100).unwrap();
+        assert_near!(face.oriented_surface().normal(u, v), normal);
+    });
+}
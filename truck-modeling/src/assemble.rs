@@ -0,0 +1,202 @@
+use crate::{errors::Error, *};
+use std::collections::{HashMap, HashSet};
+use truck_topology::shell::ShellCondition;
+
+/// Options of [`builder::solid_from_faces`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AssembleOptions {
+    /// the distance within which the vertices are merged and the edges are welded
+    pub tolerance: f64,
+    /// If `true`, the adjacent faces on the same surface are merged into one face.
+    pub merge_faces: bool,
+    /// the number of the samples on each edge for [`AssembleError::gaps`].
+    /// cf. [`BoundaryGap::max_boundary_gap`]
+    pub gap_samples: usize,
+}
+
+impl Default for AssembleOptions {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            tolerance: TOLERANCE,
+            merge_faces: false,
+            gap_samples: 4,
+        }
+    }
+}
+
+/// The failure of [`builder::solid_from_faces`], with the diagnostics of the assembled shells.
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error("cannot assemble a solid from the faces: {cause}")]
+pub struct AssembleError {
+    /// the error of the validation by [`Solid::try_new`]
+    pub cause: Error,
+    /// the shells assembled from the faces, i.e. the healed connected components
+    pub shells: Vec<Shell>,
+    /// the defects of the shells
+    pub defects: Vec<Defect>,
+    /// the boundary loops of the shells, along which faces are missing
+    pub open_boundaries: Vec<Wire>,
+    /// the repairs performed before the validation
+    pub log: HealingLog,
+    /// the gaps between the adjacent faces, for each shell
+    pub gaps: Vec<GapReport>,
+}
+
+pub(crate) fn solid_from_faces(
+    faces: Vec<Face>,
+    options: AssembleOptions,
+) -> std::result::Result<Solid, Box<AssembleError>> {
+    let tol = options.tolerance;
+    let mut log = HealingLog::default();
+    // The faces are not mutated: the healing replaces the vertices, the edges, and the faces
+    // of the shell by new ones, so the clones of the faces held by the caller are intact.
+    let mut shell: Shell = faces.into();
+    healing::merge_vertices(&mut shell, tol, &mut log);
+    healing::weld_edges(&mut shell, tol, &mut log);
+    let mut shells = shell.connected_components();
+    for shell in &mut shells {
+        healing::repair_orientation(shell, tol, &mut log);
+        healing::remove_slivers(shell, &mut log);
+        if options.merge_faces {
+            merge_faces(shell, tol, &mut log);
+        }
+    }
+    orient_cavities(&mut shells, &mut log);
+    if shells.is_empty() {
+        shells.push(Shell::new());
+    }
+    match Solid::try_new(shells.clone()) {
+        Ok(solid) => Ok(solid),
+        Err(error) => Err(Box::new(AssembleError {
+            cause: error.into(),
+            defects: shells.iter().flat_map(healing::defects).collect(),
+            open_boundaries: shells.iter().flat_map(Shell::extract_boundaries).collect(),
+            log,
+            gaps: shells
+                .iter()
+                .map(|shell| shell.max_boundary_gap(options.gap_samples))
+                .collect(),
+            shells,
+        })),
+    }
+}
+
+/// Inverts the shells inside an odd number of the other closed shells, which bound cavities.
+/// All shells are supposed to be oriented outward.
+fn orient_cavities(shells: &mut [Shell], log: &mut HealingLog) {
+    let solids: Vec<(usize, Solid)> = shells
+        .iter()
+        .enumerate()
+        .filter(|(_, shell)| shell.shell_condition() == ShellCondition::Closed)
+        .map(|(i, shell)| (i, Solid::new_unchecked(vec![shell.clone()])))
+        .collect();
+    if solids.len() < 2 {
+        return;
+    }
+    for (i, shell) in shells.iter_mut().enumerate() {
+        let Some(point) = shell.vertex_iter().next().map(|v| v.point()) else {
+            continue;
+        };
+        let depth = solids
+            .iter()
+            .filter(|(j, solid)| *j != i && solid.contains_point(point))
+            .count();
+        if depth % 2 == 1 {
+            shell.face_iter_mut().for_each(|face| {
+                face.invert();
+                log.repairs.push(Repair::InvertFace(face.id()));
+            });
+        }
+    }
+}
+
+/// Whether the oriented surfaces of the faces are the same, i.e. the faces share the surface, or
+/// the planes of the faces coincide within `tol`.
+fn same_surface(face0: &Face, face1: &Face, tol: f64) -> bool {
+    match (face0.oriented_surface(), face1.oriented_surface()) {
+        (Surface::Plane(plane0), Surface::Plane(plane1)) => {
+            let (n0, n1) = (plane0.normal(), plane1.normal());
+            let offset = n0.dot(plane1.origin() - plane0.origin());
+            n0.dot(n1) > 0.0 && n0.cross(n1).magnitude() < tol && offset.abs() < tol
+        }
+        _ => face0.id() == face1.id() && face0.orientation() == face1.orientation(),
+    }
+}
+
+/// Merges the groups of the faces adjacent by the edges shared by two faces on the same surface.
+fn merge_faces(shell: &mut Shell, tol: f64, log: &mut HealingLog) {
+    let faces: Vec<Face> = shell.face_iter().cloned().collect();
+    let mut uses: HashMap<EdgeID, Vec<usize>> = HashMap::new();
+    faces.iter().enumerate().for_each(|(i, face)| {
+        face.edge_iter().for_each(|edge| uses.entry(edge.id()).or_default().push(i));
+    });
+    let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); faces.len()];
+    let mut internal = HashSet::new();
+    for (id, users) in &uses {
+        if let [i, j] = users[..] {
+            if i != j && same_surface(&faces[i], &faces[j], tol) {
+                adjacency[i].push(j);
+                adjacency[j].push(i);
+                internal.insert(*id);
+            }
+        }
+    }
+    let mut visited = vec![false; faces.len()];
+    let mut new_faces = Vec::with_capacity(faces.len());
+    for start in 0..faces.len() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let mut group = vec![start];
+        let mut k = 0;
+        while k < group.len() {
+            for &j in &adjacency[group[k]] {
+                if !visited[j] {
+                    visited[j] = true;
+                    group.push(j);
+                }
+            }
+            k += 1;
+        }
+        let merged = match group.len() {
+            1 => None,
+            _ => merged_face(&faces, &group, &internal),
+        };
+        match merged {
+            Some(face) => {
+                let ids = group.iter().map(|&i| faces[i].id()).collect();
+                log.repairs.push(Repair::MergeFaces(ids));
+                new_faces.push(face);
+            }
+            None => new_faces.extend(group.iter().map(|&i| faces[i].clone())),
+        }
+    }
+    *shell = new_faces.into();
+}
+
+/// Returns the face on the surface of the group bounded by the loops of the edges which are not
+/// `internal`, or `None` if the edges are not chained into simple loops.
+fn merged_face(faces: &[Face], group: &[usize], internal: &HashSet<EdgeID>) -> Option<Face> {
+    let edges: Vec<Edge> = group
+        .iter()
+        .flat_map(|&i| faces[i].boundaries())
+        .flatten()
+        .filter(|edge| !internal.contains(&edge.id()))
+        .collect();
+    let mut used = vec![false; edges.len()];
+    let mut wires = Vec::new();
+    while let Some(start) = used.iter().position(|used| !used) {
+        used[start] = true;
+        let mut wire: Wire = vec![edges[start].clone()].into();
+        while wire.back_vertex() != wire.front_vertex() {
+            let back = wire.back_vertex()?.clone();
+            let next = (0..edges.len()).find(|&k| !used[k] && edges[k].front() == &back)?;
+            used[next] = true;
+            wire.push_back(edges[next].clone());
+        }
+        wires.push(wire);
+    }
+    Face::try_new(wires, faces[group[0]].oriented_surface()).ok()
+}
@@ -0,0 +1,229 @@
+use crate::ray_casting::face_domain;
+use crate::*;
+use std::collections::HashMap;
+
+/// The audit of the junction of an edge with the previous edge in the wire.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JunctionAudit {
+    /// whether the back vertex of the previous edge is the front vertex of the edge
+    pub connected: bool,
+    /// the distance between the end of the previous oriented curve and the start of this one
+    pub gap: f64,
+    /// the angle between the tangents of the oriented curves at the junction, in `[0, π]`.
+    /// For smooth wires, an angle near `π` indicates a flipped edge.
+    pub angle: f64,
+}
+
+/// The audit of an edge in a boundary wire.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EdgeAudit {
+    /// the id of the edge
+    pub edge_id: EdgeID,
+    /// whether the edge is traversed along its curve, i.e. [`Edge::orientation`]
+    pub forward: bool,
+    /// whether the oriented curve runs from the front vertex to the back vertex
+    pub consistent_ends: bool,
+    /// the junction with the previous edge, at the front vertex
+    pub junction: JunctionAudit,
+}
+
+impl EdgeAudit {
+    /// Returns whether the edge chains head-to-tail with the previous edge.
+    #[inline(always)]
+    pub fn is_consistent(&self) -> bool {
+        self.consistent_ends && self.junction.connected && self.junction.gap < TOLERANCE
+    }
+}
+
+/// The audit of a boundary wire of a face.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WireAudit {
+    /// the audits of the edges, in the order of the wire
+    pub edges: Vec<EdgeAudit>,
+    /// the signed area enclosed by the wire as traversed in [`Face::boundaries`], in the
+    /// parameter space of the surface. `None` if the wire cannot be projected to the surface.
+    pub uv_area: Option<f64>,
+}
+
+/// The audit of the orientation of a face, by [`AuditOrientation::orientation_audit`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrientationAudit {
+    /// the id of the face
+    pub face_id: FaceID,
+    /// the orientation flag of the face, i.e. [`Face::orientation`]
+    pub orientation: bool,
+    /// the audits of the boundary wires, in the order of [`Face::boundaries`]
+    pub wires: Vec<WireAudit>,
+    /// the sum of the signed areas of the wires, multiplied by `-1` if the face is inverted.
+    /// Positive if the winding of the boundaries agrees with the orientation of the face.
+    /// `None` if the boundaries cannot be projected to the surface.
+    pub winding_agreement: Option<f64>,
+    /// whether all the edges chain head-to-tail and the winding agrees with the orientation
+    pub consistent: bool,
+}
+
+/// An edge traversed in the same direction by more than one face.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EdgeDisagreement {
+    /// the id of the edge
+    pub edge_id: EdgeID,
+    /// whether the faces traverse the edge along its curve
+    pub forward: bool,
+    /// the ids of the faces traversing the edge in the direction
+    pub faces: Vec<FaceID>,
+}
+
+/// The audit of the orientations of a shell, by [`AuditOrientation::orientation_audit`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShellOrientationAudit {
+    /// the audits of the faces, in the order of the shell
+    pub faces: Vec<OrientationAudit>,
+    /// the edges traversed in the same direction by more than one face
+    pub disagreements: Vec<EdgeDisagreement>,
+    /// whether all the faces are consistent and there are no disagreements
+    pub consistent: bool,
+}
+
+/// Audits the orientations of the boundaries, for debugging orientation bugs.
+pub trait AuditOrientation {
+    /// the report
+    type Audit;
+    /// Returns the audit of the orientations.
+    ///
+    /// For each boundary edge of a face, the traversal direction, whether its curve runs from
+    /// the front vertex to the back vertex, and the junction with the previous edge are
+    /// reported. The winding of the boundaries is measured in the parameter space of the
+    /// surface and compared with the orientation flag of the face. A shell additionally
+    /// reports the edges traversed in the same direction by two faces.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    /// let shell = &cube.boundaries()[0];
+    /// assert!(shell.orientation_audit().consistent);
+    ///
+    /// let mut shell = shell.clone();
+    /// shell[0].invert();
+    /// let audit = shell.orientation_audit();
+    /// // the inverted face is consistent in itself, but disagrees with the adjacent faces.
+    /// assert!(audit.faces[0].consistent);
+    /// assert_eq!(audit.disagreements.len(), 4);
+    /// ```
+    fn orientation_audit(&self) -> Self::Audit;
+}
+
+const DIVISION_TOLERANCE: f64 = 1.0e-3;
+
+impl AuditOrientation for Face {
+    type Audit = OrientationAudit;
+    fn orientation_audit(&self) -> OrientationAudit {
+        let sign = match self.orientation() {
+            true => 1.0,
+            false => -1.0,
+        };
+        let areas: Option<Vec<f64>> = face_domain(self, DIVISION_TOLERANCE)
+            .ok()
+            .map(|domain| domain.uvs.iter().map(|uvs| signed_area(uvs) * sign).collect());
+        let wires: Vec<WireAudit> = self
+            .boundaries()
+            .iter()
+            .enumerate()
+            .map(|(i, wire)| WireAudit {
+                edges: wire_audit(wire),
+                uv_area: areas.as_ref().map(|areas| areas[i]),
+            })
+            .collect();
+        let winding_agreement = areas.map(|areas| areas.iter().sum::<f64>() * sign);
+        let consistent = winding_agreement.is_some_and(|agreement| agreement > 0.0)
+            && wires.iter().flat_map(|wire| &wire.edges).all(EdgeAudit::is_consistent);
+        OrientationAudit {
+            face_id: self.id(),
+            orientation: self.orientation(),
+            wires,
+            winding_agreement,
+            consistent,
+        }
+    }
+}
+
+impl AuditOrientation for Shell {
+    type Audit = ShellOrientationAudit;
+    fn orientation_audit(&self) -> ShellOrientationAudit {
+        let faces: Vec<OrientationAudit> =
+            self.face_iter().map(Face::orientation_audit).collect();
+        let mut edges = Vec::<(EdgeID, bool)>::new();
+        let mut traversals = HashMap::<(EdgeID, bool), Vec<FaceID>>::new();
+        self.face_iter().for_each(|face| {
+            face.edge_iter().for_each(|edge| {
+                let key = (edge.id(), edge.orientation());
+                let faces = traversals.entry(key).or_default();
+                if faces.is_empty() {
+                    edges.push(key);
+                }
+                faces.push(face.id());
+            })
+        });
+        let disagreements: Vec<EdgeDisagreement> = edges
+            .into_iter()
+            .filter(|key| traversals[key].len() > 1)
+            .map(|(edge_id, forward)| EdgeDisagreement {
+                edge_id,
+                forward,
+                faces: traversals[&(edge_id, forward)].clone(),
+            })
+            .collect();
+        let consistent = disagreements.is_empty() && faces.iter().all(|audit| audit.consistent);
+        ShellOrientationAudit {
+            faces,
+            disagreements,
+            consistent,
+        }
+    }
+}
+
+fn wire_audit(wire: &Wire) -> Vec<EdgeAudit> {
+    let ends = |edge: &Edge| {
+        let curve = edge.oriented_curve();
+        let (t0, t1) = curve.range_tuple();
+        let start = (curve.subs(t0), curve.der(t0));
+        let end = (curve.subs(t1), curve.der(t1));
+        (start, end)
+    };
+    let edges: Vec<_> = wire.edge_iter().map(|edge| (edge, ends(edge))).collect();
+    let previous = edges.iter().cycle().skip(edges.len().saturating_sub(1));
+    edges
+        .iter()
+        .zip(previous)
+        .map(|((edge, (start, end)), (prev, (_, prev_end)))| {
+            let junction = JunctionAudit {
+                connected: prev.back() == edge.front(),
+                gap: prev_end.0.distance(start.0),
+                angle: tangent_angle(prev_end.1, start.1),
+            };
+            EdgeAudit {
+                edge_id: edge.id(),
+                forward: edge.orientation(),
+                consistent_ends: start.0.near(&edge.front().point())
+                    && end.0.near(&edge.back().point()),
+                junction,
+            }
+        })
+        .collect()
+}
+
+/// Returns the angle between the tangents, `0.0` if a tangent vanishes.
+fn tangent_angle(v0: Vector3, v1: Vector3) -> f64 {
+    match v0.so_small() || v1.so_small() {
+        true => 0.0,
+        false => v0.angle(v1).0,
+    }
+}
+
+/// Returns the signed area of the closed polygon by the shoelace formula.
+fn signed_area(polygon: &[Point2]) -> f64 {
+    let closed = polygon.iter().zip(polygon.iter().cycle().skip(1));
+    closed.map(|(p, q)| p.x * q.y - q.x * p.y).sum::<f64>() / 2.0
+}
@@ -0,0 +1,163 @@
+use crate::*;
+
+/// The number of the isoparametric curves examined to detect the axis of a spline surface.
+const ISOCURVE_SAMPLES: usize = 4;
+
+/// Extracts the axes of straight edges.
+pub trait EdgeAxis {
+    /// Returns the front point and the unit direction of the edge, if the edge is straight.
+    ///
+    /// The curve is regarded as straight if the points of its parameter division are on the
+    /// segment between the ends within `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertices([(1.0, 0.0, 0.0), (1.0, 2.0, 0.0)]);
+    /// let line: Edge = builder::line(&v[0], &v[1]);
+    /// let (origin, dir) = line.as_axis().unwrap();
+    /// assert_near!(origin, Point3::new(1.0, 0.0, 0.0));
+    /// assert_near!(dir, Vector3::unit_y());
+    ///
+    /// let arc: Edge = builder::circle_arc(&v[0], &v[1], Point3::new(2.0, 1.0, 0.0));
+    /// assert!(arc.as_axis().is_none());
+    /// ```
+    fn as_axis(&self) -> Option<(Point3, Vector3)>;
+}
+
+/// Extracts the axes of faces.
+pub trait FaceAxis {
+    /// Returns the centroid and the unit normal of a planar face, oriented as the face.
+    ///
+    /// The centroid is the center of mass of the area bounded by the boundaries, including the
+    /// holes. Returns `None` if the surface is not [`Surface::Plane`].
+    fn normal_axis(&self) -> Option<(Point3, Vector3)>;
+
+    /// Returns a point on the axis and the unit direction of the axis of a surface of revolution,
+    /// e.g. a cylinder, a cone, or a sphere.
+    ///
+    /// The axes of [`Surface::RevolutedCurve`] are read from the definitions. The B-spline and
+    /// NURBS surfaces are examined whether the isoparametric curves in one direction are the
+    /// circles whose centers are on the common axis. Returns `None` for planes and the other
+    /// surfaces.
+    fn revolution_axis(&self) -> Option<(Point3, Vector3)>;
+}
+
+impl EdgeAxis for Edge {
+    fn as_axis(&self) -> Option<(Point3, Vector3)> {
+        let (p, q) = (self.front().point(), self.back().point());
+        if p.near(&q) {
+            return None;
+        }
+        let dir = (q - p).normalize();
+        let curve = self.curve();
+        let straight = match &curve {
+            Curve::Line(_) => true,
+            _ => {
+                let (_, pts) = curve.parameter_division(curve.range_tuple(), TOLERANCE);
+                pts.into_iter().all(|pt| {
+                    let t = (pt - p).dot(dir);
+                    (pt - p - dir * t).so_small() && -TOLERANCE < t && t < p.distance(q) + TOLERANCE
+                })
+            }
+        };
+        match straight {
+            true => Some((p, dir)),
+            false => None,
+        }
+    }
+}
+
+impl FaceAxis for Face {
+    fn normal_axis(&self) -> Option<(Point3, Vector3)> {
+        let Surface::Plane(plane) = self.surface() else {
+            return None;
+        };
+        let origin = plane.origin();
+        let u_axis = plane.u_axis().normalize();
+        let v_axis = plane.normal().cross(u_axis);
+        let loops = self.boundary_in_plane(TOLERANCE)?;
+        // the area and the first moments of the polygons
+        let (area, moment) = loops
+            .iter()
+            .flat_map(|pts| pts.iter().zip(pts.iter().cycle().skip(1)))
+            .fold((0.0, Vector2::zero()), |(area, moment), (p, q)| {
+                let cross = p.0 * q.1 - p.1 * q.0;
+                let sum = Vector2::new(p.0 + q.0, p.1 + q.1);
+                (area + cross / 2.0, moment + sum * cross / 6.0)
+            });
+        if area.so_small() {
+            return None;
+        }
+        let centroid = moment / area;
+        let normal = match self.orientation() {
+            true => plane.normal(),
+            false => -plane.normal(),
+        };
+        Some((origin + u_axis * centroid.x + v_axis * centroid.y, normal))
+    }
+
+    fn revolution_axis(&self) -> Option<(Point3, Vector3)> {
+        match self.surface() {
+            Surface::RevolutedCurve(processor) => {
+                let (surface, mat) = (processor.entity(), processor.transform());
+                let origin = mat.transform_point(surface.origin());
+                let axis = mat.transform_vector(surface.axis()).normalize();
+                Some((origin, axis))
+            }
+            Surface::BSplineSurface(surface) => spline_revolution_axis(&surface),
+            Surface::NurbsSurface(surface) => spline_revolution_axis(&surface),
+            Surface::Plane(_) => None,
+        }
+    }
+}
+
+/// Returns the common axis of the circular isoparametric curves in `u` or `v` direction.
+fn spline_revolution_axis<S>(surface: &S) -> Option<(Point3, Vector3)>
+where S: ParametricSurface3D + BoundedSurface {
+    let ((u0, u1), (v0, v1)) = surface.range_tuple();
+    let lerp = |a: f64, b: f64, t: f64| a + (b - a) * t;
+    [true, false].into_iter().find_map(|u_circles| {
+        let circles = (0..ISOCURVE_SAMPLES)
+            .map(|i| {
+                let s = i as f64 / (ISOCURVE_SAMPLES - 1) as f64;
+                let pts: Vec<Point3> = (0..5)
+                    .map(|j| {
+                        let t = j as f64 / 4.0;
+                        match u_circles {
+                            true => surface.subs(lerp(u0, u1, t), lerp(v0, v1, s)),
+                            false => surface.subs(lerp(u0, u1, s), lerp(v0, v1, t)),
+                        }
+                    })
+                    .collect();
+                isocurve_circle(&pts)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let (origin, axis) = circles[0];
+        circles.iter().all(|(center, normal)| {
+            let offset = center - origin;
+            normal.cross(axis).so_small() && (offset - axis * offset.dot(axis)).so_small()
+        })
+        .then_some((origin, axis))
+    })
+}
+
+/// Returns the center and the unit normal of the circle through the points, if all points are on
+/// one circle.
+fn isocurve_circle(pts: &[Point3]) -> Option<(Point3, Vector3)> {
+    let (a, b, c) = (pts[0], pts[1], pts[2]);
+    let (ab, ac) = (b - a, c - a);
+    let normal = ab.cross(ac);
+    if normal.so_small() {
+        return None;
+    }
+    let center = a
+        + (ac.magnitude2() * normal.cross(ab) + ab.magnitude2() * ac.cross(normal))
+            / (2.0 * normal.magnitude2());
+    let (normal, radius) = (normal.normalize(), center.distance(a));
+    let on_circle =
+        |p: &Point3| (p - center).dot(normal).so_small() && p.distance(center).near(&radius);
+    match pts.iter().all(on_circle) {
+        true => Some((center, normal)),
+        false => None,
+    }
+}
@@ -1,8 +1,17 @@
 use crate::{
+    assemble::{self, AssembleError, AssembleOptions},
+    axis::{EdgeAxis, FaceAxis},
     errors::Error,
+    fillet,
+    gordon,
     geom_impls::{self, ArcConnector, ExtrudeConnector, LineConnector, RevoluteConnector},
+    normals::AlignSurfaceNormals,
+    pipe,
+    planarity, ray_casting,
+    roles::{self, RevolutionRole, RevolutionRoles, SweepRole, SweepRoles},
+    snapshot::EntityId,
     topo_traits::*,
-    Result,
+    Curve, Result, Surface,
 };
 use truck_geometry::prelude::*;
 use truck_topology::*;
@@ -12,6 +21,7 @@ type Edge<C> = truck_topology::Edge<Point3, C>;
 type Wire<C> = truck_topology::Wire<Point3, C>;
 type Face<C, S> = truck_topology::Face<Point3, C, S>;
 type Shell<C, S> = truck_topology::Shell<Point3, C, S>;
+type Solid<C, S> = truck_topology::Solid<Point3, C, S>;
 
 /// Creates and returns a vertex by a three dimensional point.
 /// # Examples
@@ -100,6 +110,60 @@ where Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>: ToSameGeometry<C> {
     Edge::new(vertex0, vertex1, curve.to_same_geometry())
 }
 
+/// Returns an arc from `vertex0` to `vertex1` with the bulge `bulge`.
+///
+/// As in DXF, `bulge` is the tangent of a quarter of the included angle of the arc.
+/// A positive bulge makes the arc counterclockwise seen from the `+z` direction,
+/// and a negative one makes it clockwise. If `bulge == 0`, returns the line edge.
+/// # Failure
+/// - If `vertex0` and `vertex1` are the same vertex, returns [`Error::FromTopology`].
+/// - If the chord from `vertex0` to `vertex1` is degenerate or parallel to the z-axis,
+///   returns [`Error::DegenerateChord`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // draw the unit upper semicircle
+/// let vertex0 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let vertex1 = builder::vertex(Point3::new(-1.0, 0.0, 0.0));
+/// let arc: Edge = builder::arc_between(&vertex0, &vertex1, 1.0).unwrap();
+/// assert_eq!(arc.front(), &vertex0);
+/// assert_eq!(arc.back(), &vertex1);
+/// # let curve = arc.oriented_curve();
+/// # let (t0, t1) = curve.range_tuple();
+/// # assert_near!(curve.subs((t0 + t1) / 2.0), Point3::new(0.0, 1.0, 0.0));
+/// # const N: usize = 10;
+/// # for i in 0..=N {
+/// #     let t = t0 + (t1 - t0) * i as f64 / N as f64;
+/// #     assert_near!(curve.subs(t).to_vec().magnitude(), 1.0);
+/// # }
+///
+/// // bulge = 0 gives a straight edge
+/// let line: Edge = builder::arc_between(&vertex0, &vertex1, 0.0).unwrap();
+/// assert!(matches!(line.curve(), Curve::Line(_)));
+/// ```
+pub fn arc_between<C>(vertex0: &Vertex, vertex1: &Vertex, bulge: f64) -> Result<Edge<C>>
+where
+    Line<Point3>: ToSameGeometry<C>,
+    Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>: ToSameGeometry<C>, {
+    let pt0 = vertex0.point();
+    let pt1 = vertex1.point();
+    if bulge.so_small() {
+        return Ok(Edge::try_new(vertex0, vertex1, Line(pt0, pt1).to_same_geometry())?);
+    }
+    let chord = pt1 - pt0;
+    let normal = chord.cross(Vector3::unit_z());
+    if normal.so_small() {
+        return match vertex0 == vertex1 {
+            true => Err(errors::Error::SameVertex.into()),
+            false => Err(Error::DegenerateChord),
+        };
+    }
+    let transit = pt0.midpoint(pt1) + bulge * chord.magnitude() / 2.0 * normal.normalize();
+    let curve = geom_impls::circle_arc_by_three_points(pt0, pt1, transit);
+    Ok(Edge::try_new(vertex0, vertex1, curve.to_same_geometry())?)
+}
+
 /// Returns a Bezier curve from `vertex0` to `vertex1` with inter control points `inter_points`.
 /// # Examples
 /// ```
@@ -282,6 +346,169 @@ where
     Ok(shell)
 }
 
+/// Returns the shell of the ruled faces between the corresponding edges of `wire0` and `wire1`.
+///
+/// The edges are paired in order as [`try_wire_homotopy`]. If `wire1` runs in the opposite
+/// direction to `wire0`, i.e. the middle points of the edges of the inverse of `wire1` are
+/// closer to the ones of `wire0` in total, the inverse is lofted instead. Since the inverse of
+/// a closed wire starts from the same vertex, the closed wires have to start from the
+/// corresponding vertices.
+/// # Examples
+/// ```
+/// // a cone frustum between the circles running in the opposite directions
+/// use truck_modeling::*;
+/// let v0 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(2.0, 0.0, 1.0));
+/// let circle0 = builder::rsweep(&v0, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+/// let circle1 = builder::rsweep(&v1, Point3::origin(), -Vector3::unit_z(), Rad(7.0));
+///
+/// let shell: Shell = builder::loft(&circle0, &circle1).unwrap();
+/// assert_eq!(shell.len(), circle0.len());
+/// assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+/// assert_eq!(shell.extract_boundaries().len(), 2);
+/// ```
+/// # Failures
+/// - [`Error::NotSameNumberOfEdges`] if the wires have different numbers of edges, and
+/// - [`Error::InconsistentWires`] if either wire is not continuous or only one is closed.
+pub fn loft<C, S>(wire0: &Wire<C>, wire1: &Wire<C>) -> Result<Shell<C, S>>
+where
+    C: ParametricCurve3D + BoundedCurve + Invertible,
+    Line<Point3>: ToSameGeometry<C>,
+    HomotopySurface<C, C>: ToSameGeometry<S>, {
+    if wire0.len() != wire1.len() {
+        return Err(Error::NotSameNumberOfEdges);
+    }
+    if !wire0.is_continuous() || !wire1.is_continuous() || wire0.is_closed() != wire1.is_closed()
+    {
+        return Err(Error::InconsistentWires);
+    }
+    let inverse = wire1.inverse();
+    match pairing_distance(wire0, &inverse) < pairing_distance(wire0, wire1) {
+        true => try_wire_homotopy(wire0, &inverse),
+        false => try_wire_homotopy(wire0, wire1),
+    }
+}
+
+/// the sum of the distances between the middle points of the corresponding edges
+fn pairing_distance<C>(wire0: &Wire<C>, wire1: &Wire<C>) -> f64
+where C: ParametricCurve3D + BoundedCurve + Invertible {
+    let middle = |edge: &Edge<C>| {
+        let curve = edge.oriented_curve();
+        let (t0, t1) = curve.range_tuple();
+        curve.subs((t0 + t1) / 2.0)
+    };
+    let iter = wire0.edge_iter().zip(wire1.edge_iter());
+    iter.map(|(edge0, edge1)| middle(edge0).distance(middle(edge1))).sum()
+}
+
+/// Reparametrizes `curves` so that the corresponding parameters are mapped to common ones, e.g.
+/// for matching the features of the sections before skinning.
+///
+/// `correspondence[i]` is the strictly increasing parameters of the features of `curves[i]`
+/// inside the parameter range, and the `j`th parameters of all curves correspond to each other.
+/// The common parameter of the `j`th features is the average of the normalized parameters
+/// `(t - t0) / (t1 - t0)`, where `(t0, t1)` is the parameter range of each curve. Each curve is
+/// wrapped by [`ReparametrizedCurve`] with the monotone piecewise cubic map from the common
+/// parameters to its own ones, so the parameter ranges of the results are `(0, 1)`. The maps of
+/// the curves which are already reparametrized are composed instead of nesting the curves.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let line0 = Line(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0));
+/// let line1 = Line(Point3::new(0.0, 1.0, 0.0), Point3::new(2.0, 1.0, 0.0));
+/// let mut curves = vec![Curve::Line(line0), Curve::Line(line1)];
+///
+/// // the features at 0.2 and 0.6 are moved to the common parameter 0.4
+/// let correspondence = vec![vec![0.2], vec![0.6]];
+/// builder::match_parameterizations(&mut curves, &correspondence).unwrap();
+/// assert_near!(curves[0].subs(0.4), Point3::new(0.2, 0.0, 0.0));
+/// assert_near!(curves[1].subs(0.4), Point3::new(1.2, 1.0, 0.0));
+/// assert_eq!(curves[1].range_tuple(), (0.0, 1.0));
+/// ```
+/// # Failures
+/// - [`Error::InvalidCorrespondence`] if the numbers of the curves or the parameters differ, and
+/// - [`Error::FromGeometry`] wrapping [`NonMonotoneParameterMap`] if the parameters are not
+///   strictly increasing inside the parameter range.
+///
+/// [`NonMonotoneParameterMap`]: truck_geometry::errors::Error::NonMonotoneParameterMap
+pub fn match_parameterizations(curves: &mut [Curve], correspondence: &[Vec<f64>]) -> Result<()> {
+    let n = correspondence.first().map_or(0, Vec::len);
+    if curves.len() != correspondence.len() || correspondence.iter().any(|p| p.len() != n) {
+        return Err(Error::InvalidCorrespondence);
+    }
+    let mut common = vec![0.0; n];
+    curves.iter().zip(correspondence).for_each(|(curve, params)| {
+        let (t0, t1) = curve.range_tuple();
+        common.iter_mut().zip(params).for_each(|(s, t)| {
+            *s += (t - t0) / (t1 - t0) / curves.len() as f64;
+        });
+    });
+    let maps = curves
+        .iter()
+        .zip(correspondence)
+        .map(|(curve, params)| {
+            let (t0, t1) = curve.range_tuple();
+            let s = std::iter::once(0.0).chain(common.iter().copied()).chain([1.0]);
+            let t = std::iter::once(t0).chain(params.iter().copied()).chain([t1]);
+            ParameterMap::try_new(s.collect(), t.collect())
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    curves.iter_mut().zip(maps).for_each(|(curve, map)| {
+        *curve = match curve {
+            Curve::ReparametrizedCurve(curve) => Curve::ReparametrizedCurve(curve.compose(&map)),
+            _ => Curve::ReparametrizedCurve(ReparametrizedCurve::new(Box::new(curve.clone()), map)),
+        };
+    });
+    Ok(())
+}
+
+/// Builds the Gordon surface interpolating the network of the curves: `u_curves` along `u`,
+/// ordered in `v`, and `v_curves` along `v`, ordered in `u`.
+///
+/// The surface is the sum of the skinning surfaces through `u_curves` and through `v_curves`,
+/// minus the tensor product surface through their intersection points. The parameters of the
+/// curves are normalized to `[0, 1]`, and the parameters of the intersections are searched
+/// and averaged through the network, so each `u`-curve should meet the `v`-curves at the same
+/// parameters, and vice versa. The first and the last curves are the boundaries of the surface.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// // the network on the surface `z = x (1 - x) (1 + y)`
+/// let u_curves: Vec<BSplineCurve<Point3>> = [0.0, 0.5, 1.0]
+///     .into_iter()
+///     .map(|y| {
+///         let (p0, p2) = (Point3::new(0.0, y, 0.0), Point3::new(1.0, y, 0.0));
+///         let p1 = Point3::new(0.5, y, (1.0 + y) / 2.0);
+///         BSplineCurve::new(KnotVec::bezier_knot(2), vec![p0, p1, p2])
+///     })
+///     .collect();
+/// let v_curves: Vec<BSplineCurve<Point3>> = [0.0, 0.3, 0.5, 1.0]
+///     .into_iter()
+///     .map(|x| {
+///         let z = x * (1.0 - x);
+///         let (p0, p1) = (Point3::new(x, 0.0, z), Point3::new(x, 1.0, 2.0 * z));
+///         BSplineCurve::new(KnotVec::bezier_knot(1), vec![p0, p1])
+///     })
+///     .collect();
+///
+/// let surface = builder::gordon_surface(&u_curves, &v_curves, TOLERANCE).unwrap();
+/// for (u, v) in [(0.25, 0.25), (0.7, 0.4), (0.5, 1.0)] {
+///     assert_near!(surface.subs(u, v), Point3::new(u, v, u * (1.0 - u) * (1.0 + v)));
+/// }
+/// ```
+/// # Failures
+/// - [`Error::InvalidCurveNetwork`] if there are less than two curves in either direction, or
+///   the curves are not ordered, and
+/// - [`Error::InconsistentCurveNetwork`] if the curves do not intersect at the common
+///   parameters within `tol`.
+pub fn gordon_surface(
+    u_curves: &[BSplineCurve<Point3>],
+    v_curves: &[BSplineCurve<Point3>],
+    tol: f64,
+) -> Result<BSplineSurface<Point3>> {
+    gordon::gordon_surface(u_curves, v_curves, tol)
+}
+
 /// Try attatiching a plane whose boundary is `wire`.
 /// # Examples
 /// ```
@@ -331,30 +558,117 @@ where
 /// ```
 pub fn try_attach_plane<C, S>(wires: impl Into<Vec<Wire<C>>>) -> Result<Face<C, S>>
 where
-    C: ParametricCurve3D + BoundedCurve,
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
     Plane: IncludeCurve<C> + ToSameGeometry<S>, {
     let wires = wires.into();
     let _ = Face::try_new(wires.clone(), ())?;
-    let pts = wires
-        .iter()
-        .map(|wire| {
-            wire.edge_iter()
-                .flat_map(|edge| {
-                    let p0 = edge.front().point();
-                    let curve = edge.curve();
-                    let (t0, t1) = curve.range_tuple();
-                    let p1 = curve.subs((t0 + t1) / 2.0);
-                    [p0, p1]
-                })
-                .collect()
-        })
-        .collect::<Vec<_>>();
+    let (plane, _) = planarity::fit_wires_plane(&wires, ToleranceSpec::default())
+        .map_err(|_| Error::WireNotInOnePlane)?;
+    Ok(Face::new_unchecked(wires, plane.to_same_geometry()))
+}
 
-    let plane = match geom_impls::attach_plane(pts) {
-        Some(got) => got,
-        None => return Err(Error::WireNotInOnePlane),
+/// Creates a face on `surface` bounded by the outer boundary `outer` and the holes `holes`.
+///
+/// The boundaries are validated as follows:
+/// - all boundaries must be closed,
+/// - all edges must be on `surface` within `tol`,
+/// - `outer` must be counterclockwise and `holes` must be clockwise in the parameter space, and
+/// - each hole must be inside `outer` and outside the other holes.
+///
+/// If a point of the edges cannot be projected to `surface`, e.g. on a degenerate surface,
+/// returns [`Error::ParameterSearchFailed`] with the edge.
+/// # Examples
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let (o, x, y) = (Point3::origin(), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0));
+/// let surface: Surface = Plane::new(o, x, y).into();
+/// let square = |p: [(f64, f64, f64); 4]| -> Wire {
+///     let v = builder::vertices(p);
+///     (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect()
+/// };
+/// let outer = square([(0.0, 0.0, 0.0), (3.0, 0.0, 0.0), (3.0, 3.0, 0.0), (0.0, 3.0, 0.0)]);
+/// let hole = square([(1.0, 1.0, 0.0), (1.0, 2.0, 0.0), (2.0, 2.0, 0.0), (2.0, 1.0, 0.0)]);
+/// let face = builder::face_on_surface(surface.clone(), outer.clone(), vec![hole], TOLERANCE);
+/// let face = face.unwrap();
+/// assert_eq!(face.boundaries().len(), 2);
+///
+/// // the hole must be inside the outer boundary
+/// let hole = square([(4.0, 1.0, 0.0), (4.0, 2.0, 0.0), (5.0, 2.0, 0.0), (5.0, 1.0, 0.0)]);
+/// let res = builder::face_on_surface(surface.clone(), outer.clone(), vec![hole], TOLERANCE);
+/// assert_eq!(res.unwrap_err(), Error::InvalidLoopNesting);
+///
+/// // the edges must be on the surface
+/// let hole = square([(1.0, 1.0, 0.0), (1.0, 2.0, 0.0), (2.0, 2.0, 0.1), (2.0, 1.0, 0.0)]);
+/// let res = builder::face_on_surface(surface, outer, vec![hole], TOLERANCE);
+/// assert!(matches!(res, Err(Error::EdgeNotOnSurface(_))));
+/// ```
+pub fn face_on_surface(
+    surface: Surface,
+    outer: Wire<Curve>,
+    holes: Vec<Wire<Curve>>,
+    tol: f64,
+) -> Result<Face<Curve, Surface>> {
+    let wires: Vec<Wire<Curve>> = std::iter::once(outer).chain(holes).collect();
+    if wires.iter().any(|wire| !wire.is_closed()) {
+        return Err(errors::Error::NotClosedWire.into());
+    }
+    let polygons = wires
+        .iter()
+        .map(|wire| boundary_polygon(&surface, wire, tol))
+        .collect::<Result<Vec<_>>>()?;
+    let area = |polygon: &[Point2]| -> f64 {
+        let closed = polygon.iter().zip(polygon.iter().cycle().skip(1));
+        closed.map(|(p, q)| p.to_vec().perp_dot(q.to_vec())).sum()
     };
-    Ok(Face::new_unchecked(wires, plane.to_same_geometry()))
+    if area(&polygons[0]) <= 0.0 || polygons[1..].iter().any(|polygon| area(polygon) >= 0.0) {
+        return Err(Error::InvalidLoopOrientation);
+    }
+    let inside = |uv: Point2, j: usize| ray_casting::winding_number(&polygons[j], uv) != 0;
+    let nested = polygons.iter().enumerate().skip(1).all(|(i, polygon)| {
+        let uv = polygon[0];
+        inside(uv, 0) && (1..polygons.len()).all(|j| j == i || !inside(uv, j))
+    });
+    match nested {
+        true => Ok(Face::try_new(wires, surface)?),
+        false => Err(Error::InvalidLoopNesting),
+    }
+}
+
+/// Returns the polygon in the parameter space of `surface` approximating `wire`, with checking
+/// that the wire is on the surface.
+fn boundary_polygon(surface: &Surface, wire: &Wire<Curve>, tol: f64) -> Result<Vec<Point2>> {
+    let periods = (surface.u_period(), surface.v_period());
+    let mut hint: Option<Point2> = None;
+    let mut polygon = Vec::new();
+    for edge in wire.edge_iter() {
+        let curve = edge.oriented_curve();
+        let (params, _) = curve.parameter_division(curve.range_tuple(), tol.max(TOLERANCE));
+        // the midpoints are also checked, since the division of lines has no inner points
+        let mids = params.windows(2).map(|t| (t[0] + t[1]) / 2.0);
+        let mut params: Vec<f64> = params.iter().copied().chain(mids).collect();
+        params.sort_by(f64::total_cmp);
+        params.pop();
+        for t in params {
+            let point = curve.subs(t);
+            let uv = surface
+                .search_nearest_parameter(point, hint.map(Into::into), 100)
+                .or_else(|| surface.search_nearest_parameter(point, None, 100))
+                .ok_or_else(|| Error::ParameterSearchFailed {
+                    entity: EntityId::Edge(edge.id()),
+                    point,
+                    hint: hint.map(Into::into),
+                    surface_kind: surface.kind(),
+                })?;
+            if surface.subs(uv.0, uv.1).distance(point) >= tol {
+                return Err(Error::EdgeNotOnSurface(edge.id()));
+            }
+            let uv = Point2::from(uv);
+            let uv = hint.map_or(uv, |hint| ray_casting::unwrap_uv(uv, hint, periods));
+            hint = Some(uv);
+            polygon.push(uv);
+        }
+    }
+    Ok(polygon)
 }
 
 /// Returns another topology whose points, curves, and surfaces are cloned.
@@ -387,6 +701,43 @@ pub fn rotated<T: Mapped<Matrix4>>(elem: &T, origin: Point3, axis: Vector3, angl
     transformed(elem, mat2 * mat1 * mat0)
 }
 
+/// Returns a vertex, edge, wire, face, shell or solid rotated about the straight edge `edge`.
+///
+/// The axis is directed from the front to the back of the edge, cf. [`EdgeAxis::as_axis`].
+/// # Failure
+/// Returns [`Error::NoAxis`] if the edge is not straight.
+/// # Examples
+/// ```
+/// use std::f64::consts::PI;
+/// use truck_modeling::*;
+/// let v = builder::vertices([(1.0, 0.0, 0.0), (1.0, 0.0, 1.0)]);
+/// let edge: Edge = builder::line(&v[0], &v[1]);
+/// let marker = builder::vertex(Point3::new(2.0, 0.0, 0.0));
+/// let rotated = builder::rotated_about_edge(&marker, &edge, Rad(PI / 2.0)).unwrap();
+/// assert_near!(rotated.point(), Point3::new(1.0, 1.0, 0.0));
+/// ```
+pub fn rotated_about_edge<T: Mapped<Matrix4>>(
+    elem: &T,
+    edge: &crate::Edge,
+    angle: Rad<f64>,
+) -> Result<T> {
+    let (origin, axis) = edge.as_axis().ok_or(Error::NoAxis)?;
+    Ok(rotated(elem, origin, axis, angle))
+}
+
+/// Returns a vertex, edge, wire, face, shell or solid rotated about the normal axis through the
+/// centroid of the planar face `face`, cf. [`FaceAxis::normal_axis`].
+/// # Failure
+/// Returns [`Error::NoAxis`] if the face is not planar.
+pub fn rotated_about_face_normal<T: Mapped<Matrix4>>(
+    elem: &T,
+    face: &crate::Face,
+    angle: Rad<f64>,
+) -> Result<T> {
+    let (origin, axis) = face.normal_axis().ok_or(Error::NoAxis)?;
+    Ok(rotated(elem, origin, axis, angle))
+}
+
 /// Returns a scaled vertex, edge, wire, face, shell or solid.
 pub fn scaled<T: Mapped<Matrix4>>(elem: &T, origin: Point3, scalars: Vector3) -> T {
     let mat0 = Matrix4::from_translation(-origin.to_vec());
@@ -448,11 +799,252 @@ where T: Sweep<Matrix4, LineConnector, ExtrudeConnector, Swept> {
     elem.sweep(trsl, LineConnector, ExtrudeConnector { vector })
 }
 
+/// Sweeps a face by a vector same as [`tsweep`], and returns the solid with the roles of its
+/// faces.
+///
+/// The roles are assigned in the order of the faces of the solid:
+/// - the first face is [`SweepRole::Bottom`], the inverse of `face` with the same id,
+/// - the next faces are [`SweepRole::Side`], swept from the edges of the boundaries of `face`
+///   in the order of [`Face::boundary_iters`], which are the edges of the oriented boundaries,
+/// - the last face is [`SweepRole::Top`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let (cube, roles) = builder::tsweep_annotated(&f, Vector3::unit_z());
+///
+/// assert_eq!(roles.face(SweepRole::Bottom), Some(f.id()));
+/// let top = roles.select(&cube, SweepRole::Top).next().unwrap();
+/// assert!(top.vertex_iter().all(|v| v.point().z == 1.0));
+/// // the side face swept from the first edge
+/// let side = roles.select(&cube, SweepRole::Side { source_edge_index: 0 }).next().unwrap();
+/// let edge = &f.boundaries()[0][0];
+/// assert!(side.edge_iter().any(|e| e.id() == edge.id()));
+/// ```
+pub fn tsweep_annotated(
+    face: &Face<Curve, Surface>,
+    vector: Vector3,
+) -> (Solid<Curve, Surface>, SweepRoles) {
+    let solid: Solid<Curve, Surface> = tsweep(face, vector);
+    let sides = roles::lateral_edge_indices(face, &solid).into_iter();
+    let sides = sides.map(|source_edge_index| SweepRole::Side { source_edge_index });
+    let iter = std::iter::once(SweepRole::Bottom).chain(sides).chain([SweepRole::Top]);
+    let roles = SweepRoles::new(&solid, iter);
+    (solid, roles)
+}
+
+/// Sweeps the planar region bounded by the closed wire `profile` by the translation, and
+/// returns the solid.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0)]);
+/// let profile: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+/// let cube: Solid = builder::sweep(&profile, Vector3::unit_z()).unwrap();
+/// assert_eq!(cube.boundaries()[0].len(), 6);
+/// ```
+/// # Failures
+/// If `profile` is not closed, returns `Error::OpenWire` with the largest gap between the
+/// consecutive edges. If `profile` is not on one plane, returns `Error::WireNotInOnePlane`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0)]);
+/// let profile: Wire = (0..3).map(|i| builder::line(&v[i], &v[i + 1])).collect();
+/// let res = builder::sweep::<Curve, Surface>(&profile, Vector3::unit_z());
+/// assert_eq!(res.unwrap_err(), Error::OpenWire { gap: 1.0 });
+/// ```
+pub fn sweep<C, S>(profile: &Wire<C>, vector: Vector3) -> Result<Solid<C, S>>
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: Clone + Invertible,
+    Plane: IncludeCurve<C> + ToSameGeometry<S>,
+    Face<C, S>: Sweep<Matrix4, LineConnector, ExtrudeConnector, Solid<C, S>>, {
+    sweep_with_options(profile, vector, SweepOptions::default())
+}
+
+/// Options of the sweeps producing solids.
+/// cf. [`sweep_with_options`] and [`revolve_with_options`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SweepOptions {
+    /// If `true`, the surfaces of the inverted faces are inverted after sweeping so that their
+    /// normals point outward. cf. [`AlignSurfaceNormals`](../normals/trait.AlignSurfaceNormals.html)
+    pub align_surface_normals: bool,
+}
+
+/// [`sweep`] with `options`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0)]);
+/// let profile: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+/// let options = builder::SweepOptions {
+///     align_surface_normals: true,
+/// };
+/// let cube: Solid = builder::sweep_with_options(&profile, Vector3::unit_z(), options).unwrap();
+/// assert!(cube.face_iter().all(|face| face.orientation()));
+/// ```
+pub fn sweep_with_options<C, S>(
+    profile: &Wire<C>,
+    vector: Vector3,
+    options: SweepOptions,
+) -> Result<Solid<C, S>>
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: Clone + Invertible,
+    Plane: IncludeCurve<C> + ToSameGeometry<S>,
+    Face<C, S>: Sweep<Matrix4, LineConnector, ExtrudeConnector, Solid<C, S>>, {
+    let face = closed_profile(profile)?;
+    Ok(options.apply(tsweep(&face, vector)))
+}
+
+/// The frame moving `profile` along the path in [`pipe_sweep_with_frame`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SweepFrame {
+    /// the rotation-minimizing frame, computed by the double reflection method. The profile
+    /// does not twist around the path even at the inflection points of the path.
+    #[default]
+    RotationMinimizing,
+    /// the Frenet frame, whose normal is the principal normal of the path. The profile is
+    /// turned over at the inflection points of the path, where the principal normal flips.
+    Frenet,
+}
+
+/// Sweeps `profile` along `path` with the rotation-minimizing frame, and returns the shell of
+/// the side faces.
+///
+/// This is the same as `pipe_sweep_with_frame(profile, path, SweepFrame::RotationMinimizing, tol)`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// // a square tube bent along the quarter of the circle of radius 2
+/// let v = builder::vertices([
+///     (1.9, 0.0, -0.1),
+///     (2.1, 0.0, -0.1),
+///     (2.1, 0.0, 0.1),
+///     (1.9, 0.0, 0.1),
+/// ]);
+/// let profile: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+/// let p = builder::vertices([(2.0, 0.0, 0.0), (0.0, 2.0, 0.0)]);
+/// let transit = Point3::new(f64::sqrt(2.0), f64::sqrt(2.0), 0.0);
+/// let path: Edge = builder::circle_arc(&p[0], &p[1], transit);
+///
+/// let tube = builder::pipe_sweep(&profile, &path.curve(), 1.0e-4).unwrap();
+/// assert_eq!(tube.len(), 4);
+/// // the profile is moved to the end of the path
+/// assert!(tube.vertex_iter().all(|v| v.point().x.so_small() || v.point().y.so_small()));
+/// ```
+#[inline(always)]
+pub fn pipe_sweep(profile: &Wire<Curve>, path: &Curve, tol: f64) -> Result<Shell<Curve, Surface>> {
+    pipe_sweep_with_frame(profile, path, SweepFrame::RotationMinimizing, tol)
+}
+
+/// Sweeps `profile` along `path` with `frame`, and returns the shell of the side faces.
+///
+/// The profile is placed as it is at the start of the path, and moved rigidly by the frame
+/// along the path. Each edge of the profile is swept into one face, or two faces if the path is
+/// closed, whose surface is the NURBS surface interpolating the moved curves of the edge at the
+/// stations on the path. The stations are dense enough that the surfaces deviate from the exact
+/// sweep by about `tol`. The paths whose ends meet with the same tangent are closed: the
+/// rotation of the frame around the path is distributed so that the frame returns to the start,
+/// and the profile at the end is the profile itself, so that the shell has no end.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// // a torus swept along the closed path `x^2 + y^2 = 9`
+/// let v = builder::vertex(Point3::new(4.0, 0.0, 0.0));
+/// let center = Point3::new(3.0, 0.0, 0.0);
+/// let profile: Wire = builder::rsweep(&v, center, Vector3::unit_y(), Rad(7.0));
+/// let r = f64::sqrt(0.5);
+/// let control_points = [(1.0, 0.0, 1.0), (r, r, r), (0.0, 1.0, 1.0), (-r, r, r), (-1.0, 0.0, 1.0),
+///     (-r, -r, r), (0.0, -1.0, 1.0), (r, -r, r), (1.0, 0.0, 1.0)];
+/// let control_points = control_points.map(|(x, y, w)| Vector4::new(3.0 * x, 3.0 * y, 0.0, w));
+/// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0, 4.0]);
+/// let circle = NurbsCurve::new(BSplineCurve::new(knot_vec, control_points.to_vec()));
+/// let path = Curve::NurbsCurve(circle);
+///
+/// let frame = builder::SweepFrame::Frenet;
+/// let torus = builder::pipe_sweep_with_frame(&profile, &path, frame, 1.0e-4).unwrap();
+/// assert_eq!(torus.shell_condition(), ShellCondition::Closed);
+/// ```
+/// # Failures
+/// Returns [`Error::DegeneratePath`] if the derivative of `path` vanishes at a station.
+/// # Panics
+/// Panics if an edge of `profile` is an intersection curve, which cannot be lifted to NURBS.
+#[inline(always)]
+pub fn pipe_sweep_with_frame(
+    profile: &Wire<Curve>,
+    path: &Curve,
+    frame: SweepFrame,
+    tol: f64,
+) -> Result<Shell<Curve, Surface>> {
+    pipe::pipe_sweep(profile, path, frame, tol)
+}
+
+/// Extrudes the planar `face` by `vector`, and returns the solid.
+///
+/// The face is the bottom cap and its translation is the top cap. The boundaries of the face are
+/// swept into the side walls, so the holes of the face become the through-holes of the solid.
+/// The orientations of the faces are chosen so that the solid is not inverted whichever side of
+/// the face `vector` points to.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let square = |p: [(f64, f64, f64); 4]| -> Wire {
+///     let v = builder::vertices(p);
+///     (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect()
+/// };
+/// let outer = square([(0.0, 0.0, 0.0), (3.0, 0.0, 0.0), (3.0, 3.0, 0.0), (0.0, 3.0, 0.0)]);
+/// let hole = square([(1.0, 1.0, 0.0), (1.0, 2.0, 0.0), (2.0, 2.0, 0.0), (2.0, 1.0, 0.0)]);
+/// let face: Face = builder::try_attach_plane(vec![outer, hole]).unwrap();
+///
+/// let solid = builder::extrude(&face, Vector3::new(0.0, 0.0, 1.0)).unwrap();
+/// assert_eq!(solid.face_iter().count(), 10);
+/// assert!(solid.contains_point(Point3::new(0.5, 0.5, 0.5)));
+/// assert!(!solid.contains_point(Point3::new(1.5, 1.5, 0.5)));
+/// ```
+/// # Failures
+/// - Returns [`Error::NotPlanarFace`] if the surface of `face` is not a plane.
+/// - Returns [`Error::ExtrusionParallelToFace`] if `vector` is zero or parallel to the plane.
+pub fn extrude(face: &Face<Curve, Surface>, vector: Vector3) -> Result<Solid<Curve, Surface>> {
+    let Surface::Plane(plane) = face.surface() else {
+        return Err(Error::NotPlanarFace);
+    };
+    let normal = match face.orientation() {
+        true => plane.normal(),
+        false => -plane.normal(),
+    };
+    let cos = normal.dot(vector);
+    if vector.so_small() || (cos / vector.magnitude()).so_small() {
+        return Err(Error::ExtrusionParallelToFace);
+    }
+    // the bottom cap is the inverse of the swept face
+    match cos > 0.0 {
+        true => Ok(tsweep(face, vector)),
+        false => Ok(tsweep(&face.inverse(), vector)),
+    }
+}
+
+impl SweepOptions {
+    fn apply<C, S: Clone + Invertible>(self, mut solid: Solid<C, S>) -> Solid<C, S> {
+        if self.align_surface_normals {
+            solid.align_surface_normals_outward();
+        }
+        solid
+    }
+}
+
 /// Sweeps a vertex, an edge, a wire, a face, or a shell by the rotation.
 /// # Details
 /// If the absolute value of `angle` is more than 2π rad, then the result is closed shape.
 /// For example, the result of sweeping a disk is a bent cylinder if `angle` is less than 2π rad
 /// and a solid torus if `angle` is more than 2π rad.
+/// The angles within `TOLERANCE` of 2π rad are regarded as 2π rad, and the sweep is closed.
+///
+/// The arcs of a sweep by at most π rad are divided into two arcs at the midpoint, and the ones
+/// of a longer sweep into three arcs. The angles within `TOLERANCE` of π rad are regarded as
+/// π rad, so the topology of the sweep does not depend on the numerical error of the angle.
 /// # Remarks
 /// `axis` must be normalized. If not, panics occurs in debug mode.
 /// # Examples
@@ -558,20 +1150,167 @@ where
     debug_assert!(axis.magnitude().near(&1.0));
     let angle = angle.into();
     let sign = f64::signum(angle.0);
-    if angle.0.abs() >= 2.0 * PI.0 {
+    if is_whole_turn(angle) {
         whole_rsweep(elem, origin, sign * axis)
+    } else if (angle * sign).0.near(&PI.0) {
+        partial_rsweep(elem, origin, sign * axis, PI)
     } else {
         partial_rsweep(elem, origin, sign * axis, angle * sign)
     }
 }
 
+/// Sweeps a face by the rotation same as [`rsweep`], and returns the solid with the roles of
+/// its faces.
+///
+/// The roles are assigned in the order of the faces of the solid:
+/// - the first face is [`RevolutionRole::Start`], the inverse of `face` with the same id,
+/// - the next faces are [`RevolutionRole::Lateral`]. The sweep is divided into some steps, and
+///   each step has the faces swept from the edges of the boundaries of `face` in the order of
+///   [`Face::boundary_iters`], so each edge makes one lateral face in each step,
+/// - the last face is [`RevolutionRole::End`].
+///
+/// If `angle` is a whole turn, the solid has no start and end faces, and has one shell for each
+/// boundary of `face`, whose faces are the lateral faces step by step.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let axis = Vector3::unit_y();
+///
+/// let (bent, roles) = builder::rsweep_annotated(&f, Point3::origin(), axis, Rad(PI / 2.0));
+/// assert_eq!(roles.face(RevolutionRole::Start), Some(f.id()));
+/// assert_eq!(roles.faces(RevolutionRole::End).count(), 1);
+/// let lateral = RevolutionRole::Lateral { source_edge_index: 1 };
+/// // the outer wall, swept from the edge at `x = 2`
+/// assert!(roles.faces(lateral).count() > 1);
+/// let walls = roles.select(&bent, lateral);
+/// assert!(walls.flat_map(|face| face.vertex_iter()).all(|v| {
+///     let p = v.point();
+///     f64::hypot(p.x, p.z).near(&2.0)
+/// }));
+///
+/// let (ring, roles) = builder::rsweep_annotated(&f, Point3::origin(), axis, Rad(7.0));
+/// assert_eq!(roles.len(), ring.face_iter().count());
+/// assert_eq!(roles.face(RevolutionRole::Start), None);
+/// ```
+pub fn rsweep_annotated<R: Into<Rad<f64>>>(
+    face: &Face<Curve, Surface>,
+    origin: Point3,
+    axis: Vector3,
+    angle: R,
+) -> (Solid<Curve, Surface>, RevolutionRoles) {
+    let angle = angle.into();
+    let solid: Solid<Curve, Surface> = rsweep(face, origin, axis, angle);
+    let laterals = roles::lateral_edge_indices(face, &solid).into_iter();
+    let laterals = laterals.map(|source_edge_index| RevolutionRole::Lateral { source_edge_index });
+    let roles = match is_whole_turn(angle) {
+        true => RevolutionRoles::new(&solid, laterals),
+        false => {
+            let iter = std::iter::once(RevolutionRole::Start).chain(laterals);
+            RevolutionRoles::new(&solid, iter.chain([RevolutionRole::End]))
+        }
+    };
+    (solid, roles)
+}
+
+/// Sweeps the planar region bounded by the closed wire `profile` by the rotation, and returns
+/// the solid. The details of the rotation are the same as [`rsweep`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertices([(1.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 1.0, 0.0), (1.0, 1.0, 0.0)]);
+/// let profile: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+/// let ring: Solid = builder::revolve(&profile, Point3::origin(), Vector3::unit_y(), Rad(7.0))
+///     .unwrap();
+/// assert_eq!(ring.boundaries().len(), 1);
+/// ```
+/// # Failures
+/// If `profile` is not closed, returns `Error::OpenWire` with the largest gap between the
+/// consecutive edges. If `profile` is not on one plane, returns `Error::WireNotInOnePlane`.
+///
+/// # Remarks
+/// `axis` must be normalized. If not, panics occurs in debug mode.
+pub fn revolve<C, S, R>(
+    profile: &Wire<C>,
+    origin: Point3,
+    axis: Vector3,
+    angle: R,
+) -> Result<Solid<C, S>>
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: Clone + Invertible,
+    Plane: IncludeCurve<C> + ToSameGeometry<S>,
+    Face<C, S>: ClosedSweep<Matrix4, ArcConnector, RevoluteConnector, Solid<C, S>>,
+    R: Into<Rad<f64>>, {
+    revolve_with_options(profile, origin, axis, angle, SweepOptions::default())
+}
+
+/// [`revolve`] with `options`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertices([(1.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 1.0, 0.0), (1.0, 1.0, 0.0)]);
+/// let profile: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+/// let options = builder::SweepOptions {
+///     align_surface_normals: true,
+/// };
+/// let (origin, axis) = (Point3::origin(), Vector3::unit_y());
+/// let ring: Solid = builder::revolve_with_options(&profile, origin, axis, Rad(7.0), options)
+///     .unwrap();
+/// assert!(ring.face_iter().all(|face| face.orientation()));
+/// ```
+pub fn revolve_with_options<C, S, R>(
+    profile: &Wire<C>,
+    origin: Point3,
+    axis: Vector3,
+    angle: R,
+    options: SweepOptions,
+) -> Result<Solid<C, S>>
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: Clone + Invertible,
+    Plane: IncludeCurve<C> + ToSameGeometry<S>,
+    Face<C, S>: ClosedSweep<Matrix4, ArcConnector, RevoluteConnector, Solid<C, S>>,
+    R: Into<Rad<f64>>, {
+    let face = closed_profile(profile)?;
+    Ok(options.apply(rsweep(&face, origin, axis, angle)))
+}
+
+/// Attaches a plane to `profile` after checking that `profile` is closed.
+fn closed_profile<C, S>(profile: &Wire<C>) -> Result<Face<C, S>>
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    Plane: IncludeCurve<C> + ToSameGeometry<S>, {
+    if profile.is_empty() {
+        return Err(errors::Error::EmptyWire.into());
+    }
+    let edges = profile.edge_iter();
+    let gap = edges
+        .clone()
+        .zip(edges.cycle().skip(1))
+        .filter(|(edge0, edge1)| edge0.back() != edge1.front())
+        .map(|(edge0, edge1)| edge0.back().point().distance(edge1.front().point()))
+        .reduce(f64::max);
+    match gap {
+        Some(gap) => Err(Error::OpenWire { gap }),
+        None => try_attach_plane(vec![profile.clone()]),
+    }
+}
+
+/// Whether the sweep by `angle` goes around the axis.
+#[inline(always)]
+fn is_whole_turn(angle: Rad<f64>) -> bool { angle.0.abs() >= 2.0 * PI.0 - TOLERANCE }
+
 fn partial_rsweep<T: MultiSweep<Matrix4, ArcConnector, RevoluteConnector, Swept>, Swept>(
     elem: &T,
     origin: Point3,
     axis: Vector3,
     angle: Rad<f64>,
 ) -> Swept {
-    let division = if angle.0.abs() < PI.0 { 2 } else { 3 };
+    let division = if angle.0.abs() <= PI.0 { 2 } else { 3 };
     let mat0 = Matrix4::from_translation(-origin.to_vec());
     let mat1 = Matrix4::from_axis_angle(axis, angle / division as f64);
     let mat2 = Matrix4::from_translation(origin.to_vec());
@@ -646,7 +1385,7 @@ where
     Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>: ToSameGeometry<C>,
     RevolutedCurve<C>: ToSameGeometry<S>, {
     let angle = angle.into();
-    let closed = angle.0.abs() >= 2.0 * PI.0;
+    let closed = is_whole_turn(angle);
     let mut wire = wire.clone();
     if wire.is_empty() {
         return Shell::new();
@@ -710,6 +1449,153 @@ where
     shell
 }
 
+/// Fillets a convex edge between planar faces by the rolling ball of `radius`.
+///
+/// The edge must be a line segment and each of its ends must be shared by exactly three planar
+/// faces. The adjacent faces are trimmed, and a cylindrical face is inserted.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let edge = cube.edge_iter().next().unwrap();
+/// let filleted = builder::fillet_edge(&cube, edge.id(), 0.2, TOLERANCE).unwrap();
+/// assert_eq!(filleted.face_iter().count(), 7);
+/// assert!(filleted.is_geometric_consistent());
+/// ```
+pub fn fillet_edge(
+    solid: &Solid<Curve, Surface>,
+    edge: EdgeID<Curve>,
+    radius: f64,
+    tol: f64,
+) -> Result<Solid<Curve, Surface>> {
+    fillet::fillet_edges(solid, &[edge], radius, tol)
+}
+
+/// Fillets the edges of `solid` by the rolling ball of `radius` at once, cf. [`fillet_edge`].
+///
+/// Two fillets meeting at a vertex are cut by the plane bisecting them, which must contain the
+/// third edge at the vertex. The corner where three fillets meet is blended by the sphere
+/// touching the three faces. If three or more filleted edges meet at a vertex of more than
+/// three edges, returns [`Error::FilletCorners`] listing such vertices. If the ball does not fit
+/// between the faces adjacent to an edge, or the trimmed adjacent edges vanish, returns
+/// [`Error::FilletRadiusTooLarge`].
+/// # Examples
+/// ```
+/// use truck_modeling::{errors::Error, *};
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // the vertical edges of the face `y = 0`
+/// let edges: Vec<EdgeID> = cube
+///     .edge_iter()
+///     .filter(|edge| {
+///         let (p, q) = (edge.front().point(), edge.back().point());
+///         p.y.so_small() && q.y.so_small() && p.x.near(&q.x)
+///     })
+///     .map(|edge| edge.id())
+///     .collect();
+/// let filleted = builder::fillet_edges(&cube, &edges, 0.2, TOLERANCE).unwrap();
+/// assert_eq!(filleted.face_iter().count(), 8);
+/// assert!(filleted.is_geometric_consistent());
+///
+/// // the bottom and top edges of the face are trimmed from both ends
+/// let res = builder::fillet_edges(&cube, &edges, 0.6, TOLERANCE);
+/// assert_eq!(res.unwrap_err(), Error::FilletRadiusTooLarge);
+/// ```
+pub fn fillet_edges(
+    solid: &Solid<Curve, Surface>,
+    edges: &[EdgeID<Curve>],
+    radius: f64,
+    tol: f64,
+) -> Result<Solid<Curve, Surface>> {
+    fillet::fillet_edges(solid, edges, radius, tol)
+}
+
+/// Fillets all convex edges of `solid` by [`fillet_edge`].
+///
+/// The edges are convex if the dihedral angles at their middle points are convex by more than
+/// `tol` in sine. The edges are filleted at once by [`fillet_edges`], so the corners of three
+/// fillets are blended by spherical patches. If the middle point of an edge cannot be projected
+/// to the surface of an adjacent face, returns [`Error::ParameterSearchFailed`] with the face.
+/// # Examples
+/// ```
+/// use truck_modeling::{errors::Error, *};
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // the 6 trimmed faces, the 12 fillets and the 8 corner patches
+/// let rounded = builder::round_edges(&cube, 0.2, TOLERANCE).unwrap();
+/// assert_eq!(rounded.face_iter().count(), 26);
+/// assert!(rounded.is_geometric_consistent());
+///
+/// // the fillets of the opposite edges overlap
+/// let res = builder::round_edges(&cube, 0.6, TOLERANCE);
+/// assert_eq!(res.unwrap_err(), Error::FilletRadiusTooLarge);
+/// ```
+pub fn round_edges(
+    solid: &Solid<Curve, Surface>,
+    radius: f64,
+    tol: f64,
+) -> Result<Solid<Curve, Surface>> {
+    let edges = fillet::convex_edges(solid, tol)?;
+    fillet::fillet_edges(solid, &edges, radius, tol)
+}
+
+/// Assembles the faces into a solid, which should be closed within `options.tolerance`.
+///
+/// The faces need not share the topology, nor be ordered or oriented. The following steps are
+/// performed in order:
+/// 1. merging the vertices within the tolerance, found by a spatial hash,
+/// 2. welding the edges with the same ends whose curves are within the tolerance,
+/// 3. splitting the faces into the connected components,
+/// 4. orienting the faces of each component consistently and outward, and removing the
+///    slivers, as [`SolidHealing`](../healing/trait.SolidHealing.html),
+/// 5. inverting the components inside the others, which bound cavities,
+/// 6. merging the adjacent faces on the same surface, if [`AssembleOptions::merge_faces`], and
+/// 7. validating the components by `Solid::try_new`.
+///
+/// The faces are never mutated: the vertices, the edges, and the faces of the solid are new
+/// ones if they are repaired, so the clones of the faces are intact even on failure.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // the faces with no shared topology, the first of which is inverted
+/// let mut faces: Vec<Face> = cube.face_iter().map(builder::clone).collect();
+/// faces[0].invert();
+/// let solid = builder::solid_from_faces(faces.clone(), AssembleOptions::default()).unwrap();
+/// assert_eq!(solid.face_iter().count(), 6);
+/// assert!(solid.contains_point(Point3::new(0.5, 0.5, 0.5)));
+/// assert!(!solid.contains_point(Point3::new(1.5, 0.5, 0.5)));
+///
+/// // the error reports the loop of the missing face
+/// faces.pop();
+/// let error = builder::solid_from_faces(faces, AssembleOptions::default()).unwrap_err();
+/// assert_eq!(error.open_boundaries.len(), 1);
+/// assert_eq!(error.open_boundaries[0].len(), 4);
+/// ```
+/// # Failures
+/// Returns the boxed [`AssembleError`] with the assembled shells, their defects and open boundaries,
+/// the log of the repairs, and the gaps between the adjacent faces.
+pub fn solid_from_faces(
+    faces: Vec<Face<Curve, Surface>>,
+    options: AssembleOptions,
+) -> std::result::Result<Solid<Curve, Surface>, Box<AssembleError>> {
+    assemble::solid_from_faces(faces, options)
+}
+
 #[cfg(test)]
 mod partial_torus {
     use crate::*;
@@ -0,0 +1,94 @@
+use crate::*;
+
+/// The bounding volume hierarchy of boxes, e.g. the boxes of the faces of a shell.
+#[derive(Clone, Debug)]
+pub(crate) struct BoxTree {
+    bdb: BoundingBox<Point3>,
+    node: Node,
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    /// the index of the box
+    Leaf(usize),
+    Branch(Box<BoxTree>, Box<BoxTree>),
+}
+
+impl BoxTree {
+    /// Builds the hierarchy whose leaves are the indices of `boxes`, by dividing the boxes at the
+    /// median along the longest axis. Returns `None` if `boxes` is empty.
+    pub(crate) fn new(boxes: &[BoundingBox<Point3>]) -> Option<Self> {
+        let mut items: Vec<(usize, BoundingBox<Point3>)> =
+            boxes.iter().copied().enumerate().collect();
+        (!items.is_empty()).then(|| Self::build(&mut items))
+    }
+
+    fn build(items: &mut [(usize, BoundingBox<Point3>)]) -> Self {
+        let bdb = items.iter().fold(BoundingBox::new(), |mut bdb, (_, item)| {
+            bdb += *item;
+            bdb
+        });
+        if let [(index, _)] = items {
+            let node = Node::Leaf(*index);
+            return Self { bdb, node };
+        }
+        let diagonal = bdb.diagonal();
+        let axis = (0..3)
+            .max_by(|i, j| diagonal[*i].total_cmp(&diagonal[*j]))
+            .unwrap_or_default();
+        items.sort_by(|(_, bdb0), (_, bdb1)| bdb0.center()[axis].total_cmp(&bdb1.center()[axis]));
+        let (left, right) = items.split_at_mut(items.len() / 2);
+        let node = Node::Branch(Box::new(Self::build(left)), Box::new(Self::build(right)));
+        Self { bdb, node }
+    }
+
+    /// Visits the pairs of the leaves of `self` and `other` whose boxes are within `distance`,
+    /// until `f` returns `Some`. The larger box is divided first.
+    pub(crate) fn find_pair<T>(
+        &self,
+        other: &Self,
+        distance: f64,
+        mut f: impl FnMut(usize, usize) -> Option<T>,
+    ) -> Option<T> {
+        let mut stack = vec![(self, other)];
+        while let Some((tree0, tree1)) = stack.pop() {
+            if boxes_distance(tree0.bdb, tree1.bdb) > distance {
+                continue;
+            }
+            match (&tree0.node, &tree1.node) {
+                (Node::Leaf(i), Node::Leaf(j)) => {
+                    if let Some(res) = f(*i, *j) {
+                        return Some(res);
+                    }
+                }
+                (Node::Branch(left, right), Node::Leaf(_)) => {
+                    stack.push((left.as_ref(), tree1));
+                    stack.push((right.as_ref(), tree1));
+                }
+                (Node::Leaf(_), Node::Branch(left, right)) => {
+                    stack.push((tree0, left.as_ref()));
+                    stack.push((tree0, right.as_ref()));
+                }
+                (Node::Branch(left0, right0), Node::Branch(left1, right1)) => {
+                    if tree0.bdb.diameter() >= tree1.bdb.diameter() {
+                        stack.push((left0.as_ref(), tree1));
+                        stack.push((right0.as_ref(), tree1));
+                    } else {
+                        stack.push((tree0, left1.as_ref()));
+                        stack.push((tree0, right1.as_ref()));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Returns the distance between the boxes, zero if they overlap.
+pub(crate) fn boxes_distance(bdb0: BoundingBox<Point3>, bdb1: BoundingBox<Point3>) -> f64 {
+    let gap = |i: usize| {
+        let gap = f64::max(bdb0.min()[i] - bdb1.max()[i], bdb1.min()[i] - bdb0.max()[i]);
+        f64::max(gap, 0.0)
+    };
+    Vector3::new(gap(0), gap(1), gap(2)).magnitude()
+}
@@ -0,0 +1,152 @@
+use crate::base::*;
+use std::collections::HashMap;
+use truck_topology::{Edge, EdgeID, Face, Shell, Solid, Wire};
+
+/// Normalizes the representation of topological shapes, without changing the shapes.
+pub trait Canonicalize {
+    /// Normalizes the representational freedom of `self` deterministically.
+    ///
+    /// - Each edge is directed from the vertex with the smaller quantized point,
+    ///   with the curve inverted if necessary.
+    /// - Each closed wire is rotated to start at the vertex with the lexicographically smallest
+    ///   quantized point.
+    /// - The inner boundaries of each face are sorted by the minimum corners of their
+    ///   bounding boxes. The first boundary is kept as the first.
+    /// - The surface of each inverted face is inverted by [`Invertible`], and the orientation
+    ///   flag of the face is cleared.
+    /// - The faces of each shell are sorted by the quantized points of their boundaries.
+    ///
+    /// The points are quantized by `TOLERANCE`. The vertices are preserved, but the faces and
+    /// the redirected edges are newly created, so their ids change.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let mut cube = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// cube.canonicalize();
+    /// assert!(cube.face_iter().all(Face::orientation));
+    /// let wire = &cube.boundaries()[0][0].boundaries()[0];
+    /// assert_eq!(wire.front_vertex().unwrap().point(), Point3::origin());
+    /// ```
+    fn canonicalize(&mut self);
+}
+
+type EdgeMap<C> = HashMap<EdgeID<C>, Edge<Point3, C>>;
+type Key = [i64; 3];
+
+impl<C, S> Canonicalize for Solid<Point3, C, S>
+where
+    C: Invertible + Clone,
+    S: Invertible + Clone,
+{
+    fn canonicalize(&mut self) {
+        let mut edge_map = EdgeMap::new();
+        let shells = self
+            .boundaries()
+            .iter()
+            .map(|shell| canonical_shell(shell, &mut edge_map))
+            .collect();
+        *self = Solid::debug_new(shells);
+    }
+}
+
+impl<C, S> Canonicalize for Shell<Point3, C, S>
+where
+    C: Invertible + Clone,
+    S: Invertible + Clone,
+{
+    fn canonicalize(&mut self) { *self = canonical_shell(self, &mut EdgeMap::new()); }
+}
+
+fn quantize(point: Point3) -> Key {
+    [point.x, point.y, point.z].map(|x| f64::round(x / TOLERANCE) as i64)
+}
+
+fn edge_keys<C>(edge: &Edge<Point3, C>) -> (Key, Key) {
+    (
+        quantize(edge.absolute_front().point()),
+        quantize(edge.absolute_back().point()),
+    )
+}
+
+fn canonical_edge<C>(edge: &Edge<Point3, C>, edge_map: &mut EdgeMap<C>) -> Edge<Point3, C>
+where C: Invertible + Clone {
+    let (front, back) = edge_keys(edge);
+    if front <= back {
+        return edge.clone();
+    }
+    let new_edge = edge_map.entry(edge.id()).or_insert_with(|| {
+        let (front, back) = (edge.absolute_front(), edge.absolute_back());
+        Edge::new(back, front, edge.curve().inverse())
+    });
+    match edge.orientation() {
+        true => new_edge.inverse(),
+        false => new_edge.clone(),
+    }
+}
+
+fn vertex_keys<C>(wire: &Wire<Point3, C>) -> Vec<Key> {
+    wire.vertex_iter().map(|v| quantize(v.point())).collect()
+}
+
+fn canonical_wire<C>(wire: &Wire<Point3, C>, edge_map: &mut EdgeMap<C>) -> Wire<Point3, C>
+where C: Invertible + Clone {
+    let mut wire: Wire<Point3, C> = wire.iter().map(|e| canonical_edge(e, edge_map)).collect();
+    if wire.is_closed() && !wire.is_empty() {
+        let keys: Vec<Key> = wire.iter().map(|e| quantize(e.front().point())).collect();
+        let rotated = |r: usize| keys[r..].iter().chain(&keys[..r]);
+        let start = (0..keys.len())
+            .min_by(|r, s| rotated(*r).cmp(rotated(*s)))
+            .unwrap_or(0);
+        wire.rotate_left(start);
+    }
+    wire
+}
+
+fn min_corner<C>(wire: &Wire<Point3, C>) -> Key {
+    let bdb: BoundingBox<Point3> = wire.vertex_iter().map(|v| v.point()).collect();
+    quantize(bdb.min())
+}
+
+fn canonical_face<C, S>(
+    face: &Face<Point3, C, S>,
+    edge_map: &mut EdgeMap<C>,
+) -> Face<Point3, C, S>
+where
+    C: Invertible + Clone,
+    S: Invertible + Clone,
+{
+    let mut wires: Vec<_> = face
+        .boundaries()
+        .iter()
+        .map(|wire| canonical_wire(wire, edge_map))
+        .collect();
+    if wires.len() > 2 {
+        wires[1..].sort_by_cached_key(|wire| (min_corner(wire), vertex_keys(wire)));
+    }
+    Face::debug_new(wires, face.oriented_surface())
+}
+
+fn face_key<C, S>(face: &Face<Point3, C, S>) -> Vec<Vec<Key>> {
+    face.absolute_boundaries().iter().map(vertex_keys).collect()
+}
+
+fn canonical_shell<C, S>(
+    shell: &Shell<Point3, C, S>,
+    edge_map: &mut EdgeMap<C>,
+) -> Shell<Point3, C, S>
+where
+    C: Invertible + Clone,
+    S: Invertible + Clone,
+{
+    let mut faces: Vec<_> = shell
+        .face_iter()
+        .map(|face| canonical_face(face, edge_map))
+        .collect();
+    faces.sort_by_cached_key(face_key);
+    faces.into()
+}
@@ -0,0 +1,148 @@
+use crate::errors::Error;
+use crate::ray_casting::face_domain;
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+use truck_base::convex_hull::convex_hull_3d;
+
+/// Convex hulls of solids, e.g. for the collision proxies.
+pub trait ConvexHull {
+    /// Returns the convex hull of the points sampled on the boundaries of `self`.
+    ///
+    /// The curves and the surfaces are divided with the tolerance `tol`, so the hull of a curved
+    /// solid is a polyhedron inscribed in the exact hull. The faces of the hull are planar
+    /// polygons: the coplanar triangles of the hull are merged into one face. The hull is a
+    /// closed solid whose faces are oriented outward.
+    ///
+    /// # Failure
+    /// - [`Error::DegenerateConvexHull`] if all points are coplanar.
+    /// - [`Error::ParameterSearchFailed`] if the boundaries of a face cannot be projected to the
+    ///   surface.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// // L-shaped prism
+    /// let points = [
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 1.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(1.0, 2.0, 0.0),
+    ///     Point3::new(0.0, 2.0, 0.0),
+    /// ];
+    /// let v = builder::vertices(points);
+    /// let wire: Wire = (0..6).map(|i| builder::line(&v[i], &v[(i + 1) % 6])).collect();
+    /// let face: Face = builder::try_attach_plane(vec![wire]).unwrap();
+    /// let solid: Solid = builder::tsweep(&face, Vector3::unit_z());
+    ///
+    /// let hull = solid.convex_hull(0.01).unwrap();
+    /// let shell = &hull.boundaries()[0];
+    /// // the pentagonal prism
+    /// assert_eq!(shell.len(), 7);
+    /// let pentagons = shell.face_iter().filter(|face| face.boundaries()[0].len() == 5);
+    /// assert_eq!(pentagons.count(), 2);
+    /// assert!(hull.contains_point(Point3::new(1.4, 1.4, 0.5)));
+    /// assert!(!solid.contains_point(Point3::new(1.4, 1.4, 0.5)));
+    /// ```
+    fn convex_hull(&self, tol: f64) -> Result<Solid>;
+}
+
+impl ConvexHull for Solid {
+    fn convex_hull(&self, tol: f64) -> Result<Solid> {
+        let points: Vec<Point3> = self
+            .face_iter()
+            .map(|face| face_points(face, tol))
+            .collect::<Result<Vec<_>>>()?
+            .concat();
+        let triangles = convex_hull_3d(&points);
+        if triangles.is_empty() {
+            return Err(Error::DegenerateConvexHull);
+        }
+
+        let mut vertices = HashMap::<usize, Vertex>::default();
+        let mut vertex = |i: usize| {
+            let vertex = vertices.entry(i).or_insert_with(|| builder::vertex(points[i]));
+            vertex.clone()
+        };
+        let mut edges = HashMap::<(usize, usize), Edge>::default();
+        let mut edge = |a: usize, b: usize| {
+            let (v0, v1) = (a.min(b), a.max(b));
+            let edge = edges
+                .entry((v0, v1))
+                .or_insert_with(|| builder::line(&vertex(v0), &vertex(v1)));
+            match a < b {
+                true => edge.clone(),
+                false => edge.inverse(),
+            }
+        };
+        let shell: Shell = coplanar_polygons(&points, &triangles)
+            .into_iter()
+            .map(|polygon| {
+                let closed = polygon.iter().zip(polygon.iter().cycle().skip(1));
+                let wire: Wire = closed.map(|(a, b)| edge(*a, *b)).collect();
+                builder::try_attach_plane(vec![wire])
+            })
+            .collect::<Result<_>>()?;
+        Ok(Solid::try_new(vec![shell])?)
+    }
+}
+
+/// Returns the points on the boundaries and on the interior of the face.
+fn face_points(face: &Face, tol: f64) -> Result<Vec<Point3>> {
+    let domain = face_domain(face, tol)?;
+    let surface = face.surface();
+    let (min, max) = (domain.uv_range.min(), domain.uv_range.max());
+    let (us, vs) = surface.parameter_division(((min.x, max.x), (min.y, max.y)), tol);
+    let mut points: Vec<Point3> = us
+        .iter()
+        .flat_map(|u| vs.iter().map(move |v| Point2::new(*u, *v)))
+        .map(|uv| (uv, surface.subs(uv.x, uv.y)))
+        .filter(|(uv, point)| domain.includes(*uv, *point, tol))
+        .map(|(_, point)| point)
+        .collect();
+    points.extend(domain.points.into_iter().flatten());
+    Ok(points)
+}
+
+/// Merges the coplanar adjacent triangles into convex polygons, counterclockwise from outside.
+fn coplanar_polygons(points: &[Point3], triangles: &[[usize; 3]]) -> Vec<Vec<usize>> {
+    let normal = |[a, b, c]: [usize; 3]| {
+        let (a, b, c) = (points[a], points[b], points[c]);
+        (b - a).cross(c - a).normalize()
+    };
+    let mut groups: Vec<(Vector3, Vec<[usize; 3]>)> = Vec::new();
+    for triangle in triangles {
+        let n = normal(*triangle);
+        // the coplanar faces of a convex hull have the same outward normals
+        match groups.iter_mut().find(|(normal, _)| normal.near(&n)) {
+            Some((_, group)) => group.push(*triangle),
+            None => groups.push((n, vec![*triangle])),
+        }
+    }
+    groups
+        .into_iter()
+        .flat_map(|(_, group)| {
+            let edges: Vec<(usize, usize)> = group
+                .iter()
+                .flat_map(|[a, b, c]| [(*a, *b), (*b, *c), (*c, *a)])
+                .collect();
+            let next: HashMap<usize, usize> = edges
+                .iter()
+                .filter(|(a, b)| !edges.contains(&(*b, *a)))
+                .copied()
+                .collect();
+            let start = *next.keys().min().unwrap();
+            let mut polygon = vec![start];
+            let mut current = next[&start];
+            while current != start && polygon.len() < next.len() {
+                polygon.push(current);
+                current = next[&current];
+            }
+            // the triangles are kept if the boundary is not one loop
+            match current == start && polygon.len() == next.len() {
+                true => vec![polygon],
+                false => group.into_iter().map(Vec::from).collect(),
+            }
+        })
+        .collect()
+}
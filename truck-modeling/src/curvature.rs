@@ -0,0 +1,340 @@
+use crate::base::*;
+use truck_topology::{Edge, Wire};
+
+/// A sample of the curvature of a curve.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CurvatureSample {
+    /// the index of the edge in the wire. Always `0` for the profile of an edge.
+    pub edge_index: usize,
+    /// the parameter of the oriented curve of the edge
+    pub parameter: f64,
+    /// the arc length from the start point of the edge or the wire
+    pub arc_length: f64,
+    /// the curvature. For planar curves, the curvature is signed with respect to
+    /// [`CurvatureProfile::reference_normal`].
+    pub curvature: f64,
+    /// the position of the curve
+    pub position: Point3,
+    /// the unit vector from the position to the center of curvature.
+    /// The zero vector if the curvature vanishes.
+    pub comb: Vector3,
+}
+
+/// A point on the curve specified by the edge index, the parameter and the arc length.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CurvePoint {
+    /// the index of the edge in the wire. Always `0` for the profile of an edge.
+    pub edge_index: usize,
+    /// the parameter of the oriented curve of the edge
+    pub parameter: f64,
+    /// the arc length from the start point of the edge or the wire
+    pub arc_length: f64,
+}
+
+/// The curvature distribution along an edge or a wire.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurvatureProfile {
+    /// samples placed at even intervals of the arc length in each edge
+    pub samples: Vec<CurvatureSample>,
+    /// the normal of the plane including the curve, `None` if the curve is not planar
+    pub reference_normal: Option<Vector3>,
+    /// Inflection points refined by bisection.
+    /// - For planar curves, the points where the signed curvature changes its sign.
+    /// - For non-planar curves, the points where the derivative of the curvature changes its sign
+    ///   and the curvature vanishes.
+    pub inflections: Vec<CurvePoint>,
+    /// the maximum absolute value of the curvature over samples
+    pub max_curvature: f64,
+    /// the sample point attaining [`CurvatureProfile::max_curvature`]
+    pub max_curvature_at: CurvePoint,
+    /// whether the absolute value of the curvature is monotone in each span between inflections.
+    /// The length is `inflections.len() + 1`.
+    pub monotone_spans: Vec<bool>,
+    /// the indices of the first samples of the edges
+    pub edge_boundaries: Vec<usize>,
+}
+
+/// Analyzes the curvature of edges and wires.
+pub trait CurvatureAnalysis {
+    /// Returns the curvature profile with `samples` samples for each edge.
+    /// For wires, `samples` is distributed among edges in proportion to their arc length.
+    ///
+    /// # Panics
+    /// Panics if `samples < 2` or `self` has no edges.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    ///
+    /// // a cubic S-curve with an inflection at `t = 0.5`
+    /// let v = builder::vertices([(0.0, 0.0, 0.0), (3.0, 0.0, 0.0)]);
+    /// let inter_points = vec![Point3::new(1.0, 1.0, 0.0), Point3::new(2.0, -1.0, 0.0)];
+    /// let edge: Edge = builder::bezier(&v[0], &v[1], inter_points);
+    ///
+    /// let profile = edge.curvature_profile(64);
+    /// assert_eq!(profile.samples.len(), 64);
+    /// assert_eq!(profile.inflections.len(), 1);
+    /// assert!(f64::abs(profile.inflections[0].parameter - 0.5) < 1.0e-6);
+    /// assert_eq!(profile.monotone_spans.len(), 2);
+    /// ```
+    fn curvature_profile(&self, samples: usize) -> CurvatureProfile;
+}
+
+impl<C> CurvatureAnalysis for Edge<Point3, C>
+where C: ParametricCurve3D + BoundedCurve + Invertible + Clone
+{
+    fn curvature_profile(&self, samples: usize) -> CurvatureProfile {
+        let curves = [self.oriented_curve()];
+        let lengths = [arc_length(&curves[0])];
+        profile(&curves, &lengths, &[samples])
+    }
+}
+
+impl<C> CurvatureAnalysis for Wire<Point3, C>
+where C: ParametricCurve3D + BoundedCurve + Invertible + Clone
+{
+    fn curvature_profile(&self, samples: usize) -> CurvatureProfile {
+        let curves: Vec<C> = self.edge_iter().map(Edge::oriented_curve).collect();
+        let lengths: Vec<f64> = curves.iter().map(arc_length).collect();
+        let total = lengths.iter().sum::<f64>();
+        let counts: Vec<usize> = lengths
+            .iter()
+            .map(|len| usize::max(2, f64::round(samples as f64 * len / total) as usize))
+            .collect();
+        profile(&curves, &lengths, &counts)
+    }
+}
+
+const ARC_LENGTH_DIVISION: usize = 256;
+const BISECTION_TRIALS: usize = 64;
+
+/// Returns the cumulative arc lengths on the uniform division of the parameter range.
+fn arc_length_table<C: ParametricCurve3D + BoundedCurve>(curve: &C) -> Vec<(f64, f64)> {
+    let (t0, t1) = curve.range_tuple();
+    let division: Vec<f64> = (0..=ARC_LENGTH_DIVISION)
+        .map(|i| t0 + (t1 - t0) * i as f64 / ARC_LENGTH_DIVISION as f64)
+        .collect();
+    algo::curve::arc_length_table(curve, &division, TOLERANCE)
+}
+
+fn arc_length<C: ParametricCurve3D + BoundedCurve>(curve: &C) -> f64 {
+    arc_length_table(curve).last().unwrap().1
+}
+
+/// Returns `c' × c''` and the curvature.
+fn curvature_vector<C: ParametricCurve3D>(curve: &C, t: f64) -> (Vector3, f64) {
+    let (der, der2) = (curve.der(t), curve.der2(t));
+    let cross = der.cross(der2);
+    let speed = der.magnitude();
+    match speed.so_small() {
+        true => (Vector3::zero(), 0.0),
+        false => (cross, cross.magnitude() / (speed * speed * speed)),
+    }
+}
+
+fn signed_curvature<C: ParametricCurve3D>(curve: &C, t: f64, normal: Vector3) -> f64 {
+    let (cross, curvature) = curvature_vector(curve, t);
+    match cross.dot(normal) < 0.0 {
+        true => -curvature,
+        false => curvature,
+    }
+}
+
+/// Returns the normal of the plane including all curves, if exists.
+fn reference_normal<C: ParametricCurve3D + BoundedCurve>(curves: &[C]) -> Option<Vector3> {
+    let points: Vec<Point3> = curves
+        .iter()
+        .flat_map(|curve| {
+            let (t0, t1) = curve.range_tuple();
+            (0..=16).map(move |i| curve.subs(t0 + (t1 - t0) * i as f64 / 16.0))
+        })
+        .collect();
+    let origin = points[0];
+    let (normal, _) = points.iter().fold((None, None), |(normal, first), p| {
+        let vec: Vector3 = p - origin;
+        match (normal, first) {
+            (Some(normal), _) => (Some(normal), first),
+            (None, None) if !vec.so_small() => (None, Some(vec)),
+            (None, Some(first)) => {
+                let cross = Vector3::cross(first, vec);
+                match cross.so_small() {
+                    true => (None, Some(first)),
+                    false => (Some(cross.normalize()), Some(first)),
+                }
+            }
+            _ => (None, None),
+        }
+    });
+    let normal = normal?;
+    let planar = points.iter().all(|p| (p - origin).dot(normal).so_small());
+    let oriented = curves.iter().find_map(|curve| {
+        let (t0, t1) = curve.range_tuple();
+        (0..=16).find_map(|i| {
+            let (cross, _) = curvature_vector(curve, t0 + (t1 - t0) * i as f64 / 16.0);
+            match cross.dot(normal) {
+                x if x.so_small() => None,
+                x if x < 0.0 => Some(-normal),
+                _ => Some(normal),
+            }
+        })
+    });
+    match planar {
+        true => Some(oriented.unwrap_or(normal)),
+        false => None,
+    }
+}
+
+/// Finds `t` in `(t0, t1)` such that `f(t) == 0` by bisection,
+/// where `f(t0)` and `f(t1)` has different signs.
+fn bisection(f: impl Fn(f64) -> f64, (mut t0, mut t1): (f64, f64)) -> f64 {
+    let positive = f(t0) > 0.0;
+    for _ in 0..BISECTION_TRIALS {
+        let t = (t0 + t1) / 2.0;
+        match (f(t) > 0.0) == positive {
+            true => t0 = t,
+            false => t1 = t,
+        }
+    }
+    (t0 + t1) / 2.0
+}
+
+fn profile<C>(curves: &[C], lengths: &[f64], counts: &[usize]) -> CurvatureProfile
+where C: ParametricCurve3D + BoundedCurve {
+    assert!(!curves.is_empty(), "no edges");
+    assert!(counts.iter().all(|n| *n >= 2), "samples must be at least 2");
+    let reference_normal = reference_normal(curves);
+    let curvature = |curve: &C, t: f64| match reference_normal {
+        Some(normal) => signed_curvature(curve, t, normal),
+        None => curvature_vector(curve, t).1,
+    };
+
+    let mut samples = Vec::new();
+    let mut edge_boundaries = Vec::new();
+    let mut inflections = Vec::new();
+    let mut offset = 0.0;
+    curves.iter().enumerate().for_each(|(edge_index, curve)| {
+        let table = arc_length_table(curve);
+        let len = lengths[edge_index];
+        let n = counts[edge_index];
+        edge_boundaries.push(samples.len());
+        let start = samples.len();
+        samples.extend((0..n).map(|i| {
+            let arc_length = len * i as f64 / (n - 1) as f64;
+            let parameter =
+                algo::curve::parameter_by_arc_length(curve, &table, arc_length, TOLERANCE);
+            let (cross, _) = curvature_vector(curve, parameter);
+            let comb = match cross.so_small() {
+                true => Vector3::zero(),
+                false => cross.cross(curve.der(parameter)).normalize(),
+            };
+            CurvatureSample {
+                edge_index,
+                parameter,
+                arc_length: offset + arc_length,
+                curvature: curvature(curve, parameter),
+                position: curve.subs(parameter),
+                comb,
+            }
+        }));
+        let arc_length_at = |t: f64| {
+            let idx = table.partition_point(|(s, _)| *s < t).clamp(1, table.len() - 1);
+            let ((t0, l0), (t1, l1)) = (table[idx - 1], table[idx]);
+            offset + l0 + (l1 - l0) * (t - t0) / (t1 - t0)
+        };
+        // Samples with exactly vanishing curvature are skipped
+        // so that inflections on samples are not missed.
+        let edge_samples: Vec<_> = match reference_normal {
+            Some(_) => samples[start..]
+                .iter()
+                .filter(|s| s.curvature != 0.0)
+                .collect(),
+            None => samples[start..].iter().collect(),
+        };
+        edge_samples.windows(2).for_each(|w| {
+            let range = (w[0].parameter, w[1].parameter);
+            let parameter = match reference_normal {
+                Some(_) => {
+                    if w[0].curvature * w[1].curvature > 0.0 {
+                        return;
+                    }
+                    bisection(|t| curvature(curve, t), range)
+                }
+                None => {
+                    let dt = (range.1 - range.0) * 1.0e-3;
+                    // the differences are one-sided at the ends, so as to stay in the domain
+                    let (t0, t1) = curve.range_tuple();
+                    let dk = |t: f64| {
+                        curvature(curve, f64::min(t + dt, t1)) - curvature(curve, f64::max(t - dt, t0))
+                    };
+                    if dk(range.0) >= 0.0 || dk(range.1) <= 0.0 {
+                        return;
+                    }
+                    let t = bisection(dk, range);
+                    if !curvature(curve, t).so_small() {
+                        return;
+                    }
+                    t
+                }
+            };
+            inflections.push(CurvePoint {
+                edge_index,
+                parameter,
+                arc_length: arc_length_at(parameter),
+            });
+        });
+        offset += len;
+    });
+    // sign changes at junctions of edges
+    if reference_normal.is_some() {
+        edge_boundaries.iter().skip(1).for_each(|idx| {
+            let (p, q) = (&samples[idx - 1], &samples[*idx]);
+            if p.curvature * q.curvature < 0.0 {
+                inflections.push(CurvePoint {
+                    edge_index: q.edge_index,
+                    parameter: q.parameter,
+                    arc_length: q.arc_length,
+                });
+            }
+        });
+        inflections.sort_by(|a, b| a.arc_length.partial_cmp(&b.arc_length).unwrap());
+    }
+
+    let max_sample = samples
+        .iter()
+        .max_by(|a, b| a.curvature.abs().partial_cmp(&b.curvature.abs()).unwrap())
+        .unwrap();
+    let max_curvature = max_sample.curvature.abs();
+    let max_curvature_at = CurvePoint {
+        edge_index: max_sample.edge_index,
+        parameter: max_sample.parameter,
+        arc_length: max_sample.arc_length,
+    };
+
+    let monotone = |span: &[CurvatureSample]| {
+        let increasing = span
+            .windows(2)
+            .all(|w| w[0].curvature.abs() <= w[1].curvature.abs() + TOLERANCE);
+        let decreasing = span
+            .windows(2)
+            .all(|w| w[0].curvature.abs() + TOLERANCE >= w[1].curvature.abs());
+        increasing || decreasing
+    };
+    let mut monotone_spans = Vec::with_capacity(inflections.len() + 1);
+    let mut start = 0;
+    inflections.iter().for_each(|inflection| {
+        let end = samples.partition_point(|s| s.arc_length < inflection.arc_length);
+        monotone_spans.push(monotone(&samples[start..end]));
+        start = end;
+    });
+    monotone_spans.push(monotone(&samples[start..]));
+
+    CurvatureProfile {
+        samples,
+        reference_normal,
+        inflections,
+        max_curvature,
+        max_curvature_at,
+        monotone_spans,
+        edge_boundaries,
+    }
+}
@@ -0,0 +1,576 @@
+use crate::ray_casting::{face_domain, FaceDomain};
+use crate::{fillet, geom_impls, single_curve, *};
+use std::collections::{HashMap, HashSet};
+use std::f64::consts::PI;
+
+/// the angle between the normals of the adjacent faces regarded as a feature edge
+const FEATURE_ANGLE: f64 = 1.0e-2;
+/// the minimum number of the divisions of the parameter ranges for marching the contours
+const MIN_DIVISION: usize = 8;
+/// the number of the divisions of the lines and circles for trimming them by faces
+const TRIM_DIVISION: usize = 64;
+/// the maximum number of doubling the samples of the contours for fitting
+const MAX_REFINEMENT: usize = 6;
+const SNP_TRIALS: usize = 100;
+const NEWTON_TRIALS: usize = 100;
+const BISECTION_TRIALS: usize = 64;
+
+/// The classification of the edges of solids for drawings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EdgeClass {
+    /// The adjacent faces meet at an angle. The edge is always drawn.
+    Feature,
+    /// The adjacent faces are tangent. The edge is hidden unless it is on a silhouette.
+    Smooth,
+}
+
+/// The unique edges of a solid classified for drawings, returned by [`Drawing::wireframe`].
+#[derive(Clone, Debug)]
+pub struct Wireframe {
+    /// the edges and their classes, in the order of `Solid::edge_iter`
+    pub edges: Vec<(Edge, EdgeClass)>,
+    /// the ids of the edges regarded as feature edges, since the normals of the adjacent faces
+    /// cannot be computed
+    pub unresolved: Vec<EdgeID>,
+}
+
+impl Wireframe {
+    /// Returns the iterator over the feature edges.
+    #[inline(always)]
+    pub fn feature_edges(&self) -> impl Iterator<Item = &Edge> {
+        self.edges_of(EdgeClass::Feature)
+    }
+
+    /// Returns the iterator over the smooth edges.
+    #[inline(always)]
+    pub fn smooth_edges(&self) -> impl Iterator<Item = &Edge> { self.edges_of(EdgeClass::Smooth) }
+
+    fn edges_of(&self, class: EdgeClass) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |(_, c)| *c == class).map(|(edge, _)| edge)
+    }
+}
+
+/// Generates the edges of solids for 2D drawings.
+pub trait Drawing {
+    /// Returns the unique edges classified by the dihedral angles at their middle points.
+    ///
+    /// An edge is smooth if the normals of the adjacent faces are parallel within
+    /// `1.0e-2` radian, e.g. the seams of cylinders, and a feature edge otherwise.
+    /// The edges whose normals cannot be computed are regarded as feature edges, and recorded in
+    /// [`Wireframe::unresolved`].
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let wireframe = cube.wireframe();
+    /// assert_eq!(wireframe.feature_edges().count(), 12);
+    /// assert_eq!(wireframe.smooth_edges().count(), 0);
+    /// ```
+    fn wireframe(&self) -> Wireframe;
+
+    /// Returns the silhouette curves of the curved faces viewed in the direction `view_dir`,
+    /// i.e. the curves where the normals of the surfaces are perpendicular to `view_dir`.
+    /// The curves are view-dependent: hidden lines are not removed.
+    ///
+    /// The curves are computed for each face and trimmed by the face.
+    /// - The silhouettes of the spheres are the exact circle arcs.
+    /// - The silhouettes of the surfaces of revolution of lines, e.g. cylinders and cones, are
+    ///   the exact lines.
+    /// - The silhouettes of the other surfaces are marched over the parameter division of the
+    ///   faces, refined by Newton's method in the parameter plane, and fitted by B-spline curves
+    ///   within `tol`. If the fitting fails within `tol`, the curve with the least error is used.
+    ///
+    /// The planes and the faces parallel to `view_dir` have no silhouettes.
+    ///
+    /// # Failures
+    /// Returns [`Error::ParameterSearchFailed`](crate::errors::Error::ParameterSearchFailed) with
+    /// the face if the boundaries of a curved face cannot be projected to the surface.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// // the cylinder of the radius 1 and the height 2 around the y-axis
+    /// let p = [(0.0, 2.0, 0.0), (1.0, 2.0, 0.0), (1.0, 0.0, 0.0), (0.0, 0.0, 0.0)];
+    /// let v = builder::vertices(p);
+    /// let wire: Wire = (0..3).map(|i| builder::line(&v[i], &v[i + 1])).collect();
+    /// let shell = builder::cone(&wire, Vector3::unit_y(), Rad(2.0 * PI));
+    /// let cylinder = Solid::new(vec![shell]);
+    ///
+    /// let curves = cylinder.silhouette_curves(Vector3::unit_x(), 1.0e-3).unwrap();
+    /// assert_eq!(curves.len(), 2);
+    /// for curve in curves {
+    ///     let Curve::Line(line) = curve else { panic!() };
+    ///     assert_near!(line.0.x, 0.0);
+    ///     assert_near!(f64::abs(line.0.z), 1.0);
+    ///     assert_near!((line.1 - line.0).magnitude(), 2.0);
+    /// }
+    /// ```
+    fn silhouette_curves(&self, view_dir: Vector3, tol: f64) -> Result<Vec<Curve>>;
+}
+
+impl Drawing for Solid {
+    fn wireframe(&self) -> Wireframe {
+        let mut classified = HashSet::new();
+        let (mut edges, mut unresolved) = (Vec::new(), Vec::new());
+        for shell in self.boundaries() {
+            let boundaries: Vec<Vec<Wire>> = shell.face_iter().map(Face::boundaries).collect();
+            let occurrences = fillet::occurrences(&boundaries);
+            for edge in shell.edge_iter() {
+                if !classified.insert(edge.id()) {
+                    continue;
+                }
+                let class = match occurrences[&edge.id()].as_slice() {
+                    &[(i0, j0, k0), (i1, _, _)] => {
+                        edge_class(&shell[i0], &boundaries[i0][j0][k0], &shell[i1])
+                    }
+                    _ => Some(EdgeClass::Feature),
+                };
+                if class.is_none() {
+                    unresolved.push(edge.id());
+                }
+                edges.push((edge, class.unwrap_or(EdgeClass::Feature)));
+            }
+        }
+        Wireframe { edges, unresolved }
+    }
+
+    fn silhouette_curves(&self, view_dir: Vector3, tol: f64) -> Result<Vec<Curve>> {
+        let dir = view_dir.normalize();
+        let curves = self
+            .face_iter()
+            .map(|face| face_silhouettes(face, dir, tol))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(curves.concat())
+    }
+}
+
+/// Returns the class of the edge, `None` if the normals of the faces cannot be computed.
+fn edge_class(face0: &Face, edge: &Edge, face1: &Face) -> Option<EdgeClass> {
+    let (_, n0, n1) = fillet::middle_normals(face0, edge, face1).ok()?;
+    match n0.angle(n1).0 < FEATURE_ANGLE {
+        true => Some(EdgeClass::Smooth),
+        false => Some(EdgeClass::Feature),
+    }
+}
+
+fn face_silhouettes(face: &Face, dir: Vector3, tol: f64) -> Result<Vec<Curve>> {
+    let surface = face.surface();
+    if matches!(surface, Surface::Plane(_)) {
+        return Ok(Vec::new());
+    }
+    let domain = face_domain(face, tol)?;
+    let contour = Contour {
+        surface: &surface,
+        domain: &domain,
+        field: Field::Silhouette(dir),
+        tol,
+    };
+    if let Some((center, radius)) = contour.sphere() {
+        return Ok(contour.trimmed_circle(center, radius, dir));
+    }
+    match &surface {
+        Surface::RevolutedCurve(surface)
+            if matches!(surface.entity().entity_curve(), Curve::Line(_)) =>
+        {
+            Ok(contour.ruled_silhouettes())
+        }
+        _ => Ok(contour.marched_curves()),
+    }
+}
+
+/// Returns the end of the interval between `t0` and `t1` on which `pred` holds, by bisection.
+/// `pred(t0)` must be `true` and `pred(t1)` must be `false`.
+pub(crate) fn bisect(mut t0: f64, mut t1: f64, pred: impl Fn(f64) -> bool) -> f64 {
+    for _ in 0..BISECTION_TRIALS {
+        let t = (t0 + t1) / 2.0;
+        match pred(t) {
+            true => t0 = t,
+            false => t1 = t,
+        }
+        if (t1 - t0).abs() < TOLERANCE * TOLERANCE {
+            break;
+        }
+    }
+    t0
+}
+
+/// Returns the maximal intervals on which `inside` holds, found by the samples `ts` and bisection
+/// at the changes.
+pub(crate) fn inside_runs(ts: &[f64], inside: impl Fn(f64) -> bool) -> Vec<(f64, f64)> {
+    let flags: Vec<bool> = ts.iter().map(|t| inside(*t)).collect();
+    let mut runs = Vec::new();
+    let mut start = flags[0].then_some(ts[0]);
+    for i in 1..ts.len() {
+        match (flags[i - 1], flags[i]) {
+            (false, true) => start = Some(bisect(ts[i], ts[i - 1], &inside)),
+            (true, false) => {
+                let end = bisect(ts[i - 1], ts[i], &inside);
+                runs.push((start.take().unwrap(), end));
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = start {
+        runs.push((start, ts[ts.len() - 1]));
+    }
+    runs.retain(|(t0, t1)| t1 - t0 > TOLERANCE);
+    runs
+}
+
+pub(crate) fn uniform_division((t0, t1): (f64, f64), n: usize) -> Vec<f64> {
+    (0..=n).map(|i| t0 + (t1 - t0) * i as f64 / n as f64).collect()
+}
+
+/// the edge of the grid: `(i, j, 0)` is from `(i, j)` to `(i + 1, j)`, and `(i, j, 1)` is from
+/// `(i, j)` to `(i, j + 1)`.
+type GridEdge = (usize, usize, usize);
+
+/// The scalar field on the surface whose zero set is the contour.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Field {
+    /// `normal · dir` for the silhouettes viewed in the direction `dir`
+    Silhouette(Vector3),
+    /// `(point - origin) · normal` for the section by the plane
+    Section(Point3, Vector3),
+}
+
+/// The contour of a field on a face, i.e. the silhouettes or the sections.
+pub(crate) struct Contour<'a> {
+    pub(crate) surface: &'a Surface,
+    pub(crate) domain: &'a FaceDomain,
+    pub(crate) field: Field,
+    pub(crate) tol: f64,
+}
+
+impl Contour<'_> {
+    pub(crate) fn range(&self) -> ((f64, f64), (f64, f64)) {
+        let (min, max) = (self.domain.uv_range.min(), self.domain.uv_range.max());
+        ((min.x, max.x), (min.y, max.y))
+    }
+
+    fn value(&self, uv: Point2) -> f64 {
+        match self.field {
+            Field::Silhouette(dir) => self.surface.normal(uv.x, uv.y).dot(dir),
+            Field::Section(origin, normal) => (self.surface.subs(uv.x, uv.y) - origin).dot(normal),
+        }
+    }
+
+    pub(crate) fn includes(&self, uv: Point2) -> bool {
+        let point = self.surface.subs(uv.x, uv.y);
+        self.domain.includes(uv, point, self.tol)
+    }
+
+    /// Returns the value of the field and its gradient in the parameter plane.
+    fn value_gradient(&self, uv: Point2) -> Option<(f64, Vector2)> {
+        match self.field {
+            Field::Silhouette(dir) => self.normal_gradient(uv, dir),
+            Field::Section(origin, normal) => {
+                let (surface, (u, v)) = (self.surface, (uv.x, uv.y));
+                let (uder, vder) = (surface.uder(u, v), surface.vder(u, v));
+                let gradient = Vector2::new(uder.dot(normal), vder.dot(normal));
+                Some(((surface.subs(u, v) - origin).dot(normal), gradient))
+            }
+        }
+    }
+
+    /// Returns the value `normal · dir` and its gradient in the parameter plane, where the normal
+    /// is the normalized cross product of the derivations.
+    fn normal_gradient(&self, uv: Point2, dir: Vector3) -> Option<(f64, Vector2)> {
+        let (surface, (u, v)) = (self.surface, (uv.x, uv.y));
+        let (uder, vder, uvder) = (surface.uder(u, v), surface.vder(u, v), surface.uvder(u, v));
+        let cross = uder.cross(vder);
+        let abs = cross.magnitude();
+        if abs.so_small() {
+            return None;
+        }
+        let normal = cross / abs;
+        // the derivations of the normal are the tangential parts of those of the cross product
+        let der = |cross_der: Vector3| (cross_der - normal * normal.dot(cross_der)).dot(dir);
+        let cross_uder = surface.uuder(u, v).cross(vder) + uder.cross(uvder);
+        let cross_vder = uvder.cross(vder) + uder.cross(surface.vvder(u, v));
+        let gradient = Vector2::new(der(cross_uder), der(cross_vder)) / abs;
+        Some((normal.dot(dir), gradient))
+    }
+
+    /// Moves `uv` onto the contour by Newton's method along the gradient.
+    fn newton(&self, mut uv: Point2) -> Option<Point2> {
+        for _ in 0..NEWTON_TRIALS {
+            let (value, gradient) = self.value_gradient(uv)?;
+            if gradient.magnitude2().so_small2() {
+                return None;
+            }
+            let delta = gradient * (value / gradient.magnitude2());
+            uv -= delta;
+            if delta.so_small() {
+                return Some(uv);
+            }
+        }
+        None
+    }
+
+    /// Returns the center and the radius if the surface is a part of a sphere, by checking that
+    /// the centers of curvature of the samples coincide.
+    pub(crate) fn sphere(&self) -> Option<(Point3, f64)> {
+        let ((u0, u1), (v0, v1)) = self.range();
+        let samples: Vec<(Point3, Vector3)> = uniform_division((u0, u1), 4)
+            .into_iter()
+            .flat_map(|u| uniform_division((v0, v1), 4).into_iter().map(move |v| (u, v)))
+            .map(|(u, v)| (self.surface.subs(u, v), self.surface.normal(u, v)))
+            .collect();
+        let (p0, n0) = samples[12];
+        let (p1, n1) = samples.iter().copied().max_by(|(_, n), (_, m)| {
+            (*n - n0).magnitude2().total_cmp(&(*m - n0).magnitude2())
+        })?;
+        if (n1 - n0).so_small() {
+            return None;
+        }
+        let radius = (p0 - p1).dot(n0 - n1) / (n0 - n1).magnitude2();
+        let center = p0 - n0 * radius;
+        let on_sphere = samples.iter().all(|(p, n)| center.near(&(*p - *n * radius)));
+        match on_sphere && !radius.so_small() {
+            true => Some((center, radius.abs())),
+            false => None,
+        }
+    }
+
+    /// The circle around the unit vector `axis` on the sphere, trimmed by the face. The circle is
+    /// trimmed exactly by the boundaries, so that the arcs of the adjacent faces do not overlap,
+    /// e.g. at the poles where all faces meet.
+    pub(crate) fn trimmed_circle(&self, center: Point3, radius: f64, axis: Vector3) -> Vec<Curve> {
+        let other = match axis.x.abs() < 0.9 {
+            true => Vector3::unit_x(),
+            false => Vector3::unit_y(),
+        };
+        let e0 = axis.cross(other).normalize();
+        let e1 = axis.cross(e0);
+        let point = |t: f64| center + (e0 * f64::cos(t) + e1 * f64::sin(t)) * radius;
+        // the search from the middle of the face keeps the parameters near the poles in the face
+        let ((u0, u1), (v0, v1)) = self.range();
+        let hint = ((u0 + u1) / 2.0, (v0 + v1) / 2.0);
+        let inside = |t: f64| {
+            let point = point(t);
+            let uv = (self.surface.search_parameter(point, hint, SNP_TRIALS))
+                .or_else(|| self.surface.search_parameter(point, None, SNP_TRIALS));
+            uv.is_some_and(|uv| self.domain.strictly_includes(uv.into()))
+        };
+        let mut runs = inside_runs(&uniform_division((0.0, 2.0 * PI), TRIM_DIVISION), inside);
+        // the run through `t = 0`
+        if runs.len() > 1 && runs[0].0 == 0.0 && runs[runs.len() - 1].1 == 2.0 * PI {
+            let (_, t1) = runs.remove(0);
+            runs.last_mut().unwrap().1 = t1 + 2.0 * PI;
+        }
+        runs.into_iter()
+            .map(|(t0, t1)| {
+                let arc = geom_impls::circle_arc(point(t0), center, axis, Rad(t1 - t0));
+                arc.to_same_geometry()
+            })
+            .collect()
+    }
+
+    /// The silhouettes of the surface of revolution of a line, whose normals are constant along
+    /// the rulings `v = const`.
+    fn ruled_silhouettes(&self) -> Vec<Curve> {
+        let ((u0, u1), vrange) = self.range();
+        let u = (u0 + u1) / 2.0;
+        let value = |v: f64| self.value(Point2::new(u, v));
+        let vs = uniform_division(vrange, TRIM_DIVISION);
+        let values: Vec<f64> = vs.iter().map(|v| value(*v)).collect();
+        if values.iter().all(|x| x.abs() < TOLERANCE) {
+            return Vec::new();
+        }
+        let us = uniform_division((u0, u1), TRIM_DIVISION);
+        (1..vs.len())
+            .filter(|i| (values[i - 1] < 0.0) != (values[*i] < 0.0))
+            .map(|i| {
+                let sign = values[i - 1] < 0.0;
+                bisect(vs[i - 1], vs[i], |v| (value(v) < 0.0) == sign)
+            })
+            .flat_map(|v| {
+                let runs = inside_runs(&us, |u| self.includes(Point2::new(u, v)));
+                runs.into_iter().map(move |(u0, u1)| {
+                    let line = Line(self.surface.subs(u0, v), self.surface.subs(u1, v));
+                    Curve::Line(line)
+                })
+            })
+            .collect()
+    }
+
+    /// The contour marched over the grid of the parameter division.
+    pub(crate) fn marched_curves(&self) -> Vec<Curve> {
+        let (urange, vrange) = self.range();
+        let (us, vs) = self.surface.parameter_division((urange, vrange), self.tol);
+        let us = match us.len() > MIN_DIVISION {
+            true => us,
+            false => uniform_division(urange, MIN_DIVISION),
+        };
+        let vs = match vs.len() > MIN_DIVISION {
+            true => vs,
+            false => uniform_division(vrange, MIN_DIVISION),
+        };
+        let grid = |i: usize, j: usize| Point2::new(us[i], vs[j]);
+        let values: Vec<Vec<f64>> = (0..us.len())
+            .map(|i| (0..vs.len()).map(|j| self.value(grid(i, j))).collect())
+            .collect();
+        if values.iter().flatten().all(|x| x.abs() < TOLERANCE) {
+            return Vec::new();
+        }
+        let sign = |i: usize, j: usize| values[i][j] < 0.0;
+
+        // the crossings of the contour and the edges of the grid
+        let mut crossings = HashMap::<GridEdge, Point2>::new();
+        let mut add_crossing = |edge: GridEdge, p: Point2, q: Point2, sign: bool| {
+            let at = |t: f64| p + (q - p) * t;
+            let t = bisect(0.0, 1.0, |t| (self.value(at(t)) < 0.0) == sign);
+            crossings.insert(edge, at(t));
+        };
+        for i in 0..us.len() {
+            for j in 0..vs.len() {
+                if i + 1 < us.len() && sign(i, j) != sign(i + 1, j) {
+                    add_crossing((i, j, 0), grid(i, j), grid(i + 1, j), sign(i, j));
+                }
+                if j + 1 < vs.len() && sign(i, j) != sign(i, j + 1) {
+                    add_crossing((i, j, 1), grid(i, j), grid(i, j + 1), sign(i, j));
+                }
+            }
+        }
+
+        // the segments of the contour in the cells
+        let mut links = HashMap::<GridEdge, Vec<GridEdge>>::new();
+        for i in 0..us.len() - 1 {
+            for j in 0..vs.len() - 1 {
+                let sides = [(i, j, 0), (i + 1, j, 1), (i, j + 1, 0), (i, j, 1)];
+                let crossed: Vec<GridEdge> = sides
+                    .into_iter()
+                    .filter(|side| crossings.contains_key(side))
+                    .collect();
+                let pairs = match crossed.len() {
+                    2 => vec![(crossed[0], crossed[1])],
+                    // the saddle: the center decides which corners are separated
+                    4 => {
+                        let center = grid(i, j).midpoint(grid(i + 1, j + 1));
+                        match (self.value(center) < 0.0) == sign(i, j) {
+                            true => vec![(sides[0], sides[1]), (sides[2], sides[3])],
+                            false => vec![(sides[0], sides[3]), (sides[1], sides[2])],
+                        }
+                    }
+                    _ => Vec::new(),
+                };
+                pairs.into_iter().for_each(|(a, b)| {
+                    links.entry(a).or_default().push(b);
+                    links.entry(b).or_default().push(a);
+                });
+            }
+        }
+
+        chains(&links)
+            .into_iter()
+            .flat_map(|chain| {
+                let uvs: Vec<Point2> = chain.iter().map(|edge| crossings[edge]).collect();
+                self.trimmed_chain(&uvs)
+            })
+            .collect()
+    }
+
+    /// Trims the polyline on the contour by the face, and fits curves to the pieces.
+    fn trimmed_chain(&self, uvs: &[Point2]) -> Vec<Curve> {
+        // the point on the contour at the parameter `t` of the polyline
+        let uv_at = |t: f64| {
+            let i = usize::min(t.floor() as usize, uvs.len() - 2);
+            let s = t - i as f64;
+            match s.so_small() || (1.0 - s).so_small() {
+                true => uvs[i] + (uvs[i + 1] - uvs[i]) * s.round(),
+                false => {
+                    let uv = uvs[i] + (uvs[i + 1] - uvs[i]) * s;
+                    self.newton(uv).unwrap_or(uv)
+                }
+            }
+        };
+        let ts: Vec<f64> = (0..uvs.len()).map(|i| i as f64).collect();
+        let runs = inside_runs(&ts, |t| self.includes(uv_at(t)));
+        runs.into_iter()
+            .filter_map(|(t0, t1)| {
+                let mut division = vec![t0];
+                division.extend(ts.iter().copied().filter(|t| t0 < *t && *t < t1));
+                division.push(t1);
+                let point = |t: f64| {
+                    let uv = uv_at(t);
+                    self.surface.subs(uv.x, uv.y)
+                };
+                // the pieces degenerated to points, e.g. the contours touching the corners
+                let front = point(t0);
+                if division.iter().all(|t| point(*t).distance(front) < self.tol) {
+                    return None;
+                }
+                let mut least: Option<(BSplineCurve<Point3>, f64)> = None;
+                for level in 0..=MAX_REFINEMENT {
+                    let samples = refined_samples(&division, level, point);
+                    let coarse = level == 0;
+                    let fitted = single_curve::fit_samples(&samples, (0.0, 1.0), coarse, self.tol);
+                    let Ok((curve, error)) = fitted else {
+                        continue;
+                    };
+                    if error <= self.tol {
+                        return Some(Curve::BSplineCurve(curve));
+                    }
+                    if least.as_ref().is_none_or(|(_, least)| error < *least) {
+                        least = Some((curve, error));
+                    }
+                }
+                least.map(|(curve, _)| Curve::BSplineCurve(curve))
+            })
+            .collect()
+    }
+}
+
+/// Returns the points at the division and their midpoints alternately, i.e. the division points
+/// have the even indices. Each division is divided into `2^level` parts.
+fn refined_samples(division: &[f64], level: usize, point: impl Fn(f64) -> Point3) -> Vec<Point3> {
+    let parts = 1 << level;
+    let mut samples = vec![point(division[0])];
+    division.windows(2).for_each(|p| {
+        (0..parts).for_each(|i| {
+            let t = |s: f64| p[0] + (p[1] - p[0]) * (i as f64 + s) / parts as f64;
+            samples.push(point(t(0.5)));
+            samples.push(point(t(1.0)));
+        });
+    });
+    samples
+}
+
+/// Links the edges of the grid into the polylines. The closed polylines end at their starts.
+fn chains(links: &HashMap<GridEdge, Vec<GridEdge>>) -> Vec<Vec<GridEdge>> {
+    let mut keys: Vec<GridEdge> = links.keys().copied().collect();
+    keys.sort();
+    // the open polylines start from their ends, and the closed ones from anywhere
+    let ends = keys.iter().filter(|key| links[key].len() == 1);
+    let starts: Vec<GridEdge> = ends.chain(&keys).copied().collect();
+    let mut visited = HashSet::new();
+    let mut res = Vec::new();
+    for start in starts {
+        if !visited.insert(start) {
+            continue;
+        }
+        let mut chain = vec![start];
+        while let Some(next) = links[&chain[chain.len() - 1]]
+            .iter()
+            .find(|key| !visited.contains(*key))
+        {
+            visited.insert(*next);
+            chain.push(*next);
+        }
+        if chain.len() > 2 && links[&chain[chain.len() - 1]].contains(&start) {
+            chain.push(start);
+        }
+        if chain.len() > 1 {
+            res.push(chain);
+        }
+    }
+    res
+}
@@ -1,11 +1,14 @@
 use thiserror::Error;
 
 /// Modeling errors
-#[derive(Debug, PartialEq, Eq, Error)]
+#[derive(Debug, PartialEq, Error)]
 pub enum Error {
     /// wrapper of topological error
     #[error(transparent)]
     FromTopology(#[from] truck_topology::errors::Error),
+    /// wrapper of geometrical error
+    #[error(transparent)]
+    FromGeometry(#[from] truck_geometry::errors::Error),
     /// tried to attach a plane to a wire that was not on one plane.
     /// cf. [`builder::try_attach_plane`](../builder/fn.try_attach_plane.html)
     #[error("cannot attach a plane to a wire that is not on one plane.")]
@@ -14,6 +17,183 @@ pub enum Error {
     /// cf. [`builder::try_wire_homotopy`](../builder/fn.try_wire_homotopy.html)
     #[error("The wires must contain the same number of edges to create a homotopy.")]
     NotSameNumberOfEdges,
+    /// tried to create an arc by a chord which is degenerate or parallel to the z-axis.
+    /// cf. [`builder::arc_between`](../builder/fn.arc_between.html)
+    #[error("The chord of an arc must not be degenerate or parallel to the z-axis.")]
+    DegenerateChord,
+    /// tried to fillet an edge which is not a convex line segment between planar faces, whose
+    /// ends are not shared by exactly three faces, or whose fillet cannot meet the adjacent ones.
+    /// cf. [`builder::fillet_edge`](../builder/fn.fillet_edge.html)
+    #[error("The edge cannot be filleted.")]
+    CannotFillet,
+    /// tried to fillet an edge by a radius larger than the adjacent edges.
+    /// cf. [`builder::fillet_edge`](../builder/fn.fillet_edge.html)
+    #[error("The fillet radius is too large for the adjacent edges.")]
+    FilletRadiusTooLarge,
+    /// tried to fillet three or more edges meeting at vertices of more than three edges.
+    /// cf. [`builder::fillet_edges`](../builder/fn.fillet_edges.html)
+    #[error("The corners of fillets are not supported, at the vertices: {0:?}")]
+    FilletCorners(Vec<crate::VertexID>),
+    /// an operation of a history referred to a result which is not recorded before it.
+    /// cf. [`ModelHistory`](../history/struct.ModelHistory.html)
+    #[error("The result of the {0}th operation is not recorded before the operation.")]
+    HistoryIndexOutOfRange(usize),
+    /// an operation of a history referred to a shape of an unexpected type.
+    /// cf. [`ModelHistory`](../history/struct.ModelHistory.html)
+    #[error("The result of the {0}th operation is unexpected type of shape for the operation.")]
+    UnexpectedShape(usize),
+    /// tried to create a face whose boundary is not on the surface within the tolerance.
+    /// cf. [`builder::face_on_surface`](../builder/fn.face_on_surface.html)
+    #[error("The edge {0:?} is not on the surface.")]
+    EdgeNotOnSurface(crate::EdgeID),
+    /// tried to create a face whose outer boundary is not counterclockwise or whose holes are not
+    /// clockwise, in the parameter space of the surface.
+    /// cf. [`builder::face_on_surface`](../builder/fn.face_on_surface.html)
+    #[error("The outer boundary must be counterclockwise, and the holes must be clockwise.")]
+    InvalidLoopOrientation,
+    /// tried to create a face whose holes are not inside the outer boundary, or nested.
+    /// cf. [`builder::face_on_surface`](../builder/fn.face_on_surface.html)
+    #[error("The holes must be inside the outer boundary and outside the other holes.")]
+    InvalidLoopNesting,
+    /// healing shells needed more repairs than the limit.
+    /// cf. [`SolidHealing`](../healing/trait.SolidHealing.html)
+    #[error("The healing needed {0} repairs, more than the limit.")]
+    TooManyRepairs(usize),
+    /// the shells are not boundaries of a solid even after healing.
+    /// cf. [`SolidHealing`](../healing/trait.SolidHealing.html)
+    #[error("The shells have defects even after healing: {0:?}")]
+    UnhealedDefects(Vec<crate::healing::Defect>),
+    /// tried to extract a patch of a face whose boundaries span no area in the parameter space.
+    /// cf. [`TrimmedSurfacePatch`](../patch/trait.TrimmedSurfacePatch.html)
+    #[error("The boundaries of the face span no area in the parameter space.")]
+    DegenerateParameterRange,
+    /// tried to merge solids with different units of length.
+    /// cf. [`UnitizedSolid::try_merge`](../unit/struct.UnitizedSolid.html#method.try_merge)
+    #[error("The solids have different units of length.")]
+    UnitMismatch,
+    /// tried to compute the convex hull of a solid whose points are coplanar.
+    /// cf. [`ConvexHull`](../convex_hull/trait.ConvexHull.html)
+    #[error("The convex hull has no volume.")]
+    DegenerateConvexHull,
+    /// tried to rotate about an edge which is not straight, or a face which is not planar.
+    /// cf. [`builder::rotated_about_edge`](../builder/fn.rotated_about_edge.html)
+    #[error("The reference entity does not define an axis.")]
+    NoAxis,
+    /// tried to sweep a profile wire which is not closed into a solid.
+    /// `gap` is the largest distance between the ends of consecutive edges.
+    /// cf. [`builder::sweep`](../builder/fn.sweep.html)
+    #[error("The profile wire is not closed, the gap is {gap}.")]
+    OpenWire {
+        /// the largest distance between the ends of consecutive edges
+        gap: f64,
+    },
+    /// tried to extrude a face whose surface is not a plane.
+    /// cf. [`builder::extrude`](../builder/fn.extrude.html)
+    #[error("The face to be extruded must be planar.")]
+    NotPlanarFace,
+    /// tried to extrude a face by a vector which is zero or parallel to the face.
+    /// cf. [`builder::extrude`](../builder/fn.extrude.html)
+    #[error("The extrusion vector must not be zero or parallel to the face.")]
+    ExtrusionParallelToFace,
+    /// tried to match the parameterizations of curves by lists of parameters whose number is not
+    /// the same as the curves, or whose lengths are different.
+    /// cf. [`builder::match_parameterizations`](../builder/fn.match_parameterizations.html)
+    #[error("The correspondence must have the same number of parameters for each curve.")]
+    InvalidCorrespondence,
+    /// tried to approximate a wire whose consecutive edges are not connected by one curve.
+    /// `gap` is the largest distance between the ends of consecutive edges.
+    /// cf. [`SingleCurve`](../single_curve/trait.SingleCurve.html)
+    #[error("The wire is not connected, the gap is {gap}.")]
+    DisconnectedWire {
+        /// the largest distance between the ends of consecutive edges
+        gap: f64,
+    },
+    /// failed to approximate a wire by one curve within the tolerance.
+    /// cf. [`SingleCurve`](../single_curve/trait.SingleCurve.html)
+    #[error("The wire cannot be approximated by one curve, the least error is {error}.")]
+    CurveFittingFailure {
+        /// the least error of the approximations
+        error: f64,
+    },
+    /// tried to restore the geometry of an entity which is no longer in the solid.
+    /// cf. [`SnapshotGeometry`](../snapshot/trait.SnapshotGeometry.html)
+    #[error("The entity {0:?} is not in the solid.")]
+    EntityNotFound(crate::snapshot::EntityId),
+    /// failed to search the parameter of a point on a surface, e.g. on a degenerate surface.
+    ///
+    /// The public functions searching parameters on surfaces behave as follows on the failures.
+    ///
+    /// | function | behavior on the failures |
+    /// |----------|--------------------------|
+    /// | [`builder::face_on_surface`] | returns this error with the edge |
+    /// | [`builder::fillet_edge`] | returns this error with the face (untested) |
+    /// | [`builder::round_edges`] | returns this error with the face |
+    /// | [`ConvexHull::convex_hull`] | returns this error with the face |
+    /// | [`Drawing::silhouette_curves`] | returns this error with the face |
+    /// | [`FaceProximity::proximity`] | returns this error with the face |
+    /// | [`PlaneSection::section`] | returns this error with the face |
+    /// | [`TrimmedSurfacePatch::trimmed_surface_patch`] | returns this error with the face |
+    /// | [`Drawing::wireframe`] | records the edges in `Wireframe::unresolved` |
+    /// | [`BoundaryGap::max_boundary_gap`] | records the samples in `GapReport::fallbacks` |
+    /// | [`AuditOrientation::orientation_audit`] | records the wires by `uv_area: None` |
+    /// | [`Interference::interferes_with`] | does not check the faces |
+    /// | [`RayCasting::ray_hits`] | does not hit the faces |
+    /// | [`RemoveInteriorFaces::remove_interior_faces`] | keeps the faces |
+    /// | [`PlanarBoundary::boundary_in_plane`] | returns `None` (untested) |
+    /// | [`Validate::validate`] | reports `ValidationIssue::UnprojectableFace` |
+    ///
+    /// [`builder::face_on_surface`]: ../builder/fn.face_on_surface.html
+    /// [`builder::fillet_edge`]: ../builder/fn.fillet_edge.html
+    /// [`builder::round_edges`]: ../builder/fn.round_edges.html
+    /// [`ConvexHull::convex_hull`]: ../convex_hull/trait.ConvexHull.html
+    /// [`Drawing::silhouette_curves`]: ../drawing/trait.Drawing.html
+    /// [`FaceProximity::proximity`]: ../proximity/trait.FaceProximity.html
+    /// [`PlaneSection::section`]: ../section/trait.PlaneSection.html
+    /// [`TrimmedSurfacePatch::trimmed_surface_patch`]: ../patch/trait.TrimmedSurfacePatch.html
+    /// [`Drawing::wireframe`]: ../drawing/trait.Drawing.html
+    /// [`BoundaryGap::max_boundary_gap`]: ../gap/trait.BoundaryGap.html
+    /// [`AuditOrientation::orientation_audit`]: ../audit/trait.AuditOrientation.html
+    /// [`Interference::interferes_with`]: ../interference/trait.Interference.html
+    /// [`RayCasting::ray_hits`]: ../ray_casting/trait.RayCasting.html
+    /// [`RemoveInteriorFaces::remove_interior_faces`]: ../interior/trait.RemoveInteriorFaces.html
+    /// [`PlanarBoundary::boundary_in_plane`]: ../flatten/trait.PlanarBoundary.html
+    /// [`Validate::validate`]: ../validation/trait.Validate.html
+    #[error(
+        "Failed to search the parameter of {point:?} on the {surface_kind:?} of {entity:?}, \
+         from the hint {hint:?}."
+    )]
+    ParameterSearchFailed {
+        /// the entity whose surface is searched, or the edge to be put on the surface
+        entity: crate::snapshot::EntityId,
+        /// the point whose parameter is searched
+        point: crate::Point3,
+        /// the hint of the first search, `None` if the parameter is presearched
+        hint: Option<(f64, f64)>,
+        /// the variant of the surface
+        surface_kind: crate::SurfaceKind,
+    },
+    /// tried to build a Gordon surface through less than two curves in either direction, or
+    /// through the curves which are not ordered along the curves in the other direction.
+    /// cf. [`builder::gordon_surface`](../builder/fn.gordon_surface.html)
+    #[error("The curve network must have two or more ordered curves in each direction.")]
+    InvalidCurveNetwork,
+    /// the curves of the network do not intersect at the common parameters within the tolerance.
+    /// `gap` is the largest distance between the curves at the intersections.
+    /// cf. [`builder::gordon_surface`](../builder/fn.gordon_surface.html)
+    #[error("The curves of the network do not intersect consistently, the gap is {gap}.")]
+    InconsistentCurveNetwork {
+        /// the largest distance between the curves at the intersections
+        gap: f64,
+    },
+    /// tried to loft between wires one of which is not continuous, or only one of which is
+    /// closed.
+    /// cf. [`builder::loft`](../builder/fn.loft.html)
+    #[error("The wires to be lofted must be continuous and both closed or both open.")]
+    InconsistentWires,
+    /// tried to sweep along a path whose derivative vanishes.
+    /// cf. [`builder::pipe_sweep`](../builder/fn.pipe_sweep.html)
+    #[error("The derivative of the path must not vanish.")]
+    DegeneratePath,
 }
 
 #[test]
@@ -31,6 +211,7 @@ fn print_messages() {
     )
     .unwrap();
     writeln!(&mut std::io::stderr(), "{}\n", Error::WireNotInOnePlane).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::DegenerateChord).unwrap();
     writeln!(
         &mut std::io::stderr(),
         "*******************************************************"
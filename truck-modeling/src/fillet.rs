@@ -0,0 +1,615 @@
+use crate::{errors::Error, geom_impls, *};
+use std::collections::{HashMap, HashSet};
+use std::f64::consts::PI;
+
+/// the position of an edge in a shell: the indices of the face, the wire, and the edge
+type Occurrence = (usize, usize, usize);
+
+pub(super) fn occurrences(boundaries: &[Vec<Wire>]) -> HashMap<EdgeID, Vec<Occurrence>> {
+    let mut res = HashMap::<EdgeID, Vec<Occurrence>>::new();
+    for (i, wires) in boundaries.iter().enumerate() {
+        for (j, wire) in wires.iter().enumerate() {
+            for (k, edge) in wire.edge_iter().enumerate() {
+                res.entry(edge.id()).or_default().push((i, j, k));
+            }
+        }
+    }
+    res
+}
+
+/// Returns the edges whose dihedral angles are convex, in the order of `solid.edge_iter()`.
+pub(super) fn convex_edges(solid: &Solid, tol: f64) -> Result<Vec<EdgeID>> {
+    let mut res = Vec::new();
+    for shell in solid.boundaries() {
+        let boundaries: Vec<Vec<Wire>> = shell.face_iter().map(Face::boundaries).collect();
+        let occurrences = occurrences(&boundaries);
+        for edge in shell.edge_iter() {
+            let &[(i0, j0, k0), (i1, _, _)] = occurrences[&edge.id()].as_slice() else {
+                continue;
+            };
+            let edge = &boundaries[i0][j0][k0];
+            if !res.contains(&edge.id()) && is_convex(&shell[i0], edge, &shell[i1], tol)? {
+                res.push(edge.id());
+            }
+        }
+    }
+    Ok(res)
+}
+
+/// Whether the dihedral angle at the middle of `edge`, which is oriented as in `face0`, is convex.
+fn is_convex(face0: &Face, edge: &Edge, face1: &Face, tol: f64) -> Result<bool> {
+    let (der, n0, n1) = middle_normals(face0, edge, face1)?;
+    Ok(der.dot(n0.cross(n1)) > tol)
+}
+
+/// Returns the unit tangent of `edge`, which is oriented as in `face0`, and the normals of the
+/// faces at the middle of the edge.
+///
+/// # Failures
+/// Returns [`Error::ParameterSearchFailed`] with the face if the middle point cannot be
+/// projected to the surface.
+pub(super) fn middle_normals(
+    face0: &Face,
+    edge: &Edge,
+    face1: &Face,
+) -> Result<(Vector3, Vector3, Vector3)> {
+    let curve = edge.oriented_curve();
+    let (t0, t1) = curve.range_tuple();
+    let t = (t0 + t1) / 2.0;
+    let (point, der) = (curve.subs(t), curve.der(t).normalize());
+    let normal = |face: &Face| -> Result<Vector3> {
+        let surface = face.oriented_surface();
+        let (u, v) = surface.search_parameter(point, None, 100).ok_or_else(|| {
+            Error::ParameterSearchFailed {
+                entity: EntityId::Face(face.id()),
+                point,
+                hint: None,
+                surface_kind: surface.kind(),
+            }
+        })?;
+        Ok(surface.normal(u, v))
+    };
+    Ok((der, normal(face0)?, normal(face1)?))
+}
+
+/// Fillets all edges in `edges` by the rolling ball of `radius`.
+pub(super) fn fillet_edges(
+    solid: &Solid,
+    edges: &[EdgeID],
+    radius: f64,
+    tol: f64,
+) -> Result<Solid> {
+    let corners = corners(solid, edges);
+    if !corners.is_empty() {
+        return Err(Error::FilletCorners(corners));
+    }
+    let mut filleted = HashSet::new();
+    let shells = solid
+        .boundaries()
+        .iter()
+        .map(|shell| fillet_shell(shell, edges, radius, tol, &mut filleted))
+        .collect::<Result<Vec<_>>>()?;
+    if edges.iter().any(|id| !filleted.contains(id)) {
+        return Err(Error::CannotFillet);
+    }
+    Ok(Solid::try_new(shells)?)
+}
+
+/// the vertices where three or more filleted edges meet among more than three edges
+fn corners(solid: &Solid, edges: &[EdgeID]) -> Vec<VertexID> {
+    // the numbers of the filleted edges and all edges at the vertices
+    let mut counts = HashMap::<VertexID, (usize, usize)>::new();
+    solid
+        .edge_iter()
+        .map(|edge| (edge.id(), edge))
+        .collect::<HashMap<_, _>>()
+        .into_iter()
+        .flat_map(|(id, edge)| [edge.front().id(), edge.back().id()].map(|v| (v, id)))
+        .for_each(|(v, id)| {
+            let count = counts.entry(v).or_default();
+            count.0 += edges.contains(&id) as usize;
+            count.1 += 1;
+        });
+    let mut res = Vec::new();
+    solid.vertex_iter().for_each(|v| {
+        let count = counts.get(&v.id());
+        let corner = count.is_some_and(|&(filleted, valence)| filleted >= 3 && valence > 3);
+        if corner && !res.contains(&v.id()) {
+            res.push(v.id());
+        }
+    });
+    res
+}
+
+/// the modifications of the boundaries of a shell by fillets
+#[derive(Default)]
+struct Modification {
+    /// the filleted edges, the front vertices in the first faces, and the replacing edges
+    /// in the first and second faces, respectively
+    replaced: HashMap<EdgeID, (VertexID, Edge, Edge)>,
+    /// the adjacent edges, the vertices to be moved, and the new vertices
+    trims: HashMap<EdgeID, (Edge, Vec<(VertexID, Vertex)>)>,
+    /// the edges inserted after the given edges ending at the given vertices
+    insertions: HashMap<(EdgeID, VertexID), Edge>,
+    /// the ends of the fillets at the vertices where several fillets meet, from the contact
+    /// points on the first faces to the ones on the second faces, and how far they go beyond
+    /// the vertices
+    junctions: HashMap<(EdgeID, VertexID), (Edge, f64)>,
+    /// the fillet faces and the corner patches
+    faces: Vec<Face>,
+}
+
+fn fillet_shell(
+    shell: &Shell,
+    edges: &[EdgeID],
+    radius: f64,
+    tol: f64,
+    filleted: &mut HashSet<EdgeID>,
+) -> Result<Shell> {
+    let boundaries: Vec<Vec<Wire>> = shell.face_iter().map(Face::boundaries).collect();
+    let occurrences = occurrences(&boundaries);
+    let mut fillets = Vec::new();
+    for id in edges {
+        // the duplicated ids are filleted once
+        let Some(occurrence) = occurrences.get(id).filter(|_| !filleted.contains(id)) else {
+            continue;
+        };
+        let &[occurrence0, occurrence1] = occurrence.as_slice() else {
+            return Err(Error::CannotFillet);
+        };
+        let pair = [occurrence0, occurrence1];
+        fillets.push(EdgeFillet::new(shell, &boundaries, &occurrences, pair, radius, tol)?);
+        filleted.insert(*id);
+    }
+    let mut modification = Modification::default();
+    build_junctions(&fillets, tol, &mut modification)?;
+    for fillet in &fillets {
+        fillet.build(tol, &mut modification)?;
+    }
+    modification.apply(shell, &boundaries, tol)
+}
+
+/// the filleted edge and its neighborhood
+struct EdgeFillet {
+    /// the id of the edge
+    id: EdgeID,
+    /// the indices of the first and second faces
+    faces: [usize; 2],
+    /// the ends of the edge, oriented as in the first face
+    ends: [Vertex; 2],
+    /// the outer normals of the first and second faces
+    normals: [Vector3; 2],
+    /// the normals of the side faces at the ends
+    side_normals: [Vector3; 2],
+    /// the edge of the first face ending at the front and the one starting at the back
+    first_neighbors: [Edge; 2],
+    /// the edge of the second face starting at the front and the one ending at the back
+    second_neighbors: [Edge; 2],
+    /// the radius of the rolling ball
+    radius: f64,
+    /// the unit direction of the edge, oriented as in the first face
+    dir: Vector3,
+    /// the center of the ball relative to the front
+    offset: Vector3,
+    /// the contact points of the ball on the first and second faces relative to the front
+    contacts: [Vector3; 2],
+    /// the section of the fillet at the front, from the contact point on the first face
+    section: Curve,
+}
+
+impl EdgeFillet {
+    fn new(
+        shell: &Shell,
+        boundaries: &[Vec<Wire>],
+        occurrences: &HashMap<EdgeID, Vec<Occurrence>>,
+        [(i0, j0, k0), (i1, j1, k1)]: [Occurrence; 2],
+        radius: f64,
+        tol: f64,
+    ) -> Result<Self> {
+        let neighbors = |i: usize, j: usize, k: usize| {
+            let wire = &boundaries[i][j];
+            let len = wire.len();
+            [wire[(k + len - 1) % len].clone(), wire[(k + 1) % len].clone()]
+        };
+        let edge = &boundaries[i0][j0][k0];
+        let ends = [edge.front().clone(), edge.back().clone()];
+        let [prev0, next0] = neighbors(i0, j0, k0);
+        let [prev1, next1] = neighbors(i1, j1, k1);
+        let first_neighbors = [prev0, next0];
+        let second_neighbors = [next1, prev1];
+        let side_face = |neighbor: &Edge, face: usize, vertex: &Vertex| {
+            let sides = occurrences[&neighbor.id()]
+                .iter()
+                .map(|o| o.0)
+                .filter(|&i| i != face)
+                .collect::<Vec<_>>();
+            let valence = boundaries
+                .iter()
+                .filter(|wires| wires.iter().flat_map(Wire::vertex_iter).any(|v| v == *vertex))
+                .count();
+            match (sides.as_slice(), valence) {
+                (&[i], 3) => Ok(i),
+                _ => Err(Error::CannotFillet),
+            }
+        };
+        let sides = [
+            side_face(&first_neighbors[0], i0, &ends[0])?,
+            side_face(&first_neighbors[1], i0, &ends[1])?,
+        ];
+        let normal = |i: usize| match shell[i].oriented_surface() {
+            Surface::Plane(plane) => Ok(plane.normal()),
+            _ => Err(Error::CannotFillet),
+        };
+        let straight = [edge]
+            .into_iter()
+            .chain(&first_neighbors)
+            .chain(&second_neighbors)
+            .all(|edge| is_straight(edge, TOLERANCE));
+        if !straight || ends[0].id() == ends[1].id() {
+            return Err(Error::CannotFillet);
+        }
+        let (p0, p1) = (ends[0].point(), ends[1].point());
+        let dir = (p1 - p0).normalize();
+        let (n0, n1) = (normal(i0)?, normal(i1)?);
+        if dir.dot(n0.cross(n1)) <= tol {
+            return Err(Error::CannotFillet);
+        }
+        // the center of the rolling ball relative to the edge, and the contact points
+        let offset = -(n0 + n1) * radius / (1.0 + n0.dot(n1));
+        let (d0, d1) = (offset + n0 * radius, offset + n1 * radius);
+        let transit = p0 + offset + (n0 + n1).normalize() * radius;
+        let section =
+            geom_impls::circle_arc_by_three_points(p0 + d0, p0 + d1, transit).to_same_geometry();
+        Ok(Self {
+            id: edge.id(),
+            faces: [i0, i1],
+            ends,
+            normals: [n0, n1],
+            side_normals: [normal(sides[0])?, normal(sides[1])?],
+            first_neighbors,
+            second_neighbors,
+            radius,
+            dir,
+            offset,
+            contacts: [d0, d1],
+            section,
+        })
+    }
+
+    /// Returns the projection along the edge onto the plane through `origin` perpendicular to
+    /// `normal`, and how far the projected section goes beyond the end `i`.
+    fn end_projection(
+        &self,
+        i: usize,
+        origin: Point3,
+        normal: Vector3,
+        tol: f64,
+    ) -> Result<(Matrix4, f64)> {
+        let normal = normal.normalize();
+        let cos = self.dir.dot(normal);
+        if cos.abs() <= tol {
+            return Err(Error::CannotFillet);
+        }
+        let distance = (origin - self.ends[i].point()).dot(normal).abs();
+        let extent = (distance + self.offset.magnitude() + self.radius) / cos.abs() + self.radius;
+        Ok((projection(self.dir, origin, normal), extent))
+    }
+
+    /// Returns the end of the fillet at the end `i` of the edge, from the contact point on the
+    /// first face, and how far it goes beyond the vertex.
+    ///
+    /// If no other fillets meet at the vertex, the section is projected onto the side face, and
+    /// the side face is modified.
+    fn end(&self, i: usize, tol: f64, modification: &mut Modification) -> Result<(Edge, f64)> {
+        let vertex = &self.ends[i];
+        if let Some(end) = modification.junctions.get(&(self.id, vertex.id())) {
+            return Ok(end.clone());
+        }
+        let (mat, extent) = self.end_projection(i, vertex.point(), self.side_normals[i], tol)?;
+        let front = self.ends[0].point();
+        let [a, b] = self.contacts.map(|d| Vertex::new(mat.transform_point(front + d)));
+        let arc = Edge::new(&a, &b, self.section.transformed(mat));
+        let (g, h) = (&self.first_neighbors[i], &self.second_neighbors[i]);
+        modification.trim(g, vertex, &a);
+        modification.trim(h, vertex, &b);
+        let (key, inserted) = match i {
+            0 => ((h.id(), vertex.id()), arc.inverse()),
+            _ => ((g.id(), vertex.id()), arc.clone()),
+        };
+        modification.insertions.insert(key, inserted);
+        Ok((arc, extent))
+    }
+
+    fn build(&self, tol: f64, modification: &mut Modification) -> Result<()> {
+        let (arc0, extent0) = self.end(0, tol, modification)?;
+        let (arc1, extent1) = self.end(1, tol, modification)?;
+        let (a0, b0, a1, b1) = (arc0.front(), arc0.back(), arc1.front(), arc1.back());
+        let dir = self.dir;
+        if (a1.point() - a0.point()).dot(dir) <= tol || (b1.point() - b0.point()).dot(dir) <= tol
+        {
+            return Err(Error::FilletRadiusTooLarge);
+        }
+        let line = |v: &Vertex, w: &Vertex| Edge::new(v, w, Line(v.point(), w.point()).into());
+        let (line0, line1) = (line(a0, a1), line(b0, b1));
+
+        let (p0, p1) = (self.ends[0].point(), self.ends[1].point());
+        let start = self.section.transformed(Matrix4::from_translation(-dir * extent0));
+        let vector = dir * (extent0 + p0.distance(p1) + extent1);
+        let mut surface: Surface = ExtrudedCurve::by_extrusion(start, vector).to_same_geometry();
+        let [n0, n1] = self.normals;
+        let transit = p0 + self.offset + (n0 + n1).normalize() * self.radius;
+        orient(&mut surface, transit, n0 + n1, EntityId::Edge(self.id))?;
+        let wire = Wire::from(vec![line0.inverse(), arc0, line1.clone(), arc1.inverse()]);
+        modification.faces.push(Face::new(vec![wire], surface));
+        let v0 = self.ends[0].id();
+        modification.replaced.insert(self.id, (v0, line0, line1.inverse()));
+        Ok(())
+    }
+}
+
+/// Builds the ends of the fillets at the vertices where several fillets meet.
+fn build_junctions(
+    fillets: &[EdgeFillet],
+    tol: f64,
+    modification: &mut Modification,
+) -> Result<()> {
+    let mut order = Vec::new();
+    let mut junctions = HashMap::<VertexID, Vec<(&EdgeFillet, usize)>>::new();
+    for fillet in fillets {
+        for (i, vertex) in fillet.ends.iter().enumerate() {
+            let meeting = junctions.entry(vertex.id()).or_insert_with(|| {
+                order.push(vertex.id());
+                Vec::new()
+            });
+            meeting.push((fillet, i));
+        }
+    }
+    order.into_iter().try_for_each(|id| match junctions[&id].as_slice() {
+        [_] => Ok(()),
+        &[end0, end1] => mitre(end0, end1, tol, modification),
+        meeting => corner(meeting, tol, modification),
+    })
+}
+
+/// Builds the common end of two fillets meeting at a vertex on the plane bisecting them.
+///
+/// The plane must contain the third edge at the vertex, which is trimmed by the end.
+fn mitre(
+    (fillet, i): (&EdgeFillet, usize),
+    (other, j): (&EdgeFillet, usize),
+    tol: f64,
+    modification: &mut Modification,
+) -> Result<()> {
+    let vertex = &fillet.ends[i];
+    // the face shared by the fillets, and the third edge between the other faces
+    let (shared, third) = match fillet.first_neighbors[i].id() == other.id {
+        true => (fillet.faces[0], &fillet.second_neighbors[i]),
+        false => (fillet.faces[1], &fillet.first_neighbors[i]),
+    };
+    let away = |fillet: &EdgeFillet, i: usize| if i == 0 { fillet.dir } else { -fillet.dir };
+    let normal = away(fillet, i) - away(other, j);
+    let (mat, extent) = fillet.end_projection(i, vertex.point(), normal, tol)?;
+    let (_, other_extent) = other.end_projection(j, vertex.point(), normal, tol)?;
+    let front = fillet.ends[0].point();
+    let [a, b] = fillet.contacts.map(|d| Vertex::new(mat.transform_point(front + d)));
+    let bottom = if shared == fillet.faces[0] { &b } else { &a };
+    let (q0, q1) = (third.front().point(), third.back().point());
+    let (vec, w) = ((q1 - q0).normalize(), bottom.point() - q0);
+    if (w - vec * w.dot(vec)).magnitude() > tol {
+        return Err(Error::CannotFillet);
+    }
+    modification.trim(third, vertex, bottom);
+    let edge = Edge::new(&a, &b, fillet.section.transformed(mat));
+    let other_edge = match (other.faces[0] == shared) == (fillet.faces[0] == shared) {
+        true => edge.clone(),
+        false => edge.inverse(),
+    };
+    let junctions = &mut modification.junctions;
+    junctions.insert((fillet.id, vertex.id()), (edge, extent));
+    junctions.insert((other.id, vertex.id()), (other_edge, other_extent));
+    Ok(())
+}
+
+/// Builds the spherical patch at the vertex where three fillets meet, and their ends on it.
+fn corner(
+    meeting: &[(&EdgeFillet, usize)],
+    tol: f64,
+    modification: &mut Modification,
+) -> Result<()> {
+    let (fillet, i) = meeting[0];
+    let (vertex, radius) = (&fillet.ends[i], fillet.radius);
+    let mut faces = Vec::<(usize, Vector3)>::new();
+    for (fillet, _) in meeting {
+        for (face, normal) in fillet.faces.into_iter().zip(fillet.normals) {
+            if faces.iter().all(|&(i, _)| i != face) {
+                faces.push((face, normal));
+            }
+        }
+    }
+    let &[(_, n0), (_, n1), (_, n2)] = faces.as_slice() else {
+        return Err(Error::CannotFillet);
+    };
+    // the center of the ball touching the three faces
+    let inverse = Matrix3::from_cols(n0, n1, n2).transpose().invert();
+    let inverse = inverse.ok_or(Error::CannotFillet)?;
+    let center = vertex.point() - inverse * Vector3::new(radius, radius, radius);
+    let contacts = faces
+        .iter()
+        .map(|&(face, normal)| (face, Vertex::new(center + normal * radius)))
+        .collect::<HashMap<_, _>>();
+    let mut wire = Vec::new();
+    for &(fillet, i) in meeting {
+        let (mat, extent) = fillet.end_projection(i, center, fillet.dir, tol)?;
+        let [a, b] = fillet.faces.map(|face| &contacts[&face]);
+        let edge = Edge::new(a, b, fillet.section.transformed(mat));
+        // the fillet face has the end at the front in the same orientation
+        wire.push(if i == 0 { edge.inverse() } else { edge.clone() });
+        modification.junctions.insert((fillet.id, vertex.id()), (edge, extent));
+    }
+    if wire[0].back() != wire[1].front() {
+        wire.swap(1, 2);
+    }
+
+    // the contact points are within `angle` from the middle of the patch
+    let middle = (n0 + n1 + n2).normalize();
+    let cos = [n0, n1, n2].into_iter().fold(1.0, |cos, n| f64::min(cos, n.dot(middle)));
+    if cos <= tol {
+        return Err(Error::CannotFillet);
+    }
+    let angle = (cos.acos() + PI / 2.0) / 2.0;
+    let mut surface = Surface::NurbsSurface(sphere_patch(center, radius, middle, angle));
+    let top = center + middle * radius;
+    orient(&mut surface, top, middle, EntityId::Vertex(vertex.id()))?;
+    modification.faces.push(Face::new(vec![wire.into()], surface));
+    Ok(())
+}
+
+/// Returns the part of the sphere whose latitude and longitude from `middle` are within `angle`,
+/// which is less than the right angle, as a rational biquadratic surface.
+fn sphere_patch(center: Point3, radius: f64, middle: Vector3, angle: f64) -> NurbsSurface<Vector4> {
+    let axis = geom_impls::take_one_axis_by_normal(middle);
+    let side = axis.cross(middle);
+    let (cos, sin) = (angle.cos(), angle.sin());
+    // the control points and the weights of the meridian
+    let meridian = [
+        (center + (middle * cos - axis * sin) * radius, 1.0),
+        (center + middle * radius / cos, cos),
+        (center + (middle * cos + axis * sin) * radius, 1.0),
+    ];
+    let control_points = meridian
+        .into_iter()
+        .map(|(point, weight)| {
+            let origin = center + axis * axis.dot(point - center);
+            let (r, rotated) = (point - origin, side * middle.dot(point - origin) * sin);
+            [
+                (origin + r * cos - rotated, weight),
+                (origin + r / cos, weight * cos),
+                (origin + r * cos + rotated, weight),
+            ]
+            .map(|(point, weight)| point.to_homogeneous() * weight)
+            .to_vec()
+        })
+        .collect();
+    let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    NurbsSurface::new(BSplineSurface::new(knot_vecs, control_points))
+}
+
+/// Inverts `surface` if its normal at `point` is opposite to `normal`.
+///
+/// # Failures
+/// Returns [`Error::ParameterSearchFailed`] with `entity` if `point` cannot be projected.
+fn orient(surface: &mut Surface, point: Point3, normal: Vector3, entity: EntityId) -> Result<()> {
+    let (u, v) = surface.search_parameter(point, None, 100).ok_or_else(|| {
+        Error::ParameterSearchFailed {
+            entity,
+            point,
+            hint: None,
+            surface_kind: surface.kind(),
+        }
+    })?;
+    if surface.normal(u, v).dot(normal) < 0.0 {
+        surface.invert();
+    }
+    Ok(())
+}
+
+impl Modification {
+    /// Moves the end `vertex` of `edge` to `new`.
+    fn trim(&mut self, edge: &Edge, vertex: &Vertex, new: &Vertex) {
+        self.trims
+            .entry(edge.id())
+            .or_insert_with(|| (edge.absolute_clone(), Vec::new()))
+            .1
+            .push((vertex.id(), new.clone()));
+    }
+
+    fn apply(self, shell: &Shell, boundaries: &[Vec<Wire>], tol: f64) -> Result<Shell> {
+        let Self {
+            replaced,
+            trims,
+            insertions,
+            faces,
+            ..
+        } = self;
+        let trimmed = trims
+            .into_iter()
+            .map(|(id, (edge, ends))| {
+                let end = |v: &Vertex| {
+                    let new = ends.iter().find(|(id, _)| *id == v.id());
+                    new.map_or_else(|| v.clone(), |(_, new)| new.clone())
+                };
+                let (front, back) = (end(edge.front()), end(edge.back()));
+                let vec = edge.back().point() - edge.front().point();
+                if (back.point() - front.point()).dot(vec) <= tol * vec.magnitude() {
+                    return Err(Error::FilletRadiusTooLarge);
+                }
+                let curve = Line(front.point(), back.point()).into();
+                Ok((id, Edge::new(&front, &back, curve)))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+        let modified = |edge: &Edge| {
+            let (id, back) = (edge.id(), edge.back().id());
+            replaced.contains_key(&id)
+                || trimmed.contains_key(&id)
+                || insertions.contains_key(&(id, back))
+        };
+        let mut res: Shell = shell
+            .face_iter()
+            .zip(boundaries)
+            .map(|(face, wires)| {
+                if !wires.iter().flat_map(Wire::edge_iter).any(modified) {
+                    return face.clone();
+                }
+                let wires = wires
+                    .iter()
+                    .map(|wire| {
+                        let mut new_wire = Wire::new();
+                        for edge in wire {
+                            let id = edge.id();
+                            if let Some((front, edge0, edge1)) = replaced.get(&id) {
+                                match edge.front().id() == *front {
+                                    true => new_wire.push_back(edge0.clone()),
+                                    false => new_wire.push_back(edge1.clone()),
+                                }
+                            } else if let Some(new) = trimmed.get(&id) {
+                                match edge.orientation() {
+                                    true => new_wire.push_back(new.clone()),
+                                    false => new_wire.push_back(new.inverse()),
+                                }
+                            } else {
+                                new_wire.push_back(edge.clone());
+                            }
+                            if let Some(corner) = insertions.get(&(id, edge.back().id())) {
+                                new_wire.push_back(corner.clone());
+                            }
+                        }
+                        new_wire
+                    })
+                    .collect();
+                Face::new(wires, face.oriented_surface())
+            })
+            .collect();
+        res.extend(faces);
+        Ok(res)
+    }
+}
+
+/// Whether the edge is a line segment.
+fn is_straight(edge: &Edge, tol: f64) -> bool {
+    let curve = edge.curve();
+    let (t0, t1) = curve.range_tuple();
+    let (p, q) = (curve.subs(t0), curve.subs(t1));
+    let dir = (q - p).normalize();
+    [0.25, 0.5, 0.75].into_iter().all(|s| {
+        let vec = curve.subs(t0 + (t1 - t0) * s) - p;
+        (vec - dir * vec.dot(dir)).magnitude() < tol
+    })
+}
+
+/// the projection onto the plane through `origin` perpendicular to `normal`, along `dir`
+fn projection(dir: Vector3, origin: Point3, normal: Vector3) -> Matrix4 {
+    let cos = dir.dot(normal);
+    let outer = Matrix3::from_cols(dir * normal.x, dir * normal.y, dir * normal.z);
+    let trsl = Matrix4::from_translation(dir * normal.dot(origin.to_vec()) / cos);
+    trsl * Matrix4::from(Matrix3::identity() - outer / cos)
+}
@@ -0,0 +1,174 @@
+use crate::ray_casting::face_domain;
+use crate::*;
+
+/// A closed polyline in the coordinates of a plane.
+pub type PlanarLoop = Vec<(f64, f64)>;
+
+/// An entity of the 2D drawings, e.g. in DXF files, in the coordinates of a plane.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DxfEntity {
+    /// the line segment from the first point to the second
+    Line(Point2, Point2),
+    /// the circular arc `(center, radius, a0, a1)`, counterclockwise from the angle `a0` to `a1`
+    /// in radians. The central angle is `a1 - a0`, which is `2π` for a full circle.
+    Arc(Point2, f64, f64, f64),
+    /// the polyline approximating a general curve
+    Polyline(Vec<Point2>),
+}
+
+/// Flattens the boundaries of planar faces, e.g. for the 2D nesting and cutting tools.
+pub trait PlanarBoundary {
+    /// Returns the boundaries of `self` as polylines in the `(u, v)` coordinates of the plane,
+    /// or `None` if the surface is not a plane or the boundaries cannot be projected to the plane.
+    ///
+    /// The coordinates are taken in the orthonormal basis of the plane: the origin is
+    /// [`Plane::origin`], the `u`-axis is the normalized [`Plane::u_axis`], and the `v`-axis is
+    /// the cross product of [`Plane::normal`] and the `u`-axis. Hence, the lengths and the angles
+    /// are preserved, even if the axes of the plane are not orthonormal. The curves of the edges
+    /// are divided with the chord tolerance `tol`. The first polyline is the outer loop, and the
+    /// others are the holes. The loops are oriented with respect to the plane, not to the face,
+    /// so the outer loop is counterclockwise and the holes are clockwise in the `(u, v)`
+    /// coordinates even if the face is inverted. The loops are closed implicitly: the first point
+    /// is not repeated at the end.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::new(1.0, 2.0, 3.0));
+    /// let e = builder::tsweep(&v, Vector3::new(2.0, 0.0, 0.0));
+    /// let face: Face = builder::tsweep(&e, Vector3::new(0.0, 1.0, 0.0));
+    ///
+    /// let loops = face.boundary_in_plane(0.01).unwrap();
+    /// assert_eq!(loops.len(), 1);
+    /// assert_eq!(loops[0].len(), 4);
+    /// let area = loops[0]
+    ///     .iter()
+    ///     .zip(loops[0].iter().cycle().skip(1))
+    ///     .map(|(p, q)| p.0 * q.1 - p.1 * q.0)
+    ///     .sum::<f64>()
+    ///     / 2.0;
+    /// assert_near!(area, 2.0);
+    /// ```
+    fn boundary_in_plane(&self, tol: f64) -> Option<Vec<PlanarLoop>>;
+}
+
+impl PlanarBoundary for Face {
+    fn boundary_in_plane(&self, tol: f64) -> Option<Vec<PlanarLoop>> {
+        let Surface::Plane(plane) = self.surface() else {
+            return None;
+        };
+        let origin = plane.origin();
+        let u_axis = plane.u_axis().normalize();
+        let v_axis = plane.normal().cross(u_axis);
+        let domain = face_domain(self, tol).ok()?;
+        let loops = domain
+            .points
+            .into_iter()
+            .map(|points| {
+                let coords = |p: Point3| ((p - origin).dot(u_axis), (p - origin).dot(v_axis));
+                points.into_iter().map(coords).collect()
+            })
+            .collect();
+        Some(loops)
+    }
+}
+
+/// Converts planar edges and wires into the entities of 2D drawings.
+pub trait DxfExport {
+    /// Returns the entities of `self` projected into the coordinates of `plane`.
+    ///
+    /// The coordinates are taken in the orthonormal basis of the plane, as in
+    /// [`PlanarBoundary::boundary_in_plane`]. The curves are divided with the chord tolerance
+    /// `tol`, and each edge is exported as a line if the points are on a line segment within
+    /// `tol`, as an arc if they are on a circle within `tol`, and otherwise as a polyline.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertices([(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 2.0, 0.0)]);
+    /// let wire: Wire = vec![
+    ///     builder::line(&v[0], &v[1]),
+    ///     builder::circle_arc(&v[1], &v[2], Point3::new(3.0, 1.0, 0.0)),
+    /// ]
+    /// .into();
+    /// let plane = Plane::xy();
+    ///
+    /// let entities = wire.to_dxf_entities(&plane, 1.0e-3);
+    /// assert_eq!(
+    ///     entities[0],
+    ///     DxfEntity::Line(Point2::new(0.0, 0.0), Point2::new(2.0, 0.0)),
+    /// );
+    /// // the arc turns counterclockwise, from the angle -π/2 to π/2 around (2, 1).
+    /// let DxfEntity::Arc(center, radius, a0, a1) = entities[1] else {
+    ///     unreachable!()
+    /// };
+    /// assert_near!(center, Point2::new(2.0, 1.0));
+    /// assert_near!(radius, 1.0);
+    /// assert_near!(a0, -std::f64::consts::PI / 2.0);
+    /// assert_near!(a1, std::f64::consts::PI / 2.0);
+    /// ```
+    fn to_dxf_entities(&self, plane: &Plane, tol: f64) -> Vec<DxfEntity>;
+}
+
+impl DxfExport for Edge {
+    fn to_dxf_entities(&self, plane: &Plane, tol: f64) -> Vec<DxfEntity> {
+        let origin = plane.origin();
+        let u_axis = plane.u_axis().normalize();
+        let v_axis = plane.normal().cross(u_axis);
+        let curve = self.oriented_curve();
+        let points: Vec<Point2> = curve
+            .sample_iter(curve.range_tuple(), tol)
+            .map(|(_, p)| Point2::new((p - origin).dot(u_axis), (p - origin).dot(v_axis)))
+            .collect();
+        vec![recognize(points, tol)]
+    }
+}
+
+impl DxfExport for Wire {
+    fn to_dxf_entities(&self, plane: &Plane, tol: f64) -> Vec<DxfEntity> {
+        self.edge_iter().flat_map(|edge| edge.to_dxf_entities(plane, tol)).collect()
+    }
+}
+
+/// Recognizes the polyline as a line segment or a circular arc within `tol`.
+fn recognize(points: Vec<Point2>, tol: f64) -> DxfEntity {
+    let (p0, p1) = (points[0], points[points.len() - 1]);
+    let on_segment = |p: &Point2| {
+        let dir = p1 - p0;
+        let t = f64::clamp((p - p0).dot(dir) / dir.magnitude2(), 0.0, 1.0);
+        p.distance(p0 + dir * t) < tol
+    };
+    if !p0.near(&p1) && points.iter().all(on_segment) {
+        return DxfEntity::Line(p0, p1);
+    }
+    let arc = circle_through(p0, points[points.len() / 2], points[points.len() * 3 / 4]);
+    let arc = arc.filter(|(center, radius)| {
+        let on_circle = |p: &Point2| f64::abs(p.distance(*center) - radius) < tol;
+        points.iter().all(on_circle)
+    });
+    let Some((center, radius)) = arc else {
+        return DxfEntity::Polyline(points);
+    };
+    let angle = |p: Point2| f64::atan2(p.y - center.y, p.x - center.x);
+    let sweep: f64 = points
+        .windows(2)
+        .map(|p| (p[0] - center).angle(p[1] - center).0)
+        .sum();
+    match sweep > 0.0 {
+        true => DxfEntity::Arc(center, radius, angle(p0), angle(p0) + sweep),
+        false => DxfEntity::Arc(center, radius, angle(p1), angle(p1) - sweep),
+    }
+}
+
+/// Returns the center and the radius of the circle through the three points.
+fn circle_through(a: Point2, b: Point2, c: Point2) -> Option<(Point2, f64)> {
+    let (ab, ac) = (b - a, c - a);
+    let det = 2.0 * ab.perp_dot(ac);
+    if det.so_small() {
+        return None;
+    }
+    let x = (ac.y * ab.magnitude2() - ab.y * ac.magnitude2()) / det;
+    let y = (ab.x * ac.magnitude2() - ac.x * ab.magnitude2()) / det;
+    let center = a + Vector2::new(x, y);
+    Some((center, center.distance(a)))
+}
@@ -0,0 +1,195 @@
+use crate::ray_casting::{face_domain, FaceDomain};
+use crate::*;
+use std::collections::HashMap;
+
+/// The gap between the two faces adjacent to an edge, at a sample on the edge.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EdgeGap {
+    /// the id of the edge
+    pub edge_id: EdgeID,
+    /// the parameter of the curve of the edge, i.e. [`Edge::curve`]
+    pub parameter: f64,
+    /// the sampled point on the curve
+    pub point: Point3,
+    /// the larger of the distances from the point to the two faces
+    pub deviation: f64,
+    /// the distance between the closest points on the two faces, i.e. the width of the crack
+    pub crack: f64,
+}
+
+impl EdgeGap {
+    /// Returns the gap, the larger of [`EdgeGap::deviation`] and [`EdgeGap::crack`].
+    #[inline(always)]
+    pub fn gap(&self) -> f64 { f64::max(self.deviation, self.crack) }
+}
+
+/// The gaps between the adjacent faces of a shell, by [`BoundaryGap::max_boundary_gap`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GapReport {
+    /// the sample with the largest gap, `None` if no edge is shared by two faces
+    pub max: Option<EdgeGap>,
+    /// the mean of the gaps of all samples, `0.0` if there are no samples
+    pub mean: f64,
+    /// the pairs of an upper bound and the number of the samples whose gaps are less than the
+    /// bound and not less than the previous bound. The bounds are the powers of ten from `1e-12`
+    /// to `1`, and the infinity.
+    pub histogram: Vec<(f64, usize)>,
+    /// the number of the samples
+    pub samples: usize,
+    /// the number of the closest points which are not found on the surfaces. They are taken on
+    /// the boundaries of the faces instead, or at the samples themselves if the boundaries cannot
+    /// be projected to the surfaces either.
+    pub fallbacks: usize,
+}
+
+/// Measures the watertightness of shells.
+pub trait BoundaryGap {
+    /// Returns the gaps between the adjacent faces along the edges shared by two faces.
+    ///
+    /// Each shared edge is sampled at the centers of the `samples_per_edge` equal divisions of
+    /// the parameter range. For each sample, the closest points on the two faces are searched
+    /// from the nearest point of the boundary of each face, so that they are taken on the
+    /// trimmed regions around the edge. The gap of the sample is the larger of the distances
+    /// from the sample to the faces and the distance between the two closest points.
+    ///
+    /// This is the quantitative companion of [`Shell::is_geometric_consistent`].
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    /// let shell = &cube.boundaries()[0];
+    ///
+    /// let report = shell.max_boundary_gap(4);
+    /// assert_eq!(report.samples, 12 * 4);
+    /// assert!(report.max.unwrap().gap() < 1.0e-10);
+    /// ```
+    fn max_boundary_gap(&self, samples_per_edge: usize) -> GapReport;
+}
+
+const SNP_TRIALS: usize = 100;
+const DOMAIN_TOLERANCE: f64 = 1.0e-3;
+
+impl BoundaryGap for Shell {
+    fn max_boundary_gap(&self, samples_per_edge: usize) -> GapReport {
+        let (gaps, fallbacks) = edge_gaps(self, samples_per_edge);
+        let mut histogram: Vec<(f64, usize)> = (-12..=0)
+            .map(|i| (f64::powi(10.0, i), 0))
+            .chain([(f64::INFINITY, 0)])
+            .collect();
+        gaps.iter().for_each(|gap| {
+            if let Some(bin) = histogram.iter_mut().find(|(bound, _)| gap.gap() < *bound) {
+                bin.1 += 1;
+            }
+        });
+        let max = gaps.iter().copied().reduce(|x, y| match y.gap() > x.gap() {
+            true => y,
+            false => x,
+        });
+        let mean = match gaps.is_empty() {
+            true => 0.0,
+            false => gaps.iter().map(EdgeGap::gap).sum::<f64>() / gaps.len() as f64,
+        };
+        GapReport {
+            max,
+            mean,
+            histogram,
+            samples: gaps.len(),
+            fallbacks,
+        }
+    }
+}
+
+/// Returns the gaps at all samples of the edges shared by two faces, in the order of the edges,
+/// and the number of the fallbacks, cf. [`BoundaryGap::max_boundary_gap`].
+pub(crate) fn edge_gaps(shell: &Shell, samples_per_edge: usize) -> (Vec<EdgeGap>, usize) {
+    let mut adjacency = HashMap::<EdgeID, Vec<usize>>::new();
+    let mut edges = Vec::new();
+    shell.face_iter().enumerate().for_each(|(i, face)| {
+        face.edge_iter().for_each(|edge| {
+            let faces = adjacency.entry(edge.id()).or_default();
+            if faces.is_empty() {
+                edges.push(edge);
+            }
+            faces.push(i);
+        })
+    });
+    let surfaces: Vec<Surface> = shell.face_iter().map(Face::surface).collect();
+    let domains: Vec<Option<FaceDomain>> = shell
+        .face_iter()
+        .map(|face| face_domain(face, DOMAIN_TOLERANCE).ok())
+        .collect();
+    let mut fallbacks = 0;
+    let mut foot = |i: usize, point: Point3| {
+        let domain = domains[i].as_ref();
+        foot_point(&surfaces[i], domain, point).unwrap_or_else(|| {
+            fallbacks += 1;
+            domain.map_or(point, |domain| boundary_foot(domain, point))
+        })
+    };
+
+    let mut gaps = Vec::new();
+    for edge in &edges {
+        let (i, j) = match adjacency[&edge.id()][..] {
+            [i, j] => (i, j),
+            _ => continue,
+        };
+        let curve = edge.curve();
+        let (t0, t1) = curve.range_tuple();
+        gaps.extend((0..samples_per_edge).map(|k| {
+            let parameter = t0 + (t1 - t0) * (k as f64 + 0.5) / samples_per_edge as f64;
+            let point = curve.subs(parameter);
+            let (p, q) = (foot(i, point), foot(j, point));
+            EdgeGap {
+                edge_id: edge.id(),
+                parameter,
+                point,
+                deviation: f64::max(point.distance(p), point.distance(q)),
+                crack: p.distance(q),
+            }
+        }));
+    }
+    (gaps, fallbacks)
+}
+
+/// Returns the closest point on the face to `point`, by Newton's method from the nearest point
+/// of the boundary, or `None` if the search fails.
+fn foot_point(surface: &Surface, domain: Option<&FaceDomain>, point: Point3) -> Option<Point3> {
+    let Some(domain) = domain else {
+        return surface
+            .search_nearest_parameter(point, None, SNP_TRIALS)
+            .map(|(u, v)| surface.subs(u, v));
+    };
+    let nearest = domain
+        .points
+        .iter()
+        .flatten()
+        .zip(domain.uvs.iter().flatten())
+        .min_by(|(p, _), (q, _)| p.distance2(point).total_cmp(&q.distance2(point)));
+    let hint = nearest.map(|(_, uv)| (uv.x, uv.y));
+    surface
+        .search_nearest_parameter(point, hint, SNP_TRIALS)
+        .or_else(|| surface.search_nearest_parameter(point, None, SNP_TRIALS))
+        .map(|(u, v)| surface.subs(u, v))
+}
+
+/// Returns the closest point on the polylines of the boundaries to `point`.
+fn boundary_foot(domain: &FaceDomain, point: Point3) -> Point3 {
+    let closed = domain
+        .points
+        .iter()
+        .flat_map(|pts| pts.iter().copied().zip(pts.iter().copied().cycle().skip(1)));
+    closed
+        .map(|(p, q)| {
+            let vec = q - p;
+            let t = match vec.magnitude2().so_small2() {
+                true => 0.0,
+                false => f64::clamp((point - p).dot(vec) / vec.magnitude2(), 0.0, 1.0),
+            };
+            p + vec * t
+        })
+        .min_by(|p, q| p.distance2(point).total_cmp(&q.distance2(point)))
+        .unwrap_or(point)
+}
@@ -47,7 +47,7 @@ fn closed_polyline_orientation<'a>(pts: impl IntoIterator<Item = &'a Vec<Point3>
         >= 0.0
 }
 
-fn take_one_axis_by_normal(n: Vector3) -> Vector3 {
+pub(super) fn take_one_axis_by_normal(n: Vector3) -> Vector3 {
     let a = n.map(f64::abs);
     if a.x > a.z || a.y > a.z {
         Vector3::new(-n.y, n.x, 0.0).normalize()
@@ -56,43 +56,54 @@ fn take_one_axis_by_normal(n: Vector3) -> Vector3 {
     }
 }
 
-pub(super) fn attach_plane(mut pts: Vec<Vec<Point3>>) -> Option<Plane> {
+/// Fits a plane to the closed polylines, and returns it with the maximum distance of the points.
+///
+/// The points are shifted by the centroid before summing the normal by Newell's method, so that
+/// the digits of far coordinates do not cancel out. Returns `None` if the polylines span no area
+/// relative to their size. The plane passes the centroid, covers the points by the parameter
+/// range, and is oriented along the polylines.
+pub(super) fn fit_plane(pts: &[Vec<Point3>]) -> Option<(Plane, f64)> {
+    let count = pts.iter().map(Vec::len).sum::<usize>();
+    if count == 0 {
+        return None;
+    }
     let center = pts
         .iter()
         .flatten()
         .fold(Point3::origin(), |sum, pt| sum + pt.to_vec())
-        / pts.len() as f64;
+        / count as f64;
     let normal = pts
         .iter()
         .flat_map(|vec| vec.iter().circular_tuple_windows())
         .fold(Vector3::zero(), |sum, (p0, p1)| {
             sum + (p0 - center).cross(p1 - center)
         });
-    let n = match normal.so_small() {
+    let size2 = pts.iter().flatten().map(|pt| pt.distance2(center)).fold(0.0, f64::max);
+    let n = match normal.magnitude() <= TOLERANCE * size2 {
         true => return None,
         false => normal.normalize(),
     };
     let a = take_one_axis_by_normal(n);
-    let mat: Matrix4 = Matrix3::from_cols(a, n.cross(a), n).into();
-    pts.iter_mut()
-        .flatten()
-        .for_each(|pt| *pt = mat.invert().unwrap().transform_point(*pt));
-    let bnd_box: BoundingBox<Point3> = pts.iter().flatten().collect();
-    let diag = bnd_box.diagonal();
-    if !diag[2].so_small() {
-        return None;
-    }
-    let (max, min) = match closed_polyline_orientation(&pts) {
+    let mat = Matrix4::from_translation(center.to_vec())
+        * Matrix4::from(Matrix3::from_cols(a, n.cross(a), n));
+    let inv = mat.invert()?;
+    let local: Vec<Vec<Point3>> = pts
+        .iter()
+        .map(|vec| vec.iter().map(|pt| inv.transform_point(*pt)).collect())
+        .collect();
+    let bnd_box: BoundingBox<Point3> = local.iter().flatten().collect();
+    let deviation = local.iter().flatten().map(|pt| pt.z.abs()).fold(0.0, f64::max);
+    let (max, min) = match closed_polyline_orientation(&local) {
         true => (bnd_box.max(), bnd_box.min()),
         false => (bnd_box.min(), bnd_box.max()),
     };
     let plane = Plane::new(
-        Point3::new(min[0], min[1], min[2]),
-        Point3::new(max[0], min[1], min[2]),
-        Point3::new(min[0], max[1], min[2]),
+        Point3::new(min[0], min[1], 0.0),
+        Point3::new(max[0], min[1], 0.0),
+        Point3::new(min[0], max[1], 0.0),
     )
     .transformed(mat);
-    Some(plane)
+    Some((plane, deviation))
 }
 
 #[cfg(test)]
@@ -248,7 +259,7 @@ mod test_geom_impl {
         }
 
         #[test]
-        fn test_attach_plane_with_single_boundary(
+        fn test_fit_plane_with_single_boundary(
             axis_pole in array::uniform2(-1.0f64..1.0),
             origin in array::uniform3(-10.0f64..10.0),
             angles in array::uniform10(0.01f64..(2.0 * PI - 0.01)),
@@ -266,12 +277,13 @@ mod test_geom_impl {
                 .into_iter()
                 .map(|p| trsf.transform_point(p))
                 .collect();
-            let plane = attach_plane(vec![boundary]).unwrap();
+            let (plane, deviation) = fit_plane(&[boundary]).unwrap();
+            prop_assert!(deviation.so_small());
             prop_assert_near!(plane.normal(), axis);
         }
 
         #[test]
-        fn test_attach_plane_with_multiple_boundary(
+        fn test_fit_plane_with_multiple_boundary(
             axis_pole in array::uniform2(-1.0f64..1.0),
             origin in array::uniform3(-10.0f64..10.0),
             points in array::uniform8(1.0f64..9.0),
@@ -297,7 +309,8 @@ mod test_geom_impl {
                 .iter_mut()
                 .flatten()
                 .for_each(|p| *p = trsf.transform_point(*p));
-            let plane = attach_plane(multiple_boundary).unwrap();
+            let (plane, deviation) = fit_plane(&multiple_boundary).unwrap();
+            prop_assert!(deviation.so_small());
             prop_assert_near!(plane.normal(), axis);
         }
     }
@@ -341,7 +354,9 @@ where
     ExtrudedCurve<C, Vector3>: ToSameGeometry<S>,
 {
     fn connector(self) -> impl Fn(&C, &C) -> S {
-        move |curve0, _| ExtrudedCurve::by_extrusion(curve0.clone(), self.vector).to_same_geometry()
+        move |curve0, _| {
+            ExtrudedCurve::by_extrusion_scaled(curve0.clone(), self.vector).to_same_geometry()
+        }
     }
 }
 
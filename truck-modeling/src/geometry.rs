@@ -14,7 +14,6 @@ pub use truck_polymesh::PolylineCurve;
     Deserialize,
     From,
     TryInto,
-    ParametricCurve,
     BoundedCurve,
     ParameterDivision1D,
     Cut,
@@ -31,6 +30,10 @@ pub enum Curve {
     NurbsCurve(NurbsCurve<Vector4>),
     /// intersection curve
     IntersectionCurve(IntersectionCurve<Box<Curve>, Box<Surface>, Box<Surface>>),
+    /// curve restricted to a parameter sub-interval, keeping the definition of the entire curve
+    TrimmedCurve(TrimmedCurve<Box<Curve>>),
+    /// curve whose parameter is changed by a strictly increasing map
+    ReparametrizedCurve(ReparametrizedCurve<Box<Curve>>),
 }
 
 macro_rules! derive_curve_method {
@@ -40,6 +43,8 @@ macro_rules! derive_curve_method {
             Curve::BSplineCurve(got) => $method(got, $($ver), *),
             Curve::NurbsCurve(got) => $method(got, $($ver), *),
             Curve::IntersectionCurve(got) => $method(got, $($ver), *),
+            Curve::TrimmedCurve(got) => $method(got, $($ver), *),
+            Curve::ReparametrizedCurve(got) => $method(got, $($ver), *),
         }
     };
 }
@@ -51,10 +56,51 @@ macro_rules! derive_curve_self_method {
             Curve::BSplineCurve(got) => Curve::BSplineCurve($method(got, $($ver), *)),
             Curve::NurbsCurve(got) => Curve::NurbsCurve($method(got, $($ver), *)),
             Curve::IntersectionCurve(got) => Curve::IntersectionCurve($method(got, $($ver), *)),
+            Curve::TrimmedCurve(got) => Curve::TrimmedCurve($method(got, $($ver), *)),
+            Curve::ReparametrizedCurve(got) => {
+                Curve::ReparametrizedCurve($method(got, $($ver), *))
+            }
         }
     };
 }
 
+/// The parameters are asserted to be in the domain if [`strict_domain_checks`] is on.
+impl ParametricCurve for Curve {
+    type Point = Point3;
+    type Vector = Vector3;
+    #[inline(always)]
+    fn subs(&self, t: f64) -> Point3 {
+        debug_assert_curve_domain(self, t);
+        derive_curve_method!(self, ParametricCurve::subs, t)
+    }
+    #[inline(always)]
+    fn der(&self, t: f64) -> Vector3 {
+        debug_assert_curve_domain(self, t);
+        derive_curve_method!(self, ParametricCurve::der, t)
+    }
+    #[inline(always)]
+    fn der2(&self, t: f64) -> Vector3 {
+        debug_assert_curve_domain(self, t);
+        derive_curve_method!(self, ParametricCurve::der2, t)
+    }
+    #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Vector3 {
+        debug_assert_curve_domain(self, t);
+        derive_curve_method!(self, ParametricCurve::der_n, n, t)
+    }
+    #[inline(always)]
+    fn ders(&self, n: usize, t: f64) -> CurveDers<Vector3> {
+        debug_assert_curve_domain(self, t);
+        derive_curve_method!(self, ParametricCurve::ders, n, t)
+    }
+    #[inline(always)]
+    fn parameter_range(&self) -> ParameterRange {
+        derive_curve_method!(self, ParametricCurve::parameter_range,)
+    }
+    #[inline(always)]
+    fn period(&self) -> Option<f64> { derive_curve_method!(self, ParametricCurve::period,) }
+}
+
 impl Transformed<Matrix4> for Curve {
     fn transform_by(&mut self, trans: Matrix4) {
         derive_curve_method!(self, Transformed::transform_by, trans);
@@ -107,10 +153,77 @@ impl Curve {
             Curve::IntersectionCurve(_) => {
                 unimplemented!("intersection curve cannot connect by homotopy")
             }
+            Curve::TrimmedCurve(curve) => curve.curve().lift_up().subcurve(curve.range()),
+            // The same geometry, but the parameter of the original curve is restored.
+            Curve::ReparametrizedCurve(curve) => {
+                curve.curve().lift_up().subcurve(curve.map().image())
+            }
+        }
+    }
+
+    /// Returns the restriction of the curve to `range`, keeping the parameter.
+    ///
+    /// B-spline and NURBS curves are cut exactly at the knots inserted at the ends, and trimmed
+    /// curves are trimmed again. Since the parameter range of lines is fixed to `(0, 1)`, lines,
+    /// intersection curves and reparametrized curves are wrapped by [`TrimmedCurve`]. If `range` is decreasing,
+    /// returns the restriction of `self.inverse()`, i.e. the result is evaluated as the inverse
+    /// by the same parameters.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let line = Curve::Line(Line(Point3::origin(), Point3::new(4.0, 0.0, 0.0)));
+    /// let sub = line.subcurve((0.25, 0.5));
+    /// assert_eq!(sub.range_tuple(), (0.25, 0.5));
+    /// assert_near!(sub.subs(0.3), line.subs(0.3));
+    ///
+    /// let sub = line.subcurve((0.5, 0.25));
+    /// assert_eq!(sub.range_tuple(), (0.5, 0.75));
+    /// assert_near!(sub.front(), Point3::new(2.0, 0.0, 0.0));
+    /// assert_near!(sub.back(), Point3::new(1.0, 0.0, 0.0));
+    /// ```
+    /// # Panics
+    /// Panics if an end of `range` is out of the parameter range more than `TOLERANCE`, or if
+    /// `range` is degenerate.
+    pub fn subcurve(&self, range: (f64, f64)) -> Curve {
+        match self {
+            Curve::BSplineCurve(curve) => Curve::BSplineCurve(curve.subcurve(range)),
+            Curve::NurbsCurve(curve) => Curve::NurbsCurve(curve.subcurve(range)),
+            Curve::TrimmedCurve(curve) => Curve::TrimmedCurve(curve.subcurve(range)),
+            Curve::Line(_) | Curve::IntersectionCurve(_) | Curve::ReparametrizedCurve(_) => {
+                let (t0, t1) = self.range_tuple();
+                let trimmed = TrimmedCurve::new(Box::new(self.clone()), (t0, t1));
+                Curve::TrimmedCurve(trimmed.subcurve(range))
+            }
         }
     }
 }
 
+/// 2-dimensional curve, e.g. the projections of curves into planes.
+/// cf. [`ProjectToPlane`](../projection/trait.ProjectToPlane.html)
+#[derive(
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    From,
+    TryInto,
+    ParametricCurve,
+    BoundedCurve,
+    ParameterDivision1D,
+    Cut,
+    Invertible,
+    SearchNearestParameterD1,
+    SearchParameterD1,
+)]
+pub enum Curve2D {
+    /// line
+    Line(Line<Point2>),
+    /// 2-dimensional B-spline curve
+    BSplineCurve(BSplineCurve<Point2>),
+    /// 2-dimensional NURBS curve
+    NurbsCurve(NurbsCurve<Vector3>),
+}
+
 /// 3-dimensional surfaces
 #[derive(
     Clone,
@@ -119,7 +232,6 @@ impl Curve {
     Deserialize,
     From,
     TryInto,
-    ParametricSurface,
     ParameterDivision2D,
     Invertible,
     SearchParameterD2,
@@ -157,6 +269,101 @@ macro_rules! derive_surface_self_method {
     };
 }
 
+/// The variants of [`Surface`] without the geometry, e.g. for reporting errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SurfaceKind {
+    /// Plane
+    Plane,
+    /// 3-dimensional B-spline surface
+    BSplineSurface,
+    /// 3-dimensional NURBS Surface
+    NurbsSurface,
+    /// revoluted curve
+    RevolutedCurve,
+}
+
+impl Surface {
+    /// Returns the variant of the surface.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let surface: Surface = Plane::xy().into();
+    /// assert_eq!(surface.kind(), SurfaceKind::Plane);
+    /// ```
+    #[inline(always)]
+    pub const fn kind(&self) -> SurfaceKind {
+        match self {
+            Surface::Plane(_) => SurfaceKind::Plane,
+            Surface::BSplineSurface(_) => SurfaceKind::BSplineSurface,
+            Surface::NurbsSurface(_) => SurfaceKind::NurbsSurface,
+            Surface::RevolutedCurve(_) => SurfaceKind::RevolutedCurve,
+        }
+    }
+
+    /// Asserts that `(u, v)` is in the domain. The planes are regarded as unbounded, although
+    /// their parameter ranges are the unit square.
+    #[inline(always)]
+    fn debug_assert_domain(&self, u: f64, v: f64) {
+        if !matches!(self, Surface::Plane(_)) {
+            debug_assert_surface_domain(self, u, v);
+        }
+    }
+}
+
+/// The parameters are asserted to be in the domain if [`strict_domain_checks`] is on.
+impl ParametricSurface for Surface {
+    type Point = Point3;
+    type Vector = Vector3;
+    #[inline(always)]
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        self.debug_assert_domain(u, v);
+        derive_surface_method!(self, ParametricSurface::subs, u, v)
+    }
+    #[inline(always)]
+    fn uder(&self, u: f64, v: f64) -> Vector3 {
+        self.debug_assert_domain(u, v);
+        derive_surface_method!(self, ParametricSurface::uder, u, v)
+    }
+    #[inline(always)]
+    fn vder(&self, u: f64, v: f64) -> Vector3 {
+        self.debug_assert_domain(u, v);
+        derive_surface_method!(self, ParametricSurface::vder, u, v)
+    }
+    #[inline(always)]
+    fn uuder(&self, u: f64, v: f64) -> Vector3 {
+        self.debug_assert_domain(u, v);
+        derive_surface_method!(self, ParametricSurface::uuder, u, v)
+    }
+    #[inline(always)]
+    fn uvder(&self, u: f64, v: f64) -> Vector3 {
+        self.debug_assert_domain(u, v);
+        derive_surface_method!(self, ParametricSurface::uvder, u, v)
+    }
+    #[inline(always)]
+    fn vvder(&self, u: f64, v: f64) -> Vector3 {
+        self.debug_assert_domain(u, v);
+        derive_surface_method!(self, ParametricSurface::vvder, u, v)
+    }
+    #[inline(always)]
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Vector3 {
+        self.debug_assert_domain(u, v);
+        derive_surface_method!(self, ParametricSurface::der_mn, m, n, u, v)
+    }
+    #[inline(always)]
+    fn ders(&self, max_order: usize, u: f64, v: f64) -> SurfaceDers<Vector3> {
+        self.debug_assert_domain(u, v);
+        derive_surface_method!(self, ParametricSurface::ders, max_order, u, v)
+    }
+    #[inline(always)]
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
+        derive_surface_method!(self, ParametricSurface::parameter_range,)
+    }
+    #[inline(always)]
+    fn u_period(&self) -> Option<f64> { derive_surface_method!(self, ParametricSurface::u_period,) }
+    #[inline(always)]
+    fn v_period(&self) -> Option<f64> { derive_surface_method!(self, ParametricSurface::v_period,) }
+}
+
 impl ParametricSurface3D for Surface {
     #[inline(always)]
     fn normal(&self, u: f64, v: f64) -> Vector3 {
@@ -164,6 +371,15 @@ impl ParametricSurface3D for Surface {
     }
 }
 
+/// All the variants swap the parameters `u` and `v`: the planes swap their axes, the splines
+/// swap their control points, and the revoluted curves flip the orientations of the processors.
+impl InvertibleSurface for Surface {
+    #[inline(always)]
+    fn inverse_map(&self) -> ParamMap2D {
+        derive_surface_method!(self, InvertibleSurface::inverse_map,)
+    }
+}
+
 impl Transformed<Matrix4> for Surface {
     fn transform_by(&mut self, trans: Matrix4) {
         derive_surface_method!(self, Transformed::transform_by, trans);
@@ -182,22 +398,36 @@ impl IncludeCurve<Curve> for Surface {
                 Curve::BSplineCurve(curve) => surface.include(curve),
                 Curve::NurbsCurve(curve) => surface.include(curve),
                 Curve::IntersectionCurve(_) => unimplemented!(),
+                Curve::TrimmedCurve(_) | Curve::ReparametrizedCurve(_) => {
+                    surface.include(&trimmed_as_nurbs(curve))
+                }
             },
             Surface::NurbsSurface(surface) => match curve {
                 &Curve::Line(curve) => surface.include(&BSplineCurve::from(curve)),
                 Curve::BSplineCurve(curve) => surface.include(curve),
                 Curve::NurbsCurve(curve) => surface.include(curve),
                 Curve::IntersectionCurve(_) => unimplemented!(),
+                Curve::TrimmedCurve(_) | Curve::ReparametrizedCurve(_) => {
+                    surface.include(&trimmed_as_nurbs(curve))
+                }
             },
             Surface::Plane(surface) => match curve {
                 &Curve::Line(curve) => surface.include(&BSplineCurve::from(curve)),
                 Curve::BSplineCurve(curve) => surface.include(curve),
                 Curve::NurbsCurve(curve) => surface.include(curve),
                 Curve::IntersectionCurve(_) => unimplemented!(),
+                Curve::TrimmedCurve(_) | Curve::ReparametrizedCurve(_) => {
+                    surface.include(&trimmed_as_nurbs(curve))
+                }
             },
             Surface::RevolutedCurve(surface) => match surface.entity_curve() {
-                &Curve::Line(curve) => {
-                    self.include(&Curve::BSplineCurve(BSplineCurve::from(curve)))
+                &Curve::Line(entity_curve) => {
+                    let surface = RevolutedCurve::by_revolution(
+                        Curve::BSplineCurve(BSplineCurve::from(entity_curve)),
+                        surface.origin(),
+                        surface.axis(),
+                    );
+                    Surface::RevolutedCurve(Processor::new(surface)).include(curve)
                 }
                 Curve::BSplineCurve(entity_curve) => {
                     let surface = RevolutedCurve::by_revolution(
@@ -210,6 +440,9 @@ impl IncludeCurve<Curve> for Surface {
                         Curve::BSplineCurve(curve) => surface.include(curve),
                         Curve::NurbsCurve(curve) => surface.include(curve),
                         Curve::IntersectionCurve(_) => unimplemented!(),
+                        Curve::TrimmedCurve(_) | Curve::ReparametrizedCurve(_) => {
+                    surface.include(&trimmed_as_nurbs(curve))
+                }
                     }
                 }
                 Curve::NurbsCurve(entity_curve) => {
@@ -223,14 +456,29 @@ impl IncludeCurve<Curve> for Surface {
                         Curve::BSplineCurve(curve) => surface.include(curve),
                         Curve::NurbsCurve(curve) => surface.include(curve),
                         Curve::IntersectionCurve(_) => unimplemented!(),
+                        Curve::TrimmedCurve(_) | Curve::ReparametrizedCurve(_) => {
+                    surface.include(&trimmed_as_nurbs(curve))
+                }
                     }
                 }
                 Curve::IntersectionCurve(_) => unimplemented!(),
+                Curve::TrimmedCurve(_) | Curve::ReparametrizedCurve(_) => {
+                    let entity_curve = trimmed_as_nurbs(surface.entity_curve());
+                    let surface = RevolutedCurve::by_revolution(
+                        Curve::NurbsCurve(entity_curve),
+                        surface.origin(),
+                        surface.axis(),
+                    );
+                    Surface::RevolutedCurve(Processor::new(surface)).include(curve)
+                }
             },
         }
     }
 }
 
+/// Converts the trimmed or reparametrized curve into the NURBS curve with the same geometry.
+fn trimmed_as_nurbs(curve: &Curve) -> NurbsCurve<Vector4> { NurbsCurve::new(curve.lift_up()) }
+
 impl IncludeCurve<Curve> for Plane {
     fn include(&self, curve: &Curve) -> bool {
         curve.lift_up().control_points().iter().all(|v| {
@@ -274,6 +522,27 @@ impl SearchNearestParameter<D2> for Surface {
             }
         }
     }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<(f64, f64)> {
+        match self {
+            Surface::Plane(plane) => plane.search_nearest_parameter(point, hint, trials),
+            Surface::BSplineSurface(bspsurface) => {
+                bspsurface.search_nearest_parameter_with_presearch(point, hint, trials, division)
+            }
+            Surface::NurbsSurface(surface) => {
+                surface.search_nearest_parameter_with_presearch(point, hint, trials, division)
+            }
+            Surface::RevolutedCurve(rotted) => {
+                let hint = algo::surface::presearch_hint(rotted, point, hint.into(), division);
+                algo::surface::search_nearest_parameter(rotted, point, hint, trials)
+            }
+        }
+    }
 }
 
 impl ToSameGeometry<Surface> for HomotopySurface<Curve, Curve> {
@@ -289,22 +558,38 @@ impl ToSameGeometry<Surface> for ExtrudedCurve<Curve, Vector3> {
         let (curve0, vector) = (self.entity_curve(), self.extruding_vector());
         let trsl = Matrix4::from_translation(vector);
         let curve1 = self.entity_curve().transformed(trsl);
+        // the homotopies are parametrized by `v` in `[0, 1]`
+        let length = self.length().unwrap_or(1.0);
         match (curve0, curve1) {
             (Curve::Line(line), Curve::Line(_)) => {
-                Plane::new(line.0, line.1, line.0 + vector).into()
+                Plane::new(line.0, line.1, line.0 + vector / length).into()
             }
             (Curve::BSplineCurve(curve0), Curve::BSplineCurve(curve1)) => {
-                BSplineSurface::homotopy(curve0.clone(), curve1.clone()).into()
+                let surface = BSplineSurface::homotopy(curve0.clone(), curve1.clone());
+                scale_vknots(surface, length).into()
             }
             (Curve::NurbsCurve(curve0), Curve::NurbsCurve(curve1)) => {
-                NurbsSurface::new(BSplineSurface::homotopy(
+                let surface = BSplineSurface::homotopy(
                     curve0.non_rationalized().clone(),
                     curve1.non_rationalized().clone(),
-                ))
-                .into()
+                );
+                NurbsSurface::new(scale_vknots(surface, length)).into()
             }
             (Curve::IntersectionCurve(_), Curve::IntersectionCurve(_)) => unimplemented!(),
+            (Curve::TrimmedCurve(_), curve1 @ Curve::TrimmedCurve(_))
+            | (Curve::ReparametrizedCurve(_), curve1 @ Curve::ReparametrizedCurve(_)) => {
+                let (curve0, curve1) = (curve0.lift_up(), curve1.lift_up());
+                let surface = BSplineSurface::homotopy(curve0, curve1);
+                NurbsSurface::new(scale_vknots(surface, length)).into()
+            }
             _ => unreachable!(),
         }
     }
 }
+
+/// Scales the knot vector of `v` from `[0, 1]` to `[0, length]`.
+fn scale_vknots<P: Clone>(surface: BSplineSurface<P>, length: f64) -> BSplineSurface<P> {
+    let (uknots, mut vknots) = surface.knot_vecs().clone();
+    vknots.transform(length, 0.0);
+    BSplineSurface::new_unchecked((uknots, vknots), surface.control_points().clone())
+}
@@ -0,0 +1,186 @@
+use crate::*;
+use rustc_hash::FxHasher;
+use std::hash::Hasher;
+
+impl Curve {
+    /// Returns the hash of the summary of the representation quantized by `tol`: the variant,
+    /// the degree, the knots, the control points and the normalized weights.
+    ///
+    /// The curves equal within `tol` as representations are mapped to the same key in most
+    /// cases, so the key can be used for deduplicating caches. However, this is a heuristic: two
+    /// near values may be rounded to different integers, and different curves may collide. So,
+    /// confirm the equality of the curves with the same key, e.g. by
+    /// [`BSplineCurve::near_as_curve`] of the B-spline variants.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let curve0 = Curve::Line(Line(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)));
+    /// let curve1 = Curve::Line(Line(Point3::new(1.0e-9, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)));
+    /// let curve2 = Curve::Line(Line(Point3::new(0.0, 1.0, 0.0), Point3::new(1.0, 0.0, 0.0)));
+    /// assert_eq!(curve0.geometry_hash(TOLERANCE), curve1.geometry_hash(TOLERANCE));
+    /// assert_ne!(curve0.geometry_hash(TOLERANCE), curve2.geometry_hash(TOLERANCE));
+    /// ```
+    pub fn geometry_hash(&self, tol: f64) -> u64 {
+        let mut hasher = QuantizedHasher::new(tol);
+        hasher.curve(self);
+        hasher.finish()
+    }
+}
+
+impl Surface {
+    /// Returns the hash of the summary of the representation quantized by `tol`: the variant,
+    /// the degrees, the knots, the control points and the normalized weights.
+    ///
+    /// The surfaces equal within `tol` as representations are mapped to the same key in most
+    /// cases, so the key can be used for deduplicating the surfaces of faces before tessellation.
+    /// However, this is a heuristic: two near values may be rounded to different integers, and
+    /// different surfaces may collide. So, confirm the equality of the surfaces with the same
+    /// key, e.g. by [`BSplineSurface::near_as_surface`] of the B-spline variants.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let face0: Face = builder::tsweep(&e, Vector3::unit_y());
+    /// let face1 = builder::translated(&face0, Vector3::new(0.0, 0.0, 1.0e-9));
+    /// let face2 = builder::translated(&face0, Vector3::unit_z());
+    ///
+    /// let hash = |face: &Face| face.surface().geometry_hash(TOLERANCE);
+    /// assert_eq!(hash(&face0), hash(&face1));
+    /// assert_ne!(hash(&face0), hash(&face2));
+    /// ```
+    pub fn geometry_hash(&self, tol: f64) -> u64 {
+        let mut hasher = QuantizedHasher::new(tol);
+        hasher.surface(self);
+        hasher.finish()
+    }
+}
+
+/// hasher of the values quantized by the tolerance
+struct QuantizedHasher {
+    hasher: FxHasher,
+    tol: f64,
+}
+
+impl QuantizedHasher {
+    fn new(tol: f64) -> Self {
+        Self {
+            hasher: FxHasher::default(),
+            tol,
+        }
+    }
+    fn finish(&self) -> u64 { self.hasher.finish() }
+
+    fn tag(&mut self, tag: u8) { self.hasher.write_u8(tag) }
+    fn size(&mut self, size: usize) { self.hasher.write_usize(size) }
+    fn floats(&mut self, floats: impl IntoIterator<Item = f64>) {
+        floats
+            .into_iter()
+            .for_each(|x| self.hasher.write_i64(f64::round(x / self.tol) as i64))
+    }
+    fn point(&mut self, p: Point3) { self.floats([p.x, p.y, p.z]) }
+    fn vector(&mut self, v: Vector3) { self.floats([v.x, v.y, v.z]) }
+    fn knot_vec(&mut self, knot_vec: &KnotVec) {
+        self.size(knot_vec.len());
+        self.floats(knot_vec.iter().copied());
+    }
+    fn points<'a>(&mut self, points: impl IntoIterator<Item = &'a Point3>) {
+        points.into_iter().for_each(|p| self.point(*p));
+    }
+    /// The weights are normalized by the first one, since scaling all of them does not change the
+    /// shape.
+    fn weighted_points<'a>(&mut self, points: impl IntoIterator<Item = &'a Vector4>) {
+        let mut first = None;
+        points.into_iter().for_each(|v| {
+            let weight0 = *first.get_or_insert(v.w);
+            self.point(v.to_point());
+            self.floats([v.w / weight0]);
+        });
+    }
+
+    fn curve(&mut self, curve: &Curve) {
+        match curve {
+            Curve::Line(line) => {
+                self.tag(0);
+                self.point(line.0);
+                self.point(line.1);
+            }
+            Curve::BSplineCurve(curve) => {
+                self.tag(1);
+                self.size(curve.degree());
+                self.knot_vec(curve.knot_vec());
+                self.size(curve.control_points().len());
+                self.points(curve.control_points());
+            }
+            Curve::NurbsCurve(curve) => {
+                self.tag(2);
+                self.size(curve.degree());
+                self.knot_vec(curve.knot_vec());
+                self.size(curve.control_points().len());
+                self.weighted_points(curve.control_points());
+            }
+            Curve::IntersectionCurve(curve) => {
+                self.tag(3);
+                self.surface(curve.surface0());
+                self.surface(curve.surface1());
+                self.curve(curve.leader());
+            }
+            Curve::TrimmedCurve(curve) => {
+                self.tag(4);
+                let (t0, t1) = curve.range();
+                self.floats([t0, t1]);
+                self.curve(curve.curve());
+            }
+            Curve::ReparametrizedCurve(curve) => {
+                self.tag(5);
+                let map = curve.map();
+                self.size(map.params().len());
+                self.floats(map.params().iter().copied());
+                self.floats(map.values().iter().copied());
+                self.floats(map.ders().iter().copied());
+                self.curve(curve.curve());
+            }
+        }
+    }
+
+    fn surface(&mut self, surface: &Surface) {
+        match surface {
+            Surface::Plane(plane) => {
+                self.tag(0);
+                self.point(plane.origin());
+                self.vector(plane.u_axis());
+                self.vector(plane.v_axis());
+            }
+            Surface::BSplineSurface(surface) => {
+                self.tag(1);
+                let (udegree, vdegree) = surface.degrees();
+                self.size(udegree);
+                self.size(vdegree);
+                self.knot_vec(&surface.knot_vecs().0);
+                self.knot_vec(&surface.knot_vecs().1);
+                self.size(surface.control_points().len());
+                self.points(surface.control_points().iter().flatten());
+            }
+            Surface::NurbsSurface(surface) => {
+                self.tag(2);
+                let (udegree, vdegree) = surface.degrees();
+                self.size(udegree);
+                self.size(vdegree);
+                self.knot_vec(&surface.knot_vecs().0);
+                self.knot_vec(&surface.knot_vecs().1);
+                self.size(surface.control_points().len());
+                self.weighted_points(surface.control_points().iter().flatten());
+            }
+            Surface::RevolutedCurve(surface) => {
+                self.tag(3);
+                let revolution = surface.entity();
+                self.curve(revolution.entity_curve());
+                self.point(revolution.origin());
+                self.vector(revolution.axis());
+                let matrix: [[f64; 4]; 4] = (*surface.transform()).into();
+                self.floats(matrix.into_iter().flatten());
+                self.tag(u8::from(surface.orientation()));
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{errors::Error, *};
+use algo::curve::{presearch_closest_point, search_closest_parameter};
+
+/// the maximum degree of the interpolations across the curves
+const MAX_INTERPOLATION_DEGREE: usize = 3;
+const PRESEARCH_DIVISION: usize = 50;
+const SEARCH_PARAMETER_TRIALS: usize = 100;
+
+/// Builds the Gordon surface through the network, cf. [`builder::gordon_surface`].
+pub(super) fn gordon_surface(
+    u_curves: &[BSplineCurve<Point3>],
+    v_curves: &[BSplineCurve<Point3>],
+    tol: f64,
+) -> Result<BSplineSurface<Point3>> {
+    if u_curves.len() < 2 || v_curves.len() < 2 {
+        return Err(Error::InvalidCurveNetwork);
+    }
+    let u_curves = compatible_curves(u_curves);
+    let v_curves = compatible_curves(v_curves);
+    let (uparams, vparams) = intersection_parameters(&u_curves, &v_curves)?;
+
+    // the intersection points, the `j`-th point of the `i`-th row is on the `i`-th u-curve
+    let mut gap = 0.0_f64;
+    let points: Vec<Vec<Point3>> = u_curves
+        .iter()
+        .zip(&vparams)
+        .map(|(u_curve, v)| {
+            v_curves
+                .iter()
+                .zip(&uparams)
+                .map(|(v_curve, u)| {
+                    let (pt0, pt1) = (u_curve.subs(*u), v_curve.subs(*v));
+                    gap = gap.max(pt0.distance(pt1));
+                    pt0.midpoint(pt1)
+                })
+                .collect()
+        })
+        .collect();
+    if gap > tol {
+        return Err(Error::InconsistentCurveNetwork { gap });
+    }
+
+    let uknot_vec = interpolation_knot_vec(&uparams);
+    let vknot_vec = interpolation_knot_vec(&vparams);
+
+    // the skinning surface of the u-curves
+    let len = u_curves[0].control_points().len();
+    let control_points = (0..len)
+        .map(|k| interpolate(&vknot_vec, &vparams, u_curves.iter().map(|c| *c.control_point(k))))
+        .collect::<Result<Vec<_>>>()?;
+    let knot_vecs = (u_curves[0].knot_vec().clone(), vknot_vec.clone());
+    let u_skin = BSplineSurface::try_new(knot_vecs, control_points)?;
+
+    // the skinning surface of the v-curves
+    let len = v_curves[0].control_points().len();
+    let columns = (0..len)
+        .map(|l| interpolate(&uknot_vec, &uparams, v_curves.iter().map(|c| *c.control_point(l))))
+        .collect::<Result<Vec<_>>>()?;
+    let control_points = (0..columns[0].len())
+        .map(|k| columns.iter().map(|column| column[k]).collect())
+        .collect();
+    let knot_vecs = (uknot_vec.clone(), v_curves[0].knot_vec().clone());
+    let v_skin = BSplineSurface::try_new(knot_vecs, control_points)?;
+
+    // the tensor product surface of the intersection points
+    let rows = points
+        .iter()
+        .map(|row| interpolate(&uknot_vec, &uparams, row.iter().copied()))
+        .collect::<Result<Vec<_>>>()?;
+    let control_points = (0..rows[0].len())
+        .map(|k| interpolate(&vknot_vec, &vparams, rows.iter().map(|row| row[k])))
+        .collect::<Result<Vec<_>>>()?;
+    let tensor = BSplineSurface::try_new((uknot_vec, vknot_vec), control_points)?;
+
+    let mut surfaces = [u_skin, v_skin, tensor];
+    compatible_surfaces(&mut surfaces);
+    let [u_skin, v_skin, tensor] = surfaces;
+    let control_points = u_skin
+        .control_points()
+        .iter()
+        .zip(v_skin.control_points())
+        .zip(tensor.control_points())
+        .map(|((row0, row1), row2)| {
+            let iter = row0.iter().zip(row1).zip(row2);
+            iter.map(|((p0, p1), p2)| *p0 + (*p1 - *p2)).collect()
+        })
+        .collect();
+    Ok(BSplineSurface::new(u_skin.knot_vecs().clone(), control_points))
+}
+
+/// Returns the parameters of the v-curves on the u-curves and the ones of the u-curves on the
+/// v-curves, averaged through the network. The parameters of the first and the last curves are
+/// the ends of the range.
+fn intersection_parameters(
+    u_curves: &[BSplineCurve<Point3>],
+    v_curves: &[BSplineCurve<Point3>],
+) -> Result<(Vec<f64>, Vec<f64>)> {
+    let (mut uparams, mut vparams) = (vec![0.0; v_curves.len()], vec![0.0; u_curves.len()]);
+    for (i, u_curve) in u_curves.iter().enumerate() {
+        for (j, v_curve) in v_curves.iter().enumerate() {
+            let ranges = ((0.0, 1.0), (0.0, 1.0));
+            let hint = presearch_closest_point(u_curve, v_curve, ranges, PRESEARCH_DIVISION);
+            let trials = SEARCH_PARAMETER_TRIALS;
+            let (s, t) = search_closest_parameter(u_curve, v_curve, hint, trials).unwrap_or(hint);
+            uparams[j] += s.clamp(0.0, 1.0) / u_curves.len() as f64;
+            vparams[i] += t.clamp(0.0, 1.0) / v_curves.len() as f64;
+        }
+    }
+    for params in [&mut uparams, &mut vparams] {
+        let n = params.len();
+        (params[0], params[n - 1]) = (0.0, 1.0);
+        if params.windows(2).any(|x| x[1] - x[0] < TOLERANCE) {
+            return Err(Error::InvalidCurveNetwork);
+        }
+    }
+    Ok((uparams, vparams))
+}
+
+/// Returns the clones of `curves` normalized and made the same degree and knot vector.
+fn compatible_curves(curves: &[BSplineCurve<Point3>]) -> Vec<BSplineCurve<Point3>> {
+    let mut curves = curves.to_vec();
+    let degree = curves.iter().map(BSplineCurve::degree).max().unwrap_or(0);
+    curves.iter_mut().for_each(|curve| {
+        curve.knot_normalize();
+        while curve.degree() < degree {
+            curve.elevate_degree();
+        }
+    });
+    let (first, rest) = curves.split_first_mut().unwrap();
+    rest.iter().for_each(|curve| {
+        missing_knots(curve.knot_vec(), first.knot_vec()).into_iter().for_each(|x| {
+            first.add_knot(x);
+        });
+    });
+    rest.iter_mut().for_each(|curve| {
+        missing_knots(first.knot_vec(), curve.knot_vec()).into_iter().for_each(|x| {
+            curve.add_knot(x);
+        });
+    });
+    curves
+}
+
+/// Makes `surfaces`, whose parameter ranges are the same, the same degrees and knot vectors.
+fn compatible_surfaces(surfaces: &mut [BSplineSurface<Point3>]) {
+    let udegree = surfaces.iter().map(BSplineSurface::udegree).max().unwrap_or(0);
+    let vdegree = surfaces.iter().map(BSplineSurface::vdegree).max().unwrap_or(0);
+    surfaces.iter_mut().for_each(|surface| {
+        while surface.udegree() < udegree {
+            surface.elevate_udegree();
+        }
+        while surface.vdegree() < vdegree {
+            surface.elevate_vdegree();
+        }
+    });
+    let (first, rest) = surfaces.split_first_mut().unwrap();
+    rest.iter().for_each(|surface| {
+        missing_knots(surface.uknot_vec(), first.uknot_vec()).into_iter().for_each(|x| {
+            first.add_uknot(x);
+        });
+        missing_knots(surface.vknot_vec(), first.vknot_vec()).into_iter().for_each(|x| {
+            first.add_vknot(x);
+        });
+    });
+    rest.iter_mut().for_each(|surface| {
+        missing_knots(first.uknot_vec(), surface.uknot_vec()).into_iter().for_each(|x| {
+            surface.add_uknot(x);
+        });
+        missing_knots(first.vknot_vec(), surface.vknot_vec()).into_iter().for_each(|x| {
+            surface.add_vknot(x);
+        });
+    });
+}
+
+/// Returns the knots to be added to `current` so that it includes `target` with multiplicity.
+fn missing_knots(target: &KnotVec, current: &KnotVec) -> Vec<f64> {
+    let (knots, mults) = target.to_single_multi();
+    knots
+        .into_iter()
+        .zip(mults)
+        .flat_map(|(x, mult)| {
+            let current_mult = current.iter().filter(|y| x.near(y)).count();
+            std::iter::repeat_n(x, mult.saturating_sub(current_mult))
+        })
+        .collect()
+}
+
+/// The knot vector interpolating the points at `params` by the averaging technique.
+pub(crate) fn interpolation_knot_vec(params: &[f64]) -> KnotVec {
+    let n = params.len();
+    let degree = usize::min(MAX_INTERPOLATION_DEGREE, n - 1);
+    let inner = (1..n - degree).map(|j| params[j..j + degree].iter().sum::<f64>() / degree as f64);
+    let front = std::iter::repeat_n(params[0], degree + 1);
+    let back = std::iter::repeat_n(params[n - 1], degree + 1);
+    KnotVec::from(front.chain(inner).chain(back).collect::<Vec<_>>())
+}
+
+/// Returns the control points of the curve with `knot_vec` interpolating `points` at `params`.
+pub(crate) fn interpolate<P: control_point::ControlPoint<f64>>(
+    knot_vec: &KnotVec,
+    params: &[f64],
+    points: impl Iterator<Item = P>,
+) -> Result<Vec<P>> {
+    let parameter_points: Vec<(f64, P)> = params.iter().copied().zip(points).collect();
+    let curve = BSplineCurve::try_interpole(knot_vec.clone(), parameter_points)?;
+    Ok(curve.destruct().1)
+}
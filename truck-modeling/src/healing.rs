@@ -0,0 +1,368 @@
+use crate::{errors::Error, ray_casting::faces_ray_hits, *};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A repair performed by [`SolidHealing`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Repair {
+    /// the vertices within the tolerance are merged into the vertex `kept`
+    MergeVertices {
+        /// the id of the remaining vertex
+        kept: VertexID,
+        /// the ids of the merged vertices
+        merged: Vec<VertexID>,
+    },
+    /// the edge `removed` with the same ends and geometry as `kept` is replaced by `kept`
+    WeldEdges {
+        /// the id of the remaining edge
+        kept: EdgeID,
+        /// the id of the replaced edge
+        removed: EdgeID,
+    },
+    /// the face is inverted in order to be oriented consistently and outward
+    InvertFace(FaceID),
+    /// the face whose boundary edges run back and forth along other faces is removed
+    RemoveSliver(FaceID),
+    /// the adjacent faces on the same surface are merged into one face.
+    /// cf. [`AssembleOptions::merge_faces`](../assemble/struct.AssembleOptions.html)
+    MergeFaces(Vec<FaceID>),
+}
+
+/// The repairs performed by [`SolidHealing`], in order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HealingLog {
+    /// the repairs
+    pub repairs: Vec<Repair>,
+}
+
+impl HealingLog {
+    /// Returns the number of the repairs.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.repairs.len() }
+    /// Returns whether no repair is needed.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.repairs.is_empty() }
+}
+
+/// A defect of a shell remaining after healing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Defect {
+    /// the edge is used by only one face
+    OpenEdge(EdgeID),
+    /// the edge is used more than twice
+    NonManifoldEdge(EdgeID),
+    /// the edge is used twice in the same direction
+    InconsistentEdge(EdgeID),
+    /// the neighborhood of the vertex is not a disk
+    SingularVertex(VertexID),
+    /// the shell is not connected
+    Disconnected,
+}
+
+/// Creates solids from shells which are closed within the tolerance.
+pub trait SolidHealing: Sized {
+    /// Heals `shells` and creates a solid, with the log of the repairs.
+    ///
+    /// The following repairs are performed for each shell, in order:
+    /// 1. merging the vertices within `tol`,
+    /// 2. welding the edges with the same ends whose curves are within `tol`,
+    /// 3. inverting faces in order to orient them consistently and outward, and
+    /// 4. removing the slivers, the faces all of whose edges go and return along other faces.
+    ///
+    /// The healed shells are validated by [`Solid::try_new`]. If it fails, the residual
+    /// defects are returned by [`Error::UnhealedDefects`].
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// // two faces of a unit square, with no shared topology
+    /// let p = [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0)];
+    /// let v = builder::vertices(p);
+    /// let w = builder::vertices([p[0], p[3], p[2], p[1]]);
+    /// let wire0: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    /// let wire1: Wire = (0..4).map(|i| builder::line(&w[i], &w[(i + 1) % 4])).collect();
+    /// let shell: Shell = vec![
+    ///     builder::try_attach_plane(vec![wire0]).unwrap(),
+    ///     builder::try_attach_plane(vec![wire1]).unwrap(),
+    /// ]
+    /// .into();
+    /// assert!(Solid::try_new(vec![shell.clone()]).is_err());
+    ///
+    /// // a flat solid whose faces are glued
+    /// let (solid, log) = Solid::try_new_healed(vec![shell], 1.0e-6).unwrap();
+    /// assert_eq!(solid.face_iter().count(), 2);
+    /// let welds = log.repairs.iter().filter(|r| matches!(r, Repair::WeldEdges { .. }));
+    /// assert_eq!(welds.count(), 4);
+    /// ```
+    fn try_new_healed(shells: Vec<Shell>, tol: f64) -> Result<(Self, HealingLog)> {
+        Self::try_new_healed_with_max_repairs(shells, tol, usize::MAX)
+    }
+
+    /// Heals `shells` and creates a solid by [`SolidHealing::try_new_healed`], or returns
+    /// [`Error::TooManyRepairs`] if the number of the repairs exceeds `max_repairs`.
+    fn try_new_healed_with_max_repairs(
+        shells: Vec<Shell>,
+        tol: f64,
+        max_repairs: usize,
+    ) -> Result<(Self, HealingLog)>;
+}
+
+impl SolidHealing for Solid {
+    fn try_new_healed_with_max_repairs(
+        mut shells: Vec<Shell>,
+        tol: f64,
+        max_repairs: usize,
+    ) -> Result<(Self, HealingLog)> {
+        let mut log = HealingLog::default();
+        for shell in &mut shells {
+            merge_vertices(shell, tol, &mut log);
+            weld_edges(shell, tol, &mut log);
+            repair_orientation(shell, tol, &mut log);
+            remove_slivers(shell, &mut log);
+        }
+        if log.len() > max_repairs {
+            return Err(Error::TooManyRepairs(log.len()));
+        }
+        match Solid::try_new(shells.clone()) {
+            Ok(solid) => Ok((solid, log)),
+            Err(error) => {
+                let defects: Vec<Defect> = shells.iter().flat_map(defects).collect();
+                match defects.is_empty() {
+                    true => Err(error.into()),
+                    false => Err(Error::UnhealedDefects(defects)),
+                }
+            }
+        }
+    }
+}
+
+/// Returns the unique elements in the order of appearance.
+fn unique<T, K>(iter: impl Iterator<Item = T>, key: impl Fn(&T) -> K) -> Vec<T>
+where K: Eq + std::hash::Hash {
+    let mut set = HashSet::new();
+    iter.filter(|x| set.insert(key(x))).collect()
+}
+
+/// Merges the vertices within `tol`, found by the spatial hash whose cells are the cubes of the
+/// size `tol`.
+pub(crate) fn merge_vertices(shell: &mut Shell, tol: f64, log: &mut HealingLog) {
+    let vertices = unique(shell.vertex_iter(), Vertex::id);
+    let cell = |p: Point3| [p.x, p.y, p.z].map(|x| (x / tol).floor() as i64);
+    // union-find of the vertices within the tolerance
+    let mut parents: Vec<usize> = (0..vertices.len()).collect();
+    fn root(parents: &mut [usize], i: usize) -> usize {
+        match parents[i] == i {
+            true => i,
+            false => {
+                let r = root(parents, parents[i]);
+                parents[i] = r;
+                r
+            }
+        }
+    }
+    let mut grid: HashMap<[i64; 3], Vec<usize>> = HashMap::new();
+    for (i, vertex) in vertices.iter().enumerate() {
+        let p = vertex.point();
+        let c = cell(p);
+        // the vertices within `tol` are in the adjacent cells
+        for k in 0..27 {
+            let offset = [k / 9 - 1, k / 3 % 3 - 1, k % 3 - 1];
+            let neighbors = grid.get(&[c[0] + offset[0], c[1] + offset[1], c[2] + offset[2]]);
+            for &j in neighbors.into_iter().flatten() {
+                if vertices[j].point().distance(p) < tol {
+                    let (ri, rj) = (root(&mut parents, i), root(&mut parents, j));
+                    parents[ri.max(rj)] = ri.min(rj);
+                }
+            }
+        }
+        grid.entry(c).or_default().push(i);
+    }
+    let mut clusters: HashMap<usize, Vec<usize>> = HashMap::new();
+    (0..vertices.len()).for_each(|i| clusters.entry(root(&mut parents, i)).or_default().push(i));
+    let mut clusters: Vec<Vec<usize>> = clusters.into_values().filter(|c| c.len() > 1).collect();
+    clusters.sort();
+    for cluster in clusters {
+        let kept = &vertices[cluster[0]];
+        let merged: Vec<VertexID> = cluster[1..]
+            .iter()
+            .map(|&i| &vertices[i])
+            .filter(|v| shell.merge_vertices(kept, v))
+            .map(Vertex::id)
+            .collect();
+        if !merged.is_empty() {
+            let kept = kept.id();
+            log.repairs.push(Repair::MergeVertices { kept, merged });
+        }
+    }
+}
+
+/// Whether the curve of `edge0` is within `tol` from the one of `edge1`.
+fn coincident(edge0: &Edge, edge1: &Edge, tol: f64) -> bool {
+    let (curve0, curve1) = (edge0.curve(), edge1.curve());
+    let (t0, t1) = curve0.range_tuple();
+    (1..8).all(|i| {
+        let p = curve0.subs(t0 + (t1 - t0) * i as f64 / 8.0);
+        let t = curve1.search_nearest_parameter(p, None, 100);
+        t.is_some_and(|t| curve1.subs(t).distance(p) < tol)
+    })
+}
+
+pub(crate) fn weld_edges(shell: &mut Shell, tol: f64, log: &mut HealingLog) {
+    let edges = unique(shell.edge_iter(), Edge::id);
+    let mut kept_edges: HashMap<(VertexID, VertexID), Vec<Edge>> = HashMap::new();
+    let mut replaced: HashMap<EdgeID, Edge> = HashMap::new();
+    for edge in edges {
+        let (front, back) = (edge.absolute_front().id(), edge.absolute_back().id());
+        let key = match kept_edges.contains_key(&(back, front)) {
+            true => (back, front),
+            false => (front, back),
+        };
+        let group = kept_edges.entry(key).or_default();
+        match group.iter().find(|kept| coincident(&edge, kept, tol)) {
+            Some(kept) => {
+                let kept_clone = match kept.absolute_front().id() == front {
+                    true => kept.absolute_clone(),
+                    false => kept.absolute_clone().inverse(),
+                };
+                log.repairs.push(Repair::WeldEdges {
+                    kept: kept.id(),
+                    removed: edge.id(),
+                });
+                replaced.insert(edge.id(), kept_clone);
+            }
+            None => group.push(edge.absolute_clone()),
+        }
+    }
+    if replaced.is_empty() {
+        return;
+    }
+    let faces = shell.face_iter().map(|face| {
+        let wires = face.boundaries().into_iter().map(|wire| {
+            let edges = wire.into_iter().map(|edge| match replaced.get(&edge.id()) {
+                Some(kept) if edge.orientation() => kept.clone(),
+                Some(kept) => kept.inverse(),
+                None => edge,
+            });
+            edges.collect()
+        });
+        Face::new_unchecked(wires.collect(), face.oriented_surface())
+    });
+    *shell = faces.collect();
+}
+
+/// Whether the faces of `shell` are oriented outward, decided by the first hit of a ray.
+fn is_outward(shell: &Shell, tol: f64) -> bool {
+    let bdb: BoundingBox<Point3> = shell.vertex_iter().map(|v| v.point()).collect();
+    let (center, diameter) = (bdb.center(), bdb.diameter());
+    // generic directions, in order to avoid passing through edges and vertices
+    let dirs = [
+        Vector3::new(0.5377, 0.1831, 0.8230),
+        Vector3::new(-0.3187, 0.8919, 0.3209),
+        Vector3::new(0.6911, -0.5923, -0.3862),
+    ];
+    dirs.into_iter()
+        .find_map(|dir| {
+            let origin = center + dir.normalize() * (diameter + 1.0);
+            let hits = faces_ray_hits(shell.face_iter(), origin, -dir, tol);
+            hits.first().map(|hit| hit.entering)
+        })
+        .unwrap_or(true)
+}
+
+pub(crate) fn repair_orientation(shell: &mut Shell, tol: f64, log: &mut HealingLog) {
+    let mut uses: HashMap<EdgeID, Vec<(usize, bool)>> = HashMap::new();
+    for (i, face) in shell.face_iter().enumerate() {
+        face.edge_iter().for_each(|edge| {
+            uses.entry(edge.id()).or_default().push((i, edge.orientation()));
+        });
+    }
+    // the faces adjacent by manifold edges, with whether the flips of them must differ
+    let mut adjacency: Vec<Vec<(usize, bool)>> = vec![Vec::new(); shell.len()];
+    for uses in uses.values() {
+        if let [(i, o0), (j, o1)] = uses[..] {
+            if i != j {
+                adjacency[i].push((j, o0 == o1));
+                adjacency[j].push((i, o0 == o1));
+            }
+        }
+    }
+    let mut flips: Vec<Option<bool>> = vec![None; shell.len()];
+    for start in 0..shell.len() {
+        if flips[start].is_some() {
+            continue;
+        }
+        flips[start] = Some(false);
+        let mut queue = VecDeque::from([start]);
+        while let Some(i) = queue.pop_front() {
+            let flip = flips[i].unwrap();
+            for &(j, differ) in &adjacency[i] {
+                if flips[j].is_none() {
+                    flips[j] = Some(flip ^ differ);
+                    queue.push_back(j);
+                }
+            }
+        }
+    }
+    let mut flipped: Shell = shell.clone();
+    flipped
+        .face_iter_mut()
+        .zip(&flips)
+        .filter(|(_, flip)| flip.unwrap())
+        .for_each(|(face, _)| {
+            face.invert();
+        });
+    let inverse_all = !is_outward(&flipped, tol);
+    for (face, flip) in shell.face_iter_mut().zip(flips) {
+        if flip.unwrap() ^ inverse_all {
+            face.invert();
+            log.repairs.push(Repair::InvertFace(face.id()));
+        }
+    }
+}
+
+pub(crate) fn remove_slivers(shell: &mut Shell, log: &mut HealingLog) {
+    let mut counts: HashMap<EdgeID, usize> = HashMap::new();
+    shell.edge_iter().for_each(|edge| *counts.entry(edge.id()).or_default() += 1);
+    let is_sliver = |face: &Face| {
+        let edges: Vec<Edge> = face.edge_iter().collect();
+        edges.iter().all(|edge| {
+            let mut iter = edges.iter().filter(|e| e.id() == edge.id());
+            let paired = matches!(
+                (iter.next(), iter.next(), iter.next()),
+                (Some(e0), Some(e1), None) if e0.orientation() != e1.orientation()
+            );
+            paired && counts[&edge.id()] > 2
+        })
+    };
+    let (slivers, faces): (Vec<Face>, Vec<Face>) = shell.face_iter().cloned().partition(is_sliver);
+    for face in slivers {
+        log.repairs.push(Repair::RemoveSliver(face.id()));
+    }
+    *shell = faces.into();
+}
+
+/// Returns the defects of the shell preventing it from being a boundary of a solid.
+pub(crate) fn defects(shell: &Shell) -> Vec<Defect> {
+    let mut uses: HashMap<EdgeID, Vec<bool>> = HashMap::new();
+    let mut edges = Vec::new();
+    shell.edge_iter().for_each(|edge| {
+        let entry = uses.entry(edge.id()).or_default();
+        if entry.is_empty() {
+            edges.push(edge.id());
+        }
+        entry.push(edge.orientation());
+    });
+    let mut res: Vec<Defect> = edges
+        .into_iter()
+        .filter_map(|id| match uses[&id][..] {
+            [_] => Some(Defect::OpenEdge(id)),
+            [o0, o1] if o0 == o1 => Some(Defect::InconsistentEdge(id)),
+            [_, _] => None,
+            _ => Some(Defect::NonManifoldEdge(id)),
+        })
+        .collect();
+    let singular = shell.singular_vertices().into_iter();
+    res.extend(singular.map(|v| Defect::SingularVertex(v.id())));
+    if !shell.is_connected() {
+        res.push(Defect::Disconnected);
+    }
+    res
+}
@@ -0,0 +1,439 @@
+use crate::{errors::Error, *};
+use derive_more::{From, TryInto};
+use serde::{Deserialize, Serialize};
+
+/// The result of an operation of [`ModelHistory`].
+#[derive(Clone, Debug, From, TryInto)]
+pub enum Shape {
+    /// vertex
+    Vertex(Vertex),
+    /// edge
+    Edge(Edge),
+    /// wire
+    Wire(Wire),
+    /// face
+    Face(Face),
+    /// shell
+    Shell(Shell),
+    /// solid
+    Solid(Solid),
+}
+
+/// A modeling operation recorded in [`ModelHistory`].
+///
+/// The operands are referred by the indices of the operations in the history,
+/// which must be less than the index of the operation itself.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Operation {
+    /// [`builder::vertex`]
+    Vertex {
+        /// the point of the vertex
+        point: Point3,
+    },
+    /// [`builder::line`]
+    Line {
+        /// the index of the front vertex
+        front: usize,
+        /// the index of the back vertex
+        back: usize,
+    },
+    /// [`builder::circle_arc`]
+    CircleArc {
+        /// the index of the front vertex
+        front: usize,
+        /// the index of the back vertex
+        back: usize,
+        /// the point on the arc
+        transit: Point3,
+    },
+    /// the wire connecting edges
+    Wire {
+        /// the indices of the edges
+        edges: Vec<usize>,
+    },
+    /// [`builder::try_attach_plane`]
+    AttachPlane {
+        /// the indices of the boundary wires
+        wires: Vec<usize>,
+    },
+    /// [`builder::tsweep`] of a vertex, an edge, a wire, or a face
+    Tsweep {
+        /// the index of the swept element
+        elem: usize,
+        /// the vector of the sweep
+        vector: Vector3,
+    },
+    /// [`builder::rsweep`] of a vertex, an edge, a wire, or a face
+    Rsweep {
+        /// the index of the swept element
+        elem: usize,
+        /// a point on the axis
+        origin: Point3,
+        /// the direction of the axis
+        axis: Vector3,
+        /// the angle of the rotation
+        angle: Rad<f64>,
+    },
+    /// [`builder::transformed`]
+    Transformed {
+        /// the index of the transformed element
+        elem: usize,
+        /// the matrix of the transformation
+        matrix: Matrix4,
+    },
+    /// [`builder::translated`]
+    Translated {
+        /// the index of the translated element
+        elem: usize,
+        /// the vector of the translation
+        vector: Vector3,
+    },
+    /// [`builder::rotated`]
+    Rotated {
+        /// the index of the rotated element
+        elem: usize,
+        /// a point on the axis
+        origin: Point3,
+        /// the direction of the axis
+        axis: Vector3,
+        /// the angle of the rotation
+        angle: Rad<f64>,
+    },
+    /// [`builder::scaled`]
+    Scaled {
+        /// the index of the scaled element
+        elem: usize,
+        /// the center of the scaling
+        origin: Point3,
+        /// the scalars of the coordinates
+        scalars: Vector3,
+    },
+}
+
+impl Operation {
+    /// Returns the indices of the operands.
+    pub fn dependencies(&self) -> Vec<usize> {
+        use Operation::*;
+        match self {
+            Vertex { .. } => Vec::new(),
+            Line { front, back } | CircleArc { front, back, .. } => vec![*front, *back],
+            Wire { edges } => edges.clone(),
+            AttachPlane { wires } => wires.clone(),
+            Tsweep { elem, .. }
+            | Rsweep { elem, .. }
+            | Transformed { elem, .. }
+            | Translated { elem, .. }
+            | Rotated { elem, .. }
+            | Scaled { elem, .. } => vec![*elem],
+        }
+    }
+
+    /// Executes the operation, whose operands are taken from `results`.
+    fn execute(&self, results: &[Shape]) -> Result<Shape> {
+        let shape = |index: usize| results.get(index).ok_or(Error::HistoryIndexOutOfRange(index));
+        let vertex = |index: usize| match shape(index)? {
+            Shape::Vertex(vertex) => Ok(vertex),
+            _ => Err(Error::UnexpectedShape(index)),
+        };
+        let res = match self {
+            Operation::Vertex { point } => builder::vertex(*point).into(),
+            Operation::Line { front, back } => {
+                builder::line(vertex(*front)?, vertex(*back)?).into()
+            }
+            Operation::CircleArc {
+                front,
+                back,
+                transit,
+            } => builder::circle_arc(vertex(*front)?, vertex(*back)?, *transit).into(),
+            Operation::Wire { edges } => {
+                let edges = edges.iter().map(|&index| match shape(index)? {
+                    Shape::Edge(edge) => Ok(edge.clone()),
+                    _ => Err(Error::UnexpectedShape(index)),
+                });
+                edges.collect::<Result<Wire>>()?.into()
+            }
+            Operation::AttachPlane { wires } => {
+                let wires = wires.iter().map(|&index| match shape(index)? {
+                    Shape::Wire(wire) => Ok(wire.clone()),
+                    _ => Err(Error::UnexpectedShape(index)),
+                });
+                builder::try_attach_plane(wires.collect::<Result<Vec<_>>>()?)?.into()
+            }
+            Operation::Tsweep { elem, vector } => match shape(*elem)? {
+                Shape::Vertex(vertex) => builder::tsweep::<_, Edge>(vertex, *vector).into(),
+                Shape::Edge(edge) => builder::tsweep::<_, Face>(edge, *vector).into(),
+                Shape::Wire(wire) => builder::tsweep::<_, Shell>(wire, *vector).into(),
+                Shape::Face(face) => builder::tsweep::<_, Solid>(face, *vector).into(),
+                _ => return Err(Error::UnexpectedShape(*elem)),
+            },
+            Operation::Rsweep {
+                elem,
+                origin,
+                axis,
+                angle,
+            } => match shape(*elem)? {
+                Shape::Vertex(v) => builder::rsweep::<_, Wire, _>(v, *origin, *axis, *angle).into(),
+                Shape::Edge(e) => builder::rsweep::<_, Shell, _>(e, *origin, *axis, *angle).into(),
+                Shape::Wire(w) => builder::rsweep::<_, Shell, _>(w, *origin, *axis, *angle).into(),
+                Shape::Face(f) => builder::rsweep::<_, Solid, _>(f, *origin, *axis, *angle).into(),
+                _ => return Err(Error::UnexpectedShape(*elem)),
+            },
+            Operation::Transformed { elem, matrix } => shape(*elem)?.transformed(*matrix),
+            Operation::Translated { elem, vector } => {
+                shape(*elem)?.transformed(Matrix4::from_translation(*vector))
+            }
+            Operation::Rotated {
+                elem,
+                origin,
+                axis,
+                angle,
+            } => {
+                let matrix = Matrix4::from_axis_angle(*axis, *angle);
+                shape(*elem)?.transformed(around(*origin, matrix))
+            }
+            Operation::Scaled {
+                elem,
+                origin,
+                scalars,
+            } => {
+                let matrix = Matrix4::from_nonuniform_scale(scalars.x, scalars.y, scalars.z);
+                shape(*elem)?.transformed(around(*origin, matrix))
+            }
+        };
+        Ok(res)
+    }
+}
+
+/// the transformation `matrix` whose center is moved to `origin`
+fn around(origin: Point3, matrix: Matrix4) -> Matrix4 {
+    let vector = origin.to_vec();
+    Matrix4::from_translation(vector) * matrix * Matrix4::from_translation(-vector)
+}
+
+impl Shape {
+    fn transformed(&self, matrix: Matrix4) -> Shape {
+        match self {
+            Shape::Vertex(x) => builder::transformed(x, matrix).into(),
+            Shape::Edge(x) => builder::transformed(x, matrix).into(),
+            Shape::Wire(x) => builder::transformed(x, matrix).into(),
+            Shape::Face(x) => builder::transformed(x, matrix).into(),
+            Shape::Shell(x) => builder::transformed(x, matrix).into(),
+            Shape::Solid(x) => builder::transformed(x, matrix).into(),
+        }
+    }
+}
+
+/// The log of modeling operations, which can be replayed after the parameters are changed.
+///
+/// The operations are recorded by the methods mirroring the functions of [`builder`],
+/// which return the indices of the operations in place of the results. The builder functions
+/// themselves do not record anything.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let mut history = ModelHistory::new();
+/// let v = history.vertex(Point3::origin());
+/// let e = history.tsweep(v, Vector3::unit_x()).unwrap();
+/// let f = history.tsweep(e, Vector3::unit_y()).unwrap();
+/// let cube = history.tsweep(f, Vector3::unit_z()).unwrap();
+///
+/// // make the cube higher
+/// let op = Operation::Tsweep { elem: f, vector: Vector3::new(0.0, 0.0, 2.0) };
+/// history.replace_op(cube, op).unwrap();
+/// let Some(Shape::Solid(solid)) = history.result(cube) else {
+///     unreachable!()
+/// };
+/// assert!(solid.vertex_iter().any(|v| v.point() == Point3::new(1.0, 1.0, 2.0)));
+/// // the operations before the sweep were not executed again
+/// assert_eq!(history.execution_count(f), 1);
+/// assert_eq!(history.execution_count(cube), 2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ModelHistory {
+    operations: Vec<Operation>,
+    results: Vec<Shape>,
+    counts: Vec<usize>,
+}
+
+impl ModelHistory {
+    /// Creates an empty history.
+    #[inline(always)]
+    pub fn new() -> Self { Self::default() }
+
+    /// Creates the history by executing `operations`, e.g. deserialized ones.
+    pub fn from_operations(operations: impl IntoIterator<Item = Operation>) -> Result<Self> {
+        let mut history = Self::new();
+        for operation in operations {
+            history.push(operation)?;
+        }
+        Ok(history)
+    }
+
+    /// Returns the recorded operations.
+    #[inline(always)]
+    pub fn operations(&self) -> &[Operation] { &self.operations }
+
+    /// Returns the current result of the `index`th operation.
+    #[inline(always)]
+    pub fn result(&self, index: usize) -> Option<&Shape> { self.results.get(index) }
+
+    /// Returns the number of the executions of the `index`th operation, including the first one.
+    #[inline(always)]
+    pub fn execution_count(&self, index: usize) -> usize {
+        self.counts.get(index).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of the recorded operations.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.operations.len() }
+
+    /// Returns whether no operation is recorded.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.operations.is_empty() }
+
+    /// Executes `operation` and records it, returning its index.
+    pub fn push(&mut self, operation: Operation) -> Result<usize> {
+        let shape = operation.execute(&self.results)?;
+        self.operations.push(operation);
+        self.results.push(shape);
+        self.counts.push(1);
+        Ok(self.operations.len() - 1)
+    }
+
+    /// Records [`builder::vertex`].
+    pub fn vertex<P: Into<Point3>>(&mut self, point: P) -> usize {
+        let operation = Operation::Vertex {
+            point: point.into(),
+        };
+        self.push(operation).unwrap()
+    }
+
+    /// Records [`builder::line`].
+    pub fn line(&mut self, front: usize, back: usize) -> Result<usize> {
+        self.push(Operation::Line { front, back })
+    }
+
+    /// Records [`builder::circle_arc`].
+    pub fn circle_arc(&mut self, front: usize, back: usize, transit: Point3) -> Result<usize> {
+        self.push(Operation::CircleArc {
+            front,
+            back,
+            transit,
+        })
+    }
+
+    /// Records the wire connecting edges.
+    pub fn wire(&mut self, edges: impl Into<Vec<usize>>) -> Result<usize> {
+        let edges = edges.into();
+        self.push(Operation::Wire { edges })
+    }
+
+    /// Records [`builder::try_attach_plane`].
+    pub fn try_attach_plane(&mut self, wires: impl Into<Vec<usize>>) -> Result<usize> {
+        let wires = wires.into();
+        self.push(Operation::AttachPlane { wires })
+    }
+
+    /// Records [`builder::tsweep`].
+    pub fn tsweep(&mut self, elem: usize, vector: Vector3) -> Result<usize> {
+        self.push(Operation::Tsweep { elem, vector })
+    }
+
+    /// Records [`builder::rsweep`].
+    pub fn rsweep<R: Into<Rad<f64>>>(
+        &mut self,
+        elem: usize,
+        origin: Point3,
+        axis: Vector3,
+        angle: R,
+    ) -> Result<usize> {
+        self.push(Operation::Rsweep {
+            elem,
+            origin,
+            axis,
+            angle: angle.into(),
+        })
+    }
+
+    /// Records [`builder::transformed`].
+    pub fn transformed(&mut self, elem: usize, matrix: Matrix4) -> Result<usize> {
+        self.push(Operation::Transformed { elem, matrix })
+    }
+
+    /// Records [`builder::translated`].
+    pub fn translated(&mut self, elem: usize, vector: Vector3) -> Result<usize> {
+        self.push(Operation::Translated { elem, vector })
+    }
+
+    /// Records [`builder::rotated`].
+    pub fn rotated(
+        &mut self,
+        elem: usize,
+        origin: Point3,
+        axis: Vector3,
+        angle: Rad<f64>,
+    ) -> Result<usize> {
+        self.push(Operation::Rotated {
+            elem,
+            origin,
+            axis,
+            angle,
+        })
+    }
+
+    /// Records [`builder::scaled`].
+    pub fn scaled(&mut self, elem: usize, origin: Point3, scalars: Vector3) -> Result<usize> {
+        self.push(Operation::Scaled {
+            elem,
+            origin,
+            scalars,
+        })
+    }
+
+    /// Executes all operations from the beginning, and returns the resulting solids.
+    ///
+    /// The recorded results and the execution counts are not changed.
+    pub fn replay(&self) -> Result<Vec<Solid>> {
+        let mut results = Vec::with_capacity(self.operations.len());
+        for operation in &self.operations {
+            let shape = operation.execute(&results)?;
+            results.push(shape);
+        }
+        let solids = results.into_iter().filter_map(|shape| match shape {
+            Shape::Solid(solid) => Some(solid),
+            _ => None,
+        });
+        Ok(solids.collect())
+    }
+
+    /// Replaces the `index`th operation by `operation`, and executes again the operations
+    /// depending on it directly or indirectly.
+    ///
+    /// The operands of `operation` must be recorded before `index`. On error, `self` is left
+    /// untouched.
+    pub fn replace_op(&mut self, index: usize, operation: Operation) -> Result<()> {
+        if index >= self.operations.len() {
+            return Err(Error::HistoryIndexOutOfRange(index));
+        }
+        if let Some(&dep) = operation.dependencies().iter().find(|&&dep| dep >= index) {
+            return Err(Error::HistoryIndexOutOfRange(dep));
+        }
+        let mut dirty = vec![false; self.operations.len()];
+        dirty[index] = true;
+        let mut results = self.results.clone();
+        results[index] = operation.execute(&results)?;
+        for i in index + 1..self.operations.len() {
+            let deps = self.operations[i].dependencies();
+            if deps.into_iter().any(|dep| dirty[dep]) {
+                dirty[i] = true;
+                results[i] = self.operations[i].execute(&results)?;
+            }
+        }
+        self.operations[index] = operation;
+        self.results = results;
+        self.counts.iter_mut().zip(dirty).for_each(|(count, dirty)| {
+            *count += dirty as usize;
+        });
+        Ok(())
+    }
+}
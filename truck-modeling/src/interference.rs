@@ -0,0 +1,85 @@
+use crate::bvh::BoxTree;
+use crate::*;
+use algo::surface::SurfaceProximity;
+
+/// A pair of points of two shells within the clearance.
+/// cf. [`Interference`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InterferenceWitness {
+    /// the ids of the faces of the first and the second shells
+    pub face_ids: (FaceID, FaceID),
+    /// the points on the faces of the first and the second shells
+    pub points: (Point3, Point3),
+    /// the distance between the points, zero if the faces intersect or touch
+    pub distance: f64,
+}
+
+/// Clash detection of shells.
+pub trait Interference {
+    /// Returns a pair of points of `self` and `other` within `clearance`, or `None` if the shells
+    /// are farther than `clearance`.
+    ///
+    /// The pairs of faces are traversed on the hierarchies of the bounding boxes of the faces,
+    /// pruning the pairs of boxes farther than `clearance`. The remaining pairs of faces are
+    /// classified by [`FaceProximity::proximity`], and the traversal stops at the first pair
+    /// within `clearance`, so the returned pair is not the closest one in general.
+    ///
+    /// The distances between the faces are computed within the numerical margin of
+    /// [`TOLERANCE`], i.e. the shells closer than `clearance - TOLERANCE` are never missed.
+    /// The faces whose boundaries cannot be projected to their surfaces are not checked.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    /// let shell = &cube.boundaries()[0];
+    /// let moved = builder::translated(shell, Vector3::new(1.1, 0.0, 0.0));
+    ///
+    /// assert!(shell.interferes_with(&moved, 0.05).is_none());
+    /// let witness = shell.interferes_with(&moved, 0.2).unwrap();
+    /// assert_near!(witness.distance, 0.1);
+    /// assert_near!(witness.points.0.x, 1.0);
+    /// assert_near!(witness.points.1.x, 1.1);
+    /// ```
+    fn interferes_with(&self, other: &Self, clearance: f64) -> Option<InterferenceWitness>;
+}
+
+impl Interference for Shell {
+    fn interferes_with(&self, other: &Shell, clearance: f64) -> Option<InterferenceWitness> {
+        let faces_tree = |shell: &Shell| {
+            let boxes: Vec<BoundingBox<Point3>> =
+                shell.face_iter().map(Face::cached_bounding_box).collect();
+            BoxTree::new(&boxes)
+        };
+        let (tree0, tree1) = (faces_tree(self)?, faces_tree(other)?);
+        tree0.find_pair(&tree1, clearance, |i, j| {
+            face_witness(&self[i], &other[j], clearance)
+        })
+    }
+}
+
+pub(crate) fn face_witness(
+    face0: &Face,
+    face1: &Face,
+    clearance: f64,
+) -> Option<InterferenceWitness> {
+    let (points, distance) = match face0.proximity(face1, TOLERANCE).ok()? {
+        SurfaceProximity::Intersecting {
+            seed_points: points,
+        }
+        | SurfaceProximity::Tangential {
+            contact_samples: points,
+        } => ((points[0], points[0]), 0.0),
+        SurfaceProximity::Disjoint {
+            distance,
+            witness_pair,
+        } => (witness_pair, distance),
+    };
+    (distance <= clearance).then(|| InterferenceWitness {
+        face_ids: (face0.id(), face1.id()),
+        points,
+        distance,
+    })
+}
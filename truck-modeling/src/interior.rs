@@ -0,0 +1,125 @@
+use crate::ray_casting::{face_domain, faces_ray_hits};
+use crate::*;
+
+/// Removes the faces inside solids, left by naive boolean operations.
+pub trait RemoveInteriorFaces: Sized {
+    /// Removes the faces with the solid on the both sides, and stitches the remaining faces into
+    /// a solid by [`builder::solid_from_faces`] with the tolerance `tol`. Returns the solid and
+    /// the number of the removed faces.
+    ///
+    /// A face is interior if the points offset from a sample in the interior of the face by
+    /// `10 * tol` along the normal and the opposite are both in the solid. The containments are
+    /// decided by the signed counts of the intersections of the rays going away from the face,
+    /// so the coincident faces, e.g. the common wall of two solids glued together, are removed.
+    /// The faces whose boundaries cannot be projected to their surfaces are kept.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// // the naive union of two cubes glued at the wall `x = 1`
+    /// let moved = builder::translated(&cube, Vector3::unit_x());
+    /// let faces: Shell = cube.face_iter().chain(moved.face_iter()).cloned().collect();
+    /// let union = Solid::new_unchecked(vec![faces]);
+    ///
+    /// let (solid, removed) = union.remove_interior_faces(TOLERANCE).unwrap();
+    /// assert_eq!(removed, 2);
+    /// assert_eq!(solid.face_iter().count(), 10);
+    /// assert!(solid.contains_point(Point3::new(1.0, 0.5, 0.5)));
+    /// ```
+    /// # Failures
+    /// Returns the boxed [`AssembleError`] if the remaining faces are not stitched into a solid.
+    fn remove_interior_faces(
+        &self,
+        tol: f64,
+    ) -> std::result::Result<(Self, usize), Box<AssembleError>>;
+}
+
+const DOMAIN_TOLERANCE: f64 = 1.0e-3;
+/// generic directions, in order to avoid passing through edges and vertices
+const DIRECTIONS: [[f64; 3]; 3] = [
+    [0.5377, 0.1831, 0.8230],
+    [-0.3187, 0.8919, 0.3209],
+    [0.6911, -0.5923, -0.3862],
+];
+
+impl RemoveInteriorFaces for Solid {
+    fn remove_interior_faces(
+        &self,
+        tol: f64,
+    ) -> std::result::Result<(Self, usize), Box<AssembleError>> {
+        let faces: Vec<&Face> = self.face_iter().collect();
+        let remaining: Vec<Face> = faces
+            .iter()
+            .filter(|face| !is_interior(face, &faces, tol))
+            .map(|face| (*face).clone())
+            .collect();
+        let removed = faces.len() - remaining.len();
+        let options = AssembleOptions {
+            tolerance: tol,
+            ..Default::default()
+        };
+        let solid = builder::solid_from_faces(remaining, options)?;
+        Ok((solid, removed))
+    }
+}
+
+/// Whether the solid bounded by `faces` is on the both sides of `face`.
+fn is_interior(face: &Face, faces: &[&Face], tol: f64) -> bool {
+    let Some((point, normal)) = interior_sample(face) else {
+        return false;
+    };
+    // the most transversal direction, in order not to come back to the face
+    let dir = DIRECTIONS
+        .into_iter()
+        .map(|dir| Vector3::from(dir).normalize())
+        .max_by(|d0, d1| d0.dot(normal).abs().total_cmp(&d1.dot(normal).abs()))
+        .unwrap();
+    let dir = dir * dir.dot(normal).signum();
+    let offset = normal * 10.0 * tol;
+    winding(faces, point + offset, dir, tol) > 0 && winding(faces, point - offset, -dir, tol) > 0
+}
+
+/// The signed count of the intersections of the ray and the faces, positive inside the solid.
+fn winding(faces: &[&Face], origin: Point3, dir: Vector3, tol: f64) -> isize {
+    let hits = faces_ray_hits(faces.iter().copied(), origin, dir, tol);
+    hits.into_iter().fold(0, |count, hit| match hit.entering {
+        true => count - 1,
+        false => count + 1,
+    })
+}
+
+/// Returns a point in the interior of the face, and the oriented unit normal at the point.
+///
+/// The parameter is the midpoint of the widest interval inside the boundaries on the horizontal
+/// line through the center of the parameter range.
+fn interior_sample(face: &Face) -> Option<(Point3, Vector3)> {
+    let domain = face_domain(face, DOMAIN_TOLERANCE).ok()?;
+    let v = domain.uv_range.center().y;
+    let mut crossings: Vec<f64> = domain
+        .uvs
+        .iter()
+        .flat_map(|uvs| {
+            let closed = uvs.iter().zip(uvs.iter().cycle().skip(1));
+            closed.filter_map(move |(p, q)| match (p.y <= v) != (q.y <= v) {
+                true => Some(p.x + (q.x - p.x) * (v - p.y) / (q.y - p.y)),
+                false => None,
+            })
+        })
+        .collect();
+    crossings.sort_by(f64::total_cmp);
+    let (u0, u1) = crossings
+        .chunks_exact(2)
+        .map(|pair| (pair[0], pair[1]))
+        .max_by(|(u0, u1), (w0, w1)| (u1 - u0).total_cmp(&(w1 - w0)))?;
+    let u = (u0 + u1) / 2.0;
+    let surface = face.surface();
+    let normal = match face.orientation() {
+        true => surface.normal(u, v),
+        false => -surface.normal(u, v),
+    };
+    Some((surface.subs(u, v), normal))
+}
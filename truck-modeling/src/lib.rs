@@ -18,8 +18,8 @@
 /// re-export `truck_base`.
 pub mod base {
     pub use truck_base::{
-        assert_near, assert_near2, bounding_box::BoundingBox, cgmath64::*, prop_assert_near,
-        prop_assert_near2, tolerance::*,
+        assert_near, assert_near2, assert_near_rel, bounding_box::BoundingBox, cgmath64::*,
+        prop_assert_near, prop_assert_near2, prop_assert_near_rel, tolerance::*,
     };
     pub use truck_geotrait::*;
 }
@@ -64,6 +64,19 @@ pub mod topo_traits {
     }
 
     /// Abstract sweeping, builds a circle-arc, a prism, a half torus, and so on.
+    ///
+    /// # Remarks
+    /// `Solid` does not implement this trait. The Minkowski-like extrusion of a solid is not the
+    /// union of the sweeps of its boundary shells, and sweeping the inner shells would lose the
+    /// cavities. So, sweeping a solid is a compile error rather than a silently wrong solid.
+    /// ```compile_fail
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    /// let _: Vec<Result<Solid>> = builder::tsweep(&cube, Vector3::unit_x());
+    /// ```
     pub trait Sweep<T, Pc, Cc, Swept> {
         /// Transform topologies and connect vertices and edges in boundaries.
         fn sweep(&self, trans: T, point_connector: Pc, curve_connector: Cc) -> Swept;
@@ -98,15 +111,126 @@ pub use topo_traits::*;
 /// `Result` with crate's errors.
 pub type Result<T> = std::result::Result<T, errors::Error>;
 
+/// The most used traits and types, for `use truck_modeling::prelude::*`.
+///
+/// This module contains the base types and the geometric traits, the geometric elements, the
+/// topological elements, the modules [`builder`] and [`primitive`], and all the traits of this
+/// crate.
+pub mod prelude {
+    pub use crate::{
+        base::*,
+        builder,
+        errors::Error,
+        primitive,
+        topo_traits::{ClosedSweep, Mapped, MultiSweep, Sweep},
+        AlignSurfaceNormals, AuditOrientation, BSplineCurve, BSplineSurface, BoundaryGap,
+        Canonicalize, ClosestEdge, ClosestPoint, ConvexHull, Curve, CurvatureAnalysis, Drawing,
+        DxfExport, Edge, EdgeAxis, EdgeID, Face, FaceAxis, FaceID, FaceProximity, FitPlane,
+        Interference, KnotVec, Line, MapGeometry, NurbsCurve, NurbsSurface, PlanarBoundary, Plane,
+        PlaneSection, ProjectToPlane, RayCasting, RemoveInteriorFaces, Result, Shell, SingleCurve,
+        SnapshotGeometry, Solid, SolidHealing, SplitSeams, Surface, TrimmedSurfacePatch, Validate,
+        Vertex, VertexID, Wire,
+    };
+}
+
+/// assembling faces into solids
+pub mod assemble;
+pub use assemble::{AssembleError, AssembleOptions};
+/// auditing the orientations of faces and shells
+pub mod audit;
+pub use audit::{
+    AuditOrientation, EdgeAudit, EdgeDisagreement, JunctionAudit, OrientationAudit,
+    ShellOrientationAudit, WireAudit,
+};
+/// axes of edges and faces
+pub mod axis;
+pub use axis::{EdgeAxis, FaceAxis};
 /// the building model utility API
 pub mod builder;
+mod bvh;
+/// canonical representation of shells and solids
+pub mod canonical;
+pub use canonical::Canonicalize;
 mod closed_sweep;
+/// convex hulls of solids
+pub mod convex_hull;
+pub use convex_hull::ConvexHull;
+/// curvature analysis of edges and wires
+pub mod curvature;
+pub use curvature::CurvatureAnalysis;
+/// wireframes and silhouettes of solids for drawings
+pub mod drawing;
+pub use drawing::{Drawing, EdgeClass, Wireframe};
 /// declare errors
 pub mod errors;
+mod fillet;
+/// flattening the boundaries of planar faces
+pub mod flatten;
+pub use flatten::{DxfEntity, DxfExport, PlanarBoundary, PlanarLoop};
+/// gaps between the adjacent faces of shells
+pub mod gap;
+pub use gap::{BoundaryGap, EdgeGap, GapReport};
 mod geom_impls;
+mod geometry_hash;
+mod gordon;
+/// healing shells closed within tolerance into solids
+pub mod healing;
+pub use healing::{Defect, HealingLog, Repair, SolidHealing};
+/// recording and replaying modeling operations
+pub mod history;
+pub use history::{ModelHistory, Operation, Shape};
+/// clash detection of shells
+pub mod interference;
+pub use interference::{Interference, InterferenceWitness};
+/// removing the faces inside solids
+pub mod interior;
+pub use interior::RemoveInteriorFaces;
+/// fallible mapping of the geometry of solids
+pub mod map_geometry;
+pub use map_geometry::{MapError, MapGeometry};
 mod mapped;
 mod multi_sweep;
+/// aligning the normals of surfaces with the orientations of faces
+pub mod normals;
+pub use normals::AlignSurfaceNormals;
+/// extracting faces as rectangular patches of surfaces
+pub mod patch;
+pub use patch::{PatchRange, TrimmedSurfacePatch};
+mod pipe;
+/// fitting planes to wires
+pub mod planarity;
+pub use planarity::{FitPlane, NonPlanarError};
 /// primitive shapes
 pub mod primitive;
+/// projecting wires into planes
+pub mod projection;
+pub use projection::{Edge2D, PlanarProjection, ProjectToPlane, Vertex2D, Wire2D};
+/// proximity queries of wires, shells, and faces
+pub mod proximity;
+pub use proximity::{ClosestEdge, ClosestPoint, FaceProximity};
+/// ray casting to solids
+pub mod ray_casting;
+pub use ray_casting::{RayCasting, RayHit};
+/// roles of the faces of solids made by sweeps and primitives
+pub mod roles;
+pub use roles::{CuboidFace, FaceRoles, RevolutionRole, RevolutionRoles, SweepRole, SweepRoles};
+/// sections of solids by planes
+pub mod section;
+pub use section::PlaneSection;
+/// splitting faces at the seams of periodic surfaces
+pub mod seam;
+pub use seam::SplitSeams;
+/// approximating wires by single curves
+pub mod single_curve;
+pub use single_curve::SingleCurve;
+/// snapshots of the geometry of solids
+pub mod snapshot;
+pub use snapshot::{EntityId, GeometrySnapshot, SnapshotGeometry};
 mod sweep;
 mod topo_impls;
+/// units of length of solids
+pub mod unit;
+pub use unit::{LengthUnit, UnitizedSolid};
+/// validating shells and solids before exporting them
+pub mod validation;
+pub use validation::{Validate, ValidationIssue, ValidationReport};
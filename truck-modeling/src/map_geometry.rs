@@ -0,0 +1,147 @@
+use crate::*;
+use std::collections::{hash_map::Entry, HashMap};
+use thiserror::Error;
+
+/// The error of [`MapGeometry`], with the id of the entity whose geometry failed to be mapped.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum MapError<E> {
+    /// failed to map the point of the vertex
+    #[error("failed to map the point of the vertex {0:?}: {1}")]
+    Point(VertexID, E),
+    /// failed to map the curve of the edge
+    #[error("failed to map the curve of the edge {0:?}: {1}")]
+    Curve(EdgeID, E),
+    /// failed to map the surface of the face
+    #[error("failed to map the surface of the face {0:?}: {1}")]
+    Surface(FaceID, E),
+}
+
+/// Maps the geometry of solids by fallible closures.
+///
+/// Each unique point, curve, and surface is mapped exactly once, in the order of
+/// [`Solid::vertex_iter`], [`Solid::edge_iter`], and [`Solid::face_iter`], respectively.
+/// The closures receive the absolute geometry, i.e. [`Edge::curve`] and [`Face::surface`],
+/// regardless of the orientations. All closures are called before the geometry is replaced,
+/// so `self` is left untouched on error.
+pub trait MapGeometry: Sized {
+    /// Returns a new solid whose geometry is mapped, with the same sharing structure as `self`.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let mat = Matrix4::from_scale(2.0);
+    /// let mut count = 0;
+    /// let scaled = cube
+    ///     .try_map_geometry(
+    ///         |p| Ok::<_, ()>(mat.transform_point(*p)),
+    ///         |c| {
+    ///             count += 1;
+    ///             Ok(c.transformed(mat))
+    ///         },
+    ///         |s| Ok(s.transformed(mat)),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(count, 12);
+    /// assert!(scaled.vertex_iter().any(|v| v.point() == Point3::new(2.0, 2.0, 2.0)));
+    /// assert!(scaled.is_geometric_consistent());
+    /// ```
+    fn try_map_geometry<E>(
+        &self,
+        point_mapping: impl FnMut(&Point3) -> std::result::Result<Point3, E>,
+        curve_mapping: impl FnMut(&Curve) -> std::result::Result<Curve, E>,
+        surface_mapping: impl FnMut(&Surface) -> std::result::Result<Surface, E>,
+    ) -> std::result::Result<Self, MapError<E>>;
+
+    /// Maps the geometry of `self` in place, by [`Vertex::set_point`], [`Edge::set_curve`], and
+    /// [`Face::set_surface`]. The geometry shared with other topologies is also replaced.
+    fn try_map_geometry_mut<E>(
+        &mut self,
+        point_mapping: impl FnMut(&Point3) -> std::result::Result<Point3, E>,
+        curve_mapping: impl FnMut(&Curve) -> std::result::Result<Curve, E>,
+        surface_mapping: impl FnMut(&Surface) -> std::result::Result<Surface, E>,
+    ) -> std::result::Result<(), MapError<E>>;
+}
+
+/// the mapped geometry, indexed by the ids of the original entities
+struct Geometry {
+    points: HashMap<VertexID, Point3>,
+    curves: HashMap<EdgeID, Curve>,
+    surfaces: HashMap<FaceID, Surface>,
+}
+
+impl Geometry {
+    fn try_new<E>(
+        solid: &Solid,
+        mut point_mapping: impl FnMut(&Point3) -> std::result::Result<Point3, E>,
+        mut curve_mapping: impl FnMut(&Curve) -> std::result::Result<Curve, E>,
+        mut surface_mapping: impl FnMut(&Surface) -> std::result::Result<Surface, E>,
+    ) -> std::result::Result<Self, MapError<E>> {
+        let mut points = HashMap::new();
+        for vertex in solid.vertex_iter() {
+            if let Entry::Vacant(entry) = points.entry(vertex.id()) {
+                let point = point_mapping(&vertex.point());
+                entry.insert(point.map_err(|e| MapError::Point(vertex.id(), e))?);
+            }
+        }
+        let mut curves = HashMap::new();
+        for edge in solid.edge_iter() {
+            if let Entry::Vacant(entry) = curves.entry(edge.id()) {
+                let curve = curve_mapping(&edge.curve());
+                entry.insert(curve.map_err(|e| MapError::Curve(edge.id(), e))?);
+            }
+        }
+        let mut surfaces = HashMap::new();
+        for face in solid.face_iter() {
+            if let Entry::Vacant(entry) = surfaces.entry(face.id()) {
+                let surface = surface_mapping(&face.surface());
+                entry.insert(surface.map_err(|e| MapError::Surface(face.id(), e))?);
+            }
+        }
+        Ok(Self {
+            points,
+            curves,
+            surfaces,
+        })
+    }
+
+    /// Sets the geometry of the entities of `target` corresponding to the ones of `original`.
+    /// The two solids must have the same structure.
+    fn apply(&self, original: &Solid, target: &Solid) {
+        for (face0, face1) in original.face_iter().zip(target.face_iter()) {
+            face1.set_surface(self.surfaces[&face0.id()].clone());
+            for (edge0, edge1) in face0.edge_iter().zip(face1.edge_iter()) {
+                edge1.set_curve(self.curves[&edge0.id()].clone());
+                edge1.front().set_point(self.points[&edge0.front().id()]);
+            }
+        }
+    }
+}
+
+impl MapGeometry for Solid {
+    fn try_map_geometry<E>(
+        &self,
+        point_mapping: impl FnMut(&Point3) -> std::result::Result<Point3, E>,
+        curve_mapping: impl FnMut(&Curve) -> std::result::Result<Curve, E>,
+        surface_mapping: impl FnMut(&Surface) -> std::result::Result<Surface, E>,
+    ) -> std::result::Result<Self, MapError<E>> {
+        let geometry = Geometry::try_new(self, point_mapping, curve_mapping, surface_mapping)?;
+        let res = self.mapped(Point3::clone, Curve::clone, Surface::clone);
+        geometry.apply(self, &res);
+        Ok(res)
+    }
+
+    fn try_map_geometry_mut<E>(
+        &mut self,
+        point_mapping: impl FnMut(&Point3) -> std::result::Result<Point3, E>,
+        curve_mapping: impl FnMut(&Curve) -> std::result::Result<Curve, E>,
+        surface_mapping: impl FnMut(&Surface) -> std::result::Result<Surface, E>,
+    ) -> std::result::Result<(), MapError<E>> {
+        let geometry = Geometry::try_new(self, point_mapping, curve_mapping, surface_mapping)?;
+        geometry.apply(self, self);
+        Ok(())
+    }
+}
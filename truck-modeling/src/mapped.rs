@@ -54,6 +54,8 @@ where T: GeometricMapping<P> + GeometricMapping<C> + GeometricMapping<S> + Copy
     }
 }
 
+/// All boundary shells, including the inner shells bounding the cavities, are mapped with their
+/// orientations, and the vertices and edges shared by several shells are mapped only once.
 impl<P, C, S, T> Mapped<T> for Solid<P, C, S>
 where T: GeometricMapping<P> + GeometricMapping<C> + GeometricMapping<S> + Copy
 {
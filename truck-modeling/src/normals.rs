@@ -0,0 +1,62 @@
+use truck_geotrait::Invertible;
+use truck_topology::{Face, Shell, Solid};
+
+/// Aligns the normals of the surfaces with the orientations of the faces.
+pub trait AlignSurfaceNormals {
+    /// Replaces the faces whose orientations are inverted by the faces with the inverted
+    /// surfaces and the positive orientations, i.e. the raw normals of the surfaces coincide with
+    /// the oriented normals of the faces. The oriented surfaces and the boundaries of the faces are
+    /// not changed, so the consumers ignoring the orientations of faces agree with the ones
+    /// respecting them. In particular, the raw normals of the boundaries of a solid point outward.
+    ///
+    /// The surfaces are inverted by [`Invertible::inverse`], which swaps or negates the
+    /// parameters depending on the type of the surface, as reported by
+    /// `InvertibleSurface::inverse_map`. The edges are not changed, since they do not store the
+    /// parameters on the surfaces. The replaced faces get the new IDs.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let mut cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    /// assert!(cube.face_iter().any(|face| !face.orientation()));
+    ///
+    /// cube.align_surface_normals_outward();
+    /// assert!(cube.face_iter().all(|face| face.orientation()));
+    /// let bottom = cube.face_iter().find(|face| face.surface().subs(0.5, 0.5).z.so_small());
+    /// let normal = bottom.unwrap().surface().normal(0.5, 0.5);
+    /// assert_near!(normal, -Vector3::unit_z());
+    /// ```
+    fn align_surface_normals_outward(&mut self);
+}
+
+fn aligned<P, C, S>(face: &Face<P, C, S>) -> Option<Face<P, C, S>>
+where S: Clone + Invertible {
+    match face.orientation() {
+        true => None,
+        false => Some(Face::new_unchecked(face.boundaries(), face.oriented_surface())),
+    }
+}
+
+impl<P, C, S> AlignSurfaceNormals for Shell<P, C, S>
+where S: Clone + Invertible
+{
+    fn align_surface_normals_outward(&mut self) {
+        self.face_iter_mut().for_each(|face| {
+            if let Some(aligned) = aligned(face) {
+                *face = aligned;
+            }
+        });
+    }
+}
+
+impl<P, C, S> AlignSurfaceNormals for Solid<P, C, S>
+where S: Clone + Invertible
+{
+    fn align_surface_normals_outward(&mut self) {
+        let mut shells = self.boundaries().clone();
+        shells.iter_mut().for_each(|shell| shell.align_surface_normals_outward());
+        *self = Solid::new_unchecked(shells);
+    }
+}
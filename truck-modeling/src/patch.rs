@@ -0,0 +1,119 @@
+use crate::errors::Error;
+use crate::ray_casting::face_domain;
+use crate::*;
+
+/// the tolerance for dividing the boundaries into the parameter polylines
+const BOUNDARY_TOLERANCE: f64 = 1.0e-3;
+
+/// A rectangular range of the parameters of a surface, `((u0, u1), (v0, v1))`.
+pub type PatchRange = ((f64, f64), (f64, f64));
+
+/// Extracts a face as a rectangular patch of its surface.
+pub trait TrimmedSurfacePatch {
+    /// Returns the surface of `self` and the rectangular range of its parameters including all
+    /// the boundaries of `self`.
+    ///
+    /// The range is the bounding box of the boundaries in the parameter space, expanded by
+    /// `margin` in parameter units and clamped to the domain of the surface. On periodic
+    /// surfaces, the boundaries are unwrapped to be contiguous, so the range may exceed one
+    /// period of the domain. The domains of planes are regarded as unbounded.
+    ///
+    /// If `clipped` is `true`, the B-spline and NURBS surfaces are split by knot insertion, and
+    /// the returned surface is the sub-patch whose domain is exactly the range. The other
+    /// surfaces are returned as they are. The orientation of `self` is not applied to the surface.
+    ///
+    /// # Errors
+    /// - [`Error::ParameterSearchFailed`] if the boundaries cannot be projected to the surface.
+    /// - [`Error::DegenerateParameterRange`] if the boundaries span no area in the parameter space.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let plane = Plane::new(
+    ///     Point3::origin(),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    /// );
+    /// let v = builder::vertex(Point3::new(500.0, 500.0, 0.0));
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let face: Face = builder::tsweep(&e, Vector3::unit_y());
+    /// let face = Face::new(face.boundaries(), Surface::Plane(plane));
+    ///
+    /// let (_, ((u0, u1), (v0, v1))) = face.trimmed_surface_patch(0.0, false).unwrap();
+    /// assert_near!(u0, 500.0);
+    /// assert_near!(u1, 501.0);
+    /// assert_near!(v0, 500.0);
+    /// assert_near!(v1, 501.0);
+    /// ```
+    fn trimmed_surface_patch(&self, margin: f64, clipped: bool) -> Result<(Surface, PatchRange)>;
+}
+
+impl TrimmedSurfacePatch for Face {
+    fn trimmed_surface_patch(&self, margin: f64, clipped: bool) -> Result<(Surface, PatchRange)> {
+        let domain = face_domain(self, BOUNDARY_TOLERANCE)?;
+        let (min, max) = (domain.uv_range.min(), domain.uv_range.max());
+        let diag = max - min;
+        if diag.x.so_small() || diag.y.so_small() {
+            return Err(Error::DegenerateParameterRange);
+        }
+        let surface = self.surface();
+        let (urange, vrange) = match &surface {
+            Surface::Plane(_) => (None, None),
+            _ => surface.try_range_tuple(),
+        };
+        let urange = expand((min.x, max.x), margin, urange, surface.u_period());
+        let vrange = expand((min.y, max.y), margin, vrange, surface.v_period());
+        let surface = match (clipped, surface) {
+            (true, Surface::BSplineSurface(surface)) => {
+                Surface::BSplineSurface(clip(surface, urange, vrange))
+            }
+            (true, Surface::NurbsSurface(surface)) => {
+                let surface = clip(surface.non_rationalized().clone(), urange, vrange);
+                Surface::NurbsSurface(NurbsSurface::new(surface))
+            }
+            (_, surface) => surface,
+        };
+        Ok((surface, (urange, vrange)))
+    }
+}
+
+/// Expands `range` by `margin`, clamped to `domain`, or to one period for periodic directions.
+fn expand(
+    (t0, t1): (f64, f64),
+    margin: f64,
+    domain: Option<(f64, f64)>,
+    period: Option<f64>,
+) -> (f64, f64) {
+    let (t0, t1) = (t0 - margin, t1 + margin);
+    match (period, domain) {
+        (Some(period), _) if t1 - t0 > period => {
+            let center = (t0 + t1) / 2.0;
+            (center - period / 2.0, center + period / 2.0)
+        }
+        (Some(_), _) | (None, None) => (t0, t1),
+        (None, Some((s0, s1))) => (f64::max(t0, s0), f64::min(t1, s1)),
+    }
+}
+
+/// Splits off the sub-patch of `surface` on the range by knot insertion.
+fn clip<P: control_point::ControlPoint<f64> + Tolerance>(
+    mut surface: BSplineSurface<P>,
+    (u0, u1): (f64, f64),
+    (v0, v1): (f64, f64),
+) -> BSplineSurface<P> {
+    let ends = |knot_vec: &KnotVec| (knot_vec[0], knot_vec[knot_vec.len() - 1]);
+    let ((s0, s1), (t0, t1)) = (ends(surface.uknot_vec()), ends(surface.vknot_vec()));
+    if u1 < s1 - TOLERANCE {
+        surface.ucut(u1);
+    }
+    if u0 > s0 + TOLERANCE {
+        surface = surface.ucut(u0);
+    }
+    if v1 < t1 - TOLERANCE {
+        surface.vcut(v1);
+    }
+    if v0 > t0 + TOLERANCE {
+        surface = surface.vcut(v0);
+    }
+    surface
+}
@@ -0,0 +1,198 @@
+use crate::{
+    builder::{self, SweepFrame},
+    errors::Error,
+    gordon::{interpolate, interpolation_knot_vec},
+    *,
+};
+use std::collections::HashMap;
+
+/// the largest angle between the tangents of the path at the consecutive stations
+const MAX_STATION_ANGLE: f64 = std::f64::consts::PI / 8.0;
+
+/// Sweeps `profile` along `path`, cf. [`builder::pipe_sweep_with_frame`].
+pub(super) fn pipe_sweep(
+    profile: &Wire,
+    path: &Curve,
+    frame: SweepFrame,
+    tol: f64,
+) -> Result<Shell> {
+    let (t0, t1) = path.range_tuple();
+    let (der0, der1) = (path.der(t0), path.der(t1));
+    let closed = path.subs(t0).near(&path.subs(t1))
+        && !der0.so_small()
+        && !der1.so_small()
+        && der0.normalize().near(&der1.normalize());
+    let radius = profile_radius(profile, path.subs(t0), tol);
+    let mut params = stations(path, radius, tol);
+    if closed && params.len() < 3 {
+        params.insert(1, (t0 + t1) / 2.0);
+    }
+    let frames = frames(path, &params, frame, closed)?;
+    let start = frames[0].invert().ok_or(Error::DegeneratePath)?;
+    let mats: Vec<Matrix4> = frames.iter().map(|f| *f * start).collect();
+
+    // The closed path is divided into two parts, since an edge cannot be a loop.
+    let n = params.len();
+    let ends = match closed {
+        true => vec![0, n / 2, n - 1],
+        false => vec![0, n - 1],
+    };
+    let sections: Vec<Wire> = ends
+        .iter()
+        .map(|&k| match k == 0 || (closed && k == n - 1) {
+            true => profile.clone(),
+            false => builder::transformed(profile, mats[k]),
+        })
+        .collect();
+
+    let mut shell = Shell::new();
+    for (ends, sections) in ends.windows(2).zip(sections.windows(2)) {
+        let (params, mats) = (&params[ends[0]..=ends[1]], &mats[ends[0]..=ends[1]]);
+        let knot_vec = interpolation_knot_vec(params);
+        let mut rails = HashMap::<VertexID, Edge>::new();
+        let mut rail = |v: &Vertex, v0: &Vertex, v1: &Vertex| -> Result<Edge> {
+            if let Some(edge) = rails.get(&v0.id()) {
+                return Ok(edge.clone());
+            }
+            let points = mats.iter().map(|mat| mat.transform_point(v.point()));
+            let control_points = interpolate(&knot_vec, params, points)?;
+            let curve = BSplineCurve::new(knot_vec.clone(), control_points);
+            let edge = Edge::new(v0, v1, Curve::BSplineCurve(curve));
+            rails.insert(v0.id(), edge.clone());
+            Ok(edge)
+        };
+        let iter = profile.edge_iter().zip(sections[0].edge_iter()).zip(sections[1].edge_iter());
+        for ((edge, edge0), edge1) in iter {
+            let front = rail(edge.front(), edge0.front(), edge1.front())?;
+            let back = rail(edge.back(), edge0.back(), edge1.back())?;
+            // The homogeneous control points are moved rigidly, so the weights are kept.
+            let (uknot_vec, control_points) = edge.oriented_curve().lift_up().destruct();
+            let control_points = control_points
+                .iter()
+                .map(|p| interpolate(&knot_vec, params, mats.iter().map(|mat| *mat * *p)))
+                .collect::<Result<Vec<_>>>()?;
+            let surface = BSplineSurface::try_new((uknot_vec, knot_vec.clone()), control_points)?;
+            let wire = wire![edge0.clone(), back, edge1.inverse(), front.inverse()];
+            shell.push(Face::new(vec![wire], NurbsSurface::new(surface).into()));
+        }
+    }
+    Ok(shell)
+}
+
+/// the largest distance between `origin` and the division points of the edges of `profile`
+fn profile_radius(profile: &Wire, origin: Point3, tol: f64) -> f64 {
+    profile
+        .edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.curve();
+            curve.parameter_division(curve.range_tuple(), tol).1
+        })
+        .fold(0.0, |max, pt| f64::max(max, pt.distance(origin)))
+}
+
+/// Returns the parameters of the stations on `path`.
+///
+/// The division of the path is refined so that the tangent turns between the stations by at
+/// most the central angle of the arc of `radius` whose sagitta is `tol`.
+fn stations(path: &Curve, radius: f64, tol: f64) -> Vec<f64> {
+    let (params, _) = path.parameter_division(path.range_tuple(), tol);
+    let max_angle = 2.0 * f64::acos(f64::max(1.0 - tol / radius, 0.0));
+    let max_angle = f64::min(max_angle, MAX_STATION_ANGLE);
+    let angle = |s: f64, t: f64| path.der(s).angle(path.der(t)).0;
+    let mut res = vec![params[0]];
+    params.windows(2).for_each(|p| {
+        let mid = (p[0] + p[1]) / 2.0;
+        let turn = angle(p[0], mid) + angle(mid, p[1]);
+        let n = usize::max(f64::ceil(turn / max_angle) as usize, 1);
+        res.extend((1..=n).map(|j| p[0] + (p[1] - p[0]) * j as f64 / n as f64));
+    });
+    res
+}
+
+/// Returns the frames of `path` at `params`, whose columns are the normal, the binormal, the
+/// tangent and the point of the path.
+fn frames(path: &Curve, params: &[f64], frame: SweepFrame, closed: bool) -> Result<Vec<Matrix4>> {
+    let points: Vec<Point3> = params.iter().map(|t| path.subs(*t)).collect();
+    let tangents = params
+        .iter()
+        .map(|t| match path.der(*t) {
+            der if der.so_small() => Err(Error::DegeneratePath),
+            der => Ok(der.normalize()),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let normal = frenet_normal(path, params[0], tangents[0]);
+    let mut normals = vec![normal.unwrap_or_else(|| perpendicular(tangents[0]))];
+    for i in 1..params.len() {
+        let (prev, tangent) = (normals[i - 1], tangents[i]);
+        let normal = match frame {
+            SweepFrame::Frenet => frenet_normal(path, params[i], tangent)
+                .unwrap_or_else(|| project(prev, tangent)),
+            SweepFrame::RotationMinimizing => {
+                // the double reflection method
+                let v = points[i] - points[i - 1];
+                let (r, t) = match v.so_small() {
+                    true => (prev, tangents[i - 1]),
+                    false => (reflect(prev, v), reflect(tangents[i - 1], v)),
+                };
+                let v = tangent - t;
+                match v.so_small() {
+                    true => project(r, tangent),
+                    false => project(reflect(r, v), tangent),
+                }
+            }
+        };
+        normals.push(normal);
+    }
+    if closed {
+        // distribute the rotation between the last normal and the first one along the path
+        let (last, tangent) = (normals[normals.len() - 1], tangents[tangents.len() - 1]);
+        let first = project(normals[0], tangent);
+        let angle = f64::atan2(last.cross(first).dot(tangent), last.dot(first));
+        let (t0, t1) = (params[0], params[params.len() - 1]);
+        let iter = normals.iter_mut().zip(&tangents).zip(params);
+        iter.for_each(|((normal, tangent), t)| {
+            let rotation = Matrix3::from_axis_angle(*tangent, Rad(angle * (t - t0) / (t1 - t0)));
+            *normal = rotation * *normal;
+        });
+    }
+    let iter = points.into_iter().zip(tangents).zip(normals);
+    let frames = iter.map(|((point, tangent), normal)| {
+        let binormal = tangent.cross(normal);
+        let (n, b, t) = (normal.extend(0.0), binormal.extend(0.0), tangent.extend(0.0));
+        Matrix4::from_cols(n, b, t, point.to_homogeneous())
+    });
+    Ok(frames.collect())
+}
+
+/// the principal normal of `path` at `t`, `None` if the curvature vanishes
+fn frenet_normal(path: &Curve, t: f64, tangent: Vector3) -> Option<Vector3> {
+    let der2 = path.der2(t);
+    let normal = der2 - tangent * der2.dot(tangent);
+    match (normal / path.der(t).magnitude2()).so_small() {
+        true => None,
+        false => Some(normal.normalize()),
+    }
+}
+
+/// the reflection of `vector` in the plane perpendicular to `axis`
+fn reflect(vector: Vector3, axis: Vector3) -> Vector3 {
+    vector - axis * (2.0 * axis.dot(vector) / axis.magnitude2())
+}
+
+/// the unit vector of the component of `vector` perpendicular to the unit vector `tangent`
+fn project(vector: Vector3, tangent: Vector3) -> Vector3 {
+    let normal = vector - tangent * vector.dot(tangent);
+    match normal.so_small() {
+        true => perpendicular(tangent),
+        false => normal.normalize(),
+    }
+}
+
+/// a unit vector perpendicular to the unit vector `tangent`
+pub(crate) fn perpendicular(tangent: Vector3) -> Vector3 {
+    let axis = match tangent.x.abs() < 0.9 {
+        true => Vector3::unit_x(),
+        false => Vector3::unit_y(),
+    };
+    (axis - tangent * axis.dot(tangent)).normalize()
+}
@@ -0,0 +1,116 @@
+use crate::*;
+use thiserror::Error;
+
+type Wire<C> = truck_topology::Wire<Point3, C>;
+
+/// The error of [`FitPlane::fit_plane`].
+#[derive(Clone, Debug, PartialEq, Error)]
+pub enum NonPlanarError {
+    /// the points of the wire span no area, e.g. the wire is a line segment
+    #[error("The wire spans no area, so no plane is determined.")]
+    Degenerate,
+    /// the points of the wire are out of the fitted plane by more than the tolerance
+    #[error("The wire is out of the plane by {deviation}.")]
+    Deviation {
+        /// the fitted plane
+        plane: Plane,
+        /// the maximum distance from the plane to the sampled points
+        deviation: f64,
+    },
+}
+
+/// Fits planes to wires, without creating faces.
+pub trait FitPlane {
+    /// Returns the plane fitted to the points sampled on `self`, with the maximum distance from
+    /// the plane to the points.
+    ///
+    /// The tolerance `tol` is the threshold of the scale of the vertices by [`ToleranceSpec`], and
+    /// a number is the absolute tolerance. Each edge is divided with the chord tolerance `tol`, so
+    /// that the curves bulging out of the plane of the vertices are detected. The plane is fitted
+    /// in the coordinates centered at the centroid of the points, and is oriented along the wire
+    /// as the plane of [`builder::try_attach_plane`].
+    ///
+    /// # Failure
+    /// - [`NonPlanarError::Degenerate`] if the points span no area relative to their size.
+    /// - [`NonPlanarError::Deviation`] if the maximum distance is more than `tol`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertices([(0.0, 0.0, 5.0), (2.0, 0.0, 5.0), (2.0, 1.0, 5.0)]);
+    /// let wire: Wire = vec![
+    ///     builder::line(&v[0], &v[1]),
+    ///     builder::line(&v[1], &v[2]),
+    ///     builder::line(&v[2], &v[0]),
+    /// ]
+    /// .into();
+    /// let (plane, deviation) = wire.fit_plane(1.0e-3).unwrap();
+    /// assert_near!(plane.normal(), Vector3::unit_z());
+    /// assert!(deviation < 1.0e-10);
+    /// assert!(wire.is_planar(1.0e-3));
+    ///
+    /// // the square bent by `0.1` is planar relative to its size `1.0e6`
+    /// let v = builder::vertices([
+    ///     (0.0, 0.0, 0.0),
+    ///     (1.0e6, 0.0, 0.0),
+    ///     (1.0e6, 1.0e6, 0.1),
+    ///     (0.0, 1.0e6, 0.0),
+    /// ]);
+    /// let wire: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    /// assert!(wire.is_planar(ToleranceSpec::new(TOLERANCE, 1.0e-6)));
+    /// assert!(!wire.is_planar(1.0e-3));
+    /// ```
+    fn fit_plane(
+        &self,
+        tol: impl Into<ToleranceSpec>,
+    ) -> std::result::Result<(Plane, f64), NonPlanarError>;
+
+    /// Returns whether `self` is planar within `tol`, cf. [`FitPlane::fit_plane`].
+    #[inline(always)]
+    fn is_planar(&self, tol: impl Into<ToleranceSpec>) -> bool { self.fit_plane(tol).is_ok() }
+}
+
+impl<C> FitPlane for Wire<C>
+where C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>
+{
+    fn fit_plane(
+        &self,
+        tol: impl Into<ToleranceSpec>,
+    ) -> std::result::Result<(Plane, f64), NonPlanarError> {
+        fit_wires_plane(std::slice::from_ref(self), tol.into())
+    }
+}
+
+/// Fits the plane to the points sampled on all wires, cf. [`FitPlane::fit_plane`].
+pub(crate) fn fit_wires_plane<C>(
+    wires: &[Wire<C>],
+    spec: ToleranceSpec,
+) -> std::result::Result<(Plane, f64), NonPlanarError>
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+{
+    let vertices = wires.iter().flat_map(Wire::vertex_iter);
+    let scale = vertices.fold(0.0, |max, v| f64::max(max, v.point().tolerance_scale()));
+    let tol = spec.threshold(scale);
+    let pts: Vec<Vec<Point3>> = wires
+        .iter()
+        .map(|wire| {
+            wire.edge_iter()
+                .flat_map(|edge| {
+                    let curve = edge.curve();
+                    let (_, mut pts) = curve.parameter_division(curve.range_tuple(), tol);
+                    if !edge.orientation() {
+                        pts.reverse();
+                    }
+                    pts.pop();
+                    pts
+                })
+                .collect()
+        })
+        .collect();
+    match geom_impls::fit_plane(&pts) {
+        Some((plane, deviation)) if deviation <= tol => Ok((plane, deviation)),
+        Some((plane, deviation)) => Err(NonPlanarError::Deviation { plane, deviation }),
+        None => Err(NonPlanarError::Degenerate),
+    }
+}
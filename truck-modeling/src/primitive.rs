@@ -1,4 +1,8 @@
-use crate::builder;
+use crate::{
+    builder,
+    pipe::perpendicular,
+    roles::{CuboidFace, FaceRoles},
+};
 use std::f64::consts::PI;
 use truck_geometry::prelude::*;
 use truck_topology::*;
@@ -189,3 +193,176 @@ where
 
     Solid::new(vec![shell])
 }
+
+/// [`cuboid`] with the names of its faces, in the order of the faces of the solid: the bottom,
+/// the front, the right, the back, the left, and the top.
+/// # Example
+/// ```
+/// use truck_modeling::*;
+/// let bbd = BoundingBox::from_iter([Point3::new(-1.0, 2.0, -3.0), Point3::new(1.0, 5.0, 4.0)]);
+/// let (solid, roles) = primitive::cuboid_annotated(bbd);
+///
+/// let right = roles.select(&solid, CuboidFace::Right).next().unwrap();
+/// assert!(right.vertex_iter().all(|v| v.point().x == 1.0));
+/// let top = roles.select(&solid, CuboidFace::Top).next().unwrap();
+/// assert!(top.vertex_iter().all(|v| v.point().z == 4.0));
+/// ```
+pub fn cuboid_annotated(r#box: BoundingBox<Point3>) -> (crate::Solid, FaceRoles<CuboidFace>) {
+    use CuboidFace::*;
+    let solid = cuboid(r#box);
+    let roles = FaceRoles::new(&solid, [Bottom, Front, Right, Back, Left, Top]);
+    (solid, roles)
+}
+
+/// cylinder, defined by the center of the bottom, the radius, the axis and the height
+///
+/// The bottom and the top are planar disks, and the side is extruded from the circle of the
+/// bottom. The length of `axis` is ignored.
+/// # Example
+/// ```
+/// use truck_modeling::*;
+/// let center = Point3::new(1.0, -2.0, 0.5);
+/// let axis = Vector3::new(0.0, 0.0, 2.0);
+/// let solid: Solid = primitive::cylinder(center, 1.5, axis, 3.0);
+/// assert_eq!(solid.boundaries()[0].len(), 5);
+/// assert!(solid.is_geometric_consistent());
+///
+/// assert!(solid.contains_point(Point3::new(2.0, -2.0, 3.0)));
+/// assert!(!solid.contains_point(Point3::new(2.0, -2.0, 3.6)));
+/// assert!(!solid.contains_point(Point3::new(2.6, -2.0, 3.0)));
+/// ```
+pub fn cylinder<C, S>(
+    bottom_center: Point3,
+    radius: f64,
+    axis: Vector3,
+    height: f64,
+) -> Solid<Point3, C, S>
+where
+    C: Clone + Transformed<Matrix4>,
+    S: Clone + Transformed<Matrix4>,
+    Line<Point3>: ToSameGeometry<C>,
+    Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>: ToSameGeometry<C>,
+    ExtrudedCurve<C, Vector3>: ToSameGeometry<S>,
+    Plane: ToSameGeometry<S>, {
+    let axis = axis.normalize();
+    let x = perpendicular(axis);
+    let start = bottom_center + radius * x;
+    let wire = circle(start, bottom_center, axis, 3);
+    // the normal of the bottom is along the axis, so that the swept solid is outward
+    let another = bottom_center + radius * axis.cross(x);
+    let plane = Plane::new(bottom_center, start, another);
+    let disk = Face::new(vec![wire], plane.to_same_geometry());
+    builder::tsweep(&disk, height * axis)
+}
+
+/// cone, defined by the center of the bottom, the radius, the axis and the height
+///
+/// The bottom is a planar disk, and the side is revolved from the line from the apex to the
+/// circle of the bottom. The apex is a vertex shared by the side faces, i.e. the degenerate edges
+/// are removed as in [`builder::cone`]. The length of `axis` is ignored.
+/// # Example
+/// ```
+/// use std::collections::HashSet;
+/// use truck_modeling::*;
+/// let center = Point3::new(1.0, -2.0, 0.5);
+/// let axis = Vector3::new(0.0, 0.0, 2.0);
+/// let solid: Solid = primitive::cone(center, 1.5, axis, 3.0);
+/// assert_eq!(solid.boundaries()[0].len(), 4);
+/// assert!(solid.is_geometric_consistent());
+///
+/// // the apex and the three vertices on the circle
+/// let vertices: HashSet<VertexID> = solid.vertex_iter().map(|v| v.id()).collect();
+/// assert_eq!(vertices.len(), 4);
+/// assert!(solid.contains_point(Point3::new(1.5, -2.0, 1.5)));
+/// assert!(!solid.contains_point(Point3::new(2.0, -2.0, 2.5)));
+/// ```
+pub fn cone<C, S>(
+    bottom_center: Point3,
+    radius: f64,
+    axis: Vector3,
+    height: f64,
+) -> Solid<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve + Cut + Invertible + Transformed<Matrix4>,
+    S: Invertible,
+    Line<Point3>: ToSameGeometry<C>,
+    Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>: ToSameGeometry<C>,
+    RevolutedCurve<C>: ToSameGeometry<S>,
+    Plane: ToSameGeometry<S>, {
+    let axis = axis.normalize();
+    let x = perpendicular(axis);
+    let v = builder::vertices([bottom_center + height * axis, bottom_center + radius * x]);
+    let wire = wire![builder::line(&v[0], &v[1])];
+    let mut shell = builder::cone(&wire, axis, Rad(2.0 * PI));
+    // The side faces run along the circle counterclockwise around the axis.
+    let boundary = shell.extract_boundaries().pop().unwrap().inverse();
+    let another = bottom_center - radius * axis.cross(x);
+    let plane = Plane::new(bottom_center, v[1].point(), another);
+    shell.push(Face::new(vec![boundary], plane.to_same_geometry()));
+    Solid::new(vec![shell])
+}
+
+/// sphere, defined by the center and the radius
+///
+/// The side is revolved around the z-axis from the half circle from the north pole to the south
+/// pole. The poles are the vertices shared by the faces, i.e. the degenerate edges are removed as
+/// in [`builder::cone`].
+/// # Example
+/// ```
+/// use truck_modeling::*;
+/// let center = Point3::new(1.0, -2.0, 0.5);
+/// let solid: Solid = primitive::sphere(center, 2.0);
+/// assert_eq!(solid.boundaries()[0].shell_condition(), ShellCondition::Closed);
+/// assert!(solid.is_geometric_consistent());
+///
+/// assert!(solid.contains_point(Point3::new(2.0, -1.0, 1.5)));
+/// assert!(!solid.contains_point(Point3::new(2.5, -0.5, 2.0)));
+/// ```
+pub fn sphere<C, S>(center: Point3, radius: f64) -> Solid<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve + Cut + Invertible + Transformed<Matrix4>,
+    S: Invertible,
+    Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>: ToSameGeometry<C>,
+    RevolutedCurve<C>: ToSameGeometry<S>, {
+    let v = builder::vertex(center + radius * Vector3::unit_z());
+    let wire: Wire<Point3, C> = builder::rsweep(&v, center, Vector3::unit_y(), Rad(PI));
+    let shell = builder::cone(&wire, Vector3::unit_z(), Rad(2.0 * PI));
+    Solid::new(vec![shell])
+}
+
+/// torus, defined by the center, the radius of the center circle of the tube, the radius of
+/// the tube and the axis
+///
+/// The side is revolved around the axis from the circle of the tube. The length of `axis` is
+/// ignored.
+/// # Example
+/// ```
+/// use truck_modeling::*;
+/// let center = Point3::new(1.0, -2.0, 0.5);
+/// let solid: Solid = primitive::torus(center, 2.0, 0.5, Vector3::unit_z());
+/// assert_eq!(solid.boundaries()[0].shell_condition(), ShellCondition::Closed);
+/// assert!(solid.is_geometric_consistent());
+///
+/// assert!(solid.contains_point(Point3::new(3.0, -2.0, 0.5)));
+/// assert!(!solid.contains_point(center));
+/// ```
+pub fn torus<C, S>(
+    center: Point3,
+    large_radius: f64,
+    small_radius: f64,
+    axis: Vector3,
+) -> Solid<Point3, C, S>
+where
+    C: Clone + Transformed<Matrix4>,
+    S: Clone,
+    Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>: ToSameGeometry<C>,
+    RevolutedCurve<C>: ToSameGeometry<S>, {
+    let axis = axis.normalize();
+    let x = perpendicular(axis);
+    let tube_center = center + large_radius * x;
+    let v = builder::vertex(tube_center + small_radius * x);
+    // the circle turns from the outside to the bottom, so that the side is outward
+    let tube_axis = axis.cross(x);
+    let wire: Wire<Point3, C> = builder::rsweep(&v, tube_center, tube_axis, Rad(2.0 * PI));
+    Solid::new(vec![builder::rsweep(&wire, center, axis, Rad(2.0 * PI))])
+}
@@ -0,0 +1,197 @@
+use crate::*;
+use std::collections::HashMap;
+
+/// 2-dimensional vertex
+pub type Vertex2D = truck_topology::Vertex<Point2>;
+/// 2-dimensional edge
+pub type Edge2D = truck_topology::Edge<Point2, Curve2D>;
+/// 2-dimensional wire
+pub type Wire2D = truck_topology::Wire<Point2, Curve2D>;
+
+/// The orthographic projection of a wire into a plane.
+/// cf. [`ProjectToPlane`]
+#[derive(Clone, Debug)]
+pub struct PlanarProjection {
+    /// the projected wire in the coordinates of the plane
+    pub wire: Wire2D,
+    /// the edges projected to single points, which are dropped from `wire`
+    pub dropped: Vec<EdgeID>,
+    /// whether the projected wire intersects itself
+    pub self_intersecting: bool,
+}
+
+/// Projecting wires into planes, e.g. for the 2D sketches and the 2D boolean operations.
+pub trait ProjectToPlane {
+    /// Returns the orthographic projection of `self` into the plane through `origin` spanned by
+    /// `x_axis` and `y_axis`.
+    ///
+    /// The coordinates `(x, y)` of a point `p` are given by the decomposition
+    /// `p = origin + x * x_axis + y * y_axis + z * x_axis.cross(y_axis)`, i.e. the point is
+    /// projected along the normal of the plane. The axes need not be orthonormal.
+    ///
+    /// Lines, B-spline curves, NURBS curves and their trimmed curves are projected exactly by
+    /// their control points. The other curves are approximated by the polylines through the
+    /// points divided with the tolerance `tol`. The edges projected within `tol` of single points
+    /// are dropped and reported in [`PlanarProjection::dropped`], and their ends are merged into
+    /// one vertex. The self-intersections introduced by the projection are not resolved, only
+    /// reported in [`PlanarProjection::self_intersecting`].
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 1.0), (1.0, 1.0, 2.0)]);
+    /// let wire: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[2])].into();
+    ///
+    /// let (x_axis, y_axis) = (Vector3::unit_x(), Vector3::unit_y());
+    /// let projection = wire.project_to_plane_2d(Point3::origin(), x_axis, y_axis, TOLERANCE);
+    /// let points: Vec<Point2> = projection.wire.vertex_iter().map(|v| v.point()).collect();
+    /// let expected = [Point2::new(0.0, 0.0), Point2::new(1.0, 0.0), Point2::new(1.0, 1.0)];
+    /// assert_eq!(points, expected);
+    /// assert!(!projection.self_intersecting);
+    /// ```
+    /// # Panics
+    /// Panics if `x_axis` and `y_axis` are parallel.
+    fn project_to_plane_2d(
+        &self,
+        origin: Point3,
+        x_axis: Vector3,
+        y_axis: Vector3,
+        tol: f64,
+    ) -> PlanarProjection;
+}
+
+impl ProjectToPlane for Wire {
+    fn project_to_plane_2d(
+        &self,
+        origin: Point3,
+        x_axis: Vector3,
+        y_axis: Vector3,
+        tol: f64,
+    ) -> PlanarProjection {
+        let inv = Matrix3::from_cols(x_axis, y_axis, x_axis.cross(y_axis))
+            .invert()
+            .expect("The axes of the plane must not be parallel.");
+        let projection = Projection { origin, inv };
+        let (mut kept, mut dropped) = (Vec::new(), Vec::new());
+        self.edge_iter().for_each(|edge| {
+            let curve = projection.curve(&edge.curve(), tol);
+            let (_, points) = curve.parameter_division(curve.range_tuple(), tol);
+            let front = curve.front();
+            match points.iter().all(|p| p.distance(front) <= tol) {
+                true => dropped.push(edge),
+                false => kept.push((edge, curve)),
+            }
+        });
+
+        let mut parent = HashMap::<VertexID, VertexID>::new();
+        dropped.iter().for_each(|edge| {
+            let root0 = root(&parent, edge.front().id());
+            let root1 = root(&parent, edge.back().id());
+            if root0 != root1 {
+                parent.insert(root1, root0);
+            }
+        });
+        let mut vertices = HashMap::<VertexID, Vertex2D>::new();
+        let mut vertex = |v: &Vertex| {
+            let entry = vertices.entry(root(&parent, v.id()));
+            entry.or_insert_with(|| Vertex2D::new(projection.point(v.point()))).clone()
+        };
+        let wire: Wire2D = kept
+            .into_iter()
+            .map(|(edge, curve)| {
+                let v0 = vertex(edge.absolute_front());
+                let mut v1 = vertex(edge.absolute_back());
+                // the projection of a closed edge is kept open, since edges must not be loops.
+                if v0 == v1 {
+                    v1 = Vertex2D::new(projection.point(edge.absolute_back().point()));
+                }
+                let edge2d = Edge2D::new(&v0, &v1, curve);
+                match edge.orientation() {
+                    true => edge2d,
+                    false => edge2d.inverse(),
+                }
+            })
+            .collect();
+        PlanarProjection {
+            self_intersecting: self_intersecting(&wire, tol),
+            dropped: dropped.into_iter().map(|edge| edge.id()).collect(),
+            wire,
+        }
+    }
+}
+
+/// the orthographic projection into a plane
+struct Projection {
+    origin: Point3,
+    inv: Matrix3,
+}
+
+impl Projection {
+    fn vector(&self, vector: Vector3) -> Vector2 { (self.inv * vector).truncate() }
+    fn point(&self, point: Point3) -> Point2 { Point2::from_vec(self.vector(point - self.origin)) }
+    fn homogeneous(&self, v: Vector4) -> Vector3 {
+        self.vector(v.truncate() - self.origin.to_vec() * v.w).extend(v.w)
+    }
+
+    fn curve(&self, curve: &Curve, tol: f64) -> Curve2D {
+        match curve {
+            Curve::Line(Line(p, q)) => Line(self.point(*p), self.point(*q)).into(),
+            Curve::BSplineCurve(curve) => {
+                let control_points = curve.control_points().iter();
+                let control_points = control_points.map(|p| self.point(*p)).collect();
+                BSplineCurve::new(curve.knot_vec().clone(), control_points).into()
+            }
+            Curve::NurbsCurve(curve) => {
+                let curve = curve.non_rationalized();
+                let control_points = curve.control_points().iter();
+                let control_points = control_points.map(|v| self.homogeneous(*v)).collect();
+                let curve = BSplineCurve::new(curve.knot_vec().clone(), control_points);
+                NurbsCurve::new(curve).into()
+            }
+            Curve::TrimmedCurve(trimmed) => match self.curve(trimmed.curve(), tol) {
+                Curve2D::Line(_) => {
+                    let (p, q) = (self.point(curve.front()), self.point(curve.back()));
+                    Line(p, q).into()
+                }
+                Curve2D::BSplineCurve(curve) => curve.subcurve(trimmed.range()).into(),
+                Curve2D::NurbsCurve(curve) => curve.subcurve(trimmed.range()).into(),
+            },
+            Curve::IntersectionCurve(_) | Curve::ReparametrizedCurve(_) => {
+                let (params, points) = curve.parameter_division(curve.range_tuple(), tol);
+                let knots = std::iter::once(params[0])
+                    .chain(params.iter().copied())
+                    .chain(std::iter::once(params[params.len() - 1]));
+                let control_points = points.into_iter().map(|p| self.point(p)).collect();
+                BSplineCurve::new(knots.collect(), control_points).into()
+            }
+        }
+    }
+}
+
+fn root(parent: &HashMap<VertexID, VertexID>, mut id: VertexID) -> VertexID {
+    while let Some(next) = parent.get(&id) {
+        id = *next;
+    }
+    id
+}
+
+/// Whether the polyline dividing `wire` has two crossing segments which are not adjacent.
+fn self_intersecting(wire: &Wire2D, tol: f64) -> bool {
+    let mut polyline = Vec::<Point2>::new();
+    wire.edge_iter().for_each(|edge| {
+        let curve = edge.oriented_curve();
+        let (_, points) = curve.parameter_division(curve.range_tuple(), tol);
+        let skip = usize::from(!polyline.is_empty());
+        polyline.extend(points.into_iter().skip(skip));
+    });
+    let closed = wire.is_closed();
+    let segments: Vec<Line<Point2>> = polyline.windows(2).map(|p| Line(p[0], p[1])).collect();
+    let n = segments.len();
+    (0..n).any(|i| {
+        ((i + 2)..n)
+            .filter(|&j| !(closed && i == 0 && j == n - 1))
+            .any(|j| match segments[i].intersection(segments[j]) {
+                Some((s, t, _)) => (0.0..=1.0).contains(&s) && (0.0..=1.0).contains(&t),
+                None => false,
+            })
+    })
+}
@@ -0,0 +1,349 @@
+use crate::ray_casting::{face_domain, FaceDomain};
+use crate::*;
+use algo::surface::SurfaceProximity;
+use std::collections::HashSet;
+
+/// The closest point on a wire.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WireProximity {
+    /// the index of the edge in the wire
+    pub edge_index: usize,
+    /// the id of the edge
+    pub edge_id: EdgeID,
+    /// the parameter of the curve of the edge, i.e. [`Edge::curve`]
+    pub parameter: f64,
+    /// the closest point
+    pub point: Point3,
+    /// the distance from the query point to the closest point
+    pub distance: f64,
+}
+
+/// The closest point on the edges of a shell.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EdgeProximity {
+    /// the id of the edge
+    pub edge_id: EdgeID,
+    /// the parameter of the curve of the edge, i.e. [`Edge::curve`]
+    pub parameter: f64,
+    /// the closest point
+    pub point: Point3,
+    /// the distance from the query point to the closest point
+    pub distance: f64,
+}
+
+/// Searches the closest point on a wire.
+pub trait ClosestPoint {
+    /// Returns the closest point on the edges of `self`, `None` if `self` has no edges.
+    ///
+    /// If the point is equidistant to several edges, the edge with the lowest index is adopted.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0)]);
+    /// let wire: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[2])].into();
+    ///
+    /// let prox = wire.closest_point(Point3::new(1.5, 0.5, 0.0)).unwrap();
+    /// assert_eq!(prox.edge_index, 1);
+    /// assert_near!(prox.point, Point3::new(1.0, 0.5, 0.0));
+    /// assert_near!(prox.distance, 0.5);
+    /// ```
+    fn closest_point(&self, point: Point3) -> Option<WireProximity>;
+}
+
+/// Searches the closest edge of a shell.
+pub trait ClosestEdge {
+    /// Returns the closest point on the edges of `self`, `None` if `self` has no edges.
+    ///
+    /// If the point is equidistant to several edges, the first one in [`Shell::edge_iter`] is
+    /// adopted.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    /// let shell = &cube.boundaries()[0];
+    ///
+    /// let prox = shell.closest_edge(Point3::new(0.5, -1.0, -1.0)).unwrap();
+    /// assert_near!(prox.point, Point3::new(0.5, 0.0, 0.0));
+    /// assert_near!(prox.distance, f64::sqrt(2.0));
+    /// ```
+    fn closest_edge(&self, point: Point3) -> Option<EdgeProximity>;
+}
+
+/// Classifies the relation between two faces.
+pub trait FaceProximity {
+    /// Returns the relation between the faces by [`algo::surface::proximity`], restricted to
+    /// the trimmed regions.
+    ///
+    /// The seed points and the contact samples out of the faces are removed. If the closest
+    /// points of the surfaces are out of the faces, the distance is approximated by
+    /// the boundaries.
+    ///
+    /// # Failures
+    /// Returns [`Error::ParameterSearchFailed`](crate::errors::Error::ParameterSearchFailed) with
+    /// the face if the boundaries cannot be projected to the surfaces.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// use algo::surface::SurfaceProximity;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    /// let faces: Vec<&Face> = cube.face_iter().collect();
+    ///
+    /// // the bottom and the top
+    /// match faces[0].proximity(faces[5], TOLERANCE).unwrap() {
+    ///     SurfaceProximity::Disjoint { distance, .. } => assert_near!(distance, 1.0),
+    ///     res => panic!("{res:?}"),
+    /// }
+    /// ```
+    fn proximity(&self, other: &Self, tol: f64) -> Result<SurfaceProximity>;
+}
+
+impl ClosestPoint for Wire {
+    fn closest_point(&self, point: Point3) -> Option<WireProximity> {
+        let (edge_index, edge, proximity) = closest(self.edge_iter().cloned(), point)?;
+        Some(WireProximity {
+            edge_index,
+            edge_id: edge.id(),
+            parameter: proximity.parameter,
+            point: proximity.point,
+            distance: proximity.distance,
+        })
+    }
+}
+
+impl ClosestEdge for Shell {
+    fn closest_edge(&self, point: Point3) -> Option<EdgeProximity> {
+        let mut ids = HashSet::new();
+        let edges = self.edge_iter().filter(|edge| ids.insert(edge.id()));
+        let (_, edge, proximity) = closest(edges, point)?;
+        Some(EdgeProximity {
+            edge_id: edge.id(),
+            parameter: proximity.parameter,
+            point: proximity.point,
+            distance: proximity.distance,
+        })
+    }
+}
+
+impl FaceProximity for Face {
+    fn proximity(&self, other: &Face, tol: f64) -> Result<SurfaceProximity> {
+        let (domain0, domain1) = (face_domain(self, tol)?, face_domain(other, tol)?);
+        let (surface0, surface1) = (self.surface(), other.surface());
+        let res = algo::surface::proximity(
+            &surface0,
+            &surface1,
+            uv_range(&domain0),
+            uv_range(&domain1),
+            tol,
+        );
+        let on_faces = |point: &Point3| {
+            on_face(&surface0, &domain0, *point, tol) && on_face(&surface1, &domain1, *point, tol)
+        };
+        match res {
+            SurfaceProximity::Intersecting { mut seed_points } => {
+                seed_points.retain(on_faces);
+                if !seed_points.is_empty() {
+                    return Ok(SurfaceProximity::Intersecting { seed_points });
+                }
+            }
+            SurfaceProximity::Tangential {
+                mut contact_samples,
+            } => {
+                contact_samples.retain(on_faces);
+                if !contact_samples.is_empty() {
+                    return Ok(SurfaceProximity::Tangential { contact_samples });
+                }
+            }
+            SurfaceProximity::Disjoint {
+                distance,
+                witness_pair: (p, q),
+            } => {
+                if on_face(&surface0, &domain0, p, tol) && on_face(&surface1, &domain1, q, tol) {
+                    let witness_pair = (p, q);
+                    return Ok(SurfaceProximity::Disjoint {
+                        distance,
+                        witness_pair,
+                    });
+                }
+            }
+        }
+        let (distance, witness_pair) =
+            boundary_distance((&surface0, &domain0), (&surface1, &domain1), tol);
+        Ok(SurfaceProximity::Disjoint {
+            distance,
+            witness_pair,
+        })
+    }
+}
+
+const SNP_TRIALS: usize = 100;
+
+#[derive(Clone, Copy, Debug)]
+struct Proximity {
+    parameter: f64,
+    point: Point3,
+    distance: f64,
+}
+
+/// Returns the distance from `point` to `bdb`, a lower bound of the distances to the curve.
+fn box_distance(bdb: BoundingBox<Point3>, point: Point3) -> f64 {
+    let (min, max) = (bdb.min(), bdb.max());
+    let excess = |i: usize| f64::max(f64::max(min[i] - point[i], point[i] - max[i]), 0.0);
+    Vector3::new(excess(0), excess(1), excess(2)).magnitude()
+}
+
+/// Returns the closest point on the curve of `edge`, including the end vertices.
+fn edge_proximity(edge: &Edge, point: Point3) -> Proximity {
+    let curve = edge.curve();
+    let (t0, t1) = curve.range_tuple();
+    let proximity = |parameter: f64, pt: Point3| Proximity {
+        parameter,
+        point: pt,
+        distance: pt.distance(point),
+    };
+    // The minimizer may be an end vertex, which Newton's method does not reach reliably.
+    let front = proximity(t0, edge.absolute_front().point());
+    let back = proximity(t1, edge.absolute_back().point());
+    let mut res = match back.distance < front.distance {
+        true => back,
+        false => front,
+    };
+    let t = curve.search_nearest_parameter(point, SPHint1D::Range(t0, t1), SNP_TRIALS);
+    if let Some(t) = t.filter(|t| t0 <= *t && *t <= t1) {
+        let inner = proximity(t, curve.subs(t));
+        if inner.distance < res.distance - TOLERANCE {
+            res = inner;
+        }
+    }
+    res
+}
+
+/// Returns the closest edge with its index in `edges`, by best-first search on the cached
+/// bounding boxes. The ties are broken by the lowest index.
+fn closest(edges: impl Iterator<Item = Edge>, point: Point3) -> Option<(usize, Edge, Proximity)> {
+    let mut candidates: Vec<_> = edges
+        .enumerate()
+        .map(|(i, edge)| (box_distance(edge.cached_bounding_box(), point), i, edge))
+        .collect();
+    candidates.sort_by(|x, y| x.0.total_cmp(&y.0).then(x.1.cmp(&y.1)));
+    let mut res: Option<(usize, Edge, Proximity)> = None;
+    for (lower_bound, i, edge) in candidates {
+        if let Some((_, _, best)) = &res {
+            if lower_bound > best.distance + TOLERANCE {
+                break;
+            }
+        }
+        let proximity = edge_proximity(&edge, point);
+        let better = match &res {
+            None => true,
+            Some((best_index, _, best)) => {
+                proximity.distance < best.distance - TOLERANCE
+                    || (proximity.distance <= best.distance + TOLERANCE && i < *best_index)
+            }
+        };
+        if better {
+            res = Some((i, edge, proximity));
+        }
+    }
+    res
+}
+
+fn uv_range(domain: &FaceDomain) -> ((f64, f64), (f64, f64)) {
+    let (min, max) = (domain.uv_range.min(), domain.uv_range.max());
+    ((min.x, max.x), (min.y, max.y))
+}
+
+/// whether `point` is on the surface in the face
+fn on_face(surface: &Surface, domain: &FaceDomain, point: Point3, tol: f64) -> bool {
+    let Some((u, v)) = surface.search_nearest_parameter(point, None, SNP_TRIALS) else {
+        return false;
+    };
+    surface.subs(u, v).distance(point) <= tol && domain.includes(Point2::new(u, v), point, tol)
+}
+
+/// Returns the closest points on two segments.
+fn segments_closest_points(
+    (p0, p1): (Point3, Point3),
+    (q0, q1): (Point3, Point3),
+) -> (Point3, Point3) {
+    let (d0, d1, r) = (p1 - p0, q1 - q0, p0 - q0);
+    let (a, e, f) = (d0.magnitude2(), d1.magnitude2(), d1.dot(r));
+    let (s, t) = if a.so_small2() && e.so_small2() {
+        (0.0, 0.0)
+    } else if a.so_small2() {
+        (0.0, f64::clamp(f / e, 0.0, 1.0))
+    } else {
+        let c = d0.dot(r);
+        if e.so_small2() {
+            (f64::clamp(-c / a, 0.0, 1.0), 0.0)
+        } else {
+            let b = d0.dot(d1);
+            let denom = a * e - b * b;
+            let s = match denom.so_small2() {
+                true => 0.0,
+                false => f64::clamp((b * f - c * e) / denom, 0.0, 1.0),
+            };
+            let t = (b * s + f) / e;
+            if t < 0.0 {
+                (f64::clamp(-c / a, 0.0, 1.0), 0.0)
+            } else if t > 1.0 {
+                (f64::clamp((b - c) / a, 0.0, 1.0), 1.0)
+            } else {
+                (s, t)
+            }
+        }
+    };
+    (p0 + d0 * s, q0 + d1 * t)
+}
+
+fn segments(domain: &FaceDomain) -> impl Iterator<Item = (Point3, Point3)> + '_ {
+    domain
+        .points
+        .iter()
+        .flat_map(|pts| pts.iter().copied().zip(pts.iter().copied().cycle().skip(1)))
+}
+
+/// Returns the approximate distance between the faces by the boundaries: the distances between
+/// the boundaries, and the distances from the boundaries to the other faces.
+fn boundary_distance(
+    (surface0, domain0): (&Surface, &FaceDomain),
+    (surface1, domain1): (&Surface, &FaceDomain),
+    tol: f64,
+) -> (f64, (Point3, Point3)) {
+    let mut best = (f64::INFINITY, (Point3::origin(), Point3::origin()));
+    let mut update = |(p, q): (Point3, Point3)| {
+        if p.distance(q) < best.0 {
+            best = (p.distance(q), (p, q));
+        }
+    };
+    for seg0 in segments(domain0) {
+        segments(domain1).for_each(|seg1| update(segments_closest_points(seg0, seg1)));
+    }
+    let projection = |surface: &Surface, domain: &FaceDomain, point: Point3| {
+        let (u, v) = surface.search_nearest_parameter(point, None, SNP_TRIALS)?;
+        let proj = surface.subs(u, v);
+        match domain.includes(Point2::new(u, v), proj, tol) {
+            true => Some(proj),
+            false => None,
+        }
+    };
+    for p in domain0.points.iter().flatten() {
+        if let Some(q) = projection(surface1, domain1, *p) {
+            update((*p, q));
+        }
+    }
+    for q in domain1.points.iter().flatten() {
+        if let Some(p) = projection(surface0, domain0, *q) {
+            update((p, *q));
+        }
+    }
+    best
+}
@@ -0,0 +1,382 @@
+use crate::{errors::Error, *};
+use truck_topology::uv_loop::UvLoop;
+
+/// An intersection of a ray and a face of a solid.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit {
+    /// the parameter of the ray, i.e. the intersection is `origin + t * dir`
+    pub t: f64,
+    /// the id of the face
+    pub face: FaceID,
+    /// the parameter of the surface of the face, i.e. [`Face::surface`]
+    pub uv: (f64, f64),
+    /// the intersection point
+    pub point: Point3,
+    /// whether the ray enters the solid, i.e. the dot product of the normal and `dir` is negative
+    pub entering: bool,
+}
+
+/// Casts rays to solids.
+pub trait RayCasting {
+    /// Returns all intersections of the ray from `origin` in the direction `dir` with the faces,
+    /// sorted by `t`. The intersections behind `origin` are not included.
+    ///
+    /// The intersections whose distance is within `tol` are merged. A group of merged
+    /// intersections is replaced by its first entering (resp. exiting) one if the entering
+    /// (resp. exiting) ones are the majority, and removed if tie. Hence, the ray passing through
+    /// an edge is counted once, and the ray grazing a surface or a silhouette edge is not counted.
+    /// The faces whose boundaries cannot be projected to their surfaces are not hit.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let hits = cube.ray_hits(Point3::new(-1.0, 0.5, 0.5), Vector3::unit_x(), TOLERANCE);
+    /// assert_eq!(hits.len(), 2);
+    /// assert_near!(hits[0].t, 1.0);
+    /// assert!(hits[0].entering);
+    /// assert_near!(hits[1].point, Point3::new(1.0, 0.5, 0.5));
+    /// assert!(!hits[1].entering);
+    /// ```
+    fn ray_hits(&self, origin: Point3, dir: Vector3, tol: f64) -> Vec<RayHit>;
+
+    /// Returns whether `point` is in `self`, by the signed count of the intersections of a ray.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// assert!(cube.contains_point(Point3::new(0.5, 0.5, 0.5)));
+    /// assert!(!cube.contains_point(Point3::new(1.5, 0.5, 0.5)));
+    /// ```
+    fn contains_point(&self, point: Point3) -> bool {
+        // a generic direction, in order to avoid passing through edges and vertices
+        let dir = Vector3::new(0.5377, 0.1831, 0.8230).normalize();
+        let count = self
+            .ray_hits(point, dir, TOLERANCE)
+            .into_iter()
+            .fold(0_isize, |count, hit| match hit.entering {
+                true => count - 1,
+                false => count + 1,
+            });
+        count > 0
+    }
+}
+
+impl RayCasting for Solid {
+    fn ray_hits(&self, origin: Point3, dir: Vector3, tol: f64) -> Vec<RayHit> {
+        faces_ray_hits(self.face_iter(), origin, dir, tol)
+    }
+}
+
+/// Returns the intersections of the ray and the faces, in the same way as [`RayCasting::ray_hits`].
+pub(crate) fn faces_ray_hits<'a>(
+    faces: impl Iterator<Item = &'a Face>,
+    origin: Point3,
+    dir: Vector3,
+    tol: f64,
+) -> Vec<RayHit> {
+    let mut hits: Vec<RayHit> = faces
+        .flat_map(|face| face_hits(face, origin, dir, tol))
+        .collect();
+    hits.sort_by(|x, y| x.t.total_cmp(&y.t));
+    merge_hits(hits, dir.magnitude(), tol)
+}
+
+const SNP_TRIALS: usize = 100;
+const NEWTON_TRIALS: usize = 100;
+const MESH_RELATIVE_TOLERANCE: f64 = 1.0e-2;
+const BARYCENTRIC_MARGIN: f64 = 0.1;
+
+/// The boundaries of a face, approximated by polylines in the space and in the parameter space.
+#[derive(Clone, Debug)]
+pub(crate) struct FaceDomain {
+    pub(crate) points: Vec<Vec<Point3>>,
+    pub(crate) uvs: Vec<Vec<Point2>>,
+    pub(crate) uv_range: BoundingBox<Point2>,
+    loops: Vec<UvLoop>,
+}
+
+/// Moves `x` by the multiple of `period` to the nearest to `hint`.
+fn unwrap_period(x: f64, hint: f64, period: Option<f64>) -> f64 {
+    match period {
+        Some(period) => x + period * f64::round((hint - x) / period),
+        None => x,
+    }
+}
+
+pub(crate) fn unwrap_uv(uv: Point2, hint: Point2, periods: (Option<f64>, Option<f64>)) -> Point2 {
+    Point2::new(
+        unwrap_period(uv.x, hint.x, periods.0),
+        unwrap_period(uv.y, hint.y, periods.1),
+    )
+}
+
+/// Returns the boundaries of the face in the parameter space.
+///
+/// # Failures
+/// Returns [`Error::ParameterSearchFailed`] with the face if a point of the boundaries cannot be
+/// projected to the surface.
+pub(crate) fn face_domain(face: &Face, tol: f64) -> Result<FaceDomain> {
+    let surface = face.surface();
+    let periods = (surface.u_period(), surface.v_period());
+    let mut hint: Option<Point2> = None;
+    let (mut points, mut uvs) = (Vec::new(), Vec::new());
+    for wire in face.absolute_boundaries() {
+        let (mut wire_points, mut wire_uvs) = (Vec::new(), Vec::new());
+        for edge in wire.edge_iter() {
+            let curve = edge.oriented_curve();
+            let (_, mut pts) = curve.parameter_division(curve.range_tuple(), tol);
+            pts.pop();
+            for pt in pts {
+                let uv = surface
+                    .search_parameter(pt, hint.map(Into::into), SNP_TRIALS)
+                    .or_else(|| surface.search_nearest_parameter(pt, None, SNP_TRIALS))
+                    .ok_or_else(|| Error::ParameterSearchFailed {
+                        entity: EntityId::Face(face.id()),
+                        point: pt,
+                        hint: hint.map(Into::into),
+                        surface_kind: surface.kind(),
+                    })?;
+                let uv = Point2::from(uv);
+                let uv = hint.map_or(uv, |hint| unwrap_uv(uv, hint, periods));
+                hint = Some(uv);
+                wire_points.push(pt);
+                wire_uvs.push(uv);
+            }
+        }
+        let (wire_points, wire_uvs) = unfold_poles(&surface, &wire_points, &wire_uvs);
+        points.push(wire_points);
+        uvs.push(wire_uvs);
+    }
+    let uv_range = uvs.iter().flatten().collect();
+    let (urange, vrange) = surface.try_range_tuple();
+    let origin = Point2::new(urange.map_or(0.0, |r| r.0), vrange.map_or(0.0, |r| r.0));
+    let loops = uvs
+        .iter()
+        .map(|uvs| UvLoop::new(uvs.iter().copied(), periods, origin))
+        .collect();
+    Ok(FaceDomain {
+        points,
+        uvs,
+        uv_range,
+        loops,
+    })
+}
+
+/// Inserts the steps along the collapsed sides of the surface into the closed boundary, since the
+/// parameter of a pole is not unique, e.g. the center of a disk made by revolving a segment.
+fn unfold_poles(
+    surface: &Surface,
+    points: &[Point3],
+    uvs: &[Point2],
+) -> (Vec<Point3>, Vec<Point2>) {
+    let collapsed = |uv: Point2| {
+        let (u, v) = (uv.x, uv.y);
+        (surface.uder(u, v).so_small(), surface.vder(u, v).so_small())
+    };
+    let (mut res_points, mut res_uvs) = (Vec::new(), Vec::new());
+    for i in 0..uvs.len() {
+        let j = (i + 1) % uvs.len();
+        let (uv0, uv1) = (uvs[i], uvs[j]);
+        res_points.push(points[i]);
+        res_uvs.push(uv0);
+        let ((u0, v0), (u1, v1)) = (collapsed(uv0), collapsed(uv1));
+        // the parameter changes along the collapsed side at the start or at the end
+        let step = if uv0.x.near(&uv1.x) || uv0.y.near(&uv1.y) {
+            None
+        } else if u0 {
+            Some((Point2::new(uv1.x, uv0.y), points[i]))
+        } else if v0 {
+            Some((Point2::new(uv0.x, uv1.y), points[i]))
+        } else if u1 {
+            Some((Point2::new(uv0.x, uv1.y), points[j]))
+        } else if v1 {
+            Some((Point2::new(uv1.x, uv0.y), points[j]))
+        } else {
+            None
+        };
+        if let Some((uv, point)) = step {
+            res_points.push(point);
+            res_uvs.push(uv);
+        }
+    }
+    (res_points, res_uvs)
+}
+
+/// Returns the winding number of the closed polyline `polygon` around `uv`.
+pub(crate) fn winding_number(polygon: &[Point2], uv: Point2) -> isize {
+    let closed = polygon.iter().zip(polygon.iter().cycle().skip(1));
+    closed.fold(0, |count, (p, q)| {
+        let cross = (q - p).perp_dot(uv - p);
+        match (p.y <= uv.y, q.y <= uv.y) {
+            (true, false) if cross > 0.0 => count + 1,
+            (false, true) if cross < 0.0 => count - 1,
+            _ => count,
+        }
+    })
+}
+
+pub(crate) fn segment_distance(p: Point3, q: Point3, point: Point3) -> f64 {
+    let vec = q - p;
+    let t = match vec.magnitude2().so_small2() {
+        true => 0.0,
+        false => f64::clamp((point - p).dot(vec) / vec.magnitude2(), 0.0, 1.0),
+    };
+    point.distance(p + vec * t)
+}
+
+impl FaceDomain {
+    /// whether the parameter `uv` is in the face by the winding numbers of the boundaries, without
+    /// the margin of [`FaceDomain::includes`].
+    pub(crate) fn strictly_includes(&self, uv: Point2) -> bool {
+        let winding: isize = self.loops.iter().map(|l| l.winding_number(uv)).sum();
+        winding != 0
+    }
+
+    /// whether the parameter `uv` is in the face, or the point is within `tol` from the boundaries.
+    /// The parameter may be in any copy of the fundamental domain of the periodic surface.
+    pub(crate) fn includes(&self, uv: Point2, point: Point3, tol: f64) -> bool {
+        self.strictly_includes(uv)
+            || self.points.iter().any(|pts| {
+                let mut closed = pts.iter().zip(pts.iter().cycle().skip(1));
+                closed.any(|(p, q)| segment_distance(*p, *q, point) < tol)
+            })
+    }
+}
+
+/// Returns the parameters of the intersection of the triangle and the line, with the barycentric
+/// coordinates, by the Möller-Trumbore algorithm.
+fn triangle_intersection(
+    triangle: [Point3; 3],
+    origin: Point3,
+    dir: Vector3,
+) -> Option<(f64, f64, f64)> {
+    let (a, b) = (triangle[1] - triangle[0], triangle[2] - triangle[0]);
+    let mat = Matrix3::from_cols(a, b, -dir);
+    let (s, t, r) = mat.invert().map(|inv| inv * (origin - triangle[0])).map(Into::into)?;
+    let margin = -BARYCENTRIC_MARGIN;
+    match s >= margin && t >= margin && s + t <= 1.0 - margin {
+        true => Some((s, t, r)),
+        false => None,
+    }
+}
+
+/// Solves `surface(u, v) = origin + t * dir` by Newton's method.
+fn newton_intersection(
+    surface: &Surface,
+    origin: Point3,
+    dir: Vector3,
+    hint: (f64, f64, f64),
+) -> Option<(f64, f64, f64)> {
+    let mut uvt = Vector3::from(hint);
+    for _ in 0..NEWTON_TRIALS {
+        let (u, v, t) = uvt.into();
+        let residual = surface.subs(u, v) - (origin + dir * t);
+        let jacobian = Matrix3::from_cols(surface.uder(u, v), surface.vder(u, v), -dir);
+        let delta = jacobian.invert()? * residual;
+        uvt -= delta;
+        if delta.so_small() {
+            let (u, v, t) = uvt.into();
+            let residual = surface.subs(u, v) - (origin + dir * t);
+            return match residual.so_small() {
+                true => Some(uvt.into()),
+                false => None,
+            };
+        }
+    }
+    None
+}
+
+fn face_hits(face: &Face, origin: Point3, dir: Vector3, tol: f64) -> Vec<RayHit> {
+    let Ok(domain) = face_domain(face, tol) else {
+        return Vec::new();
+    };
+    let surface = face.surface();
+    let (min, max) = (domain.uv_range.min(), domain.uv_range.max());
+    let diameter = domain.points.iter().flatten().collect::<BoundingBox<_>>().diameter();
+    let mesh_tol = f64::max(diameter * MESH_RELATIVE_TOLERANCE, tol);
+    let (us, vs) = surface.parameter_division(((min.x, max.x), (min.y, max.y)), mesh_tol);
+    let grid: Vec<Vec<Point3>> = us
+        .iter()
+        .map(|u| vs.iter().map(|v| surface.subs(*u, *v)).collect())
+        .collect();
+
+    let mut hits: Vec<RayHit> = Vec::new();
+    for i in 1..us.len() {
+        for j in 1..vs.len() {
+            let corners = [(i - 1, j - 1), (i, j - 1), (i, j), (i - 1, j)];
+            for (c0, c1, c2) in [(0, 1, 2), (0, 2, 3)] {
+                let [k0, k1, k2] = [corners[c0], corners[c1], corners[c2]];
+                let triangle = [grid[k0.0][k0.1], grid[k1.0][k1.1], grid[k2.0][k2.1]];
+                let Some((s, t, r)) = triangle_intersection(triangle, origin, dir) else {
+                    continue;
+                };
+                let param = |k: (usize, usize)| Vector2::new(us[k.0], vs[k.1]);
+                let uv0 = param(k0) + (param(k1) - param(k0)) * s + (param(k2) - param(k0)) * t;
+                let hint = (uv0.x, uv0.y, r);
+                // the iterations may go beyond the domain, the solution is checked below
+                let newton = || newton_intersection(&surface, origin, dir, hint);
+                let Some((u, v, t)) = with_extrapolation(newton) else {
+                    continue;
+                };
+                let point = surface.subs(u, v);
+                if t * dir.magnitude() < -tol || !domain.includes(Point2::new(u, v), point, tol) {
+                    continue;
+                }
+                let normal = match face.orientation() {
+                    true => surface.normal(u, v),
+                    false => -surface.normal(u, v),
+                };
+                let hit = RayHit {
+                    t,
+                    face: face.id(),
+                    uv: (u, v),
+                    point,
+                    entering: normal.dot(dir) < 0.0,
+                };
+                // the same intersection found from the adjacent triangles
+                let duplicated = hits.iter().any(|x| {
+                    x.entering == hit.entering && x.point.distance(hit.point) <= tol
+                });
+                if !duplicated {
+                    hits.push(hit);
+                }
+            }
+        }
+    }
+    hits
+}
+
+/// Merges the sorted hits whose distance is within `tol`.
+fn merge_hits(hits: Vec<RayHit>, dir_length: f64, tol: f64) -> Vec<RayHit> {
+    let mut groups: Vec<Vec<RayHit>> = Vec::new();
+    for hit in hits {
+        match groups.last_mut() {
+            Some(group) if (hit.t - group[group.len() - 1].t) * dir_length <= tol => {
+                group.push(hit)
+            }
+            _ => groups.push(vec![hit]),
+        }
+    }
+    groups
+        .into_iter()
+        .filter_map(|group| {
+            let entering = group.iter().filter(|hit| hit.entering).count();
+            let exiting = group.len() - entering;
+            match entering.cmp(&exiting) {
+                std::cmp::Ordering::Greater => group.into_iter().find(|hit| hit.entering),
+                std::cmp::Ordering::Less => group.into_iter().find(|hit| !hit.entering),
+                std::cmp::Ordering::Equal => None,
+            }
+        })
+        .collect()
+}
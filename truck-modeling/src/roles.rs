@@ -0,0 +1,156 @@
+use crate::*;
+use std::collections::HashMap;
+
+/// The role of a face of the solid made by [`builder::tsweep_annotated`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SweepRole {
+    /// the swept face, inverted
+    Bottom,
+    /// the translated copy of the swept face
+    Top,
+    /// the face swept from an edge of the boundaries of the swept face
+    Side {
+        /// the index of the edge, counted through all boundaries of the swept face in order
+        source_edge_index: usize,
+    },
+}
+
+/// The role of a face of the solid made by [`builder::rsweep_annotated`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RevolutionRole {
+    /// the swept face, inverted. The whole revolution has no start face.
+    Start,
+    /// the rotated copy of the swept face. The whole revolution has no end face.
+    End,
+    /// one of the faces swept from an edge of the boundaries of the swept face
+    Lateral {
+        /// the index of the edge, counted through all boundaries of the swept face in order
+        source_edge_index: usize,
+    },
+}
+
+/// The face of the cuboid made by [`primitive::cuboid_annotated`], named by the direction of
+/// the outer normal in the coordinates of the bounding box.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CuboidFace {
+    /// the face at the minimum `z`
+    Bottom,
+    /// the face at the minimum `y`
+    Front,
+    /// the face at the maximum `x`
+    Right,
+    /// the face at the maximum `y`
+    Back,
+    /// the face at the minimum `x`
+    Left,
+    /// the face at the maximum `z`
+    Top,
+}
+
+/// The faces of a solid tagged by their roles in the construction of the solid.
+///
+/// The roles are assigned in the order of the faces of the solid, so they are recovered for
+/// the copies made by [`Mapped`], e.g. [`builder::transformed`], by [`FaceRoles::transferred`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FaceRoles<R> {
+    faces: Vec<(R, FaceID)>,
+}
+
+/// The roles of the faces of the solid made by [`builder::tsweep_annotated`].
+pub type SweepRoles = FaceRoles<SweepRole>;
+/// The roles of the faces of the solid made by [`builder::rsweep_annotated`].
+pub type RevolutionRoles = FaceRoles<RevolutionRole>;
+
+impl<R: Copy + PartialEq> FaceRoles<R> {
+    /// Tags the faces of `solid` in order.
+    pub(crate) fn new(solid: &Solid, roles: impl IntoIterator<Item = R>) -> Self {
+        let faces = roles.into_iter().zip(solid.face_iter().map(Face::id)).collect();
+        Self { faces }
+    }
+
+    /// Returns the number of the tagged faces.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.faces.len() }
+
+    /// Returns whether no face is tagged.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.faces.is_empty() }
+
+    /// Returns the iterator over the roles and the ids of the faces, in the order of the faces
+    /// of the solid.
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = (R, FaceID)> + '_ { self.faces.iter().copied() }
+
+    /// Returns the ids of the faces of `role`.
+    pub fn faces(&self, role: R) -> impl Iterator<Item = FaceID> + '_ {
+        self.iter().filter(move |(r, _)| *r == role).map(|(_, id)| id)
+    }
+
+    /// Returns the id of the first face of `role`.
+    #[inline(always)]
+    pub fn face(&self, role: R) -> Option<FaceID> { self.faces(role).next() }
+
+    /// Returns the role of the face `id`.
+    pub fn role(&self, id: FaceID) -> Option<R> {
+        self.iter().find(|(_, face_id)| *face_id == id).map(|(role, _)| role)
+    }
+
+    /// Returns the faces of `solid` whose role is `role`.
+    pub fn select<'a>(&'a self, solid: &'a Solid, role: R) -> impl Iterator<Item = &'a Face> {
+        solid.face_iter().filter(move |face| self.role(face.id()) == Some(role))
+    }
+
+    /// Returns the roles of the faces of `mapped`, which is made from `original` by [`Mapped`].
+    ///
+    /// The faces of `mapped` correspond to the ones of `original` in order. Returns `None` if
+    /// the numbers of the faces are different or a tagged face is not in `original`.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let (cube, roles) = builder::tsweep_annotated(&f, Vector3::unit_z());
+    ///
+    /// let moved = builder::rotated(&cube, Point3::origin(), Vector3::unit_x(), Rad(1.0));
+    /// let moved_roles = roles.transferred(&cube, &moved).unwrap();
+    /// let top = moved_roles.select(&moved, SweepRole::Top).next().unwrap();
+    /// let normal = Matrix3::from_axis_angle(Vector3::unit_x(), Rad(1.0)) * Vector3::unit_z();
+    /// assert!(top.vertex_iter().all(|v| v.point().to_vec().dot(normal).near(&1.0)));
+    /// ```
+    pub fn transferred(&self, original: &Solid, mapped: &Solid) -> Option<Self> {
+        let indices: HashMap<FaceID, usize> =
+            original.face_iter().enumerate().map(|(i, face)| (face.id(), i)).collect();
+        let ids: Vec<FaceID> = mapped.face_iter().map(Face::id).collect();
+        if ids.len() != indices.len() {
+            return None;
+        }
+        let faces = self.iter().map(|(role, id)| Some((role, ids[*indices.get(&id)?])));
+        Some(Self {
+            faces: faces.collect::<Option<_>>()?,
+        })
+    }
+}
+
+/// The index of the edge of each lateral face of the solid swept from `profile`.
+///
+/// The sweeps of [`builder`] put the lateral faces step by step, and in each step, the faces
+/// of the edges through all boundaries of `profile` in order. The whole revolution has one
+/// shell for each boundary, and the other sweeps have the start face and the end face around
+/// the lateral faces in one shell.
+pub(crate) fn lateral_edge_indices(profile: &Face, solid: &Solid) -> Vec<usize> {
+    let lens: Vec<usize> = profile.boundaries().iter().map(|wire| wire.len()).collect();
+    let shells = solid.boundaries();
+    if shells.len() == lens.len() && shells[0][0].id() != profile.id() {
+        let offsets = lens.iter().scan(0, |sum, len| {
+            *sum += len;
+            Some(*sum - len)
+        });
+        let iter = shells.iter().zip(lens.iter().zip(offsets));
+        iter.flat_map(|(shell, (len, offset))| (0..shell.len()).map(move |j| offset + j % len))
+            .collect()
+    } else {
+        let n: usize = lens.iter().sum();
+        (0..shells[0].len() - 2).map(|j| j % n).collect()
+    }
+}
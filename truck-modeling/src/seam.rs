@@ -0,0 +1,287 @@
+use crate::*;
+use std::collections::{HashMap, HashSet};
+
+/// Splits the faces wrapping around periodic surfaces.
+pub trait SplitSeams {
+    /// Returns the shell whose faces with seams are split into two faces without seams.
+    ///
+    /// A seam is an edge appearing twice in a boundary of a face on a revolved surface,
+    /// along a meridian. Such a face is cut along the opposite meridian into two faces with
+    /// simple boundaries. The cutting edge is newly created, and both the cutting edge and the
+    /// seam are shared by the two faces. The boundary edges crossing the opposite meridian are
+    /// cut, also in the adjacent faces, so the shell is still closed if `self` is closed.
+    ///
+    /// The faces are left as they are, if their boundaries cross the opposite meridian other
+    /// than twice, or their inner boundaries cross it.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use truck_modeling::*;
+    ///
+    /// // a sphere with one face, whose boundary consists of the seam from the north pole to
+    /// // the south pole and its inverse
+    /// let v = builder::vertices([(0.0, 0.0, 1.0), (0.0, 0.0, -1.0)]);
+    /// let seam: Edge = builder::circle_arc(&v[0], &v[1], Point3::new(1.0, 0.0, 0.0));
+    /// let curve = seam.curve();
+    /// let surface = RevolutedCurve::by_revolution(curve, Point3::origin(), Vector3::unit_z());
+    /// let wire: Wire = vec![seam.clone(), seam.inverse()].into();
+    /// let face = Face::new_unchecked(vec![wire], Processor::new(surface).into());
+    /// let sphere: Shell = vec![face].into();
+    ///
+    /// let split = sphere.split_seams();
+    /// assert_eq!(split.len(), 2);
+    /// assert_eq!(split.shell_condition(), ShellCondition::Closed);
+    /// assert!(split.is_geometric_consistent());
+    /// // the two faces share the seam and the cutting edge
+    /// assert_eq!(split[0].boundaries()[0].len(), 2);
+    /// assert_eq!(split[1].boundaries()[0].len(), 2);
+    /// ```
+    fn split_seams(&self) -> Self;
+}
+
+impl SplitSeams for Shell {
+    fn split_seams(&self) -> Shell {
+        let mut cuts = HashMap::new();
+        let plans: Vec<Option<Split>> =
+            self.face_iter().map(|face| Split::try_new(face, &mut cuts)).collect();
+        let mut res = Shell::new();
+        for (face, plan) in self.face_iter().zip(plans) {
+            let touched = face.edge_iter().any(|edge| cuts.contains_key(&edge.id()));
+            if plan.is_none() && !touched {
+                res.push(face.clone());
+                continue;
+            }
+            let boundaries = face.boundaries();
+            let wires: Vec<Wire> = boundaries.iter().map(|wire| cut_wire(wire, &cuts)).collect();
+            let surface = face.oriented_surface();
+            match plan {
+                Some(plan) => res.extend(plan.split(wires, surface)),
+                None => res.push(Face::new_unchecked(wires, surface)),
+            }
+        }
+        res
+    }
+}
+
+/// Replaces the cut edges by their pieces.
+fn cut_wire(wire: &Wire, cuts: &HashMap<EdgeID, (Edge, Edge)>) -> Wire {
+    let mut res = Wire::new();
+    for edge in wire {
+        match (cuts.get(&edge.id()), edge.orientation()) {
+            (Some((edge0, edge1)), true) => res.extend([edge0.clone(), edge1.clone()]),
+            (Some((edge0, edge1)), false) => res.extend([edge1.inverse(), edge0.inverse()]),
+            (None, _) => res.push_back(edge.clone()),
+        }
+    }
+    res
+}
+
+/// The frame of the revolution of a revolved surface, in the coordinate of the entity.
+struct Meridian {
+    inverse: Matrix4,
+    origin: Point3,
+    axis: Vector3,
+    /// the horizontal direction of the seam
+    dir: Vector3,
+}
+
+impl Meridian {
+    fn new(surface: &Processor<RevolutedCurve<Curve>, Matrix4>, seam: &Edge) -> Option<Self> {
+        let inverse = surface.transform().invert()?;
+        let (origin, axis) = (surface.entity().origin(), surface.entity().axis());
+        let mut meridian = Self {
+            inverse,
+            origin,
+            axis,
+            dir: Vector3::zero(),
+        };
+        let points = sample(&seam.curve()).map(|p| meridian.horizontal(p));
+        let dir = points.iter().max_by(|a, b| a.magnitude2().total_cmp(&b.magnitude2()))?;
+        meridian.dir = dir.normalize();
+        let on_meridian = points.iter().all(|h| h.dot(meridian.normal()).so_small());
+        let on_half = points.iter().all(|h| h.dot(meridian.dir) > -TOLERANCE);
+        match !dir.so_small() && on_meridian && on_half {
+            true => Some(meridian),
+            false => None,
+        }
+    }
+    /// the normal of the meridian plane
+    fn normal(&self) -> Vector3 { self.axis.cross(self.dir) }
+    /// the horizontal component of the position from the axis
+    fn horizontal(&self, p: Point3) -> Vector3 {
+        let q = self.inverse.transform_point(p) - self.origin;
+        q - self.axis * q.dot(self.axis)
+    }
+    /// positive on the side to which the surface rotates from the seam
+    fn side(&self, p: Point3) -> f64 { self.horizontal(p).dot(self.normal()) }
+    fn on_axis(&self, p: Point3) -> bool { self.horizontal(p).so_small() }
+    /// Whether the point is on the other side of the axis than the seam.
+    fn is_opposite(&self, p: Point3) -> bool { self.horizontal(p).dot(self.dir) < 0.0 }
+}
+
+/// samples points on the curve at the uniform parameters
+fn sample(curve: &Curve) -> [Point3; 9] {
+    let (t0, t1) = curve.range_tuple();
+    std::array::from_fn(|i| curve.subs(t0 + (t1 - t0) * i as f64 / 8.0))
+}
+
+/// the plan to split a face with a seam
+struct Split {
+    /// the index of the wire including the seam
+    wire_index: usize,
+    /// the cutting edge along the opposite meridian
+    edge: Edge,
+    meridian: Meridian,
+}
+
+impl Split {
+    fn try_new(face: &Face, cuts: &mut HashMap<EdgeID, (Edge, Edge)>) -> Option<Self> {
+        let Surface::RevolutedCurve(surface) = face.surface() else {
+            return None;
+        };
+        let wires = face.boundaries();
+        let (wire_index, meridian) = wires.iter().enumerate().find_map(|(i, wire)| {
+            let seam = wire.edge_iter().find(|edge| {
+                let count = wire.edge_iter().filter(|e| e.id() == edge.id()).count();
+                count == 2
+            })?;
+            Some((i, Meridian::new(&surface, seam)?))
+        })?;
+
+        let mut ends = Vec::new();
+        let mut new_cuts: Vec<(EdgeID, (Edge, Edge))> = Vec::new();
+        for (i, wire) in wires.iter().enumerate() {
+            for edge in wire {
+                if i == wire_index && meridian.on_axis(edge.front().point()) {
+                    ends.push(edge.front().clone());
+                }
+                let count = wire.edge_iter().filter(|e| e.id() == edge.id()).count();
+                if count == 2 {
+                    continue;
+                }
+                for t in crossings(&edge.curve(), &meridian) {
+                    // each edge is cut at most once
+                    let id = edge.id();
+                    let cut = cuts.contains_key(&id) || new_cuts.iter().any(|(x, _)| *x == id);
+                    if i != wire_index || cut {
+                        return None;
+                    }
+                    let vertex = Vertex::new(edge.curve().subs(t));
+                    let (edge0, edge1) = edge.absolute_clone().cut_with_parameter(&vertex, t)?;
+                    ends.push(vertex);
+                    new_cuts.push((edge.id(), (edge0, edge1)));
+                }
+            }
+        }
+        let mut ids = HashSet::new();
+        ends.retain(|v| ids.insert(v.id()));
+        if ends.len() != 2 {
+            return None;
+        }
+
+        let edge = opposite_edge(&surface, &meridian, [&ends[0], &ends[1]])?;
+        cuts.extend(new_cuts);
+        Some(Self {
+            wire_index,
+            edge,
+            meridian,
+        })
+    }
+
+    fn split(self, mut wires: Vec<Wire>, surface: Surface) -> [Face; 2] {
+        let wire = wires.remove(self.wire_index);
+        let (front, back) = (self.edge.front().id(), self.edge.back().id());
+        let i0 = wire.iter().position(|edge| edge.front().id() == front).unwrap();
+        let i1 = wire.iter().position(|edge| edge.front().id() == back).unwrap();
+        let len = wire.len();
+        let path = |i: usize, j: usize| -> Wire {
+            let n = (j + len - i) % len;
+            (0..n).map(|k| wire[(i + k) % len].clone()).collect()
+        };
+        let (mut wire0, mut wire1) = (path(i0, i1), path(i1, i0));
+        wire0.push_back(self.edge.inverse());
+        wire1.push_back(self.edge.clone());
+
+        // the side of the first half is decided by the first edge, rotating from the seam
+        let edge = &wire0[0];
+        let curve = edge.oriented_curve();
+        let (t0, t1) = curve.range_tuple();
+        let side = self.meridian.side(curve.subs((t0 + t1) / 2.0));
+        let positive = match side.so_small() {
+            true => edge.back().id() == back,
+            false => side > 0.0,
+        };
+        let (mut boundaries0, mut boundaries1) = (vec![wire0], vec![wire1]);
+        for wire in wires {
+            let side = self.meridian.side(wire.front_vertex().unwrap().point());
+            match (side > 0.0) == positive {
+                true => boundaries0.push(wire),
+                false => boundaries1.push(wire),
+            }
+        }
+        [
+            Face::debug_new(boundaries0, surface.clone()),
+            Face::debug_new(boundaries1, surface),
+        ]
+    }
+}
+
+/// Returns the parameters of the curve crossing the opposite meridian.
+fn crossings(curve: &Curve, meridian: &Meridian) -> Vec<f64> {
+    let (t0, t1) = curve.range_tuple();
+    let (params, _) = curve.parameter_division((t0, t1), 1.0e-3);
+    let side = |t: f64| meridian.side(curve.subs(t));
+    params
+        .windows(2)
+        .filter_map(|w| {
+            let (mut a, mut b) = (w[0], w[1]);
+            let (fa, fb) = (side(a), side(b));
+            if fa.abs() < TOLERANCE || fb.abs() < TOLERANCE || fa * fb > 0.0 {
+                return None;
+            }
+            for _ in 0..100 {
+                let mid = (a + b) / 2.0;
+                match side(mid) * fa > 0.0 {
+                    true => a = mid,
+                    false => b = mid,
+                }
+            }
+            let t = (a + b) / 2.0;
+            match meridian.is_opposite(curve.subs(t)) {
+                true => Some(t),
+                false => None,
+            }
+        })
+        .collect()
+}
+
+/// Creates the edge along the opposite meridian from `ends[0]` to `ends[1]`.
+fn opposite_edge(
+    surface: &Processor<RevolutedCurve<Curve>, Matrix4>,
+    meridian: &Meridian,
+    ends: [&Vertex; 2],
+) -> Option<Edge> {
+    let curve = surface.entity().entity_curve();
+    let generatrix = sample(curve)
+        .map(|p| p - meridian.origin)
+        .map(|q| q - meridian.axis * q.dot(meridian.axis))
+        .into_iter()
+        .max_by(|a, b| a.magnitude2().total_cmp(&b.magnitude2()))?;
+    if generatrix.so_small() {
+        return None;
+    }
+    let (dir, axis) = (-meridian.dir, meridian.axis);
+    let angle = f64::atan2(generatrix.cross(dir).dot(axis), generatrix.dot(dir));
+    let (origin, rotation) = (meridian.origin.to_vec(), Matrix3::from_axis_angle(axis, Rad(angle)));
+    let local = Matrix4::from_translation(origin)
+        * Matrix4::from(rotation)
+        * Matrix4::from_translation(-origin);
+    let inverse = local.invert()? * meridian.inverse;
+    let [t0, t1] = ends.map(|v| {
+        let p = inverse.transform_point(v.point());
+        curve.search_parameter(p, None, 100)
+    });
+    let (t0, t1) = (t0?, t1?);
+    let sub = curve.subcurve((t0, t1)).transformed(surface.transform() * local);
+    Some(Edge::new(ends[0], ends[1], sub))
+}
@@ -0,0 +1,257 @@
+use crate::drawing::{inside_runs, uniform_division, Contour, Field};
+use crate::ray_casting::{face_domain, segment_distance};
+use crate::*;
+use std::collections::HashSet;
+
+/// the number of the divisions of the lines for trimming them by faces
+const TRIM_DIVISION: usize = 64;
+/// the distance of the endpoints of the fragments regarded as one vertex, relative to `tol`
+const SNAP_FACTOR: f64 = 10.0;
+
+/// Sections of solids by planes.
+pub trait PlaneSection {
+    /// Returns the wires of the section by `plane`.
+    ///
+    /// Every face is intersected with the plane, and the fragments of the intersection curves
+    /// are assembled into wires by matching their endpoints within `10 * tol`.
+    /// - The sections of the planes are the exact lines.
+    /// - The sections of the spheres are the exact circle arcs.
+    /// - The sections of the other surfaces are marched over the parameter division of the
+    ///   faces and fitted by B-spline curves within `tol`, as the silhouettes of
+    ///   [`Drawing::silhouette_curves`].
+    ///
+    /// The edges lying on the plane are the fragments themselves, and the fragments coincident
+    /// with them or with each other, e.g. the sections of the faces adjacent to such edges, are
+    /// removed. The faces lying on the plane have no sections other than their edges. If the
+    /// plane only touches the solid at vertices, there are no wires.
+    ///
+    /// The wires are closed if the solid is closed and the plane crosses it, and may be open if
+    /// the plane only touches the solid along edges.
+    ///
+    /// # Failures
+    /// Returns [`Error::ParameterSearchFailed`](crate::errors::Error::ParameterSearchFailed) with
+    /// the face if the boundaries of a face cannot be projected to the surface.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let plane = Plane::new(
+    ///     Point3::new(0.0, 0.0, 0.5),
+    ///     Point3::new(1.0, 0.0, 0.5),
+    ///     Point3::new(0.0, 1.0, 0.5),
+    /// );
+    /// let wires = cube.section(&plane, 1.0e-3).unwrap();
+    /// assert_eq!(wires.len(), 1);
+    /// assert!(wires[0].is_closed());
+    /// assert_eq!(wires[0].len(), 4);
+    /// wires[0].vertex_iter().for_each(|v| assert!((v.point().z - 0.5).abs() < 1.0e-3));
+    /// ```
+    fn section(&self, plane: &Plane, tol: f64) -> Result<Vec<Wire>>;
+}
+
+impl PlaneSection for Solid {
+    fn section(&self, plane: &Plane, tol: f64) -> Result<Vec<Wire>> {
+        let (origin, normal) = (plane.origin(), plane.normal());
+        let on_plane = |point: &Point3| (*point - origin).dot(normal).abs() < tol;
+        let mut ids = HashSet::new();
+        // the edges on the plane precede, so that they are kept rather than their copies
+        let edges = self
+            .edge_iter()
+            .filter(|edge| ids.insert(edge.id()))
+            .map(|edge| edge.oriented_curve())
+            .filter(|curve| {
+                let (_, points) = curve.parameter_division(curve.range_tuple(), tol);
+                points.iter().all(on_plane)
+            });
+        let sections = self
+            .face_iter()
+            .map(|face| plane_section(face, origin, normal, tol))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(assemble_wires(edges.chain(sections.concat()), SNAP_FACTOR * tol, tol))
+    }
+}
+
+/// Returns the fragments of the intersection curves of the face and the plane.
+fn plane_section(face: &Face, origin: Point3, normal: Vector3, tol: f64) -> Result<Vec<Curve>> {
+    let surface = face.surface();
+    let domain = face_domain(face, tol)?;
+    let contour = Contour {
+        surface: &surface,
+        domain: &domain,
+        field: Field::Section(origin, normal),
+        tol,
+    };
+    if let Surface::Plane(plane) = &surface {
+        return Ok(line_sections(&contour, plane, origin, normal));
+    }
+    let curves = match contour.sphere() {
+        Some((center, radius)) => {
+            let distance = (center - origin).dot(normal);
+            match distance.abs() < radius {
+                true => {
+                    let radius = f64::sqrt(radius * radius - distance * distance);
+                    contour.trimmed_circle(center - normal * distance, radius, normal)
+                }
+                false => Vec::new(),
+            }
+        }
+        None => contour.marched_curves(),
+    };
+    Ok(curves)
+}
+
+/// The intersection line of the planes, trimmed by the face.
+fn line_sections(
+    contour: &Contour<'_>,
+    plane: &Plane,
+    origin: Point3,
+    normal: Vector3,
+) -> Vec<Curve> {
+    if plane.normal().cross(normal).so_small() {
+        return Vec::new();
+    }
+    // the line is `uv0 + dir * t` in the parameter plane
+    let (u_axis, v_axis) = (plane.u_axis(), plane.v_axis());
+    let gradient = Vector2::new(u_axis.dot(normal), v_axis.dot(normal));
+    let height = (origin - plane.origin()).dot(normal);
+    let uv0 = Point2::from_vec(gradient * (height / gradient.magnitude2()));
+    let dir = Vector2::new(-gradient.y, gradient.x);
+    let ((u0, u1), (v0, v1)) = contour.range();
+    let corners = [(u0, v0), (u1, v0), (u0, v1), (u1, v1)];
+    let ts = corners.map(|(u, v)| (Point2::new(u, v) - uv0).dot(dir) / dir.magnitude2());
+    let t0 = ts.into_iter().fold(f64::INFINITY, f64::min);
+    let t1 = ts.into_iter().fold(f64::NEG_INFINITY, f64::max);
+    let uv = |t: f64| uv0 + dir * t;
+    let runs = inside_runs(&uniform_division((t0, t1), TRIM_DIVISION), |t| {
+        contour.includes(uv(t))
+    });
+    runs.into_iter()
+        .map(|(t0, t1)| {
+            let (p, q) = (uv(t0), uv(t1));
+            Curve::Line(Line(plane.subs(p.x, p.y), plane.subs(q.x, q.y)))
+        })
+        .collect()
+}
+
+/// A fragment of the section between the clustered endpoints.
+struct Fragment {
+    curve: Curve,
+    ends: (usize, usize),
+    polyline: Vec<Point3>,
+}
+
+/// Assembles the fragments into wires by matching the endpoints within `snap`.
+fn assemble_wires(curves: impl IntoIterator<Item = Curve>, snap: f64, tol: f64) -> Vec<Wire> {
+    let mut points = Vec::<Point3>::new();
+    let mut cluster = |point: Point3| match points.iter().position(|p| p.distance(point) < snap) {
+        Some(idx) => idx,
+        None => {
+            points.push(point);
+            points.len() - 1
+        }
+    };
+    let mut fragments = Vec::<Fragment>::new();
+    for curve in curves {
+        let (t0, t1) = curve.range_tuple();
+        let (front, middle) = (curve.subs(t0), curve.subs((t0 + t1) / 2.0));
+        let ends = (cluster(front), cluster(curve.subs(t1)));
+        // the fragments degenerated to the vertices
+        if ends.0 == ends.1 && middle.distance(front) < snap {
+            continue;
+        }
+        let coincident = fragments.iter().any(|fragment| {
+            let same_ends = fragment.ends == ends || fragment.ends == (ends.1, ends.0);
+            same_ends && polyline_distance(&fragment.polyline, middle) < snap
+        });
+        if !coincident {
+            let (_, polyline) = curve.parameter_division((t0, t1), tol);
+            fragments.push(Fragment {
+                curve,
+                ends,
+                polyline,
+            });
+        }
+    }
+
+    let mut vertices: Vec<Vertex> = points.iter().map(|p| Vertex::new(*p)).collect();
+    let mut links = Vec::<(usize, usize, Edge)>::new();
+    for Fragment { mut curve, ends, .. } in fragments {
+        let (a, b) = ends;
+        if a != b {
+            let curve = snapped(curve, points[a], points[b]);
+            links.push((a, b, Edge::new(&vertices[a], &vertices[b], curve)));
+            continue;
+        }
+        // the closed fragment is divided at the middle, since the edges have distinct vertices
+        let (t0, t1) = curve.range_tuple();
+        let middle = curve.subs((t0 + t1) / 2.0);
+        let back = curve.cut((t0 + t1) / 2.0);
+        let m = vertices.len();
+        vertices.push(Vertex::new(middle));
+        let front = snapped(curve, points[a], middle);
+        let back = snapped(back, middle, points[a]);
+        links.push((a, m, Edge::new(&vertices[a], &vertices[m], front)));
+        links.push((m, a, Edge::new(&vertices[m], &vertices[a], back)));
+    }
+
+    let mut incident = vec![Vec::new(); vertices.len()];
+    links.iter().enumerate().for_each(|(i, (a, b, _))| {
+        incident[*a].push(i);
+        incident[*b].push(i);
+    });
+    // the open wires start from their ends, and the closed ones from anywhere
+    let ends = (0..vertices.len()).filter(|i| incident[*i].len() % 2 == 1);
+    let starts: Vec<usize> = ends.chain(0..vertices.len()).collect();
+    let mut used = vec![false; links.len()];
+    let mut wires = Vec::new();
+    for start in starts {
+        while incident[start].iter().any(|i| !used[*i]) {
+            let (mut wire, mut node) = (Wire::new(), start);
+            while let Some(&i) = incident[node].iter().find(|i| !used[**i]) {
+                used[i] = true;
+                let (a, b, edge) = &links[i];
+                let (edge, next) = match *a == node {
+                    true => (edge.clone(), *b),
+                    false => (edge.inverse(), *a),
+                };
+                wire.push_back(edge);
+                node = next;
+            }
+            wires.push(wire);
+        }
+    }
+    wires
+}
+
+fn polyline_distance(polyline: &[Point3], point: Point3) -> f64 {
+    polyline
+        .windows(2)
+        .map(|p| segment_distance(p[0], p[1], point))
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Moves the ends of the curve to the vertices.
+fn snapped(curve: Curve, front: Point3, back: Point3) -> Curve {
+    match curve {
+        Curve::Line(_) => Curve::Line(Line(front, back)),
+        Curve::BSplineCurve(mut curve) => {
+            let last = curve.control_points().len() - 1;
+            *curve.control_point_mut(0) = front;
+            *curve.control_point_mut(last) = back;
+            Curve::BSplineCurve(curve)
+        }
+        Curve::NurbsCurve(mut curve) => {
+            let last = curve.control_points().len() - 1;
+            let (w0, w1) = (curve.control_point(0).w, curve.control_point(last).w);
+            *curve.control_point_mut(0) = (front.to_vec() * w0).extend(w0);
+            *curve.control_point_mut(last) = (back.to_vec() * w1).extend(w1);
+            Curve::NurbsCurve(curve)
+        }
+        curve => curve,
+    }
+}
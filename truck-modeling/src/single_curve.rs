@@ -0,0 +1,243 @@
+use crate::{errors::Error, Result};
+use truck_geometry::prelude::*;
+use truck_topology::{Edge, Wire};
+
+/// the maximum degree of the fitted curves
+const MAX_DEGREE: usize = 3;
+/// the maximum number of doubling the samples of the wire
+const MAX_REFINEMENT: usize = 6;
+/// the angle between the tangents of the consecutive edges regarded as a corner
+const CORNER_ANGLE: f64 = 1.0e-2;
+
+/// Approximates wires by single curves.
+pub trait SingleCurve {
+    /// Returns the B-spline curve approximating the whole wire within `tol`, and the achieved
+    /// fit error.
+    ///
+    /// The wire is divided into the runs of edges at the corners, the vertices where the
+    /// tangents of the oriented curves of the edges are discontinuous. The oriented curves of the
+    /// edges of each run are divided by [`ParameterDivision1D`], and a cubic B-spline curve
+    /// parametrized by the chord length interpolates the subsets of the division points,
+    /// increasing the points until the curve is within `tol` from the run. If the error is not
+    /// within `tol` even by interpolating all points, the divisions are refined. The curves of the
+    /// runs are concatenated into one curve in `[0, 1]`, which is smooth except at the corners.
+    ///
+    /// The fit error is the maximum distance between the division points and the midpoints of
+    /// the divisions and the points of the curve at the corresponding parameters, so it is an
+    /// upper bound of the distance from the samples to the curve. The start and the end of the
+    /// curve are exactly the points of the front and the back vertices of the wire.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// // the semicircle consisting of two arcs, followed by a tangent line
+    /// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    /// let mut wire: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(PI));
+    /// let end = builder::vertex(Point3::new(-1.0, -1.0, 0.0));
+    /// let line = builder::line(wire.back_vertex().unwrap(), &end);
+    /// wire.push_back(line);
+    ///
+    /// let (curve, error) = wire.to_single_curve(1.0e-3).unwrap();
+    /// assert!(error <= 1.0e-3);
+    /// assert_eq!(curve.range_tuple(), (0.0, 1.0));
+    /// assert_near!(curve.front(), Point3::new(1.0, 0.0, 0.0));
+    /// assert_near!(curve.back(), Point3::new(-1.0, -1.0, 0.0));
+    /// assert!(f64::abs(curve.subs(0.3).to_vec().magnitude() - 1.0) < 2.0e-3);
+    /// ```
+    /// # Failures
+    /// - If the wire is empty, returns [`truck_topology::errors::Error::EmptyWire`].
+    /// - If the back vertex of an edge is not the front vertex of the next edge, returns
+    ///   [`Error::DisconnectedWire`] with the largest gap between the consecutive edges.
+    /// - If the curve cannot be fitted within `tol`, returns [`Error::CurveFittingFailure`] with
+    ///   the least error.
+    fn to_single_curve(&self, tol: f64) -> Result<(BSplineCurve<Point3>, f64)>;
+}
+
+impl<C> SingleCurve for Wire<Point3, C>
+where C: ParametricCurve3D + BoundedCurve + Invertible + ParameterDivision1D<Point = Point3>
+{
+    fn to_single_curve(&self, tol: f64) -> Result<(BSplineCurve<Point3>, f64)> {
+        if self.is_empty() {
+            return Err(truck_topology::errors::Error::EmptyWire.into());
+        }
+        let gap = self
+            .edge_iter()
+            .zip(self.edge_iter().skip(1))
+            .filter(|(edge0, edge1)| edge0.back() != edge1.front())
+            .map(|(edge0, edge1)| edge0.back().point().distance(edge1.front().point()))
+            .reduce(f64::max);
+        if let Some(gap) = gap {
+            return Err(Error::DisconnectedWire { gap });
+        }
+
+        let runs = smooth_runs(self);
+        let lengths: Vec<f64> = runs
+            .iter()
+            .map(|run| polyline_length(&run_samples(run, tol, 0)))
+            .collect();
+        let total: f64 = lengths.iter().sum();
+        let mut front = 0.0;
+        let mut error = 0.0;
+        let mut curves = Vec::with_capacity(runs.len());
+        for (i, (run, length)) in runs.iter().zip(&lengths).enumerate() {
+            let back = match (i + 1 == runs.len(), total.so_small()) {
+                (true, _) => 1.0,
+                (false, true) => (i + 1) as f64 / runs.len() as f64,
+                (false, false) => front + length / total,
+            };
+            let (curve, run_error) = fit_run(run, (front, back), tol)?;
+            curves.push(curve);
+            error = f64::max(error, run_error);
+            front = back;
+        }
+        let curve = curves.into_iter().reduce(|curve0, curve1| curve0.concat(&curve1));
+        Ok((curve.unwrap(), error))
+    }
+}
+
+/// Divides the edges of the wire at the corners.
+fn smooth_runs<C>(wire: &Wire<Point3, C>) -> Vec<Vec<Edge<Point3, C>>>
+where C: ParametricCurve3D + BoundedCurve + Invertible {
+    let mut runs = vec![Vec::<Edge<Point3, C>>::new()];
+    wire.edge_iter().for_each(|edge| {
+        let run = runs.last_mut().unwrap();
+        let is_corner = run.last().is_some_and(|last| {
+            let (curve0, curve1) = (last.oriented_curve(), edge.oriented_curve());
+            let der0 = curve0.der(curve0.range_tuple().1);
+            let der1 = curve1.der(curve1.range_tuple().0);
+            der0.so_small() || der1.so_small() || der0.angle(der1).0 > CORNER_ANGLE
+        });
+        match is_corner {
+            true => runs.push(vec![edge.clone()]),
+            false => run.push(edge.clone()),
+        }
+    });
+    runs
+}
+
+/// Fits a curve with the parameter range `range` to the run of edges.
+fn fit_run<C>(
+    run: &[Edge<Point3, C>],
+    range: (f64, f64),
+    tol: f64,
+) -> Result<(BSplineCurve<Point3>, f64)>
+where
+    C: ParametricCurve3D + BoundedCurve + Invertible + ParameterDivision1D<Point = Point3>,
+{
+    let mut least_error = f64::INFINITY;
+    for level in 0..=MAX_REFINEMENT {
+        let samples = run_samples(run, tol, level);
+        let (curve, error) = fit_samples(&samples, range, level == 0, tol)?;
+        if error <= tol {
+            return Ok((curve, error));
+        }
+        least_error = f64::min(least_error, error);
+    }
+    Err(Error::CurveFittingFailure { error: least_error })
+}
+
+/// Fits a curve with the parameter range `range` to the samples, the division points of the even
+/// indices and their midpoints of the odd indices. The curve interpolates the division points
+/// increasing from `MAX_DEGREE + 1` if `coarse`, or all of them otherwise. Returns the first curve
+/// within `tol` from the samples, or the curve with the least error.
+pub(super) fn fit_samples(
+    samples: &[Point3],
+    range: (f64, f64),
+    coarse: bool,
+    tol: f64,
+) -> Result<(BSplineCurve<Point3>, f64)> {
+    let params = chord_length_parameters(samples, range);
+    let len = samples.len() / 2 + 1;
+    let mut n = match coarse {
+        true => usize::min(len, MAX_DEGREE + 1),
+        false => len,
+    };
+    let mut best: Option<(BSplineCurve<Point3>, f64)> = None;
+    loop {
+        let curve = interpolate(samples, &params, n)?;
+        let error = samples
+            .iter()
+            .zip(&params)
+            .map(|(p, t)| p.distance(curve.subs(*t)))
+            .fold(0.0, f64::max);
+        if error <= tol {
+            return Ok((curve, error));
+        }
+        if best.as_ref().is_none_or(|(_, least)| error < *least) {
+            best = Some((curve, error));
+        }
+        if n == len {
+            return Ok(best.unwrap());
+        }
+        n = usize::min(2 * n - 1, len);
+    }
+}
+
+/// Returns the division points of the oriented curves of the edges and their midpoints
+/// alternately, i.e. the division points have the even indices. Each division is divided into
+/// `2^level` parts.
+fn run_samples<C>(run: &[Edge<Point3, C>], tol: f64, level: usize) -> Vec<Point3>
+where C: ParametricCurve3D + BoundedCurve + Invertible + ParameterDivision1D<Point = Point3> {
+    let mut samples = vec![run[0].front().point()];
+    let parts = 1 << level;
+    run.iter().for_each(|edge| {
+        let curve = edge.oriented_curve();
+        let (params, _) = curve.parameter_division(curve.range_tuple(), tol);
+        params.windows(2).for_each(|p| {
+            (0..parts).for_each(|i| {
+                let t = |s: f64| p[0] + (p[1] - p[0]) * (i as f64 + s) / parts as f64;
+                samples.push(curve.subs(t(0.5)));
+                samples.push(curve.subs(t(1.0)));
+            });
+        });
+        // the end of the edge is exactly the vertex
+        *samples.last_mut().unwrap() = edge.back().point();
+    });
+    samples
+}
+
+fn polyline_length(points: &[Point3]) -> f64 {
+    points.windows(2).map(|p| p[0].distance(p[1])).sum()
+}
+
+/// the chord length parameters of the points normalized into `range`
+fn chord_length_parameters(points: &[Point3], (t0, t1): (f64, f64)) -> Vec<f64> {
+    let mut lengths = vec![0.0];
+    points.windows(2).for_each(|p| {
+        let last = *lengths.last().unwrap();
+        lengths.push(last + p[0].distance(p[1]));
+    });
+    let total = *lengths.last().unwrap();
+    let n = points.len() - 1;
+    let mut params: Vec<f64> = match total.so_small() {
+        true => (0..=n).map(|i| t0 + (t1 - t0) * i as f64 / n as f64).collect(),
+        false => lengths.into_iter().map(|s| t0 + (t1 - t0) * s / total).collect(),
+    };
+    // the ends of the ranges of the runs must be the same for concatenation
+    params[n] = t1;
+    params
+}
+
+/// Interpolates the `n` division points of the even indices, distributed evenly by the indices,
+/// with the knots averaging the parameters.
+fn interpolate(samples: &[Point3], params: &[f64], n: usize) -> Result<BSplineCurve<Point3>> {
+    let len = samples.len() / 2 + 1;
+    let (ts, points): (Vec<f64>, Vec<Point3>) = (0..n)
+        .map(|k| {
+            let i = 2 * ((k * (len - 1)) as f64 / (n - 1) as f64).round() as usize;
+            (params[i], samples[i])
+        })
+        .unzip();
+    let degree = usize::min(MAX_DEGREE, n - 1);
+    let mut knots = vec![ts[0]; degree + 1];
+    knots.extend((1..n - degree).map(|j| ts[j..j + degree].iter().sum::<f64>() / degree as f64));
+    knots.extend(vec![ts[n - 1]; degree + 1]);
+    let (front, back) = (points[0], points[n - 1]);
+    let parameter_points: Vec<(f64, Point3)> = ts.into_iter().zip(points).collect();
+    let mut curve = BSplineCurve::try_interpole(KnotVec::from(knots), parameter_points)?;
+    // the clamped curve passes the end control points, which are free from the rounding errors
+    *curve.control_point_mut(0) = front;
+    *curve.control_point_mut(n - 1) = back;
+    Ok(curve)
+}
@@ -0,0 +1,245 @@
+use crate::{errors::Error, *};
+use std::collections::{HashMap, HashSet};
+
+/// The id of a vertex, an edge, or a face.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EntityId {
+    /// the id of a vertex
+    Vertex(VertexID),
+    /// the id of an edge
+    Edge(EdgeID),
+    /// the id of a face
+    Face(FaceID),
+}
+
+/// The geometry of the entities of a solid, returned by [`SnapshotGeometry::geometry_snapshot`].
+///
+/// The snapshot holds the entities themselves with the clones of their points, curves, and
+/// surfaces, so restoring the geometry does not clone the topology.
+#[derive(Clone, Debug, Default)]
+pub struct GeometrySnapshot {
+    points: Vec<(Vertex, Point3)>,
+    curves: Vec<(Edge, Curve)>,
+    surfaces: Vec<(Face, Surface)>,
+}
+
+impl GeometrySnapshot {
+    /// Returns the number of the entities in the snapshot.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.points.len() + self.curves.len() + self.surfaces.len() }
+
+    /// Returns whether the snapshot has no entities.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the iterator over the ids of the entities, the vertices, the edges, and the faces
+    /// in this order.
+    pub fn ids(&self) -> impl Iterator<Item = EntityId> + '_ {
+        let vertices = self.points.iter().map(|(v, _)| EntityId::Vertex(v.id()));
+        let edges = self.curves.iter().map(|(e, _)| EntityId::Edge(e.id()));
+        let faces = self.surfaces.iter().map(|(f, _)| EntityId::Face(f.id()));
+        vertices.chain(edges).chain(faces)
+    }
+
+    /// Returns the ids of the entities whose geometry is different between the snapshots,
+    /// including the entities in only one of them. The ids of `self` come first, in the order of
+    /// [`GeometrySnapshot::ids`].
+    ///
+    /// The points are compared by `near`, and the curves and the surfaces by their
+    /// `geometry_hash(TOLERANCE)`, so the changes within `TOLERANCE` are not reported in most
+    /// cases.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let snapshot0 = cube.geometry_snapshot();
+    /// let vertex = cube.vertex_iter().next().unwrap();
+    /// vertex.set_point(vertex.point() + Vector3::new(0.0, 0.0, 0.1));
+    /// let snapshot1 = cube.geometry_snapshot();
+    /// assert_eq!(snapshot0.diff(&snapshot1), vec![EntityId::Vertex(vertex.id())]);
+    /// ```
+    pub fn diff(&self, other: &GeometrySnapshot) -> Vec<EntityId> {
+        let (keys0, keys1) = (self.keys(), other.keys());
+        let (map0, map1): (HashMap<_, _>, HashMap<_, _>) = (
+            keys0.iter().map(|(id, key)| (*id, key)).collect(),
+            keys1.iter().map(|(id, key)| (*id, key)).collect(),
+        );
+        let changed = keys0
+            .iter()
+            .filter(|(id, key0)| map1.get(id).is_none_or(|key1| !key0.near(key1)))
+            .map(|(id, _)| *id);
+        let added = keys1
+            .iter()
+            .filter(|(id, _)| !map0.contains_key(id))
+            .map(|(id, _)| *id);
+        changed.chain(added).collect()
+    }
+
+    fn keys(&self) -> Vec<(EntityId, GeometryKey)> {
+        let points = self
+            .points
+            .iter()
+            .map(|(v, p)| (EntityId::Vertex(v.id()), GeometryKey::Point(*p)));
+        let curves = self.curves.iter().map(|(e, c)| {
+            (EntityId::Edge(e.id()), GeometryKey::Hash(c.geometry_hash(TOLERANCE)))
+        });
+        let surfaces = self.surfaces.iter().map(|(f, s)| {
+            (EntityId::Face(f.id()), GeometryKey::Hash(s.geometry_hash(TOLERANCE)))
+        });
+        points.chain(curves).chain(surfaces).collect()
+    }
+}
+
+/// the summary of the geometry compared by [`GeometrySnapshot::diff`]
+enum GeometryKey {
+    Point(Point3),
+    Hash(u64),
+}
+
+impl GeometryKey {
+    fn near(&self, other: &GeometryKey) -> bool {
+        match (self, other) {
+            (GeometryKey::Point(p), GeometryKey::Point(q)) => p.near(q),
+            (GeometryKey::Hash(x), GeometryKey::Hash(y)) => x == y,
+            _ => false,
+        }
+    }
+}
+
+/// Snapshots and restores the geometry of solids without cloning the topology, e.g. for rolling
+/// back the perturbations in optimization loops.
+pub trait SnapshotGeometry {
+    /// Returns the snapshot of the points, the curves, and the surfaces of all the unique
+    /// entities, in the order of [`Solid::vertex_iter`], [`Solid::edge_iter`], and
+    /// [`Solid::face_iter`], respectively.
+    fn geometry_snapshot(&self) -> GeometrySnapshot;
+
+    /// Returns the snapshot of the entities of `ids` only. The ids which are not in `self` are
+    /// ignored.
+    fn geometry_snapshot_of(&self, ids: &[EntityId]) -> GeometrySnapshot;
+
+    /// Writes the geometry of the snapshot back by [`Vertex::set_point`], [`Edge::set_curve`],
+    /// and [`Face::set_surface`], so the revisions of the curves and the surfaces are renewed
+    /// and the caches depending on them are invalidated.
+    ///
+    /// Only the kinds of the entities in the snapshot are traversed for checking their
+    /// existence, e.g. the edges and the vertices are not traversed for the snapshot of faces.
+    ///
+    /// # Failures
+    /// Returns [`Error::EntityNotFound`] if any entity of the snapshot is no longer in `self`.
+    /// Then, the geometry of `self` is left untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let mut cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let face = cube.face_iter().next().unwrap().clone();
+    /// let snapshot = cube.geometry_snapshot_of(&[EntityId::Face(face.id())]);
+    ///
+    /// // perturb the surface
+    /// let mat = Matrix4::from_translation(Vector3::new(0.0, 0.0, 0.1));
+    /// face.set_surface(face.surface().transformed(mat));
+    /// let revision = face.revision();
+    ///
+    /// let ids = [EntityId::Face(face.id())];
+    /// assert_eq!(snapshot.diff(&cube.geometry_snapshot_of(&ids)), ids);
+    ///
+    /// cube.restore_geometry(&snapshot).unwrap();
+    /// assert!(face.revision() > revision);
+    /// assert!(snapshot.diff(&cube.geometry_snapshot_of(&ids)).is_empty());
+    /// ```
+    fn restore_geometry(&mut self, snapshot: &GeometrySnapshot) -> Result<()>;
+}
+
+impl SnapshotGeometry for Solid {
+    fn geometry_snapshot(&self) -> GeometrySnapshot {
+        let (mut vertices, mut edges, mut faces) = (HashSet::new(), HashSet::new(), HashSet::new());
+        let points = self
+            .vertex_iter()
+            .filter(|v| vertices.insert(v.id()))
+            .map(|v| {
+                let point = v.point();
+                (v, point)
+            })
+            .collect();
+        let curves = self
+            .edge_iter()
+            .filter(|e| edges.insert(e.id()))
+            .map(|e| {
+                let curve = e.curve();
+                (e, curve)
+            })
+            .collect();
+        let surfaces = self
+            .face_iter()
+            .filter(|f| faces.insert(f.id()))
+            .map(|f| (f.clone(), f.surface()))
+            .collect();
+        GeometrySnapshot {
+            points,
+            curves,
+            surfaces,
+        }
+    }
+
+    fn geometry_snapshot_of(&self, ids: &[EntityId]) -> GeometrySnapshot {
+        let mut ids: HashSet<EntityId> = ids.iter().copied().collect();
+        let mut snapshot = GeometrySnapshot::default();
+        if ids.iter().any(|id| matches!(id, EntityId::Vertex(_))) {
+            self.vertex_iter()
+                .filter(|v| ids.remove(&EntityId::Vertex(v.id())))
+                .for_each(|v| {
+                    let point = v.point();
+                    snapshot.points.push((v, point));
+                });
+        }
+        if ids.iter().any(|id| matches!(id, EntityId::Edge(_))) {
+            self.edge_iter()
+                .filter(|e| ids.remove(&EntityId::Edge(e.id())))
+                .for_each(|e| {
+                    let curve = e.curve();
+                    snapshot.curves.push((e, curve));
+                });
+        }
+        if ids.iter().any(|id| matches!(id, EntityId::Face(_))) {
+            self.face_iter()
+                .filter(|f| ids.remove(&EntityId::Face(f.id())))
+                .for_each(|f| snapshot.surfaces.push((f.clone(), f.surface())));
+        }
+        snapshot
+    }
+
+    fn restore_geometry(&mut self, snapshot: &GeometrySnapshot) -> Result<()> {
+        let mut missing: HashSet<EntityId> = snapshot.ids().collect();
+        if !snapshot.points.is_empty() {
+            for v in self.vertex_iter() {
+                missing.remove(&EntityId::Vertex(v.id()));
+            }
+        }
+        if !snapshot.curves.is_empty() {
+            for e in self.edge_iter() {
+                missing.remove(&EntityId::Edge(e.id()));
+            }
+        }
+        if !snapshot.surfaces.is_empty() {
+            for f in self.face_iter() {
+                missing.remove(&EntityId::Face(f.id()));
+            }
+        }
+        if let Some(id) = snapshot.ids().find(|id| missing.contains(id)) {
+            return Err(Error::EntityNotFound(id));
+        }
+        snapshot.points.iter().for_each(|(v, p)| v.set_point(*p));
+        snapshot.curves.iter().for_each(|(e, c)| e.set_curve(c.clone()));
+        snapshot.surfaces.iter().for_each(|(f, s)| f.set_surface(s.clone()));
+        Ok(())
+    }
+}
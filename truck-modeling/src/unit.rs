@@ -0,0 +1,196 @@
+use crate::{errors::Error, *};
+use std::collections::{hash_map::DefaultHasher, HashSet};
+use std::hash::{Hash, Hasher};
+use truck_topology::compress::CompressedSolid;
+
+/// The unit of length.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LengthUnit {
+    /// meter
+    Meter,
+    /// millimeter
+    Millimeter,
+    /// inch, i.e. 25.4 millimeters
+    Inch,
+    /// the unit with the length in meters
+    Custom(f64),
+}
+
+impl LengthUnit {
+    /// Returns the length of the unit in meters.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// assert_eq!(LengthUnit::Millimeter.meters(), 0.001);
+    /// assert_eq!(LengthUnit::Custom(0.3048).meters(), 0.3048);
+    /// ```
+    #[inline(always)]
+    pub fn meters(self) -> f64 {
+        match self {
+            LengthUnit::Meter => 1.0,
+            LengthUnit::Millimeter => 0.001,
+            LengthUnit::Inch => 0.0254,
+            LengthUnit::Custom(meters) => meters,
+        }
+    }
+
+    /// Returns the ratio of `self` to `unit`, i.e. the factor converting lengths in `self` to
+    /// lengths in `unit`.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// assert_eq!(LengthUnit::Meter.ratio_to(LengthUnit::Millimeter), 1000.0);
+    /// ```
+    #[inline(always)]
+    pub fn ratio_to(self, unit: LengthUnit) -> f64 { self.meters() / unit.meters() }
+}
+
+/// A solid tagged with the unit of length of its coordinates.
+///
+/// The tolerance of the solid is also expressed in the unit. It is rescaled by the conversion of
+/// the unit, but never gets less than `TOLERANCE`, since the geometric predicates cannot
+/// distinguish the points within `TOLERANCE` in any unit.
+#[derive(Clone, Debug)]
+pub struct UnitizedSolid {
+    /// the solid
+    pub solid: Solid,
+    /// the unit of the coordinates of the solid
+    pub unit: LengthUnit,
+    /// the tolerance of the solid, in `unit`
+    pub tolerance: f64,
+}
+
+impl UnitizedSolid {
+    /// Tags `solid` with `unit`, with the tolerance `TOLERANCE`.
+    #[inline(always)]
+    pub fn new(solid: Solid, unit: LengthUnit) -> Self {
+        Self {
+            solid,
+            unit,
+            tolerance: TOLERANCE,
+        }
+    }
+
+    /// Returns the solid converted to `unit`.
+    ///
+    /// The solid is scaled by [`Mapped`], so each vertex, curve and surface shared by several
+    /// faces or shells is scaled exactly once, and the sharing is preserved.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube = UnitizedSolid::new(builder::tsweep(&f, Vector3::unit_z()), LengthUnit::Meter);
+    ///
+    /// let converted = cube.convert_to(LengthUnit::Millimeter);
+    /// assert_eq!(converted.unit, LengthUnit::Millimeter);
+    /// assert_near!(converted.tolerance, 0.001);
+    /// let vertex = converted.solid.boundaries()[0].vertex_iter().nth(2).unwrap();
+    /// assert_near!(vertex.point(), Point3::new(1000.0, 1000.0, 0.0));
+    /// ```
+    pub fn convert_to(&self, unit: LengthUnit) -> UnitizedSolid {
+        let ratio = self.unit.ratio_to(unit);
+        let solid = match ratio == 1.0 {
+            true => self.solid.clone(),
+            false => builder::scaled(
+                &self.solid,
+                Point3::origin(),
+                Vector3::new(ratio, ratio, ratio),
+            ),
+        };
+        UnitizedSolid {
+            solid,
+            unit,
+            tolerance: f64::max(self.tolerance * ratio, TOLERANCE),
+        }
+    }
+
+    /// Merges the boundaries of `a` and `b` into one solid, if the units are the same.
+    ///
+    /// The units are compared by the lengths in meters, so `LengthUnit::Custom(0.001)` can be
+    /// merged with `LengthUnit::Millimeter`. The tolerance of the result is the larger one.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::{errors::Error, *};
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    /// let moved = builder::translated(&cube, Vector3::new(2.0, 0.0, 0.0));
+    ///
+    /// let a = UnitizedSolid::new(cube, LengthUnit::Meter);
+    /// let b = UnitizedSolid::new(moved, LengthUnit::Millimeter);
+    /// let err = UnitizedSolid::try_merge(&a, &b).unwrap_err();
+    /// assert_eq!(err, Error::UnitMismatch);
+    ///
+    /// let b = b.convert_to(LengthUnit::Meter);
+    /// let merged = UnitizedSolid::try_merge(&a, &b).unwrap();
+    /// assert_eq!(merged.solid.boundaries().len(), 2);
+    /// ```
+    pub fn try_merge(a: &UnitizedSolid, b: &UnitizedSolid) -> Result<UnitizedSolid> {
+        if a.unit.meters() != b.unit.meters() {
+            return Err(Error::UnitMismatch);
+        }
+        let boundaries = a
+            .solid
+            .boundaries()
+            .iter()
+            .chain(b.solid.boundaries())
+            .cloned()
+            .collect();
+        Ok(UnitizedSolid {
+            solid: Solid::try_new(boundaries)?,
+            unit: a.unit,
+            tolerance: f64::max(a.tolerance, b.tolerance),
+        })
+    }
+
+    /// Returns the ids of the edges shorter than the tolerance.
+    ///
+    /// The lengths are measured along the polylines approximating the curves within the
+    /// tolerance, so the closed edges are not flagged unless they are small.
+    pub fn degenerate_edges(&self) -> Vec<EdgeID> {
+        let mut checked = HashSet::new();
+        self.solid
+            .edge_iter()
+            .filter(|edge| checked.insert(edge.id()))
+            .filter(|edge| {
+                let curve = edge.curve();
+                let pts = curve.sample_iter(curve.range_tuple(), self.tolerance);
+                let (length, _) = pts.fold((0.0, None), |(length, prev), (_, pt)| {
+                    let dist = prev.map_or(0.0, |prev: Point3| prev.distance(pt));
+                    (length + dist, Some(pt))
+                });
+                length < self.tolerance
+            })
+            .map(|edge| edge.id())
+            .collect()
+    }
+
+    /// Returns the hash of the unit, the topology and the points quantized by `TOLERANCE`.
+    ///
+    /// The fingerprint changes by the conversion of the unit, and is restored by the converting
+    /// back to the original unit.
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.unit.meters().to_bits().hash(&mut hasher);
+        let CompressedSolid { boundaries } = self.solid.compress();
+        boundaries.iter().for_each(|shell| {
+            shell.vertices.iter().for_each(|p| {
+                [p.x, p.y, p.z]
+                    .map(|x| f64::round(x / TOLERANCE) as i64)
+                    .hash(&mut hasher)
+            });
+            shell.edges.iter().for_each(|e| e.vertices.hash(&mut hasher));
+            shell.faces.iter().for_each(|face| {
+                face.orientation.hash(&mut hasher);
+                face.boundaries.iter().for_each(|wire| {
+                    wire.iter()
+                        .for_each(|e| (e.index, e.orientation).hash(&mut hasher))
+                });
+            });
+        });
+        hasher.finish()
+    }
+}
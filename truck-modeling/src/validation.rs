@@ -0,0 +1,254 @@
+use crate::bvh::BoxTree;
+use crate::healing::{defects, Defect};
+use crate::{gap::edge_gaps, interference::face_witness, *};
+use std::collections::{HashMap, HashSet};
+
+/// An issue found by [`Validate::validate`], with the offending entity.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationIssue {
+    /// the edge is used by only one face, so the shell is not closed
+    OpenEdge(EdgeID),
+    /// the edge is used by more than two faces
+    NonManifoldEdge(EdgeID),
+    /// the neighborhood of the vertex is not a disk
+    SingularVertex(VertexID),
+    /// the shell is not connected
+    DisconnectedShell {
+        /// the index of the shell in the boundaries of the solid
+        shell_index: usize,
+    },
+    /// the edge is traversed in the same direction by two faces
+    InconsistentEdge(EdgeID),
+    /// the winding of the boundaries in the parameter space disagrees with the orientation of
+    /// the face, cf. [`OrientationAudit::winding_agreement`]
+    InvertedBoundary {
+        /// the id of the face
+        face_id: FaceID,
+        /// the sum of the signed areas of the boundaries, not positive
+        winding_agreement: f64,
+    },
+    /// the boundaries of the face cannot be projected to its surface, so the winding of the
+    /// boundaries is not checked
+    UnprojectableFace(FaceID),
+    /// the end of the curve of the edge is apart from the vertex
+    EdgeVertexDeviation {
+        /// the id of the edge
+        edge_id: EdgeID,
+        /// the id of the vertex
+        vertex_id: VertexID,
+        /// the distance between the end of the curve and the point of the vertex
+        deviation: f64,
+    },
+    /// the faces adjacent to the edge are apart from the edge or from each other,
+    /// cf. [`EdgeGap`]
+    FaceGap {
+        /// the id of the edge
+        edge_id: EdgeID,
+        /// the sample on the edge with the largest gap
+        point: Point3,
+        /// the largest gap of the samples on the edge
+        gap: f64,
+    },
+    /// the faces sharing no vertex intersect or touch each other
+    SelfIntersection {
+        /// the ids of the faces
+        face_ids: (FaceID, FaceID),
+        /// the points on the faces, cf. [`InterferenceWitness::points`]
+        points: (Point3, Point3),
+        /// the distance between the points
+        distance: f64,
+    },
+    /// the face is collapsed into a point
+    DegenerateFace {
+        /// the id of the face
+        face_id: FaceID,
+        /// the diameter of the bounding box of the samples on the boundaries of the face
+        size: f64,
+    },
+}
+
+/// The result of [`Validate::validate`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidationReport {
+    /// whether no issue is found
+    pub is_valid: bool,
+    /// the issues, in the order of the checks and of the shells
+    pub issues: Vec<ValidationIssue>,
+}
+
+/// Validates shells and solids before exporting them.
+pub trait Validate {
+    /// Runs all validations and reports the issues.
+    ///
+    /// The following checks are performed for each shell, in order:
+    /// 1. closure and manifoldness: the open, non-manifold and inconsistently oriented edges,
+    ///    the singular vertices and the disconnected shells,
+    /// 2. the degenerate faces whose sizes are within `tol`,
+    /// 3. the orientations of the faces, by [`AuditOrientation::orientation_audit`],
+    /// 4. the ends of the curves of the edges apart from the vertices by more than `tol`, and
+    /// 5. the gaps between the faces adjacent to the edges larger than `tol`, by
+    ///    [`BoundaryGap::max_boundary_gap`].
+    ///
+    /// Finally, the self-intersections are searched among all faces of all shells by
+    /// [`FaceProximity::proximity`]. The check is conservative: only the pairs of the faces
+    /// sharing no vertex are checked, since the adjacent faces always touch each other.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    /// assert!(cube.validate(1.0e-6).is_valid);
+    ///
+    /// // move a vertex of the cube without moving the curves
+    /// let vertex = cube.vertex_iter().next().unwrap();
+    /// vertex.set_point(Point3::new(-0.1, 0.0, 0.0));
+    /// let report = cube.validate(1.0e-6);
+    /// assert!(!report.is_valid);
+    /// assert!(report.issues.iter().all(|issue| matches!(
+    ///     issue,
+    ///     ValidationIssue::EdgeVertexDeviation { vertex_id, .. } if *vertex_id == vertex.id(),
+    /// )));
+    /// ```
+    fn validate(&self, tol: f64) -> ValidationReport;
+}
+
+/// the number of the samples on each edge for measuring the gaps
+const GAP_SAMPLES: usize = 4;
+
+impl Validate for Shell {
+    fn validate(&self, tol: f64) -> ValidationReport {
+        validate_shells(std::slice::from_ref(self), tol)
+    }
+}
+
+impl Validate for Solid {
+    fn validate(&self, tol: f64) -> ValidationReport { validate_shells(self.boundaries(), tol) }
+}
+
+/// Returns the diameter of the samples on the boundaries. The cached bounding box is not used,
+/// since it has the margin larger than the tolerances.
+fn face_size(face: &Face) -> f64 {
+    let samples: BoundingBox<Point3> = face
+        .edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.curve();
+            let ((t0, t1), n) = (curve.range_tuple(), GAP_SAMPLES as f64);
+            (0..=GAP_SAMPLES).map(move |i| curve.subs(t0 + (t1 - t0) * i as f64 / n))
+        })
+        .collect();
+    samples.diameter()
+}
+
+fn validate_shells(shells: &[Shell], tol: f64) -> ValidationReport {
+    let mut issues = Vec::new();
+    shells.iter().enumerate().for_each(|(shell_index, shell)| {
+        issues.extend(defects(shell).into_iter().map(|defect| match defect {
+            Defect::OpenEdge(id) => ValidationIssue::OpenEdge(id),
+            Defect::NonManifoldEdge(id) => ValidationIssue::NonManifoldEdge(id),
+            Defect::InconsistentEdge(id) => ValidationIssue::InconsistentEdge(id),
+            Defect::SingularVertex(id) => ValidationIssue::SingularVertex(id),
+            Defect::Disconnected => ValidationIssue::DisconnectedShell { shell_index },
+        }));
+        issues.extend(shell.face_iter().filter_map(|face| {
+            let size = face_size(face);
+            (size <= tol).then(|| ValidationIssue::DegenerateFace {
+                face_id: face.id(),
+                size,
+            })
+        }));
+        issues.extend(shell.face_iter().filter_map(|face| {
+            match face.orientation_audit().winding_agreement {
+                None => Some(ValidationIssue::UnprojectableFace(face.id())),
+                Some(winding_agreement) if winding_agreement <= 0.0 => {
+                    Some(ValidationIssue::InvertedBoundary {
+                        face_id: face.id(),
+                        winding_agreement,
+                    })
+                }
+                Some(_) => None,
+            }
+        }));
+        issues.extend(edge_vertex_deviations(shell, tol));
+        issues.extend(face_gaps(shell, tol));
+    });
+    issues.extend(self_intersections(shells, tol));
+    ValidationReport {
+        is_valid: issues.is_empty(),
+        issues,
+    }
+}
+
+fn edge_vertex_deviations(shell: &Shell, tol: f64) -> Vec<ValidationIssue> {
+    let mut checked = HashSet::new();
+    let mut issues = Vec::new();
+    shell.edge_iter().filter(|edge| checked.insert(edge.id())).for_each(|edge| {
+        let curve = edge.curve();
+        let (t0, t1) = curve.range_tuple();
+        let ends = [
+            (edge.absolute_front(), curve.subs(t0)),
+            (edge.absolute_back(), curve.subs(t1)),
+        ];
+        issues.extend(ends.into_iter().filter_map(|(vertex, point)| {
+            let deviation = vertex.point().distance(point);
+            (deviation > tol).then(|| ValidationIssue::EdgeVertexDeviation {
+                edge_id: edge.id(),
+                vertex_id: vertex.id(),
+                deviation,
+            })
+        }));
+    });
+    issues
+}
+
+/// Returns the largest gap of each edge larger than `tol`, in the order of the edges.
+fn face_gaps(shell: &Shell, tol: f64) -> Vec<ValidationIssue> {
+    let (gaps, _) = edge_gaps(shell, GAP_SAMPLES);
+    let mut edges = Vec::new();
+    let mut largest = HashMap::<EdgeID, EdgeGap>::new();
+    gaps.into_iter().filter(|gap| gap.gap() > tol).for_each(|gap| {
+        let entry = largest.entry(gap.edge_id).or_insert_with(|| {
+            edges.push(gap.edge_id);
+            gap
+        });
+        if gap.gap() > entry.gap() {
+            *entry = gap;
+        }
+    });
+    edges
+        .into_iter()
+        .map(|edge_id| ValidationIssue::FaceGap {
+            edge_id,
+            point: largest[&edge_id].point,
+            gap: largest[&edge_id].gap(),
+        })
+        .collect()
+}
+
+fn self_intersections(shells: &[Shell], tol: f64) -> Vec<ValidationIssue> {
+    let faces: Vec<&Face> = shells.iter().flat_map(Shell::face_iter).collect();
+    let vertices: Vec<HashSet<VertexID>> = faces
+        .iter()
+        .map(|face| face.vertex_iter().map(|v| v.id()).collect())
+        .collect();
+    let boxes: Vec<BoundingBox<Point3>> =
+        faces.iter().map(|face| face.cached_bounding_box()).collect();
+    let Some(tree) = BoxTree::new(&boxes) else {
+        return Vec::new();
+    };
+    let mut issues = Vec::new();
+    tree.find_pair(&tree, tol, |i, j| {
+        if i < j && vertices[i].is_disjoint(&vertices[j]) {
+            if let Some(witness) = face_witness(faces[i], faces[j], tol) {
+                issues.push(ValidationIssue::SelfIntersection {
+                    face_ids: witness.face_ids,
+                    points: witness.points,
+                    distance: witness.distance,
+                });
+            }
+        }
+        None::<()>
+    });
+    issues
+}
@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+use truck_modeling::{errors::Error, *};
+
+const CORNERS: [(f64, f64, f64); 8] = [
+    (0.0, 0.0, 0.0),
+    (1.0, 0.0, 0.0),
+    (1.0, 1.0, 0.0),
+    (0.0, 1.0, 0.0),
+    (0.0, 0.0, 1.0),
+    (1.0, 0.0, 1.0),
+    (1.0, 1.0, 1.0),
+    (0.0, 1.0, 1.0),
+];
+
+/// the corners of the faces of the unit cube, counterclockwise from the outside
+const FACES: [[usize; 4]; 6] = [
+    [0, 3, 2, 1],
+    [4, 5, 6, 7],
+    [0, 1, 5, 4],
+    [3, 7, 6, 2],
+    [0, 4, 7, 3],
+    [1, 2, 6, 5],
+];
+
+/// linear congruential generator, in order to make the tests reproducible
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        self.0 >> 33
+    }
+    /// a number in `[-1, 1]`
+    fn signed(&mut self) -> f64 { self.next() as f64 / (1u64 << 30) as f64 - 1.0 }
+    fn shuffle<T>(&mut self, vec: &mut [T]) {
+        (1..vec.len()).rev().for_each(|i| vec.swap(i, self.next() as usize % (i + 1)));
+    }
+}
+
+/// the faces of the cube without shared topology, whose vertices are moved by the noise of
+/// `1e-9`. The faces are oriented randomly.
+fn noisy_cube_faces(origin: Point3, size: f64, random: &mut Random) -> Vec<Face> {
+    FACES
+        .iter()
+        .map(|corners| {
+            let v: Vec<Vertex> = corners
+                .iter()
+                .map(|&c| {
+                    let noise = Vector3::new(random.signed(), random.signed(), random.signed());
+                    builder::vertex(origin + size * Vector3::from(CORNERS[c]) + noise * 1.0e-9)
+                })
+                .collect();
+            let wire: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+            let face: Face = builder::try_attach_plane(vec![wire]).unwrap();
+            match random.next() % 2 {
+                0 => face,
+                _ => face.inverse(),
+            }
+        })
+        .collect()
+}
+
+/// the orientations and the vertices of the faces, in order to check that the faces are intact
+fn snapshot(faces: &[Face]) -> Vec<(bool, Vec<VertexID>)> {
+    faces
+        .iter()
+        .map(|face| (face.orientation(), face.vertex_iter().map(|v| v.id()).collect()))
+        .collect()
+}
+
+#[test]
+fn noisy_cube() {
+    let mut random = Random(0);
+    for _ in 0..4 {
+        let mut faces = noisy_cube_faces(Point3::origin(), 1.0, &mut random);
+        random.shuffle(&mut faces);
+        let solid = builder::solid_from_faces(faces, AssembleOptions::default()).unwrap();
+
+        assert_eq!(solid.boundaries().len(), 1);
+        assert_eq!(solid.face_iter().count(), 6);
+        let edges: HashSet<EdgeID> = solid.edge_iter().map(|edge| edge.id()).collect();
+        assert_eq!(edges.len(), 12);
+        let vertices: HashSet<VertexID> = solid.vertex_iter().map(|v| v.id()).collect();
+        assert_eq!(vertices.len(), 8);
+        let bdb: BoundingBox<Point3> = solid.vertex_iter().map(|v| v.point()).collect();
+        assert!(bdb.min().distance(Point3::origin()) < 1.0e-8);
+        assert!(bdb.max().distance(Point3::new(1.0, 1.0, 1.0)) < 1.0e-8);
+        assert!(solid.is_geometric_consistent());
+        assert!(solid.contains_point(Point3::new(0.5, 0.5, 0.5)));
+        assert!(!solid.contains_point(Point3::new(1.5, 0.5, 0.5)));
+    }
+}
+
+#[test]
+fn open_boundary() {
+    let mut random = Random(1);
+    let mut faces = noisy_cube_faces(Point3::origin(), 1.0, &mut random);
+    let missing = faces.remove(3);
+    random.shuffle(&mut faces);
+    let before = snapshot(&faces);
+    let error = builder::solid_from_faces(faces.clone(), AssembleOptions::default()).unwrap_err();
+    assert_eq!(snapshot(&faces), before);
+
+    let err = truck_topology::errors::Error::NotClosedShell;
+    assert_eq!(error.cause, Error::FromTopology(err));
+    assert_eq!(error.shells.len(), 1);
+    assert_eq!(error.defects.len(), 4);
+    assert!(error.defects.iter().all(|d| matches!(d, Defect::OpenEdge(_))));
+    // the boundary loop is the one of the missing face
+    assert_eq!(error.open_boundaries.len(), 1);
+    let boundary = &error.open_boundaries[0];
+    assert_eq!(boundary.len(), 4);
+    assert!(boundary.is_closed());
+    for vertex in boundary.vertex_iter() {
+        let near = |w: Vertex| w.point().distance(vertex.point()) < 1.0e-8;
+        assert!(missing.vertex_iter().any(near));
+    }
+    // the other edges are welded, and the gaps are the noise
+    assert_eq!(error.gaps.len(), 1);
+    assert_eq!(error.gaps[0].samples, 8 * 4);
+    assert!(error.gaps[0].max.unwrap().gap() < 1.0e-8);
+    let welds = error.log.repairs.iter();
+    assert_eq!(welds.filter(|r| matches!(r, Repair::WeldEdges { .. })).count(), 8);
+}
+
+#[test]
+fn cube_with_cavity() {
+    let mut random = Random(2);
+    let mut faces = noisy_cube_faces(Point3::origin(), 3.0, &mut random);
+    faces.extend(noisy_cube_faces(Point3::new(1.0, 1.0, 1.0), 1.0, &mut random));
+    random.shuffle(&mut faces);
+    let solid = builder::solid_from_faces(faces, AssembleOptions::default()).unwrap();
+
+    assert_eq!(solid.boundaries().len(), 2);
+    assert!(solid.contains_point(Point3::new(0.5, 0.5, 0.5)));
+    assert!(solid.contains_point(Point3::new(2.5, 1.5, 1.5)));
+    assert!(!solid.contains_point(Point3::new(1.5, 1.5, 1.5)));
+    assert!(!solid.contains_point(Point3::new(3.5, 1.5, 1.5)));
+}
+
+#[test]
+fn merge_coplanar_faces() {
+    let mut random = Random(3);
+    let mut faces = noisy_cube_faces(Point3::origin(), 1.0, &mut random);
+    // the top face divided into two triangles by the diagonal
+    let top = faces.remove(1);
+    let p: Vec<Point3> = top.vertex_iter().map(|v| v.point()).collect();
+    for triangle in [[p[0], p[1], p[2]], [p[0], p[2], p[3]]] {
+        let v = builder::vertices(triangle);
+        let wire: Wire = (0..3).map(|i| builder::line(&v[i], &v[(i + 1) % 3])).collect();
+        faces.push(builder::try_attach_plane(vec![wire]).unwrap());
+    }
+    random.shuffle(&mut faces);
+
+    let solid = builder::solid_from_faces(faces.clone(), AssembleOptions::default()).unwrap();
+    assert_eq!(solid.face_iter().count(), 7);
+
+    let options = AssembleOptions {
+        merge_faces: true,
+        ..Default::default()
+    };
+    let solid = builder::solid_from_faces(faces, options).unwrap();
+    assert_eq!(solid.face_iter().count(), 6);
+    assert!(solid.is_geometric_consistent());
+    assert!(solid.contains_point(Point3::new(0.5, 0.5, 0.5)));
+    assert!(!solid.contains_point(Point3::new(0.5, 0.5, 1.5)));
+}
@@ -0,0 +1,104 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+mod common;
+use common::*;
+
+#[test]
+fn clean_cube() {
+    let shell = unit_cube_shell();
+    let audit = shell.orientation_audit();
+    assert!(audit.consistent);
+    assert!(audit.disagreements.is_empty());
+    assert_eq!(audit.faces.len(), 6);
+    for (face, face_audit) in shell.face_iter().zip(&audit.faces) {
+        assert_eq!(face_audit.face_id, face.id());
+        assert_eq!(face_audit.orientation, face.orientation());
+        assert!(face_audit.consistent);
+        assert!(face_audit.winding_agreement.unwrap() > 0.0);
+        assert_eq!(face_audit.wires.len(), 1);
+        let wire_audit = &face_audit.wires[0];
+        let wire = &face.boundaries()[0];
+        assert_eq!(wire_audit.edges.len(), 4);
+        let sign = match face.orientation() {
+            true => 1.0,
+            false => -1.0,
+        };
+        assert!(wire_audit.uv_area.unwrap() * sign > 0.0);
+        for (edge, edge_audit) in wire.edge_iter().zip(&wire_audit.edges) {
+            assert_eq!(edge_audit.edge_id, edge.id());
+            assert_eq!(edge_audit.forward, edge.orientation());
+            assert!(edge_audit.is_consistent());
+            assert_near!(edge_audit.junction.angle, PI / 2.0);
+        }
+    }
+}
+
+#[test]
+fn flipped_edge() {
+    let mut shell = unit_cube_shell();
+    let face = &shell[0];
+    let mut wire = face.absolute_boundaries()[0].clone();
+    let flipped_id = wire[1].id();
+    wire[1] = wire[1].inverse();
+    let mut flipped = Face::new_unchecked(vec![wire], face.surface());
+    if !face.orientation() {
+        flipped.invert();
+    }
+    shell[0] = flipped;
+
+    let audit = shell.orientation_audit();
+    assert!(!audit.consistent);
+    let face_audit = &audit.faces[0];
+    assert!(!face_audit.consistent);
+    let edges = &face_audit.wires[0].edges;
+    // the junctions at both ends of the flipped edge are broken
+    let broken: Vec<EdgeID> = edges
+        .iter()
+        .filter(|edge| !edge.junction.connected)
+        .map(|edge| edge.edge_id)
+        .collect();
+    assert_eq!(broken.len(), 2);
+    assert!(broken.contains(&flipped_id));
+    assert!(edges.iter().all(|edge| edge.consistent_ends));
+    assert_eq!(edges.iter().filter(|edge| edge.junction.gap > 0.5).count(), 2);
+    assert!(audit.faces[1..].iter().all(|audit| audit.consistent));
+
+    // the flipped edge is traversed in the same direction as the adjacent face
+    assert_eq!(audit.disagreements.len(), 1);
+    let disagreement = &audit.disagreements[0];
+    assert_eq!(disagreement.edge_id, flipped_id);
+    assert_eq!(disagreement.faces.len(), 2);
+    assert!(disagreement.faces.contains(&shell[0].id()));
+}
+
+#[test]
+fn inverted_face() {
+    let mut shell = unit_cube_shell();
+    shell[2].invert();
+    let audit = shell.orientation_audit();
+    assert!(!audit.consistent);
+    // the inverted face is consistent in itself
+    assert!(audit.faces.iter().all(|audit| audit.consistent));
+    assert_eq!(audit.disagreements.len(), 4);
+    let face_edges: Vec<(EdgeID, bool)> = shell[2]
+        .edge_iter()
+        .map(|edge| (edge.id(), edge.orientation()))
+        .collect();
+    for disagreement in &audit.disagreements {
+        assert!(face_edges.contains(&(disagreement.edge_id, disagreement.forward)));
+        assert_eq!(disagreement.faces.len(), 2);
+        assert!(disagreement.faces.contains(&shell[2].id()));
+    }
+}
+
+#[test]
+fn reversed_boundary() {
+    let shell = unit_cube_shell();
+    let face = &shell[0];
+    let wire = face.absolute_boundaries()[0].inverse();
+    let reversed = Face::new(vec![wire], face.surface());
+    let audit = reversed.orientation_audit();
+    assert!(!audit.consistent);
+    assert!(audit.winding_agreement.unwrap() < 0.0);
+    assert!(audit.wires[0].edges.iter().all(EdgeAudit::is_consistent));
+}
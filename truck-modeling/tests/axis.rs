@@ -0,0 +1,82 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+fn cube_center(solid: &Solid) -> Point3 {
+    let sum = solid.vertex_iter().fold(Vector3::zero(), |sum, v| sum + v.point().to_vec());
+    Point3::from_vec(sum / solid.vertex_iter().count() as f64)
+}
+
+fn marker() -> Solid {
+    let v = builder::vertex(Point3::new(1.9, -0.1, 0.9));
+    let e = builder::tsweep(&v, 0.2 * Vector3::unit_x());
+    let f = builder::tsweep(&e, 0.2 * Vector3::unit_y());
+    builder::tsweep(&f, 0.2 * Vector3::unit_z())
+}
+
+#[test]
+fn extruded_cylinder_axes() {
+    // the cylinder of radius 1 and height 2 along the line x = y = 1
+    let v = builder::vertex(Point3::new(2.0, 1.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::new(1.0, 1.0, 0.0), Vector3::unit_z(), Rad(2.0 * PI));
+    let disk = builder::try_attach_plane(&[circle]).unwrap();
+    let cylinder: Solid = builder::tsweep(&disk, 2.0 * Vector3::unit_z());
+    let shell = &cylinder.boundaries()[0];
+
+    // the wall is a spline surface
+    let wall = shell
+        .face_iter()
+        .find(|face| !matches!(face.surface(), Surface::Plane(_)))
+        .unwrap();
+    assert!(wall.normal_axis().is_none());
+    let (origin, axis) = wall.revolution_axis().unwrap();
+    assert!(axis.cross(Vector3::unit_z()).so_small());
+    assert_near!(origin.x, 1.0);
+    assert_near!(origin.y, 1.0);
+
+    // the top cap
+    let (cap, (center, normal)) = shell
+        .face_iter()
+        .filter_map(|face| Some((face, face.normal_axis()?)))
+        .find(|(_, (center, _))| center.z > 1.0)
+        .unwrap();
+    assert_near!(center, Point3::new(1.0, 1.0, 2.0));
+    assert_near!(normal, Vector3::unit_z());
+    assert!(cap.revolution_axis().is_none());
+
+    // rotate the marker around (2, 0, 1) by the quarter about the axes
+    let rotated = builder::rotated(&marker(), origin, axis, Rad(PI / 2.0));
+    let expected = match axis.z > 0.0 {
+        true => Point3::new(2.0, 2.0, 1.0),
+        false => Point3::new(0.0, 0.0, 1.0),
+    };
+    assert_near!(cube_center(&rotated), expected);
+    let rotated = builder::rotated_about_face_normal(&marker(), cap, Rad(PI / 2.0)).unwrap();
+    assert_near!(cube_center(&rotated), Point3::new(2.0, 2.0, 1.0));
+    assert_eq!(
+        builder::rotated_about_face_normal(&marker(), wall, Rad(PI)).unwrap_err(),
+        errors::Error::NoAxis,
+    );
+}
+
+#[test]
+fn revolved_cylinder_axes() {
+    // the wall of the cylinder around the y-axis, by revolving a line
+    let v = builder::vertices([(1.0, 0.0, 0.0), (1.0, 2.0, 0.0)]);
+    let line: Edge = builder::line(&v[0], &v[1]);
+    let wall: Shell = builder::rsweep(&line, Point3::origin(), Vector3::unit_y(), Rad(2.0 * PI));
+    let (origin, axis) = wall[0].revolution_axis().unwrap();
+    assert!(axis.cross(Vector3::unit_y()).so_small());
+    assert!((origin - Point3::origin()).cross(axis).so_small());
+
+    // the straight edge on the wall is also an axis
+    let (origin, dir) = line.as_axis().unwrap();
+    let rotated = builder::rotated_about_edge(&marker(), &line, Rad(PI)).unwrap();
+    assert_eq!((origin, dir), (Point3::new(1.0, 0.0, 0.0), Vector3::unit_y()));
+    assert_near!(cube_center(&rotated), Point3::new(0.0, 0.0, -1.0));
+
+    let arc: Edge = builder::circle_arc(&v[0], &v[1], Point3::new(2.0, 1.0, 0.0));
+    assert_eq!(
+        builder::rotated_about_edge(&marker(), &arc, Rad(PI)).unwrap_err(),
+        errors::Error::NoAxis,
+    );
+}
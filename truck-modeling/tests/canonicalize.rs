@@ -0,0 +1,159 @@
+use std::collections::HashSet;
+use truck_modeling::*;
+use truck_topology::compress::CompressedSolid;
+mod common;
+use common::*;
+
+type Key = [i64; 3];
+type Boundaries = Vec<Vec<(usize, bool)>>;
+
+fn quantize(v: Vector3) -> Key { [v.x, v.y, v.z].map(|x| f64::round(x * 1.0e6) as i64) }
+
+fn cube_zyx() -> Solid {
+    let v = builder::vertex(Point3::new(1.0, 1.0, 1.0));
+    let e = builder::tsweep(&v, -Vector3::unit_z());
+    let f: Face = builder::tsweep(&e, -Vector3::unit_y());
+    let solid: Solid = builder::tsweep(&f, -Vector3::unit_x());
+    // shuffle the faces and the start vertices of the boundaries, and invert the faces twice
+    let faces = solid.boundaries()[0]
+        .face_iter()
+        .rev()
+        .map(|face| {
+            let mut wire = face.boundaries().pop().unwrap();
+            wire.rotate_left(1);
+            let mut face = Face::new(vec![wire.inverse()], face.oriented_surface().inverse());
+            face.invert();
+            face
+        })
+        .collect();
+    Solid::new(vec![faces])
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct TopologyDump {
+    vertices: Vec<Key>,
+    edges: Vec<(usize, usize)>,
+    faces: Vec<(Boundaries, bool)>,
+}
+
+fn topology_dump(solid: &Solid) -> TopologyDump {
+    let CompressedSolid { boundaries } = solid.compress();
+    let shell = &boundaries[0];
+    TopologyDump {
+        vertices: shell.vertices.iter().map(|p| quantize(p.to_vec())).collect(),
+        edges: shell.edges.iter().map(|e| e.vertices).collect(),
+        faces: shell
+            .faces
+            .iter()
+            .map(|face| {
+                let boundaries = face
+                    .boundaries
+                    .iter()
+                    .map(|wire| wire.iter().map(|e| (e.index, e.orientation)).collect())
+                    .collect();
+                (boundaries, face.orientation)
+            })
+            .collect(),
+    }
+}
+
+/// the points at the middle of edges and the normals of faces at their vertices
+fn geometric_fingerprint(solid: &Solid) -> Vec<Key> {
+    let mut res = Vec::new();
+    for face in solid.face_iter() {
+        for edge in face.edge_iter() {
+            let curve = edge.oriented_curve();
+            let (t0, t1) = curve.range_tuple();
+            res.push(quantize(curve.subs((t0 + t1) / 2.0).to_vec()));
+        }
+        let surface = face.oriented_surface();
+        for vertex in face.vertex_iter() {
+            let (u, v) = surface.search_parameter(vertex.point(), None, 100).unwrap();
+            res.push(quantize(surface.normal(u, v)));
+        }
+    }
+    res
+}
+
+/// the sets of the vertices and the outer normals of faces, independent of the representation
+fn shape(solid: &Solid) -> HashSet<(Vec<Key>, Key)> {
+    solid
+        .face_iter()
+        .map(|face| {
+            let mut points: Vec<Key> = face
+                .vertex_iter()
+                .map(|v| quantize(v.point().to_vec()))
+                .collect();
+            points.sort();
+            let surface = face.oriented_surface();
+            let outer = &face.boundaries()[0];
+            let sum = outer.vertex_iter().map(|v| v.point().to_vec()).sum::<Vector3>();
+            let center = sum / outer.len() as f64;
+            let (u, v) = surface.search_parameter(Point3::from_vec(center), None, 100).unwrap();
+            (points, quantize(surface.normal(u, v)))
+        })
+        .collect()
+}
+
+#[test]
+fn congruent_cubes() {
+    let (mut cube0, mut cube1) = (unit_cube(), cube_zyx());
+    let (shape0, shape1) = (shape(&cube0), shape(&cube1));
+    assert_eq!(shape0, shape1);
+    assert_ne!(topology_dump(&cube0), topology_dump(&cube1));
+
+    cube0.canonicalize();
+    cube1.canonicalize();
+
+    // the shapes are unchanged
+    for cube in [&cube0, &cube1] {
+        assert!(cube.is_geometric_consistent());
+        assert!(Solid::try_new(cube.boundaries().clone()).is_ok());
+        assert_eq!(shape(cube), shape0);
+        assert!(cube.face_iter().all(Face::orientation));
+    }
+
+    assert_eq!(topology_dump(&cube0), topology_dump(&cube1));
+    assert_eq!(geometric_fingerprint(&cube0), geometric_fingerprint(&cube1));
+
+    // idempotent
+    let dump = topology_dump(&cube0);
+    cube0.canonicalize();
+    assert_eq!(topology_dump(&cube0), dump);
+}
+
+#[test]
+fn inner_boundaries() {
+    let v = builder::vertices([
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ]);
+    let outer: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    let square = |x: f64, y: f64| -> Wire {
+        let v = builder::vertices([
+            (x, y, 0.0),
+            (x, y + 1.0, 0.0),
+            (x + 1.0, y + 1.0, 0.0),
+            (x + 1.0, y, 0.0),
+        ]);
+        (0..4).rev().map(|i| builder::line(&v[(i + 1) % 4], &v[i])).collect::<Wire>()
+    };
+    let wires = vec![outer, square(5.0, 5.0), square(1.0, 1.0), square(1.0, 5.0)];
+    let face: Face = builder::try_attach_plane(wires).unwrap();
+    let sheet: Solid = builder::tsweep(&face, Vector3::unit_z());
+
+    let mut solid = sheet.clone();
+    solid.canonicalize();
+    assert_eq!(shape(&solid), shape(&sheet));
+    let bottom = solid.face_iter().find(|face| face.boundaries().len() == 4).unwrap();
+    let corners: Vec<Point3> = bottom.boundaries()[1..]
+        .iter()
+        .map(|wire| wire.front_vertex().unwrap().point())
+        .collect();
+    assert_eq!(
+        corners,
+        vec![Point3::new(1.0, 1.0, 0.0), Point3::new(1.0, 5.0, 0.0), Point3::new(5.0, 5.0, 0.0)],
+    );
+}
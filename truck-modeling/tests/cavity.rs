@@ -0,0 +1,47 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+mod common;
+use common::*;
+
+/// the cube of size 3 with the cubic cavity of size 1 at the center
+fn cube_with_cavity() -> Solid {
+    let mut outer = cube(Point3::origin(), 3.0).into_boundaries();
+    let mut cavity = cube(Point3::new(1.0, 1.0, 1.0), 1.0);
+    cavity.not();
+    outer.append(&mut cavity.into_boundaries());
+    Solid::new(outer)
+}
+
+/// the signed volume bounded by the shell of planar polygons
+fn signed_volume(shell: &Shell) -> f64 {
+    shell
+        .face_iter()
+        .flat_map(|face| face.boundaries())
+        .map(|wire| {
+            let points: Vec<Vector3> = wire.vertex_iter().map(|v| v.point().to_vec()).collect();
+            let area = (0..points.len()).fold(Vector3::zero(), |sum, i| {
+                sum + points[i].cross(points[(i + 1) % points.len()])
+            });
+            points[0].dot(area) / 6.0
+        })
+        .sum()
+}
+
+#[test]
+fn rotated_cavity() {
+    let solid = cube_with_cavity();
+    let axis = Vector3::new(1.0, 1.0, 1.0).normalize();
+    let rotated = builder::rotated(&solid, Point3::new(1.5, 1.5, 1.5), axis, Rad(PI / 3.0));
+
+    let volumes: Vec<f64> = rotated.boundaries().iter().map(signed_volume).collect();
+    assert_near!(volumes[0], 27.0);
+    assert_near!(volumes[1], -1.0);
+
+    // the center is in the cavity, out of the solid.
+    assert!(!rotated.contains_point(Point3::new(1.5, 1.5, 1.5)));
+    let matrix = Matrix4::from_translation(Vector3::new(1.5, 1.5, 1.5))
+        * Matrix4::from_axis_angle(axis, Rad(PI / 3.0))
+        * Matrix4::from_translation(Vector3::new(-1.5, -1.5, -1.5));
+    let wall = matrix.transform_point(Point3::new(0.5, 1.5, 1.5));
+    assert!(rotated.contains_point(wall));
+}
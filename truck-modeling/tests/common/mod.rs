@@ -0,0 +1,16 @@
+#![allow(dead_code)]
+use truck_modeling::*;
+
+/// the cube `[origin, origin + size]^3` swept along the axes
+pub fn cube(origin: Point3, size: f64) -> Solid {
+    let v = builder::vertex(origin);
+    let e = builder::tsweep(&v, size * Vector3::unit_x());
+    let f = builder::tsweep(&e, size * Vector3::unit_y());
+    builder::tsweep(&f, size * Vector3::unit_z())
+}
+
+/// the cube `[0, 1]^3`
+pub fn unit_cube() -> Solid { cube(Point3::origin(), 1.0) }
+
+/// the boundary of the cube `[0, 1]^3`
+pub fn unit_cube_shell() -> Shell { unit_cube().into_boundaries().pop().unwrap() }
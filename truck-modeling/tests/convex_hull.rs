@@ -0,0 +1,49 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+/// the signed volume bounded by the shell of planar polygons
+fn signed_volume(shell: &Shell) -> f64 {
+    shell
+        .face_iter()
+        .flat_map(|face| face.boundaries())
+        .map(|wire| {
+            let points: Vec<Vector3> = wire.vertex_iter().map(|v| v.point().to_vec()).collect();
+            let area = (0..points.len()).fold(Vector3::zero(), |sum, i| {
+                sum + points[i].cross(points[(i + 1) % points.len()])
+            });
+            points[0].dot(area) / 6.0
+        })
+        .sum()
+}
+
+#[test]
+fn convex_hull_of_cube() {
+    let bbox = BoundingBox::from_iter([Point3::new(-1.0, 0.0, 2.0), Point3::new(1.0, 3.0, 4.0)]);
+    let cube: Solid = primitive::cuboid(bbox);
+    let hull = cube.convex_hull(0.01).unwrap();
+    let shell = &hull.boundaries()[0];
+    assert_eq!(shell.len(), 6);
+    assert!(shell.face_iter().all(|face| face.boundaries()[0].len() == 4));
+    assert_near!(signed_volume(shell), 12.0);
+}
+
+#[test]
+fn convex_hull_of_cylinder() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI));
+    let disk = builder::try_attach_plane(&[circle]).unwrap();
+    let cylinder: Solid = builder::tsweep(&disk, Vector3::new(0.0, 0.0, 2.0));
+
+    let tol = 0.01;
+    let hull = cylinder.convex_hull(tol).unwrap();
+    let shell = &hull.boundaries()[0];
+    // the polygonal top and bottom are merged into single faces
+    let polygons = shell.face_iter().filter(|face| face.boundaries()[0].len() > 4);
+    assert_eq!(polygons.count(), 2);
+    // the inscribed prism is outward and approximates the cylinder
+    let volume = signed_volume(shell);
+    assert!(volume > 0.0 && volume <= 2.0 * PI);
+    assert!(2.0 * PI - volume < 2.0 * PI * 2.0 * tol, "{volume}");
+    assert!(hull.contains_point(Point3::new(0.0, 0.0, 1.0)));
+    assert!(!hull.contains_point(Point3::new(1.01, 0.0, 1.0)));
+}
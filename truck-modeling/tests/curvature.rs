@@ -0,0 +1,77 @@
+use truck_modeling::*;
+
+#[test]
+fn circle_has_constant_curvature() {
+    let v0 = builder::vertex(Point3::new(2.0, 0.0, 0.0));
+    let v1 = builder::vertex(Point3::new(-2.0, 0.0, 0.0));
+    let edge: Edge = builder::circle_arc(&v0, &v1, Point3::new(0.0, 2.0, 0.0));
+    let profile = edge.curvature_profile(32);
+    assert_eq!(profile.samples.len(), 32);
+    profile.samples.iter().for_each(|sample| {
+        assert!(f64::abs(sample.curvature - 0.5) < 1.0e-6, "{sample:?}");
+        assert_near!(sample.position + 2.0 * sample.comb, Point3::origin());
+    });
+    assert!(profile.inflections.is_empty());
+    assert_eq!(profile.monotone_spans, vec![true]);
+    assert_near!(profile.samples[31].arc_length, 2.0 * std::f64::consts::PI);
+
+    // arc length sampling
+    let lengths: Vec<f64> = profile
+        .samples
+        .windows(2)
+        .map(|w| w[0].position.distance(w[1].position))
+        .collect();
+    assert!(lengths.iter().all(|len| f64::abs(len - lengths[0]) < 1.0e-6));
+}
+
+#[test]
+fn s_curve_inflection() {
+    let v = builder::vertices([(0.0, 0.0, 0.0), (3.0, 0.0, 0.0)]);
+    let inter_points = vec![Point3::new(1.0, 1.0, 0.0), Point3::new(2.0, -1.0, 0.0)];
+    let edge: Edge = builder::bezier(&v[0], &v[1], inter_points);
+    for samples in [5, 16, 33, 100] {
+        let profile = edge.curvature_profile(samples);
+        assert_eq!(profile.inflections.len(), 1, "samples: {samples}");
+        let inflection = profile.inflections[0];
+        assert!(f64::abs(inflection.parameter - 0.5) < 1.0e-6);
+        let half_length = profile.samples[samples - 1].arc_length / 2.0;
+        assert!(f64::abs(inflection.arc_length - half_length) < 1.0e-3);
+        assert_eq!(profile.monotone_spans.len(), 2);
+    }
+
+    // the inverted edge has also one inflection
+    let profile = edge.inverse().curvature_profile(16);
+    assert_eq!(profile.inflections.len(), 1);
+    assert!(f64::abs(profile.inflections[0].parameter - 0.5) < 1.0e-6);
+}
+
+#[test]
+fn g2_junction() {
+    let knot_vec = KnotVec::uniform_knot(3, 2);
+    let control_points = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 2.0, 0.0),
+        Point3::new(2.0, -1.0, 1.0),
+        Point3::new(3.0, 1.0, 0.0),
+        Point3::new(4.0, 0.0, 2.0),
+    ];
+    let mut bsp0 = BSplineCurve::new(knot_vec, control_points);
+    let bsp1 = bsp0.cut(0.5);
+    let v = builder::vertices([bsp0.front(), bsp1.front(), bsp1.back()]);
+    let wire: Wire = vec![
+        Edge::new(&v[0], &v[1], bsp0.into()),
+        Edge::new(&v[1], &v[2], bsp1.into()),
+    ]
+    .into();
+    let profile = wire.curvature_profile(64);
+    assert!(profile.reference_normal.is_none());
+    assert_eq!(profile.edge_boundaries.len(), 2);
+    let idx = profile.edge_boundaries[1];
+    let (p, q) = (profile.samples[idx - 1], profile.samples[idx]);
+    assert_eq!((p.edge_index, q.edge_index), (0, 1));
+    assert_near!(p.position, q.position);
+    assert_near!(p.arc_length, q.arc_length);
+    assert!(f64::abs(p.curvature - q.curvature) < 1.0e-6 * p.curvature.abs());
+    let max = profile.samples.iter().map(|s| s.curvature).fold(0.0, f64::max);
+    assert!(profile.max_curvature >= max);
+}
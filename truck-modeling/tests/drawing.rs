@@ -0,0 +1,110 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+fn length(curve: &Curve) -> f64 {
+    let (_, points) = curve.parameter_division(curve.range_tuple(), 1.0e-4);
+    points.windows(2).map(|p| p[0].distance(p[1])).sum()
+}
+
+/// the cylinder of the radius 1 and the height 1 around the y-axis, by revolving lines
+fn revoluted_cylinder() -> Solid {
+    let p = [(0.0, 1.0, 0.0), (1.0, 1.0, 0.0), (1.0, 0.0, 0.0), (0.0, 0.0, 0.0)];
+    let v = builder::vertices(p);
+    let wire: Wire = (0..3).map(|i| builder::line(&v[i], &v[i + 1])).collect();
+    Solid::new(vec![builder::cone(&wire, Vector3::unit_y(), Rad(2.0 * PI))])
+}
+
+#[test]
+fn cube() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+
+    let wireframe = cube.wireframe();
+    assert_eq!(wireframe.edges.len(), 12);
+    assert!(wireframe.unresolved.is_empty());
+    assert_eq!(wireframe.feature_edges().count(), 12);
+    let curves = cube.silhouette_curves(Vector3::new(1.0, 2.0, 3.0), 1.0e-3).unwrap();
+    assert!(curves.is_empty());
+}
+
+#[test]
+fn cylinder() {
+    let cylinder = revoluted_cylinder();
+    let wireframe = cylinder.wireframe();
+    // the seams are smooth, and the rims of three arcs are feature edges
+    assert!(wireframe.smooth_edges().count() >= 2);
+    assert_eq!(wireframe.feature_edges().count(), 6);
+    wireframe.feature_edges().for_each(|edge| {
+        let (p, q) = (edge.front().point(), edge.back().point());
+        assert_near!(p.x * p.x + p.z * p.z, 1.0);
+        assert_near!(q.x * q.x + q.z * q.z, 1.0);
+        assert_near!(p.y, q.y);
+    });
+
+    let curves = cylinder.silhouette_curves(Vector3::unit_x(), 1.0e-3).unwrap();
+    assert_eq!(curves.len(), 2);
+    let mut zs: Vec<f64> = curves
+        .iter()
+        .map(|curve| {
+            let Curve::Line(line) = curve else {
+                panic!("the silhouette is not a line: {curve:?}");
+            };
+            assert_near!(line.0.x, 0.0);
+            assert_near!(line.1.x, 0.0);
+            assert_near!(line.0.z, line.1.z);
+            assert_near!(f64::abs(line.1.y - line.0.y), 1.0);
+            line.0.z
+        })
+        .collect();
+    zs.sort_by(f64::total_cmp);
+    assert_near!(zs[0], -1.0);
+    assert_near!(zs[1], 1.0);
+
+    // viewed along the axis, the side face has no silhouettes
+    assert!(cylinder.silhouette_curves(Vector3::unit_y(), 1.0e-3).unwrap().is_empty());
+}
+
+#[test]
+fn nurbs_cylinder() {
+    let start = Point3::new(1.0, 0.0, 0.0);
+    let wire: Wire = primitive::circle(start, Point3::origin(), Vector3::unit_y(), 3);
+    let disk = builder::try_attach_plane(vec![wire]).unwrap();
+    let cylinder: Solid = builder::tsweep(&disk, Vector3::unit_y());
+
+    let tol = 1.0e-3;
+    let curves = cylinder.silhouette_curves(Vector3::unit_x(), tol).unwrap();
+    assert_eq!(curves.len(), 2);
+    curves.iter().for_each(|curve| {
+        let (_, points) = curve.parameter_division(curve.range_tuple(), tol);
+        points.iter().for_each(|p| {
+            assert!(p.x.abs() < tol, "{p:?}");
+            assert!((p.z.abs() - 1.0).abs() < tol, "{p:?}");
+        });
+        assert!((length(curve) - 1.0).abs() < 10.0 * tol);
+    });
+}
+
+#[test]
+fn sphere() {
+    let v0 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let v1 = builder::vertex(Point3::new(0.0, -1.0, 0.0));
+    let arc = builder::circle_arc(&v0, &v1, Point3::new(1.0, 0.0, 0.0));
+    let wire: Wire = vec![arc].into();
+    let shell = builder::cone(&wire, Vector3::unit_y(), Rad(2.0 * PI));
+    let sphere = Solid::new(vec![shell]);
+
+    let dir = Vector3::new(1.0, 0.0, 1.0);
+    let curves = sphere.silhouette_curves(dir, 1.0e-3).unwrap();
+    assert!(!curves.is_empty());
+    curves.iter().for_each(|curve| {
+        let (_, points) = curve.parameter_division(curve.range_tuple(), 1.0e-3);
+        points.iter().for_each(|p| {
+            assert_near!(p.to_vec().dot(dir), 0.0);
+            assert_near!(p.to_vec().magnitude(), 1.0);
+        });
+    });
+    let total: f64 = curves.iter().map(length).sum();
+    assert!((total - 2.0 * PI).abs() < 1.0e-3, "{total}");
+}
@@ -0,0 +1,51 @@
+use truck_modeling::{errors::Error, *};
+
+fn square(p: [(f64, f64, f64); 4]) -> Wire {
+    let v = builder::vertices(p);
+    (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect()
+}
+
+/// the square `[0, 3] x [0, 3]` on the plane `z = 0` with the square hole `[1, 2] x [1, 2]`
+fn square_with_hole() -> Face {
+    let outer = square([(0.0, 0.0, 0.0), (3.0, 0.0, 0.0), (3.0, 3.0, 0.0), (0.0, 3.0, 0.0)]);
+    let hole = square([(1.0, 1.0, 0.0), (1.0, 2.0, 0.0), (2.0, 2.0, 0.0), (2.0, 1.0, 0.0)]);
+    builder::try_attach_plane(vec![outer, hole]).unwrap()
+}
+
+#[test]
+fn extrude_with_hole() {
+    let face = square_with_hole();
+    for (vector, z) in [((0.0, 0.0, 2.0), 1.0), ((0.5, 0.0, -2.0), -1.0)] {
+        let solid = builder::extrude(&face, Vector3::from(vector)).unwrap();
+        assert_eq!(solid.boundaries().len(), 1);
+        assert_eq!(solid.face_iter().count(), 10);
+        assert!(solid.is_geometric_consistent());
+        assert!(solid.boundaries()[0].face_iter().any(|f| f.id() == face.id()));
+
+        let x = vector.0 / 2.0;
+        assert!(solid.contains_point(Point3::new(x + 0.5, 0.5, z)));
+        assert!(solid.contains_point(Point3::new(x + 2.5, 1.5, z)));
+        // the hole goes through the solid
+        assert!(!solid.contains_point(Point3::new(x + 1.5, 1.5, z)));
+        assert!(!solid.contains_point(Point3::new(1.5, 1.5, -z)));
+    }
+
+    let inverted = builder::extrude(&face.inverse(), Vector3::unit_z()).unwrap();
+    assert!(inverted.contains_point(Point3::new(0.5, 0.5, 0.5)));
+    assert!(!inverted.contains_point(Point3::new(1.5, 1.5, 0.5)));
+}
+
+#[test]
+fn extrude_failures() {
+    let face = square_with_hole();
+    let res = builder::extrude(&face, Vector3::new(1.0, 1.0, 0.0));
+    assert_eq!(res.unwrap_err(), Error::ExtrusionParallelToFace);
+    let res = builder::extrude(&face, Vector3::zero());
+    assert_eq!(res.unwrap_err(), Error::ExtrusionParallelToFace);
+
+    let v = builder::vertices([(1.0, 0.0, 0.0), (-1.0, 0.0, 0.0)]);
+    let arc: Edge = builder::circle_arc(&v[0], &v[1], Point3::new(0.0, 1.0, 0.0));
+    let wall: Face = builder::tsweep(&arc, Vector3::unit_z());
+    let res = builder::extrude(&wall, Vector3::unit_x());
+    assert_eq!(res.unwrap_err(), Error::NotPlanarFace);
+}
@@ -0,0 +1,74 @@
+use truck_modeling::{errors::Error, *};
+
+fn unit_sphere() -> Surface {
+    let v = builder::vertices([(0.0, 0.0, 1.0), (0.0, 0.0, -1.0)]);
+    let meridian: Edge = builder::circle_arc(&v[0], &v[1], Point3::new(1.0, 0.0, 0.0));
+    let axis = Vector3::unit_z();
+    let surface = RevolutedCurve::by_revolution(meridian.curve(), Point3::origin(), axis);
+    Processor::new(surface).into()
+}
+
+fn on_sphere(x: f64, y: f64, z: f64) -> Point3 {
+    Point3::from_vec(Vector3::new(x, y, z).normalize())
+}
+
+/// a triangle on the unit sphere bounded by circle arcs
+fn spherical_triangle() -> Wire {
+    let p = [on_sphere(1.0, 0.1, 0.1), on_sphere(1.0, 0.5, 0.1), on_sphere(1.0, 0.3, 0.5)];
+    let v = builder::vertices(p);
+    (0..3)
+        .map(|i| {
+            let (p, q) = (p[i], p[(i + 1) % 3]);
+            let transit = on_sphere(p.x + q.x, p.y + q.y, p.z + q.z);
+            builder::circle_arc(&v[i], &v[(i + 1) % 3], transit)
+        })
+        .collect()
+}
+
+#[test]
+fn face_on_sphere() {
+    let surface = unit_sphere();
+    let wire = spherical_triangle();
+    let res0 = builder::face_on_surface(surface.clone(), wire.clone(), Vec::new(), 1.0e-6);
+    let res1 = builder::face_on_surface(surface, wire.inverse(), Vec::new(), 1.0e-6);
+    let (face, err) = match (res0, res1) {
+        (Ok(face), Err(err)) | (Err(err), Ok(face)) => (face, err),
+        (res0, res1) => panic!("{res0:?}, {res1:?}"),
+    };
+    assert_eq!(err, Error::InvalidLoopOrientation);
+    assert!(face.is_geometric_consistent());
+
+    // the normal is compatible with the counterclockwise boundary
+    let surface = face.oriented_surface();
+    let center = on_sphere(1.0, 0.3, 0.23);
+    let (u, v) = surface.search_parameter(center, None, 100).unwrap();
+    let normal = surface.normal(u, v);
+    let boundary = face.boundaries()[0].clone();
+    let points: Vec<Point3> = boundary.vertex_iter().map(|v| v.point()).collect();
+    let winding = (points[1] - points[0]).cross(points[2] - points[1]);
+    assert!(winding.dot(normal) > 0.0);
+}
+
+#[test]
+fn invalid_boundaries() {
+    let surface = unit_sphere();
+    let p = [on_sphere(1.0, 0.1, 0.1), on_sphere(1.0, 0.5, 0.1), on_sphere(1.0, 0.3, 0.5)];
+    let v = builder::vertices(p);
+
+    // the chord of the sphere is not on the sphere
+    let mut wire = spherical_triangle();
+    let chord = builder::line(wire[0].front(), wire[0].back());
+    wire[0] = chord.clone();
+    let res = builder::face_on_surface(surface.clone(), wire.clone(), Vec::new(), 1.0e-6);
+    assert_eq!(res.unwrap_err(), Error::EdgeNotOnSurface(chord.id()));
+    let res = builder::face_on_surface(surface.clone(), wire.inverse(), Vec::new(), 1.0e-6);
+    assert_eq!(res.unwrap_err(), Error::EdgeNotOnSurface(chord.id()));
+
+    let transit = on_sphere(2.0, 0.6, 0.2);
+    let open: Wire = vec![builder::circle_arc(&v[0], &v[1], transit)].into();
+    let res = builder::face_on_surface(surface, open, Vec::new(), 1.0e-6);
+    assert_eq!(
+        res.unwrap_err(),
+        Error::FromTopology(truck_topology::errors::Error::NotClosedWire)
+    );
+}
@@ -0,0 +1,229 @@
+use std::collections::HashSet;
+use truck_modeling::{errors::Error, *};
+mod common;
+use common::*;
+
+fn vertical_edge(solid: &Solid, x: f64, y: f64) -> EdgeID {
+    let edge = solid.edge_iter().find(|edge| {
+        let (p, q) = (edge.front().point(), edge.back().point());
+        p.x.near(&x) && p.y.near(&y) && q.x.near(&x) && q.y.near(&y)
+    });
+    edge.unwrap().id()
+}
+
+#[test]
+fn fillet_cube_edges() {
+    let cube = unit_cube();
+    let radius = 0.2;
+    let solid = builder::fillet_edge(&cube, vertical_edge(&cube, 1.0, 1.0), radius, TOLERANCE);
+    let solid = solid.unwrap();
+    assert!(solid.is_geometric_consistent());
+    assert_eq!(solid.face_iter().count(), 7);
+    let edges: HashSet<EdgeID> = solid.edge_iter().map(|edge| edge.id()).collect();
+    assert_eq!(edges.len(), 15);
+
+    // the fillet face is on the cylinder around the offset axis
+    let axis = Point3::new(1.0 - radius, 1.0 - radius, 0.0);
+    let fillet = solid
+        .face_iter()
+        .find(|face| !matches!(face.surface(), Surface::Plane(_)))
+        .unwrap();
+    for vertex in fillet.vertex_iter() {
+        let p = vertex.point();
+        assert_near!(Vector2::new(p.x - axis.x, p.y - axis.y).magnitude(), radius);
+    }
+    let surface = fillet.oriented_surface();
+    let normal = Vector3::new(1.0, 1.0, 0.0).normalize();
+    let point = axis + normal * radius + Vector3::new(0.0, 0.0, 0.5);
+    let (u, v) = surface.search_parameter(point, None, 100).unwrap();
+    assert_near!(surface.normal(u, v), normal);
+
+    let inner = 1.0 - radius * 0.5;
+    let outer = 1.0 - radius * 0.1;
+    assert!(solid.contains_point(Point3::new(inner, inner, 0.5)));
+    assert!(!solid.contains_point(Point3::new(outer, outer, 0.5)));
+
+    // the opposite edge is not affected by the first fillet
+    let solid = builder::fillet_edge(&solid, vertical_edge(&solid, 0.0, 0.0), radius, TOLERANCE);
+    let solid = solid.unwrap();
+    assert!(solid.is_geometric_consistent());
+    assert_eq!(solid.face_iter().count(), 8);
+    assert!(!solid.contains_point(Point3::new(1.0 - outer, 1.0 - outer, 0.5)));
+    assert!(solid.contains_point(Point3::new(0.5, 0.5, 0.5)));
+}
+
+#[test]
+fn fillet_errors() {
+    let cube = unit_cube();
+    let edge = vertical_edge(&cube, 1.0, 0.0);
+    let res = builder::fillet_edge(&cube, edge, 1.5, TOLERANCE);
+    assert_eq!(res.unwrap_err(), Error::FilletRadiusTooLarge);
+
+    // the concave edge of an L-shaped prism
+    let v = builder::vertices([
+        (0.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (2.0, 1.0, 0.0),
+        (1.0, 1.0, 0.0),
+        (1.0, 2.0, 0.0),
+        (0.0, 2.0, 0.0),
+    ]);
+    let wire: Wire = (0..6).map(|i| builder::line(&v[i], &v[(i + 1) % 6])).collect();
+    let face: Face = builder::try_attach_plane(vec![wire]).unwrap();
+    let prism: Solid = builder::tsweep(&face, Vector3::unit_z());
+    let edge = vertical_edge(&prism, 1.0, 1.0);
+    let res = builder::fillet_edge(&prism, edge, 0.2, TOLERANCE);
+    assert_eq!(res.unwrap_err(), Error::CannotFillet);
+
+    // the apex of a pyramid is a corner of four fillets
+    let v = builder::vertices([
+        (0.0, 0.0, 0.0),
+        (1.0, 0.0, 0.0),
+        (1.0, 1.0, 0.0),
+        (0.0, 1.0, 0.0),
+        (0.5, 0.5, 1.0),
+    ]);
+    let base: Vec<Edge> = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    let ridges: Vec<Edge> = (0..4).map(|i| builder::line(&v[i], &v[4])).collect();
+    let mut faces: Vec<Face> = (0..4)
+        .map(|i| {
+            let wire = vec![base[i].clone(), ridges[(i + 1) % 4].clone(), ridges[i].inverse()];
+            builder::try_attach_plane(vec![wire.into()]).unwrap()
+        })
+        .collect();
+    let bottom: Wire = base.iter().rev().map(Edge::inverse).collect();
+    faces.push(builder::try_attach_plane(vec![bottom]).unwrap());
+    let pyramid = Solid::new(vec![faces.into()]);
+    let res = builder::round_edges(&pyramid, 0.05, TOLERANCE);
+    assert_eq!(res.unwrap_err(), Error::FilletCorners(vec![v[4].id()]));
+}
+
+#[test]
+fn fillet_skew_edge() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::new(0.5, 1.0, 0.0));
+    let solid: Solid = builder::tsweep(&f, Vector3::new(0.3, 0.2, 1.0));
+    let edge = solid.edge_iter().find(|edge| {
+        let (p, q) = (edge.front().point(), edge.back().point());
+        p.z.near(&0.0) && q.z.near(&0.0) && p.y.near(&0.0) && q.y.near(&0.0)
+    });
+    let filleted = builder::fillet_edge(&solid, edge.unwrap().id(), 0.1, TOLERANCE).unwrap();
+    assert!(filleted.is_geometric_consistent());
+    assert!(filleted.contains_point(Point3::new(0.5, 0.05, 0.05)));
+    assert!(!filleted.contains_point(Point3::new(0.5, 0.005, 0.005)));
+    assert!(filleted.contains_point(Point3::new(0.8, 0.5, 0.5)));
+
+    // the corner patches of the oblique corners
+    let rounded = builder::round_edges(&solid, 0.1, TOLERANCE).unwrap();
+    assert!(rounded.is_geometric_consistent());
+    assert_eq!(rounded.face_iter().count(), 26);
+    assert!(rounded.contains_point(Point3::new(0.8, 0.5, 0.5)));
+}
+
+#[test]
+fn fillet_several_edges() {
+    let cube = unit_cube();
+    let radius = 0.2;
+    // the duplicated id is filleted once
+    let edges = [
+        vertical_edge(&cube, 0.0, 0.0),
+        vertical_edge(&cube, 1.0, 0.0),
+        vertical_edge(&cube, 0.0, 0.0),
+    ];
+    let solid = builder::fillet_edges(&cube, &edges, radius, TOLERANCE).unwrap();
+    assert!(solid.is_geometric_consistent());
+    assert_eq!(solid.face_iter().count(), 8);
+    let edge_ids: HashSet<EdgeID> = solid.edge_iter().map(|edge| edge.id()).collect();
+    assert_eq!(edge_ids.len(), 18);
+    let fillets = solid
+        .face_iter()
+        .filter(|face| !matches!(face.surface(), Surface::Plane(_)))
+        .count();
+    assert_eq!(fillets, 2);
+
+    // the face between the fillets is trimmed from both ends
+    let (inner, outer) = (radius * 0.5, radius * 0.1);
+    for x in [inner, 1.0 - inner] {
+        assert!(solid.contains_point(Point3::new(x, inner, 0.5)));
+    }
+    for x in [outer, 1.0 - outer] {
+        assert!(!solid.contains_point(Point3::new(x, outer, 0.5)));
+    }
+    assert!(solid.contains_point(Point3::new(1.0 - outer, 1.0 - outer, 0.5)));
+
+    // the trimmed edges of the face would be reversed
+    let res = builder::fillet_edges(&cube, &edges, 0.6, TOLERANCE);
+    assert_eq!(res.unwrap_err(), Error::FilletRadiusTooLarge);
+
+    // the vertical edge and the bottom edge along the x-axis meet at the origin
+    let bottom = cube.edge_iter().find(|edge| {
+        let (p, q) = (edge.front().point(), edge.back().point());
+        p.y.so_small() && p.z.so_small() && q.y.so_small() && q.z.so_small()
+    });
+    let edges = [vertical_edge(&cube, 0.0, 0.0), bottom.unwrap().id()];
+    let solid = builder::fillet_edges(&cube, &edges, radius, TOLERANCE).unwrap();
+    assert!(solid.is_geometric_consistent());
+    assert_eq!(solid.face_iter().count(), 8);
+    // the fillets are cut by the plane `x = z`, which contains the edge along the y-axis
+    let (inner, outer) = (radius * 0.5, radius * 0.1);
+    assert!(solid.contains_point(Point3::new(inner, 0.5, inner)));
+    assert!(!solid.contains_point(Point3::new(0.5, outer, outer)));
+    assert!(!solid.contains_point(Point3::new(outer, outer, 0.5)));
+    assert!(!solid.contains_point(Point3::new(outer, outer, outer)));
+    assert!(solid.contains_point(Point3::new(radius, inner, radius)));
+}
+
+#[test]
+fn round_box_edges() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::new(2.0, 0.0, 0.0));
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cuboid: Solid = builder::tsweep(&f, Vector3::new(0.0, 0.0, 0.5));
+    let radius = 0.2;
+    let solid = builder::round_edges(&cuboid, radius, TOLERANCE).unwrap();
+    assert!(solid.is_geometric_consistent());
+    // the 6 trimmed faces, the 12 fillets and the 8 corner patches
+    assert_eq!(solid.face_iter().count(), 26);
+
+    // the corner patches are on the spheres touching the three faces
+    let center = Point3::new(radius, radius, radius);
+    let corner = solid
+        .face_iter()
+        .find(|face| face.vertex_iter().all(|v| v.point().distance(center) < 0.5))
+        .filter(|face| face.boundaries()[0].len() == 3)
+        .unwrap();
+    corner.vertex_iter().for_each(|v| assert_near!(v.point().distance(center), radius));
+    let normal = Vector3::new(-1.0, -1.0, -1.0).normalize();
+    let surface = corner.oriented_surface();
+    let (u, v) = surface.search_parameter(center + normal * radius, None, 100).unwrap();
+    assert_near!(surface.normal(u, v), normal);
+
+    let (inner, outer) = (radius * 0.5, radius * 0.2);
+    assert!(solid.contains_point(Point3::new(inner, inner, inner)));
+    assert!(!solid.contains_point(Point3::new(outer, outer, outer)));
+
+    // the top and bottom fillets overlap
+    let res = builder::round_edges(&cuboid, 0.3, TOLERANCE);
+    assert_eq!(res.unwrap_err(), Error::FilletRadiusTooLarge);
+
+    // the fillets at the concave edge of an L-shaped prism are cut by the plane bisecting them
+    let v = builder::vertices([
+        (0.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (2.0, 1.0, 0.0),
+        (1.0, 1.0, 0.0),
+        (1.0, 2.0, 0.0),
+        (0.0, 2.0, 0.0),
+    ]);
+    let wire: Wire = (0..6).map(|i| builder::line(&v[i], &v[(i + 1) % 6])).collect();
+    let face: Face = builder::try_attach_plane(vec![wire]).unwrap();
+    let prism: Solid = builder::tsweep(&face, Vector3::unit_z());
+    let solid = builder::round_edges(&prism, radius, TOLERANCE).unwrap();
+    assert!(solid.is_geometric_consistent());
+    // the 8 trimmed faces, the 17 fillets and the 10 corner patches
+    assert_eq!(solid.face_iter().count(), 35);
+    assert!(solid.contains_point(Point3::new(1.0 - outer, 1.0 - outer, 1.0 - inner - outer)));
+    assert!(!solid.contains_point(Point3::new(1.5, 1.0 - outer, 1.0 - outer)));
+    assert!(!solid.contains_point(Point3::new(1.0 - outer, 1.5, 1.0 - outer)));
+}
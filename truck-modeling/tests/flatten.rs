@@ -0,0 +1,100 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+fn signed_area(polyline: &[(f64, f64)]) -> f64 {
+    let closed = polyline.iter().zip(polyline.iter().cycle().skip(1));
+    closed.map(|(p, q)| p.0 * q.1 - p.1 * q.0).sum::<f64>() / 2.0
+}
+
+#[test]
+fn square_with_circular_hole() {
+    let v = builder::vertices([(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 4.0, 0.0), (0.0, 4.0, 0.0)]);
+    let outer: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    let w = builder::vertices([(3.0, 2.0, 0.0), (1.0, 2.0, 0.0)]);
+    let hole: Wire = vec![
+        builder::circle_arc(&w[0], &w[1], Point3::new(2.0, 1.0, 0.0)),
+        builder::circle_arc(&w[1], &w[0], Point3::new(2.0, 3.0, 0.0)),
+    ]
+    .into();
+    let face: Face = builder::try_attach_plane(vec![outer, hole]).unwrap();
+
+    let tol = 1.0e-3;
+    let loops = face.boundary_in_plane(tol).unwrap();
+    assert_eq!(loops.len(), 2);
+    assert_near!(signed_area(&loops[0]), 16.0);
+    // the inscribed polygon is smaller than the circle by at most the chord tolerance
+    let hole_area = signed_area(&loops[1]);
+    assert!(hole_area < -PI * (1.0 - tol) * (1.0 - tol) && hole_area > -PI, "{hole_area}");
+
+    // the loops are oriented with respect to the plane
+    let inverted = face.inverse();
+    let loops = inverted.boundary_in_plane(tol).unwrap();
+    assert_near!(signed_area(&loops[0]), 16.0);
+    assert!(signed_area(&loops[1]) < 0.0);
+}
+
+#[test]
+fn non_planar_face() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI));
+    let shell: Shell = builder::tsweep(&circle, Vector3::unit_z());
+    assert!(shell.face_iter().all(|face| face.boundary_in_plane(0.01).is_none()));
+}
+
+#[test]
+fn dxf_entities() {
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+        Point3::new(-1.0, 0.0, 1.0),
+    );
+    let v = builder::vertices([(1.0, 0.0, 1.0), (0.0, 2.0, 1.0), (-1.0, 0.0, 1.0)]);
+    // the plane coordinates of (x, y, 1) are (y, -x), so the arc is clockwise in the plane
+    let arc = builder::circle_arc(&v[0], &v[2], Point3::new(0.0, -1.0, 1.0));
+    let curve = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![Point3::new(-1.0, 0.0, 1.0), Point3::new(0.0, 4.0, 1.0), Point3::new(1.0, 0.0, 1.0)],
+    );
+    let wire: Wire = vec![
+        builder::line(&v[0], &v[1]),
+        builder::line(&v[1], &v[2]),
+        arc.inverse(),
+        Edge::new(&v[2], &v[0], curve.into()),
+    ]
+    .into();
+
+    let tol = 1.0e-3;
+    let entities = wire.to_dxf_entities(&plane, tol);
+    assert_eq!(entities.len(), 4);
+    assert_eq!(
+        entities[0],
+        DxfEntity::Line(Point2::new(0.0, -1.0), Point2::new(2.0, 0.0)),
+    );
+    let DxfEntity::Arc(center, radius, a0, a1) = entities[2] else {
+        panic!("{:?}", entities[2]);
+    };
+    assert_near!(center, Point2::origin());
+    assert_near!(radius, 1.0);
+    // the reversed arc is counterclockwise from (0, 1) to (0, -1) through (-1, 0)
+    assert_near!(a1 - a0, PI);
+    assert_near!(Point2::new(f64::cos(a0), f64::sin(a0)), Point2::new(0.0, 1.0));
+    // the parabola is not an arc
+    let DxfEntity::Polyline(points) = &entities[3] else {
+        panic!("{:?}", entities[3]);
+    };
+    assert_near!(points[0], Point2::new(0.0, 1.0));
+    assert_near!(points[points.len() - 1], Point2::new(0.0, -1.0));
+
+    let center = Point3::new(0.0, 0.0, 1.0);
+    let circle = builder::rsweep(&v[0], center, Vector3::unit_z(), Rad(2.0 * PI));
+    let entities = circle.to_dxf_entities(&plane, tol);
+    assert!(entities.iter().all(|entity| matches!(entity, DxfEntity::Arc(..))));
+    let central_angle: f64 = entities
+        .iter()
+        .map(|entity| match entity {
+            DxfEntity::Arc(_, _, a0, a1) => a1 - a0,
+            _ => unreachable!(),
+        })
+        .sum();
+    assert_near!(central_angle, 2.0 * PI);
+}
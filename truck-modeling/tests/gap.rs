@@ -0,0 +1,65 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+mod common;
+use common::*;
+
+#[test]
+fn pristine_cube() {
+    let shell = unit_cube_shell();
+    let report = shell.max_boundary_gap(8);
+    assert_eq!(report.samples, 12 * 8);
+    assert_eq!(report.fallbacks, 0);
+    assert!(report.max.unwrap().gap() < 1.0e-12, "{report:?}");
+    assert!(report.mean < 1.0e-12, "{report:?}");
+    assert_eq!(report.histogram.len(), 14);
+    assert_eq!(report.histogram[0].1, 12 * 8);
+    assert_eq!(report.histogram.iter().map(|(_, count)| count).sum::<usize>(), 12 * 8);
+}
+
+#[test]
+fn perturbed_cube() {
+    let shell = unit_cube_shell();
+    // move the top face upward
+    let top = &shell[5];
+    let mat = Matrix4::from_translation(Vector3::new(0.0, 0.0, 1.0e-5));
+    top.set_surface(top.surface().transformed(mat));
+    let top_edges: Vec<EdgeID> = top.edge_iter().map(|edge| edge.id()).collect();
+
+    let report = shell.max_boundary_gap(8);
+    let max = report.max.unwrap();
+    assert!(top_edges.contains(&max.edge_id));
+    assert!(f64::abs(max.point.z - 1.0) < TOLERANCE, "{max:?}");
+    assert!(f64::abs(max.deviation - 1.0e-5) < 1.0e-10, "{max:?}");
+    assert!(f64::abs(max.crack - 1.0e-5) < 1.0e-10, "{max:?}");
+    // four of the twelve edges are on the top face
+    assert!(f64::abs(report.mean - 1.0e-5 / 3.0) < 1.0e-10, "{report:?}");
+    let large: usize = report
+        .histogram
+        .iter()
+        .filter(|(bound, _)| *bound > 1.0e-6)
+        .map(|(_, count)| count)
+        .sum();
+    assert_eq!(large, 4 * 8, "{report:?}");
+}
+
+#[test]
+fn pristine_cylinder() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let square = builder::tsweep(&e, Vector3::unit_z());
+    let solid: Solid = builder::rsweep(&square, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI));
+    let report = solid.boundaries()[0].max_boundary_gap(8);
+    assert!(report.samples > 0);
+    assert!(report.max.unwrap().gap() < 1.0e-6, "{report:?}");
+}
+
+#[test]
+fn open_shell() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let report = Shell::from(vec![f]).max_boundary_gap(8);
+    assert_eq!(report.samples, 0);
+    assert_eq!(report.max, None);
+    assert_eq!(report.mean, 0.0);
+}
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use truck_modeling::*;
+
+#[test]
+fn nurbs_weights_are_normalized() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let arc: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(1.0));
+    let Curve::NurbsCurve(curve) = arc[0].curve() else {
+        panic!("the arc must be a NURBS curve");
+    };
+    let mut scaled = curve.clone();
+    scaled.transform_control_points(|v| *v *= 3.0);
+    let hash = |curve: &NurbsCurve<Vector4>| {
+        Curve::NurbsCurve(curve.clone()).geometry_hash(TOLERANCE)
+    };
+    assert_eq!(hash(&curve), hash(&scaled));
+
+    let mut moved = curve.clone();
+    moved.transform_control_points(|v| v.z += v.w);
+    assert_ne!(hash(&curve), hash(&moved));
+}
+
+#[test]
+fn dedup_surfaces_of_shell() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let mut shell = cube.into_boundaries().pop().unwrap();
+    // the copies of faces have the same surfaces as the originals up to the numerical error
+    let copies: Vec<Face> = shell
+        .iter()
+        .map(|face| builder::translated(face, Vector3::new(1.0e-10, 0.0, 0.0)))
+        .collect();
+    shell.extend(copies);
+
+    let mut classes = HashMap::<u64, Vec<Surface>>::new();
+    shell.face_iter().for_each(|face| {
+        let surface = face.surface();
+        classes.entry(surface.geometry_hash(TOLERANCE)).or_default().push(surface);
+    });
+    assert_eq!(classes.len(), 6);
+    assert!(classes.values().all(|surfaces| surfaces.len() == 2));
+}
@@ -0,0 +1,264 @@
+use std::fmt::Debug;
+use std::path::PathBuf;
+use truck_modeling::*;
+use truck_topology::compress::{CompressedEdgeIndex, CompressedShell};
+
+/// the tolerance of the geometry hashes
+const HASH_TOLERANCE: f64 = 1.0e-4;
+/// the number of the sample points of each edge for the bounding box of the sampling
+const EDGE_DIVISION: usize = 8;
+/// the number of the sample points in each direction for the mass properties
+const MASS_DIVISION: usize = 12;
+
+/// A model constructed by a golden case.
+pub enum Model {
+    Solid(Solid),
+    Shell(Shell),
+}
+
+impl From<Solid> for Model {
+    fn from(solid: Solid) -> Self { Model::Solid(solid) }
+}
+
+impl From<Shell> for Model {
+    fn from(shell: Shell) -> Self { Model::Shell(shell) }
+}
+
+impl From<Face> for Model {
+    fn from(face: Face) -> Self { Model::Shell(vec![face].into()) }
+}
+
+/// Compares the dump of `model` with the golden file `data/<name>.txt`.
+///
+/// The golden file is (re)written if the environment variable `UPDATE_GOLDEN` is `1`, or if it
+/// does not exist yet. Otherwise, panics with the changed entities if the dump does not match.
+pub fn check(name: &str, model: impl Into<Model>) {
+    let actual = dump(name, &model.into());
+    let path = golden_path(name);
+    let update = std::env::var("UPDATE_GOLDEN").is_ok_and(|var| var == "1");
+    match std::fs::read_to_string(&path) {
+        Ok(expected) if !update => {
+            let diff = diff(&expected, &actual);
+            if !diff.is_empty() {
+                panic!(
+                    "the golden model `{name}` is changed:\n{}\n\
+                     If the change is intended, rerun with `UPDATE_GOLDEN=1`.",
+                    diff.join("\n"),
+                );
+            }
+        }
+        Err(error) if !update && error.kind() != std::io::ErrorKind::NotFound => {
+            panic!("failed to read {}: {error}", path.display())
+        }
+        _ => {
+            std::fs::write(&path, actual).unwrap();
+            eprintln!("recorded the golden model `{name}`");
+        }
+    }
+}
+
+fn golden_path(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden/data");
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join(format!("{name}.txt"))
+}
+
+/// Returns the dump of the canonicalized model: the entity counts, the topology and the
+/// geometric fingerprint of each entity, and the sampled mass properties.
+///
+/// Each line is labeled by the entity before `": "`, which is the key of [`diff`].
+pub fn dump(name: &str, model: &Model) -> String {
+    let shells = match model {
+        Model::Solid(solid) => {
+            let mut solid = solid.clone();
+            solid.canonicalize();
+            solid.into_boundaries()
+        }
+        Model::Shell(shell) => {
+            let mut shell = shell.clone();
+            shell.canonicalize();
+            vec![shell]
+        }
+    };
+    let compressed: Vec<_> = shells.iter().map(Shell::compress).collect();
+
+    let mut lines = vec![
+        format!("# the golden model `{name}`"),
+        "# regenerate by `UPDATE_GOLDEN=1 cargo test --test golden`".to_string(),
+    ];
+    let count = |f: fn(&CompressedShell<Point3, Curve, Surface>) -> usize| -> usize {
+        compressed.iter().map(f).sum()
+    };
+    lines.push(format!(
+        "counts: {} shells, {} faces, {} edges, {} vertices",
+        compressed.len(),
+        count(|shell| shell.faces.len()),
+        count(|shell| shell.edges.len()),
+        count(|shell| shell.vertices.len()),
+    ));
+    compressed.iter().enumerate().for_each(|(i, shell)| {
+        shell.vertices.iter().enumerate().for_each(|(j, point)| {
+            lines.push(format!("shell {i} vertex {j}: {}", fmt_point(*point)));
+        });
+        shell.edges.iter().enumerate().for_each(|(j, edge)| {
+            let (v0, v1) = edge.vertices;
+            lines.push(format!("shell {i} edge {j}: {v0} -> {v1}, {}", fmt_curve(&edge.curve)));
+        });
+        shell.faces.iter().enumerate().for_each(|(j, face)| {
+            let boundaries: Vec<String> =
+                face.boundaries.iter().map(|wire| fmt_boundary(wire)).collect();
+            let points: Vec<Point3> = face
+                .boundaries
+                .iter()
+                .flatten()
+                .map(|e| shell.vertices[shell.edges[e.index].vertices.0])
+                .collect();
+            lines.push(format!(
+                "shell {i} face {j}: {}, {}",
+                boundaries.join(" "),
+                fmt_surface(&face.surface, &points),
+            ));
+        });
+    });
+    if let Model::Solid(solid) = model {
+        lines.push(format!("mass: {}", fmt_mass(solid)));
+    }
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// Returns the lines describing the entities whose lines differ in `expected` and `actual`.
+pub fn diff(expected: &str, actual: &str) -> Vec<String> {
+    let entries = |dump: &str| -> Vec<(String, String)> {
+        dump.lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| match line.split_once(": ") {
+                Some((label, value)) => (label.to_string(), value.to_string()),
+                None => (line.to_string(), String::new()),
+            })
+            .collect()
+    };
+    let (expected, actual) = (entries(expected), entries(actual));
+    let find = |entries: &[(String, String)], label: &str| {
+        entries.iter().find(|(l, _)| l == label).map(|(_, value)| value.clone())
+    };
+    let mut res = Vec::new();
+    expected.iter().for_each(|(label, old)| match find(&actual, label) {
+        Some(new) if &new != old => res.push(format!("changed {label}:\n  - {old}\n  + {new}")),
+        Some(_) => {}
+        None => res.push(format!("removed {label}: {old}")),
+    });
+    actual.iter().for_each(|(label, new)| {
+        if find(&expected, label).is_none() {
+            res.push(format!("added {label}: {new}"));
+        }
+    });
+    res
+}
+
+fn fmt_f64(x: f64) -> String {
+    // `+ 0.0` removes the negative zero
+    let x = (x * 1.0e4).round() / 1.0e4 + 0.0;
+    format!("{x:.4}")
+}
+
+fn fmt_point(p: Point3) -> String {
+    format!("({}, {}, {})", fmt_f64(p.x), fmt_f64(p.y), fmt_f64(p.z))
+}
+
+fn fmt_vector(v: Vector3) -> String { fmt_point(Point3::from_vec(v)) }
+
+/// the name of the variant of the enum `x`
+fn variant<T: Debug>(x: &T) -> String {
+    let debug = format!("{x:?}");
+    debug.split(['(', '{', ' ']).next().unwrap().to_string()
+}
+
+fn fmt_boundary(wire: &[CompressedEdgeIndex]) -> String {
+    let edges: Vec<String> = wire
+        .iter()
+        .map(|e| format!("{}{}", e.index, if e.orientation { "+" } else { "-" }))
+        .collect();
+    format!("[{}]", edges.join(" "))
+}
+
+fn fmt_curve(curve: &Curve) -> String {
+    let (t0, t1) = curve.range_tuple();
+    format!(
+        "{} through {}, hash {:016x}",
+        variant(curve),
+        fmt_point(curve.subs((t0 + t1) / 2.0)),
+        curve.geometry_hash(HASH_TOLERANCE),
+    )
+}
+
+/// the surface at the average of the parameters of the boundary vertices `points`
+fn fmt_surface(surface: &Surface, points: &[Point3]) -> String {
+    let hash = surface.geometry_hash(HASH_TOLERANCE);
+    let uvs: Option<Vec<(f64, f64)>> = points
+        .iter()
+        .map(|p| surface.search_parameter(*p, None, 100))
+        .collect();
+    let fingerprint = match uvs {
+        Some(uvs) if !uvs.is_empty() => {
+            let n = uvs.len() as f64;
+            let u = uvs.iter().map(|uv| uv.0).sum::<f64>() / n;
+            let v = uvs.iter().map(|uv| uv.1).sum::<f64>() / n;
+            let normal = surface.normal(u, v);
+            let normal = match [normal.x, normal.y, normal.z].iter().all(|x| x.is_finite()) {
+                true => fmt_vector(normal),
+                false => "degenerate".to_string(),
+            };
+            format!("at {} normal {normal}", fmt_point(surface.subs(u, v)))
+        }
+        _ => "unresolved parameters".to_string(),
+    };
+    format!("{} {fingerprint}, hash {hash:016x}", variant(surface))
+}
+
+/// the volume and the centroid sampled by the points of the grid in the bounding box, which are
+/// classified by a ray along each column of the grid
+fn fmt_mass(solid: &Solid) -> String {
+    let bdb: BoundingBox<Point3> = solid
+        .edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.curve();
+            let (t0, t1) = curve.range_tuple();
+            (0..=EDGE_DIVISION)
+                .map(move |i| curve.subs(t0 + (t1 - t0) * i as f64 / EDGE_DIVISION as f64))
+        })
+        .collect();
+    // the surfaces may bulge out of the edges
+    let (center, diag) = (bdb.center(), bdb.diagonal() * 0.6);
+    let (min, size) = (center - diag, diag * 2.0);
+    let n = MASS_DIVISION as f64;
+    let (mut count, mut sum) = (0_usize, Vector3::zero());
+    for i in 0..MASS_DIVISION {
+        for j in 0..MASS_DIVISION {
+            let (x, y) = (i as f64 + 0.5, j as f64 + 0.5);
+            let origin = min + Vector3::new(x * size.x / n, y * size.y / n, 0.0);
+            let hits = solid.ray_hits(origin, Vector3::unit_z(), HASH_TOLERANCE);
+            for k in 0..MASS_DIVISION {
+                let z = (k as f64 + 0.5) * size.z / n;
+                // The ray starts from outside. The inverted solids, e.g. swept to the back of the
+                // faces, are measured by the negative windings.
+                let winding = hits.iter().take_while(|hit| hit.t < z).fold(0, |w, hit| {
+                    match hit.entering {
+                        true => w + 1,
+                        false => w - 1,
+                    }
+                });
+                if winding != 0 {
+                    count += 1;
+                    sum += (origin + Vector3::unit_z() * z).to_vec();
+                }
+            }
+        }
+    }
+    let volume = size.x * size.y * size.z * count as f64 / (n * n * n);
+    let centroid = match count {
+        0 => "none".to_string(),
+        _ => fmt_vector(sum / count as f64),
+    };
+    format!("sampled volume {}, centroid {centroid}", fmt_f64(volume))
+}
@@ -0,0 +1,228 @@
+//! Golden-model regression suite of the builder.
+//!
+//! Each case constructs a model by the builder and compares the dump of the canonicalized model
+//! with the golden file `data/<case>.txt`. On mismatch, the changed entities are printed. Run
+//! `UPDATE_GOLDEN=1 cargo test --test golden` in order to regenerate the golden files after an
+//! intended change. A new case is a test calling [`harness::check`], whose golden file is
+//! recorded at the first run.
+
+mod harness;
+
+use harness::check;
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+fn polygon(points: &[(f64, f64, f64)]) -> Wire {
+    let v = builder::vertices(points.iter().copied());
+    let n = v.len();
+    (0..n).map(|i| builder::line(&v[i], &v[(i + 1) % n])).collect()
+}
+
+fn unit_square() -> Face {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    builder::tsweep(&e, Vector3::unit_y())
+}
+
+fn unit_cube() -> Solid { builder::tsweep(&unit_square(), Vector3::unit_z()) }
+
+#[test]
+fn primitive_cuboid() {
+    let bdb = BoundingBox::from_iter([Point3::new(-1.0, 0.0, 2.0), Point3::new(2.0, 1.5, 3.0)]);
+    check("primitive_cuboid", primitive::cuboid::<Curve, Surface>(bdb));
+}
+
+#[test]
+fn primitive_rect() {
+    let bdb = BoundingBox::from_iter([Point2::new(-1.0, -2.0), Point2::new(2.0, 1.0)]);
+    let plane = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let rect: Wire = primitive::rect(bdb, plane);
+    let face: Face = builder::try_attach_plane(vec![rect]).unwrap();
+    check("primitive_rect", face);
+}
+
+#[test]
+fn primitive_cylinder() {
+    let (start, axis) = (Point3::new(2.0, 0.0, 0.0), Vector3::unit_z());
+    let circle: Wire = primitive::circle(start, Point3::origin(), axis, 4);
+    let disk: Face = builder::try_attach_plane(vec![circle]).unwrap();
+    check("primitive_cylinder", builder::tsweep::<_, Solid>(&disk, Vector3::unit_z()));
+}
+
+#[test]
+fn tsweep_cube() { check("tsweep_cube", unit_cube()) }
+
+#[test]
+fn tsweep_bezier_face() {
+    let v = builder::vertices([(0.0, 0.0, 0.0), (2.0, 0.0, 0.0)]);
+    let inter_points = vec![Point3::new(0.5, 1.0, 0.0), Point3::new(1.5, -1.0, 0.0)];
+    let edge = builder::bezier(&v[0], &v[1], inter_points);
+    let face: Face = builder::tsweep(&edge, Vector3::new(0.0, 0.5, 1.0));
+    check("tsweep_bezier_face", face);
+}
+
+#[test]
+fn sweep_profile() {
+    let profile = polygon(&[
+        (0.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (2.0, 1.0, 0.0),
+        (1.0, 1.0, 0.0),
+        (1.0, 2.0, 0.0),
+        (0.0, 2.0, 0.0),
+    ]);
+    let solid: Solid = builder::sweep(&profile, Vector3::new(0.0, 0.0, 1.5)).unwrap();
+    check("sweep_profile", solid);
+}
+
+#[test]
+fn sweep_aligned_normals() {
+    let profile = polygon(&[(0.0, 0.0, 0.0), (0.0, 1.0, 0.0), (1.0, 1.0, 0.0), (1.0, 0.0, 0.0)]);
+    let options = builder::SweepOptions {
+        align_surface_normals: true,
+    };
+    let solid: Solid =
+        builder::sweep_with_options(&profile, Vector3::unit_z(), options).unwrap();
+    check("sweep_aligned_normals", solid);
+}
+
+#[test]
+fn sweep_arc_profile() {
+    let v = builder::vertices([(1.0, 0.0, 0.0), (-1.0, 0.0, 0.0)]);
+    let arc = builder::arc_between(&v[0], &v[1], 1.0).unwrap();
+    let profile: Wire = vec![arc, builder::line(&v[1], &v[0])].into();
+    let solid: Solid = builder::sweep(&profile, Vector3::unit_z()).unwrap();
+    check("sweep_arc_profile", solid);
+}
+
+#[test]
+fn homotopy_face() {
+    let v = builder::vertices([
+        (0.0, 0.0, 0.0),
+        (1.0, 0.0, 0.0),
+        (0.0, 1.0, 1.0),
+        (1.0, 2.0, 1.0),
+    ]);
+    let edge0 = builder::line(&v[0], &v[1]);
+    let edge1 = builder::circle_arc(&v[2], &v[3], Point3::new(0.5, 1.8, 1.0));
+    let face: Face = builder::homotopy(&edge0, &edge1);
+    check("homotopy_face", face);
+}
+
+#[test]
+fn wire_homotopy_shell() {
+    let wire0 = polygon(&[(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.5, 0.5, 0.0)]);
+    let wire1 = polygon(&[(0.0, 0.0, 1.0), (2.0, 0.0, 1.0), (1.0, 1.0, 1.0)]);
+    let shell: Shell = builder::try_wire_homotopy(&wire0, &wire1).unwrap();
+    check("wire_homotopy_shell", shell);
+}
+
+#[test]
+fn rsweep_quarter() {
+    let face = builder::translated(&unit_square(), Vector3::new(1.0, 0.0, 0.0));
+    let solid: Solid = builder::rsweep(&face, Point3::origin(), Vector3::unit_y(), Rad(PI / 2.0));
+    check("rsweep_quarter", solid);
+}
+
+#[test]
+fn rsweep_half() {
+    let face = builder::translated(&unit_square(), Vector3::new(1.0, 0.0, 0.0));
+    let solid: Solid = builder::rsweep(&face, Point3::origin(), Vector3::unit_y(), Rad(PI));
+    check("rsweep_half", solid);
+}
+
+#[test]
+fn rsweep_torus() {
+    let v = builder::vertex(Point3::new(3.0, 0.0, 0.0));
+    let center = Point3::new(2.0, 0.0, 0.0);
+    let circle: Wire = builder::rsweep(&v, center, Vector3::unit_z(), Rad(2.0 * PI));
+    let torus: Shell =
+        builder::rsweep(&circle, Point3::origin(), Vector3::unit_y(), Rad(2.0 * PI));
+    check("rsweep_torus", Solid::new(vec![torus]));
+}
+
+#[test]
+fn revolve_ring() {
+    let profile = polygon(&[(1.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 1.0, 0.0), (1.0, 1.0, 0.0)]);
+    let ring: Solid = builder::revolve(&profile, Point3::origin(), Vector3::unit_y(), Rad(7.0))
+        .unwrap();
+    check("revolve_ring", ring);
+}
+
+#[test]
+fn revolve_touching_axis() {
+    // the profile touches the axis at the origin
+    let profile = polygon(&[(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0)]);
+    let solid: Solid = builder::revolve(&profile, Point3::origin(), Vector3::unit_y(), Rad(7.0))
+        .unwrap();
+    check("revolve_touching_axis", solid);
+}
+
+#[test]
+fn cone() {
+    let v = builder::vertices([(0.0, 1.0, 0.0), (0.0, 0.0, 1.0), (0.0, 0.0, 0.0)]);
+    let wire: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[2])].into();
+    let cone: Shell = builder::cone(&wire, Vector3::unit_y(), Rad(2.0 * PI));
+    check("cone", Solid::new(vec![cone]));
+}
+
+#[test]
+fn sphere() {
+    // the semicircle with the both ends on the axis
+    let v = builder::vertices([(0.0, 1.0, 0.0), (0.0, -1.0, 0.0)]);
+    let wire: Wire = vec![builder::circle_arc(&v[0], &v[1], Point3::new(0.0, 0.0, 1.0))].into();
+    let sphere: Shell = builder::cone(&wire, Vector3::unit_y(), Rad(2.0 * PI));
+    check("sphere", Solid::new(vec![sphere]));
+}
+
+#[test]
+fn face_with_holes() {
+    let outer = polygon(&[(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 4.0, 0.0), (0.0, 4.0, 0.0)]);
+    let hole = |x: f64, y: f64| {
+        polygon(&[(x, y, 0.0), (x, y + 1.0, 0.0), (x + 1.0, y + 1.0, 0.0), (x + 1.0, y, 0.0)])
+    };
+    let face: Face = builder::try_attach_plane(vec![outer, hole(1.0, 1.0), hole(2.5, 2.0)])
+        .unwrap();
+    check("face_with_holes", builder::tsweep::<_, Solid>(&face, Vector3::unit_z()));
+}
+
+#[test]
+fn revolve_with_hole() {
+    let outer = polygon(&[(2.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 2.0, 0.0), (2.0, 2.0, 0.0)]);
+    let hole = polygon(&[(2.5, 0.5, 0.0), (2.5, 1.5, 0.0), (3.5, 1.5, 0.0), (3.5, 0.5, 0.0)]);
+    let face: Face = builder::try_attach_plane(vec![outer, hole]).unwrap();
+    let solid: Solid = builder::rsweep(&face, Point3::origin(), Vector3::unit_y(), Rad(PI / 2.0));
+    check("revolve_with_hole", solid);
+}
+
+#[test]
+fn translated_rotated_cube() {
+    let cube = builder::translated(&unit_cube(), Vector3::new(1.0, 2.0, 3.0));
+    let axis = Vector3::new(1.0, 1.0, 1.0).normalize();
+    check("translated_rotated_cube", builder::rotated(&cube, Point3::origin(), axis, Rad(0.5)));
+}
+
+#[test]
+fn scaled_cylinder() {
+    let (start, axis) = (Point3::new(1.0, 0.0, 0.0), Vector3::unit_z());
+    let circle: Wire = primitive::circle(start, Point3::origin(), axis, 3);
+    let disk: Face = builder::try_attach_plane(vec![circle]).unwrap();
+    let cylinder: Solid = builder::tsweep(&disk, Vector3::unit_z());
+    let scalars = Vector3::new(2.0, 1.0, 0.5);
+    check("scaled_cylinder", builder::scaled(&cylinder, Point3::origin(), scalars));
+}
+
+#[test]
+fn sheared_cube() {
+    let mat = Matrix4::from_cols(
+        Vector4::new(1.0, 0.0, 0.0, 0.0),
+        Vector4::new(0.5, 1.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.3, 1.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    );
+    check("sheared_cube", builder::transformed(&unit_cube(), mat));
+}
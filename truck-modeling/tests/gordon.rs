@@ -0,0 +1,62 @@
+use std::f64::consts::PI;
+use truck_modeling::{errors::Error, *};
+
+/// the surface sampled by the curves of the network
+fn shape(x: f64, y: f64) -> Point3 {
+    Point3::new(x, y, 0.2 * f64::sin(PI * x) * f64::cos(PI * y) + 0.1 * x * y)
+}
+
+/// the cubic curve interpolating `points` at the uniform parameters
+fn interpolation(points: impl Fn(f64) -> Point3, n: usize) -> BSplineCurve<Point3> {
+    let parameter_points: Vec<(f64, Point3)> = (0..n)
+        .map(|i| {
+            let t = i as f64 / (n - 1) as f64;
+            (t, points(t))
+        })
+        .collect();
+    BSplineCurve::interpole(KnotVec::uniform_knot(3, n - 3), parameter_points)
+}
+
+/// the network of the curves through the intersections at `(xs[j], ys[i])`
+fn network(xs: &[f64], ys: &[f64]) -> (Vec<BSplineCurve<Point3>>, Vec<BSplineCurve<Point3>>) {
+    let u_curves = ys.iter().map(|y| interpolation(|x| shape(x, *y), 9)).collect();
+    let v_curves = xs.iter().map(|x| interpolation(|y| shape(*x, y), 5)).collect();
+    (u_curves, v_curves)
+}
+
+#[test]
+fn interpolates_network() {
+    let (xs, ys) = ([0.0, 0.25, 0.5, 0.75, 1.0], [0.0, 0.5, 1.0]);
+    let (u_curves, v_curves) = network(&xs, &ys);
+    let surface = builder::gordon_surface(&u_curves, &v_curves, TOLERANCE).unwrap();
+    assert_eq!(surface.range_tuple(), ((0.0, 1.0), (0.0, 1.0)));
+    let params: Vec<f64> = (0..=10).map(|i| i as f64 / 10.0).collect();
+    u_curves.iter().zip(ys).for_each(|(curve, y)| {
+        params.iter().for_each(|t| assert_near!(surface.subs(*t, y), curve.subs(*t)));
+    });
+    v_curves.iter().zip(xs).for_each(|(curve, x)| {
+        params.iter().for_each(|t| assert_near!(surface.subs(x, *t), curve.subs(*t)));
+    });
+}
+
+#[test]
+fn inconsistent_network() {
+    let (xs, ys) = ([0.0, 0.5, 1.0], [0.0, 0.5, 1.0]);
+    let (u_curves, mut v_curves) = network(&xs, &ys);
+    let res = builder::gordon_surface(&u_curves[..1], &v_curves, TOLERANCE);
+    assert_eq!(res.unwrap_err(), Error::InvalidCurveNetwork);
+    // the u-curves are not ordered
+    let reordered = [u_curves[0].clone(), u_curves[2].clone(), u_curves[1].clone()];
+    let res = builder::gordon_surface(&reordered, &v_curves, TOLERANCE);
+    assert!(res.is_err());
+
+    // the middle v-curve is lifted off the u-curves
+    v_curves[1].transform_by(Matrix4::from_translation(Vector3::new(0.0, 0.0, 0.01)));
+    let Err(Error::InconsistentCurveNetwork { gap }) =
+        builder::gordon_surface(&u_curves, &v_curves, TOLERANCE)
+    else {
+        panic!("the network must be inconsistent");
+    };
+    assert!(gap > 0.005, "{gap}");
+    assert!(builder::gordon_surface(&u_curves, &v_curves, 0.02).is_ok());
+}
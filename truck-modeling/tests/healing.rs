@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+use truck_modeling::{errors::Error, *};
+
+const CORNERS: [(f64, f64, f64); 8] = [
+    (0.0, 0.0, 0.0),
+    (1.0, 0.0, 0.0),
+    (1.0, 1.0, 0.0),
+    (0.0, 1.0, 0.0),
+    (0.0, 0.0, 1.0),
+    (1.0, 0.0, 1.0),
+    (1.0, 1.0, 1.0),
+    (0.0, 1.0, 1.0),
+];
+
+/// the corners of the faces of the unit cube, counterclockwise from the outside
+const FACES: [[usize; 4]; 6] = [
+    [0, 3, 2, 1],
+    [4, 5, 6, 7],
+    [0, 1, 5, 4],
+    [3, 7, 6, 2],
+    [0, 4, 7, 3],
+    [1, 2, 6, 5],
+];
+
+/// a corner of the cube moved by the noise, different for each `seed`
+fn noisy_corner(index: usize, seed: usize) -> Point3 {
+    let k = seed as f64;
+    let noise = Vector3::new(f64::sin(k * 1.3), f64::cos(k * 2.1), f64::sin(k * 0.7));
+    Point3::from(CORNERS[index]) + noise * 1.0e-9
+}
+
+/// the faces of the unit cube without shared topology, whose last face is inverted
+fn cube_faces() -> Vec<Face> {
+    let mut seed = 0;
+    FACES
+        .iter()
+        .enumerate()
+        .map(|(i, corners)| {
+            let mut corners = *corners;
+            if i == 5 {
+                corners.reverse();
+            }
+            let v: Vec<Vertex> = corners
+                .iter()
+                .map(|&c| {
+                    seed += 1;
+                    builder::vertex(noisy_corner(c, seed))
+                })
+                .collect();
+            let wire: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+            builder::try_attach_plane(vec![wire]).unwrap()
+        })
+        .collect()
+}
+
+fn count(log: &HealingLog, pred: impl Fn(&Repair) -> bool) -> usize {
+    log.repairs.iter().filter(|repair| pred(repair)).count()
+}
+
+#[test]
+fn heal_cube() {
+    let shell: Shell = cube_faces().into();
+    assert!(Solid::try_new(vec![shell.clone()]).is_err());
+    let (solid, log) = Solid::try_new_healed(vec![shell], 1.0e-6).unwrap();
+
+    assert_eq!(count(&log, |r| matches!(r, Repair::WeldEdges { .. })), 12);
+    assert_eq!(
+        count(&log, |r| matches!(r, Repair::MergeVertices { merged, .. } if merged.len() == 2)),
+        8,
+    );
+    assert_eq!(count(&log, |r| matches!(r, Repair::InvertFace(_))), 1);
+    assert_eq!(log.len(), 21);
+
+    let edges: HashSet<EdgeID> = solid.edge_iter().map(|edge| edge.id()).collect();
+    assert_eq!(edges.len(), 12);
+    let vertices: HashSet<VertexID> = solid.vertex_iter().map(|v| v.id()).collect();
+    assert_eq!(vertices.len(), 8);
+    assert!(solid.is_geometric_consistent());
+    assert!(solid.contains_point(Point3::new(0.5, 0.5, 0.5)));
+    assert!(!solid.contains_point(Point3::new(1.5, 0.5, 0.5)));
+}
+
+#[test]
+fn heal_cube_with_sliver() {
+    let mut faces = cube_faces();
+    let v = builder::vertices([noisy_corner(0, 100), noisy_corner(1, 101)]);
+    let wire: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[0])].into();
+    let plane = Plane::new(CORNERS[0].into(), CORNERS[1].into(), CORNERS[4].into());
+    faces.insert(3, Face::new(vec![wire], plane.into()));
+    let (solid, log) = Solid::try_new_healed(vec![faces.into()], 1.0e-6).unwrap();
+
+    assert_eq!(count(&log, |r| matches!(r, Repair::RemoveSliver(_))), 1);
+    assert_eq!(count(&log, |r| matches!(r, Repair::WeldEdges { .. })), 14);
+    assert_eq!(solid.face_iter().count(), 6);
+    assert!(solid.contains_point(Point3::new(0.5, 0.5, 0.5)));
+}
+
+#[test]
+fn residual_defects() {
+    let mut faces = cube_faces();
+    faces.remove(1);
+    let res = Solid::try_new_healed(vec![faces.into()], 1.0e-6);
+    let Err(Error::UnhealedDefects(defects)) = res else {
+        panic!("{res:?}");
+    };
+    assert_eq!(defects.len(), 4);
+    assert!(defects.iter().all(|d| matches!(d, Defect::OpenEdge(_))));
+
+    let res = Solid::try_new_healed_with_max_repairs(vec![cube_faces().into()], 1.0e-6, 10);
+    assert_eq!(res.unwrap_err(), Error::TooManyRepairs(21));
+}
@@ -0,0 +1,136 @@
+use truck_modeling::{errors::Error, *};
+
+/// the volume of a solid bounded by planar polygons
+fn polyhedron_volume(solid: &Solid) -> f64 {
+    let loops = solid.face_iter().flat_map(|face| face.boundaries());
+    let sum: f64 = loops
+        .map(|wire| {
+            let points: Vec<Vector3> = wire.vertex_iter().map(|v| v.point().to_vec()).collect();
+            let p = points[0];
+            let fan = points[1..].windows(2);
+            fan.map(|w| p.dot(w[0].cross(w[1]))).sum::<f64>()
+        })
+        .sum();
+    sum / 6.0
+}
+
+struct Bracket {
+    history: ModelHistory,
+    profile: Vec<usize>,
+    extrusion: usize,
+    moved: usize,
+    independent: usize,
+}
+
+/// an L-shaped bracket extruded from a profile, and an independent cube
+fn bracket() -> Bracket {
+    let mut history = ModelHistory::new();
+    let v: Vec<usize> = [
+        (0.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (2.0, 1.0, 0.0),
+        (1.0, 1.0, 0.0),
+        (1.0, 2.0, 0.0),
+        (0.0, 2.0, 0.0),
+    ]
+    .into_iter()
+    .map(|p| history.vertex(p))
+    .collect();
+    let edges: Vec<usize> = (0..6)
+        .map(|i| history.line(v[i], v[(i + 1) % 6]).unwrap())
+        .collect();
+    let wire = history.wire(edges.clone()).unwrap();
+    let face = history.try_attach_plane(vec![wire]).unwrap();
+    let extrusion = history.tsweep(face, Vector3::unit_z()).unwrap();
+    let moved = history.translated(extrusion, Vector3::unit_x() * 5.0).unwrap();
+
+    let w = history.vertex((-3.0, 0.0, 0.0));
+    let e = history.tsweep(w, Vector3::unit_x()).unwrap();
+    let f = history.tsweep(e, Vector3::unit_y()).unwrap();
+    let independent = history.tsweep(f, Vector3::unit_z()).unwrap();
+
+    let profile = v.into_iter().chain(edges).chain([wire, face]).collect();
+    Bracket {
+        history,
+        profile,
+        extrusion,
+        moved,
+        independent,
+    }
+}
+
+fn solid(history: &ModelHistory, index: usize) -> Solid {
+    Solid::try_from(history.result(index).unwrap().clone()).unwrap()
+}
+
+#[test]
+fn change_extrusion_height() {
+    let Bracket {
+        mut history,
+        profile,
+        extrusion,
+        moved,
+        independent,
+    } = bracket();
+    assert_near!(polyhedron_volume(&solid(&history, moved)), 3.0);
+
+    let face = profile[profile.len() - 1];
+    let vector = Vector3::new(0.0, 0.0, 2.5);
+    history.replace_op(extrusion, Operation::Tsweep { elem: face, vector }).unwrap();
+    assert!(profile.iter().all(|&i| history.execution_count(i) == 1));
+    assert_eq!(history.execution_count(extrusion), 2);
+    assert_eq!(history.execution_count(moved), 2);
+    assert_eq!(history.execution_count(independent), 1);
+
+    let moved_solid = solid(&history, moved);
+    assert!(moved_solid.is_geometric_consistent());
+    assert_near!(polyhedron_volume(&moved_solid), 7.5);
+    assert!(moved_solid.vertex_iter().all(|v| v.point().x >= 5.0));
+
+    // replaying from scratch gives the same solids, without changing the counts
+    let solids = history.replay().unwrap();
+    let volumes: Vec<f64> = solids.iter().map(polyhedron_volume).collect();
+    assert_near!(volumes[0], 7.5);
+    assert_near!(volumes[1], 7.5);
+    assert_near!(volumes[2], 1.0);
+    assert_eq!(history.execution_count(moved), 2);
+}
+
+#[test]
+fn serialize_operations() {
+    let Bracket { history, moved, .. } = bracket();
+    let json = serde_json::to_string(history.operations()).unwrap();
+    let operations: Vec<Operation> = serde_json::from_str(&json).unwrap();
+    assert_eq!(operations, history.operations());
+    let restored = ModelHistory::from_operations(operations).unwrap();
+    assert_eq!(restored.len(), history.len());
+    assert_near!(polyhedron_volume(&solid(&restored, moved)), 3.0);
+}
+
+#[test]
+fn invalid_replacement() {
+    let Bracket {
+        mut history,
+        profile,
+        extrusion,
+        moved,
+        ..
+    } = bracket();
+    let op = Operation::Tsweep {
+        elem: moved,
+        vector: Vector3::unit_z(),
+    };
+    let res = history.replace_op(extrusion, op);
+    assert_eq!(res, Err(Error::HistoryIndexOutOfRange(moved)));
+
+    // the plane cannot be attached to the edge made in place of the wire
+    let op = Operation::Tsweep {
+        elem: profile[0],
+        vector: Vector3::unit_z(),
+    };
+    let wire = profile[profile.len() - 2];
+    let res = history.replace_op(wire, op);
+    assert_eq!(res, Err(Error::UnexpectedShape(wire)));
+    assert_eq!(history.execution_count(wire), 1);
+    assert_near!(polyhedron_volume(&solid(&history, moved)), 3.0);
+}
@@ -0,0 +1,112 @@
+use algo::surface::SurfaceProximity;
+use std::time::Instant;
+use truck_modeling::*;
+mod common;
+use common::*;
+
+/// prism of the counter-clockwise polygon in the xy-plane
+fn prism(points: &[(f64, f64)]) -> Shell {
+    let v: Vec<Vertex> = points
+        .iter()
+        .map(|&(x, y)| builder::vertex(Point3::new(x, y, 0.0)))
+        .collect();
+    let n = v.len();
+    let profile: Wire = (0..n).map(|i| builder::line(&v[i], &v[(i + 1) % n])).collect();
+    let solid: Solid = builder::sweep(&profile, Vector3::unit_z()).unwrap();
+    solid.into_boundaries().pop().unwrap()
+}
+
+/// The C-shapes hook each other: the lower arm of the first one is in the notch of the second
+/// one. The bounding boxes overlap, and the distance of the shapes is 0.2.
+fn interlocking_c_shapes() -> (Shell, Shell) {
+    let c0 = prism(&[
+        (0.0, 0.0),
+        (3.0, 0.0),
+        (3.0, 1.0),
+        (1.0, 1.0),
+        (1.0, 2.0),
+        (3.0, 2.0),
+        (3.0, 3.0),
+        (0.0, 3.0),
+    ]);
+    let c1 = prism(&[
+        (1.5, -1.5),
+        (4.5, -1.5),
+        (4.5, 1.8),
+        (1.5, 1.8),
+        (1.5, 1.2),
+        (3.5, 1.2),
+        (3.5, -0.5),
+        (1.5, -0.5),
+    ]);
+    (c0, c1)
+}
+
+#[test]
+fn separated_cubes() {
+    let clearance = 0.1;
+    let cube = unit_cube_shell();
+
+    let far = builder::translated(&cube, Vector3::new(1.0 + 2.0 * clearance, 0.0, 0.0));
+    assert!(cube.interferes_with(&far, clearance).is_none());
+
+    let gap = 0.5 * clearance;
+    let near = builder::translated(&cube, Vector3::new(1.0 + gap, 0.0, 0.0));
+    let witness = cube.interferes_with(&near, clearance).unwrap();
+    assert_near!(witness.distance, gap);
+    assert_near!(witness.points.0.distance(witness.points.1), gap);
+    assert_near!(witness.points.0.x, 1.0);
+    assert_near!(witness.points.1.x, 1.0 + gap);
+    assert!(cube.face_iter().any(|face| face.id() == witness.face_ids.0));
+    assert!(near.face_iter().any(|face| face.id() == witness.face_ids.1));
+}
+
+#[test]
+fn touching_cubes() {
+    let cube = unit_cube_shell();
+    let moved = builder::translated(&cube, Vector3::new(1.0, 0.5, 0.5));
+    let witness = cube.interferes_with(&moved, 0.0).unwrap();
+    assert_near!(witness.distance, 0.0);
+    assert_near!(witness.points.0.x, 1.0);
+}
+
+#[test]
+fn interlocking_shapes() {
+    let (c0, c1) = interlocking_c_shapes();
+    let (bdb0, bdb1) = (c0.bounding_box(), c1.bounding_box());
+    assert!(!(bdb0 ^ bdb1).is_empty());
+
+    assert!(c0.interferes_with(&c1, 0.1).is_none());
+    assert!(c1.interferes_with(&c0, 0.1).is_none());
+    let witness = c0.interferes_with(&c1, 0.3).unwrap();
+    assert!(witness.distance < 0.3 + TOLERANCE);
+    assert!(witness.distance > 0.2 - TOLERANCE);
+}
+
+#[test]
+#[ignore]
+fn early_exit_benchmark() {
+    let (c0, c1) = interlocking_c_shapes();
+    // the upper arm of the second shape touches the lower arm of the first one
+    let c1 = builder::translated(&c1, Vector3::new(0.0, -0.2, 0.0));
+
+    let instant = Instant::now();
+    let witness = c0.interferes_with(&c1, 0.0).unwrap();
+    let early_exit = instant.elapsed();
+    assert_near!(witness.distance, 0.0);
+
+    let instant = Instant::now();
+    let distance = c0
+        .face_iter()
+        .flat_map(|face0| c1.face_iter().map(move |face1| (face0, face1)))
+        .filter_map(|(face0, face1)| match face0.proximity(face1, TOLERANCE).ok()? {
+            SurfaceProximity::Disjoint { distance, .. } => Some(distance),
+            _ => Some(0.0),
+        })
+        .fold(f64::INFINITY, f64::min);
+    let brute_force = instant.elapsed();
+    assert_near!(distance, 0.0);
+
+    let ratio = brute_force.as_secs_f64() / early_exit.as_secs_f64();
+    assert!(ratio >= 5.0, "the early exit only accelerates {ratio} times");
+}
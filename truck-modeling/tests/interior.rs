@@ -0,0 +1,62 @@
+use truck_modeling::*;
+mod common;
+use common::*;
+
+/// the solid whose boundary is the faces of all solids, as a naive union
+fn naive_union(solids: &[Solid]) -> Solid {
+    let faces: Shell = solids.iter().flat_map(Solid::face_iter).cloned().collect();
+    Solid::new_unchecked(vec![faces])
+}
+
+#[test]
+fn glued_cubes() {
+    let cubes = [
+        cube(Point3::origin(), 1.0),
+        cube(Point3::new(1.0, 0.0, 0.0), 1.0),
+        cube(Point3::new(1.0, 1.0, 0.0), 1.0),
+    ];
+    let (solid, removed) = naive_union(&cubes).remove_interior_faces(TOLERANCE).unwrap();
+    assert_eq!(removed, 4);
+    assert_eq!(solid.boundaries().len(), 1);
+    assert_eq!(solid.face_iter().count(), 14);
+    assert!(solid.is_geometric_consistent());
+    for point in [(0.5, 0.5, 0.5), (1.0, 0.5, 0.5), (1.5, 1.0, 0.5), (1.5, 1.5, 0.5)] {
+        assert!(solid.contains_point(Point3::from(point)));
+    }
+    assert!(!solid.contains_point(Point3::new(0.5, 1.5, 0.5)));
+}
+
+#[test]
+fn inner_face() {
+    let big = cube(Point3::origin(), 2.0);
+    let v = builder::vertices([(0.5, 0.5, 1.0), (1.5, 0.5, 1.0), (1.5, 1.5, 1.0), (0.5, 1.5, 1.0)]);
+    let wire: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    let mut faces = big.into_boundaries().pop().unwrap();
+    // the orientation of the inner face does not matter
+    faces.push(builder::try_attach_plane(vec![wire.inverse()]).unwrap());
+    faces.push(builder::try_attach_plane(vec![wire]).unwrap());
+    let (solid, removed) = Solid::new_unchecked(vec![faces])
+        .remove_interior_faces(TOLERANCE)
+        .unwrap();
+    assert_eq!(removed, 2);
+    assert_eq!(solid.face_iter().count(), 6);
+    assert!(solid.contains_point(Point3::new(1.0, 1.0, 1.0)));
+}
+
+#[test]
+fn no_interior_faces() {
+    let solid = cube(Point3::origin(), 1.0);
+    let (result, removed) = solid.remove_interior_faces(TOLERANCE).unwrap();
+    assert_eq!(removed, 0);
+    assert_eq!(result.face_iter().count(), 6);
+
+    // the cavity is not interior
+    let mut boundaries = cube(Point3::origin(), 3.0).into_boundaries();
+    let mut cavity = cube(Point3::new(1.0, 1.0, 1.0), 1.0);
+    cavity.not();
+    boundaries.append(&mut cavity.into_boundaries());
+    let (result, removed) = Solid::new(boundaries).remove_interior_faces(TOLERANCE).unwrap();
+    assert_eq!(removed, 0);
+    assert_eq!(result.boundaries().len(), 2);
+    assert!(!result.contains_point(Point3::new(1.5, 1.5, 1.5)));
+}
@@ -0,0 +1,47 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+fn check_inverse_map(old: &Surface) {
+    // the unbounded directions are sampled in the unit interval
+    let (urange, vrange) = old.try_range_tuple();
+    let ((u0, u1), (v0, v1)) = (urange.unwrap_or((0.0, 1.0)), vrange.unwrap_or((0.0, 1.0)));
+    let (new, map) = old.inverse_with_map();
+    const N: usize = 8;
+    for i in 0..N {
+        for j in 0..N {
+            let u = u0 + (u1 - u0) * (i as f64 + 0.5) / N as f64;
+            let v = v0 + (v1 - v0) * (j as f64 + 0.5) / N as f64;
+            let (s, t) = map.apply(u, v);
+            assert_near!(new.subs(s, t), old.subs(u, v));
+            assert_near!(new.normal(s, t), -old.normal(u, v));
+        }
+    }
+    assert_eq!(map.then(new.inverse_map()), ParamMap2D::IDENTITY);
+}
+
+#[test]
+fn all_variants() {
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(2.0, 0.0, 1.0),
+        Point3::new(1.0, 3.0, 2.0),
+    );
+    check_inverse_map(&Surface::Plane(plane));
+
+    let v = builder::vertices([(0.0, 0.0, 0.0), (3.0, 0.0, 1.0)]);
+    let edge0: Edge = builder::bezier(&v[0], &v[1], vec![Point3::new(1.0, 1.0, 0.0)]);
+    let face: Face = builder::tsweep(&edge0, Vector3::new(0.0, 1.0, 1.0));
+    assert!(matches!(face.surface(), Surface::BSplineSurface(_)));
+    check_inverse_map(&face.surface());
+
+    let v = builder::vertex(Point3::new(2.0, 0.0, 0.0));
+    let circle: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI));
+    let face: Face = builder::tsweep(&circle[0], Vector3::unit_z());
+    assert!(matches!(face.surface(), Surface::NurbsSurface(_)));
+    check_inverse_map(&face.surface());
+
+    let edge: Edge = builder::line(&v, &builder::vertex(Point3::new(1.0, 0.0, 2.0)));
+    let shell: Shell = builder::rsweep(&edge, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI));
+    assert!(matches!(shell[0].surface(), Surface::RevolutedCurve(_)));
+    check_inverse_map(&shell[0].surface());
+}
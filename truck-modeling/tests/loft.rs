@@ -0,0 +1,76 @@
+use truck_modeling::{errors::Error, *};
+
+/// Asserts that the faces of `shell` are on the cone frustum `hypot(x, y) = 1 + z`.
+fn assert_on_frustum(shell: &Shell) {
+    shell.face_iter().for_each(|face| {
+        let surface = face.oriented_surface();
+        let (Some((u0, u1)), Some((v0, v1))) = surface.try_range_tuple() else {
+            panic!("the ruled surface is not bounded");
+        };
+        const N: usize = 8;
+        (0..=N).for_each(|i| {
+            (0..=N).for_each(|j| {
+                let u = u0 + (u1 - u0) * i as f64 / N as f64;
+                let v = v0 + (v1 - v0) * j as f64 / N as f64;
+                let p = surface.subs(u, v);
+                assert_near!(f64::hypot(p.x, p.y), 1.0 + p.z);
+            });
+        });
+    });
+}
+
+#[test]
+fn circles_to_frustum() {
+    let v0 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let v1 = builder::vertex(Point3::new(2.0, 0.0, 1.0));
+    let circle0 = builder::rsweep(&v0, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let circle1 = builder::rsweep(&v1, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+
+    let shell: Shell = builder::loft(&circle0, &circle1).unwrap();
+    assert_eq!(shell.len(), circle0.len());
+    assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+    assert_on_frustum(&shell);
+
+    // the reversed circle is corrected
+    let shell: Shell = builder::loft(&circle0, &circle1.inverse()).unwrap();
+    assert_eq!(shell.len(), circle0.len());
+    assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+    assert_on_frustum(&shell);
+    let boundaries = shell.extract_boundaries();
+    assert_eq!(boundaries.len(), 2);
+    boundaries.iter().for_each(|wire| {
+        let z = wire.front_vertex().unwrap().point().z;
+        assert!(wire.vertex_iter().all(|v| v.point().z.near(&z)));
+    });
+}
+
+#[test]
+fn inconsistent_wires() {
+    let v = builder::vertices([
+        (0.0, 0.0, 0.0),
+        (1.0, 0.0, 0.0),
+        (1.0, 1.0, 0.0),
+        (0.0, 1.0, 0.0),
+    ]);
+    let square: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    let open: Wire = square.edge_iter().take(3).cloned().collect();
+    let moved = builder::translated(&square, Vector3::unit_z());
+    assert!(matches!(
+        builder::loft::<Curve, Surface>(&open, &moved),
+        Err(Error::NotSameNumberOfEdges),
+    ));
+
+    let w = builder::vertices([
+        (0.0, 0.0, 1.0),
+        (1.0, 0.0, 1.0),
+        (1.0, 1.0, 1.0),
+        (0.0, 1.0, 1.0),
+        (0.0, 0.0, 1.0),
+    ]);
+    let polyline: Wire = (0..4).map(|i| builder::line(&w[i], &w[i + 1])).collect();
+    assert!(!polyline.is_closed());
+    assert!(matches!(
+        builder::loft::<Curve, Surface>(&square, &polyline),
+        Err(Error::InconsistentWires),
+    ));
+}
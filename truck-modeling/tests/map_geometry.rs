@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+use truck_modeling::*;
+mod common;
+use common::*;
+
+fn points(solid: &Solid) -> Vec<Point3> { solid.vertex_iter().map(|v| v.point()).collect() }
+
+#[test]
+fn unit_conversion() {
+    let cube = cube(Point3::origin(), 1000.0);
+    let mat = Matrix4::from_scale(1.0e-3);
+    let (mut n_points, mut n_curves, mut n_surfaces) = (0, 0, 0);
+    let converted = cube
+        .try_map_geometry(
+            |p| {
+                n_points += 1;
+                Ok::<_, ()>(mat.transform_point(*p))
+            },
+            |c| {
+                n_curves += 1;
+                Ok(c.transformed(mat))
+            },
+            |s| {
+                n_surfaces += 1;
+                Ok(s.transformed(mat))
+            },
+        )
+        .unwrap();
+    assert_eq!((n_points, n_curves, n_surfaces), (8, 12, 6));
+    assert!(converted.is_geometric_consistent());
+    assert!(Solid::try_new(converted.boundaries().clone()).is_ok());
+    let expected: Vec<Point3> = points(&cube).into_iter().map(|p| p * 1.0e-3).collect();
+    assert_eq!(points(&converted), expected);
+
+    // the sharing structure is kept
+    let edges: HashSet<EdgeID> = converted.edge_iter().map(|edge| edge.id()).collect();
+    assert_eq!(edges.len(), 12);
+    let vertices: HashSet<VertexID> = converted.vertex_iter().map(|v| v.id()).collect();
+    assert_eq!(vertices.len(), 8);
+    assert!(cube.edge_iter().all(|edge| !edges.contains(&edge.id())));
+
+    // in place, the clone sharing the geometry is also converted
+    let mut cube = cube;
+    let shared = cube.clone();
+    cube.try_map_geometry_mut(
+        |p| Ok::<_, ()>(mat.transform_point(*p)),
+        |c| Ok(c.transformed(mat)),
+        |s| Ok(s.transformed(mat)),
+    )
+    .unwrap();
+    assert_eq!(points(&shared), expected);
+    assert!(shared.is_geometric_consistent());
+}
+
+#[test]
+fn failing_closure() {
+    let mut cube = cube(Point3::origin(), 1000.0);
+    let original = points(&cube);
+    let mut ids = Vec::new();
+    cube.edge_iter().for_each(|edge| {
+        if !ids.contains(&edge.id()) {
+            ids.push(edge.id());
+        }
+    });
+
+    let mat = Matrix4::from_scale(1.0e-3);
+    let mut count = 0;
+    let mut curve_mapping = |c: &Curve| {
+        count += 1;
+        match count {
+            5 => Err("the fifth curve"),
+            _ => Ok(c.transformed(mat)),
+        }
+    };
+    let res = cube.try_map_geometry_mut(
+        |p| Ok(mat.transform_point(*p)),
+        &mut curve_mapping,
+        |s| Ok(s.transformed(mat)),
+    );
+    assert_eq!(res, Err(MapError::Curve(ids[4], "the fifth curve")));
+    assert_eq!(points(&cube), original);
+    assert!(cube.is_geometric_consistent());
+}
@@ -0,0 +1,67 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+/// a point in the middle of the face and the normal there
+fn sample(face: &Face, surface: &Surface) -> (Point3, Vector3) {
+    let points: Vec<Point3> = face.boundaries()[0].vertex_iter().map(|v| v.point()).collect();
+    let sum = points.iter().fold(Vector3::zero(), |sum, p| sum + p.to_vec());
+    let center = Point3::from_vec(sum / points.len() as f64);
+    let (u, v) = surface.search_nearest_parameter(center, None, 100).unwrap();
+    (surface.subs(u, v), surface.normal(u, v))
+}
+
+fn assert_aligned(mut solid: Solid) {
+    let oriented: Vec<(Point3, Vector3)> = solid
+        .face_iter()
+        .map(|face| sample(face, &face.oriented_surface()))
+        .collect();
+
+    solid.align_surface_normals_outward();
+    assert!(Solid::try_new(solid.boundaries().clone()).is_ok());
+    solid.face_iter().zip(oriented).for_each(|(face, (point, normal))| {
+        assert!(face.orientation());
+        // the oriented normals are not changed
+        let (point0, normal0) = sample(face, &face.oriented_surface());
+        assert_near!(point0, point);
+        assert_near!(normal0, normal);
+        // the raw normals point outward
+        let (point, normal) = sample(face, &face.surface());
+        assert!(!solid.contains_point(point + 0.01 * normal));
+        assert!(solid.contains_point(point - 0.01 * normal));
+    });
+}
+
+#[test]
+fn align_cube() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    assert!(cube.face_iter().any(|face| !face.orientation()));
+    assert_aligned(cube);
+}
+
+#[test]
+fn align_cylinder() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI));
+    let disk: Face = builder::try_attach_plane(vec![circle]).unwrap();
+    assert_aligned(builder::tsweep(&disk, Vector3::new(0.0, 0.0, 2.0)));
+}
+
+#[test]
+fn align_revolved_solid() {
+    // the normal of the profile is +y, the direction of the rotation
+    let v = builder::vertices([(1.0, 0.0, 0.0), (1.0, 0.0, 1.0), (2.0, 0.0, 1.0), (2.0, 0.0, 0.0)]);
+    let profile: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    let solid = builder::revolve(&profile, Point3::origin(), Vector3::unit_z(), Rad(PI)).unwrap();
+    assert_aligned(solid);
+
+    let options = builder::SweepOptions {
+        align_surface_normals: true,
+    };
+    let (origin, axis) = (Point3::origin(), Vector3::unit_z());
+    let solid: Solid =
+        builder::revolve_with_options(&profile, origin, axis, Rad(2.0 * PI), options).unwrap();
+    assert!(solid.face_iter().all(|face| face.orientation()));
+}
@@ -0,0 +1,54 @@
+use truck_modeling::*;
+
+fn punched_plane() -> Face {
+    let v = builder::vertices([
+        (0.0, 0.0, 0.0),
+        (100.0, 0.0, 0.0),
+        (100.0, 100.0, 0.0),
+        (0.0, 100.0, 0.0),
+    ]);
+    let outer: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    let hole: Wire = primitive::circle(
+        Point3::new(51.0, 50.0, 0.0),
+        Point3::new(50.0, 50.0, 0.0),
+        Vector3::unit_z(),
+        4,
+    );
+    builder::try_attach_plane(vec![outer, hole.inverse()]).unwrap()
+}
+
+#[test]
+fn trimming_aware_division() {
+    let face = punched_plane();
+    let surface = face.surface();
+    let (udiv, vdiv) = face.parameter_division(0.01);
+    assert!(udiv.windows(2).all(|a| a[0] < a[1]));
+    assert!(vdiv.windows(2).all(|a| a[0] < a[1]));
+    assert!(udiv.len() + vdiv.len() < 200, "{} {}", udiv.len(), vdiv.len());
+
+    let mut near_cells = 0;
+    let mut max_far_cell = 0.0_f64;
+    udiv.windows(2).for_each(|u| {
+        vdiv.windows(2).for_each(|v| {
+            let cell = BoundingBox::from_iter([
+                surface.subs(u[0], v[0]),
+                surface.subs(u[1], v[0]),
+                surface.subs(u[0], v[1]),
+                surface.subs(u[1], v[1]),
+            ]);
+            let (min, max) = (cell.min(), cell.max());
+            match min.x < 51.0 && 49.0 < max.x && min.y < 51.0 && 49.0 < max.y {
+                true => {
+                    near_cells += 1;
+                    assert!(cell.diameter() < 2.0 * f64::sqrt(2.0), "{cell:?}");
+                }
+                false => max_far_cell = max_far_cell.max(cell.diameter()),
+            }
+        })
+    });
+    assert!(near_cells >= 16);
+    assert!(max_far_cell > 10.0);
+
+    let (udiv4, vdiv4) = face.parameter_division_with_crossings(0.01, 8);
+    assert!(udiv4.len() >= udiv.len() && vdiv4.len() >= vdiv.len());
+}
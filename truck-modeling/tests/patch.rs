@@ -0,0 +1,109 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+/// the boundary of the unit square `[500, 501] x [500, 501] x {0}`
+fn unit_square() -> Vec<Wire> {
+    let v = builder::vertex(Point3::new(500.0, 500.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let face: Face = builder::tsweep(&e, Vector3::unit_y());
+    face.boundaries()
+}
+
+fn assert_range(range: (f64, f64), expected: (f64, f64)) {
+    assert!(range.0.near(&expected.0) && range.1.near(&expected.1), "{range:?}");
+}
+
+#[test]
+fn small_face_on_large_plane() {
+    let plane = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let face = Face::new(unit_square(), Surface::Plane(plane));
+    let (surface, (urange, vrange)) = face.trimmed_surface_patch(0.1, true).unwrap();
+    assert!(matches!(surface, Surface::Plane(got) if got == plane));
+    assert_range(urange, (499.9, 501.1));
+    assert_range(vrange, (499.9, 501.1));
+}
+
+#[test]
+fn clip_spline_surface() {
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 1000.0, 1000.0]);
+    let bsp = BSplineSurface::new(
+        (knot_vec.clone(), knot_vec),
+        vec![
+            vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1000.0, 0.0)],
+            vec![Point3::new(1000.0, 0.0, 0.0), Point3::new(1000.0, 1000.0, 0.0)],
+        ],
+    );
+    let face = Face::new(unit_square(), Surface::BSplineSurface(bsp.clone()));
+    let (surface, (urange, vrange)) = face.trimmed_surface_patch(0.5, false).unwrap();
+    assert!(matches!(surface, Surface::BSplineSurface(ref got) if *got == bsp));
+    assert_range(urange, (499.5, 501.5));
+    assert_range(vrange, (499.5, 501.5));
+
+    let (surface, (urange, vrange)) = face.trimmed_surface_patch(0.5, true).unwrap();
+    let Surface::BSplineSurface(patch) = surface else {
+        panic!("the patch is not a B-spline surface: {surface:?}")
+    };
+    assert_eq!(patch.try_range_tuple(), (Some(urange), Some(vrange)));
+    for (u, v) in [(499.5, 499.5), (500.3, 501.2), (501.5, 501.5)] {
+        assert_near!(patch.subs(u, v), bsp.subs(u, v));
+    }
+
+    // the margin is clamped to the domain
+    let (_, (urange, vrange)) = face.trimmed_surface_patch(600.0, true).unwrap();
+    assert_range(urange, (0.0, 1000.0));
+    assert_range(vrange, (0.0, 1000.0));
+}
+
+#[test]
+fn face_across_seam() {
+    let point = |angle: f64, z: f64| Point3::new(f64::cos(angle), f64::sin(angle), z);
+    let v = builder::vertices([
+        point(-0.5, 0.0),
+        point(0.5, 0.0),
+        point(0.5, 1.0),
+        point(-0.5, 1.0),
+    ]);
+    let wire: Wire = vec![
+        builder::circle_arc(&v[0], &v[1], point(0.0, 0.0)),
+        builder::line(&v[1], &v[2]),
+        builder::circle_arc(&v[2], &v[3], point(0.0, 1.0)),
+        builder::line(&v[3], &v[0]),
+    ]
+    .into();
+    let line = Line(Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 1.0));
+    let generatrix = Curve::BSplineCurve(BSplineCurve::from(line));
+    let surface = RevolutedCurve::by_revolution(generatrix, Point3::origin(), Vector3::unit_z());
+    let face = Face::new(vec![wire], Processor::new(surface).into());
+
+    // the angles are contiguous across the seam
+    let (_, (urange, vrange)) = face.trimmed_surface_patch(0.0, false).unwrap();
+    assert_range(urange, (0.0, 1.0));
+    assert_near!(vrange.1 - vrange.0, 1.0);
+    assert_near!(f64::sin(vrange.0), f64::sin(-0.5));
+    assert_near!(f64::cos(vrange.0), f64::cos(-0.5));
+
+    // the margin is clamped to the range of the generatrix, but not in the periodic direction
+    let (_, (urange, vrange)) = face.trimmed_surface_patch(0.1, false).unwrap();
+    assert_range(urange, (0.0, 1.0));
+    assert_near!(vrange.1 - vrange.0, 1.2);
+    let (_, (_, vrange)) = face.trimmed_surface_patch(4.0, false).unwrap();
+    assert_near!(vrange.1 - vrange.0, 2.0 * PI);
+}
+
+#[test]
+fn degenerate_boundary() {
+    let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)]);
+    let wire: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[0])].into();
+    let plane = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let face = Face::new(vec![wire], Surface::Plane(plane));
+    let res = face.trimmed_surface_patch(0.1, false);
+    assert!(matches!(res, Err(errors::Error::DegenerateParameterRange)));
+}
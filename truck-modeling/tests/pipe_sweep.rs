@@ -0,0 +1,85 @@
+use truck_modeling::*;
+
+/// the circle `x^2 + y^2 = 9` on the plane `z = 0`, starting from `(3, 0, 0)`
+fn circle_path() -> Curve {
+    let r = f64::sqrt(0.5);
+    let control_points = [
+        (1.0, 0.0, 1.0),
+        (r, r, r),
+        (0.0, 1.0, 1.0),
+        (-r, r, r),
+        (-1.0, 0.0, 1.0),
+        (-r, -r, r),
+        (0.0, -1.0, 1.0),
+        (r, -r, r),
+        (1.0, 0.0, 1.0),
+    ];
+    let control_points = control_points.map(|(x, y, w)| Vector4::new(3.0 * x, 3.0 * y, 0.0, w));
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0, 4.0]);
+    let circle = NurbsCurve::new(BSplineCurve::new(knot_vec, control_points.to_vec()));
+    Curve::NurbsCurve(circle)
+}
+
+#[test]
+fn torus_along_closed_path() {
+    let tol = 1.0e-4;
+    let v = builder::vertex(Point3::new(4.0, 0.0, 0.0));
+    let center = Point3::new(3.0, 0.0, 0.0);
+    let profile: Wire = builder::rsweep(&v, center, Vector3::unit_y(), Rad(7.0));
+    let torus = builder::pipe_sweep(&profile, &circle_path(), tol).unwrap();
+    assert_eq!(torus.len(), 2 * profile.len());
+    assert_eq!(torus.shell_condition(), ShellCondition::Closed);
+
+    let on_torus = |p: Point3| f64::hypot(f64::hypot(p.x, p.y) - 3.0, p.z);
+    torus.face_iter().for_each(|face| {
+        let surface = face.surface();
+        let (Some((u0, u1)), Some((v0, v1))) = surface.try_range_tuple() else {
+            panic!("the swept surface is not bounded");
+        };
+        const N: usize = 10;
+        (0..=N).for_each(|i| {
+            (0..=N).for_each(|j| {
+                let u = u0 + (u1 - u0) * i as f64 / N as f64;
+                let v = v0 + (v1 - v0) * j as f64 / N as f64;
+                let p = surface.subs(u, v);
+                assert!(f64::abs(on_torus(p) - 1.0) < 10.0 * tol, "{p:?}");
+                // the surfaces are genuine parametric surfaces
+                let (s, t) = surface.search_parameter(p, (u, v), 100).unwrap();
+                assert_near!(surface.subs(s, t), p);
+            });
+        });
+    });
+}
+
+#[test]
+fn frames_at_inflection() {
+    // the path in the plane `z = 0` has an inflection point at the middle
+    let control_points = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(2.0, -1.0, 0.0),
+        Point3::new(3.0, 0.0, 0.0),
+    ];
+    let path = Curve::BSplineCurve(BSplineCurve::new(KnotVec::bezier_knot(3), control_points));
+    let v = builder::vertices([(0.0, 0.0, 0.1), (0.0, 0.0, 0.3)]);
+    let profile: Wire = vec![builder::line(&v[0], &v[1])].into();
+
+    let end_heights = |frame: builder::SweepFrame| -> Vec<f64> {
+        let shell = builder::pipe_sweep_with_frame(&profile, &path, frame, 1.0e-4).unwrap();
+        assert_eq!(shell.len(), 1);
+        let vertices = shell.vertex_iter().filter(|v| v.point().x.near(&3.0));
+        let mut heights: Vec<f64> = vertices.map(|v| v.point().z).collect();
+        heights.sort_by(f64::total_cmp);
+        heights
+    };
+    // The rotation-minimizing frame keeps the profile upright.
+    let heights = end_heights(builder::SweepFrame::RotationMinimizing);
+    assert_eq!(heights.len(), 2);
+    assert_near!(heights[0], 0.1);
+    assert_near!(heights[1], 0.3);
+    // The Frenet frame turns the profile over at the inflection point.
+    let heights = end_heights(builder::SweepFrame::Frenet);
+    assert_eq!(heights.len(), 2);
+    assert_near!(heights[0], -0.3);
+    assert_near!(heights[1], -0.1);
+}
@@ -0,0 +1,115 @@
+use truck_modeling::*;
+
+#[test]
+fn bulging_arc_is_not_planar() {
+    let tol = 1.0e-4;
+    let height = 10.0 * tol;
+    let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0)]);
+    // the vertices are on the xy-plane, but the arc is not.
+    let arc = builder::circle_arc(&v[3], &v[0], Point3::new(0.0, 0.5, height));
+    let wire: Wire = vec![
+        builder::line(&v[0], &v[1]),
+        builder::line(&v[1], &v[2]),
+        builder::line(&v[2], &v[3]),
+        arc.clone(),
+    ]
+    .into();
+
+    let Err(NonPlanarError::Deviation { plane, deviation }) = wire.fit_plane(tol) else {
+        panic!("the wire must not be planar");
+    };
+    assert!(deviation > tol && deviation <= height, "{deviation}");
+    // the deviation is the distance of the farthest point of the wire
+    let distance = |p: Point3| f64::abs((p - plane.origin()).dot(plane.normal()));
+    let farthest = (0..=100)
+        .map(|i| arc.curve().subs(i as f64 / 100.0 * arc.curve().range_tuple().1))
+        .chain(v.iter().map(|v| v.point()))
+        .map(distance)
+        .fold(0.0, f64::max);
+    assert!(f64::abs(farthest - deviation) < tol, "{farthest} {deviation}");
+    assert!(!wire.is_planar(tol));
+    assert_eq!(
+        builder::try_attach_plane::<_, Surface>(vec![wire]).unwrap_err(),
+        errors::Error::WireNotInOnePlane,
+    );
+}
+
+#[test]
+fn planar_wire_far_from_origin() {
+    let origin = Point3::new(1.0e6, -2.0e6, 3.0e6);
+    let (u, v) = (Vector3::new(1.0, 1.0, 0.0), Vector3::new(0.0, 1.0, 1.0));
+    let normal = u.cross(v).normalize();
+    let p = builder::vertices([origin, origin + 2.0 * u, origin + 2.0 * u + v, origin + v]);
+    let wire: Wire = vec![
+        builder::line(&p[0], &p[1]),
+        builder::circle_arc(&p[1], &p[2], origin + 2.5 * u + 0.5 * v),
+        builder::line(&p[2], &p[3]),
+        builder::line(&p[3], &p[0]),
+    ]
+    .into();
+
+    let tol = 1.0e-4;
+    let (plane, deviation) = wire.fit_plane(tol).unwrap();
+    assert_near!(plane.normal(), normal);
+    assert!(deviation < 1.0e-8, "{deviation}");
+    assert!(f64::abs((origin - plane.origin()).dot(normal)) < 1.0e-8);
+
+    let line: Wire = vec![builder::line(&p[0], &p[1]), builder::line(&p[1], &p[0])].into();
+    assert_eq!(line.fit_plane(tol), Err(NonPlanarError::Degenerate));
+}
+
+#[test]
+fn relative_planarity() {
+    // the square of the size `scale` whose corner is lifted by `scale * bump`
+    let square = |scale: f64, bump: f64| -> Wire {
+        let v = builder::vertices([
+            Point3::new(0.0, 0.0, 0.0) * scale,
+            Point3::new(1.0, 0.0, 0.0) * scale,
+            Point3::new(1.0, 1.0, bump) * scale,
+            Point3::new(0.0, 1.0, 0.0) * scale,
+        ]);
+        (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect()
+    };
+    let relative = ToleranceSpec::new(0.0, 1.0e-3);
+    for scale in [1.0e-6, 1.0, 1.0e6] {
+        assert!(square(scale, 1.0e-5).is_planar(relative), "{scale}");
+        assert!(!square(scale, 0.1).is_planar(relative), "{scale}");
+        // the default spec is the same as the absolute tolerance
+        for bump in [1.0e-8, 1.0e-5, 0.1] {
+            let wire = square(scale, bump);
+            assert_eq!(wire.is_planar(TOLERANCE), wire.is_planar(ToleranceSpec::default()));
+        }
+    }
+    // the absolute tolerance fails at both extremes
+    assert!(square(1.0e-6, 0.1).is_planar(TOLERANCE));
+    assert!(!square(1.0e6, 1.0e-5).is_planar(TOLERANCE));
+}
+
+#[test]
+fn relative_edge_equality() {
+    // the line of the length `scale` and the bezier bulged by `scale * bump` at the middle
+    let edges = |scale: f64, bump: f64| -> (Edge, Edge) {
+        let v = builder::vertices([Point3::new(0.0, 0.0, 0.0), Point3::new(scale, 0.0, 0.0)]);
+        let inter = vec![Point3::new(0.5, 2.0 * bump, 0.0) * scale];
+        (builder::line(&v[0], &v[1]), builder::bezier(&v[0], &v[1], inter))
+    };
+    let relative = ToleranceSpec::new(0.0, 1.0e-3);
+    for scale in [1.0e-6, 1.0, 1.0e6] {
+        let (line, bezier) = edges(scale, 1.0e-5);
+        assert!(line.geometric_eq(&bezier, relative), "{scale}");
+        assert!(!line.geometric_eq(&bezier.inverse(), relative), "{scale}");
+        let (line, bezier) = edges(scale, 0.1);
+        assert!(!line.geometric_eq(&bezier, relative), "{scale}");
+        // the default spec is the same as the absolute tolerance
+        for bump in [1.0e-8, 1.0e-5, 0.1] {
+            let (line, bezier) = edges(scale, bump);
+            let default = line.geometric_eq(&bezier, ToleranceSpec::default());
+            assert_eq!(line.geometric_eq(&bezier, TOLERANCE.into()), default);
+        }
+    }
+    // the absolute tolerance fails at both extremes
+    let (line, bezier) = edges(1.0e-6, 0.1);
+    assert!(line.geometric_eq(&bezier, TOLERANCE.into()));
+    let (line, bezier) = edges(1.0e6, 1.0e-5);
+    assert!(!line.geometric_eq(&bezier, TOLERANCE.into()));
+}
@@ -0,0 +1,24 @@
+use truck_modeling::prelude::*;
+
+#[test]
+fn modeling_with_prelude() {
+    let v: Vertex = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+    let e: Edge = builder::tsweep(&v, Vector3::unit_x());
+    let f: Face = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let moved = builder::translated(&cube, Vector3::new(1.0, 0.0, 0.0));
+    assert!(moved.contains_point(Point3::new(1.5, 0.5, 0.5)));
+    assert!(!moved.contains_point(Point3::new(0.5, 0.5, 0.5)));
+
+    let top = moved
+        .face_iter()
+        .find(|face| face.normal_axis().is_some_and(|(p, _)| p.z > 0.9))
+        .unwrap();
+    let surface: Surface = top.oriented_surface();
+    let point = Point3::new(1.25, 0.75, 1.0);
+    let (u, v) = surface.search_parameter(point, None, 10).unwrap();
+    assert_near!(surface.subs(u, v), point);
+
+    let hull: Result<Solid> = moved.convex_hull(0.01);
+    assert_eq!(hull.unwrap().face_iter().count(), 6);
+}
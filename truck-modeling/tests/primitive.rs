@@ -0,0 +1,180 @@
+use std::{collections::HashSet, f64::consts::PI};
+use truck_modeling::*;
+
+const RADIUS: f64 = 1.5;
+const HEIGHT: f64 = 2.0;
+
+fn center() -> Point3 { Point3::new(1.0, -2.0, 0.5) }
+
+fn axis() -> Vector3 { Vector3::new(1.0, 1.0, 2.0).normalize() }
+
+/// an orthonormal basis of the plane perpendicular to the axis
+fn radial_basis() -> (Vector3, Vector3) {
+    let x = axis().cross(Vector3::unit_x()).normalize();
+    (x, axis().cross(x))
+}
+
+/// The volume of the solid of revolution, integrated by the rays from the axis.
+///
+/// The distances of the hits are the radii of the sections, so the orientations of the side faces
+/// are also checked.
+fn revolution_volume(solid: &Solid) -> f64 {
+    const N: usize = 20;
+    const M: usize = 5;
+    let (x, y) = radial_basis();
+    let ds = HEIGHT / N as f64;
+    let dtheta = 2.0 * PI / M as f64;
+    (0..N)
+        .flat_map(|i| (0..M).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let origin = center() + (i as f64 + 0.5) * ds * axis();
+            let theta = (j as f64 + 0.3) * dtheta;
+            let dir = f64::cos(theta) * x + f64::sin(theta) * y;
+            let hits = solid.ray_hits(origin, dir, TOLERANCE);
+            assert_eq!(hits.len(), 1, "{origin:?} {dir:?}");
+            assert!(!hits[0].entering);
+            hits[0].t * hits[0].t / 2.0 * dtheta * ds
+        })
+        .sum()
+}
+
+/// the hits of the ray along the axis from the point below the bottom
+fn axial_hits(solid: &Solid, offset: f64) -> Vec<RayHit> {
+    let (x, _) = radial_basis();
+    let origin = center() - axis() + offset * x;
+    solid.ray_hits(origin, axis(), TOLERANCE)
+}
+
+#[test]
+fn cylinder() {
+    let solid: Solid = primitive::cylinder(center(), RADIUS, 3.0 * axis(), HEIGHT);
+    let shell = &solid.boundaries()[0];
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    assert!(solid.is_geometric_consistent());
+    assert!(shell.face_iter().all(|face| face.boundaries().len() == 1));
+
+    // the bottom is entered and the top is exited
+    let hits = axial_hits(&solid, 0.5 * RADIUS);
+    assert_eq!(hits.len(), 2);
+    assert!(hits[0].entering && !hits[1].entering);
+    assert_near!(hits[0].t, 1.0);
+    assert_near!(hits[1].t, 1.0 + HEIGHT);
+
+    let volume = PI * RADIUS * RADIUS * HEIGHT;
+    assert!(f64::abs(revolution_volume(&solid) - volume) < 1.0e-3 * volume);
+}
+
+#[test]
+fn cone() {
+    let solid: Solid = primitive::cone(center(), RADIUS, axis(), HEIGHT);
+    let shell = &solid.boundaries()[0];
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    assert!(solid.is_geometric_consistent());
+
+    // The apex is a single vertex, and no face or edge is collapsed into it.
+    let apex = center() + HEIGHT * axis();
+    let apexes: HashSet<VertexID> = solid
+        .vertex_iter()
+        .filter(|v| v.point().near(&apex))
+        .map(|v| v.id())
+        .collect();
+    assert_eq!(apexes.len(), 1);
+    assert!(solid.edge_iter().all(|edge| !edge.front().point().near(&edge.back().point())));
+    shell.face_iter().for_each(|face| {
+        assert!(face.cached_bounding_box().diameter() > RADIUS);
+    });
+
+    // the bottom is entered and the side is exited
+    let hits = axial_hits(&solid, 0.5 * RADIUS);
+    assert_eq!(hits.len(), 2);
+    assert!(hits[0].entering && !hits[1].entering);
+    assert_near!(hits[0].t, 1.0);
+    assert_near!(hits[1].t, 1.0 + 0.5 * HEIGHT);
+
+    // the relative error of the midpoint rule is `1 / (4 N^2)`
+    let volume = PI * RADIUS * RADIUS * HEIGHT / 3.0;
+    assert!(f64::abs(revolution_volume(&solid) - volume) < 1.0e-3 * volume);
+}
+
+/// Asserts that each point is found on the surface of some face of `solid`.
+fn assert_on_surfaces(solid: &Solid, points: impl IntoIterator<Item = Point3>) {
+    points.into_iter().for_each(|point| {
+        let found = solid.face_iter().any(|face| {
+            let surface = face.surface();
+            let param = surface.search_parameter(point, None, 100);
+            param.is_some_and(|(u, v)| surface.subs(u, v).near(&point))
+        });
+        assert!(found, "{point:?}");
+    });
+}
+
+#[test]
+fn sphere() {
+    let solid: Solid = primitive::sphere(center(), RADIUS);
+    let shell = &solid.boundaries()[0];
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    assert!(shell.extract_boundaries().is_empty());
+    assert!(solid.is_geometric_consistent());
+
+    // The poles are single vertices, and no edge is collapsed into them.
+    [1.0, -1.0].into_iter().for_each(|sign| {
+        let pole = center() + sign * RADIUS * Vector3::unit_z();
+        let poles: HashSet<VertexID> = solid
+            .vertex_iter()
+            .filter(|v| v.point().near(&pole))
+            .map(|v| v.id())
+            .collect();
+        assert_eq!(poles.len(), 1);
+    });
+    assert!(solid.edge_iter().all(|edge| !edge.front().point().near(&edge.back().point())));
+
+    // the ray through the center
+    let dir = Vector3::new(2.0, 1.0, 2.0) / 3.0;
+    let hits = solid.ray_hits(center() - 3.0 * dir, dir, TOLERANCE);
+    assert_eq!(hits.len(), 2);
+    assert!(hits[0].entering && !hits[1].entering);
+    assert_near!(hits[0].t, 3.0 - RADIUS);
+    assert_near!(hits[1].t, 3.0 + RADIUS);
+
+    let points = (0..12).flat_map(|i| {
+        (0..7).map(move |j| {
+            let (theta, phi) = (PI * (i as f64 + 0.3) / 6.0, PI * (j as f64 - 3.0) / 7.0);
+            let dir = Vector3::new(
+                f64::cos(phi) * f64::cos(theta),
+                f64::cos(phi) * f64::sin(theta),
+                f64::sin(phi),
+            );
+            center() + RADIUS * dir
+        })
+    });
+    assert_on_surfaces(&solid, points);
+}
+
+#[test]
+fn torus() {
+    let (large, small) = (2.0, 0.5);
+    let solid: Solid = primitive::torus(center(), large, small, 3.0 * axis());
+    let shell = &solid.boundaries()[0];
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    assert!(shell.extract_boundaries().is_empty());
+    assert!(solid.is_geometric_consistent());
+
+    // the ray along the axis through the center of the tube
+    let (x, y) = radial_basis();
+    let hits = solid.ray_hits(center() + large * x - 2.0 * axis(), axis(), TOLERANCE);
+    assert_eq!(hits.len(), 2);
+    assert!(hits[0].entering && !hits[1].entering);
+    assert_near!(hits[0].t, 2.0 - small);
+    assert_near!(hits[1].t, 2.0 + small);
+    assert!(!solid.contains_point(center()));
+
+    let points = (0..12).flat_map(|i| {
+        (0..8).map(move |j| {
+            let (theta, phi) = (PI * (i as f64 + 0.3) / 6.0, PI * (j as f64 + 0.1) / 4.0);
+            let radial = f64::cos(theta) * x + f64::sin(theta) * y;
+            let r = large + small * f64::cos(phi);
+            center() + r * radial + small * f64::sin(phi) * axis()
+        })
+    });
+    assert_on_surfaces(&solid, points);
+}
@@ -0,0 +1,105 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+const XY: (Vector3, Vector3) = (Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+
+fn project(wire: &Wire) -> PlanarProjection {
+    wire.project_to_plane_2d(Point3::origin(), XY.0, XY.1, TOLERANCE)
+}
+
+/// the points of the projected wire divided with the tolerance
+fn divided_points(wire: &Wire2D) -> Vec<Point2> {
+    wire.edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.oriented_curve();
+            curve.parameter_division(curve.range_tuple(), TOLERANCE).1
+        })
+        .collect()
+}
+
+#[test]
+fn project_helix() {
+    // lifts the arcs of the unit circle to a turn of a helix-like curve of the height 3
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI));
+    let vertices: Vec<Vertex> = (0..=3)
+        .map(|i| builder::vertex(circle[i % 3].front().point() + i as f64 * Vector3::unit_z()))
+        .collect();
+    let helix: Wire = circle
+        .edge_iter()
+        .enumerate()
+        .map(|(i, edge)| {
+            let Curve::NurbsCurve(mut curve) = edge.oriented_curve() else {
+                panic!("the arcs must be NURBS curves");
+            };
+            let n = curve.control_points().len() - 1;
+            let mut k = 0;
+            curve.transform_control_points(|v| {
+                v.z = v.w * (i as f64 + k as f64 / n as f64);
+                k += 1;
+            });
+            Edge::new(&vertices[i], &vertices[i + 1], Curve::NurbsCurve(curve))
+        })
+        .collect();
+    assert!(helix.is_continuous());
+
+    let projection = project(&helix);
+    assert!(projection.dropped.is_empty());
+    assert_eq!(projection.wire.len(), 3);
+    assert!(projection.wire.is_continuous());
+    let points = divided_points(&projection.wire);
+    assert!(points.len() > 3);
+    points.iter().for_each(|p| assert_near!(p.to_vec().magnitude(), 1.0));
+}
+
+#[test]
+fn project_planar_wire() {
+    let v = builder::vertices([(1.0, 2.0, 0.0), (3.0, 2.0, 0.0), (3.0, 5.0, 0.0), (1.0, 5.0, 0.0)]);
+    let mut wire: Wire = (0..3).map(|i| builder::line(&v[i], &v[i + 1])).collect();
+    wire.push_back(builder::circle_arc(&v[3], &v[0], Point3::new(0.5, 3.5, 0.0)));
+
+    let projection = project(&wire);
+    assert!(projection.dropped.is_empty());
+    assert!(!projection.self_intersecting);
+    assert!(projection.wire.is_closed());
+    wire.edge_iter()
+        .zip(projection.wire.edge_iter())
+        .for_each(|(edge, edge2d)| {
+            let (curve, curve2d) = (edge.oriented_curve(), edge2d.oriented_curve());
+            assert_eq!(curve.range_tuple(), curve2d.range_tuple());
+            let (t0, t1) = curve.range_tuple();
+            (0..=10).for_each(|i| {
+                let t = t0 + (t1 - t0) * i as f64 / 10.0;
+                let (p, q) = (curve.subs(t), curve2d.subs(t));
+                assert_near!(Point2::new(p.x, p.y), q);
+            });
+        });
+}
+
+#[test]
+fn drop_edge_along_view() {
+    let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 0.0, 1.0), (1.0, 1.0, 1.0)]);
+    let wire: Wire = (0..3).map(|i| builder::line(&v[i], &v[i + 1])).collect();
+
+    let projection = project(&wire);
+    assert_eq!(projection.dropped, vec![wire[1].id()]);
+    assert_eq!(projection.wire.len(), 2);
+    assert!(projection.wire.is_continuous());
+    let points: Vec<Point2> = projection.wire.vertex_iter().map(|v| v.point()).collect();
+    let expected = [Point2::new(0.0, 0.0), Point2::new(1.0, 0.0), Point2::new(1.0, 1.0)];
+    assert_eq!(points, expected);
+}
+
+#[test]
+fn report_self_intersection() {
+    // a skew quadrilateral projected to a bow tie
+    let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 1.0, 0.0), (1.0, 0.0, 1.0), (0.0, 1.0, 1.0)]);
+    let wire: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    let projection = project(&wire);
+    assert!(projection.wire.is_closed());
+    assert!(projection.self_intersecting);
+
+    // the projection along the other axis is simple
+    let projection = wire.project_to_plane_2d(Point3::origin(), XY.0, Vector3::unit_z(), TOLERANCE);
+    assert!(!projection.self_intersecting);
+}
@@ -0,0 +1,169 @@
+use truck_modeling::*;
+use algo::surface::SurfaceProximity;
+mod common;
+use common::*;
+
+fn brute_force_distance(shell: &Shell, point: Point3) -> f64 {
+    shell
+        .edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.curve();
+            let (t0, t1) = curve.range_tuple();
+            (0..=1000).map(move |i| curve.subs(t0 + (t1 - t0) * i as f64 / 1000.0))
+        })
+        .map(|pt| pt.distance(point))
+        .fold(f64::INFINITY, f64::min)
+}
+
+#[test]
+fn shell_edge_midpoints() {
+    let shell = unit_cube_shell();
+    let edges: Vec<Edge> = {
+        let mut ids = std::collections::HashSet::new();
+        shell.edge_iter().filter(|e| ids.insert(e.id())).collect()
+    };
+    assert_eq!(edges.len(), 12);
+    for edge in &edges {
+        let (p, q) = (edge.front().point(), edge.back().point());
+        let mid = p.midpoint(q);
+        // push the midpoint away from the center of the cube
+        let point = mid + (mid - Point3::new(0.5, 0.5, 0.5)) * 0.2;
+        let prox = shell.closest_edge(point).unwrap();
+        assert_eq!(prox.edge_id, edge.id());
+        assert_near!(prox.point, mid);
+        assert_near!(prox.distance, mid.distance(point));
+        assert_near!(edge.curve().subs(prox.parameter), prox.point);
+    }
+}
+
+#[test]
+fn shell_shared_vertices() {
+    let shell = unit_cube_shell();
+    for vertex in shell.vertex_iter() {
+        let point = vertex.point();
+        let prox = shell.closest_edge(point).unwrap();
+        assert_eq!(prox.distance, 0.0);
+        assert_eq!(prox.point, point);
+        let edge = shell.edge_iter().find(|e| e.id() == prox.edge_id).unwrap();
+        assert!(edge.front() == &vertex || edge.back() == &vertex);
+    }
+}
+
+#[test]
+fn shell_far_point() {
+    let shell = unit_cube_shell();
+    let points = [
+        Point3::new(100.0, 200.0, -300.0),
+        Point3::new(-50.0, 0.5, 0.5),
+        Point3::new(0.3, 0.2, 80.0),
+    ];
+    for point in points {
+        let prox = shell.closest_edge(point).unwrap();
+        assert_near!(prox.distance, prox.point.distance(point));
+        assert!(f64::abs(prox.distance - brute_force_distance(&shell, point)) < 1.0e-3);
+    }
+    let prox = shell.closest_edge(points[0]).unwrap();
+    assert_eq!(prox.point, Point3::new(1.0, 1.0, 0.0));
+}
+
+#[test]
+fn shell_tie_breaking() {
+    let shell = unit_cube_shell();
+    // equidistant to the two edges on the face z = 0 through the corner (0, 0, 0)
+    let point = Point3::new(-1.0, -1.0, 0.0);
+    let prox = shell.closest_edge(point).unwrap();
+    assert_eq!(prox.point, Point3::origin());
+    assert_eq!(prox.distance, f64::sqrt(2.0));
+    // the first edge through the corner in the order of `edge_iter`
+    let first = shell
+        .edge_iter()
+        .find(|e| e.front().point() == Point3::origin() || e.back().point() == Point3::origin())
+        .unwrap();
+    assert_eq!(prox.edge_id, first.id());
+}
+
+#[test]
+fn wire_closest_point() {
+    let v = builder::vertices([(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (0.0, 2.0, 0.0)]);
+    let wire: Wire = vec![
+        builder::line(&v[0], &v[1]),
+        builder::circle_arc(&v[1], &v[2], Point3::new(f64::sqrt(2.0), f64::sqrt(2.0), 0.0)),
+        builder::line(&v[2], &v[0]),
+    ]
+    .into();
+
+    // near the midpoint of the arc
+    let prox = wire.closest_point(Point3::new(1.5, 1.5, 0.3)).unwrap();
+    assert_eq!(prox.edge_index, 1);
+    assert_eq!(prox.edge_id, wire[1].id());
+    let expected = Point3::new(f64::sqrt(2.0), f64::sqrt(2.0), 0.0);
+    assert_near!(prox.point, expected);
+    assert_near!(prox.distance, expected.distance(Point3::new(1.5, 1.5, 0.3)));
+    assert_near!(wire[1].curve().subs(prox.parameter), prox.point);
+
+    // exactly at a shared vertex
+    let prox = wire.closest_point(Point3::new(2.0, 0.0, 0.0)).unwrap();
+    assert!(prox.edge_index == 0 || prox.edge_index == 1);
+    assert_eq!(prox.distance, 0.0);
+
+    // outside of the corner between the last and the first edges
+    let prox = wire.closest_point(Point3::new(-3.0, -4.0, 0.0)).unwrap();
+    assert_eq!(prox.edge_index, 0);
+    assert_eq!(prox.distance, 5.0);
+}
+
+fn square(points: [(f64, f64, f64); 4]) -> Face {
+    let v = builder::vertices(points);
+    let wire: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    builder::try_attach_plane(vec![wire]).unwrap()
+}
+
+#[test]
+fn face_proximity() {
+    let shell = unit_cube_shell();
+    let faces: Vec<&Face> = shell.face_iter().collect();
+    // the bottom and the side sharing an edge
+    let res = faces[0].proximity(faces[1], TOLERANCE).unwrap();
+    let SurfaceProximity::Intersecting { seed_points } = res else {
+        panic!("{res:?}");
+    };
+    assert!(!seed_points.is_empty());
+    for point in seed_points {
+        assert!(point.z.so_small() && point.y.so_small(), "{point:?}");
+    }
+
+    // a vertical square crossing the bottom face
+    let face = square([(0.5, 0.2, -1.0), (0.5, 0.8, -1.0), (0.5, 0.8, 1.0), (0.5, 0.2, 1.0)]);
+    let res = faces[0].proximity(&face, TOLERANCE).unwrap();
+    let SurfaceProximity::Intersecting { seed_points } = res else {
+        panic!("{res:?}");
+    };
+    assert!(!seed_points.is_empty());
+    for point in seed_points {
+        assert!(point.z.so_small() && point.x.near(&0.5), "{point:?}");
+        assert!(0.2 - TOLERANCE < point.y && point.y < 0.8 + TOLERANCE, "{point:?}");
+    }
+}
+
+#[test]
+fn face_proximity_through_hole() {
+    let v = builder::vertices([(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 4.0, 0.0), (0.0, 4.0, 0.0)]);
+    let outer: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    let v = builder::vertices([(1.0, 1.0, 0.0), (1.0, 3.0, 0.0), (3.0, 3.0, 0.0), (3.0, 1.0, 0.0)]);
+    let hole: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    let plate: Face = builder::try_attach_plane(vec![outer, hole]).unwrap();
+
+    // The untrimmed surfaces intersect in the hole.
+    let face = square([(2.0, 1.3, -1.0), (2.0, 2.3, -1.0), (2.0, 2.3, 1.0), (2.0, 1.3, 1.0)]);
+    let res = plate.proximity(&face, TOLERANCE).unwrap();
+    let SurfaceProximity::Disjoint {
+        distance,
+        witness_pair: (p, q),
+    } = res
+    else {
+        panic!("{res:?}");
+    };
+    assert_near!(distance, 0.3);
+    assert_near!(p, Point3::new(2.0, 1.0, 0.0));
+    assert_near!(q, Point3::new(2.0, 1.3, 0.0));
+}
@@ -0,0 +1,142 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+mod common;
+use common::*;
+
+fn unit_sphere() -> Solid {
+    let v0 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let wire: Wire = builder::rsweep(&v0, Point3::origin(), Vector3::unit_x(), Rad(PI));
+    let shell = builder::cone(&wire, Vector3::unit_y(), Rad(7.0));
+    Solid::new(vec![shell])
+}
+
+#[test]
+fn cube_through_faces() {
+    let cube = unit_cube();
+    let origin = Point3::new(0.3, -2.0, 0.6);
+    let hits = cube.ray_hits(origin, Vector3::new(0.0, 2.0, 0.0), TOLERANCE);
+    assert_eq!(hits.len(), 2);
+    assert_near!(hits[0].t, 1.0);
+    assert_near!(hits[0].point, Point3::new(0.3, 0.0, 0.6));
+    assert!(hits[0].entering);
+    assert_near!(hits[1].t, 1.5);
+    assert_near!(hits[1].point, Point3::new(0.3, 1.0, 0.6));
+    assert!(!hits[1].entering);
+    for hit in hits {
+        let face = cube.face_iter().find(|face| face.id() == hit.face).unwrap();
+        let (u, v) = hit.uv;
+        assert_near!(face.surface().subs(u, v), hit.point);
+    }
+
+    // the origin is inside
+    let hits = cube.ray_hits(Point3::new(0.5, 0.5, 0.5), Vector3::unit_z(), TOLERANCE);
+    assert_eq!(hits.len(), 1);
+    assert!(!hits[0].entering);
+
+    // the ray behind the cube
+    let hits = cube.ray_hits(Point3::new(0.5, 0.5, 2.0), Vector3::unit_z(), TOLERANCE);
+    assert!(hits.is_empty());
+}
+
+#[test]
+fn cube_through_edges() {
+    let cube = unit_cube();
+    // through the edges x = y = 0 and x = y = 1
+    let hits = cube.ray_hits(Point3::new(-1.0, -1.0, 0.5), Vector3::new(1.0, 1.0, 0.0), TOLERANCE);
+    assert_eq!(hits.len(), 2);
+    assert_near!(hits[0].point, Point3::new(0.0, 0.0, 0.5));
+    assert!(hits[0].entering);
+    assert_near!(hits[1].point, Point3::new(1.0, 1.0, 0.5));
+    assert!(!hits[1].entering);
+
+    // through the vertices (0, 0, 0) and (1, 1, 1)
+    let hits = cube.ray_hits(Point3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0), TOLERANCE);
+    assert_eq!(hits.len(), 2);
+    assert!(hits[0].entering && !hits[1].entering);
+
+    // grazing the silhouette edge x = y = 0
+    let hits = cube.ray_hits(Point3::new(-1.0, 1.0, 0.5), Vector3::new(1.0, -1.0, 0.0), TOLERANCE);
+    assert!(hits.is_empty());
+}
+
+#[test]
+fn sphere_hits() {
+    let sphere = unit_sphere();
+    let hits = sphere.ray_hits(Point3::new(-3.0, 0.1, 0.2), Vector3::unit_x(), TOLERANCE);
+    assert_eq!(hits.len(), 2);
+    let x = f64::sqrt(1.0 - 0.05);
+    assert_near!(hits[0].point, Point3::new(-x, 0.1, 0.2));
+    assert!(hits[0].entering);
+    assert_near!(hits[1].point, Point3::new(x, 0.1, 0.2));
+    assert!(!hits[1].entering);
+
+    // grazing: the two intersections are within the tolerance
+    let hits = sphere.ray_hits(Point3::new(-3.0, 0.0, 1.0 - 1.0e-10), Vector3::unit_x(), 1.0e-3);
+    assert!(hits.is_empty());
+}
+
+#[test]
+fn contains_point() {
+    let sphere = unit_sphere();
+    let points = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(0.5, -0.5, 0.5),
+        Point3::new(-0.1, 0.9, 0.3),
+        Point3::new(0.8, 0.8, 0.0),
+        Point3::new(0.0, 0.0, 1.2),
+        Point3::new(-2.0, 3.0, 1.0),
+    ];
+    for point in points {
+        let inside = point.to_vec().magnitude() < 1.0;
+        assert_eq!(sphere.contains_point(point), inside, "{point:?}");
+    }
+
+    let cube = unit_cube();
+    assert!(cube.contains_point(Point3::new(0.9, 0.1, 0.5)));
+    assert!(!cube.contains_point(Point3::new(0.5, -0.1, 0.5)));
+}
+
+/// the wall of the cylinder `x^2 + y^2 = 1, 0 <= z <= 1` in the angles `[-PI / 4, PI / 4]`,
+/// whose boundary crosses the seam `y = 0, x > 0` twice
+fn seam_crossing_wall() -> Face {
+    let p = |angle: f64, z: f64| Point3::new(f64::cos(angle), f64::sin(angle), z);
+    let (a0, a1) = (-PI / 4.0, PI / 4.0);
+    let v = builder::vertices([p(a0, 0.0), p(a0, 1.0), p(a1, 1.0), p(a1, 0.0)]);
+    // counterclockwise in the parameter space `(z, angle)`
+    let wire: Wire = vec![
+        builder::line(&v[0], &v[1]),
+        builder::circle_arc(&v[1], &v[2], p(0.0, 1.0)),
+        builder::line(&v[2], &v[3]),
+        builder::circle_arc(&v[3], &v[0], p(0.0, 0.0)),
+    ]
+    .into();
+    let w = builder::vertices([p(0.0, 0.0), p(0.0, 1.0)]);
+    let line: Edge = builder::line(&w[0], &w[1]);
+    let surface = RevolutedCurve::by_revolution(line.curve(), Point3::origin(), Vector3::unit_z());
+    Face::new(vec![wire], Processor::new(surface).into())
+}
+
+#[test]
+fn seam_crossing_face() {
+    let face = seam_crossing_wall();
+    let loops = face.uv_boundary(1.0e-3).unwrap();
+    assert_eq!(loops.len(), 1);
+    assert_eq!(loops[0].branches().len(), 2);
+    assert_eq!(loops[0].total_jump(), Vector2::new(0.0, 0.0));
+    let angles = [(0.0, true), (0.5, true), (-0.5, true), (1.0, false), (-1.0, false), (PI, false)];
+    for (angle, inside) in angles {
+        // the parameters on the both sides of the seam
+        for v in [angle, angle + 2.0 * PI] {
+            let winding = loops[0].winding_number(Point2::new(0.5, v));
+            assert_eq!(winding != 0, inside, "{v}");
+        }
+    }
+
+    // the rays from the axis hit the wall only in the angles of the face
+    let solid = Solid::new_unchecked(vec![vec![face].into()]);
+    for (angle, inside) in angles {
+        let dir = Vector3::new(f64::cos(angle), f64::sin(angle), 0.0);
+        let hits = solid.ray_hits(Point3::new(0.0, 0.0, 0.5), dir, TOLERANCE);
+        assert_eq!(hits.len(), inside as usize, "{angle}");
+    }
+}
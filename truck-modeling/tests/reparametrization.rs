@@ -0,0 +1,77 @@
+use truck_modeling::{errors::Error, *};
+
+fn sections() -> Vec<Curve> {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let arc: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(2.0));
+    let bspline = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            Point3::new(1.0, 0.0, 1.0),
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::new(0.0, 1.0, 1.0),
+        ],
+    );
+    vec![arc[0].curve(), Curve::BSplineCurve(bspline)]
+}
+
+#[test]
+fn match_sections() {
+    let originals = sections();
+    let mut curves = originals.clone();
+    let correspondence: Vec<Vec<f64>> = originals
+        .iter()
+        .zip([[0.1, 0.3], [0.5, 0.9]])
+        .map(|(curve, [x, y])| {
+            let (t0, t1) = curve.range_tuple();
+            vec![t0 + (t1 - t0) * x, t0 + (t1 - t0) * y]
+        })
+        .collect();
+    builder::match_parameterizations(&mut curves, &correspondence).unwrap();
+
+    let common = [0.3, 0.6];
+    curves.iter().zip(&originals).zip(&correspondence).for_each(|((curve, original), params)| {
+        assert!(matches!(curve, Curve::ReparametrizedCurve(_)));
+        assert_eq!(curve.range_tuple(), (0.0, 1.0));
+        assert_near!(curve.front(), original.front());
+        assert_near!(curve.back(), original.back());
+        common.iter().zip(params).for_each(|(s, t)| {
+            assert_near!(curve.subs(*s), original.subs(*t));
+        });
+        for i in 0..=20 {
+            let s = i as f64 / 20.0;
+            let t = original.search_nearest_parameter(curve.subs(s), None, 100).unwrap();
+            assert_near!(curve.subs(s), original.subs(t));
+        }
+    });
+
+    // matching again composes the maps
+    let matched = curves.clone();
+    builder::match_parameterizations(&mut curves, &[vec![0.5], vec![0.7]]).unwrap();
+    let Curve::ReparametrizedCurve(curve) = &curves[0] else {
+        panic!("the curve must be reparametrized");
+    };
+    assert!(!matches!(**curve.curve(), Curve::ReparametrizedCurve(_)));
+    assert_near!(curves[0].subs(0.6), matched[0].subs(0.5));
+    assert_near!(curves[1].subs(0.6), matched[1].subs(0.7));
+}
+
+#[test]
+fn invalid_correspondence() {
+    let mut curves = sections();
+    let res = builder::match_parameterizations(&mut curves, &[vec![0.5]]);
+    assert_eq!(res, Err(Error::InvalidCorrespondence));
+    let res = builder::match_parameterizations(&mut curves, &[vec![0.5], vec![0.2, 0.4]]);
+    assert_eq!(res, Err(Error::InvalidCorrespondence));
+
+    let (t0, t1) = curves[0].range_tuple();
+    let params = vec![t0 + (t1 - t0) * 0.6, t0 + (t1 - t0) * 0.4];
+    let res = builder::match_parameterizations(&mut curves, &[params, vec![0.2, 0.4]]);
+    assert!(matches!(
+        res,
+        Err(Error::FromGeometry(
+            truck_geometry::errors::Error::NonMonotoneParameterMap(..)
+        ))
+    ));
+    // the curves are not changed on failure
+    assert!(curves.iter().all(|curve| !matches!(curve, Curve::ReparametrizedCurve(_))));
+}
@@ -0,0 +1,141 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+/// an arbitrary rigid motion
+fn motion() -> Matrix4 {
+    let axis = Vector3::new(1.0, -2.0, 0.5).normalize();
+    let translation = Matrix4::from_translation(Vector3::new(3.0, -1.0, 2.0));
+    translation * Matrix4::from_axis_angle(axis, Rad(2.0))
+}
+
+/// Asserts that the points of `face` are on the plane through `origin` with the normal `normal`,
+/// and that the outer normal of `face` is `normal`.
+fn assert_on_plane(face: &Face, origin: Point3, normal: Vector3) {
+    face.vertex_iter().for_each(|v| assert!((v.point() - origin).dot(normal).so_small()));
+    let surface = face.oriented_surface();
+    let point = face.vertex_iter().next().unwrap().point();
+    let (u, v) = surface.search_parameter(point, None, 100).unwrap();
+    assert_near!(surface.normal(u, v), normal);
+}
+
+#[test]
+fn top_of_moved_box() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let (cube, roles) = builder::tsweep_annotated(&f, Vector3::new(0.0, 0.0, 2.0));
+    assert_eq!(roles.len(), 6);
+    assert_eq!(roles.faces(SweepRole::Bottom).count(), 1);
+    assert_eq!(roles.faces(SweepRole::Top).count(), 1);
+    (0..4).for_each(|i| {
+        let role = SweepRole::Side { source_edge_index: i };
+        assert_eq!(roles.faces(role).count(), 1);
+    });
+
+    let mat = motion();
+    let moved = builder::transformed(&cube, mat);
+    assert!(roles.role(moved.boundaries()[0][0].id()).is_none());
+    let moved_roles = roles.transferred(&cube, &moved).unwrap();
+    let tops: Vec<&Face> = moved_roles.select(&moved, SweepRole::Top).collect();
+    assert_eq!(tops.len(), 1);
+    let origin = mat.transform_point(Point3::new(0.0, 0.0, 2.0));
+    assert_on_plane(tops[0], origin, mat.transform_vector(Vector3::unit_z()));
+    let bottom = moved_roles.select(&moved, SweepRole::Bottom).next().unwrap();
+    let origin = mat.transform_point(Point3::origin());
+    assert_on_plane(bottom, origin, -mat.transform_vector(Vector3::unit_z()));
+
+    // the roles of the other solid are not transferred
+    assert!(roles.transferred(&moved, &cube).is_none());
+}
+
+#[test]
+fn wall_of_moved_cylinder() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let disk: Face = builder::try_attach_plane(std::slice::from_ref(&circle)).unwrap();
+    let (cylinder, roles) = builder::tsweep_annotated(&disk, Vector3::unit_z());
+
+    let mat = motion();
+    let moved = builder::transformed(&cylinder, mat);
+    let moved_roles = roles.transferred(&cylinder, &moved).unwrap();
+    let walls: Vec<&Face> = (0..circle.len())
+        .flat_map(|i| {
+            let role = SweepRole::Side { source_edge_index: i };
+            moved_roles.select(&moved, role)
+        })
+        .collect();
+    assert_eq!(walls.len(), circle.len());
+    let origin = mat.transform_point(Point3::origin());
+    let axis = mat.transform_vector(Vector3::unit_z());
+    walls.iter().for_each(|face| {
+        let surface = face.oriented_surface();
+        // the middle points of the edges of the wall
+        face.edge_iter().for_each(|edge| {
+            let curve = edge.curve();
+            let (t0, t1) = curve.range_tuple();
+            let point = curve.subs((t0 + t1) / 2.0);
+            let radial = (point - origin) - axis * (point - origin).dot(axis);
+            assert_near!(radial.magnitude(), 1.0);
+            // the outer normal of the wall
+            let (u, v) = surface.search_parameter(point, None, 100).unwrap();
+            assert_near!(surface.normal(u, v), radial.normalize());
+        });
+    });
+    // the caps are not the walls
+    let top = moved_roles.select(&moved, SweepRole::Top).next().unwrap();
+    assert_on_plane(top, origin + axis, axis);
+}
+
+#[test]
+fn revolution_roles() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let (origin, axis) = (Point3::origin(), Vector3::unit_y());
+
+    for angle in [PI / 3.0, PI, 1.5 * PI] {
+        let (solid, roles) = builder::rsweep_annotated(&f, origin, axis, Rad(angle));
+        assert_eq!(roles.len(), solid.face_iter().count());
+        // the end face is on the rotated plane of the profile
+        let end = roles.select(&solid, RevolutionRole::End).next().unwrap();
+        let normal = Matrix3::from_axis_angle(axis, Rad(angle)) * Vector3::unit_z();
+        assert!(end.vertex_iter().all(|v| v.point().to_vec().dot(normal).so_small()));
+        let counts: Vec<usize> = (0..4)
+            .map(|i| roles.faces(RevolutionRole::Lateral { source_edge_index: i }).count())
+            .collect();
+        assert!(counts.iter().all(|count| *count == counts[0]));
+    }
+
+    let (ring, roles) = builder::rsweep_annotated(&f, origin, axis, Rad(7.0));
+    assert_eq!(roles.len(), ring.face_iter().count());
+    assert!(roles.face(RevolutionRole::Start).is_none());
+    assert!(roles.face(RevolutionRole::End).is_none());
+    // the inner wall, swept from the edge at `x = 1`
+    let role = RevolutionRole::Lateral { source_edge_index: 3 };
+    let walls = roles.select(&ring, role);
+    walls.flat_map(|face| face.vertex_iter()).for_each(|v| {
+        let p = v.point();
+        assert_near!(f64::hypot(p.x, p.z), 1.0);
+    });
+}
+
+#[test]
+fn cuboid_roles() {
+    let bbd = BoundingBox::from_iter([Point3::new(-1.0, -2.0, -3.0), Point3::new(1.0, 2.0, 3.0)]);
+    let (cuboid, roles) = primitive::cuboid_annotated(bbd);
+    let mat = motion();
+    let moved = builder::transformed(&cuboid, mat);
+    let roles = roles.transferred(&cuboid, &moved).unwrap();
+    let faces = [
+        (CuboidFace::Bottom, Point3::new(0.0, 0.0, -3.0), -Vector3::unit_z()),
+        (CuboidFace::Front, Point3::new(0.0, -2.0, 0.0), -Vector3::unit_y()),
+        (CuboidFace::Right, Point3::new(1.0, 0.0, 0.0), Vector3::unit_x()),
+        (CuboidFace::Back, Point3::new(0.0, 2.0, 0.0), Vector3::unit_y()),
+        (CuboidFace::Left, Point3::new(-1.0, 0.0, 0.0), -Vector3::unit_x()),
+        (CuboidFace::Top, Point3::new(0.0, 0.0, 3.0), Vector3::unit_z()),
+    ];
+    faces.into_iter().for_each(|(role, origin, normal)| {
+        let face = roles.select(&moved, role).next().unwrap();
+        assert_on_plane(face, mat.transform_point(origin), mat.transform_vector(normal));
+    });
+}
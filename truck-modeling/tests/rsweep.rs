@@ -0,0 +1,76 @@
+use std::collections::HashSet;
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+/// the square `[1, 2] x {0} x [0, 1]` apart from the z-axis
+fn square() -> Face {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    builder::tsweep(&e, Vector3::unit_z())
+}
+
+/// the numbers of faces, edges and vertices
+fn counts(solid: &Solid) -> (usize, usize, usize) {
+    let edges: HashSet<EdgeID> = solid.edge_iter().map(|edge| edge.id()).collect();
+    let vertices: HashSet<VertexID> = solid.vertex_iter().map(|v| v.id()).collect();
+    (solid.face_iter().count(), edges.len(), vertices.len())
+}
+
+fn assert_sweep(angle: f64, expected: (usize, usize, usize)) {
+    let solid: Solid = builder::rsweep(&square(), Point3::origin(), Vector3::unit_z(), Rad(angle));
+    assert_eq!(counts(&solid), expected, "angle: {angle}");
+    assert!(Solid::try_new(solid.boundaries().clone()).is_ok(), "angle: {angle}");
+    let consistent = |edge: Edge| edge.is_geometric_consistent();
+    assert!(solid.edge_iter().all(consistent), "angle: {angle}");
+}
+
+#[test]
+fn rsweep_by_half_turn() {
+    // the end points of the arcs are not ambiguous since the arcs are divided at the midpoint
+    for angle in [PI - 1.0e-13, PI, PI + 1.0e-13, -PI + 1.0e-13, -PI, -PI - 1.0e-13] {
+        assert_sweep(angle, (10, 20, 12));
+    }
+    let solid: Solid = builder::rsweep(&square(), Point3::origin(), Vector3::unit_z(), Rad(PI));
+    let points: Vec<Point3> = solid.vertex_iter().map(|v| v.point()).collect();
+    let midpoint = Point3::new(0.0, 1.0, 0.0);
+    assert!(points.iter().any(|p| p.near(&midpoint)));
+    let end_point = Point3::new(-1.0, 0.0, 0.0);
+    assert!(points.iter().any(|p| p.near(&end_point)));
+}
+
+#[test]
+fn rsweep_by_whole_turn() {
+    for angle in [2.0 * PI - 1.0e-13, 2.0 * PI, 2.0 * PI + 1.0e-13, 4.0 * PI] {
+        assert_sweep(angle, (12, 24, 12));
+        assert_sweep(-angle, (12, 24, 12));
+    }
+    // a little less than a whole turn is still open
+    assert_sweep(2.0 * PI - 1.0e-3, (14, 28, 16));
+}
+
+#[test]
+fn revolve_profiles() {
+    let boundary = square().boundaries()[0].clone();
+    let revolve = |profile: &Wire| -> Result<Solid> {
+        builder::revolve(profile, Point3::origin(), Vector3::unit_z(), Rad(PI))
+    };
+    let solid: Solid = revolve(&boundary).unwrap();
+    assert_eq!(counts(&solid), (10, 20, 12));
+
+    // the profile without the last edge is not closed
+    let mut profile = boundary.clone();
+    profile.pop_back();
+    let res = revolve(&profile);
+    match res {
+        Err(errors::Error::OpenWire { gap }) => assert_near!(gap, 1.0),
+        _ => panic!("the open profile must be rejected: {res:?}"),
+    }
+
+    // the gap is measured even if the ends are disconnected at the same point
+    let mut profile = boundary;
+    let last = profile.pop_back().unwrap();
+    let v = builder::vertex(last.back().point());
+    profile.push_back(builder::line(last.front(), &v));
+    let res = revolve(&profile);
+    assert_eq!(res.unwrap_err(), errors::Error::OpenWire { gap: 0.0 });
+}
@@ -0,0 +1,93 @@
+use std::collections::HashSet;
+use truck_modeling::*;
+
+/// a closed cylinder whose side face wraps around the seam `(1, 0, z)`
+fn seam_cylinder() -> Shell {
+    let v = builder::vertices([
+        (1.0, 0.0, 1.0),
+        (1.0, 0.0, 0.0),
+        (0.0, 1.0, 0.0),
+        (0.0, 1.0, 1.0),
+    ]);
+    let seam: Edge = builder::line(&v[0], &v[1]);
+    let transits = [Point3::new(0.6, 0.8, 0.0), Point3::new(-0.6, -0.8, 0.0)];
+    let bottom: [Edge; 2] = [
+        builder::circle_arc(&v[1], &v[2], transits[0]),
+        builder::circle_arc(&v[2], &v[1], transits[1]),
+    ];
+    let up = |p: Point3| p + Vector3::unit_z();
+    let top: [Edge; 2] = [
+        builder::circle_arc(&v[0], &v[3], up(transits[0])),
+        builder::circle_arc(&v[3], &v[0], up(transits[1])),
+    ];
+    let Curve::Line(line) = seam.curve() else {
+        unreachable!()
+    };
+    let generatrix = Curve::BSplineCurve(BSplineCurve::from(line));
+    let surface = RevolutedCurve::by_revolution(generatrix, Point3::origin(), Vector3::unit_z());
+    let wire: Wire = vec![
+        seam.clone(),
+        bottom[0].clone(),
+        bottom[1].clone(),
+        seam.inverse(),
+        top[1].inverse(),
+        top[0].inverse(),
+    ]
+    .into();
+    let side = Face::new_unchecked(vec![wire], Processor::new(surface).into());
+    let bottom_wire: Wire = vec![bottom[1].inverse(), bottom[0].inverse()].into();
+    let top_wire: Wire = top.into_iter().collect();
+    vec![
+        side,
+        builder::try_attach_plane(vec![bottom_wire]).unwrap(),
+        builder::try_attach_plane(vec![top_wire]).unwrap(),
+    ]
+    .into()
+}
+
+#[test]
+fn split_cylinder() {
+    let shell = seam_cylinder();
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    assert!(!shell[0].boundaries()[0].is_simple());
+
+    let split = shell.split_seams();
+    assert_eq!(split.len(), 4);
+    assert_eq!(split.shell_condition(), ShellCondition::Closed);
+    assert!(split.is_geometric_consistent());
+    assert!(split
+        .face_iter()
+        .all(|face| face.boundaries().iter().all(Wire::is_simple)));
+    let edges: HashSet<EdgeID> = split.edge_iter().map(|edge| edge.id()).collect();
+    assert_eq!(edges.len(), 8);
+
+    // the cutting edge is along the opposite meridian
+    let seam = shell[0].edge_iter().next().unwrap();
+    let cut = split[0]
+        .edge_iter()
+        .filter(|edge| edge.id() != seam.id())
+        .find(|edge| split[1].edge_iter().any(|e| e.id() == edge.id()))
+        .unwrap();
+    for t in [0.0, 0.3, 0.6, 1.0] {
+        let (t0, t1) = cut.curve().range_tuple();
+        let p = cut.curve().subs(t0 + (t1 - t0) * t);
+        assert_near!(Point2::new(p.x, p.y), Point2::new(-1.0, 0.0));
+    }
+
+    let solid = Solid::new(vec![split]);
+    assert!(solid.contains_point(Point3::new(0.5, 0.0, 0.5)));
+    assert!(solid.contains_point(Point3::new(-0.5, 0.1, 0.5)));
+    assert!(!solid.contains_point(Point3::new(-1.2, 0.0, 0.5)));
+    assert!(!solid.contains_point(Point3::new(0.0, 0.0, 1.5)));
+}
+
+#[test]
+fn split_without_seams() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let shell = &cube.boundaries()[0];
+    let split = shell.split_seams();
+    assert!(shell.face_iter().zip(&split).all(|(a, b)| a.id() == b.id()));
+}
@@ -0,0 +1,164 @@
+use std::collections::HashSet;
+use truck_modeling::{errors::Error, *};
+
+/// the NURBS surface whose weights are all zero, which is `NaN` everywhere
+fn degenerate_surface() -> Surface {
+    let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1));
+    let zero = Vector4::new(0.0, 0.0, 0.0, 0.0);
+    NurbsSurface::new(BSplineSurface::new(knot_vecs, vec![vec![zero; 2]; 2])).into()
+}
+
+/// the unit cube whose top face is put on the degenerate surface, and the top face
+fn degenerate_cube() -> (Solid, Face) {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let top = cube.boundaries()[0][5].clone();
+    assert!(top.vertex_iter().all(|v| v.point().z.near(&1.0)));
+    top.set_surface(degenerate_surface());
+    (cube, top)
+}
+
+/// Asserts that `err` is the failure of the search on the degenerate surface for `entity`, and
+/// returns the point of the failure.
+fn assert_search_failure(err: Error, entity: EntityId) -> Point3 {
+    let Error::ParameterSearchFailed {
+        entity: got,
+        point,
+        surface_kind,
+        ..
+    } = err
+    else {
+        panic!("{err:?}");
+    };
+    assert_eq!(got, entity);
+    assert_eq!(surface_kind, SurfaceKind::NurbsSurface);
+    point
+}
+
+#[test]
+fn face_on_surface() {
+    let (_, top) = degenerate_cube();
+    let wire = top.boundaries()[0].clone();
+    let res = builder::face_on_surface(degenerate_surface(), wire.clone(), Vec::new(), TOLERANCE);
+    let err = res.unwrap_err();
+    assert!(matches!(err, Error::ParameterSearchFailed { hint: None, .. }));
+    // the search fails at the front of the first edge
+    let point = assert_search_failure(err, EntityId::Edge(wire[0].id()));
+    assert_near!(point, wire[0].front().point());
+}
+
+#[test]
+fn trimmed_surface_patch() {
+    let (_, top) = degenerate_cube();
+    let err = top.trimmed_surface_patch(0.1, true).unwrap_err();
+    let point = assert_search_failure(err, EntityId::Face(top.id()));
+    assert_near!(point.z, 1.0);
+}
+
+#[test]
+fn convex_hull() {
+    let (cube, top) = degenerate_cube();
+    let err = cube.convex_hull(0.01).unwrap_err();
+    let point = assert_search_failure(err, EntityId::Face(top.id()));
+    assert_near!(point.z, 1.0);
+}
+
+#[test]
+fn section() {
+    let (cube, top) = degenerate_cube();
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 0.5),
+        Point3::new(1.0, 0.0, 0.5),
+        Point3::new(0.0, 1.0, 0.5),
+    );
+    let err = cube.section(&plane, 1.0e-3).unwrap_err();
+    assert_search_failure(err, EntityId::Face(top.id()));
+}
+
+#[test]
+fn silhouette_curves() {
+    let (cube, top) = degenerate_cube();
+    let err = cube.silhouette_curves(Vector3::new(1.0, 2.0, 3.0), 1.0e-3).unwrap_err();
+    assert_search_failure(err, EntityId::Face(top.id()));
+}
+
+#[test]
+fn proximity() {
+    let (cube, top) = degenerate_cube();
+    let bottom = &cube.boundaries()[0][0];
+    let err = bottom.proximity(&top, TOLERANCE).unwrap_err();
+    assert_search_failure(err, EntityId::Face(top.id()));
+}
+
+#[test]
+fn round_edges() {
+    let (cube, top) = degenerate_cube();
+    let err = builder::round_edges(&cube, 0.1, TOLERANCE).unwrap_err();
+    // the middle point of an edge of the top face
+    let point = assert_search_failure(err, EntityId::Face(top.id()));
+    assert_near!(point.z, 1.0);
+    assert!(point.x.near(&0.5) || point.y.near(&0.5));
+}
+
+#[test]
+fn recorded_in_reports() {
+    let (cube, top) = degenerate_cube();
+    let top_edges: HashSet<EdgeID> = top.edge_iter().map(|edge| edge.id()).collect();
+
+    let wireframe = cube.wireframe();
+    assert_eq!(wireframe.unresolved.iter().copied().collect::<HashSet<_>>(), top_edges);
+    assert_eq!(wireframe.feature_edges().count(), 12);
+
+    // one of the two closest points of each sample on the top edges
+    let report = cube.boundaries()[0].max_boundary_gap(2);
+    assert_eq!(report.samples, 12 * 2);
+    assert_eq!(report.fallbacks, 4 * 2);
+
+    let audit = top.orientation_audit();
+    assert!(audit.wires.iter().all(|wire| wire.uv_area.is_none()));
+    assert!(!audit.consistent);
+
+    let report = cube.validate(TOLERANCE);
+    assert!(report.issues.contains(&ValidationIssue::UnprojectableFace(top.id())));
+}
+
+#[test]
+fn skipped_by_queries() {
+    let (cube, _) = degenerate_cube();
+    // the ray passes through the bottom face only
+    let hits = cube.ray_hits(Point3::new(0.5, 0.5, -1.0), Vector3::unit_z(), TOLERANCE);
+    assert_eq!(hits.len(), 1);
+    assert_near!(hits[0].point, Point3::new(0.5, 0.5, 0.0));
+}
+
+#[test]
+fn interferes_with() {
+    let (cube, _) = degenerate_cube();
+    // the small cube above the top face, whose distance from the other faces is about 0.4
+    let v = builder::vertex(Point3::new(0.4, 0.4, 1.05));
+    let e = builder::tsweep(&v, Vector3::new(0.2, 0.0, 0.0));
+    let f = builder::tsweep(&e, Vector3::new(0.0, 0.2, 0.0));
+    let small: Solid = builder::tsweep(&f, Vector3::new(0.0, 0.0, 0.2));
+    let (shell, small) = (&cube.boundaries()[0], &small.boundaries()[0]);
+    assert!(shell.interferes_with(small, 0.1).is_none());
+    // the small cube beside the side face x = 1
+    let moved = builder::translated(small, Vector3::new(0.65, 0.0, -0.5));
+    assert!(shell.interferes_with(&moved, 0.1).is_some());
+}
+
+#[test]
+fn remove_interior_faces() {
+    // the cubes glued at the degenerate top face of the lower one
+    let (cube, _) = degenerate_cube();
+    let upper = builder::translated(&cube, Vector3::unit_z());
+    let faces: Shell = cube.face_iter().chain(upper.face_iter()).cloned().collect();
+    let union = Solid::new_unchecked(vec![faces]);
+    // the bottom face of the upper cube is removed, and the top face of the lower one is kept
+    let err = union.remove_interior_faces(TOLERANCE).unwrap_err();
+    let faces: Vec<&Face> = err.shells.iter().flat_map(Shell::face_iter).collect();
+    assert_eq!(faces.len(), 11);
+    let degenerate = faces.iter().filter(|face| face.surface().kind() == SurfaceKind::NurbsSurface);
+    assert_eq!(degenerate.count(), 2);
+}
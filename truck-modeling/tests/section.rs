@@ -0,0 +1,126 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+mod common;
+use common::*;
+
+const TOL: f64 = 1.0e-3;
+
+/// the plane through `origin` perpendicular to `normal`
+fn plane(origin: Point3, normal: Vector3) -> Plane {
+    let axis = match normal.x.abs() < 0.9 {
+        true => Vector3::unit_x(),
+        false => Vector3::unit_y(),
+    };
+    let u = normal.cross(axis).normalize();
+    let v = normal.cross(u).normalize();
+    Plane::new(origin, origin + u, origin + v)
+}
+
+fn length(wire: &Wire) -> f64 {
+    wire.edge_iter()
+        .map(|edge| {
+            let curve = edge.oriented_curve();
+            let (_, points) = curve.parameter_division(curve.range_tuple(), 1.0e-4);
+            points.windows(2).map(|p| p[0].distance(p[1])).sum::<f64>()
+        })
+        .sum()
+}
+
+#[test]
+fn cube_middle() {
+    let normal = Vector3::new(1.0, 1.0, 1.0).normalize();
+    let plane = plane(Point3::new(0.5, 0.5, 0.5), normal);
+    let wires = unit_cube().section(&plane, TOL).unwrap();
+    assert_eq!(wires.len(), 1);
+    assert!(wires[0].is_closed());
+    // the regular hexagon
+    assert_eq!(wires[0].len(), 6);
+    wires[0].vertex_iter().for_each(|v| {
+        assert!((v.point() - Point3::new(0.5, 0.5, 0.5)).dot(normal).abs() < TOL);
+    });
+    assert!((length(&wires[0]) - 3.0 * f64::sqrt(2.0)).abs() < 10.0 * TOL);
+}
+
+#[test]
+fn cube_through_face() {
+    let plane = plane(Point3::new(0.0, 0.0, 1.0), Vector3::unit_z());
+    let wires = unit_cube().section(&plane, TOL).unwrap();
+    assert_eq!(wires.len(), 1);
+    assert!(wires[0].is_closed());
+    // the edges of the top face, without their copies from the side faces
+    assert_eq!(wires[0].len(), 4);
+    wires[0].edge_iter().for_each(|edge| {
+        assert!(matches!(edge.curve(), Curve::Line(_)));
+        assert_near!(edge.front().point().z, 1.0);
+    });
+    assert!((length(&wires[0]) - 4.0).abs() < 10.0 * TOL);
+}
+
+#[test]
+fn cube_touching() {
+    // the plane touches the cube along the edge at `x = z = 1`
+    let normal = Vector3::new(1.0, 0.0, 1.0).normalize();
+    let wires = unit_cube().section(&plane(Point3::new(1.0, 0.0, 1.0), normal), TOL).unwrap();
+    assert_eq!(wires.len(), 1);
+    assert!(!wires[0].is_closed());
+    assert_eq!(wires[0].len(), 1);
+    assert!((length(&wires[0]) - 1.0).abs() < 10.0 * TOL);
+
+    // the plane touches the cube at the vertex at the origin
+    let normal = Vector3::new(1.0, 1.0, 1.0).normalize();
+    assert!(unit_cube().section(&plane(Point3::origin(), normal), TOL).unwrap().is_empty());
+
+    // the plane is apart from the cube
+    let plane = plane(Point3::new(0.0, 0.0, 2.0), Vector3::unit_z());
+    assert!(unit_cube().section(&plane, TOL).unwrap().is_empty());
+}
+
+#[test]
+fn cylinder() {
+    let p = [(0.0, 1.0, 0.0), (1.0, 1.0, 0.0), (1.0, 0.0, 0.0), (0.0, 0.0, 0.0)];
+    let v = builder::vertices(p);
+    let wire: Wire = (0..3).map(|i| builder::line(&v[i], &v[i + 1])).collect();
+    let cylinder = Solid::new(vec![builder::cone(&wire, Vector3::unit_y(), Rad(2.0 * PI))]);
+
+    let plane_y = plane(Point3::new(0.0, 0.5, 0.0), Vector3::unit_y());
+    let wires = cylinder.section(&plane_y, TOL).unwrap();
+    assert_eq!(wires.len(), 1);
+    assert!(wires[0].is_closed());
+    wires[0].edge_iter().for_each(|edge| {
+        let curve = edge.oriented_curve();
+        let (_, points) = curve.parameter_division(curve.range_tuple(), TOL);
+        points.iter().for_each(|p| {
+            assert!((p.y - 0.5).abs() < TOL, "{p:?}");
+            assert!((p.x * p.x + p.z * p.z - 1.0).abs() < 10.0 * TOL, "{p:?}");
+        });
+    });
+    assert!((length(&wires[0]) - 2.0 * PI).abs() < 10.0 * TOL);
+
+    // the section parallel to the axis is the rectangle of the width `sqrt(3)`
+    let plane_z = plane(Point3::new(0.0, 0.0, 0.5), Vector3::unit_z());
+    let wires = cylinder.section(&plane_z, TOL).unwrap();
+    assert_eq!(wires.len(), 1);
+    assert!(wires[0].is_closed());
+    let perimeter = 2.0 * f64::sqrt(3.0) + 2.0;
+    assert!((length(&wires[0]) - perimeter).abs() < 10.0 * TOL);
+}
+
+#[test]
+fn sphere() {
+    let v0 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let v1 = builder::vertex(Point3::new(0.0, -1.0, 0.0));
+    let arc = builder::circle_arc(&v0, &v1, Point3::new(1.0, 0.0, 0.0));
+    let wire: Wire = vec![arc].into();
+    let sphere = Solid::new(vec![builder::cone(&wire, Vector3::unit_y(), Rad(2.0 * PI))]);
+
+    let normal = Vector3::new(1.0, 1.0, 0.0).normalize();
+    let origin = Point3::from_vec(normal * 0.6);
+    let wires = sphere.section(&plane(origin, normal), TOL).unwrap();
+    assert_eq!(wires.len(), 1);
+    assert!(wires[0].is_closed());
+    wires[0].vertex_iter().for_each(|v| {
+        assert!((v.point().to_vec().dot(normal) - 0.6).abs() < TOL);
+        assert!((v.point().to_vec().magnitude() - 1.0).abs() < TOL);
+    });
+    assert!((length(&wires[0]) - 2.0 * PI * 0.8).abs() < 10.0 * TOL);
+}
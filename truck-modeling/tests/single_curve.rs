@@ -0,0 +1,66 @@
+use truck_modeling::{errors::Error, *};
+
+/// the distance from `point` to `curve`
+fn distance(curve: &BSplineCurve<Point3>, point: Point3) -> f64 {
+    let t = curve.search_nearest_parameter(point, None, 100).unwrap();
+    curve.subs(t).distance(point)
+}
+
+#[test]
+fn polyline_and_bezier() {
+    let v = builder::vertices([(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 1.0, 0.5), (4.0, 1.0, 0.0)]);
+    let inter_points = vec![Point3::new(2.5, 2.0, 0.5), Point3::new(3.5, -1.0, 0.0)];
+    let wire: Wire = vec![
+        builder::line(&v[0], &v[1]),
+        // inverted edge, traced from `v[1]` to `v[2]`
+        builder::line(&v[2], &v[1]).inverse(),
+        builder::bezier(&v[2], &v[3], inter_points),
+    ]
+    .into();
+
+    let tol = 1.0e-3;
+    let (curve, error) = wire.to_single_curve(tol).unwrap();
+    assert!(error <= tol);
+    assert_near!(curve.front(), v[0].point());
+    assert_near!(curve.back(), v[3].point());
+    // the corners are kept
+    assert!(distance(&curve, v[1].point()) < TOLERANCE);
+    assert!(distance(&curve, v[2].point()) < TOLERANCE);
+    for edge in wire.edge_iter() {
+        let oriented = edge.oriented_curve();
+        let (t0, t1) = oriented.range_tuple();
+        for i in 0..=10 {
+            let point = oriented.subs(t0 + (t1 - t0) * i as f64 / 10.0);
+            assert!(distance(&curve, point) <= tol, "{point:?}");
+        }
+    }
+    // the curve follows the orientation of the wire
+    let t = curve.search_nearest_parameter(v[1].point(), None, 100).unwrap();
+    let s = curve.search_nearest_parameter(v[2].point(), None, 100).unwrap();
+    assert!(t < s);
+}
+
+#[test]
+fn closed_wire() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let (curve, error) = circle.to_single_curve(1.0e-4).unwrap();
+    assert!(error <= 1.0e-4);
+    assert_near!(curve.front(), curve.back());
+    for i in 0..=100 {
+        let t = i as f64 / 100.0;
+        assert!(f64::abs(curve.subs(t).to_vec().magnitude() - 1.0) < 2.0e-4);
+    }
+}
+
+#[test]
+fn disconnected_wire() {
+    let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 0.5, 0.0), (2.0, 0.5, 0.0)]);
+    let wire: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[2], &v[3])].into();
+    let res = wire.to_single_curve(1.0e-3);
+    assert_eq!(res.unwrap_err(), Error::DisconnectedWire { gap: 0.5 });
+
+    let res = Wire::new().to_single_curve(1.0e-3);
+    let err = truck_topology::errors::Error::EmptyWire;
+    assert_eq!(res.unwrap_err(), Error::FromTopology(err));
+}
@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+use truck_modeling::{errors::Error, *};
+mod common;
+use common::*;
+
+/// the quantized points and the hashes of the curves and the surfaces
+fn geometric_fingerprint(solid: &Solid) -> Vec<u64> {
+    let points = solid.vertex_iter().flat_map(|v| {
+        let p = v.point();
+        [p.x, p.y, p.z].map(|x| f64::round(x / TOLERANCE) as i64 as u64)
+    });
+    let curves = solid.edge_iter().map(|e| e.curve().geometry_hash(TOLERANCE));
+    let surfaces = solid.face_iter().map(|f| f.surface().geometry_hash(TOLERANCE));
+    points.chain(curves).chain(surfaces).collect()
+}
+
+/// Moves the unique entities of the solid by `vector`.
+fn perturb(solid: &Solid, vector: Vector3) {
+    let mat = Matrix4::from_translation(vector);
+    let (mut vertices, mut edges) = (HashSet::new(), HashSet::new());
+    solid
+        .vertex_iter()
+        .filter(|v| vertices.insert(v.id()))
+        .for_each(|v| v.set_point(v.point() + vector));
+    solid
+        .edge_iter()
+        .filter(|e| edges.insert(e.id()))
+        .for_each(|e| e.set_curve(e.curve().transformed(mat)));
+    solid.face_iter().for_each(|f| f.set_surface(f.surface().transformed(mat)));
+}
+
+#[test]
+fn perturb_and_restore() {
+    let mut cube = unit_cube();
+    let fingerprint = geometric_fingerprint(&cube);
+    let snapshot = cube.geometry_snapshot();
+    assert_eq!(snapshot.len(), 8 + 12 + 6);
+    let revisions: Vec<usize> = cube.face_iter().map(Face::revision).collect();
+
+    perturb(&cube, Vector3::new(0.1, 0.2, 0.3));
+    assert_ne!(geometric_fingerprint(&cube), fingerprint);
+    assert!(cube.is_geometric_consistent());
+    assert_eq!(snapshot.diff(&cube.geometry_snapshot()).len(), 8 + 12 + 6);
+
+    cube.restore_geometry(&snapshot).unwrap();
+    assert_eq!(geometric_fingerprint(&cube), fingerprint);
+    assert!(snapshot.diff(&cube.geometry_snapshot()).is_empty());
+    let restored: Vec<usize> = cube.face_iter().map(Face::revision).collect();
+    assert!(revisions.iter().zip(&restored).all(|(r0, r1)| r0 < r1));
+    assert!(cube.is_geometric_consistent());
+}
+
+#[test]
+fn filtered() {
+    let mut cube = unit_cube();
+    let fingerprint = geometric_fingerprint(&cube);
+    let faces: Vec<Face> = cube.face_iter().take(2).cloned().collect();
+    let edge = cube.edge_iter().next().unwrap();
+    let ids = [
+        EntityId::Face(faces[0].id()),
+        EntityId::Edge(edge.id()),
+        EntityId::Face(faces[1].id()),
+    ];
+    let snapshot = cube.geometry_snapshot_of(&ids);
+    assert_eq!(snapshot.len(), 3);
+    // the vertices, the edges, and the faces in this order
+    assert_eq!(snapshot.ids().collect::<Vec<_>>(), vec![ids[1], ids[0], ids[2]]);
+
+    let mat = Matrix4::from_translation(Vector3::new(0.0, 0.0, 0.5));
+    faces.iter().for_each(|face| face.set_surface(face.surface().transformed(mat)));
+    edge.set_curve(edge.curve().transformed(mat));
+    let current = cube.geometry_snapshot_of(&ids);
+    assert_eq!(snapshot.diff(&current), vec![ids[1], ids[0], ids[2]]);
+
+    cube.restore_geometry(&snapshot).unwrap();
+    assert_eq!(geometric_fingerprint(&cube), fingerprint);
+
+    // the ids out of the solid are ignored
+    let other = builder::vertex(Point3::origin());
+    let snapshot = cube.geometry_snapshot_of(&[EntityId::Vertex(other.id())]);
+    assert!(snapshot.is_empty());
+}
+
+#[test]
+fn atomic_failure() {
+    let cube = unit_cube();
+    let snapshot = cube.geometry_snapshot();
+    let faces: Vec<Face> = cube.face_iter().cloned().collect();
+    // the solid without the first face
+    let mut open = Solid::new_unchecked(vec![faces[1..].iter().cloned().collect()]);
+
+    let mat = Matrix4::from_translation(Vector3::new(1.0, 0.0, 0.0));
+    faces[1].set_surface(faces[1].surface().transformed(mat));
+    let fingerprint = geometric_fingerprint(&open);
+    let res = open.restore_geometry(&snapshot);
+    assert_eq!(res, Err(Error::EntityNotFound(EntityId::Face(faces[0].id()))));
+    // nothing is written back
+    assert_eq!(geometric_fingerprint(&open), fingerprint);
+
+    let snapshot = open.geometry_snapshot_of(&[EntityId::Face(faces[1].id())]);
+    assert!(cube.clone().restore_geometry(&snapshot).is_ok());
+}
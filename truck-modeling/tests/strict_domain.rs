@@ -0,0 +1,58 @@
+use std::{f64::consts::PI, ops::Bound};
+use truck_modeling::*;
+
+fn bspline_surface() -> Surface {
+    let knot_vec = KnotVec::bezier_knot(1);
+    let ctrl_pts = vec![
+        vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+        vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0)],
+    ];
+    BSplineSurface::new((knot_vec.clone(), knot_vec), ctrl_pts).into()
+}
+
+#[test]
+fn checked_evaluation() {
+    let checked = CheckedParametricSurface(bspline_surface());
+    assert_near!(checked.try_subs(1.0, 1.0).unwrap(), Point3::new(1.0, 1.0, 1.0));
+    let unit = (Bound::Included(0.0), Bound::Included(1.0));
+    let error = checked.try_subs(0.5, 1.25).unwrap_err();
+    let expected = DomainError::Surface {
+        u: 0.5,
+        v: 1.25,
+        range: (unit, unit),
+    };
+    assert_eq!(error, expected);
+    assert!(error.to_string().contains("(0.5, 1.25)"));
+
+    let v = builder::vertices([(0.0, 0.0, 0.0), (2.0, 0.0, 0.0)]);
+    let line: Edge = builder::line(&v[0], &v[1]);
+    let checked = CheckedParametricCurve(line.curve());
+    assert_near!(checked.try_subs(0.25).unwrap(), Point3::new(0.5, 0.0, 0.0));
+    let error = checked.try_der(1.5).unwrap_err();
+    assert_eq!(error, DomainError::Curve { t: 1.5, range: unit });
+
+    // periodic parameters are wrapped
+    let line = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 1.0)],
+    );
+    let generatrix = Curve::BSplineCurve(line);
+    let cylinder = RevolutedCurve::by_revolution(generatrix, Point3::origin(), Vector3::unit_z());
+    let cylinder = Surface::RevolutedCurve(Processor::new(cylinder));
+    let checked = CheckedParametricSurface(cylinder.clone());
+    let wrapped = checked.try_subs(0.5, 2.0 * PI + 1.0).unwrap();
+    assert_near!(wrapped, cylinder.subs(0.5, 1.0));
+    assert!(checked.try_subs(-0.5, 1.0).is_err());
+    assert!(checked.try_uder(f64::NAN, 1.0).is_err());
+}
+
+#[test]
+#[should_panic(expected = "strict domain check: The parameter (0.5, -0.75) is out of the range")]
+fn strict_surface_evaluation() {
+    let surface = bspline_surface();
+    strict_domain_checks(true);
+    // the algorithms evaluating the extensions are exempted
+    let extended = with_extrapolation(|| surface.subs(0.5, -0.75));
+    assert_near!(extended, Point3::new(0.5, -0.75, -0.375));
+    surface.subs(0.5, -0.75);
+}
@@ -0,0 +1,91 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+fn curves() -> Vec<Curve> {
+    let line = Line(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 2.0, 3.0));
+    let bsp = BSplineCurve::new(
+        KnotVec::from(vec![0.0, 0.0, 0.0, 0.3, 1.2, 2.0, 2.0, 2.0]),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 2.0, 0.0),
+            Point3::new(2.0, -1.0, 1.0),
+            Point3::new(3.0, 1.0, 0.0),
+            Point3::new(4.0, 0.0, -1.0),
+        ],
+    );
+    let v0 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let v1 = builder::vertex(Point3::new(-1.0, 0.0, 0.0));
+    let arc = builder::circle_arc(&v0, &v1, Point3::new(0.0, 1.0, 0.0)).curve();
+    let trimmed = TrimmedCurve::new(Box::new(Curve::BSplineCurve(bsp.clone())), (0.2, 1.7));
+    let plane = |p: Point3, q: Point3| Surface::Plane(Plane::new(Point3::origin(), p, q));
+    let plane0 = plane(Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0));
+    let plane1 = plane(Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 0.0, 1.0));
+    let leader = Curve::Line(Line(Point3::new(-1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)));
+    let intersection = IntersectionCurve::new(
+        Box::new(plane0),
+        Box::new(plane1),
+        Box::new(leader),
+    );
+    vec![
+        Curve::Line(line),
+        Curve::BSplineCurve(bsp),
+        arc,
+        Curve::TrimmedCurve(trimmed),
+        Curve::IntersectionCurve(intersection),
+    ]
+}
+
+fn ratio(range: (f64, f64), s: f64) -> f64 { range.0 * (1.0 - s) + range.1 * s }
+
+#[test]
+fn subcurves_of_variants() {
+    for curve in curves() {
+        let range = curve.range_tuple();
+        let sub_range = (ratio(range, 0.2), ratio(range, 0.65));
+        let sub = curve.subcurve(sub_range);
+        assert_eq!(sub.range_tuple(), sub_range, "{curve:?}");
+        for i in 0..=20 {
+            let t = ratio(sub_range, i as f64 / 20.0);
+            assert_near!(sub.subs(t), curve.subs(t));
+        }
+    }
+}
+
+#[test]
+fn reversed_subcurves() {
+    for curve in curves() {
+        let range = curve.range_tuple();
+        let (t0, t1) = (ratio(range, 0.65), ratio(range, 0.2));
+        let sub = curve.subcurve((t0, t1));
+        let inverse = curve.inverse();
+        let sub_range = sub.range_tuple();
+        assert_near!(sub_range.1 - sub_range.0, t0 - t1);
+        assert_near!(sub.front(), curve.subs(t0));
+        assert_near!(sub.back(), curve.subs(t1));
+        for i in 0..=20 {
+            let t = ratio(sub_range, i as f64 / 20.0);
+            assert_near!(sub.subs(t), inverse.subs(t));
+        }
+    }
+}
+
+#[test]
+fn exact_arc_subcurve() {
+    let arc = &curves()[2];
+    let (t0, t1) = arc.range_tuple();
+    let sub = arc.subcurve((ratio((t0, t1), 0.1), ratio((t0, t1), 0.9)));
+    assert!(matches!(sub, Curve::NurbsCurve(_)));
+    let (s0, s1) = sub.range_tuple();
+    for i in 0..=20 {
+        let p = sub.subs(ratio((s0, s1), i as f64 / 20.0));
+        assert_near!(p.to_vec().magnitude(), 1.0);
+        assert!(p.y > 0.0 && f64::atan2(p.y, p.x) < PI);
+    }
+}
+
+#[test]
+#[should_panic(expected = "out of the range")]
+fn subcurve_out_of_domain() {
+    let line = Curve::Line(Line(Point3::origin(), Point3::new(1.0, 0.0, 0.0)));
+    line.subcurve((0.5, 1.5));
+}
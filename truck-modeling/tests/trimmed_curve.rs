@@ -0,0 +1,55 @@
+use truck_modeling::*;
+
+fn trimmed_curve() -> Curve {
+    let bsp = BSplineCurve::new(
+        KnotVec::uniform_knot(2, 3),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 2.0, 0.0),
+            Point3::new(2.0, -1.0, 0.0),
+            Point3::new(3.0, 1.0, 0.0),
+            Point3::new(4.0, 0.0, 0.0),
+        ],
+    );
+    TrimmedCurve::new(Box::new(Curve::BSplineCurve(bsp)), (0.2, 0.7)).into()
+}
+
+#[test]
+fn trimmed_curve_variant() {
+    let curve = trimmed_curve();
+    let Curve::TrimmedCurve(trimmed) = &curve else {
+        unreachable!()
+    };
+    assert_eq!(curve.range_tuple(), (0.2, 0.7));
+    assert_eq!(curve.subs(0.5), trimmed.curve().subs(0.5));
+
+    // the lifted curve is restricted to the trimming range
+    let nurbs = NurbsCurve::new(curve.lift_up());
+    assert_eq!(nurbs.range_tuple(), (0.2, 0.7));
+    for i in 0..=10 {
+        let t = 0.2 + 0.05 * i as f64;
+        assert_near!(nurbs.subs(t), curve.subs(t));
+    }
+
+    let inverse = curve.inverse();
+    assert_near!(inverse.front(), curve.back());
+    assert_near!(inverse.back(), curve.front());
+    let mat = Matrix4::from_translation(Vector3::unit_z());
+    assert_near!(curve.transformed(mat).front(), curve.front() + Vector3::unit_z());
+}
+
+#[test]
+fn sweep_trimmed_curve() {
+    let curve = trimmed_curve();
+    let v = builder::vertices([curve.front(), curve.back()]);
+    let edge = Edge::new(&v[0], &v[1], curve);
+    assert!(edge.is_geometric_consistent());
+    let face: Face = builder::tsweep(&edge, Vector3::unit_z());
+    assert!(face.is_geometric_consistent());
+    let plane = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    assert!(Surface::Plane(plane).include(&edge.curve()));
+}
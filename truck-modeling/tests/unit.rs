@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+use truck_modeling::*;
+
+fn cube(unit: LengthUnit) -> UnitizedSolid {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    UnitizedSolid::new(builder::tsweep(&f, Vector3::unit_z()), unit)
+}
+
+#[test]
+fn convert_cube_to_millimeter() {
+    let cube = cube(LengthUnit::Meter);
+    let converted = cube.convert_to(LengthUnit::Millimeter);
+    let shell = &converted.solid.boundaries()[0];
+    shell.edge_iter().for_each(|edge| {
+        let length = edge.front().point().distance(edge.back().point());
+        assert!(f64::abs(length / 1000.0 - 1.0) < 1.0e-9, "{length}");
+    });
+
+    // the shared vertices are scaled exactly once, and kept shared
+    let vertices: HashSet<_> = shell.vertex_iter().map(|v| v.id()).collect();
+    assert_eq!(vertices.len(), 8);
+    assert!(converted.degenerate_edges().is_empty());
+
+    assert_ne!(converted.fingerprint(), cube.fingerprint());
+    let back = converted.convert_to(LengthUnit::Meter);
+    assert_eq!(back.fingerprint(), cube.fingerprint());
+    assert_near!(back.tolerance, cube.tolerance);
+    // the original solid is untouched
+    let far = cube.solid.boundaries()[0]
+        .vertex_iter()
+        .any(|v| v.point() == Point3::new(1.0, 1.0, 1.0));
+    assert!(far);
+}
+
+#[test]
+fn sliver_edge_in_larger_unit() {
+    // a prism, in millimeters, whose bottom has an edge of 1.0e-4 millimeters
+    let v = builder::vertices([
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (10.0 - 1.0e-4, 10.0, 0.0),
+    ]);
+    let wire: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    let face = builder::try_attach_plane(vec![wire]).unwrap();
+    let sliver = face.boundaries()[0][2].id();
+    let prism = builder::tsweep(&face, Vector3::new(0.0, 0.0, 10.0));
+    let prism = UnitizedSolid::new(prism, LengthUnit::Millimeter);
+    assert!(prism.degenerate_edges().is_empty());
+
+    // 1.0e-7 meters is less than `TOLERANCE`
+    let converted = prism.convert_to(LengthUnit::Meter);
+    assert_eq!(converted.tolerance, TOLERANCE);
+    let degenerate = converted.degenerate_edges();
+    assert_eq!(degenerate.len(), 2);
+    let ids: HashSet<_> = converted.solid.edge_iter().map(|edge| edge.id()).collect();
+    assert!(degenerate.iter().all(|id| ids.contains(id)));
+    assert!(!ids.contains(&sliver));
+}
@@ -0,0 +1,94 @@
+use truck_modeling::*;
+mod common;
+use common::*;
+
+#[test]
+fn valid_solids() {
+    let report = unit_cube().validate(1.0e-6);
+    assert!(report.is_valid, "{report:?}");
+    assert!(report.issues.is_empty());
+
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let disk: Face = builder::try_attach_plane(&[circle]).unwrap();
+    let cylinder: Solid = builder::tsweep(&disk, Vector3::unit_z());
+    let report = cylinder.validate(1.0e-4);
+    assert!(report.is_valid, "{report:?}");
+}
+
+#[test]
+fn open_and_inverted_faces() {
+    let shell = unit_cube_shell();
+
+    let mut open = shell.clone();
+    let removed = open.pop().unwrap();
+    let report = open.validate(1.0e-6);
+    assert!(!report.is_valid);
+    let open_edges: Vec<EdgeID> = report
+        .issues
+        .iter()
+        .filter_map(|issue| match issue {
+            ValidationIssue::OpenEdge(id) => Some(*id),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(open_edges.len(), 4);
+    assert!(removed.edge_iter().all(|edge| open_edges.contains(&edge.id())));
+
+    let mut inverted = shell;
+    inverted[0].invert();
+    let report = inverted.validate(1.0e-6);
+    let count = |f: fn(&ValidationIssue) -> bool| report.issues.iter().filter(|i| f(i)).count();
+    assert_eq!(count(|issue| matches!(issue, ValidationIssue::InconsistentEdge(_))), 4);
+    // the inverted face is consistent in itself
+    assert_eq!(count(|issue| matches!(issue, ValidationIssue::InvertedBoundary { .. })), 0);
+}
+
+#[test]
+fn self_intersecting_solid() {
+    let shell0 = unit_cube_shell();
+    let shell1 = builder::translated(&shell0, Vector3::new(0.5, 0.5, 0.5));
+    let solid = Solid::new(vec![shell0, shell1]);
+    let report = solid.validate(1.0e-6);
+    assert!(!report.is_valid);
+    assert!(report.issues.iter().all(|issue| matches!(
+        issue,
+        ValidationIssue::SelfIntersection { distance, .. } if *distance <= 1.0e-6,
+    )));
+    assert!(!report.issues.is_empty());
+}
+
+#[test]
+fn gapped_face_and_collapsed_faces() {
+    let solid = unit_cube();
+    // lift the top face without moving its edges
+    let top = &solid.boundaries()[0][5];
+    let surface = top.surface();
+    top.set_surface(surface.transformed(Matrix4::from_translation(Vector3::new(0.0, 0.0, 0.1))));
+    let report = solid.validate(1.0e-6);
+    let gaps: Vec<f64> = report
+        .issues
+        .iter()
+        .filter_map(|issue| match issue {
+            ValidationIssue::FaceGap { gap, .. } => Some(*gap),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(gaps.len(), 4);
+    gaps.iter().for_each(|gap| assert_near!(*gap, 0.1));
+
+    // the top face and the bottom face are collapsed into points
+    let scalars = Vector3::new(1.0e-8, 1.0e-8, 1.0);
+    let needle: Solid = builder::scaled(&unit_cube(), Point3::origin(), scalars);
+    let collapsed: Vec<FaceID> = needle
+        .validate(1.0e-6)
+        .issues
+        .iter()
+        .filter_map(|issue| match issue {
+            ValidationIssue::DegenerateFace { face_id, size } if *size < 1.0e-6 => Some(*face_id),
+            _ => None,
+        })
+        .collect();
+    let faces = &needle.boundaries()[0];
+    assert_eq!(collapsed, vec![faces[0].id(), faces[5].id()]);
+}
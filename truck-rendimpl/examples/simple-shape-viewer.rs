@@ -132,6 +132,9 @@ impl MyApp {
                     }
                     Curve::NurbsCurve(curve) => curve.roughly_bounding_box(),
                     Curve::IntersectionCurve(_) => BoundingBox::new(),
+                    curve @ (Curve::TrimmedCurve(_) | Curve::ReparametrizedCurve(_)) => {
+                        NurbsCurve::new(curve.lift_up()).roughly_bounding_box()
+                    }
                 };
             });
         let (size, center) = (bdd_box.size(), bdd_box.center());
@@ -145,6 +145,14 @@ where
         hint: H,
         trials: usize
     );
+    derive_method!(
+        search_parameter_with_presearch<H: Into<D::Hint>>,
+        Option<D::Parameter>,
+        point: T::Point,
+        hint: H,
+        trials: usize,
+        division: usize
+    );
 }
 
 impl<D: SPDimension, T, U> SearchNearestParameter<D> for Alternative<T, U>
@@ -160,6 +168,14 @@ where
         hint: H,
         trials: usize
     );
+    derive_method!(
+        search_nearest_parameter_with_presearch<H: Into<D::Hint>>,
+        Option<D::Parameter>,
+        point: T::Point,
+        hint: H,
+        trials: usize,
+        division: usize
+    );
 }
 
 impl<T, U> Invertible for Alternative<T, U>
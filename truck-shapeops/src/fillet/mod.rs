@@ -447,7 +447,7 @@ fn cut_face_by_bezier(
 ) -> Option<(Face, Edge)> {
     let (front_edge, back_edge) = find_adjacent_edge(face, filleted_edge_id)?;
 
-    let new_front_edge = {
+    let (new_front_edge, front) = {
         let curve = front_edge.curve();
         let hint = algo::curve::presearch_closest_point(
             &bezier,
@@ -457,8 +457,7 @@ fn cut_face_by_bezier(
         );
         let (t0, t1) = search_closest_parameter(&bezier, &curve, hint, 100)?;
         let v0 = Vertex::new(bezier.subs(t0));
-        bezier = bezier.cut(t0);
-        front_edge.not_strictly_cut_with_parameter(&v0, t1)?.0
+        (front_edge.not_strictly_cut_with_parameter(&v0, t1)?.0, t0)
     };
 
     let new_back_edge = {
@@ -466,12 +465,12 @@ fn cut_face_by_bezier(
         let hint = algo::curve::presearch_closest_point(
             &bezier,
             &curve,
-            (bezier.range_tuple(), curve.range_tuple()),
+            ((front, bezier.range_tuple().1), curve.range_tuple()),
             10,
         );
         let (t0, t1) = search_closest_parameter(&bezier, &curve, hint, 100)?;
         let v1 = Vertex::new(bezier.subs(t0));
-        bezier.cut(t0);
+        bezier = bezier.subcurve((front, t0));
         back_edge.not_strictly_cut_with_parameter(&v1, t1)?.1
     };
 
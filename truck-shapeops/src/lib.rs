@@ -16,6 +16,6 @@
 mod healing;
 pub use healing::{RobustSplitClosedEdgesAndFaces, SplitClosedEdgesAndFaces};
 mod transversal;
-pub use transversal::{and, or, ShapeOpsCurve, ShapeOpsSurface};
+pub use transversal::{and, intersect, or, subtract, ShapeOpsCurve, ShapeOpsSurface};
 mod alternative;
 mod fillet;
@@ -72,11 +72,14 @@ fn altshell_to_shell<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     )
 }
 
-fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+/// Divides the faces of two shells by their intersection curves and classifies the fragments.
+/// Returns `[[and0, or0], [and1, or1]]`, where `and0` is the fragments of `shell0` inside
+/// `shell1`, `or0` is the ones outside `shell1`, and so on.
+fn classify_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     shell0: &Shell<Point3, C, S>,
     shell1: &Shell<Point3, C, S>,
     tol: f64,
-) -> Option<[Shell<Point3, C, S>; 2]> {
+) -> Option<[[AltCurveShell<C, S>; 2]; 2]> {
     nonpositive_tolerance!(tol);
     let poly_shell0 = shell0.triangulation(tol);
     let poly_shell1 = shell1.triangulation(tol);
@@ -123,58 +126,111 @@ fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
         }
         Some(())
     })?;
-    and0.append(&mut and1);
-    or0.append(&mut or1);
-    Some([
-        altshell_to_shell(&and0, tol)?,
-        altshell_to_shell(&or0, tol)?,
-    ])
+    Some([[and0, or0], [and1, or1]])
 }
 
-/// AND operation between two solids.
-pub fn and<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+/// The boolean operations of solids.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BoolOp {
+    Union,
+    Subtract,
+    Intersect,
+}
+
+fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    shell0: &Shell<Point3, C, S>,
+    shell1: &Shell<Point3, C, S>,
+    op: BoolOp,
+    tol: f64,
+) -> Option<Shell<Point3, C, S>> {
+    let [[mut and0, mut or0], [mut and1, mut or1]] =
+        classify_pair_of_shells(shell0, shell1, tol)?;
+    let res = match op {
+        BoolOp::Union => {
+            or0.append(&mut or1);
+            or0
+        }
+        BoolOp::Subtract => {
+            and1.face_iter_mut().for_each(|face| {
+                face.invert();
+            });
+            or0.append(&mut and1);
+            or0
+        }
+        BoolOp::Intersect => {
+            and0.append(&mut and1);
+            and0
+        }
+    };
+    altshell_to_shell(&res, tol)
+}
+
+fn boolean<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     solid0: &Solid<Point3, C, S>,
     solid1: &Solid<Point3, C, S>,
+    op: BoolOp,
     tol: f64,
 ) -> Option<Solid<Point3, C, S>> {
     let mut iter0 = solid0.boundaries().iter();
     let mut iter1 = solid1.boundaries().iter();
     let shell0 = iter0.next().unwrap();
     let shell1 = iter1.next().unwrap();
-    let [mut and_shell, _] = process_one_pair_of_shells(shell0, shell1, tol)?;
-    for shell in iter0 {
-        let [res, _] = process_one_pair_of_shells(&and_shell, shell, tol)?;
-        and_shell = res;
+    // The region of a solid is the intersection of the regions of its boundary shells, so the
+    // cavities of the subtracted solid would make the union of the complements.
+    if op == BoolOp::Subtract && iter1.len() > 0 {
+        return None;
     }
-    for shell in iter1 {
-        let [res, _] = process_one_pair_of_shells(&and_shell, shell, tol)?;
-        and_shell = res;
+    let mut res_shell = process_one_pair_of_shells(shell0, shell1, op, tol)?;
+    let rest_op = match op {
+        BoolOp::Subtract => BoolOp::Intersect,
+        _ => op,
+    };
+    for shell in iter0.chain(iter1) {
+        res_shell = process_one_pair_of_shells(&res_shell, shell, rest_op, tol)?;
     }
-    let boundaries = and_shell.connected_components();
+    let boundaries = res_shell.connected_components();
     Some(Solid::new(boundaries))
 }
 
+/// AND operation between two solids.
+pub fn and<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>> {
+    boolean(solid0, solid1, BoolOp::Intersect, tol)
+}
+
 /// OR operation between two solids.
 pub fn or<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     solid0: &Solid<Point3, C, S>,
     solid1: &Solid<Point3, C, S>,
     tol: f64,
 ) -> Option<Solid<Point3, C, S>> {
-    let mut iter0 = solid0.boundaries().iter();
-    let mut iter1 = solid1.boundaries().iter();
-    let shell0 = iter0.next().unwrap();
-    let shell1 = iter1.next().unwrap();
-    let [_, mut or_shell] = process_one_pair_of_shells(shell0, shell1, tol)?;
-    for shell in iter0 {
-        let [_, res] = process_one_pair_of_shells(&or_shell, shell, tol)?;
-        or_shell = res;
-    }
-    for shell in iter1 {
-        let [_, res] = process_one_pair_of_shells(&or_shell, shell, tol)?;
-        or_shell = res;
-    }
-    let boundaries = or_shell.connected_components();
-    Some(Solid::new(boundaries))
+    boolean(solid0, solid1, BoolOp::Union, tol)
+}
+
+/// Difference of two solids, i.e. the part of `solid0` outside `solid1`.
+///
+/// The faces are divided by the same way as [`and`] and [`or`], and the result consists of the
+/// fragments of `solid0` outside `solid1` and the inverted fragments of `solid1` inside `solid0`.
+/// The input is restricted as [`and`] and [`or`], and in addition, `solid1` must consist of one
+/// boundary shell. Returns `None` if `solid1` has cavities or the faces cannot be divided.
+pub fn subtract<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>> {
+    boolean(solid0, solid1, BoolOp::Subtract, tol)
+}
+
+/// Intersection of two solids, the same as [`and`].
+pub fn intersect<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>> {
+    boolean(solid0, solid1, BoolOp::Intersect, tol)
 }
 
 #[cfg(test)]
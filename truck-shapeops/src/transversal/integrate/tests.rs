@@ -19,3 +19,45 @@ fn punched_cube() {
     let file = std::fs::File::create("punched-cube.obj").unwrap();
     obj::write(&poly, file).unwrap();
 }
+
+#[test]
+fn subtract_cylinder() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+
+    let v = builder::vertex(Point3::new(0.5, 0.25, -0.5));
+    let w = builder::rsweep(&v, Point3::new(0.5, 0.5, 0.0), Vector3::unit_z(), Rad(7.0));
+    let f = builder::try_attach_plane(&[w]).unwrap();
+    let cylinder = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+    let sub = crate::subtract(&cube, &cylinder, 0.05).unwrap();
+
+    let mut not_cylinder = cylinder.clone();
+    not_cylinder.not();
+    let and = crate::and(&cube, &not_cylinder, 0.05).unwrap();
+    assert_eq!(sub.boundaries().len(), 1);
+    assert_eq!(sub.face_iter().count(), and.face_iter().count());
+    assert_eq!(sub.boundaries()[0].shell_condition(), ShellCondition::Closed);
+
+    // the cylinder minus the cube is the two disks out of the cube
+    let rest = crate::subtract(&cylinder, &cube, 0.05).unwrap();
+    assert_eq!(rest.boundaries().len(), 2);
+}
+
+#[test]
+fn overlapping_boxes_volumes() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube0: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let cube1 = builder::translated(&cube0, Vector3::new(0.5, 0.5, 0.5));
+    let volume = |solid: &Solid| solid.triangulation(0.01).to_polygon().volume();
+
+    let union = crate::or(&cube0, &cube1, 0.05).unwrap();
+    assert_near!(volume(&union), 1.875);
+    let difference = crate::subtract(&cube0, &cube1, 0.05).unwrap();
+    assert_near!(volume(&difference), 0.875);
+    let intersection = crate::intersect(&cube0, &cube1, 0.05).unwrap();
+    assert_near!(volume(&intersection), 0.125);
+}
@@ -126,6 +126,15 @@ where
     ) -> Option<f64> {
         self.ic.search_parameter(point, hint, trials)
     }
+    fn search_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<f64> {
+        self.ic.search_parameter_with_presearch(point, hint, trials, division)
+    }
 }
 
 impl<S0, S1> SearchNearestParameter<D1> for IntersectionCurveWithParameters<S0, S1>
@@ -142,6 +151,15 @@ where
     ) -> Option<f64> {
         self.ic.search_nearest_parameter(point, hint, trials)
     }
+    fn search_nearest_parameter_with_presearch<H: Into<SPHint1D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+        division: usize,
+    ) -> Option<f64> {
+        self.ic.search_nearest_parameter_with_presearch(point, hint, trials, division)
+    }
 }
 
 type IntersectionTuple<S0, S1> = (
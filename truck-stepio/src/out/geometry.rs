@@ -434,6 +434,47 @@ impl<C: StepCurve, S> StepCurve for PCurve<C, S> {
     fn same_sense(&self) -> bool { self.curve().same_sense() }
 }
 
+/// The trimmed curve is output as the non-trimmed curve, since the edge is bounded by the vertices.
+impl<C: DisplayByStep> DisplayByStep for TrimmedCurve<C> {
+    #[inline(always)]
+    fn fmt(&self, idx: usize, f: &mut Formatter<'_>) -> Result { self.curve().fmt(idx, f) }
+}
+
+impl<C: StepLength> StepLength for TrimmedCurve<C> {
+    #[inline(always)]
+    fn step_length(&self) -> usize { self.curve().step_length() }
+}
+
+impl<C: ConstStepLength> ConstStepLength for TrimmedCurve<C> {
+    const LENGTH: usize = C::LENGTH;
+}
+
+impl<C: StepCurve> StepCurve for TrimmedCurve<C> {
+    #[inline(always)]
+    fn same_sense(&self) -> bool { self.curve().same_sense() }
+}
+
+/// The reparametrized curve is output as the original curve, since the edge is bounded by the
+/// vertices.
+impl<C: DisplayByStep> DisplayByStep for ReparametrizedCurve<C> {
+    #[inline(always)]
+    fn fmt(&self, idx: usize, f: &mut Formatter<'_>) -> Result { self.curve().fmt(idx, f) }
+}
+
+impl<C: StepLength> StepLength for ReparametrizedCurve<C> {
+    #[inline(always)]
+    fn step_length(&self) -> usize { self.curve().step_length() }
+}
+
+impl<C: ConstStepLength> ConstStepLength for ReparametrizedCurve<C> {
+    const LENGTH: usize = C::LENGTH;
+}
+
+impl<C: StepCurve> StepCurve for ReparametrizedCurve<C> {
+    #[inline(always)]
+    fn same_sense(&self) -> bool { self.curve().same_sense() }
+}
+
 impl DisplayByStep for ModelingCurve {
     fn fmt(&self, idx: usize, f: &mut Formatter<'_>) -> Result {
         match self {
@@ -441,6 +482,8 @@ impl DisplayByStep for ModelingCurve {
             ModelingCurve::BSplineCurve(x) => DisplayByStep::fmt(x, idx, f),
             ModelingCurve::NurbsCurve(x) => DisplayByStep::fmt(x, idx, f),
             ModelingCurve::IntersectionCurve(x) => DisplayByStep::fmt(x, idx, f),
+            ModelingCurve::TrimmedCurve(x) => DisplayByStep::fmt(x, idx, f),
+            ModelingCurve::ReparametrizedCurve(x) => DisplayByStep::fmt(x, idx, f),
         }
     }
 }
@@ -452,6 +495,8 @@ impl StepLength for ModelingCurve {
             ModelingCurve::BSplineCurve(x) => x.step_length(),
             ModelingCurve::NurbsCurve(x) => x.step_length(),
             ModelingCurve::IntersectionCurve(x) => x.step_length(),
+            ModelingCurve::TrimmedCurve(x) => x.step_length(),
+            ModelingCurve::ReparametrizedCurve(x) => x.step_length(),
         }
     }
 }
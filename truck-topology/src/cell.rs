@@ -0,0 +1,166 @@
+use crate::{uv_loop::UvLoop, *};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use truck_base::{
+    bounding_box::BoundingBox,
+    cgmath64::{Point3, Vector3},
+};
+
+/// The number of the samples for estimating the size of the geometry.
+pub(crate) const BOUNDING_BOX_SAMPLES: usize = 8;
+const BOUNDING_BOX_RELATIVE_TOLERANCE: f64 = 1.0e-2;
+
+/// The clock of the revisions shared by all cells. Since the revisions are taken from one clock,
+/// a revision renewed later is always larger than all revisions taken before.
+static REVISION_CLOCK: AtomicUsize = AtomicUsize::new(0);
+
+#[inline(always)]
+fn next_revision() -> usize { REVISION_CLOCK.fetch_add(1, Ordering::Relaxed) + 1 }
+
+/// The clock of the serial numbers of the vertices, the edges and the faces.
+static SERIAL_CLOCK: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the serial number which has never been returned in this process.
+#[inline(always)]
+pub(crate) fn next_serial() -> usize { SERIAL_CLOCK.fetch_add(1, Ordering::Relaxed) }
+
+/// The shared cell of the curve of an edge or the surface of a face. The cell holds the revision
+/// of the geometry, renewed by [`GeometryCell::set`], the cache of the bounding box, and the cache
+/// of the boundaries in the parameter space, used only by the surfaces.
+#[derive(Debug)]
+pub(crate) struct GeometryCell<T> {
+    geometry: Mutex<T>,
+    serial: usize,
+    revision: AtomicUsize,
+    bounding_box: BoundingBoxCache<usize>,
+    uv_boundary: UvBoundaryCache,
+}
+
+impl<T> GeometryCell<T> {
+    #[inline(always)]
+    pub(crate) fn new(geometry: T) -> Self {
+        Self {
+            geometry: Mutex::new(geometry),
+            serial: next_serial(),
+            revision: AtomicUsize::new(next_revision()),
+            bounding_box: BoundingBoxCache::new(),
+            uv_boundary: UvBoundaryCache::new(),
+        }
+    }
+
+    /// Replaces the geometry and renews the revision.
+    #[inline(always)]
+    pub(crate) fn set(&self, geometry: T) {
+        let mut guard = self.geometry.lock();
+        *guard = geometry;
+        self.revision.store(next_revision(), Ordering::Release);
+    }
+
+    /// Returns the serial number of the cell, which is fixed in the lifetime of the cell.
+    #[inline(always)]
+    pub(crate) fn serial(&self) -> usize { self.serial }
+
+    #[inline(always)]
+    pub(crate) fn revision(&self) -> usize { self.revision.load(Ordering::Acquire) }
+
+    /// Returns the clone of the geometry and its revision, locking the geometry only while cloning.
+    #[inline(always)]
+    pub(crate) fn snapshot(&self) -> (usize, T)
+    where T: Clone {
+        let guard = self.geometry.lock();
+        (self.revision(), guard.clone())
+    }
+
+    #[inline(always)]
+    pub(crate) fn bounding_box(&self) -> &BoundingBoxCache<usize> { &self.bounding_box }
+
+    #[inline(always)]
+    pub(crate) fn uv_boundary(&self) -> &UvBoundaryCache { &self.uv_boundary }
+}
+
+impl<T> std::ops::Deref for GeometryCell<T> {
+    type Target = Mutex<T>;
+    #[inline(always)]
+    fn deref(&self) -> &Mutex<T> { &self.geometry }
+}
+
+/// The cache of a bounding box with the key by which the box was computed.
+#[derive(Debug)]
+pub(crate) struct BoundingBoxCache<K>(Mutex<Option<(K, BoundingBox<Point3>)>>);
+
+impl<K> BoundingBoxCache<K> {
+    #[inline(always)]
+    pub(crate) const fn new() -> Self { Self(Mutex::new(None)) }
+}
+
+impl<K: PartialEq> BoundingBoxCache<K> {
+    /// Returns the cached box if it was computed by `key`.
+    #[inline(always)]
+    pub(crate) fn get(&self, key: &K) -> Option<BoundingBox<Point3>> {
+        match &*self.0.lock() {
+            Some((cached_key, bdb)) if cached_key == key => Some(*bdb),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn store(&self, key: K, bdb: BoundingBox<Point3>) {
+        *self.0.lock() = Some((key, bdb));
+    }
+}
+
+impl BoundingBoxCache<usize> {
+    /// Stores the box unless a box computed by a newer revision has been stored in the meantime.
+    #[inline(always)]
+    pub(crate) fn store_newer(&self, revision: usize, bdb: BoundingBox<Point3>) {
+        let mut guard = self.0.lock();
+        if guard.as_ref().is_none_or(|(cached, _)| *cached < revision) {
+            *guard = Some((revision, bdb));
+        }
+    }
+}
+
+/// The key of [`UvBoundaryCache`]: the stamp of the geometry, the bits of the tolerance, and the
+/// hash of the boundary edges with their orientations.
+pub(crate) type UvBoundaryKey = (usize, u64, u64);
+
+/// The cache of the boundaries of a face in the parameter space of the surface.
+#[derive(Debug)]
+pub(crate) struct UvBoundaryCache(Mutex<Option<(UvBoundaryKey, Vec<UvLoop>)>>);
+
+impl UvBoundaryCache {
+    #[inline(always)]
+    pub(crate) const fn new() -> Self { Self(Mutex::new(None)) }
+
+    /// Returns the clone of the cached loops if they were computed by `key`.
+    #[inline(always)]
+    pub(crate) fn get(&self, key: &UvBoundaryKey) -> Option<Vec<UvLoop>> {
+        match &*self.0.lock() {
+            Some((cached_key, loops)) if cached_key == key => Some(loops.clone()),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn store(&self, key: UvBoundaryKey, loops: Vec<UvLoop>) {
+        *self.0.lock() = Some((key, loops));
+    }
+}
+
+/// Returns the tolerance of the parameter division for the geometry of the size of `bdb`.
+#[inline(always)]
+pub(crate) fn division_tolerance(bdb: BoundingBox<Point3>) -> f64 {
+    match bdb.is_empty() {
+        true => TOLERANCE,
+        false => f64::max(bdb.diameter() * BOUNDING_BOX_RELATIVE_TOLERANCE, TOLERANCE),
+    }
+}
+
+/// Expands `bdb` by the margin for the geometry between the division points with `tol`.
+#[inline(always)]
+pub(crate) fn with_margin(bdb: BoundingBox<Point3>, tol: f64) -> BoundingBox<Point3> {
+    if bdb.is_empty() {
+        return bdb;
+    }
+    let margin = Vector3::new(tol, tol, tol) * 2.0;
+    BoundingBox::from_iter([bdb.min() - margin, bdb.max() + margin])
+}
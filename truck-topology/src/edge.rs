@@ -42,7 +42,7 @@ impl<P, C> Edge<P, C> {
         Edge {
             vertices: (front.clone(), back.clone()),
             orientation: true,
-            curve: Arc::new(Mutex::new(curve)),
+            curve: Arc::new(GeometryCell::new(curve)),
         }
     }
 
@@ -275,7 +275,7 @@ impl<P, C> Edge<P, C> {
     /// assert_eq!(edge1.curve(), 1);
     /// ```
     #[inline(always)]
-    pub fn set_curve(&self, curve: C) { *self.curve.lock() = curve; }
+    pub fn set_curve(&self, curve: C) { self.curve.set(curve) }
 
     /// Returns the id that does not depend on the direction of the edge.
     /// # Examples
@@ -288,7 +288,40 @@ impl<P, C> Edge<P, C> {
     /// assert_eq!(edge0.id(), edge1.id());
     /// ```
     #[inline(always)]
-    pub fn id(&self) -> EdgeID<C> { ID::new(Arc::as_ptr(&self.curve)) }
+    pub fn id(&self) -> EdgeID<C> { ID::new(&**self.curve) }
+
+    /// Returns the serial number that does not depend on the direction of the edge, the integer
+    /// handle for associating the edge with external data, cf. [`Vertex::serial`].
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[(), ()]);
+    /// let edge0 = Edge::new(&v[0], &v[1], ());
+    /// let edge1 = Edge::new(&v[0], &v[1], ());
+    /// assert_eq!(edge0.serial(), edge0.inverse().serial());
+    /// assert_ne!(edge0.serial(), edge1.serial());
+    /// ```
+    #[inline(always)]
+    pub fn serial(&self) -> usize { self.curve.serial() }
+
+    /// Returns the revision of the curve, renewed each time the curve is set by
+    /// [`Edge::set_curve`].
+    /// A revision renewed later is larger than all revisions of the curves and the surfaces taken
+    /// before.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[(), ()]);
+    /// let edge0 = Edge::new(&v[0], &v[1], 0);
+    /// let edge1 = Edge::new(&v[0], &v[1], 0);
+    /// let revision = edge0.revision();
+    /// assert!(edge1.revision() > revision);
+    ///
+    /// edge0.inverse().set_curve(1);
+    /// assert!(edge0.revision() > edge1.revision());
+    /// ```
+    #[inline(always)]
+    pub fn revision(&self) -> usize { self.curve.revision() }
 
     /// Returns how many same edges.
     ///
@@ -402,19 +435,51 @@ impl<P, C> Edge<P, C> {
         geom_front.near(&*top_front) && geom_back.near(&*top_back)
     }
 
+    /// Returns whether the geometries of `self` and `other` coincide within `spec`.
+    ///
+    /// The ends of the oriented edges must be near, and the division points of each curve and the
+    /// middle points between them must be on the other curve. The curves may be parametrized
+    /// differently, and the default spec is the absolute `TOLERANCE`. cf. [`ToleranceSpec::near`]
+    pub fn geometric_eq(&self, other: &Self, spec: ToleranceSpec) -> bool
+    where
+        P: Copy + Tolerance + ToleranceScale,
+        C: Clone
+            + ParameterDivision1D<Point = P>
+            + BoundedCurve<Point = P>
+            + SearchNearestParameter<D1, Point = P>, {
+        let ends = |edge: &Self| (edge.front().point(), edge.back().point());
+        let ((p0, p1), (q0, q1)) = (ends(self), ends(other));
+        if !spec.near(&p0, &q0) || !spec.near(&p1, &q1) {
+            return false;
+        }
+        let scale = [p0, p1, q0, q1].iter().fold(0.0, |max, p| f64::max(max, p.tolerance_scale()));
+        let tol = f64::max(spec.threshold(scale), TOLERANCE);
+        let on_curve = |curve0: &C, curve1: &C| {
+            let (params, _) = curve0.parameter_division(curve0.range_tuple(), tol);
+            let middles = params.windows(2).map(|t| (t[0] + t[1]) / 2.0);
+            params.iter().copied().chain(middles).all(|t| {
+                let p = curve0.subs(t);
+                let s = curve1.search_nearest_parameter(p, None, SEARCH_PARAMETER_TRIALS);
+                s.is_some_and(|s| spec.near(&p, &curve1.subs(s)))
+            })
+        };
+        let (curve0, curve1) = (self.curve(), other.curve());
+        on_curve(&curve0, &curve1) && on_curve(&curve1, &curve0)
+    }
+
     #[inline(always)]
-    fn pre_cut(&self, vertex: &Vertex<P>, mut curve0: C, t: f64) -> (Self, Self)
+    pub(super) fn pre_cut(&self, vertex: &Vertex<P>, mut curve0: C, t: f64) -> (Self, Self)
     where C: Cut<Point = P> {
         let curve1 = curve0.cut(t);
         let edge0 = Edge {
             vertices: (self.absolute_front().clone(), vertex.clone()),
             orientation: self.orientation,
-            curve: Arc::new(Mutex::new(curve0)),
+            curve: Arc::new(GeometryCell::new(curve0)),
         };
         let edge1 = Edge {
             vertices: (vertex.clone(), self.absolute_back().clone()),
             orientation: self.orientation,
-            curve: Arc::new(Mutex::new(curve1)),
+            curve: Arc::new(GeometryCell::new(curve1)),
         };
         match self.orientation {
             true => (edge0, edge1),
@@ -539,6 +604,77 @@ pub enum ConcatError<P: Debug> {
     FromGeometry(truck_geotrait::ConcatError<P>),
 }
 
+impl<P, C> Edge<P, C>
+where C: BoundedCurve<Point = Point3> + ParameterDivision1D<Point = Point3>
+{
+    /// Returns the bounding box including the curve.
+    ///
+    /// The box is cached in the edge and the clones, and computed again only if the curve has been
+    /// set after the last computation. The curve is locked only while it is cloned.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// use truck_geotrait::*;
+    /// use truck_base::cgmath64::*;
+    /// use std::ops::Bound;
+    ///
+    /// #[derive(Clone, Debug)]
+    /// struct Segment(Point3, Point3);
+    /// impl ParametricCurve for Segment {
+    ///     type Point = Point3;
+    ///     type Vector = Vector3;
+    ///     fn subs(&self, t: f64) -> Point3 { self.0 + (self.1 - self.0) * t }
+    ///     fn der(&self, _: f64) -> Vector3 { self.1 - self.0 }
+    ///     fn der2(&self, _: f64) -> Vector3 { Vector3::zero() }
+    ///     fn der_n(&self, n: usize, t: f64) -> Vector3 {
+    ///         match n {
+    ///             0 => self.subs(t).to_vec(),
+    ///             1 => self.der(t),
+    ///             _ => Vector3::zero(),
+    ///         }
+    ///     }
+    ///     fn parameter_range(&self) -> ParameterRange {
+    ///         (Bound::Included(0.0), Bound::Included(1.0))
+    ///     }
+    /// }
+    /// impl BoundedCurve for Segment {}
+    /// impl ParameterDivision1D for Segment {
+    ///     type Point = Point3;
+    ///     fn parameter_division(&self, _: (f64, f64), _: f64) -> (Vec<f64>, Vec<Point3>) {
+    ///         (vec![0.0, 1.0], vec![self.0, self.1])
+    ///     }
+    /// }
+    ///
+    /// let p = [Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 2.0, 3.0)];
+    /// let v = Vertex::news(p);
+    /// let edge = Edge::new(&v[0], &v[1], Segment(p[0], p[1]));
+    /// let bdb = edge.cached_bounding_box();
+    /// assert!(bdb.contains(p[0]) && bdb.contains(p[1]));
+    /// assert!(bdb.diameter() < p[0].distance(p[1]) + 0.5);
+    /// ```
+    pub fn cached_bounding_box(&self) -> BoundingBox<Point3> {
+        if let Some(bdb) = self.curve.bounding_box().get(&self.curve.revision()) {
+            return bdb;
+        }
+        let (revision, curve) = self.curve.snapshot();
+        let bdb = curve_bounding_box(&curve);
+        self.curve.bounding_box().store_newer(revision, bdb);
+        bdb
+    }
+}
+
+/// Returns the bounding box including `curve`.
+fn curve_bounding_box<C>(curve: &C) -> BoundingBox<Point3>
+where C: BoundedCurve<Point = Point3> + ParameterDivision1D<Point = Point3> {
+    let (t0, t1) = curve.range_tuple();
+    let samples: BoundingBox<Point3> = (0..=BOUNDING_BOX_SAMPLES)
+        .map(|i| curve.subs(t0 + (t1 - t0) * i as f64 / BOUNDING_BOX_SAMPLES as f64))
+        .collect();
+    let tol = division_tolerance(samples);
+    let bdb: BoundingBox<Point3> = curve.sample_iter((t0, t1), tol).map(|(_, pt)| pt).collect();
+    with_margin(bdb + samples, tol)
+}
+
 impl<P: Debug> From<truck_geotrait::ConcatError<P>> for ConcatError<P> {
     fn from(err: truck_geotrait::ConcatError<P>) -> ConcatError<P> {
         ConcatError::FromGeometry(err)
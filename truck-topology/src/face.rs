@@ -1,4 +1,9 @@
-use crate::{errors::Error, wire::EdgeIter, *};
+use crate::{
+    errors::Error,
+    uv_loop::{UvBoundary, UvLoop},
+    wire::EdgeIter,
+    *,
+};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 impl<P, C, S> Face<P, C, S> {
@@ -61,7 +66,7 @@ impl<P, C, S> Face<P, C, S> {
         Face {
             boundaries,
             orientation: true,
-            surface: Arc::new(Mutex::new(surface)),
+            surface: Arc::new(GeometryCell::new(surface)),
         }
     }
 
@@ -215,7 +220,7 @@ impl<P, C, S> Face<P, C, S> {
     fn renew_pointer(&mut self)
     where S: Clone {
         let surface = self.surface();
-        self.surface = Arc::new(Mutex::new(surface));
+        self.surface = Arc::new(GeometryCell::new(surface));
     }
 
     /// Returns an iterator over the edges.
@@ -534,7 +539,7 @@ impl<P, C, S> Face<P, C, S> {
     /// assert_eq!(face1.surface(), 1);
     /// ```
     #[inline(always)]
-    pub fn set_surface(&self, surface: S) { *self.surface.lock() = surface; }
+    pub fn set_surface(&self, surface: S) { self.surface.set(surface) }
 
     /// Inverts the direction of the face.
     /// # Examples
@@ -607,7 +612,41 @@ impl<P, C, S> Face<P, C, S> {
     /// assert_ne!(face0.id(), face2.id());
     /// ```
     #[inline(always)]
-    pub fn id(&self) -> FaceID<S> { ID::new(Arc::as_ptr(&self.surface)) }
+    pub fn id(&self) -> FaceID<S> { ID::new(&**self.surface) }
+
+    /// Returns the serial number that does not depend on the direction of the face, the integer
+    /// handle for associating the face with external data, cf. [`Vertex::serial`].
+    ///
+    /// Like [`Face::id`], the serial number is renewed by [`Face::try_add_boundary`].
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[(); 3]);
+    /// let wire = Wire::from(vec![
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[0], ()),
+    /// ]);
+    /// let face0 = Face::new(vec![wire.clone()], ());
+    /// let face1 = Face::new(vec![wire], ());
+    /// assert_eq!(face0.serial(), face0.inverse().serial());
+    /// assert_ne!(face0.serial(), face1.serial());
+    /// ```
+    #[inline(always)]
+    pub fn serial(&self) -> usize { self.surface.serial() }
+
+    /// Returns the revision of the surface, renewed each time the surface is set by
+    /// [`Face::set_surface`]. cf. [`Edge::revision`]
+    #[inline(always)]
+    pub fn revision(&self) -> usize { self.surface.revision() }
+
+    /// Returns the newest revision among the surface and the curves of the boundaries.
+    #[inline(always)]
+    pub(crate) fn geometry_stamp(&self) -> usize {
+        let edge_stamp = |stamp: usize, edge: &Edge<P, C>| usize::max(stamp, edge.revision());
+        let surface_stamp = self.surface.revision();
+        self.boundaries.iter().flatten().fold(surface_stamp, edge_stamp)
+    }
 
     /// Returns how many same faces.
     ///
@@ -876,7 +915,7 @@ impl<P, C, S> Face<P, C, S> {
         let mut face0 = Face {
             boundaries: self.boundaries.clone(),
             orientation: self.orientation,
-            surface: Arc::new(Mutex::new(self.surface())),
+            surface: Arc::new(GeometryCell::new(self.surface())),
         };
         let boundary = &mut face0.boundaries[0];
         let i = boundary
@@ -899,7 +938,7 @@ impl<P, C, S> Face<P, C, S> {
         let face1 = Face {
             boundaries: vec![new_wire],
             orientation: self.orientation,
-            surface: Arc::new(Mutex::new(self.surface())),
+            surface: Arc::new(GeometryCell::new(self.surface())),
         };
         Some((face0, face1))
     }
@@ -1000,7 +1039,7 @@ impl<P, C, S> Face<P, C, S> {
         Some(Face {
             boundaries,
             orientation: self.orientation(),
-            surface: Arc::new(Mutex::new(surface)),
+            surface: Arc::new(GeometryCell::new(surface)),
         })
     }
 
@@ -1081,6 +1120,160 @@ impl<P, C, S: Clone + Invertible> Face<P, C, S> {
     }
 }
 
+impl<P, C, S: ParametricSurface3D> Face<P, C, S> {
+    /// Returns the normal vector of the face at the parameter `(u, v)` of the surface.
+    /// If face is inverted, then the returned normal is also inverted.
+    ///
+    /// The parameter `(u, v)` is always the one of the original surface, i.e. of `self.surface()`,
+    /// not of `self.oriented_surface()`.
+    #[inline(always)]
+    pub fn normal_at(&self, u: f64, v: f64) -> S::Vector {
+        let normal = self.surface.lock().normal(u, v);
+        match self.orientation {
+            true => normal,
+            false => -normal,
+        }
+    }
+}
+
+impl<P, C, S> Face<P, C, S>
+where
+    P: Copy,
+    C: BoundedCurve<Point = P> + ParameterDivision1D<Point = P>,
+    S: ParameterDivision2D + SearchParameter<D2, Point = P>,
+{
+    /// Returns the division of the parameter domain of the surface refined near the boundaries.
+    /// This is the same as `self.parameter_division_with_crossings(tol, 4)`.
+    #[inline(always)]
+    pub fn parameter_division(&self, tol: f64) -> (Vec<f64>, Vec<f64>) {
+        self.parameter_division_with_crossings(tol, 4)
+    }
+
+    /// Returns the division of the parameter domain of the surface refined near the boundaries.
+    ///
+    /// The division starts from the division of the surface on the bounding box of the boundaries
+    /// on the parameter domain, and additional division lines are inserted as follows:
+    /// - each boundary loop is crossed by at least `crossings` division lines in each direction,
+    /// - each cell through which the boundary passes is split until its sides are not longer than
+    ///   the boundary segment passing through it.
+    ///
+    /// Both returned divisions are strictly increasing.
+    /// Boundary points that cannot be projected onto the surface are ignored,
+    /// and if no boundary point is projected, returns empty vectors.
+    pub fn parameter_division_with_crossings(
+        &self,
+        tol: f64,
+        crossings: usize,
+    ) -> (Vec<f64>, Vec<f64>) {
+        const MAX_REFINEMENT: usize = 32;
+        let loops = self.parameter_boundaries(tol);
+        let Some(((u0, u1), (v0, v1))) = parameter_range(loops.iter().flatten()) else {
+            return (Vec::new(), Vec::new());
+        };
+        let surface = self.surface.lock();
+        let (mut udiv, mut vdiv) = surface.parameter_division(((u0, u1), (v0, v1)), tol);
+        drop(surface);
+        loops.iter().for_each(|uv_loop| {
+            let Some(((u0, u1), (v0, v1))) = parameter_range(uv_loop) else {
+                return;
+            };
+            insert_crossings(&mut udiv, (u0, u1), crossings);
+            insert_crossings(&mut vdiv, (v0, v1), crossings);
+        });
+        for _ in 0..MAX_REFINEMENT {
+            let mut refined = false;
+            loops.iter().for_each(|uv_loop| {
+                let iter = uv_loop.iter().zip(uv_loop.iter().cycle().skip(1));
+                iter.for_each(|(&(u0, v0), &(u1, v1))| {
+                    let len = f64::hypot(u1 - u0, v1 - v0);
+                    if len < TOLERANCE {
+                        return;
+                    }
+                    refined |= split_cell(&mut udiv, (u0 + u1) / 2.0, len);
+                    refined |= split_cell(&mut vdiv, (v0 + v1) / 2.0, len);
+                });
+            });
+            if !refined {
+                break;
+            }
+        }
+        (udiv, vdiv)
+    }
+
+    /// Returns the boundaries on the parameter domain of the surface, divided by `tol`.
+    /// The loops are based on the absolute boundaries, and their last points are not the same as the first points.
+    fn parameter_boundaries(&self, tol: f64) -> Vec<Vec<(f64, f64)>> {
+        let surface = self.surface.lock();
+        self.boundaries
+            .iter()
+            .map(|wire| {
+                let mut hint = None;
+                wire.edge_iter()
+                    .flat_map(|edge| {
+                        let curve = edge.curve.lock();
+                        let (_, mut pts) = curve.parameter_division(curve.range_tuple(), tol);
+                        if !edge.orientation() {
+                            pts.reverse();
+                        }
+                        pts.pop();
+                        pts
+                    })
+                    .filter_map(|pt| {
+                        let trials = SEARCH_PARAMETER_TRIALS;
+                        let uv = surface
+                            .search_parameter(pt, hint, trials)
+                            .or_else(|| surface.search_parameter(pt, None, trials))?;
+                        hint = Some(uv);
+                        Some(uv)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+fn parameter_range<'a>(
+    uvs: impl IntoIterator<Item = &'a (f64, f64)>,
+) -> Option<((f64, f64), (f64, f64))> {
+    uvs.into_iter().fold(None, |range, &(u, v)| match range {
+        None => Some(((u, u), (v, v))),
+        Some(((u0, u1), (v0, v1))) => Some(((u0.min(u), u1.max(u)), (v0.min(v), v1.max(v)))),
+    })
+}
+
+/// Inserts `t` into the sorted division `div` if `t` is not near to the existing parameters.
+fn insert_division(div: &mut Vec<f64>, t: f64) -> bool {
+    let idx = div.partition_point(|x| *x < t);
+    let near = |i: usize| div.get(i).is_some_and(|x| f64::abs(x - t) < TOLERANCE);
+    match near(idx) || (idx > 0 && near(idx - 1)) {
+        true => false,
+        false => {
+            div.insert(idx, t);
+            true
+        }
+    }
+}
+
+fn insert_crossings(div: &mut Vec<f64>, (t0, t1): (f64, f64), crossings: usize) {
+    let count = div.iter().filter(|t| t0 < **t && **t < t1).count();
+    if count < crossings {
+        (1..=crossings).for_each(|i| {
+            let t = t0 + (t1 - t0) * i as f64 / (crossings + 1) as f64;
+            insert_division(div, t);
+        });
+    }
+}
+
+/// Splits the cell including `t` if the cell is longer than `len`.
+fn split_cell(div: &mut Vec<f64>, t: f64, len: f64) -> bool {
+    let idx = div.partition_point(|x| *x <= t);
+    if idx == 0 || idx == div.len() {
+        return false;
+    }
+    let (t0, t1) = (div[idx - 1], div[idx]);
+    t1 - t0 > len && insert_division(div, (t0 + t1) / 2.0)
+}
+
 impl<P, C, S> Face<P, C, S>
 where
     P: Tolerance,
@@ -1101,6 +1294,171 @@ where
     }
 }
 
+impl<P, C, S> Face<P, C, S>
+where
+    C: BoundedCurve<Point = Point3> + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface<Point = Point3>
+        + ParameterDivision2D
+        + SearchParameter<D2, Point = Point3>,
+{
+    /// Returns the bounding box including the face.
+    ///
+    /// The box consists of the cached boxes of the boundary edges, [`Edge::cached_bounding_box`],
+    /// and the points of the surface on the parameter domain surrounding the boundaries, so it may
+    /// be larger than the face itself. The box is cached in the face and the clones, and computed
+    /// again only if the surface or a curve of the boundaries has been set after the last
+    /// computation. The surface is locked only while it is cloned.
+    pub fn cached_bounding_box(&self) -> BoundingBox<Point3> {
+        if let Some(bdb) = self.surface.bounding_box().get(&self.geometry_stamp()) {
+            return bdb;
+        }
+        let (revision, surface) = self.surface.snapshot();
+        let stamp = self
+            .boundaries
+            .iter()
+            .flatten()
+            .fold(revision, |stamp, edge| usize::max(stamp, edge.revision()));
+        let mut bdb = self.boundaries.iter().flatten().fold(
+            BoundingBox::new(),
+            |mut bdb, edge| {
+                bdb += edge.cached_bounding_box();
+                bdb
+            },
+        );
+        let range = self.boundary_parameter_range(&surface).or_else(|| {
+            match surface.try_range_tuple() {
+                (Some(urange), Some(vrange)) => Some((urange, vrange)),
+                _ => None,
+            }
+        });
+        if let Some(range) = range {
+            let tol = division_tolerance(bdb);
+            let (us, vs) = surface.parameter_division(range, tol);
+            let pts: BoundingBox<Point3> = us
+                .iter()
+                .flat_map(|u| vs.iter().map(|v| surface.subs(*u, *v)))
+                .collect();
+            bdb += with_margin(pts, tol);
+        }
+        self.surface.bounding_box().store_newer(stamp, bdb);
+        bdb
+    }
+
+    /// Returns the range of the parameters of `surface` on the samples of the boundaries,
+    /// `None` if a sample cannot be projected to the surface.
+    fn boundary_parameter_range(&self, surface: &S) -> Option<((f64, f64), (f64, f64))> {
+        let mut uv_box = BoundingBox::<Point2>::new();
+        for edge in self.boundaries.iter().flatten() {
+            let curve = edge.curve();
+            let (t0, t1) = curve.range_tuple();
+            for i in 0..=BOUNDING_BOX_SAMPLES {
+                let t = t0 + (t1 - t0) * i as f64 / BOUNDING_BOX_SAMPLES as f64;
+                let pt = curve.subs(t);
+                let (u, v) = surface.search_parameter(pt, None, SEARCH_PARAMETER_TRIALS)?;
+                uv_box.push(Point2::new(u, v));
+            }
+        }
+        match uv_box.is_empty() {
+            true => None,
+            false => {
+                let (min, max) = (uv_box.min(), uv_box.max());
+                Some(((min.x, max.x), (min.y, max.y)))
+            }
+        }
+    }
+}
+
+impl<P, C, S> Face<P, C, S>
+where
+    C: BoundedCurve<Point = Point3> + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface<Point = Point3>
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    /// Returns the absolute boundaries in the parameter space of the surface, as the loops through
+    /// the parameters of the division points of the edges with the tolerance `tol`. Returns `None`
+    /// if a point cannot be projected to the surface.
+    ///
+    /// The parameters are continued from the previous points, and the loops are divided into the
+    /// branches at the seams of the periodic surfaces. cf. [`UvLoop::new`]
+    ///
+    /// The loops are cached in the face and the clones, and computed again only if the surface, a
+    /// curve of the boundaries, the boundaries themselves, or `tol` have been changed.
+    pub fn uv_boundary(&self, tol: f64) -> Option<Vec<UvLoop>> {
+        let key = self.uv_boundary_key(tol);
+        if let Some(loops) = self.surface.uv_boundary().get(&key) {
+            return Some(loops);
+        }
+        let (edges, periods, origin) = self.uv_edge_samples(tol)?;
+        let loops: Vec<UvLoop> = edges
+            .into_iter()
+            .map(|edges| UvLoop::new(edges.into_iter().flatten(), periods, origin))
+            .collect();
+        self.surface.uv_boundary().store(key, loops.clone());
+        Some(loops)
+    }
+
+    /// Returns the absolute boundaries in the parameter space of the surface, whose segments are
+    /// tagged by the indices of the wires and the edges in the wires, for the scanline queries.
+    /// Returns `None` if a point cannot be projected to the surface.
+    /// cf. [`Face::uv_boundary`], [`UvBoundary::scanline_crossings`]
+    pub fn uv_scanline_boundary(&self, tol: f64) -> Option<UvBoundary> {
+        let (edges, periods, origin) = self.uv_edge_samples(tol)?;
+        Some(UvBoundary::new(edges, periods, origin))
+    }
+
+    /// Returns the parameters of the division points of the edges excluding the back vertices,
+    /// the periods, and the origin of the fundamental domain.
+    fn uv_edge_samples(&self, tol: f64) -> Option<UvEdgeSamples> {
+        let (_, surface) = self.surface.snapshot();
+        let periods = (surface.u_period(), surface.v_period());
+        let (urange, vrange) = surface.try_range_tuple();
+        let origin = Point2::new(urange.map_or(0.0, |r| r.0), vrange.map_or(0.0, |r| r.0));
+        let mut hint: Option<(f64, f64)> = None;
+        let edges = self
+            .boundaries
+            .iter()
+            .map(|wire| {
+                wire.iter()
+                    .map(|edge| {
+                        let curve = edge.curve();
+                        let (_, mut pts) = curve.parameter_division(curve.range_tuple(), tol);
+                        if !edge.orientation() {
+                            pts.reverse();
+                        }
+                        pts.pop();
+                        pts.into_iter()
+                            .map(|pt| {
+                                let uv = surface
+                                    .search_parameter(pt, hint, SEARCH_PARAMETER_TRIALS)
+                                    .or_else(|| {
+                                        let trials = SEARCH_PARAMETER_TRIALS;
+                                        surface.search_nearest_parameter(pt, None, trials)
+                                    })?;
+                                hint = Some(uv);
+                                Some(Point2::from(uv))
+                            })
+                            .collect::<Option<Vec<_>>>()
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some((edges, periods, origin))
+    }
+
+    fn uv_boundary_key(&self, tol: f64) -> UvBoundaryKey {
+        let mut hasher = rustc_hash::FxHasher::default();
+        self.boundaries.iter().for_each(|wire| {
+            wire.len().hash(&mut hasher);
+            wire.iter().for_each(|edge| (edge.id(), edge.orientation()).hash(&mut hasher));
+        });
+        (self.geometry_stamp(), tol.to_bits(), hasher.finish())
+    }
+}
+
+/// the parameters on the edges of the wires, the periods, and the origin of the fundamental domain
+type UvEdgeSamples = (Vec<Vec<Vec<Point2>>>, (Option<f64>, Option<f64>), Point2);
+
 impl<P, C, S> Clone for Face<P, C, S> {
     #[inline(always)]
     fn clone(&self) -> Face<P, C, S> {
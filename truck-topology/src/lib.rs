@@ -82,10 +82,16 @@
 )]
 
 use parking_lot::Mutex;
+use cell::*;
 use std::collections::VecDeque;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
-use truck_base::{id::ID, tolerance::*};
+use truck_base::{
+    bounding_box::BoundingBox,
+    cgmath64::{Point2, Point3},
+    id::ID,
+    tolerance::*,
+};
 use truck_geotrait::*;
 
 #[cfg(feature = "rclite")]
@@ -108,6 +114,7 @@ const SEARCH_PARAMETER_TRIALS: usize = 100;
 #[derive(Debug)]
 pub struct Vertex<P> {
     point: Arc<Mutex<P>>,
+    serial: usize,
 }
 
 /// Edge, which consists two vertices.
@@ -126,7 +133,7 @@ pub struct Vertex<P> {
 pub struct Edge<P, C> {
     vertices: (Vertex<P>, Vertex<P>),
     orientation: bool,
-    curve: Arc<Mutex<C>>,
+    curve: Arc<GeometryCell<C>>,
 }
 
 /// Wire, a path or cycle which consists some edges.
@@ -157,7 +164,7 @@ pub struct Wire<P, C> {
 pub struct Face<P, C, S> {
     boundaries: Vec<Wire<P, C>>,
     orientation: bool,
-    surface: Arc<Mutex<S>>,
+    surface: Arc<GeometryCell<S>>,
 }
 
 /// Shell, a connected compounded faces.
@@ -167,6 +174,7 @@ pub struct Face<P, C, S> {
 #[derive(Debug)]
 pub struct Shell<P, C, S> {
     face_list: Vec<Face<P, C, S>>,
+    bounding_box_cache: BoundingBoxCache<Vec<(FaceID<S>, usize)>>,
 }
 
 /// Solid, attached to a closed shells.
@@ -384,6 +392,7 @@ pub enum SolidDisplayFormat {
     },
 }
 
+mod cell;
 pub mod compress;
 mod edge;
 /// classifies the errors that can occur in this crate.
@@ -393,6 +402,8 @@ pub mod face;
 /// classifies shell conditions and defines the face iterators.
 pub mod shell;
 mod solid;
+/// the loops in the parameter space of the periodic surfaces, crossing the seams.
+pub mod uv_loop;
 mod vertex;
 /// define the edge iterators and the vertex iterator.
 pub mod wire;
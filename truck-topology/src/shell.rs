@@ -1,7 +1,7 @@
 use crate::*;
 use rayon::prelude::*;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
-use truck_base::entry_map::FxEntryMap as EntryMap;
+use truck_base::{cgmath64::MetricSpace, entry_map::FxEntryMap as EntryMap};
 
 /// A face adjacent to the specified face
 #[derive(Clone, Debug)]
@@ -25,12 +25,15 @@ impl<'a, P, C, S> As<&'a Face<P, C, S>> for AdjacentFace<'a, P, C, S> {
 }
 
 type FaceAdjacencyMap<'a, P, C, S> = HashMap<&'a Face<P, C, S>, Vec<AdjacentFace<'a, P, C, S>>>;
+/// the edges and the vertices on them with the parameters
+type EdgeJunctions<C> = Vec<(Edge<Point3, C>, Vec<(f64, Vertex<Point3>)>)>;
 impl<P, C, S> Shell<P, C, S> {
     /// Creates the empty shell.
     #[inline(always)]
     pub const fn new() -> Shell<P, C, S> {
         Shell {
             face_list: Vec::new(),
+            bounding_box_cache: BoundingBoxCache::new(),
         }
     }
 
@@ -39,6 +42,7 @@ impl<P, C, S> Shell<P, C, S> {
     pub fn with_capacity(capacity: usize) -> Shell<P, C, S> {
         Shell {
             face_list: Vec::with_capacity(capacity),
+            bounding_box_cache: BoundingBoxCache::new(),
         }
     }
 
@@ -558,12 +562,24 @@ impl<P, C, S> Shell<P, C, S> {
             Edge::id,
             wire::edge_entry_map_try_closure(&mut vertex_map, &mut curve_mapping),
         );
+        self.sub_try_mapped(&mut edge_map, &mut surface_mapping)
+    }
+
+    pub(super) fn sub_try_mapped<'a, Q, D, T, KF, KV>(
+        &'a self,
+        edge_map: &mut wire::EdgeEntryMapForTryMapping<'a, P, C, Q, D, KF, KV>,
+        surface_mapping: &mut impl FnMut(&S) -> Option<T>,
+    ) -> Option<Shell<Q, D, T>>
+    where
+        KF: FnMut(&'a Edge<P, C>) -> EdgeID<C>,
+        KV: FnMut(&'a Edge<P, C>) -> Option<Edge<Q, D>>,
+    {
         self.face_iter()
             .map(|face| {
                 let wires = face
                     .absolute_boundaries()
                     .iter()
-                    .map(|wire| wire.sub_try_mapped(&mut edge_map))
+                    .map(|wire| wire.sub_try_mapped(edge_map))
                     .collect::<Option<Vec<_>>>()?;
                 let surface = surface_mapping(&*face.surface.lock())?;
                 let mut new_face = Face::debug_new(wires, surface);
@@ -652,12 +668,24 @@ impl<P, C, S> Shell<P, C, S> {
             Edge::id,
             wire::edge_entry_map_closure(&mut vertex_map, &mut curve_mapping),
         );
+        self.sub_mapped(&mut edge_map, &mut surface_mapping)
+    }
+
+    pub(super) fn sub_mapped<'a, Q, D, T, KF, KV>(
+        &'a self,
+        edge_map: &mut wire::EdgeEntryMapForMapping<'a, P, C, Q, D, KF, KV>,
+        surface_mapping: &mut impl FnMut(&S) -> T,
+    ) -> Shell<Q, D, T>
+    where
+        KF: FnMut(&'a Edge<P, C>) -> EdgeID<C>,
+        KV: FnMut(&'a Edge<P, C>) -> Edge<Q, D>,
+    {
         self.face_iter()
             .map(|face| {
                 let wires: Vec<Wire<_, _>> = face
                     .absolute_boundaries()
                     .iter()
-                    .map(|wire| wire.sub_mapped(&mut edge_map))
+                    .map(|wire| wire.sub_mapped(edge_map))
                     .collect();
                 let surface = surface_mapping(&*face.surface.lock());
                 let mut new_face = Face::debug_new(wires, surface);
@@ -669,6 +697,68 @@ impl<P, C, S> Shell<P, C, S> {
             .collect()
     }
 
+    /// Returns a copy of the shell that is completely independent of the original.
+    ///
+    /// The adjacency of the faces, edges and vertices is preserved in the copy,
+    /// but all the geometries are cloned into new elements.
+    /// So, modifying the geometry of the copy never affects the original.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[0, 1, 2]);
+    /// let wire = Wire::from(vec![
+    ///     Edge::new(&v[0], &v[1], 100),
+    ///     Edge::new(&v[1], &v[2], 200),
+    ///     Edge::new(&v[2], &v[0], 300),
+    /// ]);
+    /// let shell0: Shell<_, _, _> = vec![Face::new(vec![wire], 1000)].into();
+    /// let shell1 = shell0.topological_clone();
+    ///
+    /// // The copy has the same structure but its own elements.
+    /// assert_eq!(shell1.vertex_iter().count(), 3);
+    /// assert_eq!(shell1.shell_condition(), shell0.shell_condition());
+    /// assert_ne!(shell0[0].id(), shell1[0].id());
+    ///
+    /// // Modifying the copy does not affect the original.
+    /// let v1 = shell1.vertex_iter().next().unwrap();
+    /// v1.set_point(10);
+    /// assert_eq!(v[0].point(), 0);
+    /// assert_eq!(v1.point(), 10);
+    /// ```
+    #[inline(always)]
+    pub fn topological_clone(&self) -> Shell<P, C, S>
+    where
+        P: Clone,
+        C: Clone,
+        S: Clone, {
+        self.mapped(Clone::clone, Clone::clone, Clone::clone)
+    }
+
+    /// Returns a copy of the shell that shares all the elements with the original.
+    ///
+    /// This is the same as [`Clone::clone`]: the topological elements of the copy
+    /// are the ones of the original, so modifying the geometry of the copy affects the original.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[0, 1, 2]);
+    /// let wire = Wire::from(vec![
+    ///     Edge::new(&v[0], &v[1], 100),
+    ///     Edge::new(&v[1], &v[2], 200),
+    ///     Edge::new(&v[2], &v[0], 300),
+    /// ]);
+    /// let shell0: Shell<_, _, _> = vec![Face::new(vec![wire], 1000)].into();
+    /// let shell1 = shell0.shallow_share_clone();
+    /// assert_eq!(shell0[0].id(), shell1[0].id());
+    ///
+    /// // Modifying the copy affects the original.
+    /// let v1 = shell1.vertex_iter().next().unwrap();
+    /// v1.set_point(10);
+    /// assert_eq!(v[0].point(), 10);
+    /// ```
+    #[inline(always)]
+    pub fn shallow_share_clone(&self) -> Shell<P, C, S> { self.clone() }
+
     /// Returns the consistence of the geometry of end vertices
     /// and the geometry of edge.
     #[inline(always)]
@@ -777,6 +867,73 @@ impl<P, C, S> Shell<P, C, S> {
         }
     }
 
+    /// Merges `vertex1` into `vertex0`, i.e. replaces all references to `vertex1` in the shell
+    /// by `vertex0`. The point of `vertex0` is kept.
+    ///
+    /// The edges incident to `vertex1` are recreated with the clones of their curves, and all
+    /// their appearances in the boundaries are replaced with keeping the orientations.
+    ///
+    /// # Failures
+    /// Returns `false` and not edit `self` if:
+    /// - `vertex0` and `vertex1` are the same vertex,
+    /// - `vertex1` is not included in the shell, or
+    /// - there is an edge between `vertex0` and `vertex1`, which cannot be an edge after merging.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[0, 1, 2, 3, 4, 2]);
+    /// let wire0 = wire![
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[0], ()),
+    /// ];
+    /// let wire1 = wire![
+    ///     Edge::new(&v[3], &v[4], ()),
+    ///     Edge::new(&v[4], &v[5], ()),
+    ///     Edge::new(&v[5], &v[3], ()),
+    /// ];
+    /// let faces = vec![Face::new(vec![wire0], ()), Face::new(vec![wire1], ())];
+    /// let mut shell: Shell<_, _, _> = faces.into();
+    /// assert_eq!(shell.vertex_iter().filter(|v| v.point() == 2).count(), 2);
+    ///
+    /// assert!(shell.merge_vertices(&v[2], &v[5]));
+    /// assert_eq!(shell[1].boundaries()[0][1].back(), shell[0].boundaries()[0][1].back());
+    /// assert!(shell.vertex_iter().all(|w| w.point() != 2 || w == v[2]));
+    /// ```
+    pub fn merge_vertices(&mut self, vertex0: &Vertex<P>, vertex1: &Vertex<P>) -> bool
+    where C: Clone {
+        if vertex0 == vertex1 || !self.vertex_iter().any(|v| &v == vertex1) {
+            return false;
+        }
+        let joined = self.edge_iter().any(|edge| {
+            let (v0, v1) = edge.ends();
+            (v0 == vertex0 && v1 == vertex1) || (v0 == vertex1 && v1 == vertex0)
+        });
+        if joined {
+            return false;
+        }
+        let replaced = |v: &Vertex<P>| match v == vertex1 {
+            true => vertex0.clone(),
+            false => v.clone(),
+        };
+        let mut edges = HashMap::<EdgeID<C>, Edge<P, C>>::default();
+        self.iter_mut()
+            .flat_map(|face| face.boundaries.iter_mut())
+            .flat_map(|wire| wire.iter_mut())
+            .filter(|edge| edge.absolute_front() == vertex1 || edge.absolute_back() == vertex1)
+            .for_each(|edge| {
+                let new_edge = edges.entry(edge.id()).or_insert_with(|| {
+                    let (front, back) = edge.absolute_ends();
+                    Edge::new_unchecked(&replaced(front), &replaced(back), edge.curve())
+                });
+                *edge = match edge.orientation() {
+                    true => new_edge.clone(),
+                    false => new_edge.inverse(),
+                };
+            });
+        true
+    }
+
     /// Creates display struct for debugging the shell.
     /// # Examples
     /// ```
@@ -824,11 +981,194 @@ impl<P, C, S> Shell<P, C, S> {
     }
 }
 
+impl<P, C, S> Shell<P, C, S>
+where
+    C: BoundedCurve<Point = Point3> + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface<Point = Point3>
+        + ParameterDivision2D
+        + SearchParameter<D2, Point = Point3>,
+{
+    /// Returns the bounding box including the shell, the union of [`Face::cached_bounding_box`].
+    ///
+    /// The box is cached in the shell, and computed again only if the list of the faces has been
+    /// changed or the geometry of a face has been set after the last computation. Then, only the
+    /// boxes of the changed faces and edges are computed again.
+    pub fn bounding_box(&self) -> BoundingBox<Point3> {
+        let key: Vec<(FaceID<S>, usize)> = self
+            .face_iter()
+            .map(|face| (face.id(), face.geometry_stamp()))
+            .collect();
+        if let Some(bdb) = self.bounding_box_cache.get(&key) {
+            return bdb;
+        }
+        let bdb = self.face_iter().fold(BoundingBox::new(), |mut bdb, face| {
+            bdb += face.cached_bounding_box();
+            bdb
+        });
+        self.bounding_box_cache.store(key, bdb);
+        bdb
+    }
+}
+
+impl<C, S> Shell<Point3, C, S>
+where
+    C: Clone
+        + BoundedCurve<Point = Point3>
+        + ParameterDivision1D<Point = Point3>
+        + SearchNearestParameter<D1, Point = Point3>,
+{
+    /// Returns the T-junctions of the shell, the pairs of an edge and a vertex of the shell
+    /// lying on the interior of the edge within `tol`.
+    ///
+    /// The edges are listed in the order of [`Shell::edge_iter`] with their absolute
+    /// orientations, and the vertices on the same edge are sorted along the curve.
+    /// The vertices within `tol` from the ends of the edge are not T-junctions.
+    pub fn t_junctions(&self, tol: f64) -> Vec<(Edge<Point3, C>, Vertex<Point3>)> {
+        self.t_junction_parameters(tol)
+            .into_iter()
+            .flat_map(|(edge, vertices)| vertices.into_iter().map(move |(_, v)| (edge.clone(), v)))
+            .collect()
+    }
+
+    /// Splits the edges at the T-junctions found by [`Shell::t_junctions`].
+    ///
+    /// Each edge is split at all the vertices on it at once, and all its appearances in the
+    /// boundaries are replaced with keeping the orientations. If a piece has the same ends as
+    /// an edge already in the shell and the curves are within `tol`, the existing edge is
+    /// adopted in order to share the edge between the faces on both sides.
+    ///
+    /// # Returns
+    /// Returns the number of the split points.
+    pub fn split_at_t_junctions(&mut self, tol: f64) -> usize
+    where C: Cut<Point = Point3> {
+        let junctions = self.t_junction_parameters(tol);
+        if junctions.is_empty() {
+            return 0;
+        }
+        let split_ids: HashSet<EdgeID<C>> = junctions.iter().map(|(edge, _)| edge.id()).collect();
+        type EndsMap<C> = HashMap<(VertexID<Point3>, VertexID<Point3>), Vec<Edge<Point3, C>>>;
+        let mut existing = EndsMap::<C>::default();
+        let mut ids = HashSet::<EdgeID<C>>::default();
+        self.edge_iter()
+            .filter(|edge| !split_ids.contains(&edge.id()) && ids.insert(edge.id()))
+            .for_each(|edge| {
+                let (v0, v1) = edge.absolute_ends();
+                existing.entry((v0.id(), v1.id())).or_default().push(edge.absolute_clone());
+            });
+        let find_existing = |piece: &Edge<Point3, C>| {
+            let (v0, v1) = piece.absolute_ends();
+            let curve = piece.curve();
+            let (t0, t1) = curve.range_tuple();
+            let mid = curve.subs((t0 + t1) / 2.0);
+            let on_edge = |edge: &Edge<Point3, C>| {
+                let curve = edge.curve();
+                curve
+                    .search_nearest_parameter(mid, None, SEARCH_PARAMETER_TRIALS)
+                    .is_some_and(|t| curve.subs(t).distance(mid) <= tol)
+            };
+            let mut same = existing.get(&(v0.id(), v1.id())).into_iter().flatten();
+            let mut inverse = existing.get(&(v1.id(), v0.id())).into_iter().flatten();
+            match same.find(|edge| on_edge(edge)) {
+                Some(edge) => Some(edge.clone()),
+                None => inverse.find(|edge| on_edge(edge)).map(Edge::inverse),
+            }
+        };
+        let mut counter = 0;
+        let pieces: HashMap<EdgeID<C>, Vec<Edge<Point3, C>>> = junctions
+            .into_iter()
+            .map(|(edge, vertices)| {
+                counter += vertices.len();
+                let mut pieces = Vec::with_capacity(vertices.len() + 1);
+                let remainder = vertices.into_iter().fold(edge.clone(), |edge, (t, v)| {
+                    let (piece, remainder) = edge.pre_cut(&v, edge.curve(), t);
+                    pieces.push(piece);
+                    remainder
+                });
+                pieces.push(remainder);
+                let pieces = pieces
+                    .into_iter()
+                    .map(|piece| find_existing(&piece).unwrap_or(piece))
+                    .collect();
+                (edge.id(), pieces)
+            })
+            .collect();
+        self.iter_mut()
+            .flat_map(|face| face.boundaries.iter_mut())
+            .for_each(|wire| {
+                let indices: Vec<usize> = (0..wire.len())
+                    .filter(|i| pieces.contains_key(&wire[*i].id()))
+                    .collect();
+                indices.into_iter().rev().for_each(|idx| {
+                    let edge = &wire[idx];
+                    let pieces = &pieces[&edge.id()];
+                    let new_wire: Wire<Point3, C> = match edge.orientation() {
+                        true => pieces.iter().cloned().collect(),
+                        false => pieces.iter().rev().map(Edge::inverse).collect(),
+                    };
+                    let flag = wire.swap_edge_into_wire(idx, new_wire);
+                    debug_assert!(flag);
+                });
+            });
+        counter
+    }
+
+    /// Returns the absolute edges with T-junctions and the vertices on them, sorted by the
+    /// parameters.
+    fn t_junction_parameters(&self, tol: f64) -> EdgeJunctions<C> {
+        let mut vertex_ids = HashSet::<VertexID<Point3>>::default();
+        let vertices: Vec<(Vertex<Point3>, Point3)> = self
+            .vertex_iter()
+            .filter(|v| vertex_ids.insert(v.id()))
+            .map(|v| {
+                let pt = v.point();
+                (v, pt)
+            })
+            .collect();
+        let mut edge_ids = HashSet::<EdgeID<C>>::default();
+        self.edge_iter()
+            .filter(|edge| edge_ids.insert(edge.id()))
+            .filter_map(|edge| {
+                let edge = edge.absolute_clone();
+                let bdb = with_margin(edge.cached_bounding_box(), tol);
+                let (front, back) = edge.absolute_ends();
+                let (p0, p1) = (front.point(), back.point());
+                let curve = edge.curve();
+                let (t0, t1) = curve.range_tuple();
+                let mut on_edge: Vec<(f64, Vertex<Point3>)> = vertices
+                    .iter()
+                    .filter(|(v, pt)| {
+                        v != front
+                            && v != back
+                            && bdb.contains(*pt)
+                            && pt.distance(p0) > tol
+                            && pt.distance(p1) > tol
+                    })
+                    .filter_map(|(v, pt)| {
+                        let t =
+                            curve.search_nearest_parameter(*pt, None, SEARCH_PARAMETER_TRIALS)?;
+                        let interior = t0 + TOLERANCE < t && t < t1 - TOLERANCE;
+                        match interior && curve.subs(t).distance(*pt) <= tol {
+                            true => Some((t, v.clone())),
+                            false => None,
+                        }
+                    })
+                    .collect();
+                if on_edge.is_empty() {
+                    return None;
+                }
+                on_edge.sort_by(|(s, _), (t, _)| s.total_cmp(t));
+                Some((edge, on_edge))
+            })
+            .collect()
+    }
+}
+
 impl<P, C, S> Clone for Shell<P, C, S> {
     #[inline(always)]
     fn clone(&self) -> Shell<P, C, S> {
         Shell {
             face_list: self.face_list.clone(),
+            bounding_box_cache: BoundingBoxCache::new(),
         }
     }
 }
@@ -840,6 +1180,7 @@ where Vec<Face<P, C, S>>: From<T>
     fn from(faces: T) -> Shell<P, C, S> {
         Shell {
             face_list: faces.into(),
+            bounding_box_cache: BoundingBoxCache::new(),
         }
     }
 }
@@ -849,6 +1190,7 @@ impl<P, C, S> FromIterator<Face<P, C, S>> for Shell<P, C, S> {
     fn from_iter<I: IntoIterator<Item = Face<P, C, S>>>(iter: I) -> Shell<P, C, S> {
         Shell {
             face_list: Vec::from_iter(iter),
+            bounding_box_cache: BoundingBoxCache::new(),
         }
     }
 }
@@ -910,6 +1252,7 @@ impl<P, C, S> Default for Shell<P, C, S> {
     fn default() -> Self {
         Self {
             face_list: Vec::new(),
+            bounding_box_cache: BoundingBoxCache::new(),
         }
     }
 }
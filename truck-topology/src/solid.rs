@@ -2,6 +2,7 @@ use crate::errors::Error;
 use crate::shell::ShellCondition;
 use crate::*;
 use std::vec::Vec;
+use truck_base::entry_map::FxEntryMap as EntryMap;
 
 impl<P, C, S> Solid<P, C, S> {
     /// create the shell whose boundaries is boundary.
@@ -100,12 +101,15 @@ impl<P, C, S> Solid<P, C, S> {
         mut curve_mapping: impl FnMut(&C) -> Option<D>,
         mut surface_mapping: impl FnMut(&S) -> Option<T>,
     ) -> Option<Solid<Q, D, T>> {
+        let mut vertex_map = EntryMap::new(Vertex::id, move |v| v.try_mapped(&mut point_mapping));
+        let mut edge_map = EntryMap::new(
+            Edge::id,
+            wire::edge_entry_map_try_closure(&mut vertex_map, &mut curve_mapping),
+        );
         Some(Solid::debug_new(
             self.boundaries()
                 .iter()
-                .map(move |shell| {
-                    shell.try_mapped(&mut point_mapping, &mut curve_mapping, &mut surface_mapping)
-                })
+                .map(|shell| shell.sub_try_mapped(&mut edge_map, &mut surface_mapping))
                 .collect::<Option<Vec<_>>>()?,
         ))
     }
@@ -123,16 +127,42 @@ impl<P, C, S> Solid<P, C, S> {
         mut curve_mapping: impl FnMut(&C) -> D,
         mut surface_mapping: impl FnMut(&S) -> T,
     ) -> Solid<Q, D, T> {
+        let mut vertex_map = EntryMap::new(Vertex::id, |v| v.mapped(&mut point_mapping));
+        let mut edge_map = EntryMap::new(
+            Edge::id,
+            wire::edge_entry_map_closure(&mut vertex_map, &mut curve_mapping),
+        );
         Solid::debug_new(
             self.boundaries()
                 .iter()
-                .map(move |shell| {
-                    shell.mapped(&mut point_mapping, &mut curve_mapping, &mut surface_mapping)
-                })
+                .map(|shell| shell.sub_mapped(&mut edge_map, &mut surface_mapping))
                 .collect(),
         )
     }
 
+    /// Returns a copy of the solid that is completely independent of the original.
+    ///
+    /// The topology is preserved, but all the geometries are cloned into new elements.
+    /// So, modifying the geometry of the copy never affects the original.
+    /// See [`Shell::topological_clone`] for details.
+    #[inline(always)]
+    pub fn topological_clone(&self) -> Solid<P, C, S>
+    where
+        P: Clone,
+        C: Clone,
+        S: Clone, {
+        self.mapped(Clone::clone, Clone::clone, Clone::clone)
+    }
+
+    /// Returns a copy of the solid that shares all the elements with the original.
+    ///
+    /// This is the same as [`Clone::clone`], so modifying the geometry of the copy
+    /// affects the original. See [`Shell::shallow_share_clone`] for details.
+    #[inline(always)]
+    pub fn shallow_share_clone(&self) -> Solid<P, C, S> {
+        Solid::new_unchecked(self.boundaries.clone())
+    }
+
     /// Returns the consistence of the geometry of end vertices
     /// and the geometry of edge.
     #[inline(always)]
@@ -0,0 +1,488 @@
+use truck_base::{
+    bounding_box::BoundingBox,
+    cgmath64::{Point2, Vector2},
+    tolerance::TOLERANCE,
+};
+
+/// A continuous polyline of a [`UvLoop`] in the fundamental domain of the parameter space.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UvBranch {
+    points: Vec<Point2>,
+    jump: Vector2,
+}
+
+impl UvBranch {
+    /// Returns the points of the polyline, in the fundamental domain.
+    #[inline(always)]
+    pub fn points(&self) -> &[Point2] { &self.points }
+
+    /// Returns the jump to the next branch, i.e. the multiple of the periods added to the next
+    /// branch in order to continue this branch in the covering space.
+    #[inline(always)]
+    pub fn jump(&self) -> Vector2 { self.jump }
+}
+
+/// A closed polyline in the parameter space of a periodic surface, which may cross the seams.
+///
+/// The loop is the cyclic sequence of the branches in the fundamental domain, linked by the jumps
+/// by the periods at the seams. The loops on the surfaces without periods consist of one branch.
+/// # Examples
+/// ```
+/// use truck_topology::uv_loop::UvLoop;
+/// use truck_base::cgmath64::*;
+/// use std::f64::consts::PI;
+///
+/// // the square crossing the seam `u = 0` of the period `2 PI`
+/// let samples = [(-0.5, 0.0), (0.5, 0.0), (0.5, 1.0), (-0.5, 1.0)];
+/// let samples = samples.map(|(u, v)| Point2::new(u, v));
+/// let uv_loop = UvLoop::new(samples, (Some(2.0 * PI), None), Point2::origin());
+///
+/// assert_eq!(uv_loop.branches().len(), 2);
+/// assert_eq!(uv_loop.total_jump(), Vector2::new(0.0, 0.0));
+/// assert_eq!(uv_loop.winding_number(Point2::new(0.0, 0.5)), 1);
+/// assert_eq!(uv_loop.winding_number(Point2::new(2.0 * PI - 0.1, 0.5)), 1);
+/// assert_eq!(uv_loop.winding_number(Point2::new(PI, 0.5)), 0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct UvLoop {
+    branches: Vec<UvBranch>,
+    periods: (Option<f64>, Option<f64>),
+}
+
+/// The number of the periods from `origin` to `x`, sticking to `previous` while `x` is in the
+/// domain of `previous` up to the tolerance.
+fn period_count(x: f64, origin: f64, period: Option<f64>, previous: Option<i64>) -> i64 {
+    let Some(period) = period else {
+        return 0;
+    };
+    let quot = (x - origin) / period;
+    match previous {
+        Some(k) if quot >= k as f64 - TOLERANCE && quot <= (k + 1) as f64 + TOLERANCE => k,
+        _ => f64::floor(quot + TOLERANCE) as i64,
+    }
+}
+
+/// Moves `x` by the multiple of `period` to the nearest to `hint`.
+fn unwrap_period(x: f64, hint: f64, period: Option<f64>) -> f64 {
+    match period {
+        Some(period) => x + period * f64::round((hint - x) / period),
+        None => x,
+    }
+}
+
+impl UvLoop {
+    /// Creates the loop through the parameters `samples` of the surface with `periods`, whose
+    /// fundamental domain starts at `origin`.
+    ///
+    /// The samples may be given in any copy of the fundamental domain: each sample is lifted to the
+    /// copy nearest to the previous one, and a new branch starts where the lift leaves the copy of
+    /// the current branch. The loop keeps the cyclic order of the samples, but begins at the start
+    /// of a branch, which may not be the first sample.
+    pub fn new(
+        samples: impl IntoIterator<Item = Point2>,
+        periods: (Option<f64>, Option<f64>),
+        origin: Point2,
+    ) -> Self {
+        let period_vector = |k: (i64, i64)| {
+            let (up, vp) = (periods.0.unwrap_or(0.0), periods.1.unwrap_or(0.0));
+            Vector2::new(k.0 as f64 * up, k.1 as f64 * vp)
+        };
+        let mut branches: Vec<UvBranch> = Vec::new();
+        let mut first: Option<Point2> = None;
+        let mut previous: Option<(Point2, (i64, i64))> = None;
+        for sample in samples {
+            let lift = match previous {
+                Some((lift, _)) => Point2::new(
+                    unwrap_period(sample.x, lift.x, periods.0),
+                    unwrap_period(sample.y, lift.y, periods.1),
+                ),
+                None => sample,
+            };
+            let count = (
+                period_count(lift.x, origin.x, periods.0, previous.map(|(_, k)| k.0)),
+                period_count(lift.y, origin.y, periods.1, previous.map(|(_, k)| k.1)),
+            );
+            let point = lift - period_vector(count);
+            match (previous, branches.last_mut()) {
+                (Some((_, k)), Some(branch)) if k == count => branch.points.push(point),
+                (Some((_, k)), Some(branch)) => {
+                    branch.jump = period_vector((count.0 - k.0, count.1 - k.1));
+                    branches.push(UvBranch {
+                        points: vec![point],
+                        jump: Vector2::new(0.0, 0.0),
+                    });
+                }
+                _ => branches.push(UvBranch {
+                    points: vec![point],
+                    jump: Vector2::new(0.0, 0.0),
+                }),
+            }
+            first = first.or(Some(lift));
+            previous = Some((lift, count));
+        }
+        if let (Some(first), Some((lift, count))) = (first, previous) {
+            // the lift of the first sample continuing from the last one
+            let closing = Point2::new(
+                unwrap_period(first.x, lift.x, periods.0),
+                unwrap_period(first.y, lift.y, periods.1),
+            );
+            let first_point = branches[0].points[0];
+            let first_count = (
+                f64::round((closing.x - first_point.x) / periods.0.unwrap_or(1.0)) as i64,
+                f64::round((closing.y - first_point.y) / periods.1.unwrap_or(1.0)) as i64,
+            );
+            let last = branches.last_mut().unwrap();
+            last.jump = period_vector((first_count.0 - count.0, first_count.1 - count.1));
+            // the first sample is in the middle of the branch
+            if branches.len() > 1 && first_count == count {
+                let mut last = branches.pop().unwrap();
+                last.points.append(&mut branches[0].points);
+                branches[0].points = last.points;
+            }
+        }
+        Self { branches, periods }
+    }
+
+    /// Returns the branches of the loop.
+    #[inline(always)]
+    pub fn branches(&self) -> &[UvBranch] { &self.branches }
+
+    /// Returns the periods of the surface.
+    #[inline(always)]
+    pub fn periods(&self) -> (Option<f64>, Option<f64>) { self.periods }
+
+    /// Returns the sum of the jumps of the branches, which is not zero if the loop wraps around
+    /// the surface, e.g. the boundary circles of a cylinder wall.
+    #[inline(always)]
+    pub fn total_jump(&self) -> Vector2 {
+        self.branches.iter().fold(Vector2::new(0.0, 0.0), |sum, branch| sum + branch.jump)
+    }
+
+    /// Returns the iterator on the points in the covering space, continued through the jumps from
+    /// the first branch in the fundamental domain.
+    pub fn unwrapped_iter(&self) -> impl Iterator<Item = Point2> + '_ {
+        let offsets = self.branches.iter().scan(Vector2::new(0.0, 0.0), |offset, branch| {
+            let current = *offset;
+            *offset += branch.jump;
+            Some(current)
+        });
+        self.branches
+            .iter()
+            .zip(offsets)
+            .flat_map(|(branch, offset)| branch.points.iter().map(move |p| p + offset))
+    }
+
+    /// Returns the bounding box of the loop in the covering space. cf. [`UvLoop::unwrapped_iter`]
+    #[inline(always)]
+    pub fn bounding_box(&self) -> BoundingBox<Point2> { self.unwrapped_iter().collect() }
+
+    /// Returns the winding number of the loop around `uv`, which may be in any copy of the
+    /// fundamental domain.
+    ///
+    /// The number is the signed count of the crossings of the ray from `uv` and all lifts of the
+    /// loop. The ray goes toward `+v` if `v` is not periodic, or toward `+u` otherwise. Hence,
+    /// the loops wrapping around a cylinder are counted if they are above `uv`: the band between
+    /// two loops wrapping in the opposite directions has the winding number `1` or `-1`. On the
+    /// surfaces periodic in both directions, `uv` is moved into the period above the bottom of the
+    /// loop, and the loops wrapping in both directions are not supported.
+    pub fn winding_number(&self, uv: Point2) -> isize {
+        let periods = [self.periods.0, self.periods.1];
+        let total = self.total_jump();
+        let r = match periods {
+            [_, None] => 1,
+            [None, Some(_)] => 0,
+            [Some(_), Some(vp)] => match total.y.abs() < vp / 2.0 {
+                true => 1,
+                false => 0,
+            },
+        };
+        let mut query = uv;
+        if let Some(period) = periods[r] {
+            let min = self.bounding_box().min()[r];
+            query[r] = min + (query[r] - min).rem_euclid(period);
+        }
+        let points: Vec<Point2> = self.unwrapped_iter().collect();
+        let closing = points.first().map(|first| (points[points.len() - 1], first + total));
+        let segments = points.windows(2).map(|pair| (pair[0], pair[1])).chain(closing);
+        segments.map(|(p, q)| crossings(p, q, query, r, periods[1 - r])).sum()
+    }
+}
+
+/// The signed count of the crossings of the segment `p`-`q` lifted by `period` and the ray from
+/// `query` along the axis `r`.
+fn crossings(p: Point2, q: Point2, query: Point2, r: usize, period: Option<f64>) -> isize {
+    let s = 1 - r;
+    // the crossings toward `+v` for the ray toward `+u`, and toward `-u` for the ray toward `+v`
+    let sign = match (r == 0) == (q[s] > p[s]) {
+        true => 1,
+        false => -1,
+    };
+    let (min, max) = (f64::min(p[s], q[s]), f64::max(p[s], q[s]));
+    let (k0, k1) = match period {
+        Some(period) => (
+            f64::floor((query[s] - max) / period) as i64,
+            f64::ceil((query[s] - min) / period) as i64,
+        ),
+        None => (0, 0),
+    };
+    let count = (k0..=k1)
+        .map(|k| k as f64 * period.unwrap_or(0.0))
+        .filter(|shift| {
+            let (ps, qs) = (p[s] + shift, q[s] + shift);
+            if (ps <= query[s]) == (qs <= query[s]) {
+                return false;
+            }
+            let t = (query[s] - ps) / (qs - ps);
+            p[r] + (q[r] - p[r]) * t > query[r]
+        })
+        .count() as isize;
+    sign * count
+}
+
+/// The parameter fixed on a scanline of [`UvBoundary::scanline_crossings`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UV {
+    /// the line `u = value`, parametrized by `v`
+    U,
+    /// the line `v = value`, parametrized by `u`
+    V,
+}
+
+/// The direction in which the boundary crosses a scanline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CrossingDirection {
+    /// from the smaller fixed parameter to the larger one
+    Up,
+    /// from the larger fixed parameter to the smaller one
+    Down,
+}
+
+/// A crossing of a scanline and a boundary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScanlineCrossing {
+    /// the parameter along the scanline
+    pub parameter: f64,
+    /// the index of the loop and the index of the edge in the loop
+    pub edge: (usize, usize),
+    /// the direction of the boundary
+    pub direction: CrossingDirection,
+}
+
+/// A part of a boundary on a scanline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScanlineInterval {
+    /// the smaller and the larger parameters along the scanline
+    pub range: (f64, f64),
+    /// the index of the loop and the index of the edge in the loop
+    pub edge: (usize, usize),
+}
+
+/// The result of [`UvBoundary::scanline_crossings`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScanlineResult {
+    /// the crossings sorted by the parameters
+    pub crossings: Vec<ScanlineCrossing>,
+    /// the parts of the boundaries on the scanline, sorted by the edges and the parameters
+    pub intervals: Vec<ScanlineInterval>,
+}
+
+/// The polyline of a loop of [`UvBoundary`] in the covering space.
+#[derive(Clone, Debug, PartialEq)]
+struct TaggedLoop {
+    points: Vec<Point2>,
+    /// the index of the edge of the segment from each point
+    edges: Vec<usize>,
+    /// the multiple of the periods from the first point to the end of the loop
+    jump: Vector2,
+}
+
+/// The loops in the parameter space whose segments are tagged by the edges of the boundaries,
+/// for the scanline queries.
+/// # Examples
+/// ```
+/// use truck_topology::uv_loop::*;
+/// use truck_base::cgmath64::*;
+///
+/// // the unit square, whose edges are the sides
+/// let square = vec![
+///     vec![Point2::new(0.0, 0.0)],
+///     vec![Point2::new(1.0, 0.0)],
+///     vec![Point2::new(1.0, 1.0)],
+///     vec![Point2::new(0.0, 1.0)],
+/// ];
+/// let boundary = UvBoundary::new(vec![square], (None, None), Point2::origin());
+///
+/// let res = boundary.scanline_crossings(UV::V, 0.5, 1.0e-6);
+/// assert_eq!(res.crossings.len(), 2);
+/// assert_eq!(res.crossings[0].parameter, 0.0);
+/// assert_eq!(res.crossings[0].edge, (0, 3));
+/// assert_eq!(res.crossings[0].direction, CrossingDirection::Down);
+/// assert_eq!(res.crossings[1].parameter, 1.0);
+/// assert_eq!(res.crossings[1].edge, (0, 1));
+/// assert_eq!(res.crossings[1].direction, CrossingDirection::Up);
+/// assert!(res.intervals.is_empty());
+///
+/// // the scanlines along the sides: the points on the scanlines are regarded as above them
+/// let res = boundary.scanline_crossings(UV::V, 0.0, 1.0e-6);
+/// assert!(res.crossings.is_empty());
+/// assert_eq!(res.intervals.len(), 1);
+/// assert_eq!(res.intervals[0].range, (0.0, 1.0));
+/// assert_eq!(res.intervals[0].edge, (0, 0));
+/// let res = boundary.scanline_crossings(UV::V, 1.0, 1.0e-6);
+/// assert_eq!(res.crossings.len(), 2);
+/// assert_eq!(res.intervals[0].edge, (0, 2));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct UvBoundary {
+    loops: Vec<TaggedLoop>,
+    periods: (Option<f64>, Option<f64>),
+    origin: Point2,
+}
+
+impl UvBoundary {
+    /// Creates the boundary of the surface with `periods`, whose fundamental domain starts at
+    /// `origin`.
+    ///
+    /// Each loop is the sequence of the edges, and each edge is the polyline of the parameters
+    /// from its front vertex, excluding the back vertex, which is the front of the next edge.
+    /// The parameters may be given in any copy of the fundamental domain: each one is lifted to
+    /// the copy nearest to the previous one.
+    pub fn new(
+        loops: Vec<Vec<Vec<Point2>>>,
+        periods: (Option<f64>, Option<f64>),
+        origin: Point2,
+    ) -> Self {
+        let loops = loops
+            .into_iter()
+            .map(|edges| {
+                let mut points = Vec::<Point2>::new();
+                let mut tags = Vec::new();
+                edges.into_iter().enumerate().for_each(|(i, edge)| {
+                    edge.into_iter().for_each(|uv| {
+                        let uv = match points.last() {
+                            Some(last) => Point2::new(
+                                unwrap_period(uv.x, last.x, periods.0),
+                                unwrap_period(uv.y, last.y, periods.1),
+                            ),
+                            None => uv,
+                        };
+                        points.push(uv);
+                        tags.push(i);
+                    });
+                });
+                let jump = match (points.first(), points.last()) {
+                    (Some(first), Some(last)) => {
+                        let closing = Point2::new(
+                            unwrap_period(first.x, last.x, periods.0),
+                            unwrap_period(first.y, last.y, periods.1),
+                        );
+                        closing - first
+                    }
+                    _ => Vector2::new(0.0, 0.0),
+                };
+                TaggedLoop {
+                    points,
+                    edges: tags,
+                    jump,
+                }
+            })
+            .collect();
+        Self {
+            loops,
+            periods,
+            origin,
+        }
+    }
+
+    /// Returns the periods of the surface.
+    #[inline(always)]
+    pub fn periods(&self) -> (Option<f64>, Option<f64>) { self.periods }
+
+    /// Returns the crossings of the boundary and the scanline `u = value` or `v = value`, and the
+    /// parts of the boundary on the scanline.
+    ///
+    /// The points of the polylines within `tol` of the scanline are regarded as on the scanline,
+    /// and counted as on the side of the larger fixed parameter, i.e. by the half-open convention:
+    /// - a polyline passing through the scanline at a point crosses once there,
+    /// - a polyline touching the scanline at a point crosses twice there from the smaller side,
+    ///   and never from the larger side,
+    /// - a segment on the scanline is reported as an interval, and the polyline along it crosses
+    ///   at most once at the ends.
+    ///
+    /// Hence, the loops not wrapping around the surfaces cross each scanline an even number of
+    /// times. If the fixed parameter is periodic, all copies of the boundary are intersected,
+    /// and if the other parameter is periodic, the parameters along the scanline are in the
+    /// fundamental domain.
+    pub fn scanline_crossings(&self, dir: UV, value: f64, tol: f64) -> ScanlineResult {
+        let (s, r) = match dir {
+            UV::U => (0, 1),
+            UV::V => (1, 0),
+        };
+        let periods = [self.periods.0, self.periods.1];
+        let reduce = |x: f64| match periods[r] {
+            Some(period) => {
+                let origin = self.origin[r];
+                origin + (x - origin).rem_euclid(period)
+            }
+            None => x,
+        };
+        let mut res = ScanlineResult::default();
+        for (i, tagged) in self.loops.iter().enumerate() {
+            let len = tagged.points.len();
+            for j in 0..len {
+                let p = tagged.points[j];
+                let q = match j + 1 == len {
+                    true => tagged.points[0] + tagged.jump,
+                    false => tagged.points[j + 1],
+                };
+                let edge = (i, tagged.edges[j]);
+                let (min, max) = (f64::min(p[s], q[s]), f64::max(p[s], q[s]));
+                let (k0, k1) = match periods[s] {
+                    Some(period) => (
+                        f64::floor((value - tol - max) / period) as i64,
+                        f64::ceil((value + tol - min) / period) as i64,
+                    ),
+                    None => (0, 0),
+                };
+                for k in k0..=k1 {
+                    let shift = k as f64 * periods[s].unwrap_or(0.0);
+                    let (ps, qs) = (p[s] + shift - value, q[s] + shift - value);
+                    let (p_on, q_on) = (ps.abs() <= tol, qs.abs() <= tol);
+                    if p_on && q_on {
+                        let (a, b) = (f64::min(p[r], q[r]), f64::max(p[r], q[r]));
+                        let a0 = reduce(a);
+                        res.intervals.push(ScanlineInterval {
+                            range: (a0, a0 + b - a),
+                            edge,
+                        });
+                    } else if (ps < -tol) != (qs < -tol) {
+                        let parameter = match (p_on, q_on) {
+                            (true, _) => p[r],
+                            (_, true) => q[r],
+                            _ => p[r] + (q[r] - p[r]) * ps / (ps - qs),
+                        };
+                        let direction = match ps < qs {
+                            true => CrossingDirection::Up,
+                            false => CrossingDirection::Down,
+                        };
+                        res.crossings.push(ScanlineCrossing {
+                            parameter: reduce(parameter),
+                            edge,
+                            direction,
+                        });
+                    }
+                }
+            }
+        }
+        res.crossings.sort_by(|x, y| x.parameter.total_cmp(&y.parameter));
+        res.intervals.sort_by(|x, y| x.edge.cmp(&y.edge).then(x.range.0.total_cmp(&y.range.0)));
+        res.intervals.dedup_by(|next, interval| {
+            let merge = next.edge == interval.edge && next.range.0 <= interval.range.1 + tol;
+            if merge {
+                interval.range.1 = f64::max(interval.range.1, next.range.1);
+            }
+            merge
+        });
+        res
+    }
+}
@@ -15,6 +15,7 @@ impl<P> Vertex<P> {
     pub fn new(point: P) -> Vertex<P> {
         Vertex {
             point: Arc::new(Mutex::new(point)),
+            serial: next_serial(),
         }
     }
 
@@ -94,6 +95,23 @@ impl<P> Vertex<P> {
     #[inline(always)]
     pub fn id(&self) -> VertexID<P> { ID::new(Arc::as_ptr(&self.point)) }
 
+    /// Returns the serial number of the vertex, the integer handle for associating the vertex
+    /// with external data.
+    ///
+    /// The cloned vertices have the same serial number, and distinct vertices have distinct
+    /// ones, even if one of them has been dropped. The serial numbers are assigned in each
+    /// process, so they are not preserved through serialization.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v0 = Vertex::new(());
+    /// let v1 = Vertex::new(());
+    /// assert_eq!(v0.serial(), v0.clone().serial());
+    /// assert_ne!(v0.serial(), v1.serial());
+    /// ```
+    #[inline(always)]
+    pub fn serial(&self) -> usize { self.serial }
+
     /// Returns how many same vertices.
     ///
     /// # Examples
@@ -156,6 +174,7 @@ impl<P> Clone for Vertex<P> {
     fn clone(&self) -> Vertex<P> {
         Vertex {
             point: Arc::clone(&self.point),
+            serial: self.serial,
         }
     }
 }
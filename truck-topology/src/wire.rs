@@ -541,9 +541,9 @@ impl<P, C> Wire<P, C> {
     }
 }
 
-type EdgeEntryMapForTryMapping<'a, P, C, Q, D, KF, KV> =
+pub(super) type EdgeEntryMapForTryMapping<'a, P, C, Q, D, KF, KV> =
     EntryMap<EdgeID<C>, Option<Edge<Q, D>>, KF, KV, &'a Edge<P, C>>;
-type EdgeEntryMapForMapping<'a, P, C, Q, D, KF, KV> =
+pub(super) type EdgeEntryMapForMapping<'a, P, C, Q, D, KF, KV> =
     EntryMap<EdgeID<C>, Edge<Q, D>, KF, KV, &'a Edge<P, C>>;
 
 pub(super) fn edge_entry_map_try_closure<'a, P, C, Q, D, KF, VF>(
@@ -0,0 +1,241 @@
+use std::ops::Bound;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use truck_base::{bounding_box::BoundingBox, cgmath64::*};
+use truck_geotrait::*;
+use truck_topology::*;
+
+#[derive(Clone, Debug, Default)]
+struct Counter(Arc<AtomicUsize>);
+
+impl Counter {
+    fn count(&self) { self.0.fetch_add(1, Ordering::Relaxed); }
+    fn take(&self) -> usize { self.0.swap(0, Ordering::Relaxed) }
+}
+
+/// a line segment counting the evaluations and the divisions
+#[derive(Clone, Debug)]
+struct Segment {
+    ends: (Point3, Point3),
+    evaluations: Counter,
+    divisions: Counter,
+}
+
+impl Segment {
+    fn new(p: Point3, q: Point3) -> Self {
+        Self {
+            ends: (p, q),
+            evaluations: Counter::default(),
+            divisions: Counter::default(),
+        }
+    }
+}
+
+impl ParametricCurve for Segment {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, t: f64) -> Point3 {
+        self.evaluations.count();
+        self.ends.0 + (self.ends.1 - self.ends.0) * t
+    }
+    fn der(&self, _: f64) -> Vector3 { self.ends.1 - self.ends.0 }
+    fn der2(&self, _: f64) -> Vector3 { Vector3::zero() }
+    fn der_n(&self, n: usize, t: f64) -> Vector3 {
+        match n {
+            0 => self.subs(t).to_vec(),
+            1 => self.der(t),
+            _ => Vector3::zero(),
+        }
+    }
+    fn parameter_range(&self) -> ParameterRange { (Bound::Included(0.0), Bound::Included(1.0)) }
+}
+
+impl BoundedCurve for Segment {}
+
+impl ParameterDivision1D for Segment {
+    type Point = Point3;
+    fn parameter_division(&self, (t0, t1): (f64, f64), _: f64) -> (Vec<f64>, Vec<Point3>) {
+        self.divisions.count();
+        (vec![t0, t1], vec![self.subs(t0), self.subs(t1)])
+    }
+}
+
+/// a plane `origin + u * axes.0 + v * axes.1` counting the evaluations
+#[derive(Clone, Debug)]
+struct Plane {
+    origin: Point3,
+    axes: (Vector3, Vector3),
+    evaluations: Counter,
+}
+
+impl Plane {
+    fn new(origin: Point3, axes: (Vector3, Vector3)) -> Self {
+        Self {
+            origin,
+            axes,
+            evaluations: Counter::default(),
+        }
+    }
+}
+
+impl ParametricSurface for Plane {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        self.evaluations.count();
+        self.origin + self.axes.0 * u + self.axes.1 * v
+    }
+    fn uder(&self, _: f64, _: f64) -> Vector3 { self.axes.0 }
+    fn vder(&self, _: f64, _: f64) -> Vector3 { self.axes.1 }
+    fn uuder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Vector3 {
+        match (m, n) {
+            (0, 0) => self.subs(u, v).to_vec(),
+            (1, 0) => self.axes.0,
+            (0, 1) => self.axes.1,
+            _ => Vector3::zero(),
+        }
+    }
+}
+
+impl ParameterDivision2D for Plane {
+    fn parameter_division(
+        &self,
+        ((u0, u1), (v0, v1)): ((f64, f64), (f64, f64)),
+        _: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        (vec![u0, u1], vec![v0, v1])
+    }
+}
+
+impl SearchParameter<D2> for Plane {
+    type Point = Point3;
+    fn search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        _: H,
+        _: usize,
+    ) -> Option<(f64, f64)> {
+        let vec = point - self.origin;
+        Some((vec.dot(self.axes.0), vec.dot(self.axes.1)))
+    }
+}
+
+type TestShell = Shell<Point3, Segment, Plane>;
+
+/// two unit squares on the planes `z = 0` and `x = 0` sharing the edge on the y-axis
+fn two_squares() -> TestShell {
+    let p = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 1.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let v = Vertex::news(p);
+    let line = |i: usize, j: usize| Edge::new(&v[i], &v[j], Segment::new(p[i], p[j]));
+    let shared = line(0, 3);
+    let wire0 = wire![line(0, 1), line(1, 2), line(2, 3), shared.inverse()];
+    let wire1 = wire![shared, line(3, 4), line(4, 5), line(5, 0)];
+    let plane0 = Plane::new(p[0], (Vector3::unit_x(), Vector3::unit_y()));
+    let plane1 = Plane::new(p[0], (Vector3::unit_y(), Vector3::unit_z()));
+    vec![Face::new(vec![wire0], plane0), Face::new(vec![wire1], plane1)].into()
+}
+
+fn take_evaluations(shell: &TestShell) -> Vec<usize> {
+    shell
+        .face_iter()
+        .map(|face| face.surface().evaluations.take())
+        .collect()
+}
+
+fn take_divisions(shell: &TestShell) -> usize {
+    shell
+        .edge_iter()
+        .map(|edge| edge.curve().divisions.take())
+        .sum()
+}
+
+fn assert_near_box(bdb: BoundingBox<Point3>, min: Point3, max: Point3) {
+    assert!(bdb.contains(min) && bdb.contains(max), "{bdb:?}");
+    assert!(bdb.min().distance(min) < 0.1 && bdb.max().distance(max) < 0.1, "{bdb:?}");
+}
+
+#[test]
+fn cached_bounding_box() {
+    let shell = two_squares();
+    let bdb = shell.bounding_box();
+    assert_near_box(bdb, Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    assert!(take_evaluations(&shell).iter().all(|count| *count > 0));
+    assert!(take_divisions(&shell) > 0);
+
+    // the second call evaluates nothing
+    assert_eq!(shell.bounding_box(), bdb);
+    assert_eq!(take_evaluations(&shell), vec![0, 0]);
+    assert_eq!(take_divisions(&shell), 0);
+    // the clones share the caches
+    let face = shell[0].clone();
+    assert_near_box(
+        face.cached_bounding_box(),
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+    );
+    assert_eq!(take_evaluations(&shell), vec![0, 0]);
+}
+
+#[test]
+fn recompute_only_modified_face() {
+    let shell = two_squares();
+    shell.bounding_box();
+    take_evaluations(&shell);
+    take_divisions(&shell);
+
+    // move the plane of the second face without changing the boundary
+    let revision = shell[1].revision();
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 0.0),
+        (Vector3::unit_y(), Vector3::new(-1.0, 0.0, 1.0)),
+    );
+    let evaluations = plane.evaluations.clone();
+    shell[1].set_surface(plane);
+    assert!(shell[1].revision() > revision);
+
+    let bdb = shell.bounding_box();
+    assert_near_box(bdb, Point3::new(-1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    assert!(evaluations.take() > 0);
+    assert_eq!(take_evaluations(&shell), vec![0, 0]);
+    // the boxes of the edges are not computed again
+    assert_eq!(take_divisions(&shell), 0);
+    // the edges of the first face are not evaluated
+    let edges0 = shell[0].boundaries()[0].clone();
+    for edge in &edges0 {
+        edge.curve().evaluations.take();
+    }
+    shell.bounding_box();
+    assert!(edges0.iter().all(|edge| edge.curve().evaluations.take() == 0));
+}
+
+#[test]
+fn recompute_faces_of_modified_edge() {
+    let shell = two_squares();
+    shell.bounding_box();
+    take_evaluations(&shell);
+    take_divisions(&shell);
+
+    let edge = shell[0].boundaries()[0][0].clone();
+    let p = [Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)];
+    let revision = edge.revision();
+    edge.set_curve(Segment::new(p[0], p[1]));
+    assert!(edge.revision() > revision);
+
+    shell.bounding_box();
+    let evaluations = take_evaluations(&shell);
+    assert!(evaluations[0] > 0 && evaluations[1] == 0, "{evaluations:?}");
+    // only the modified edge is divided again
+    assert_eq!(take_divisions(&shell), 1);
+}
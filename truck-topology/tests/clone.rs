@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+use truck_topology::*;
+
+fn tetrahedron() -> Solid<usize, usize, usize> {
+    let v = Vertex::news([0, 1, 2, 3]);
+    let edge = [
+        Edge::new(&v[0], &v[1], 10),
+        Edge::new(&v[0], &v[2], 20),
+        Edge::new(&v[0], &v[3], 30),
+        Edge::new(&v[1], &v[2], 40),
+        Edge::new(&v[1], &v[3], 50),
+        Edge::new(&v[2], &v[3], 60),
+    ];
+    let shell: Shell<_, _, _> = vec![
+        Face::new(vec![wire![&edge[0], &edge[3], &edge[1].inverse()]], 100),
+        Face::new(vec![wire![&edge[1], &edge[5], &edge[2].inverse()]], 200),
+        Face::new(vec![wire![&edge[2], &edge[4].inverse(), &edge[0].inverse()]], 300),
+        Face::new(vec![wire![&edge[3].inverse(), &edge[4], &edge[5].inverse()]], 400),
+    ]
+    .into();
+    Solid::new(vec![shell])
+}
+
+fn vertex_ids(solid: &Solid<usize, usize, usize>) -> HashSet<VertexID<usize>> {
+    solid.vertex_iter().map(|v| v.id()).collect()
+}
+
+#[test]
+fn topological_clone_is_independent() {
+    let solid0 = tetrahedron();
+    let solid1 = solid0.topological_clone();
+
+    // the sharing of the elements is preserved in the copy
+    assert_eq!(vertex_ids(&solid1).len(), 4);
+    let edge_ids: HashSet<_> = solid1.edge_iter().map(|e| e.id()).collect();
+    assert_eq!(edge_ids.len(), 6);
+    assert!(vertex_ids(&solid0).is_disjoint(&vertex_ids(&solid1)));
+
+    let (vertex0, vertex1) = (
+        solid0.vertex_iter().next().unwrap(),
+        solid1.vertex_iter().next().unwrap(),
+    );
+    assert_eq!(vertex0.point(), vertex1.point());
+    vertex1.set_point(100);
+    assert_eq!(vertex0.point(), 0);
+    // the modification is shared among the faces of the copy
+    assert!(solid1.vertex_iter().filter(|v| v.point() == 100).count() > 1);
+
+    let (edge0, edge1) = (
+        solid0.edge_iter().next().unwrap(),
+        solid1.edge_iter().next().unwrap(),
+    );
+    edge1.set_curve(1000);
+    assert_eq!(edge0.curve(), 10);
+
+    let (face0, face1) = (&solid0.boundaries()[0][0], &solid1.boundaries()[0][0]);
+    face1.set_surface(10000);
+    assert_eq!(face0.surface(), 100);
+}
+
+#[test]
+fn shallow_share_clone_shares_geometry() {
+    let solid0 = tetrahedron();
+    let solid1 = solid0.shallow_share_clone();
+    assert_eq!(vertex_ids(&solid0), vertex_ids(&solid1));
+
+    let vertex1 = solid1.vertex_iter().next().unwrap();
+    vertex1.set_point(100);
+    assert_eq!(solid0.vertex_iter().next().unwrap().point(), 100);
+
+    let edge1 = solid1.edge_iter().next().unwrap();
+    edge1.set_curve(1000);
+    assert_eq!(solid0.edge_iter().next().unwrap().curve(), 1000);
+}
+
+#[test]
+fn mapped_preserves_sharing_between_shells() {
+    let shell = tetrahedron().into_boundaries().pop().unwrap();
+    let mut inner = shell.clone();
+    inner.iter_mut().for_each(|face| {
+        face.invert();
+    });
+    // the two shells share all vertices and edges
+    let solid0 = Solid::new(vec![shell, inner]);
+    let solid1 = solid0.mapped(|i| *i + 1, |j| *j + 1, |k| *k + 1);
+
+    assert_eq!(vertex_ids(&solid1).len(), 4);
+    let edge_ids: HashSet<_> = solid1.edge_iter().map(|e| e.id()).collect();
+    assert_eq!(edge_ids.len(), 6);
+    let [outer1, inner1] = solid1.boundaries().as_slice() else {
+        unreachable!()
+    };
+    for (face0, face1) in outer1.face_iter().zip(inner1.face_iter()) {
+        assert_eq!(face0.surface(), face1.surface());
+        assert_ne!(face0.orientation(), face1.orientation());
+    }
+}
+
+#[test]
+fn serials_follow_ids() {
+    let solid0 = tetrahedron();
+    let solid1 = solid0.topological_clone();
+    let solid2 = solid0.shallow_share_clone();
+
+    let vertex_serials = |solid: &Solid<usize, usize, usize>| -> HashSet<usize> {
+        solid.vertex_iter().map(|v| v.serial()).collect()
+    };
+    let edge_serials = |solid: &Solid<usize, usize, usize>| -> HashSet<usize> {
+        solid.edge_iter().map(|e| e.serial()).collect()
+    };
+    let face_serials = |solid: &Solid<usize, usize, usize>| -> HashSet<usize> {
+        solid.face_iter().map(|f| f.serial()).collect()
+    };
+    assert_eq!(vertex_serials(&solid0).len(), 4);
+    assert_eq!(edge_serials(&solid0).len(), 6);
+    assert_eq!(face_serials(&solid0).len(), 4);
+
+    // the shared entities have the same serial numbers
+    assert_eq!(vertex_serials(&solid0), vertex_serials(&solid2));
+    assert_eq!(edge_serials(&solid0), edge_serials(&solid2));
+    assert_eq!(face_serials(&solid0), face_serials(&solid2));
+    // the copied entities are distinct
+    assert!(vertex_serials(&solid0).is_disjoint(&vertex_serials(&solid1)));
+    assert!(edge_serials(&solid0).is_disjoint(&edge_serials(&solid1)));
+    assert!(face_serials(&solid0).is_disjoint(&face_serials(&solid1)));
+
+    // a serial number is not reused after the entity is dropped
+    let serial = Vertex::new(0).serial();
+    assert_ne!(Vertex::new(0).serial(), serial);
+}
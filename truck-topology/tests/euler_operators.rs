@@ -1,7 +1,7 @@
 use std::ops::Bound;
 use truck_base::{assert_near, cgmath64::*, tolerance::*};
 use truck_geotrait::*;
-use truck_topology::*;
+use truck_topology::{shell::ShellCondition, *};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 struct Segment {
@@ -107,6 +107,28 @@ impl SearchParameter<D1> for Segment {
     }
 }
 
+impl SearchNearestParameter<D1> for Segment {
+    type Point = Point3;
+    fn search_nearest_parameter<H: Into<SPHint1D>>(
+        &self,
+        point: Point3,
+        _: H,
+        _: usize,
+    ) -> Option<f64> {
+        let p = point - self.ends.0;
+        let r = self.ends.1 - self.ends.0;
+        let t = f64::clamp(p.dot(r) / r.dot(r), 0.0, 1.0);
+        Some(self.range.0 + (self.range.1 - self.range.0) * t)
+    }
+}
+
+impl ParameterDivision1D for Segment {
+    type Point = Point3;
+    fn parameter_division(&self, range: (f64, f64), _: f64) -> (Vec<f64>, Vec<Point3>) {
+        (vec![range.0, range.1], vec![self.subs(range.0), self.subs(range.1)])
+    }
+}
+
 impl Invertible for Segment {
     #[inline(always)]
     fn invert(&mut self) {
@@ -208,3 +230,90 @@ fn solid_cut_edge() {
     let count = tri.edge_iter().count();
     assert_eq!(count, 12);
 }
+
+#[test]
+fn shell_merge_vertices() {
+    let p = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(2.0, 1.0, 0.0),
+    ];
+    let v = Vertex::news(&p);
+    let edge = [
+        Edge::new(&v[0], &v[1], Segment::new(p[0], p[1])),
+        Edge::new(&v[1], &v[2], Segment::new(p[1], p[2])),
+        Edge::new(&v[2], &v[0], Segment::new(p[2], p[0])),
+        Edge::new(&v[3], &v[4], Segment::new(p[3], p[4])),
+        Edge::new(&v[4], &v[5], Segment::new(p[4], p[5])),
+        Edge::new(&v[3], &v[5], Segment::new(p[3], p[5])),
+    ];
+    let mut shell: Shell<_, _, ()> = vec![
+        Face::new(vec![vec![edge[0].clone(), edge[1].clone(), edge[2].clone()].into()], ()),
+        Face::new(vec![vec![edge[3].clone(), edge[4].clone(), edge[5].inverse()].into()], ()),
+    ]
+    .into();
+    assert_eq!(shell.vertex_adjacency().len(), 6);
+
+    // the vertices of an edge cannot be merged
+    assert!(!shell.merge_vertices(&v[4], &v[3]));
+    assert!(!shell.merge_vertices(&v[1], &v[1]));
+    assert!(shell.merge_vertices(&v[1], &v[3]));
+    assert!(!shell.merge_vertices(&v[1], &v[3]));
+
+    let (face0, face1) = (&shell[0], &shell[1]);
+    assert_eq!(face0.boundaries()[0][0].back(), face1.boundaries()[0][0].front());
+    assert_eq!(face1.boundaries()[0][2].back(), &v[1]);
+    assert_eq!(shell.vertex_adjacency().len(), 5);
+    assert!(shell.vertex_iter().all(|vertex| vertex != v[3]));
+    assert!(shell.edge_iter().all(|edge| edge.is_geometric_consistent()));
+}
+
+#[test]
+fn shell_split_at_t_junctions() {
+    let p = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(1.0, -1.0, 0.0),
+        Point3::new(1.0, 1.0e-4, 0.0),
+    ];
+    let v = Vertex::news(&p);
+    let edge = [
+        Edge::new(&v[0], &v[3], Segment::new(p[0], p[3])),
+        Edge::new(&v[3], &v[1], Segment::new(p[3], p[1])),
+        Edge::new(&v[1], &v[0], Segment::new(p[1], p[0])),
+        Edge::new(&v[0], &v[4], Segment::new(p[0], p[4])),
+        Edge::new(&v[4], &v[1], Segment::new(p[4], p[1])),
+        Edge::new(&v[1], &v[2], Segment::new(p[1], p[2])),
+        Edge::new(&v[2], &v[0], Segment::new(p[2], p[0])),
+    ];
+    // the edge from v[1] to v[0] is split by v[4] only in the upper face
+    let mut shell: Shell<_, _, ()> = vec![
+        Face::new(vec![vec![edge[0].clone(), edge[1].clone(), edge[2].clone()].into()], ()),
+        Face::new(
+            vec![vec![edge[3].clone(), edge[4].clone(), edge[5].clone(), edge[6].clone()].into()],
+            (),
+        ),
+    ]
+    .into();
+    assert!(shell.t_junctions(TOLERANCE).is_empty());
+    let junctions = shell.t_junctions(1.0e-3);
+    assert_eq!(junctions.len(), 1);
+    assert_eq!(junctions[0].0.id(), edge[2].id());
+    assert_eq!(junctions[0].1, v[4]);
+    assert_eq!(shell.edge_iter().filter(|e| e.id() == edge[2].id()).count(), 1);
+
+    assert_eq!(shell.split_at_t_junctions(1.0e-3), 1);
+    assert!(shell.t_junctions(1.0e-3).is_empty());
+    assert!(shell.edge_iter().all(|e| e.id() != edge[2].id()));
+    let boundary = shell[0].boundaries()[0].clone();
+    assert_eq!(boundary.len(), 4);
+    assert_eq!(boundary[2], edge[4].inverse());
+    assert_eq!(boundary[3], edge[3].inverse());
+    assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+    assert_eq!(shell.extract_boundaries()[0].len(), 4);
+    assert_eq!(shell.split_at_t_junctions(1.0e-3), 0);
+}
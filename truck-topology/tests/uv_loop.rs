@@ -0,0 +1,218 @@
+use std::f64::consts::PI;
+use truck_base::{cgmath64::*, hash::HashGen, tolerance::*};
+use truck_topology::uv_loop::*;
+
+const PERIODS: (Option<f64>, Option<f64>) = (Some(2.0 * PI), None);
+
+fn uv_loop(samples: &[(f64, f64)]) -> UvLoop {
+    let samples = samples.iter().map(|&(u, v)| Point2::new(u, v));
+    UvLoop::new(samples, PERIODS, Point2::origin())
+}
+
+#[test]
+fn seam_crossing_loop() {
+    // the samples given in the various copies of the fundamental domain
+    let samples = [(0.5, 1.0), (-0.5, 1.0), (2.0 * PI - 0.5, 0.0), (4.0 * PI + 0.5, 0.0)];
+    let uv_loop = uv_loop(&samples);
+    assert_eq!(uv_loop.branches().len(), 2);
+    for branch in uv_loop.branches() {
+        assert!(branch.points().iter().all(|p| 0.0 <= p.x && p.x < 2.0 * PI));
+    }
+    assert_eq!(uv_loop.total_jump(), Vector2::new(0.0, 0.0));
+    let bdb = uv_loop.bounding_box();
+    assert!((bdb.max() - bdb.min()).near(&Vector2::new(1.0, 1.0)));
+    let unwrapped: Vec<Point2> = uv_loop.unwrapped_iter().collect();
+    assert_eq!(unwrapped.len(), 4);
+    let closed = unwrapped.iter().zip(unwrapped.iter().cycle().skip(1));
+    closed.for_each(|(p, q)| assert!(p.distance(*q) < 1.0 + TOLERANCE));
+
+    // counterclockwise
+    for k in -1..=1 {
+        let shift = 2.0 * PI * k as f64;
+        assert_eq!(uv_loop.winding_number(Point2::new(0.1 + shift, 0.5)), 1);
+        assert_eq!(uv_loop.winding_number(Point2::new(-0.1 + shift, 0.5)), 1);
+        assert_eq!(uv_loop.winding_number(Point2::new(PI + shift, 0.5)), 0);
+        assert_eq!(uv_loop.winding_number(Point2::new(0.1 + shift, 1.5)), 0);
+    }
+}
+
+#[test]
+fn wrapping_loops() {
+    // the boundaries of the band `0 <= v <= 1` around the cylinder
+    let n = 8;
+    let circle = |v: f64, sign: f64| -> Vec<(f64, f64)> {
+        (0..n).map(|i| (sign * 2.0 * PI * i as f64 / n as f64, v)).collect()
+    };
+    let bottom = uv_loop(&circle(0.0, 1.0));
+    let top = uv_loop(&circle(1.0, -1.0));
+    assert_eq!(bottom.total_jump(), Vector2::new(2.0 * PI, 0.0));
+    assert_eq!(top.total_jump(), Vector2::new(-2.0 * PI, 0.0));
+    assert_eq!(bottom.branches().len(), 1);
+    // the first sample is in the middle of the branch
+    assert_eq!(top.branches().len(), 1);
+
+    let winding = |u: f64, v: f64| {
+        let uv = Point2::new(u, v);
+        bottom.winding_number(uv) + top.winding_number(uv)
+    };
+    for u in [0.0, 1.0, PI, -1.0, 7.0] {
+        assert_eq!(winding(u, 0.5), 1);
+        assert_eq!(winding(u, -0.5), 0);
+        assert_eq!(winding(u, 1.5), 0);
+    }
+}
+
+fn boundary(loops: &[&[&[(f64, f64)]]], periods: (Option<f64>, Option<f64>)) -> UvBoundary {
+    let loops = loops
+        .iter()
+        .map(|edges| {
+            let edges = edges.iter();
+            edges.map(|edge| edge.iter().map(|&(u, v)| Point2::new(u, v)).collect()).collect()
+        })
+        .collect();
+    UvBoundary::new(loops, periods, Point2::origin())
+}
+
+fn parameters(res: &ScanlineResult) -> Vec<f64> {
+    res.crossings.iter().map(|crossing| crossing.parameter).collect()
+}
+
+#[test]
+fn scanline_through_vertices() {
+    let diamond: &[&[(f64, f64)]] = &[&[(0.0, -1.0)], &[(1.0, 0.0)], &[(0.0, 1.0)], &[(-1.0, 0.0)]];
+    let boundary = boundary(&[diamond], (None, None));
+
+    // passing through the vertices
+    let res = boundary.scanline_crossings(UV::V, 0.0, TOLERANCE);
+    assert_eq!(parameters(&res), vec![-1.0, 1.0]);
+    assert_eq!(res.crossings[0].edge, (0, 3));
+    assert_eq!(res.crossings[0].direction, CrossingDirection::Down);
+    assert_eq!(res.crossings[1].edge, (0, 0));
+    assert_eq!(res.crossings[1].direction, CrossingDirection::Up);
+    // the perturbation within the tolerance does not change the result
+    let perturbed = boundary.scanline_crossings(UV::V, TOLERANCE / 2.0, TOLERANCE);
+    assert_eq!(perturbed, res);
+
+    // touching from below: the double crossing
+    let res = boundary.scanline_crossings(UV::V, 1.0, TOLERANCE);
+    assert_eq!(parameters(&res), vec![0.0, 0.0]);
+    // touching from above: no crossings
+    let res = boundary.scanline_crossings(UV::V, -1.0, TOLERANCE);
+    assert!(res.crossings.is_empty());
+    assert!(res.intervals.is_empty());
+    // through the vertices along `v`
+    let res = boundary.scanline_crossings(UV::U, 0.0, TOLERANCE);
+    assert_eq!(parameters(&res), vec![-1.0, 1.0]);
+}
+
+#[test]
+fn scanline_along_edges() {
+    let rectangle: &[&[(f64, f64)]] = &[
+        &[(0.0, 0.0), (1.0, 0.0)],
+        &[(2.0, 0.0), (2.0, 0.5)],
+        &[(2.0, 1.0), (1.0, 1.0)],
+        &[(0.0, 1.0)],
+    ];
+    let boundary = boundary(&[rectangle], (None, None));
+
+    // along the bottom: the segments are merged into one interval
+    let res = boundary.scanline_crossings(UV::V, 0.0, TOLERANCE);
+    assert!(res.crossings.is_empty());
+    assert_eq!(res.intervals.len(), 1);
+    assert_eq!(res.intervals[0].range, (0.0, 2.0));
+    assert_eq!(res.intervals[0].edge, (0, 0));
+
+    // along the top: crossing at the ends of the interval
+    let res = boundary.scanline_crossings(UV::V, 1.0, TOLERANCE);
+    assert_eq!(parameters(&res), vec![0.0, 2.0]);
+    assert_eq!(res.intervals.len(), 1);
+    assert_eq!(res.intervals[0].range, (0.0, 2.0));
+    assert_eq!(res.intervals[0].edge, (0, 2));
+
+    // along the right side
+    let res = boundary.scanline_crossings(UV::U, 2.0, TOLERANCE);
+    assert_eq!(parameters(&res), vec![0.0, 1.0]);
+    assert_eq!(res.crossings[0].edge, (0, 0));
+    assert_eq!(res.crossings[1].edge, (0, 2));
+    assert_eq!(res.intervals.len(), 1);
+    assert_eq!(res.intervals[0].range, (0.0, 1.0));
+    assert_eq!(res.intervals[0].edge, (0, 1));
+}
+
+/// the square `[-2, 2]^2` with the hole of the unit circle, divided into `n` edges
+fn holed_square(n: usize) -> Vec<Vec<Vec<Point2>>> {
+    let square = [(-2.0, -2.0), (2.0, -2.0), (2.0, 2.0), (-2.0, 2.0)];
+    let square = square.iter().map(|&(u, v)| vec![Point2::new(u, v)]).collect();
+    // clockwise from the top
+    let circle = (0..n)
+        .map(|i| {
+            let t = PI / 2.0 - 2.0 * PI * i as f64 / n as f64;
+            vec![Point2::new(f64::cos(t), f64::sin(t))]
+        })
+        .collect();
+    vec![square, circle]
+}
+
+#[test]
+fn scanline_tangent_to_hole() {
+    let boundary = UvBoundary::new(holed_square(16), (None, None), Point2::origin());
+    let res = boundary.scanline_crossings(UV::V, 1.0, TOLERANCE);
+    let params = parameters(&res);
+    assert_eq!(params.len(), 4);
+    assert!(params[1].so_small() && params[2].so_small());
+    assert_eq!(res.crossings[1].edge.0, 1);
+    assert_ne!(res.crossings[1].direction, res.crossings[2].direction);
+    let res = boundary.scanline_crossings(UV::V, -1.0, TOLERANCE);
+    assert_eq!(parameters(&res), vec![-2.0, 2.0]);
+    let res = boundary.scanline_crossings(UV::V, 1.0 + 1.0e-3, TOLERANCE);
+    assert_eq!(parameters(&res), vec![-2.0, 2.0]);
+    let res = boundary.scanline_crossings(UV::U, 1.0, TOLERANCE);
+    assert_eq!(parameters(&res).len(), 4);
+}
+
+#[test]
+fn scanline_across_seam() {
+    let square: &[&[(f64, f64)]] = &[&[(-0.5, 0.0)], &[(0.5, 0.0)], &[(0.5, 1.0)], &[(-0.5, 1.0)]];
+    let boundary = boundary(&[square], PERIODS);
+    // the parameters along the scanline are in the fundamental domain
+    let res = boundary.scanline_crossings(UV::V, 0.5, TOLERANCE);
+    assert_eq!(parameters(&res), vec![0.5, 2.0 * PI - 0.5]);
+    assert_eq!(res.crossings[0].direction, CrossingDirection::Up);
+    // all copies are intersected
+    for u in [0.0, 2.0 * PI, -4.0 * PI + 0.25] {
+        let res = boundary.scanline_crossings(UV::U, u, TOLERANCE);
+        assert_eq!(parameters(&res), vec![0.0, 1.0]);
+    }
+    let res = boundary.scanline_crossings(UV::U, PI, TOLERANCE);
+    assert!(res.crossings.is_empty());
+}
+
+#[test]
+fn scanline_parity() {
+    let loops = holed_square(32);
+    let boundary = UvBoundary::new(loops.clone(), (None, None), Point2::origin());
+    let uv_loops: Vec<UvLoop> = loops
+        .into_iter()
+        .map(|edges| UvLoop::new(edges.into_iter().flatten(), (None, None), Point2::origin()))
+        .collect();
+    let inside = |uv: Point2| uv_loops.iter().map(|l| l.winding_number(uv)).sum::<isize>() != 0;
+    for i in 0..3000 {
+        let [x, y] = <f64 as HashGen<f64>>::hash2(i as f64);
+        let (dir, value) = match x < 0.5 {
+            true => (UV::U, 5.0 * y - 2.5),
+            false => (UV::V, 5.0 * y - 2.5),
+        };
+        let point = |t: f64| match dir {
+            UV::U => Point2::new(value, t),
+            UV::V => Point2::new(t, value),
+        };
+        let params = parameters(&boundary.scanline_crossings(dir, value, TOLERANCE));
+        assert_eq!(params.len() % 2, 0, "{dir:?} {value}");
+        let mut samples = vec![-3.0, 3.0];
+        samples.extend(params.windows(2).map(|t| (t[0] + t[1]) / 2.0));
+        for t in samples {
+            let count = params.iter().filter(|param| **param < t).count();
+            assert_eq!(count % 2 == 1, inside(point(t)), "{dir:?} {value} {t}");
+        }
+    }
+}